@@ -329,7 +329,7 @@ pub fn parse_with_proc_macros(
     }
 }
 
-fn default_test_proc_macros() -> [(String, ProcMacro); 5] {
+fn default_test_proc_macros() -> [(String, ProcMacro); 6] {
     [
         (
             r#"
@@ -361,6 +361,20 @@ pub fn derive_identity(item: TokenStream) -> TokenStream {
         ),
         (
             r#"
+#[proc_macro_derive(DeriveWithHelper, attributes(helper))]
+pub fn derive_with_helper(_item: TokenStream) -> TokenStream {
+    TokenStream::new()
+}
+"#
+            .into(),
+            ProcMacro {
+                name: "DeriveWithHelper".into(),
+                kind: crate::ProcMacroKind::CustomDerive,
+                expander: sync::Arc::new(EmptyProcMacroExpander),
+            },
+        ),
+        (
+            r#"
 #[proc_macro_attribute]
 pub fn input_replace(attr: TokenStream, _item: TokenStream) -> TokenStream {
     attr
@@ -501,6 +515,20 @@ fn expand(
     }
 }
 
+// Expands to nothing
+#[derive(Debug)]
+struct EmptyProcMacroExpander;
+impl ProcMacroExpander for EmptyProcMacroExpander {
+    fn expand(
+        &self,
+        _: &Subtree,
+        _: Option<&Subtree>,
+        _: &Env,
+    ) -> Result<Subtree, ProcMacroExpansionError> {
+        Ok(Subtree::empty())
+    }
+}
+
 // Pastes the attribute input as its output
 #[derive(Debug)]
 struct AttributeInputReplaceProcMacroExpander;
@@ -185,6 +185,10 @@ fn find_path_for_module(
     let root_def_map = crate_root.def_map(db);
     for (name, def_id) in root_def_map.extern_prelude() {
         if module_id == def_id {
+            if *name == known::std && !is_std_usable(&root_def_map) {
+                cov_mark::hit!(no_std_excludes_std_paths);
+                continue;
+            }
             let name = scope_name.unwrap_or_else(|| name.clone());
 
             let name_already_occupied_in_type_ns = def_map
@@ -224,6 +228,12 @@ fn find_path_for_module(
     )
 }
 
+/// Whether paths into `std` compile in the crate, which isn't the case for `#![no_std]` crates
+/// unless they declare `extern crate std;`.
+fn is_std_usable(root_def_map: &DefMap) -> bool {
+    !root_def_map.is_no_std() || root_def_map[DefMap::ROOT].scope.type_(&known::std).is_some()
+}
+
 fn find_in_scope(
     db: &dyn DefDatabase,
     def_map: &DefMap,
@@ -531,15 +541,38 @@ mod tests {
     fn check_found_path_(ra_fixture: &str, path: &str, prefix_kind: Option<PrefixKind>) {
         let (db, pos) = TestDB::with_position(ra_fixture);
         let module = db.module_at_position(pos);
+        let (mod_path, resolved) = resolve_path(&db, module, path);
+
+        let found_path =
+            find_path_inner(&db, ItemInNs::Types(resolved), module, prefix_kind, false);
+        assert_eq!(found_path, Some(mod_path), "{prefix_kind:?}");
+    }
+
+    /// Like `check_found_path_`, but checks that no path is found for the item.
+    fn check_no_path_found(ra_fixture: &str, path: &str) {
+        let (db, pos) = TestDB::with_position(ra_fixture);
+        let module = db.module_at_position(pos);
+        let (_, resolved) = resolve_path(&db, module, path);
+
+        for prefix_kind in
+            [None, Some(PrefixKind::Plain), Some(PrefixKind::ByCrate), Some(PrefixKind::BySelf)]
+        {
+            let found_path =
+                find_path_inner(&db, ItemInNs::Types(resolved), module, prefix_kind, false);
+            assert_eq!(found_path, None, "{prefix_kind:?}");
+        }
+    }
+
+    fn resolve_path(db: &TestDB, module: ModuleId, path: &str) -> (ModPath, ModuleDefId) {
         let parsed_path_file = syntax::SourceFile::parse(&format!("use {path};"));
         let ast_path =
             parsed_path_file.syntax_node().descendants().find_map(syntax::ast::Path::cast).unwrap();
-        let mod_path = ModPath::from_src(&db, ast_path, &Hygiene::new_unhygienic()).unwrap();
+        let mod_path = ModPath::from_src(db, ast_path, &Hygiene::new_unhygienic()).unwrap();
 
-        let def_map = module.def_map(&db);
+        let def_map = module.def_map(db);
         let resolved = def_map
             .resolve_path(
-                &db,
+                db,
                 module.local_id,
                 &mod_path,
                 crate::item_scope::BuiltinShadowMode::Module,
@@ -548,10 +581,7 @@ fn check_found_path_(ra_fixture: &str, path: &str, prefix_kind: Option<PrefixKin
             .0
             .take_types()
             .unwrap();
-
-        let found_path =
-            find_path_inner(&db, ItemInNs::Types(resolved), module, prefix_kind, false);
-        assert_eq!(found_path, Some(mod_path), "{prefix_kind:?}");
+        (mod_path, resolved)
     }
 
     fn check_found_path(
@@ -1056,6 +1086,53 @@ pub mod sync {
         );
     }
 
+    #[test]
+    fn no_std_paths_in_no_std_crate() {
+        cov_mark::check!(no_std_excludes_std_paths);
+        check_no_path_found(
+            r#"
+//- /main.rs crate:main deps:core,std
+#![no_std]
+
+$0
+
+//- /std.rs crate:std deps:core
+
+pub mod collections {
+    pub struct HashMap;
+}
+
+//- /zzz.rs crate:core
+        "#,
+            "std::collections::HashMap",
+        );
+    }
+
+    #[test]
+    fn std_paths_in_no_std_crate_with_extern_crate_std() {
+        check_found_path(
+            r#"
+//- /main.rs crate:main deps:core,std
+#![no_std]
+extern crate std;
+
+$0
+
+//- /std.rs crate:std deps:core
+
+pub mod collections {
+    pub struct HashMap;
+}
+
+//- /zzz.rs crate:core
+        "#,
+            "std::collections::HashMap",
+            "std::collections::HashMap",
+            "std::collections::HashMap",
+            "std::collections::HashMap",
+        );
+    }
+
     #[test]
     fn prefer_shorter_paths_if_not_alloc() {
         check_found_path(
@@ -1,5 +1,6 @@
 //! Attributes & documentation for hir types.
 
+use cfg::CfgExpr;
 use hir_def::{
     attr::{AttrsWithOwner, Documentation},
     item_scope::ItemInNs,
@@ -178,3 +179,10 @@ fn resolve_doc_path(
         }),
     }
 }
+
+/// Parses the predicate of a `#[cfg(...)]` attribute from its token tree, for the attributes that
+/// aren't lowered with a definition, like the ones of statements and expressions.
+pub fn parse_cfg(tt: &ast::TokenTree) -> CfgExpr {
+    let (tt, _) = mbe::syntax_node_to_token_tree(tt.syntax());
+    CfgExpr::parse(&tt)
+}
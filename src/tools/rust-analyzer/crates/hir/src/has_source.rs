@@ -6,7 +6,7 @@
     src::{HasChildSource, HasSource as _},
     Lookup, MacroId, VariantId,
 };
-use hir_expand::InFile;
+use hir_expand::{HirFileId, InFile};
 use syntax::ast;
 
 use crate::{
@@ -32,6 +32,12 @@ pub fn definition_source(self, db: &dyn HirDatabase) -> InFile<ModuleSource> {
         def_map[self.id.local_id].definition_source(db.upcast())
     }
 
+    /// Returns the file of the node which defines this module, without parsing it.
+    pub fn definition_source_file_id(self, db: &dyn HirDatabase) -> HirFileId {
+        let def_map = self.id.def_map(db.upcast());
+        def_map[self.id.local_id].definition_source_file_id()
+    }
+
     pub fn is_mod_rs(self, db: &dyn HirDatabase) -> bool {
         let def_map = self.id.def_map(db.upcast());
         match def_map[self.id.local_id].origin {
@@ -87,7 +87,7 @@
 use crate::db::{DefDatabase, HirDatabase};
 
 pub use crate::{
-    attrs::{HasAttrs, Namespace},
+    attrs::{parse_cfg, HasAttrs, Namespace},
     diagnostics::{
         AnyDiagnostic, BreakOutsideOfLoop, ExpectedFunction, InactiveCode, IncoherentImpl,
         IncorrectCase, InvalidDeriveTarget, MacroDefError, MacroError, MacroExpansionParseError,
@@ -2200,6 +2200,13 @@ pub fn items(self, db: &dyn HirDatabase) -> Vec<AssocItem> {
         db.trait_data(self.id).items.iter().map(|(_name, it)| (*it).into()).collect()
     }
 
+    /// The supertraits declared by this trait, without the supertraits of these.
+    pub fn direct_supertraits(self, db: &dyn HirDatabase) -> Vec<Trait> {
+        let mut res = Vec::new();
+        hir_ty::direct_super_traits(db.upcast(), self.id, |it| res.push(it.into()));
+        res
+    }
+
     pub fn items_with_supertraits(self, db: &dyn HirDatabase) -> Vec<AssocItem> {
         let traits = all_super_traits(db.upcast(), self.into());
         traits.iter().flat_map(|tr| Trait::from(*tr).items(db)).collect()
@@ -4257,6 +4264,12 @@ pub fn could_coerce_to(&self, db: &dyn HirDatabase, to: &Type) -> bool {
         hir_ty::could_coerce(db, self.env.clone(), &tys)
     }
 
+    /// Checks whether this type is equal to `other`, regardless of the trait environments the
+    /// two types were obtained in.
+    pub fn is_same_type_as(&self, other: &Type) -> bool {
+        self.ty == other.ty
+    }
+
     pub fn as_type_param(&self, db: &dyn HirDatabase) -> Option<TypeParam> {
         match self.ty.kind(Interner) {
             TyKind::Placeholder(p) => Some(TypeParam {
@@ -642,7 +642,9 @@ pub(crate) fn resolve_path(
                 }
 
                 if let Some(attr) = meta_path.parent_attr() {
-                    let adt = if let Some(field) =
+                    let adt = if let Some(adt) = attr.syntax().parent().and_then(ast::Adt::cast) {
+                        Some(adt)
+                    } else if let Some(field) =
                         attr.syntax().parent().and_then(ast::RecordField::cast)
                     {
                         field.syntax().ancestors().take(4).find_map(ast::Adt::cast)
@@ -6,10 +6,10 @@
     ModuleDefId, ModuleId, TraitId,
 };
 use hir_expand::{HirFileId, InFile};
-use hir_ty::db::HirDatabase;
+use hir_ty::{db::HirDatabase, display::HirDisplay};
 use syntax::{ast::HasName, AstNode, SmolStr, SyntaxNode, SyntaxNodePtr};
 
-use crate::{Module, ModuleDef, Semantics};
+use crate::{Impl, Module, ModuleDef, Semantics};
 
 /// The actual data that is stored in the index. It should be as compact as
 /// possible.
@@ -21,11 +21,15 @@ pub struct FileSymbol {
     pub loc: DeclarationLocation,
     pub container_name: Option<SmolStr>,
     pub is_alias: bool,
+    pub is_deprecated: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DeclarationLocation {
     /// The file id for both the `ptr` and `name_ptr`.
+    ///
+    /// For declarations generated by a macro this is the macro file, and the pointers point into
+    /// the expansion.
     pub hir_file_id: HirFileId,
     /// This points to the whole syntax node of the declaration.
     pub ptr: SyntaxNodePtr,
@@ -56,6 +60,16 @@ pub fn original_name_range(&self, db: &dyn HirDatabase) -> Option<FileRange> {
         let node = resolve_node(db, self.hir_file_id, &self.name_ptr);
         node.as_ref().original_file_range_opt(db.upcast())
     }
+
+    /// The range of the macro call that generated this declaration, mapped up to the original
+    /// file. For derives and attribute macros this is the invoking attribute.
+    ///
+    /// Returns `None` for declarations written out in source.
+    pub fn macro_call_range(&self, db: &dyn HirDatabase) -> Option<FileRange> {
+        let macro_file = self.hir_file_id.macro_file()?;
+        let loc = db.lookup_intern_macro_call(macro_file.macro_call_id);
+        Some(loc.kind.original_call_range(db.upcast()))
+    }
 }
 
 fn resolve_node(
@@ -201,9 +215,11 @@ fn collect_from_body(&mut self, body_id: impl Into<DefWithBodyId>) {
 
     fn collect_from_impl(&mut self, impl_id: ImplId) {
         let impl_data = self.db.impl_data(impl_id);
-        for &assoc_item_id in &impl_data.items {
-            self.push_assoc_item(assoc_item_id)
-        }
+        self.with_container_name(Some(self.impl_name(impl_id)), |s| {
+            for &assoc_item_id in &impl_data.items {
+                s.push_assoc_item(assoc_item_id)
+            }
+        });
     }
 
     fn collect_from_trait(&mut self, trait_id: TraitId) {
@@ -225,6 +241,19 @@ fn with_container_name(&mut self, container_name: Option<SmolStr>, f: impl FnOnc
         }
     }
 
+    /// The self type of the impl for inherent impls, `<Type as Trait>` for trait impls.
+    fn impl_name(&self, impl_id: ImplId) -> SmolStr {
+        let impl_ = Impl::from(impl_id);
+        let self_ty = impl_.self_ty(self.db).display(self.db).to_string();
+        match impl_.trait_(self.db) {
+            Some(trait_) => {
+                let trait_name = self.db.trait_data(trait_.id).name.to_smol_str();
+                SmolStr::from(format!("<{self_ty} as {trait_name}>"))
+            }
+            None => self_ty.into(),
+        }
+    }
+
     fn def_with_body_id_name(&self, body_id: DefWithBodyId) -> Option<SmolStr> {
         match body_id {
             DefWithBodyId::FunctionId(id) => Some(self.db.function_data(id).name.to_smol_str()),
@@ -261,7 +290,9 @@ fn push_decl<L>(&mut self, id: L)
             name_ptr: SyntaxNodePtr::new(name_node.syntax()),
         };
 
-        if let Some(attrs) = def.attrs(self.db) {
+        let attrs = def.attrs(self.db);
+        let is_deprecated = attrs.as_ref().map_or(false, |it| it.by_key("deprecated").exists());
+        if let Some(attrs) = attrs {
             for alias in attrs.doc_aliases() {
                 self.symbols.push(FileSymbol {
                     name: alias,
@@ -269,6 +300,7 @@ fn push_decl<L>(&mut self, id: L)
                     loc: dec_loc.clone(),
                     container_name: self.current_container_name.clone(),
                     is_alias: true,
+                    is_deprecated,
                 });
             }
         }
@@ -279,6 +311,7 @@ fn push_decl<L>(&mut self, id: L)
             container_name: self.current_container_name.clone(),
             loc: dec_loc,
             is_alias: false,
+            is_deprecated,
         });
     }
 
@@ -296,7 +329,9 @@ fn push_module(&mut self, module_id: ModuleId) {
 
         let def = ModuleDef::Module(module_id.into());
 
-        if let Some(attrs) = def.attrs(self.db) {
+        let attrs = def.attrs(self.db);
+        let is_deprecated = attrs.as_ref().map_or(false, |it| it.by_key("deprecated").exists());
+        if let Some(attrs) = attrs {
             for alias in attrs.doc_aliases() {
                 self.symbols.push(FileSymbol {
                     name: alias,
@@ -304,6 +339,7 @@ fn push_module(&mut self, module_id: ModuleId) {
                     loc: dec_loc.clone(),
                     container_name: self.current_container_name.clone(),
                     is_alias: true,
+                    is_deprecated,
                 });
             }
         }
@@ -314,6 +350,7 @@ fn push_module(&mut self, module_id: ModuleId) {
             container_name: self.current_container_name.clone(),
             loc: dec_loc,
             is_alias: false,
+            is_deprecated,
         });
     }
 }
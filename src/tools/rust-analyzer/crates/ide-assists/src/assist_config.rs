@@ -4,7 +4,7 @@
 //! module, and we use to statically check that we only produce snippet
 //! assists if we are allowed to.
 
-use ide_db::{imports::insert_use::InsertUseConfig, SnippetCap};
+use ide_db::{imports::insert_use::ImportPrefs, SnippetCap};
 
 use crate::AssistKind;
 
@@ -12,7 +12,6 @@
 pub struct AssistConfig {
     pub snippet_cap: Option<SnippetCap>,
     pub allowed: Option<Vec<AssistKind>>,
-    pub insert_use: InsertUseConfig,
-    pub prefer_no_std: bool,
+    pub imports: ImportPrefs,
     pub assist_emit_must_use: bool,
 }
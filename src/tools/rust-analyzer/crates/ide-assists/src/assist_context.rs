@@ -190,7 +190,10 @@ fn add_impl(
             let mut builder = SourceChangeBuilder::new(self.file);
             f(&mut builder);
             trigger_signature_help = builder.trigger_signature_help;
-            Some(builder.finish())
+            let mut source_change = builder.finish();
+            source_change.kind.get_or_insert(id.1.change_kind());
+            source_change.origin = Some(id.0.to_owned());
+            Some(source_change)
         } else {
             None
         };
@@ -443,6 +443,82 @@ impl<const X: usize, Y, Z> Foo<X, Z> for S<Y> {
         )
     }
 
+    #[test]
+    fn test_qualifies_ambiguous_self_assoc_type() {
+        check_assist(
+            add_missing_default_members,
+            r#"
+trait Other {
+    type Output;
+}
+
+trait Foo {
+    type Output;
+    type Item;
+    fn output(&self) -> Option<Self::Output> { None }
+    fn item(&self) -> Option<Self::Item> { None }
+}
+
+struct S;
+impl Other for S {
+    type Output = ();
+}
+
+impl Foo for S {
+    $0
+}"#,
+            r#"
+trait Other {
+    type Output;
+}
+
+trait Foo {
+    type Output;
+    type Item;
+    fn output(&self) -> Option<Self::Output> { None }
+    fn item(&self) -> Option<Self::Item> { None }
+}
+
+struct S;
+impl Other for S {
+    type Output = ();
+}
+
+impl Foo for S {
+    $0fn output(&self) -> Option<<Self as Foo>::Output> { None }
+
+    fn item(&self) -> Option<Self::Item> { None }
+}"#,
+        )
+    }
+
+    #[test]
+    fn test_paths_in_macro_calls() {
+        check_assist(
+            add_missing_default_members,
+            r#"
+mod m {
+    pub struct Foo;
+    pub trait Trait {
+        fn foo(&self, foo: Foo) -> Vec<Foo> { assert_eq!(foo, Foo); vec![foo, Foo] }
+    }
+}
+struct S;
+impl m::Trait for S { $0 }"#,
+            r#"
+mod m {
+    pub struct Foo;
+    pub trait Trait {
+        fn foo(&self, foo: Foo) -> Vec<Foo> { assert_eq!(foo, Foo); vec![foo, Foo] }
+    }
+}
+struct S;
+impl m::Trait for S {
+    $0fn foo(&self, foo: m::Foo) -> Vec<m::Foo> { assert_eq!(foo, m::Foo); vec![foo, m::Foo] }
+}"#,
+        )
+    }
+
     #[test]
     fn test_const_substitution_2() {
         check_assist(
@@ -466,6 +542,29 @@ impl<X> Foo<42, {20 + 22}, X> for () {
         )
     }
 
+    #[test]
+    fn test_const_substitution_with_defaults() {
+        check_assist(
+            add_missing_default_members,
+            r#"
+trait Foo<T, const N: usize = 4> {
+    fn get_n(&self, arg: &T) -> usize { N }
+}
+
+impl<X> Foo<X> for () {
+    $0
+}"#,
+            r#"
+trait Foo<T, const N: usize = 4> {
+    fn get_n(&self, arg: &T) -> usize { N }
+}
+
+impl<X> Foo<X> for () {
+    $0fn get_n(&self, arg: &X) -> usize { 4 }
+}"#,
+        )
+    }
+
     #[test]
     fn test_cursor_after_empty_impl_def() {
         check_assist(
@@ -702,6 +801,68 @@ fn foo(&self, bar: dyn Fn(u32) -> i32) {
         );
     }
 
+    #[test]
+    fn test_qualify_path_prefers_reexport() {
+        check_assist(
+            add_missing_impl_members,
+            r#"
+mod foo {
+    mod internal {
+        pub struct Bar;
+    }
+    pub use internal::Bar;
+    pub trait Foo { fn foo(&self, bar: internal::Bar); }
+}
+struct S;
+impl foo::Foo for S { $0 }"#,
+            r#"
+mod foo {
+    mod internal {
+        pub struct Bar;
+    }
+    pub use internal::Bar;
+    pub trait Foo { fn foo(&self, bar: internal::Bar); }
+}
+struct S;
+impl foo::Foo for S {
+    fn foo(&self, bar: foo::Bar) {
+        ${0:todo!()}
+    }
+}"#,
+        );
+    }
+
+    #[test]
+    fn test_qualify_path_uses_existing_import() {
+        check_assist(
+            add_missing_impl_members,
+            r#"
+mod foo {
+    pub mod bar {
+        pub struct Bar;
+    }
+    pub trait Foo { fn foo(&self, bar: bar::Bar); }
+}
+use foo::bar::Bar;
+struct S;
+impl foo::Foo for S { $0 }"#,
+            r#"
+mod foo {
+    pub mod bar {
+        pub struct Bar;
+    }
+    pub trait Foo { fn foo(&self, bar: bar::Bar); }
+}
+use foo::bar::Bar;
+struct S;
+impl foo::Foo for S {
+    fn foo(&self, bar: Bar) {
+        ${0:todo!()}
+    }
+}"#,
+        );
+    }
+
     #[test]
     fn test_empty_trait() {
         check_assist_not_applicable(
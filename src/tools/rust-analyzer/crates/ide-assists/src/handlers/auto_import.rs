@@ -5,8 +5,9 @@
     helpers::mod_path_to_ast,
     imports::{
         import_assets::{ImportAssets, ImportCandidate, LocatedImport},
-        insert_use::{insert_use, ImportScope},
+        insert_use::{import_alias_on_conflict, insert_use, insert_use_as_alias, ImportScope},
     },
+    source_change::ChangeKind,
 };
 use syntax::{ast, AstNode, NodeOrToken, SyntaxElement};
 
@@ -38,6 +39,10 @@
 // use super::AssistContext;
 // ```
 //
+// Setting `imports.group.style` to `std_external_crate` uses the groups of rustfmt's
+// `group_imports = "StdExternalCrate"` instead: `std`, `core` and `alloc`, external crates, and
+// all paths of the current crate together.
+//
 // .Import Granularity
 //
 // It is possible to configure how use-trees are merged with the `imports.granularity.group` setting.
@@ -47,6 +52,8 @@
 //  nesting is only supported in Rust versions later than 1.24.
 // - `module`: Merge imports from the same module into a single use statement.
 // - `item`: Don't merge imports at all, creating one import per item.
+// - `one`: Merge all imports into a single use statement, like `use {std::fmt, foo::Bar};`,
+//  as long as they have the same visibility and attributes.
 // - `preserve`: Do not change the granularity of any imports. For auto-import this has the same
 //  effect as `item`.
 //
@@ -89,11 +96,7 @@
 // ```
 pub(crate) fn auto_import(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
     let (import_assets, syntax_under_caret) = find_importable_node(ctx)?;
-    let mut proposed_imports = import_assets.search_for_imports(
-        &ctx.sema,
-        ctx.config.insert_use.prefix_kind,
-        ctx.config.prefer_no_std,
-    );
+    let mut proposed_imports = import_assets.search_for_imports(&ctx.sema, &ctx.config.imports);
     if proposed_imports.is_empty() {
         return None;
     }
@@ -103,17 +106,27 @@ pub(crate) fn auto_import(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<
         NodeOrToken::Token(token) => token.text_range(),
     };
     let group_label = group_label(import_assets.import_candidate());
-    let scope = ImportScope::find_insert_use_container(
+    let scope = ImportScope::find_insert_use_container_with_config(
         &match syntax_under_caret {
             NodeOrToken::Node(it) => it,
             NodeOrToken::Token(it) => it.parent()?,
         },
         &ctx.sema,
+        &ctx.config.imports,
     )?;
 
     // we aren't interested in different namespaces
     proposed_imports.dedup_by(|a, b| a.import_path == b.import_path);
 
+    // Traits are only imported for their items, so an import of one that conflicts with a name
+    // in scope can be renamed without touching the code using it.
+    let alias_scope = match import_assets.import_candidate() {
+        ImportCandidate::Path(_) => None,
+        ImportCandidate::TraitAssocItem(_) | ImportCandidate::TraitMethod(_) => {
+            ctx.sema.scope(scope.as_syntax_node())
+        }
+    };
+
     let current_node = match ctx.covering_element() {
         NodeOrToken::Node(node) => Some(node),
         NodeOrToken::Token(token) => token.parent(),
@@ -122,12 +135,18 @@ pub(crate) fn auto_import(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<
     let current_module =
         current_node.as_ref().and_then(|node| ctx.sema.scope(node)).map(|scope| scope.module());
 
-    // prioritize more relevant imports
-    proposed_imports
-        .sort_by_key(|import| Reverse(relevance_score(ctx, import, current_module.as_ref())));
+    // prioritize more likely traits and more relevant imports
+    proposed_imports.sort_by_cached_key(|import| {
+        Reverse((
+            import_assets.trait_import_score(ctx.db(), import),
+            relevance_score(ctx, import, current_module.as_ref()),
+        ))
+    });
 
     for import in proposed_imports {
         let import_path = import.import_path;
+        let path = mod_path_to_ast(&import_path);
+        let alias = alias_scope.as_ref().and_then(|it| import_alias_on_conflict(it, &path));
 
         acc.add_group(
             &group_label,
@@ -140,7 +159,11 @@ pub(crate) fn auto_import(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<
                     ImportScope::Module(it) => ImportScope::Module(builder.make_mut(it)),
                     ImportScope::Block(it) => ImportScope::Block(builder.make_mut(it)),
                 };
-                insert_use(&scope, mod_path_to_ast(&import_path), &ctx.config.insert_use);
+                match alias {
+                    Some(alias) => insert_use_as_alias(&scope, path, alias, &ctx.config.imports),
+                    None => insert_use(&scope, path, &ctx.config.imports),
+                }
+                builder.set_kind(ChangeKind::ImportFix);
             },
         );
     }
@@ -327,6 +350,50 @@ pub mod hash_map {
         )
     }
 
+    #[test]
+    fn no_std_paths_in_no_std_crate() {
+        let before = r"
+//- /main.rs crate:main deps:alloc,std
+#![no_std]
+Arc$0::new();
+
+//- /std.rs crate:std deps:alloc
+pub mod sync { pub use alloc::sync::Arc; }
+pub mod thread { pub struct Arc; }
+
+//- /zzz.rs crate:alloc
+pub mod sync { pub struct Arc; }
+        ";
+
+        check_auto_import_order(before, &["Import `alloc::sync::Arc`"])
+    }
+
+    #[test]
+    fn prefer_likely_traits() {
+        let before = r"
+//- /main.rs crate:main deps:foo,bar
+struct S;
+fn main() {
+    S.frob$0();
+}
+
+//- /foo.rs crate:foo
+pub trait Frob { fn frob(&self) {} }
+impl<T> Frob for T {}
+
+//- /bar.rs crate:bar
+pub mod ext {
+    pub mod future {
+        pub trait FrobExt { fn frob(&self) {} }
+        impl<T> FrobExt for T {}
+    }
+}
+pub mod prelude { pub use crate::ext::future::FrobExt; }
+        ";
+
+        check_auto_import_order(before, &["Import `bar::prelude::FrobExt`", "Import `foo::Frob`"])
+    }
+
     #[test]
     fn not_applicable_if_scope_inside_macro() {
         check_assist_not_applicable(
@@ -886,6 +953,50 @@ fn main() {
         );
     }
 
+    #[test]
+    fn trait_method_conflicting_name() {
+        check_assist(
+            auto_import,
+            r"
+            mod fmt {
+                pub trait Write {}
+            }
+            mod io {
+                pub trait Write {
+                    fn write_all(&self);
+                }
+                impl Write for u8 {
+                    fn write_all(&self) {}
+                }
+            }
+            use fmt::Write;
+
+            fn main() {
+                0u8.write_a$0ll()
+            }
+            ",
+            r"
+            mod fmt {
+                pub trait Write {}
+            }
+            mod io {
+                pub trait Write {
+                    fn write_all(&self);
+                }
+                impl Write for u8 {
+                    fn write_all(&self) {}
+                }
+            }
+            use fmt::Write;
+            use io::Write as IoWrite;
+
+            fn main() {
+                0u8.write_all()
+            }
+            ",
+        );
+    }
+
     #[test]
     fn trait_method_cross_crate() {
         check_assist(
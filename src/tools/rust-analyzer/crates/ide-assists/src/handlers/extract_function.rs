@@ -161,12 +161,12 @@ pub(crate) fn extract_function(acc: &mut Assists, ctx: &AssistContext<'_>) -> Op
                     let mod_path = module.find_use_path_prefixed(
                         ctx.sema.db,
                         ModuleDef::from(control_flow_enum),
-                        ctx.config.insert_use.prefix_kind,
-                        ctx.config.prefer_no_std,
+                        ctx.config.imports.prefix_kind,
+                        ctx.config.imports.prefer_no_std,
                     );
 
                     if let Some(mod_path) = mod_path {
-                        insert_use(&scope, mod_path_to_ast(&mod_path), &ctx.config.insert_use);
+                        insert_use(&scope, mod_path_to_ast(&mod_path), &ctx.config.imports);
                     }
                 }
             }
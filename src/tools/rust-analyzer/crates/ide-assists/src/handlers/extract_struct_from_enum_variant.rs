@@ -5,7 +5,7 @@
 use ide_db::{
     defs::Definition,
     helpers::mod_path_to_ast,
-    imports::insert_use::{insert_use, ImportScope, InsertUseConfig},
+    imports::insert_use::{insert_use, ImportPrefs, ImportScope},
     search::FileReference,
     FxHashSet, RootDatabase,
 };
@@ -81,7 +81,7 @@ pub(crate) fn extract_struct_from_enum_variant(
                     references,
                 );
                 processed.into_iter().for_each(|(path, node, import)| {
-                    apply_references(ctx.config.insert_use, path, node, import)
+                    apply_references(ctx.config.imports, path, node, import)
                 });
             }
             builder.edit_file(ctx.file_id());
@@ -97,7 +97,7 @@ pub(crate) fn extract_struct_from_enum_variant(
                     references,
                 );
                 processed.into_iter().for_each(|(path, node, import)| {
-                    apply_references(ctx.config.insert_use, path, node, import)
+                    apply_references(ctx.config.imports, path, node, import)
                 });
             }
 
@@ -348,7 +348,7 @@ fn take_all_comments(node: &SyntaxNode) -> Vec<SyntaxElement> {
 }
 
 fn apply_references(
-    insert_use_cfg: InsertUseConfig,
+    insert_use_cfg: ImportPrefs,
     segment: ast::PathSegment,
     node: SyntaxNode,
     import: Option<(ImportScope, hir::ModPath)>,
@@ -382,8 +382,8 @@ fn process_references(
                 let mod_path = module.find_use_path_prefixed(
                     ctx.sema.db,
                     *enum_module_def,
-                    ctx.config.insert_use.prefix_kind,
-                    ctx.config.prefer_no_std,
+                    ctx.config.imports.prefix_kind,
+                    ctx.config.imports.prefer_no_std,
                 );
                 if let Some(mut mod_path) = mod_path {
                     mod_path.pop_segment();
@@ -2079,7 +2079,7 @@ fn foo<U: a::A>(t: b::S<T>, u: S<U>) {
 mod a {
     pub trait A {}
 
-    pub(crate) fn bar<T, U: self::A>(t: crate::b::S<T>, u: crate::S<U>) ${0:-> _} where T: self::A {
+    pub(crate) fn bar<T, U: A>(t: crate::b::S<T>, u: crate::S<U>) ${0:-> _} where T: A {
         todo!()
     };
 }
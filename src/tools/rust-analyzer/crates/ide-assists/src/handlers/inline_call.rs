@@ -6,7 +6,10 @@
 use ide_db::{
     base_db::{FileId, FileRange},
     defs::Definition,
-    imports::insert_use::remove_path_if_in_use_stmt,
+    imports::{
+        insert_use::remove_path_if_in_use_stmt,
+        remove_unused::{referenced_defs, remove_imports, unused_imports_after_removal},
+    },
     path_transform::PathTransform,
     search::{FileReference, SearchScope},
     source_change::SourceChangeBuilder,
@@ -130,12 +133,23 @@ pub(crate) fn inline_into_callers(acc: &mut Assists, ctx: &AssistContext<'_>) ->
             for (file_id, refs) in usages.into_iter() {
                 inline_refs_for_file(file_id, refs);
             }
+            let inlined_into_def_file = current_file_usage.is_some();
             match current_file_usage {
                 Some(refs) => inline_refs_for_file(def_file, refs),
                 None => builder.edit_file(def_file),
             }
             if remove_def {
-                builder.delete(ast_func.syntax().text_range());
+                let range = ast_func.syntax().text_range();
+                // The imports are removed from the mutable tree, so the function has to be too
+                // for the edits not to overlap.
+                let func = builder.make_mut(ast_func.clone());
+                // The inlined bodies in the file may still need the imports used by the function.
+                if !inlined_into_def_file {
+                    let defs = referenced_defs(&ctx.sema, ast_func.syntax());
+                    let unused = unused_imports_after_removal(&ctx.sema, def_file, &defs, &[range]);
+                    remove_imports(builder, unused);
+                }
+                ted::remove(func.syntax());
             }
         },
     )
@@ -987,6 +1001,36 @@ fn main() {
         );
     }
 
+    #[test]
+    fn inline_elides_lifetimes_missing_from_turbofish() {
+        check_assist(
+            inline_call,
+            r#"
+fn foo<'a, T>() -> Option<&'a T> {
+    let none: Option<&'a T> = None;
+    none
+}
+
+fn main() {
+    foo$0::<usize>();
+}
+"#,
+            r#"
+fn foo<'a, T>() -> Option<&'a T> {
+    let none: Option<&'a T> = None;
+    none
+}
+
+fn main() {
+    {
+        let none: Option<&'_ usize> = None;
+        none
+    };
+}
+"#,
+        );
+    }
+
     #[test]
     fn inline_callers() {
         check_assist(
@@ -1088,6 +1132,41 @@ fn bar(a: u32, b: u32) -> u32 {
         foo * 0 + foo
     };
 }
+//- /foo.rs
+fn foo() {
+    {
+        let foo = 10;
+        foo * 0 + foo
+    };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn inline_callers_removes_unused_imports() {
+        check_assist(
+            inline_into_callers,
+            r#"
+//- /lib.rs
+mod foo;
+mod num { pub type Num = u32; }
+use num::Num;
+fn do_the_math$0(b: Num) -> Num {
+    let foo = 10;
+    foo * b + foo
+}
+//- /foo.rs
+use super::do_the_math;
+fn foo() {
+    do_the_math(0);
+}
+"#,
+            r#"
+//- /lib.rs
+mod foo;
+mod num { pub type Num = u32; }
+
 //- /foo.rs
 fn foo() {
     {
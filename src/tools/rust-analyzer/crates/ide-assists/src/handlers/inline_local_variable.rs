@@ -167,7 +167,7 @@ fn inline_let(
     }
 
     let local = sema.to_def(&bind_pat)?;
-    let UsageSearchResult { mut references } = Definition::Local(local).usages(sema).all();
+    let UsageSearchResult { mut references, .. } = Definition::Local(local).usages(sema).all();
     match references.remove(&file_id) {
         Some(references) => Some(InlineData {
             let_stmt,
@@ -214,7 +214,7 @@ fn inline_usage(
 
     let let_stmt = ast::LetStmt::cast(bind_pat.syntax().parent()?)?;
 
-    let UsageSearchResult { mut references } = Definition::Local(local).usages(sema).all();
+    let UsageSearchResult { mut references, .. } = Definition::Local(local).usages(sema).all();
     let mut references = references.remove(&file_id)?;
     let delete_let = references.len() == 1;
     references.retain(|fref| fref.name.as_name_ref() == Some(&name));
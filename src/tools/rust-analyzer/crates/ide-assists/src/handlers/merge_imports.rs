@@ -1,5 +1,8 @@
 use either::Either;
-use ide_db::imports::merge_imports::{try_merge_imports, try_merge_trees, MergeBehavior};
+use ide_db::imports::{
+    insert_use::ImportGranularity,
+    merge_imports::{try_merge_imports, try_merge_trees, MergeBehavior},
+};
 use syntax::{
     algo::neighbor,
     ast::{self, edit_in_place::Removable},
@@ -27,6 +30,11 @@
 // use std::{fmt::Formatter, io};
 // ```
 pub(crate) fn merge_imports(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // Use statements follow the configured granularity, trees in a list always merge by prefix.
+    let use_merge = match ctx.config.imports.granularity {
+        ImportGranularity::One => MergeBehavior::One,
+        _ => MergeBehavior::Crate,
+    };
     let (target, edits) = if ctx.has_empty_selection() {
         // Merge a neighbor
         let tree: ast::UseTree = ctx.find_node_at_offset()?;
@@ -34,10 +42,10 @@ pub(crate) fn merge_imports(acc: &mut Assists, ctx: &AssistContext<'_>) -> Optio
 
         let edits = if let Some(use_item) = tree.syntax().parent().and_then(ast::Use::cast) {
             let mut neighbor = next_prev().find_map(|dir| neighbor(&use_item, dir)).into_iter();
-            use_item.try_merge_from(&mut neighbor)
+            use_item.try_merge_from(&mut neighbor, use_merge)
         } else {
             let mut neighbor = next_prev().find_map(|dir| neighbor(&tree, dir)).into_iter();
-            tree.try_merge_from(&mut neighbor)
+            tree.try_merge_from(&mut neighbor, MergeBehavior::Crate)
         };
         (target, edits?)
     } else {
@@ -54,10 +62,16 @@ pub(crate) fn merge_imports(acc: &mut Assists, ctx: &AssistContext<'_>) -> Optio
         let edits = match_ast! {
             match first_selected {
                 ast::Use(use_item) => {
-                    use_item.try_merge_from(&mut selected_nodes.filter_map(ast::Use::cast))
+                    use_item.try_merge_from(
+                        &mut selected_nodes.filter_map(ast::Use::cast),
+                        use_merge,
+                    )
                 },
                 ast::UseTree(use_tree) => {
-                    use_tree.try_merge_from(&mut selected_nodes.filter_map(ast::UseTree::cast))
+                    use_tree.try_merge_from(
+                        &mut selected_nodes.filter_map(ast::UseTree::cast),
+                        MergeBehavior::Crate,
+                    )
                 },
                 _ => return None,
             }
@@ -89,11 +103,15 @@ pub(crate) fn merge_imports(acc: &mut Assists, ctx: &AssistContext<'_>) -> Optio
 }
 
 trait Merge: AstNode + Clone {
-    fn try_merge_from(self, items: &mut dyn Iterator<Item = Self>) -> Option<Vec<Edit>> {
+    fn try_merge_from(
+        self,
+        items: &mut dyn Iterator<Item = Self>,
+        merge: MergeBehavior,
+    ) -> Option<Vec<Edit>> {
         let mut edits = Vec::new();
         let mut merged = self.clone();
         for item in items {
-            merged = merged.try_merge(&item)?;
+            merged = merged.try_merge(&item, merge)?;
             edits.push(Edit::Remove(item.into_either()));
         }
         if !edits.is_empty() {
@@ -103,13 +121,13 @@ fn try_merge_from(self, items: &mut dyn Iterator<Item = Self>) -> Option<Vec<Edi
             None
         }
     }
-    fn try_merge(&self, other: &Self) -> Option<Self>;
+    fn try_merge(&self, other: &Self, merge: MergeBehavior) -> Option<Self>;
     fn into_either(self) -> Either<ast::Use, ast::UseTree>;
 }
 
 impl Merge for ast::Use {
-    fn try_merge(&self, other: &Self) -> Option<Self> {
-        try_merge_imports(self, other, MergeBehavior::Crate)
+    fn try_merge(&self, other: &Self, merge: MergeBehavior) -> Option<Self> {
+        try_merge_imports(self, other, merge)
     }
     fn into_either(self) -> Either<ast::Use, ast::UseTree> {
         Either::Left(self)
@@ -117,8 +135,8 @@ fn into_either(self) -> Either<ast::Use, ast::UseTree> {
 }
 
 impl Merge for ast::UseTree {
-    fn try_merge(&self, other: &Self) -> Option<Self> {
-        try_merge_trees(self, other, MergeBehavior::Crate)
+    fn try_merge(&self, other: &Self, merge: MergeBehavior) -> Option<Self> {
+        try_merge_trees(self, other, merge)
     }
     fn into_either(self) -> Either<ast::Use, ast::UseTree> {
         Either::Right(self)
@@ -138,7 +156,12 @@ fn replace(old: impl AstNode, new: impl AstNode) -> Self {
 
 #[cfg(test)]
 mod tests {
-    use crate::tests::{check_assist, check_assist_not_applicable};
+    use ide_db::imports::insert_use::ImportPrefs;
+
+    use crate::{
+        tests::{check_assist, check_assist_not_applicable, check_assist_with_config, TEST_CONFIG},
+        AssistConfig,
+    };
 
     use super::*;
 
@@ -184,6 +207,25 @@ fn test_merge_second() {
         );
     }
 
+    #[test]
+    fn merge_one_style() {
+        let config = AssistConfig {
+            imports: ImportPrefs { granularity: ImportGranularity::One, ..TEST_CONFIG.imports },
+            ..TEST_CONFIG
+        };
+        check_assist_with_config(
+            merge_imports,
+            config,
+            r"
+use std::fmt$0::Debug;
+use foo::Bar;
+",
+            r"
+use {std::fmt::Debug, foo::Bar};
+",
+        );
+    }
+
     #[test]
     fn merge_self1() {
         check_assist(
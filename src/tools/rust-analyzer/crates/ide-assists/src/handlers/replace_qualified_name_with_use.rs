@@ -66,13 +66,17 @@ pub(crate) fn replace_qualified_name_with_use(
             ctx.sema.scope(path.syntax())?.module().find_use_path_prefixed(
                 ctx.sema.db,
                 module,
-                ctx.config.insert_use.prefix_kind,
-                ctx.config.prefer_no_std,
+                ctx.config.imports.prefix_kind,
+                ctx.config.imports.prefer_no_std,
             )
         })
         .flatten();
 
-    let scope = ImportScope::find_insert_use_container(path.syntax(), &ctx.sema)?;
+    let scope = ImportScope::find_insert_use_container_with_config(
+        path.syntax(),
+        &ctx.sema,
+        &ctx.config.imports,
+    )?;
     let target = path.syntax().text_range();
     acc.add(
         AssistId("replace_qualified_name_with_use", AssistKind::RefactorRewrite),
@@ -93,7 +97,7 @@ pub(crate) fn replace_qualified_name_with_use(
                 Some(qualifier) => make::path_concat(qualifier, path),
                 None => path,
             };
-            insert_use(&scope, path, &ctx.config.insert_use);
+            insert_use(&scope, path, &ctx.config.imports);
         },
     )
 }
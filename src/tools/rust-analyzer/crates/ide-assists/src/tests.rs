@@ -6,7 +6,7 @@
 use hir::Semantics;
 use ide_db::{
     base_db::{fixture::WithFixture, FileId, FileRange, SourceDatabaseExt},
-    imports::insert_use::{ImportGranularity, InsertUseConfig},
+    imports::insert_use::{ImportGranularity, ImportGroupStyle, ImportLocation, ImportPrefs},
     source_change::FileSystemEdit,
     RootDatabase, SnippetCap,
 };
@@ -22,28 +22,32 @@
 pub(crate) const TEST_CONFIG: AssistConfig = AssistConfig {
     snippet_cap: SnippetCap::new(true),
     allowed: None,
-    insert_use: InsertUseConfig {
+    imports: ImportPrefs {
         granularity: ImportGranularity::Crate,
         prefix_kind: hir::PrefixKind::Plain,
         enforce_granularity: true,
         group: true,
+        group_style: ImportGroupStyle::PathKind,
         skip_glob_imports: true,
+        location: ImportLocation::Module,
+        prefer_no_std: false,
     },
-    prefer_no_std: false,
     assist_emit_must_use: false,
 };
 
 pub(crate) const TEST_CONFIG_NO_SNIPPET_CAP: AssistConfig = AssistConfig {
     snippet_cap: None,
     allowed: None,
-    insert_use: InsertUseConfig {
+    imports: ImportPrefs {
         granularity: ImportGranularity::Crate,
         prefix_kind: hir::PrefixKind::Plain,
         enforce_granularity: true,
         group: true,
+        group_style: ImportGroupStyle::PathKind,
         skip_glob_imports: true,
+        location: ImportLocation::Module,
+        prefer_no_std: false,
     },
-    prefer_no_std: false,
     assist_emit_must_use: false,
 };
 
@@ -73,6 +77,23 @@ pub(crate) fn check_assist_no_snippet_cap(
     );
 }
 
+#[track_caller]
+pub(crate) fn check_assist_with_config(
+    assist: Handler,
+    config: AssistConfig,
+    ra_fixture_before: &str,
+    ra_fixture_after: &str,
+) {
+    let ra_fixture_after = trim_indent(ra_fixture_after);
+    check_with_config(
+        config,
+        assist,
+        ra_fixture_before,
+        ExpectedResult::After(&ra_fixture_after),
+        None,
+    );
+}
+
 // There is no way to choose what assist within a group you want to test against,
 // so this is here to allow you choose.
 pub(crate) fn check_assist_by_label(
@@ -213,6 +234,8 @@ fn check_with_config(
                         // temporary placeholder for MoveDir since we are not using MoveDir in ide assists yet.
                         (dst, format!("{src_id:?}\n{src:?}"))
                     }
+                    // Deleted files are not part of the result.
+                    FileSystemEdit::DeleteFile { .. } => continue,
                 };
                 let sr = db.file_source_root(dst.anchor);
                 let sr = db.source_root(sr);
@@ -490,16 +513,28 @@ pub fn test_some_range(a: int) -> bool {
                                     Indel {
                                         insert: "let $0var_name = 5;\n    ",
                                         delete: 45..45,
+                                        annotation: None,
                                     },
                                     Indel {
                                         insert: "var_name",
                                         delete: 59..60,
+                                        annotation: None,
                                     },
                                 ],
                             },
                         },
                         file_system_edits: [],
                         is_snippet: true,
+                        snippet_edits: {},
+                        other_file_edits: [],
+                        undo_edits: {},
+                        annotations: {},
+                        kind: Some(
+                            Refactor,
+                        ),
+                        origin: Some(
+                            "extract_variable",
+                        ),
                     },
                 ),
                 trigger_signature_help: false,
@@ -549,16 +584,28 @@ pub fn test_some_range(a: int) -> bool {
                                     Indel {
                                         insert: "let $0var_name = 5;\n    ",
                                         delete: 45..45,
+                                        annotation: None,
                                     },
                                     Indel {
                                         insert: "var_name",
                                         delete: 59..60,
+                                        annotation: None,
                                     },
                                 ],
                             },
                         },
                         file_system_edits: [],
                         is_snippet: true,
+                        snippet_edits: {},
+                        other_file_edits: [],
+                        undo_edits: {},
+                        annotations: {},
+                        kind: Some(
+                            Refactor,
+                        ),
+                        origin: Some(
+                            "extract_variable",
+                        ),
                     },
                 ),
                 trigger_signature_help: false,
@@ -586,16 +633,28 @@ pub fn test_some_range(a: int) -> bool {
                                     Indel {
                                         insert: "fun_name()",
                                         delete: 59..60,
+                                        annotation: None,
                                     },
                                     Indel {
                                         insert: "\n\nfn $0fun_name() -> i32 {\n    5\n}",
                                         delete: 110..110,
+                                        annotation: None,
                                     },
                                 ],
                             },
                         },
                         file_system_edits: [],
                         is_snippet: true,
+                        snippet_edits: {},
+                        other_file_edits: [],
+                        undo_edits: {},
+                        annotations: {},
+                        kind: Some(
+                            Refactor,
+                        ),
+                        origin: Some(
+                            "extract_function",
+                        ),
                     },
                 ),
                 trigger_signature_help: false,
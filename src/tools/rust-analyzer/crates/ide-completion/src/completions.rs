@@ -624,7 +624,7 @@ fn enum_variants_with_paths(
         if let Some(path) = ctx.module.find_use_path(
             ctx.db,
             hir::ModuleDef::from(variant),
-            ctx.config.prefer_no_std,
+            ctx.config.imports.prefer_no_std,
         ) {
             // Variants with trivial paths are already added by the existing completion logic,
             // so we should avoid adding these twice
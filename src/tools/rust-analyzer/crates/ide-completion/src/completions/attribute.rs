@@ -4,7 +4,7 @@
 
 use ide_db::{
     generated::lints::{
-        Lint, CLIPPY_LINTS, CLIPPY_LINT_GROUPS, DEFAULT_LINTS, FEATURES, RUSTDOC_LINTS,
+        FeatureStatus, CLIPPY_LINTS, CLIPPY_LINT_GROUPS, DEFAULT_LINTS, FEATURES, RUSTDOC_LINTS,
     },
     syntax_helpers::node_ext::parse_tt_as_comma_sep_paths,
     FxHashMap, SymbolKind,
@@ -49,21 +49,25 @@ pub(crate) fn complete_known_attribute_input(
     match path.text().as_str() {
         "repr" => repr::complete_repr(acc, ctx, tt),
         "feature" => {
-            lint::complete_lint(acc, ctx, colon_prefix, &parse_tt_as_comma_sep_paths(tt)?, FEATURES)
+            // Accepted and removed features can't be enabled anymore.
+            let features = FEATURES
+                .iter()
+                .filter(|it| it.status == FeatureStatus::Unstable)
+                .map(|it| (it.label, it.description));
+            lint::complete_lint(acc, ctx, colon_prefix, &parse_tt_as_comma_sep_paths(tt)?, features)
         }
         "allow" | "warn" | "deny" | "forbid" => {
             let existing_lints = parse_tt_as_comma_sep_paths(tt)?;
 
-            let lints: Vec<Lint> = CLIPPY_LINT_GROUPS
+            let lints = CLIPPY_LINT_GROUPS
                 .iter()
                 .map(|g| &g.lint)
                 .chain(DEFAULT_LINTS)
                 .chain(CLIPPY_LINTS)
                 .chain(RUSTDOC_LINTS)
-                .cloned()
-                .collect();
+                .map(|it| (it.label, it.description));
 
-            lint::complete_lint(acc, ctx, colon_prefix, &existing_lints, &lints);
+            lint::complete_lint(acc, ctx, colon_prefix, &existing_lints, lints);
         }
         "cfg" => cfg::complete_cfg(acc, ctx),
         _ => (),
@@ -1,5 +1,5 @@
 //! Completion for lints
-use ide_db::{generated::lints::Lint, SymbolKind};
+use ide_db::SymbolKind;
 use syntax::ast;
 
 use crate::{context::CompletionContext, item::CompletionItem, Completions};
@@ -9,9 +9,9 @@ pub(super) fn complete_lint(
     ctx: &CompletionContext<'_>,
     is_qualified: bool,
     existing_lints: &[ast::Path],
-    lints_completions: &[Lint],
+    lints_completions: impl IntoIterator<Item = (&'static str, &'static str)>,
 ) {
-    for &Lint { label, description } in lints_completions {
+    for (label, description) in lints_completions {
         let (qual, name) = {
             // FIXME: change `Lint`'s label to not store a path in it but split the prefix off instead?
             let mut parts = label.split("::");
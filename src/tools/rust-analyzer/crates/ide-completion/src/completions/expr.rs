@@ -174,7 +174,7 @@ pub(crate) fn complete_expr_path(
                             .find_use_path(
                                 ctx.db,
                                 hir::ModuleDef::from(strukt),
-                                ctx.config.prefer_no_std,
+                                ctx.config.imports.prefer_no_std,
                             )
                             .filter(|it| it.len() > 1);
 
@@ -196,7 +196,7 @@ pub(crate) fn complete_expr_path(
                             .find_use_path(
                                 ctx.db,
                                 hir::ModuleDef::from(un),
-                                ctx.config.prefer_no_std,
+                                ctx.config.imports.prefer_no_std,
                             )
                             .filter(|it| it.len() > 1);
 
@@ -1,4 +1,6 @@
 //! See [`import_on_the_fly`].
+use std::cmp::Reverse;
+
 use hir::{ItemInNs, ModuleDef};
 use ide_db::imports::{
     import_assets::{ImportAssets, LocatedImport},
@@ -258,7 +260,7 @@ fn import_on_the_fly(
     let user_input_lowercased = potential_import_name.to_lowercase();
 
     import_assets
-        .search_for_imports(&ctx.sema, ctx.config.insert_use.prefix_kind, ctx.config.prefer_no_std)
+        .search_for_imports(&ctx.sema, &ctx.config.imports)
         .into_iter()
         .filter(ns_filter)
         .filter(|import| {
@@ -300,7 +302,7 @@ fn import_on_the_fly_pat_(
     let user_input_lowercased = potential_import_name.to_lowercase();
 
     import_assets
-        .search_for_imports(&ctx.sema, ctx.config.insert_use.prefix_kind, ctx.config.prefer_no_std)
+        .search_for_imports(&ctx.sema, &ctx.config.imports)
         .into_iter()
         .filter(ns_filter)
         .filter(|import| {
@@ -337,14 +339,20 @@ fn import_on_the_fly_method(
     let user_input_lowercased = potential_import_name.to_lowercase();
 
     import_assets
-        .search_for_imports(&ctx.sema, ctx.config.insert_use.prefix_kind, ctx.config.prefer_no_std)
+        .search_for_imports(&ctx.sema, &ctx.config.imports)
         .into_iter()
         .filter(|import| {
             !ctx.is_item_hidden(&import.item_to_import)
                 && !ctx.is_item_hidden(&import.original_item)
         })
         .sorted_by_key(|located_import| {
-            compute_fuzzy_completion_order_key(&located_import.import_path, &user_input_lowercased)
+            (
+                compute_fuzzy_completion_order_key(
+                    &located_import.import_path,
+                    &user_input_lowercased,
+                ),
+                Reverse(import_assets.trait_import_score(ctx.db, located_import)),
+            )
         })
         .for_each(|import| match import.original_item {
             ItemInNs::Values(hir::ModuleDef::Function(f)) => {
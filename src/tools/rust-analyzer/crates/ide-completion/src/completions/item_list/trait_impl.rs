@@ -1259,7 +1259,6 @@ fn method(&mut self,params: <ty!()as SomeTrait>::Output) {
         );
     }
 
-    // FIXME: `T` in `ty!(T)` should be replaced by `PathTransform`.
     #[test]
     fn macro_generated_assoc_item2() {
         check_edit(
@@ -1289,7 +1288,7 @@ macro_rules! define_method {
 }
 trait AnotherTrait<T: SomeTrait> { define_method!(T); }
 impl AnotherTrait<i32> for () {
-    fn method(&mut self,params: <ty!(T)as SomeTrait>::Output) {
+    fn method(&mut self,params: <ty!(i32)as SomeTrait>::Output) {
     $0
 }
 }
@@ -2,8 +2,14 @@
 
 mod format_like;
 
-use hir::{Documentation, HasAttrs};
-use ide_db::{imports::insert_use::ImportScope, ty_filter::TryEnum, SnippetCap};
+use hir::{Documentation, HasAttrs, StructKind};
+use ide_db::{
+    imports::insert_use::ImportScope,
+    ty_filter::{EnumPatterns, TryEnum},
+    SnippetCap,
+};
+use itertools::Itertools;
+use stdx::format_to;
 use syntax::{
     ast::{self, make, AstNode, AstToken},
     SyntaxKind::{BLOCK_EXPR, EXPR_STMT, FOR_EXPR, IF_EXPR, LOOP_EXPR, STMT_LIST, WHILE_EXPR},
@@ -70,7 +76,7 @@ pub(crate) fn complete_postfix(
     }
 
     let try_enum = TryEnum::from_ty(&ctx.sema, &receiver_ty.strip_references());
-    if let Some(try_enum) = &try_enum {
+    if let Some(try_enum) = try_enum {
         match try_enum {
             TryEnum::Result => {
                 postfix_snippet(
@@ -154,28 +160,21 @@ pub(crate) fn complete_postfix(
         add_custom_postfix_completions(acc, ctx, &postfix_snippet, &receiver_text);
     }
 
-    match try_enum {
-        Some(try_enum) => match try_enum {
-            TryEnum::Result => {
-                postfix_snippet(
-                    "match",
-                    "match expr {}",
-                    &format!("match {receiver_text} {{\n    Ok(${{1:_}}) => {{$2}},\n    Err(${{3:_}}) => {{$0}},\n}}"),
-                )
-                .add_to(acc, ctx.db);
-            }
-            TryEnum::Option => {
-                postfix_snippet(
-                    "match",
-                    "match expr {}",
-                    &format!(
-                        "match {receiver_text} {{\n    Some(${{1:_}}) => {{$2}},\n    None => {{$0}},\n}}"
-                    ),
-                )
-                .add_to(acc, ctx.db);
-            }
-        },
-        None => {
+    let enum_patterns = EnumPatterns::from_ty(
+        &ctx.scope,
+        &receiver_ty.strip_references(),
+        ctx.config.imports.prefer_no_std,
+    );
+    match enum_patterns {
+        Some(patterns) if !patterns.variants.is_empty() => {
+            postfix_snippet(
+                "match",
+                "match expr {}",
+                &format!("match {receiver_text} {{\n{}}}", match_arms_snippet(&patterns)),
+            )
+            .add_to(acc, ctx.db);
+        }
+        _ => {
             postfix_snippet(
                 "match",
                 "match expr {}",
@@ -208,6 +207,48 @@ pub(crate) fn complete_postfix(
     }
 }
 
+/// The arms of a `match` on an enum, with placeholders for the bindings of tuple fields and the
+/// bodies of the arms.
+fn match_arms_snippet(patterns: &EnumPatterns) -> String {
+    let arm_count = patterns.variants.len() + usize::from(patterns.needs_wildcard);
+    let mut placeholder = 0;
+    let mut next_placeholder = || {
+        placeholder += 1;
+        placeholder
+    };
+    let mut res = String::new();
+    let patterns =
+        patterns.variants.iter().map(Some).chain(patterns.needs_wildcard.then_some(None));
+    for (idx, variant) in patterns.enumerate() {
+        // The placeholders are numbered arm by arm, so the bindings of an arm come right before
+        // its body.
+        let arm = match variant {
+            Some(variant) => {
+                let path = &variant.path;
+                match variant.kind {
+                    StructKind::Tuple => {
+                        let fields = variant
+                            .bindings
+                            .iter()
+                            .map(|it| format!("${{{}:{it}}}", next_placeholder()))
+                            .join(", ");
+                        format!("{path}({fields})")
+                    }
+                    StructKind::Record => {
+                        format!("{path} {{ {} }}", variant.bindings.iter().join(", "))
+                    }
+                    StructKind::Unit => path.to_string(),
+                }
+            }
+            None => "_".to_owned(),
+        };
+        let body =
+            if idx + 1 == arm_count { "$0".to_owned() } else { format!("${}", next_placeholder()) };
+        format_to!(res, "    {arm} => {{{body}}},\n");
+    }
+    res
+}
+
 fn get_receiver_text(receiver: &ast::Expr, receiver_is_ambiguous_float_literal: bool) -> String {
     let text = if receiver_is_ambiguous_float_literal {
         let text = receiver.syntax().text();
@@ -482,8 +523,69 @@ fn main() {
 fn main() {
     let bar = Ok(true);
     match bar {
-    Ok(${1:_}) => {$2},
-    Err(${3:_}) => {$0},
+    Ok(${1:value}) => {$2},
+    Err(${3:err}) => {$0},
+}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn enum_match() {
+        check_edit(
+            "match",
+            r#"
+enum Shape {
+    Circle(f64),
+    Rect { w: f64, h: f64 },
+    Empty,
+}
+fn main() {
+    let shape = Shape::Empty;
+    shape.$0
+}
+"#,
+            r#"
+enum Shape {
+    Circle(f64),
+    Rect { w: f64, h: f64 },
+    Empty,
+}
+fn main() {
+    let shape = Shape::Empty;
+    match shape {
+    Shape::Circle(${1:circle}) => {$2},
+    Shape::Rect { w, h } => {$3},
+    Shape::Empty => {$0},
+}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn non_exhaustive_enum_match() {
+        check_edit(
+            "match",
+            r#"
+//- /main.rs crate:main deps:dep
+fn main(poll: dep::Poll) {
+    poll.$0
+}
+//- /dep.rs crate:dep
+#[non_exhaustive]
+pub enum Poll {
+    Ready(u32),
+    Pending,
+}
+"#,
+            r#"
+fn main(poll: dep::Poll) {
+    match poll {
+    dep::Poll::Ready(${1:value}) => {$2},
+    dep::Poll::Pending => {$3},
+    _ => {$0},
 }
 }
 "#,
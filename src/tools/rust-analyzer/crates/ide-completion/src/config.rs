@@ -4,7 +4,7 @@
 //! module, and we use to statically check that we only produce snippet
 //! completions if we are allowed to.
 
-use ide_db::{imports::insert_use::InsertUseConfig, SnippetCap};
+use ide_db::{imports::insert_use::ImportPrefs, SnippetCap};
 
 use crate::snippet::Snippet;
 
@@ -16,8 +16,7 @@ pub struct CompletionConfig {
     pub enable_private_editable: bool,
     pub callable: Option<CallableSnippets>,
     pub snippet_cap: Option<SnippetCap>,
-    pub insert_use: InsertUseConfig,
-    pub prefer_no_std: bool,
+    pub imports: ImportPrefs,
     pub snippets: Vec<Snippet>,
     pub limit: Option<usize>,
 }
@@ -219,7 +219,11 @@ pub fn resolve_completion_edits(
     let original_token =
         syntax::AstNode::syntax(&original_file).token_at_offset(offset).left_biased()?;
     let position_for_import = &original_token.parent()?;
-    let scope = ImportScope::find_insert_use_container(position_for_import, &sema)?;
+    let scope = ImportScope::find_insert_use_container_with_config(
+        position_for_import,
+        &sema,
+        &config.imports,
+    )?;
 
     let current_module = sema.scope(position_for_import)?.module();
     let current_crate = current_module.krate();
@@ -239,13 +243,13 @@ pub fn resolve_completion_edits(
                 current_module.find_use_path_prefixed(
                     db,
                     candidate,
-                    config.insert_use.prefix_kind,
-                    config.prefer_no_std,
+                    config.imports.prefix_kind,
+                    config.imports.prefer_no_std,
                 )
             })
             .find(|mod_path| mod_path.display(db).to_string() == full_import_path);
         if let Some(import_path) = import {
-            insert_use::insert_use(&new_ast, mod_path_to_ast(&import_path), &config.insert_use);
+            insert_use::insert_use(&new_ast, mod_path_to_ast(&import_path), &config.imports);
         }
     });
 
@@ -177,8 +177,8 @@ fn import_edits(ctx: &CompletionContext<'_>, requires: &[GreenNode]) -> Option<V
         let path = ctx.module.find_use_path_prefixed(
             ctx.db,
             item,
-            ctx.config.insert_use.prefix_kind,
-            ctx.config.prefer_no_std,
+            ctx.config.imports.prefix_kind,
+            ctx.config.imports.prefer_no_std,
         )?;
         Some((path.len() > 1).then(|| LocatedImport::new(path.clone(), item, item, None)))
     };
@@ -27,7 +27,7 @@
 use hir::PrefixKind;
 use ide_db::{
     base_db::{fixture::ChangeFixture, FileLoader, FilePosition},
-    imports::insert_use::{ImportGranularity, InsertUseConfig},
+    imports::insert_use::{ImportGranularity, ImportGroupStyle, ImportLocation, ImportPrefs},
     RootDatabase, SnippetCap,
 };
 use itertools::Itertools;
@@ -66,13 +66,15 @@ union Union { field: i32 }
     enable_private_editable: false,
     callable: Some(CallableSnippets::FillArguments),
     snippet_cap: SnippetCap::new(true),
-    prefer_no_std: false,
-    insert_use: InsertUseConfig {
+    imports: ImportPrefs {
         granularity: ImportGranularity::Crate,
         prefix_kind: PrefixKind::Plain,
         enforce_granularity: true,
         group: true,
+        group_style: ImportGroupStyle::PathKind,
         skip_glob_imports: true,
+        location: ImportLocation::Module,
+        prefer_no_std: false,
     },
     snippets: Vec::new(),
     limit: None,
@@ -581,7 +581,7 @@ fn main() {
 }"#;
     let mut config = TEST_CONFIG;
 
-    config.insert_use.prefix_kind = hir::PrefixKind::ByCrate;
+    config.imports.prefix_kind = hir::PrefixKind::ByCrate;
     check_edit_with_config(
         config.clone(),
         "Item",
@@ -600,7 +600,7 @@ fn main() {
 }"#,
     );
 
-    config.insert_use.prefix_kind = hir::PrefixKind::BySelf;
+    config.imports.prefix_kind = hir::PrefixKind::BySelf;
     check_edit_with_config(
         config.clone(),
         "Item",
@@ -621,7 +621,7 @@ fn main() {
 }"#,
     );
 
-    config.insert_use.prefix_kind = hir::PrefixKind::Plain;
+    config.imports.prefix_kind = hir::PrefixKind::Plain;
     check_edit_with_config(
         config,
         "Item",
@@ -9,7 +9,10 @@
 
 use syntax::TextRange;
 
-use crate::{label::Label, source_change::SourceChange};
+use crate::{
+    label::Label,
+    source_change::{ChangeKind, SourceChange},
+};
 
 #[derive(Debug, Clone)]
 pub struct Assist {
@@ -63,6 +66,19 @@ pub fn contains(self, other: AssistKind) -> bool {
         }
     }
 
+    /// The kind of the changes made by assists of this kind.
+    pub fn change_kind(self) -> ChangeKind {
+        match self {
+            AssistKind::QuickFix => ChangeKind::QuickFix,
+            AssistKind::Generate => ChangeKind::Generated,
+            AssistKind::None
+            | AssistKind::Refactor
+            | AssistKind::RefactorExtract
+            | AssistKind::RefactorInline
+            | AssistKind::RefactorRewrite => ChangeKind::Refactor,
+        }
+    }
+
     pub fn name(&self) -> &str {
         match self {
             AssistKind::None => "None",
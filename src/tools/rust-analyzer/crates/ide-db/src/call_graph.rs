@@ -0,0 +1,250 @@
+//! Call-graph reachability: finds the calls that can be reached from a function by following
+//! its callees transitively.
+//!
+//! Callees are resolved from the bodies of the functions, so calls through function pointers,
+//! closures that are passed around or trait objects are not followed.
+
+use std::collections::VecDeque;
+
+use base_db::FileRange;
+use hir::{Function, Macro, Semantics};
+use rustc_hash::{FxHashMap, FxHashSet};
+use syntax::{ast, match_ast, AstNode, SyntaxKind, SyntaxNode, WalkEvent};
+
+use crate::RootDatabase;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Callee {
+    Function(Function),
+    Macro(Macro),
+}
+
+#[derive(Debug, Clone)]
+pub struct ReachableCall {
+    /// The range of the call expression in the original file.
+    pub range: FileRange,
+    pub callee: Callee,
+    /// The functions leading to the call, from the root of the search up to and including the
+    /// function containing the call.
+    pub call_chain: Vec<Function>,
+}
+
+pub struct ReachableCalls<'a> {
+    sema: &'a Semantics<'a, RootDatabase>,
+    root: Function,
+    /// how many calls deep to follow the callees, `None` for no limit
+    max_depth: Option<usize>,
+    /// whether functions of crates outside of the workspace are walked
+    include_dependencies: bool,
+}
+
+impl<'a> ReachableCalls<'a> {
+    pub fn new(sema: &'a Semantics<'a, RootDatabase>, root: Function) -> ReachableCalls<'a> {
+        ReachableCalls { sema, root, max_depth: None, include_dependencies: false }
+    }
+
+    /// Only follow callees up to `depth` calls deep, a depth of `0` only looks at the calls in
+    /// the root function.
+    pub fn with_max_depth(mut self, depth: usize) -> ReachableCalls<'a> {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Also walk the functions of crates that are not workspace members, like the standard
+    /// library. Calls to these functions are always reported, this only controls whether their
+    /// bodies are searched as well.
+    pub fn include_dependencies(mut self) -> ReachableCalls<'a> {
+        self.include_dependencies = true;
+        self
+    }
+
+    /// Finds the reachable calls whose callee matches `predicate`.
+    ///
+    /// Every function is walked at most once, so a call is reported with the shortest call
+    /// chain leading to it.
+    pub fn find(self, mut predicate: impl FnMut(&Callee) -> bool) -> Vec<ReachableCall> {
+        let _p = profile::span("ReachableCalls::find");
+        let db = self.sema.db;
+        let mut res = Vec::new();
+        let mut callers: FxHashMap<Function, Function> = FxHashMap::default();
+        let mut visited = FxHashSet::default();
+        let mut queue = VecDeque::from([(self.root, 0)]);
+        visited.insert(self.root);
+
+        while let Some((func, depth)) = queue.pop_front() {
+            for (range, callee) in self.callees(func) {
+                if predicate(&callee) {
+                    res.push(ReachableCall {
+                        range,
+                        callee,
+                        call_chain: call_chain(&callers, func),
+                    });
+                }
+                let Callee::Function(callee) = callee else { continue };
+                if self.max_depth.map_or(false, |max| depth >= max) || visited.contains(&callee) {
+                    continue;
+                }
+                if !self.include_dependencies && !callee.module(db).krate().origin(db).is_local() {
+                    continue;
+                }
+                visited.insert(callee);
+                callers.insert(callee, func);
+                queue.push_back((callee, depth + 1));
+            }
+        }
+        res
+    }
+
+    fn callees(&self, func: Function) -> Vec<(FileRange, Callee)> {
+        body_calls(self.sema, func)
+    }
+}
+
+/// The calls in the body of `func`, including the ones in macro expansions, with the ranges of
+/// the call expressions in the original file.
+pub(crate) fn body_calls(
+    sema: &Semantics<'_, RootDatabase>,
+    func: Function,
+) -> Vec<(FileRange, Callee)> {
+    let mut res = Vec::new();
+    let Some(body) = sema.source(func).and_then(|it| it.value.body()) else { return res };
+
+    let mut stack = vec![body.syntax().clone()];
+    while let Some(root) = stack.pop() {
+        let mut preorder = root.preorder();
+        while let Some(event) = preorder.next() {
+            let WalkEvent::Enter(node) = event else { continue };
+            // Nested items are not part of the body, except for macro calls in statement
+            // position which are also items.
+            if node != root
+                && ast::Item::can_cast(node.kind())
+                && node.kind() != SyntaxKind::MACRO_CALL
+            {
+                preorder.skip_subtree();
+                continue;
+            }
+            if let Some(macro_call) = ast::MacroCall::cast(node.clone()) {
+                if let Some(expansion) = sema.expand(&macro_call) {
+                    stack.push(expansion);
+                }
+            }
+            if let Some(callee) = resolve_callee(sema, &node) {
+                res.push((sema.original_range(&node), callee));
+            }
+        }
+    }
+    res
+}
+
+fn resolve_callee(sema: &Semantics<'_, RootDatabase>, node: &SyntaxNode) -> Option<Callee> {
+    match_ast! {
+        match node {
+            ast::CallExpr(it) => {
+                let callable = sema.type_of_expr(&it.expr()?)?.original.as_callable(sema.db)?;
+                match callable.kind() {
+                    hir::CallableKind::Function(it) => Some(Callee::Function(it)),
+                    _ => None,
+                }
+            },
+            ast::MethodCallExpr(it) => sema.resolve_method_call(&it).map(Callee::Function),
+            ast::MacroCall(it) => sema.resolve_macro_call(&it).map(Callee::Macro),
+            _ => None,
+        }
+    }
+}
+
+fn call_chain(callers: &FxHashMap<Function, Function>, func: Function) -> Vec<Function> {
+    let mut chain: Vec<_> =
+        std::iter::successors(Some(func), |it| callers.get(it).copied()).collect();
+    chain.reverse();
+    chain
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::{fixture::WithFixture, SourceDatabaseExt};
+    use expect_test::{expect, Expect};
+    use hir::Semantics;
+
+    use super::*;
+
+    fn check(ra_fixture: &str, max_depth: Option<usize>, expect: Expect) {
+        let (db, position) = RootDatabase::with_position(ra_fixture);
+        let sema = Semantics::new(&db);
+        let file = sema.parse(position.file_id);
+        let func: ast::Fn =
+            sema.find_node_at_offset_with_descend(file.syntax(), position.offset).unwrap();
+        let mut search = ReachableCalls::new(&sema, sema.to_def(&func).unwrap());
+        if let Some(depth) = max_depth {
+            search = search.with_max_depth(depth);
+        }
+        let calls = search.find(|callee| match callee {
+            Callee::Function(it) => it.name(&db).to_smol_str() == "unwrap",
+            Callee::Macro(it) => it.name(&db).to_smol_str() == "panic",
+        });
+        let actual = calls
+            .into_iter()
+            .map(|it| {
+                let text = db.file_text(it.range.file_id);
+                let chain = it
+                    .call_chain
+                    .iter()
+                    .map(|it| it.name(&db).to_smol_str())
+                    .collect::<Vec<_>>()
+                    .join(" -> ");
+                format!("{chain}: {}\n", &text[it.range.range])
+            })
+            .collect::<String>();
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn reachable_panics() {
+        check(
+            r#"
+//- minicore: option, panic
+fn root$0() {
+    helper();
+    let x: Option<u32> = None;
+    x.unwrap();
+}
+fn helper() {
+    fn not_called() { panic!() }
+    let _ = Some(nested());
+}
+fn nested() -> u32 {
+    if false {
+        root();
+    }
+    panic!("oops")
+}
+fn unreachable() { panic!() }
+"#,
+            None,
+            expect![[r#"
+                root: x.unwrap()
+                root -> helper -> nested: panic!("oops")
+            "#]],
+        );
+    }
+
+    #[test]
+    fn max_depth() {
+        check(
+            r#"
+//- minicore: option, panic
+fn root$0() {
+    helper();
+    None::<u32>.unwrap();
+}
+fn helper() {
+    panic!()
+}
+"#,
+            Some(0),
+            expect![[r#"
+                root: None::<u32>.unwrap()
+            "#]],
+        );
+    }
+}
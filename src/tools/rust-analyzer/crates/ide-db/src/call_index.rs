@@ -0,0 +1,161 @@
+//! An index of the calls between functions, for call hierarchies.
+//!
+//! Finding the callers of a function with a usage search looks at every file mentioning its name,
+//! which is slow for functions called all over the workspace, and misses calls whose callee is
+//! only known after method resolution or macro expansion. Instead, the calls in the body of every
+//! function are collected by a query keyed by the function, and the callers of the functions of a
+//! crate are gathered from the calls of all the functions of each crate by another one. An edit
+//! only recomputes the calls of the functions of the edited file, and the crate queries merely
+//! regroup the calls.
+
+use base_db::{salsa, CrateId, FileRange, SourceDatabase, Upcast};
+use hir::{db::HirDatabase, AssocItem, Crate, Function, ModuleDef, Semantics};
+use triomphe::Arc;
+
+use crate::{
+    call_graph::{body_calls, Callee},
+    parallel::par_flat_map,
+    FxHashMap, RootDatabase,
+};
+
+/// A call from `caller` to `callee`, including calls through method dispatch and calls in macro
+/// expansions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Call {
+    pub caller: Function,
+    pub callee: Function,
+    /// The range of the call expression in the original file.
+    pub range: FileRange,
+}
+
+#[salsa::query_group(CallIndexDatabaseStorage)]
+pub trait CallIndexDatabase: HirDatabase + Upcast<RootDatabase> {
+    /// The calls in the body of the given function, in the order they appear in.
+    fn outgoing_calls(&self, func: Function) -> Arc<[Call]>;
+
+    /// The calls made by the functions of the given crate, grouped by callee.
+    fn crate_calls_by_callee(&self, krate: CrateId) -> Arc<FxHashMap<Function, Vec<Call>>>;
+}
+
+fn outgoing_calls(db: &dyn CallIndexDatabase, func: Function) -> Arc<[Call]> {
+    let _p = profile::span("outgoing_calls");
+    let db: &RootDatabase = db.upcast();
+    let sema = Semantics::new(db);
+    body_calls(&sema, func)
+        .into_iter()
+        .filter_map(|(range, callee)| match callee {
+            Callee::Function(callee) => Some(Call { caller: func, callee, range }),
+            Callee::Macro(_) => None,
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
+fn crate_calls_by_callee(
+    db: &dyn CallIndexDatabase,
+    krate: CrateId,
+) -> Arc<FxHashMap<Function, Vec<Call>>> {
+    let _p = profile::span("crate_calls_by_callee");
+    let root_db: &RootDatabase = db.upcast();
+    let mut res: FxHashMap<Function, Vec<Call>> = FxHashMap::default();
+    for func in crate_functions(root_db, Crate::from(krate)) {
+        for call in db.outgoing_calls(func).iter() {
+            res.entry(call.callee).or_default().push(call.clone());
+        }
+    }
+    Arc::new(res)
+}
+
+/// The calls to `func` from the functions of its crate and of the workspace crates depending on
+/// it, ordered by crate.
+pub fn incoming_calls(db: &RootDatabase, func: Function) -> Vec<Call> {
+    let _p = profile::span("incoming_calls");
+    let krate = func.module(db).krate().into();
+    let crate_graph = db.crate_graph();
+    let mut crates: Vec<CrateId> = crate_graph
+        .transitive_rev_deps(krate)
+        .filter(|&it| it == krate || crate_graph[it].origin.is_local())
+        .collect();
+    crates.sort();
+    par_flat_map(db, crates, |db, krate| {
+        db.crate_calls_by_callee(krate).get(&func).cloned().unwrap_or_default()
+    })
+}
+
+/// The functions of `krate` with a body of their own: free functions and the functions of traits
+/// and impls. Functions nested in bodies are part of the calls of their parents.
+fn crate_functions(db: &dyn HirDatabase, krate: Crate) -> Vec<Function> {
+    let as_function = |item: AssocItem| match item {
+        AssocItem::Function(it) => Some(it),
+        _ => None,
+    };
+    let mut res = Vec::new();
+    for module in krate.modules(db) {
+        for def in module.declarations(db) {
+            match def {
+                ModuleDef::Function(it) => res.push(it),
+                ModuleDef::Trait(it) => {
+                    res.extend(it.items(db).into_iter().filter_map(as_function))
+                }
+                _ => (),
+            }
+        }
+        for impl_ in module.impl_defs(db) {
+            res.extend(impl_.items(db).into_iter().filter_map(as_function));
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::{fixture::WithFixture, Change, FileId};
+
+    use super::*;
+
+    fn function(db: &RootDatabase, file_id: FileId, name: &str) -> Function {
+        let sema = Semantics::new(db);
+        let module = sema.to_module_def(file_id).unwrap();
+        crate_functions(db, module.krate())
+            .into_iter()
+            .find(|it| it.name(db).to_smol_str() == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn indexes_method_calls_and_calls_in_macros() {
+        let (mut db, file_id) = RootDatabase::with_single_file(
+            r#"
+macro_rules! call { ($f:ident) => { $f() }; }
+struct S;
+impl S { fn method(&self) { helper() } }
+fn helper() {}
+fn caller() { S.method(); call!(helper); }
+"#,
+        );
+        let caller = function(&db, file_id, "caller");
+        let helper = function(&db, file_id, "helper");
+        let method = function(&db, file_id, "method");
+
+        let callees: Vec<_> = db.outgoing_calls(caller).iter().map(|it| it.callee).collect();
+        assert_eq!(callees, [method, helper]);
+        let callers: Vec<_> = incoming_calls(&db, helper).into_iter().map(|it| it.caller).collect();
+        assert_eq!(callers, [caller, method]);
+
+        let mut change = Change::new();
+        change.change_file(
+            file_id,
+            Some(Arc::from(
+                r#"
+struct S;
+impl S { fn method(&self) {} }
+fn helper() {}
+fn caller() { S.method(); }
+"#,
+            )),
+        );
+        db.apply_change(change);
+        let helper = function(&db, file_id, "helper");
+        assert!(incoming_calls(&db, helper).is_empty());
+    }
+}
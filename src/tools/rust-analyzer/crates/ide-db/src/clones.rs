@@ -0,0 +1,280 @@
+//! Finds structurally similar code in the workspace, like copy-pasted functions.
+//!
+//! The bodies of functions, constants and statics are reduced to their sequence of tokens, without
+//! trivia and, depending on the configuration, with all identifiers or literals replaced by their
+//! kind, so that renamed copies look the same. Every run of a few consecutive tokens is hashed, and
+//! the similarity of two bodies is the share of the hashes they have in common.
+
+use std::hash::{Hash, Hasher};
+
+use base_db::{FileId, FileRange, SourceDatabase};
+use hir::{AssocItem, Crate, HasSource, ModuleDef};
+use syntax::{ast, AstNode, SyntaxKind, SyntaxNode, TextSize};
+
+use crate::{defs::Definition, parallel::par_flat_map, FxHasher, RootDatabase};
+
+/// The number of consecutive tokens hashed together.
+const SHINGLE_LEN: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CloneDetectionConfig {
+    /// Whether names are ignored, so that code differing only by the names it uses is the same.
+    pub ignore_identifiers: bool,
+    /// Whether the values of literals are ignored.
+    pub ignore_literals: bool,
+    /// The number of tokens a body needs to have to be compared to the other ones.
+    pub min_tokens: usize,
+    /// The similarity, between 0 and 1, from which two bodies are reported.
+    pub min_similarity: f64,
+}
+
+impl Default for CloneDetectionConfig {
+    fn default() -> Self {
+        CloneDetectionConfig {
+            ignore_identifiers: true,
+            ignore_literals: true,
+            min_tokens: 30,
+            min_similarity: 0.8,
+        }
+    }
+}
+
+/// The body of a definition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CodeFragment {
+    pub definition: Definition,
+    pub range: FileRange,
+}
+
+/// Two similar fragments of code.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimilarCode {
+    pub first: CodeFragment,
+    pub second: CodeFragment,
+    /// The share, between 0 and 1, of the runs of tokens of the fragments they have in common.
+    /// Fragments whose tokens are the same once normalized have a similarity of 1.
+    pub similarity: f64,
+}
+
+struct Fingerprint {
+    fragment: CodeFragment,
+    /// The hashes of the runs of tokens, sorted and deduplicated.
+    shingles: Vec<u64>,
+}
+
+/// Finds the similar bodies of the workspace crates, most similar first.
+pub fn find_similar_code(db: &RootDatabase, config: &CloneDetectionConfig) -> Vec<SimilarCode> {
+    let _p = profile::span("find_similar_code");
+    let crate_graph = db.crate_graph();
+    let definitions: Vec<Definition> = Crate::all(db)
+        .into_iter()
+        .filter(|krate| crate_graph[(*krate).into()].origin.is_local())
+        .flat_map(|krate| candidates(db, krate))
+        .collect();
+    let mut fingerprints: Vec<Fingerprint> =
+        par_flat_map(db, definitions, |db, def| fingerprint(db, def, config));
+    // Two sets of hashes can only be similar enough if their sizes are too, so each one is only
+    // compared to the next, bigger ones, until they get too big.
+    fingerprints.sort_by_key(|it| it.shingles.len());
+
+    let mut res = Vec::new();
+    for (idx, first) in fingerprints.iter().enumerate() {
+        for second in &fingerprints[idx + 1..] {
+            let max_similarity = first.shingles.len() as f64 / second.shingles.len() as f64;
+            if max_similarity < config.min_similarity {
+                break;
+            }
+            let similarity = jaccard(&first.shingles, &second.shingles);
+            if similarity >= config.min_similarity {
+                let (first, second) = if position(&first.fragment) < position(&second.fragment) {
+                    (first.fragment, second.fragment)
+                } else {
+                    (second.fragment, first.fragment)
+                };
+                res.push(SimilarCode { first, second, similarity });
+            }
+        }
+    }
+    res.sort_by(|a, b| {
+        b.similarity.total_cmp(&a.similarity).then_with(|| {
+            (position(&a.first), position(&a.second))
+                .cmp(&(position(&b.first), position(&b.second)))
+        })
+    });
+    res
+}
+
+fn position(fragment: &CodeFragment) -> (FileId, TextSize) {
+    (fragment.range.file_id, fragment.range.range.start())
+}
+
+/// The functions, constants and statics of `krate`, including the ones of impls and the default
+/// functions of traits.
+fn candidates(db: &RootDatabase, krate: Crate) -> Vec<Definition> {
+    let assoc_item = |item: AssocItem| match item {
+        AssocItem::Function(it) => Some(Definition::Function(it)),
+        AssocItem::Const(it) => Some(Definition::Const(it)),
+        AssocItem::TypeAlias(_) => None,
+    };
+    let mut res = Vec::new();
+    for module in krate.modules(db) {
+        for def in module.declarations(db) {
+            match def {
+                ModuleDef::Function(it) => res.push(Definition::Function(it)),
+                ModuleDef::Const(it) => res.push(Definition::Const(it)),
+                ModuleDef::Static(it) => res.push(Definition::Static(it)),
+                ModuleDef::Trait(it) => res.extend(it.items(db).into_iter().filter_map(assoc_item)),
+                _ => (),
+            }
+        }
+        for impl_ in module.impl_defs(db) {
+            res.extend(impl_.items(db).into_iter().filter_map(assoc_item));
+        }
+    }
+    res
+}
+
+fn fingerprint(
+    db: &RootDatabase,
+    def: Definition,
+    config: &CloneDetectionConfig,
+) -> Option<Fingerprint> {
+    let (body, file_id) = match def {
+        Definition::Function(it) => {
+            let src = it.source(db)?;
+            (src.value.body()?.syntax().clone(), src.file_id)
+        }
+        Definition::Const(it) => {
+            let src = it.source(db)?;
+            (src.value.body()?.syntax().clone(), src.file_id)
+        }
+        Definition::Static(it) => {
+            let src = it.source(db)?;
+            (src.value.body()?.syntax().clone(), src.file_id)
+        }
+        _ => return None,
+    };
+    // Items generated by macros are expected to look alike.
+    let file_id = file_id.file_id()?;
+    let tokens = normalized_tokens(&body, config);
+    if tokens.len() < config.min_tokens.max(SHINGLE_LEN) {
+        return None;
+    }
+    let mut shingles: Vec<u64> = tokens
+        .windows(SHINGLE_LEN)
+        .map(|window| {
+            let mut hasher = FxHasher::default();
+            window.hash(&mut hasher);
+            hasher.finish()
+        })
+        .collect();
+    shingles.sort_unstable();
+    shingles.dedup();
+    let range = FileRange { file_id, range: body.text_range() };
+    Some(Fingerprint { fragment: CodeFragment { definition: def, range }, shingles })
+}
+
+/// The tokens of `body` without trivia, with the text of the ignored ones left out.
+fn normalized_tokens(
+    body: &SyntaxNode,
+    config: &CloneDetectionConfig,
+) -> Vec<(SyntaxKind, String)> {
+    body.descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|token| !token.kind().is_trivia())
+        .map(|token| {
+            let kind = token.kind();
+            let ignored = match kind {
+                SyntaxKind::IDENT | SyntaxKind::LIFETIME_IDENT => config.ignore_identifiers,
+                _ if ast::Literal::can_cast(token.parent().map_or(kind, |it| it.kind())) => {
+                    config.ignore_literals
+                }
+                _ => false,
+            };
+            (kind, if ignored { String::new() } else { token.text().to_owned() })
+        })
+        .collect()
+}
+
+/// The share of the elements of two sorted and deduplicated slices they have in common.
+fn jaccard(a: &[u64], b: &[u64]) -> f64 {
+    let (mut i, mut j, mut common) = (0, 0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                common += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    let union = a.len() + b.len() - common;
+    if union == 0 {
+        return 1.0;
+    }
+    common as f64 / union as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::fixture::WithFixture;
+
+    use super::*;
+
+    const FIXTURE: &str = r#"
+fn sum_positive(values: &[i32]) -> i32 {
+    let mut total = 0;
+    for value in values {
+        if *value > 0 {
+            total += *value;
+        }
+    }
+    total
+}
+fn add_large(items: &[i32]) -> i32 {
+    let mut acc = 0;
+    for item in items {
+        if *item > 100 {
+            acc += *item;
+        }
+    }
+    acc
+}
+fn unrelated(text: &str) -> usize {
+    text.split(',').map(|part| part.trim()).filter(|part| !part.is_empty()).count() + 1
+}
+"#;
+
+    fn find(config: CloneDetectionConfig) -> Vec<(String, String, f64)> {
+        let db = RootDatabase::with_files(FIXTURE);
+        let name = |fragment: CodeFragment| {
+            fragment.definition.name(&db).unwrap().to_smol_str().to_string()
+        };
+        find_similar_code(&db, &CloneDetectionConfig { min_tokens: 10, ..config })
+            .into_iter()
+            .map(|it| (name(it.first), name(it.second), it.similarity))
+            .collect()
+    }
+
+    #[test]
+    fn finds_renamed_copies() {
+        let similar = find(CloneDetectionConfig::default());
+        assert_eq!(similar, [("sum_positive".to_owned(), "add_large".to_owned(), 1.0)]);
+    }
+
+    #[test]
+    fn respects_normalization() {
+        let config = CloneDetectionConfig { ignore_identifiers: false, ..Default::default() };
+        assert_eq!(find(config), []);
+
+        let config = CloneDetectionConfig { ignore_literals: false, ..Default::default() };
+        assert_eq!(find(config), []);
+        let similar = find(CloneDetectionConfig { min_similarity: 0.5, ..config });
+        assert_eq!(similar.len(), 1);
+        let (first, second, similarity) = &similar[0];
+        assert_eq!((first.as_str(), second.as_str()), ("sum_positive", "add_large"));
+        assert!(*similarity < 1.0);
+    }
+}
@@ -0,0 +1,200 @@
+//! Finds the definitions of the workspace that are never referenced.
+//!
+//! Unlike rustc's `dead_code` lint, which only knows about a single crate, this looks for the
+//! references to public items in the other crates of the workspace too, so it also finds items
+//! that are exported but unused.
+//!
+//! Some definitions are used without being referenced, so they are never reported:
+//! - entry points: `main`, tests and benchmarks, items exported with `#[no_mangle]` or
+//!   `#[export_name]`, and everything in test code;
+//! - the items of traits and of trait impls, which are obligations of the traits;
+//! - definitions allowing `dead_code`, or in modules allowing it, and definitions whose name
+//!   starts with `_`.
+
+use base_db::{FileRange, SourceDatabase};
+use hir::{AsAssocItem, AttrsWithOwner, Crate, HasAttrs, Module, ModuleDef, Semantics};
+
+use crate::{
+    defs::Definition, parallel::par_map, search::requires_test, side_table::definition_range,
+    RootDatabase,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DeadCodeConfig {
+    /// Whether to report definitions that are visible outside their crate. These are only used
+    /// by other crates, which may not be part of the workspace.
+    pub include_public: bool,
+}
+
+/// The unreferenced definitions of a module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeadModule {
+    pub module: Module,
+    /// The unreferenced definitions declared in the module, including the items of its inherent
+    /// impls.
+    pub definitions: Vec<Definition>,
+}
+
+/// Finds the definitions of the workspace crates without references outside of their own
+/// definition, grouped by module. Modules without such definitions are left out.
+pub fn find_dead_code(db: &RootDatabase, config: &DeadCodeConfig) -> Vec<DeadModule> {
+    let _p = profile::span("find_dead_code");
+    let crate_graph = db.crate_graph();
+    let modules: Vec<Module> = Crate::all(db)
+        .into_iter()
+        .filter(|krate| crate_graph[(*krate).into()].origin.is_local())
+        .flat_map(|krate| krate.modules(db))
+        .filter(|&module| !is_excluded_module(db, module))
+        .collect();
+    par_map(db, modules, |db, module| {
+        let sema = Semantics::new(db);
+        let definitions: Vec<_> = candidates(db, module)
+            .into_iter()
+            .filter(|&def| is_reportable(db, config, def) && !is_referenced(&sema, def))
+            .collect();
+        DeadModule { module, definitions }
+    })
+    .into_iter()
+    .filter(|it| !it.definitions.is_empty())
+    .collect()
+}
+
+/// The definitions of `module` that could be unused: its items other than modules and the items of
+/// its inherent impls.
+fn candidates(db: &RootDatabase, module: Module) -> Vec<Definition> {
+    let mut res: Vec<Definition> = module
+        .declarations(db)
+        .into_iter()
+        .filter(|def| !matches!(def, ModuleDef::Module(_) | ModuleDef::BuiltinType(_)))
+        .map(Definition::from)
+        .collect();
+    for impl_ in module.impl_defs(db) {
+        if impl_.trait_(db).is_none() && !allows_dead_code(&impl_.attrs(db)) {
+            res.extend(impl_.items(db).into_iter().map(Definition::from));
+        }
+    }
+    res
+}
+
+fn is_reportable(db: &RootDatabase, config: &DeadCodeConfig, def: Definition) -> bool {
+    let Some(attrs) = attrs(db, def) else { return false };
+    if allows_dead_code(&attrs)
+        || is_entry_point(&attrs)
+        || attrs.cfg().map_or(false, |cfg| requires_test(&cfg))
+    {
+        return false;
+    }
+    if def.name(db).map_or(true, |it| it.to_smol_str().starts_with('_')) {
+        return false;
+    }
+    if let Definition::Function(it) = def {
+        if it.name(db).to_smol_str() == "main" && it.module(db).parent(db).is_none() {
+            return false;
+        }
+    }
+    if def.as_assoc_item(db).and_then(|it| it.containing_trait_or_trait_impl(db)).is_some() {
+        return false;
+    }
+    let is_public = def.visibility(db).map_or(false, |vis| vis == hir::Visibility::Public);
+    config.include_public || !is_public
+}
+
+/// Whether `def` is referenced outside of its own definition, like by recursive calls.
+fn is_referenced(sema: &Semantics<'_, RootDatabase>, def: Definition) -> bool {
+    let own_range = definition_range(sema.db, def);
+    let usages = def.usages(sema).all();
+    let referenced = usages.file_ranges().any(|FileRange { file_id, range }| {
+        own_range.map_or(true, |own| own.file_id != file_id || !own.range.contains_range(range))
+    });
+    referenced
+}
+
+/// Whether `module`, or one of its ancestors, is only compiled for tests or allows `dead_code`.
+fn is_excluded_module(db: &RootDatabase, module: Module) -> bool {
+    std::iter::successors(Some(module), |it| it.parent(db)).any(|module| {
+        let attrs = module.attrs(db);
+        allows_dead_code(&attrs) || attrs.cfg().map_or(false, |cfg| requires_test(&cfg))
+    })
+}
+
+fn is_entry_point(attrs: &AttrsWithOwner) -> bool {
+    ["test", "bench", "no_mangle", "export_name"].into_iter().any(|key| attrs.by_key(key).exists())
+}
+
+fn allows_dead_code(attrs: &AttrsWithOwner) -> bool {
+    attrs.by_key("allow").tt_values().any(|tt| tt.to_string().contains("dead_code"))
+}
+
+fn attrs(db: &RootDatabase, def: Definition) -> Option<AttrsWithOwner> {
+    let attrs = match def {
+        Definition::Function(it) => it.attrs(db),
+        Definition::Adt(it) => it.attrs(db),
+        Definition::Const(it) => it.attrs(db),
+        Definition::Static(it) => it.attrs(db),
+        Definition::Trait(it) => it.attrs(db),
+        Definition::TraitAlias(it) => it.attrs(db),
+        Definition::TypeAlias(it) => it.attrs(db),
+        Definition::Macro(it) => it.attrs(db),
+        _ => return None,
+    };
+    Some(attrs)
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::fixture::WithFixture;
+
+    use super::*;
+
+    fn check(ra_fixture: &str, config: DeadCodeConfig, expect: &[&str]) {
+        let db = RootDatabase::with_files(ra_fixture);
+        let dead: Vec<_> = find_dead_code(&db, &config)
+            .into_iter()
+            .flat_map(|it| it.definitions)
+            .map(|def| def.name(&db).unwrap().to_smol_str().to_string())
+            .collect();
+        assert_eq!(dead, expect);
+    }
+
+    #[test]
+    fn finds_unreferenced_definitions() {
+        check(
+            r#"
+fn main() { used(); }
+fn used() {}
+fn unused() { unused() }
+#[allow(dead_code)]
+fn allowed() {}
+fn _ignored() {}
+pub fn public() {}
+struct S;
+impl S { fn method(&self) {} }
+trait Tr { fn required(&self); }
+impl Tr for S { fn required(&self) {} }
+#[cfg(test)]
+mod tests {
+    fn helper() {}
+    #[test]
+    fn test() {}
+}
+"#,
+            DeadCodeConfig::default(),
+            &["unused", "method"],
+        );
+    }
+
+    #[test]
+    fn includes_public_items_on_request() {
+        check(
+            r#"
+//- /lib.rs crate:lib
+pub fn used() {}
+pub fn unused() {}
+//- /main.rs crate:main deps:lib
+fn main() { lib::used(); }
+"#,
+            DeadCodeConfig { include_public: true },
+            &["unused"],
+        );
+    }
+}
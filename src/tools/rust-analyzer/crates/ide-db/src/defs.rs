@@ -6,11 +6,12 @@
 // FIXME: this badly needs rename/rewrite (matklad, 2020-02-06).
 
 use arrayvec::ArrayVec;
+use base_db::{impl_intern_key, salsa, FilePosition};
 use hir::{
     Adt, AsAssocItem, AssocItem, BuiltinAttr, BuiltinType, Const, Crate, DeriveHelper, Field,
-    Function, GenericParam, HasVisibility, Impl, Label, Local, Macro, Module, ModuleDef, Name,
-    PathResolution, Semantics, Static, ToolModule, Trait, TraitAlias, TypeAlias, Variant,
-    Visibility,
+    Function, GenericParam, HasVisibility, Impl, Label, Local, Macro, ModPath, Module, ModuleDef,
+    Name, PathKind, PathResolution, ScopeDef, Semantics, SemanticsScope, Static, ToolModule, Trait,
+    TraitAlias, TypeAlias, Variant, Visibility,
 };
 use stdx::impl_from;
 use syntax::{
@@ -18,7 +19,10 @@
     match_ast, SyntaxKind, SyntaxNode, SyntaxToken,
 };
 
-use crate::RootDatabase;
+use crate::{
+    helpers::{mod_path_to_ast, path_to_definition, scope_at_position},
+    RootDatabase,
+};
 
 // FIXME: a more precise name would probably be `Symbol`?
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
@@ -106,7 +110,7 @@ pub fn visibility(&self, db: &RootDatabase) -> Option<Visibility> {
         Some(vis)
     }
 
-    pub fn name(&self, db: &RootDatabase) -> Option<Name> {
+    pub fn name(&self, db: &dyn hir::db::HirDatabase) -> Option<Name> {
         let name = match self {
             Definition::Macro(it) => it.name(db),
             Definition::Field(it) => it.name(db),
@@ -130,6 +134,72 @@ pub fn name(&self, db: &RootDatabase) -> Option<Name> {
         };
         Some(name)
     }
+
+    /// Whether the definition can be referred to by a plain name at `position`, without adding
+    /// an import.
+    pub fn is_in_scope_at(&self, db: &RootDatabase, position: FilePosition) -> bool {
+        let sema = Semantics::new(db);
+        scope_at_position(&sema, position)
+            .map_or(false, |scope| self.name_in_scope(&scope).is_some())
+    }
+
+    /// The path the definition can be referred to by at `position` without adding an import:
+    /// a name in scope, or a path starting with one, like `foo::Bar` or `std::fmt::Debug`.
+    pub fn path_at(
+        &self,
+        db: &RootDatabase,
+        position: FilePosition,
+        prefer_no_std: bool,
+    ) -> Option<ModPath> {
+        let sema = Semantics::new(db);
+        self.path_in_scope(&scope_at_position(&sema, position)?, prefer_no_std)
+    }
+
+    /// Like [`Definition::path_at`], for a scope rather than a position.
+    pub fn path_in_scope(
+        &self,
+        scope: &SemanticsScope<'_>,
+        prefer_no_std: bool,
+    ) -> Option<ModPath> {
+        if let Some(name) = self.name_in_scope(scope) {
+            return Some(ModPath::from_segments(PathKind::Plain, Some(name)));
+        }
+        let path = path_to_definition(scope, *self, prefer_no_std)?;
+        // The first segment of the path may be shadowed in the scope.
+        let resolved = scope.speculative_resolve(&mod_path_to_ast(&path))?;
+        (Definition::from(resolved) == *self).then_some(path)
+    }
+
+    /// The name the definition is in scope under, which differs from its own name for renamed
+    /// imports. Names shadowed by another definition aren't considered.
+    pub fn name_in_scope(&self, scope: &SemanticsScope<'_>) -> Option<Name> {
+        let mut names = Vec::new();
+        scope.process_all_names(&mut |name, scope_def| {
+            if Definition::from_scope_def(scope_def) == Some(*self) && !names.contains(&name) {
+                names.push(name);
+            }
+        });
+        let own_name = self.name(scope.db);
+        names.sort_by_key(|name| Some(name) != own_name.as_ref());
+        names.into_iter().find(|name| {
+            let path =
+                mod_path_to_ast(&ModPath::from_segments(PathKind::Plain, Some(name.clone())));
+            scope.speculative_resolve(&path).map(Definition::from) == Some(*self)
+        })
+    }
+
+    pub(crate) fn from_scope_def(def: ScopeDef) -> Option<Definition> {
+        let def = match def {
+            ScopeDef::ModuleDef(it) => it.into(),
+            ScopeDef::GenericParam(it) => Definition::GenericParam(it),
+            ScopeDef::ImplSelfType(it) => Definition::SelfType(it),
+            ScopeDef::AdtSelfType(it) => Definition::Adt(it),
+            ScopeDef::Local(it) => Definition::Local(it),
+            ScopeDef::Label(it) => Definition::Label(it),
+            ScopeDef::Unknown => return None,
+        };
+        Some(def)
+    }
 }
 
 // FIXME: IdentClass as a name no longer fits
@@ -622,3 +692,136 @@ fn from(def: ModuleDef) -> Self {
         }
     }
 }
+
+/// A handle to an interned [`Definition`], which is cheaper to hash, compare and store than
+/// the definition itself. The handles of a database stay valid for its whole lifetime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DefinitionId(salsa::InternId);
+impl_intern_key!(DefinitionId);
+
+#[salsa::query_group(DefinitionDatabaseStorage)]
+pub trait DefinitionDatabase {
+    #[salsa::interned]
+    fn intern_definition(&self, def: Definition) -> DefinitionId;
+}
+
+impl Definition {
+    pub fn intern(self, db: &RootDatabase) -> DefinitionId {
+        db.intern_definition(self)
+    }
+}
+
+impl DefinitionId {
+    pub fn lookup(self, db: &RootDatabase) -> Definition {
+        db.lookup_intern_definition(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::fixture::WithFixture;
+    use expect_test::{expect, Expect};
+
+    use super::*;
+
+    /// Checks the path the definition named `Target` can be referred to by at `$0`.
+    fn check_path_at(ra_fixture: &str, expect: Expect) {
+        let (db, position) = RootDatabase::with_position(ra_fixture);
+        let sema = Semantics::new(&db);
+        let file = sema.parse(position.file_id);
+        let name = file
+            .syntax()
+            .descendants()
+            .filter_map(ast::Name::cast)
+            .find(|it| it.text() == "Target")
+            .unwrap();
+        let def = NameClass::classify(&sema, &name).unwrap().defined().unwrap();
+        let actual = match def.path_at(&db, position, false) {
+            Some(path) => {
+                let in_scope = def.is_in_scope_at(&db, position);
+                format!("{} (in scope: {in_scope})", path.display(&db))
+            }
+            None => "none".to_owned(),
+        };
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn interned_definitions() {
+        let (db, position) = RootDatabase::with_position("struct Target;\nfn f() {$0}");
+        let sema = Semantics::new(&db);
+        let module = sema.to_module_def(position.file_id).unwrap();
+        let defs: Vec<Definition> =
+            module.declarations(&db).into_iter().map(Definition::from).collect();
+        let ids: Vec<DefinitionId> = defs.iter().map(|&def| def.intern(&db)).collect();
+        assert_ne!(ids[0], ids[1]);
+        assert_eq!(defs[0].intern(&db), ids[0]);
+        assert_eq!(ids.iter().map(|id| id.lookup(&db)).collect::<Vec<_>>(), defs);
+    }
+
+    #[test]
+    fn path_at_in_scope() {
+        check_path_at(
+            r#"
+mod a {
+    pub struct Target;
+}
+use a::Target;
+fn f() { $0 }
+"#,
+            expect!["Target (in scope: true)"],
+        );
+        check_path_at(
+            r#"
+mod a {
+    pub struct Target;
+}
+use a::Target as Renamed;
+fn f() { $0 }
+"#,
+            expect!["Renamed (in scope: true)"],
+        );
+    }
+
+    #[test]
+    fn path_at_not_in_scope() {
+        check_path_at(
+            r#"
+mod a {
+    pub struct Target;
+}
+fn f() { $0 }
+"#,
+            expect!["a::Target (in scope: false)"],
+        );
+        check_path_at(
+            r#"
+mod a {
+    pub struct Target;
+}
+fn f() {
+    struct Other;
+    $0
+}
+"#,
+            expect!["a::Target (in scope: false)"],
+        );
+    }
+
+    #[test]
+    fn path_at_shadowed() {
+        check_path_at(
+            r#"
+mod a {
+    pub struct Target;
+}
+use a::Target;
+fn f() {
+    struct Target;
+    $0
+}
+"#,
+            expect!["none"],
+        );
+    }
+}
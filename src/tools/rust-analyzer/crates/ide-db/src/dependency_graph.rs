@@ -0,0 +1,272 @@
+//! The graphs of the references between the modules and crates of the workspace, for checking
+//! architectural rules like layering.
+//!
+//! A module depends on another one if at least one path in its own items resolves to an item of
+//! the other module, including paths in `use` items. Paths in macro calls and in the items of child
+//! modules aren't counted. The references of each module are collected by a query, so only the
+//! modules in edited files are visited again.
+
+use base_db::{salsa, SourceDatabase, Upcast};
+use hir::{db::HirDatabase, Crate, Module, ModuleSource, PathResolution, Semantics};
+use syntax::{ast, AstNode, SyntaxNode, WalkEvent};
+use triomphe::Arc;
+
+use crate::{
+    parallel::{par_flat_map, par_map},
+    FxHashMap, FxHashSet, FxIndexMap, RootDatabase,
+};
+
+#[salsa::query_group(DependencyGraphDatabaseStorage)]
+pub trait DependencyGraphDatabase: HirDatabase + Upcast<RootDatabase> {
+    /// The modules referenced by the paths in the given module, with the number of paths
+    /// resolving to items of each, in the order they are first referenced.
+    fn module_references(&self, module: Module) -> Arc<[(Module, usize)]>;
+}
+
+fn module_references(db: &dyn DependencyGraphDatabase, module: Module) -> Arc<[(Module, usize)]> {
+    let _p = profile::span("module_references");
+    let db: &RootDatabase = db.upcast();
+    let sema = Semantics::new(db);
+    let Some(root) = module_root(&sema, module) else { return Arc::from(Vec::new()) };
+
+    let mut res: FxIndexMap<Module, usize> = FxIndexMap::default();
+    let mut preorder = root.preorder();
+    while let Some(event) = preorder.next() {
+        let WalkEvent::Enter(node) = event else { continue };
+        if node != root && ast::Module::can_cast(node.kind()) {
+            preorder.skip_subtree();
+            continue;
+        }
+        // Only whole paths are counted, not their qualifiers.
+        let Some(path) = ast::Path::cast(node) else { continue };
+        if path.syntax().parent().map_or(false, |it| ast::Path::can_cast(it.kind())) {
+            continue;
+        }
+        let target = match sema.resolve_path(&path) {
+            Some(PathResolution::Def(hir::ModuleDef::Module(it))) => it,
+            Some(PathResolution::Def(def)) => match def.module(db) {
+                Some(it) => it,
+                None => continue,
+            },
+            _ => continue,
+        };
+        if target != module {
+            *res.entry(target).or_default() += 1;
+        }
+    }
+    res.into_iter().collect::<Vec<_>>().into()
+}
+
+/// The syntax of the items of `module`: the file of a module with a file of its own, or the item
+/// list of an inline module.
+fn module_root(sema: &Semantics<'_, RootDatabase>, module: Module) -> Option<SyntaxNode> {
+    let db = sema.db;
+    let file_id = module.definition_source_file_id(db).file_id()?;
+    match module.definition_source(db).value {
+        ModuleSource::SourceFile(_) => Some(sema.parse(file_id).syntax().clone()),
+        ModuleSource::Module(_) => sema
+            .parse(file_id)
+            .syntax()
+            .descendants()
+            .filter_map(ast::Module::cast)
+            .find(|it| sema.to_def(it) == Some(module))
+            .map(|it| it.syntax().clone()),
+        ModuleSource::BlockExpr(_) => None,
+    }
+}
+
+/// A directed graph of dependencies with weighted edges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyGraph<N> {
+    pub nodes: Vec<N>,
+    pub edges: Vec<DependencyEdge<N>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyEdge<N> {
+    pub from: N,
+    pub to: N,
+    /// The number of paths in `from` resolving to items of `to`.
+    pub weight: usize,
+}
+
+impl<N: Copy + Eq + std::hash::Hash> DependencyGraph<N> {
+    /// The strongly connected components of more than one node, that is the groups of nodes all
+    /// depending on each other, directly or not. Components and their nodes are in the order of
+    /// the nodes of the graph.
+    pub fn cycles(&self) -> Vec<Vec<N>> {
+        let index: FxHashMap<N, usize> =
+            self.nodes.iter().enumerate().map(|(idx, &node)| (node, idx)).collect();
+        let mut successors = vec![Vec::new(); self.nodes.len()];
+        let mut predecessors = vec![Vec::new(); self.nodes.len()];
+        for edge in &self.edges {
+            let (from, to) = (index[&edge.from], index[&edge.to]);
+            successors[from].push(to);
+            predecessors[to].push(from);
+        }
+
+        // Kosaraju's algorithm: the nodes are ordered by the time their depth-first search
+        // finishes, then the components are found by searching the reversed graph in the reverse
+        // of that order.
+        let mut visited = vec![false; self.nodes.len()];
+        let mut finished = Vec::with_capacity(self.nodes.len());
+        for start in 0..self.nodes.len() {
+            if visited[start] {
+                continue;
+            }
+            visited[start] = true;
+            let mut stack = vec![(start, 0)];
+            while let Some((node, next_edge)) = stack.pop() {
+                match successors[node].get(next_edge) {
+                    Some(&next) => {
+                        stack.push((node, next_edge + 1));
+                        if !visited[next] {
+                            visited[next] = true;
+                            stack.push((next, 0));
+                        }
+                    }
+                    None => finished.push(node),
+                }
+            }
+        }
+
+        let mut component = vec![None; self.nodes.len()];
+        let mut components: Vec<Vec<usize>> = Vec::new();
+        for &start in finished.iter().rev() {
+            if component[start].is_some() {
+                continue;
+            }
+            component[start] = Some(components.len());
+            let mut members = Vec::new();
+            let mut stack = vec![start];
+            while let Some(node) = stack.pop() {
+                members.push(node);
+                for &prev in &predecessors[node] {
+                    if component[prev].is_none() {
+                        component[prev] = Some(components.len());
+                        stack.push(prev);
+                    }
+                }
+            }
+            components.push(members);
+        }
+
+        let mut cycles: Vec<Vec<usize>> = components
+            .into_iter()
+            .filter(|it| it.len() > 1)
+            .map(|mut it| {
+                it.sort_unstable();
+                it
+            })
+            .collect();
+        cycles.sort_unstable();
+        cycles.into_iter().map(|it| it.into_iter().map(|idx| self.nodes[idx]).collect()).collect()
+    }
+}
+
+/// The graph of the references between the modules of the workspace crates. References to the
+/// modules of other crates are left out.
+pub fn module_dependency_graph(db: &RootDatabase) -> DependencyGraph<Module> {
+    let _p = profile::span("module_dependency_graph");
+    let nodes = workspace_modules(db);
+    let workspace: FxHashSet<Module> = nodes.iter().copied().collect();
+    let edges = par_flat_map(db, nodes.clone(), |db, from| {
+        db.module_references(from)
+            .iter()
+            .filter(|(to, _)| workspace.contains(to))
+            .map(|&(to, weight)| DependencyEdge { from, to, weight })
+            .collect::<Vec<_>>()
+    });
+    DependencyGraph { nodes, edges }
+}
+
+/// The graph of the references between the workspace crates and from them to their dependencies.
+pub fn crate_dependency_graph(db: &RootDatabase) -> DependencyGraph<Crate> {
+    let _p = profile::span("crate_dependency_graph");
+    let modules = workspace_modules(db);
+    let references = par_map(db, modules.clone(), |db, module| db.module_references(module));
+
+    let mut nodes: Vec<Crate> = Vec::new();
+    let mut weights: FxIndexMap<(Crate, Crate), usize> = FxIndexMap::default();
+    for (module, references) in modules.into_iter().zip(references) {
+        let from = module.krate();
+        if !nodes.contains(&from) {
+            nodes.push(from);
+        }
+        for &(target, weight) in references.iter() {
+            let to = target.krate();
+            if to != from {
+                *weights.entry((from, to)).or_default() += weight;
+            }
+        }
+    }
+    for &(_, to) in weights.keys() {
+        if !nodes.contains(&to) {
+            nodes.push(to);
+        }
+    }
+    let edges = weights
+        .into_iter()
+        .map(|((from, to), weight)| DependencyEdge { from, to, weight })
+        .collect();
+    DependencyGraph { nodes, edges }
+}
+
+fn workspace_modules(db: &RootDatabase) -> Vec<Module> {
+    let crate_graph = db.crate_graph();
+    Crate::all(db)
+        .into_iter()
+        .filter(|krate| crate_graph[(*krate).into()].origin.is_local())
+        .flat_map(|krate| krate.modules(db))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::fixture::WithFixture;
+
+    use super::*;
+
+    fn module_name(db: &RootDatabase, module: Module) -> String {
+        module.name(db).map_or_else(|| "crate".to_owned(), |it| it.to_smol_str().to_string())
+    }
+
+    #[test]
+    fn module_graph_with_cycle() {
+        let db = RootDatabase::with_files(
+            r#"
+mod a {
+    pub fn f() { crate::b::g(); }
+}
+mod b {
+    use crate::a::f;
+    pub fn g() { f(); }
+}
+mod c {
+    fn h() { crate::a::f(); crate::a::f(); crate::b::g(); }
+}
+"#,
+        );
+        let graph = module_dependency_graph(&db);
+        let edges: Vec<_> = graph
+            .edges
+            .iter()
+            .map(|it| (module_name(&db, it.from), module_name(&db, it.to), it.weight))
+            .collect();
+        assert_eq!(
+            edges,
+            [
+                ("a".to_owned(), "b".to_owned(), 1),
+                ("b".to_owned(), "a".to_owned(), 2),
+                ("c".to_owned(), "a".to_owned(), 2),
+                ("c".to_owned(), "b".to_owned(), 1),
+            ]
+        );
+        let cycles: Vec<Vec<_>> = graph
+            .cycles()
+            .into_iter()
+            .map(|it| it.into_iter().map(|module| module_name(&db, module)).collect())
+            .collect();
+        assert_eq!(cycles, [["a", "b"]]);
+    }
+}
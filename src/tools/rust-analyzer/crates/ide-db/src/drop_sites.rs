@@ -0,0 +1,281 @@
+//! Finds the places where values of a given type are dropped.
+//!
+//! There is no borrow checker or MIR in rust-analyzer, so this is an approximation
+//! built on top of [`crate::ty_search`]: locals of the type are dropped at the end
+//! of their scope unless they are moved somewhere before, values are dropped when
+//! passed to `drop()`, and places are dropped when they get overwritten.
+
+use base_db::FileId;
+use hir::{Access, PathResolution, Semantics};
+use nohash_hasher::IntMap;
+use rustc_hash::FxHashSet;
+use syntax::{
+    ast::{self, HasArgList},
+    match_ast, AstNode, SyntaxNode, TextRange,
+};
+
+use crate::{
+    defs::Definition,
+    famous_defs::FamousDefs,
+    search::SearchScope,
+    ty_search::{TypeMatch, TypeMatchKind, TypeSearch, TypeSearchTarget},
+    RootDatabase,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DropSiteKind {
+    /// A local goes out of scope, the range is the closing brace of its scope.
+    ScopeEnd,
+    /// A value is passed to `drop()`, the range is the one of the call.
+    ExplicitDrop,
+    /// A place is assigned a new value, the old one is dropped. The range is the one of the
+    /// assignment.
+    Overwrite,
+}
+
+#[derive(Debug, Clone)]
+pub struct DropSite {
+    pub range: TextRange,
+    pub kind: DropSiteKind,
+}
+
+/// Finds the places where values of type `target` are dropped.
+///
+/// This is an approximation: locals that are moved anywhere, even conditionally, are assumed not
+/// to be dropped at the end of their scope, and temporaries are not considered at all.
+pub fn drop_sites(
+    sema: &Semantics<'_, RootDatabase>,
+    target: TypeSearchTarget,
+    scope: Option<SearchScope>,
+) -> IntMap<FileId, Vec<DropSite>> {
+    let _p = profile::span("drop_sites");
+    let mut search = TypeSearch::new(sema, target);
+    if let Some(scope) = scope {
+        search = search.in_scope(scope);
+    }
+
+    let mut res: IntMap<FileId, Vec<DropSite>> = IntMap::default();
+    for (file_id, matches) in search.all() {
+        let tree = sema.parse(file_id);
+        let drop_fn = sema
+            .to_module_def(file_id)
+            .and_then(|module| FamousDefs(sema, module.krate()).core_mem_drop());
+        let exprs: FxHashSet<TextRange> =
+            matches.iter().filter(|it| it.kind == TypeMatchKind::Expr).map(|it| it.range).collect();
+        let sites = res.entry(file_id).or_default();
+
+        for TypeMatch { range, kind, .. } in &matches {
+            if *kind != TypeMatchKind::Local {
+                continue;
+            }
+            let binding = tree.syntax().covering_element(*range).ancestors().find_map(|node| {
+                match_ast! {
+                    match node {
+                        ast::IdentPat(it) => sema.to_def(&it).map(|local| (local, node)),
+                        ast::SelfParam(it) => sema.to_def(&it).map(|local| (local, node)),
+                        _ => None,
+                    }
+                }
+            });
+            let Some((local, node)) = binding else { continue };
+            let Some(range) = scope_end(&node) else { continue };
+            let usages = Definition::Local(local).usages(sema).all();
+            let is_moved = usages
+                .iter()
+                .flat_map(|(_, refs)| refs)
+                .filter_map(|it| it.name.as_name_ref())
+                .any(|name_ref| is_move(sema, name_ref));
+            if !is_moved {
+                sites.push(DropSite { range, kind: DropSiteKind::ScopeEnd });
+            }
+        }
+
+        for node in tree.syntax().descendants() {
+            match_ast! {
+                match node {
+                    ast::BinExpr(it) => {
+                        let Some(ast::BinaryOp::Assignment { op: None }) = it.op_kind() else { continue };
+                        let Some(lhs) = it.lhs() else { continue };
+                        if exprs.contains(&lhs.syntax().text_range()) {
+                            let range = it.syntax().text_range();
+                            sites.push(DropSite { range, kind: DropSiteKind::Overwrite });
+                        }
+                    },
+                    ast::CallExpr(it) => {
+                        let Some(ast::Expr::PathExpr(callee)) = it.expr() else { continue };
+                        let Some(path) = callee.path() else { continue };
+                        let Some(PathResolution::Def(hir::ModuleDef::Function(func))) =
+                            sema.resolve_path(&path) else { continue };
+                        if Some(func) != drop_fn {
+                            continue;
+                        }
+                        let arg = it.arg_list().and_then(|it| it.args().next());
+                        if arg.map_or(false, |arg| exprs.contains(&arg.syntax().text_range())) {
+                            let range = it.syntax().text_range();
+                            sites.push(DropSite { range, kind: DropSiteKind::ExplicitDrop });
+                        }
+                    },
+                    _ => (),
+                }
+            }
+        }
+
+        if sites.is_empty() {
+            res.remove(&file_id);
+        } else {
+            sites.sort_by_key(|it| it.range.start());
+        }
+    }
+    res
+}
+
+/// The end of the scope of the binding introduced by `node`, an `IdentPat` or a `SelfParam`.
+// FIXME: Handle bindings in `if let`, `while let` and `for` patterns.
+fn scope_end(node: &SyntaxNode) -> Option<TextRange> {
+    let owner = node.ancestors().skip(1).find(|it| !ast::Pat::can_cast(it.kind()))?;
+    match_ast! {
+        match owner {
+            ast::LetStmt(it) => {
+                let stmt_list = it.syntax().parent().and_then(ast::StmtList::cast)?;
+                Some(stmt_list.r_curly_token()?.text_range())
+            },
+            ast::Param(it) => body_end(&it.syntax().parent()?.parent()?),
+            ast::ParamList(it) => body_end(&it.syntax().parent()?),
+            ast::MatchArm(it) => Some(TextRange::empty(it.expr()?.syntax().text_range().end())),
+            _ => None,
+        }
+    }
+}
+
+fn body_end(owner: &SyntaxNode) -> Option<TextRange> {
+    let body = match_ast! {
+        match owner {
+            ast::Fn(it) => ast::Expr::BlockExpr(it.body()?),
+            ast::ClosureExpr(it) => it.body()?,
+            _ => return None,
+        }
+    };
+    match body {
+        ast::Expr::BlockExpr(block) => Some(block.stmt_list()?.r_curly_token()?.text_range()),
+        body => Some(TextRange::empty(body.syntax().text_range().end())),
+    }
+}
+
+/// Whether the local referred to by `name_ref` is moved out of.
+fn is_move(sema: &Semantics<'_, RootDatabase>, name_ref: &ast::NameRef) -> bool {
+    let path_expr = name_ref
+        .syntax()
+        .ancestors()
+        .take_while(|it| !ast::Expr::can_cast(it.kind()) || ast::PathExpr::can_cast(it.kind()))
+        .find_map(ast::PathExpr::cast);
+    // Not a path expression, e.g. a shorthand record field `Foo { local }`.
+    let Some(path_expr) = path_expr else { return true };
+    let Some(parent) = path_expr.syntax().parent() else { return true };
+    let is_path_expr =
+        |expr: Option<ast::Expr>| expr.as_ref().map(|it| it.syntax()) == Some(path_expr.syntax());
+    match_ast! {
+        match parent {
+            ast::RefExpr(_) => false,
+            ast::FieldExpr(_) => false,
+            ast::IndexExpr(_) => false,
+            ast::MethodCallExpr(it) => {
+                if !is_path_expr(it.receiver()) {
+                    return true;
+                }
+                sema.resolve_method_call(&it)
+                    .and_then(|func| func.self_param(sema.db))
+                    .map_or(false, |self_param| self_param.access(sema.db) == Access::Owned)
+            },
+            ast::BinExpr(it) => match it.op_kind() {
+                Some(ast::BinaryOp::Assignment { .. }) => !is_path_expr(it.lhs()),
+                // comparisons auto-ref their operands
+                Some(ast::BinaryOp::CmpOp(_)) => false,
+                _ => true,
+            },
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::{fixture::WithFixture, SourceDatabaseExt};
+    use expect_test::{expect, Expect};
+    use hir::Semantics;
+
+    use super::*;
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let (db, position) = RootDatabase::with_position(ra_fixture);
+        let sema = Semantics::new(&db);
+        let file = sema.parse(position.file_id);
+        let adt: ast::Adt =
+            sema.find_node_at_offset_with_descend(file.syntax(), position.offset).unwrap();
+        let target = TypeSearchTarget::Adt(sema.to_def(&adt).unwrap());
+        let text = db.file_text(position.file_id);
+        let actual = drop_sites(&sema, target, None)
+            .remove(&position.file_id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|it| format!("{:?} {:?} {}", it.kind, it.range, &text[it.range]))
+            .map(|it| format!("{}\n", it.trim_end()))
+            .collect::<String>();
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn scope_ends() {
+        check(
+            r#"
+struct Guard$0;
+impl Guard {
+    fn consume(self) {}
+    fn borrow(&self) {}
+}
+fn param(guard: Guard) {
+    guard.borrow();
+}
+fn locals() {
+    let a = Guard;
+    let b = Guard;
+    a.borrow();
+    b.consume();
+    let c = Guard;
+    let _ = |c: Guard| c.borrow();
+}
+fn moved(guard: Guard) -> Guard {
+    guard
+}
+"#,
+            expect![[r#"
+                ScopeEnd 49..50 }
+                ScopeEnd 122..123 }
+                ScopeEnd 261..261
+                ScopeEnd 263..264 }
+                ScopeEnd 263..264 }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn explicit_drops_and_overwrites() {
+        check(
+            r#"
+//- minicore: drop
+struct Guard$0;
+struct Holder { guard: Guard }
+fn f(mut holder: Holder) {
+    let mut a = Guard;
+    a = Guard;
+    holder.guard = Guard;
+    drop(a);
+}
+"#,
+            expect![[r#"
+                Overwrite 99..108 a = Guard
+                Overwrite 114..134 holder.guard = Guard
+                ExplicitDrop 140..147 drop(a)
+            "#]],
+        );
+    }
+}
@@ -0,0 +1,164 @@
+//! The definitions enclosing a position, from the innermost item to the crate root.
+
+use base_db::{FilePosition, FileRange};
+use hir::{ModuleSource, Semantics};
+use syntax::{
+    ast::{self, HasName},
+    match_ast, AstNode, SyntaxNode, TextRange,
+};
+
+use crate::{defs::Definition, RootDatabase};
+
+/// A definition enclosing a position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnclosingDefinition {
+    pub def: Definition,
+    /// The range of the whole definition, which for modules with their own file is the file.
+    pub range: FileRange,
+    /// The range of the name of the definition, if it has one.
+    pub focus_range: Option<TextRange>,
+}
+
+/// Returns the definitions enclosing `position`, innermost first: items, variants and fields,
+/// impls, and the modules containing them up to the crate root. Items in macro expansions aren't
+/// considered, only the ones written in the file.
+pub fn enclosing_definitions(
+    sema: &Semantics<'_, RootDatabase>,
+    position: FilePosition,
+) -> Vec<EnclosingDefinition> {
+    let _p = profile::span("enclosing_definitions");
+    let db = sema.db;
+    let file = sema.parse(position.file_id);
+    let Some(token) = file.syntax().token_at_offset(position.offset).left_biased() else {
+        return Vec::new();
+    };
+
+    let mut res = Vec::new();
+    for node in token.parent_ancestors() {
+        let Some((def, focus_range)) = enclosing_def(sema, &node) else { continue };
+        let range = FileRange { file_id: position.file_id, range: node.text_range() };
+        res.push(EnclosingDefinition { def, range, focus_range });
+    }
+
+    let Some(file_module) = sema.to_module_def(position.file_id) else { return res };
+    // The inline modules have been visited already, continue with the module of the file.
+    for module in file_module.path_to_root(db) {
+        let src = module.definition_source(db);
+        let file_id = src.file_id.original_file(db);
+        let (range, focus_range) = match &src.value {
+            ModuleSource::SourceFile(it) => (it.syntax().text_range(), None),
+            ModuleSource::Module(it) => {
+                (it.syntax().text_range(), it.name().map(|it| it.syntax().text_range()))
+            }
+            ModuleSource::BlockExpr(it) => (it.syntax().text_range(), None),
+        };
+        res.push(EnclosingDefinition {
+            def: Definition::Module(module),
+            range: FileRange { file_id, range },
+            focus_range,
+        });
+    }
+    res
+}
+
+fn enclosing_def(
+    sema: &Semantics<'_, RootDatabase>,
+    node: &SyntaxNode,
+) -> Option<(Definition, Option<TextRange>)> {
+    fn name_range(it: &impl HasName) -> Option<TextRange> {
+        it.name().map(|it| it.syntax().text_range())
+    }
+
+    match_ast! {
+        match node {
+            ast::Fn(it) => Some((sema.to_def(&it)?.into(), name_range(&it))),
+            ast::Adt(it) => Some((sema.to_def(&it)?.into(), name_range(&it))),
+            ast::Variant(it) => Some((sema.to_def(&it)?.into(), name_range(&it))),
+            ast::RecordField(it) => Some((sema.to_def(&it)?.into(), name_range(&it))),
+            ast::Const(it) => Some((sema.to_def(&it)?.into(), name_range(&it))),
+            ast::Static(it) => Some((sema.to_def(&it)?.into(), name_range(&it))),
+            ast::Trait(it) => Some((sema.to_def(&it)?.into(), name_range(&it))),
+            ast::TraitAlias(it) => Some((sema.to_def(&it)?.into(), name_range(&it))),
+            ast::TypeAlias(it) => Some((sema.to_def(&it)?.into(), name_range(&it))),
+            ast::Macro(it) => Some((sema.to_def(&it)?.into(), name_range(&it))),
+            ast::Impl(it) => {
+                let focus_range = it.self_ty().map(|it| it.syntax().text_range());
+                Some((sema.to_def(&it)?.into(), focus_range))
+            },
+            ast::Module(it) => Some((sema.to_def(&it)?.into(), name_range(&it))),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::fixture::WithFixture;
+    use expect_test::{expect, Expect};
+    use stdx::format_to;
+
+    use super::*;
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let (db, position) = RootDatabase::with_position(ra_fixture);
+        let sema = Semantics::new(&db);
+        let mut actual = String::new();
+        for it in enclosing_definitions(&sema, position) {
+            let name = match it.def.name(&db) {
+                Some(name) => name.display(&db).to_string(),
+                None => "_".to_owned(),
+            };
+            format_to!(
+                actual,
+                "{:?} {name} {:?} {:?}\n",
+                it.range.file_id,
+                it.range.range,
+                it.focus_range
+            );
+        }
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn chain_up_to_the_crate_root() {
+        check(
+            r#"
+//- /main.rs
+mod foo;
+//- /foo.rs
+struct S;
+mod bar {
+    impl super::S {
+        fn f() {
+            $0
+        }
+    }
+}
+"#,
+            expect![[r#"
+                FileId(1) f 48..79 Some(51..52)
+                FileId(1) _ 24..85 Some(29..37)
+                FileId(1) bar 10..87 Some(14..17)
+                FileId(1) foo 0..88 None
+                FileId(0) _ 0..9 None
+            "#]],
+        );
+    }
+
+    #[test]
+    fn fields_and_variants() {
+        check(
+            r#"
+enum E {
+    V { field: u$032 },
+}
+"#,
+            expect![[r#"
+                FileId(0) field 17..27 Some(17..22)
+                FileId(0) V 13..29 Some(13..14)
+                FileId(0) E 0..32 Some(5..6)
+                FileId(0) _ 0..33 None
+            "#]],
+        );
+    }
+}
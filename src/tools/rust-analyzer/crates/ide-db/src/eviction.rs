@@ -0,0 +1,167 @@
+//! Eviction of the caches of the files the user isn't working on, for long sessions.
+//!
+//! Over a session, the database accumulates the syntax trees, macro expansions and body analysis
+//! results of every file the user looked at once. Salsa can't evict single entries without
+//! recomputing them, so eviction purges the queries whose entries of inactive files exceed their
+//! capacity, and shrinks the capacities so that they don't grow back right away. The entries of
+//! the active files are purged with them and are recomputed when next used, which is cheap for a
+//! handful of files. The cheap indices, like item trees, def maps and symbol indices, have no LRU
+//! capacity and are kept.
+
+use base_db::{
+    salsa::debug::{DebugQueryTable, TableEntry},
+    FileId,
+};
+use profile::{memory_usage, Bytes};
+use triomphe::Arc;
+
+use crate::{lru::LruCapacities, FxHashSet, RootDatabase};
+
+/// The result of [`RootDatabase::evict_inactive_files`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvictionReport {
+    /// The memory allocated before the eviction.
+    pub before: Bytes,
+    /// The memory allocated after the eviction.
+    pub after: Bytes,
+    /// The capacities the queries are left with, which stay reduced, so that the caches don't
+    /// grow back right away.
+    pub capacities: LruCapacities,
+    /// The number of entries evicted, by query.
+    pub evicted: Vec<(String, usize)>,
+}
+
+impl RootDatabase {
+    /// Sets the files the user is working on, like the files open in the editor, whose entries
+    /// don't count towards the capacities in [`RootDatabase::evict_inactive_files`].
+    pub fn set_active_files(&mut self, files: impl IntoIterator<Item = FileId>) {
+        self.active_files = Arc::new(files.into_iter().collect());
+    }
+
+    pub fn active_files(&self) -> Arc<FxHashSet<FileId>> {
+        self.active_files.clone()
+    }
+
+    /// Evicts the caches of inactive files if more than `threshold` bytes are allocated,
+    /// shrinking `capacities`, the ones the database currently has, proportionally. Returns `None`
+    /// if the threshold isn't exceeded.
+    pub fn evict_inactive_files(
+        &mut self,
+        capacities: &LruCapacities,
+        threshold: Bytes,
+    ) -> Option<EvictionReport> {
+        let _p = profile::span("RootDatabase::evict_inactive_files");
+        let before = memory_usage().allocated;
+        if before <= threshold {
+            return None;
+        }
+        let capacities = capacities.under_memory_pressure(
+            before.bytes().max(0) as usize,
+            threshold.bytes().max(0) as usize,
+        );
+        let evicted = self.evict_inactive_entries(&capacities);
+        Some(EvictionReport { before, after: memory_usage().allocated, capacities, evicted })
+    }
+
+    /// Sets `capacities` and purges the queries of syntax trees, macro expansions and body
+    /// analysis results with more entries of files outside the active ones than their capacity.
+    /// Returns the number of entries evicted by query. Groups without a capacity are left alone.
+    pub fn evict_inactive_entries(&mut self, capacities: &LruCapacities) -> Vec<(String, usize)> {
+        self.set_lru_capacities(capacities);
+        let active_files = self.active_files.clone();
+        let mut evicted = Vec::new();
+        macro_rules! evict {
+            ($cap:ident: $($q:path => $file_of:expr,)*) => {
+                if let Some(capacity) = capacities.$cap {$(
+                    let table = $q.in_db(self);
+                    let keys: Vec<_> = table
+                        .entries::<Vec<TableEntry<_, _>>>()
+                        .into_iter()
+                        .filter(|entry| entry.value.is_some())
+                        .map(|entry| entry.key)
+                        .collect();
+                    let inactive = keys
+                        .iter()
+                        .filter(|&&key| !active_files.contains(&$file_of(&*self, key)))
+                        .count();
+                    let count = if inactive > capacity {
+                        // Only drops the memoized values, nothing is recomputed until next used.
+                        table.purge();
+                        keys.len()
+                    } else {
+                        0
+                    };
+                    let q: $q = Default::default();
+                    evicted.push((format!("{q:?}"), count));
+                )*}
+            }
+        }
+        evict![parse:
+            base_db::ParseQuery => |_, file_id| file_id,
+        ];
+        evict![macro_expansion:
+            hir::db::ParseMacroExpansionQuery => file_of_macro_file,
+            hir::db::MacroExpandQuery => |db, macro_call_id| {
+                file_of_macro_file(db, hir::MacroFile { macro_call_id })
+            },
+        ];
+        evict![body_analysis:
+            hir::db::BodyWithSourceMapQuery => file_of_body,
+            hir::db::BodyQuery => file_of_body,
+            hir::db::ExprScopesQuery => file_of_body,
+            hir::db::InferQueryQuery => file_of_body,
+            hir::db::MirBodyQuery => file_of_body,
+            hir::db::BorrowckQuery => file_of_body,
+        ];
+        evicted
+    }
+}
+
+fn file_of_macro_file(db: &RootDatabase, macro_file: hir::MacroFile) -> FileId {
+    hir::HirFileId::from(macro_file).original_file(db)
+}
+
+fn file_of_body(db: &RootDatabase, def: impl Into<hir::DefWithBody>) -> FileId {
+    def.into().module(db).definition_source_file_id(db).original_file(db)
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::{fixture::WithFixture, SourceDatabase};
+
+    use super::*;
+
+    #[test]
+    fn evicts_inactive_syntax_trees() {
+        let fixture: String = std::iter::once("//- /main.rs\nfn main() {}\n".to_owned())
+            .chain((1..40).map(|i| format!("//- /f{i}.rs\nfn f{i}() {{}}\n")))
+            .collect();
+        let (mut db, files) = RootDatabase::with_many_files(&fixture);
+        let parsed = |db: &RootDatabase| {
+            base_db::ParseQuery
+                .in_db(db)
+                .entries::<Vec<TableEntry<_, _>>>()
+                .into_iter()
+                .filter(|entry| entry.value.is_some())
+                .count()
+        };
+        for &file_id in &files {
+            db.parse(file_id);
+        }
+        db.set_active_files(files[..30].iter().copied());
+
+        let capacities =
+            LruCapacities { parse: Some(16), macro_expansion: None, body_analysis: None };
+        let evicted = db.evict_inactive_entries(&capacities);
+        assert_eq!(evicted, [("ParseQuery".to_owned(), 0)]);
+        assert_eq!(parsed(&db), 40);
+
+        db.set_active_files([files[0]]);
+        let evicted = db.evict_inactive_entries(&capacities);
+        assert_eq!(evicted, [("ParseQuery".to_owned(), 40)]);
+        assert_eq!(parsed(&db), 0);
+
+        db.parse(files[0]);
+        assert_eq!(parsed(&db), 1);
+    }
+}
@@ -1,7 +1,9 @@
 //! See [`FamousDefs`].
 
 use base_db::{CrateOrigin, LangCrateOrigin, SourceDatabase};
-use hir::{Crate, Enum, Macro, Module, ScopeDef, Semantics, Trait};
+use hir::{
+    Adt, Crate, Enum, Function, Macro, Module, ModuleDef, ScopeDef, Semantics, Struct, Trait,
+};
 
 use crate::RootDatabase;
 
@@ -10,6 +12,12 @@
 /// want to make sure that IDE specific paths don't become interesting inside
 /// the compiler itself as well.
 ///
+/// Besides the named accessors, any item can be looked up by its canonical
+/// path like `core::fmt::Debug` through [`FamousDefs::find_def`] and its typed
+/// variants. Paths starting with a crate other than the standard library ones,
+/// like `serde::Serialize`, are looked up in the dependency of that name, so
+/// well-known items of third-party crates work the same way.
+///
 /// Note that, by default, rust-analyzer tests **do not** include core or std
 /// libraries. If you are writing tests for functionality using [`FamousDefs`],
 /// you'd want to include minicore (see `test_utils::MiniCore`) declaration at
@@ -43,67 +51,111 @@ pub fn proc_macro(&self) -> Option<Crate> {
     }
 
     pub fn core_cmp_Ord(&self) -> Option<Trait> {
-        self.find_trait("core:cmp:Ord")
+        self.find_trait("core::cmp::Ord")
     }
 
     pub fn core_convert_From(&self) -> Option<Trait> {
-        self.find_trait("core:convert:From")
+        self.find_trait("core::convert::From")
     }
 
     pub fn core_convert_Into(&self) -> Option<Trait> {
-        self.find_trait("core:convert:Into")
+        self.find_trait("core::convert::Into")
     }
 
     pub fn core_option_Option(&self) -> Option<Enum> {
-        self.find_enum("core:option:Option")
+        self.find_enum("core::option::Option")
     }
 
     pub fn core_result_Result(&self) -> Option<Enum> {
-        self.find_enum("core:result:Result")
+        self.find_enum("core::result::Result")
     }
 
     pub fn core_default_Default(&self) -> Option<Trait> {
-        self.find_trait("core:default:Default")
+        self.find_trait("core::default::Default")
     }
 
     pub fn core_iter_Iterator(&self) -> Option<Trait> {
-        self.find_trait("core:iter:traits:iterator:Iterator")
+        self.find_trait("core::iter::traits::iterator::Iterator")
     }
 
     pub fn core_iter_IntoIterator(&self) -> Option<Trait> {
-        self.find_trait("core:iter:traits:collect:IntoIterator")
+        self.find_trait("core::iter::traits::collect::IntoIterator")
     }
 
     pub fn core_iter(&self) -> Option<Module> {
-        self.find_module("core:iter")
+        self.find_module("core::iter")
     }
 
     pub fn core_ops_Deref(&self) -> Option<Trait> {
-        self.find_trait("core:ops:Deref")
+        self.find_trait("core::ops::Deref")
     }
 
     pub fn core_ops_DerefMut(&self) -> Option<Trait> {
-        self.find_trait("core:ops:DerefMut")
+        self.find_trait("core::ops::DerefMut")
     }
 
     pub fn core_convert_AsRef(&self) -> Option<Trait> {
-        self.find_trait("core:convert:AsRef")
+        self.find_trait("core::convert::AsRef")
     }
 
     pub fn core_ops_ControlFlow(&self) -> Option<Enum> {
-        self.find_enum("core:ops:ControlFlow")
+        self.find_enum("core::ops::ControlFlow")
     }
 
     pub fn core_ops_Drop(&self) -> Option<Trait> {
-        self.find_trait("core:ops:Drop")
+        self.find_trait("core::ops::Drop")
     }
 
     pub fn core_marker_Copy(&self) -> Option<Trait> {
-        self.find_trait("core:marker:Copy")
+        self.find_trait("core::marker::Copy")
+    }
+
+    pub fn core_mem_drop(&self) -> Option<Function> {
+        self.find_function("core::mem::drop")
+    }
+
+    pub fn core_fmt_Debug(&self) -> Option<Trait> {
+        self.find_trait("core::fmt::Debug")
+    }
+
+    pub fn core_fmt_Display(&self) -> Option<Trait> {
+        self.find_trait("core::fmt::Display")
+    }
+
+    pub fn core_hash_Hash(&self) -> Option<Trait> {
+        self.find_trait("core::hash::Hash")
+    }
+
+    pub fn core_borrow_Borrow(&self) -> Option<Trait> {
+        self.find_trait("core::borrow::Borrow")
+    }
+
+    pub fn core_borrow_BorrowMut(&self) -> Option<Trait> {
+        self.find_trait("core::borrow::BorrowMut")
+    }
+
+    pub fn core_iter_Map(&self) -> Option<Struct> {
+        self.find_struct("core::iter::Map")
+    }
+
+    pub fn core_iter_Filter(&self) -> Option<Struct> {
+        self.find_struct("core::iter::Filter")
+    }
+
+    pub fn core_iter_FilterMap(&self) -> Option<Struct> {
+        self.find_struct("core::iter::FilterMap")
+    }
+
+    pub fn core_iter_Take(&self) -> Option<Struct> {
+        self.find_struct("core::iter::Take")
+    }
+
+    pub fn core_iter_Peekable(&self) -> Option<Struct> {
+        self.find_struct("core::iter::Peekable")
     }
 
     pub fn core_macros_builtin_derive(&self) -> Option<Macro> {
-        self.find_macro("core:macros:builtin:derive")
+        self.find_macro("core::macros::builtin::derive")
     }
 
     pub fn builtin_crates(&self) -> impl Iterator<Item = Crate> {
@@ -117,32 +169,53 @@ pub fn builtin_crates(&self) -> impl Iterator<Item = Crate> {
         .flatten()
     }
 
-    fn find_trait(&self, path: &str) -> Option<Trait> {
-        match self.find_def(path)? {
-            hir::ScopeDef::ModuleDef(hir::ModuleDef::Trait(it)) => Some(it),
+    pub fn find_trait(&self, path: &str) -> Option<Trait> {
+        self.find_def_by(path, |def| match def {
+            ScopeDef::ModuleDef(ModuleDef::Trait(it)) => Some(it),
             _ => None,
-        }
+        })
     }
 
-    fn find_macro(&self, path: &str) -> Option<Macro> {
-        match self.find_def(path)? {
-            hir::ScopeDef::ModuleDef(hir::ModuleDef::Macro(it)) => Some(it),
+    pub fn find_function(&self, path: &str) -> Option<Function> {
+        self.find_def_by(path, |def| match def {
+            ScopeDef::ModuleDef(ModuleDef::Function(it)) => Some(it),
             _ => None,
-        }
+        })
     }
 
-    fn find_enum(&self, path: &str) -> Option<Enum> {
-        match self.find_def(path)? {
-            hir::ScopeDef::ModuleDef(hir::ModuleDef::Adt(hir::Adt::Enum(it))) => Some(it),
+    pub fn find_macro(&self, path: &str) -> Option<Macro> {
+        self.find_def_by(path, |def| match def {
+            ScopeDef::ModuleDef(ModuleDef::Macro(it)) => Some(it),
             _ => None,
-        }
+        })
     }
 
-    fn find_module(&self, path: &str) -> Option<Module> {
-        match self.find_def(path)? {
-            hir::ScopeDef::ModuleDef(hir::ModuleDef::Module(it)) => Some(it),
+    pub fn find_enum(&self, path: &str) -> Option<Enum> {
+        self.find_def_by(path, |def| match def {
+            ScopeDef::ModuleDef(ModuleDef::Adt(Adt::Enum(it))) => Some(it),
             _ => None,
-        }
+        })
+    }
+
+    pub fn find_struct(&self, path: &str) -> Option<Struct> {
+        self.find_def_by(path, |def| match def {
+            ScopeDef::ModuleDef(ModuleDef::Adt(Adt::Struct(it))) => Some(it),
+            _ => None,
+        })
+    }
+
+    pub fn find_module(&self, path: &str) -> Option<Module> {
+        self.find_def_by(path, |def| match def {
+            ScopeDef::ModuleDef(ModuleDef::Module(it)) => Some(it),
+            _ => None,
+        })
+    }
+
+    /// Looks up an item by its canonical path, like `core::fmt::Debug` or
+    /// `tokio::main`. The path may go through private modules, and its last
+    /// segment may name an item that is declared or re-exported in the module.
+    pub fn find_def(&self, path: &str) -> Option<ScopeDef> {
+        self.find_def_by(path, Some)
     }
 
     fn find_lang_crate(&self, origin: LangCrateOrigin) -> Option<Crate> {
@@ -157,17 +230,25 @@ fn find_lang_crate(&self, origin: LangCrateOrigin) -> Option<Crate> {
         Some(res)
     }
 
-    fn find_def(&self, path: &str) -> Option<ScopeDef> {
+    fn find_crate(&self, name: &str) -> Option<Crate> {
+        match LangCrateOrigin::from(name) {
+            LangCrateOrigin::Other => {}
+            lang_crate => return self.find_lang_crate(lang_crate),
+        }
         let db = self.0.db;
-        let mut path = path.split(':');
-        let trait_ = path.next_back()?;
-        let lang_crate = path.next()?;
-        let lang_crate = match LangCrateOrigin::from(lang_crate) {
-            LangCrateOrigin::Other => return None,
-            lang_crate => lang_crate,
-        };
-        let std_crate = self.find_lang_crate(lang_crate)?;
-        let mut module = std_crate.root_module(db);
+        let dep = self.1.dependencies(db).into_iter().find(|dep| dep.name.to_smol_str() == name)?;
+        Some(dep.krate)
+    }
+
+    /// Finds the first of the items named by `path` that `f` accepts, as a
+    /// module can contain items of the same name in different namespaces,
+    /// like the `Debug` trait and derive macro.
+    fn find_def_by<T>(&self, path: &str, f: impl Fn(ScopeDef) -> Option<T>) -> Option<T> {
+        let db = self.0.db;
+        let (path, item) = path.rsplit_once("::")?;
+        let mut path = path.split("::");
+        let krate = self.find_crate(path.next()?)?;
+        let mut module = krate.root_module(db);
         for segment in path {
             module = module.children(db).find_map(|child| {
                 let name = child.name(db)?;
@@ -178,8 +259,10 @@ fn find_def(&self, path: &str) -> Option<ScopeDef> {
                 }
             })?;
         }
-        let def =
-            module.scope(db, None).into_iter().find(|(name, _def)| name.to_smol_str() == trait_)?.1;
-        Some(def)
+        module
+            .scope(db, None)
+            .into_iter()
+            .filter(|(name, _def)| name.to_smol_str() == item)
+            .find_map(|(_name, def)| f(def))
     }
 }
@@ -0,0 +1,225 @@
+//! An index of the foreign function interface of the workspace: the symbols imported from other
+//! languages by `extern` blocks, and the ones exported with `#[no_mangle]` or `#[export_name]`.
+//!
+//! Symbols are named by their link name, so an import can be matched with the export of another
+//! crate defining it. The symbols of each crate are collected by a query, which only has to run
+//! again when the crate changes.
+
+use base_db::{salsa, CrateId, SourceDatabase, Upcast};
+use hir::{db::HirDatabase, Crate, HasAttrs, HasContainer, HasSource, ItemContainer, ModuleDef};
+use syntax::{ast, AstNode, AstToken, SmolStr, SyntaxKind, T};
+use triomphe::Arc;
+
+use crate::{defs::Definition, parallel::par_flat_map, RootDatabase};
+
+#[salsa::query_group(FfiIndexDatabaseStorage)]
+pub trait FfiIndexDatabase: HirDatabase + Upcast<RootDatabase> {
+    /// The symbols imported and exported by the given crate, in the order of their declarations.
+    fn crate_ffi_symbols(&self, krate: CrateId) -> Arc<[FfiSymbol]>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FfiDirection {
+    /// A function or static declared in an `extern` block.
+    Import,
+    /// A function or static with `#[no_mangle]` or `#[export_name]`.
+    Export,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FfiSymbol {
+    /// The name of the symbol for the linker.
+    pub symbol: SmolStr,
+    pub direction: FfiDirection,
+    pub definition: Definition,
+    /// The ABI of the function or of the `extern` block, like `C`. Exported statics and exported
+    /// functions with the Rust ABI have none.
+    pub abi: Option<SmolStr>,
+    /// The libraries named by the `#[link]` attributes of the `extern` block of an import.
+    pub libraries: Vec<SmolStr>,
+}
+
+fn crate_ffi_symbols(db: &dyn FfiIndexDatabase, krate: CrateId) -> Arc<[FfiSymbol]> {
+    let _p = profile::span("crate_ffi_symbols");
+    let db: &RootDatabase = db.upcast();
+    let mut res = Vec::new();
+    for module in Crate::from(krate).modules(db) {
+        for def in module.declarations(db) {
+            let symbol = match def {
+                ModuleDef::Function(it) => ffi_function(db, it),
+                ModuleDef::Static(it) => ffi_static(db, it),
+                _ => None,
+            };
+            res.extend(symbol);
+        }
+    }
+    res.into()
+}
+
+fn ffi_function(db: &RootDatabase, function: hir::Function) -> Option<FfiSymbol> {
+    let source = function.source(db)?.value;
+    let definition = Definition::Function(function);
+    let name = function.name(db).to_smol_str();
+    if function.container(db) == ItemContainer::ExternBlock() {
+        let block = source.syntax().ancestors().find_map(ast::ExternBlock::cast)?;
+        return Some(import(&function.attrs(db), definition, name, &block));
+    }
+    let abi = source.abi().map(|it| abi_name(&it));
+    export(&function.attrs(db), definition, name, abi)
+}
+
+fn ffi_static(db: &RootDatabase, static_: hir::Static) -> Option<FfiSymbol> {
+    let definition = Definition::Static(static_);
+    let name = static_.name(db).to_smol_str();
+    if static_.container(db) == ItemContainer::ExternBlock() {
+        let source = static_.source(db)?.value;
+        let block = source.syntax().ancestors().find_map(ast::ExternBlock::cast)?;
+        return Some(import(&static_.attrs(db), definition, name, &block));
+    }
+    export(&static_.attrs(db), definition, name, None)
+}
+
+fn import(
+    attrs: &hir::Attrs,
+    definition: Definition,
+    name: SmolStr,
+    block: &ast::ExternBlock,
+) -> FfiSymbol {
+    let symbol = attrs.by_key("link_name").string_value().cloned().unwrap_or(name);
+    let abi = block.abi().map_or_else(|| "C".into(), |it| abi_name(&it));
+    let libraries = ast::HasAttrs::attrs(block).filter_map(|attr| link_library(&attr)).collect();
+    FfiSymbol { symbol, direction: FfiDirection::Import, definition, abi: Some(abi), libraries }
+}
+
+fn export(
+    attrs: &hir::Attrs,
+    definition: Definition,
+    name: SmolStr,
+    abi: Option<SmolStr>,
+) -> Option<FfiSymbol> {
+    let symbol = match attrs.by_key("export_name").string_value() {
+        Some(it) => it.clone(),
+        None if attrs.by_key("no_mangle").exists() => name,
+        None => return None,
+    };
+    Some(FfiSymbol {
+        symbol,
+        direction: FfiDirection::Export,
+        definition,
+        abi,
+        libraries: Vec::new(),
+    })
+}
+
+/// The name of an ABI, `C` if it isn't given as in `extern fn`.
+fn abi_name(abi: &ast::Abi) -> SmolStr {
+    let name = abi
+        .syntax()
+        .children_with_tokens()
+        .filter_map(|it| it.into_token())
+        .find_map(ast::String::cast)
+        .and_then(|it| it.value().map(|it| SmolStr::new(&*it)));
+    name.unwrap_or_else(|| "C".into())
+}
+
+/// The `name` of a `#[link(name = "...")]` attribute.
+fn link_library(attr: &ast::Attr) -> Option<SmolStr> {
+    if attr.simple_name()? != "link" {
+        return None;
+    }
+    let tokens: Vec<_> = attr
+        .token_tree()?
+        .syntax()
+        .children_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|it| !it.kind().is_trivia())
+        .collect();
+    tokens.windows(3).find_map(|window| match window {
+        [key, eq, value]
+            if key.kind() == SyntaxKind::IDENT && key.text() == "name" && eq.kind() == T![=] =>
+        {
+            let value = ast::String::cast(value.clone())?;
+            Some(SmolStr::new(&*value.value()?))
+        }
+        _ => None,
+    })
+}
+
+/// The symbols of the workspace crates.
+pub fn workspace_ffi_symbols(db: &RootDatabase) -> Vec<FfiSymbol> {
+    let _p = profile::span("workspace_ffi_symbols");
+    let crate_graph = db.crate_graph();
+    let mut crates: Vec<CrateId> =
+        crate_graph.iter().filter(|&krate| crate_graph[krate].origin.is_local()).collect();
+    crates.sort();
+    par_flat_map(db, crates, |db, krate| db.crate_ffi_symbols(krate).to_vec())
+}
+
+/// The imports and exports of a symbol by the workspace crates.
+pub fn find_ffi_symbol(db: &RootDatabase, symbol: &str) -> Vec<FfiSymbol> {
+    workspace_ffi_symbols(db).into_iter().filter(|it| it.symbol == symbol).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::fixture::WithFixture;
+    use expect_test::{expect, Expect};
+    use stdx::format_to;
+
+    use super::*;
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let db = RootDatabase::with_files(ra_fixture);
+        let mut actual = String::new();
+        for symbol in workspace_ffi_symbols(&db) {
+            let name = symbol.definition.name(&db).unwrap();
+            format_to!(actual, "{:?} {} ({})", symbol.direction, symbol.symbol, name.display(&db));
+            if let Some(abi) = &symbol.abi {
+                format_to!(actual, " abi={abi}");
+            }
+            if !symbol.libraries.is_empty() {
+                format_to!(actual, " link={}", symbol.libraries.join(","));
+            }
+            actual.push('\n');
+        }
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn indexes_imports_and_exports() {
+        check(
+            r#"
+//- /lib.rs crate:lib
+#[link(name = "m")]
+extern {
+    fn cos(x: f64) -> f64;
+    #[link_name = "sin"]
+    fn sine(x: f64) -> f64;
+}
+extern "system" {
+    static errno: i32;
+}
+#[no_mangle]
+pub extern "C" fn exported() {}
+#[export_name = "renamed"]
+pub fn rust_abi() {}
+#[no_mangle]
+pub static VERSION: u32 = 1;
+pub fn not_exported() {}
+//- /main.rs crate:main deps:lib
+extern "C" {
+    fn exported();
+}
+"#,
+            expect![[r#"
+                Import cos (cos) abi=C link=m
+                Import sin (sine) abi=C link=m
+                Import errno (errno) abi=system
+                Export exported (exported) abi=C
+                Export renamed (rust_abi)
+                Export VERSION (VERSION)
+                Import exported (exported) abi=C
+            "#]],
+        );
+    }
+}
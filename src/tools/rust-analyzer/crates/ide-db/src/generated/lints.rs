@@ -1,545 +1,791 @@
 //! Generated by `sourcegen_lints`, do not edit by hand.
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+    Forbid,
+}
 #[derive(Clone)]
 pub struct Lint {
     pub label: &'static str,
     pub description: &'static str,
+    /// The level of the lint when it's not configured, `Allow` for lint groups.
+    pub default_level: LintLevel,
 }
 pub struct LintGroup {
     pub lint: Lint,
     pub children: &'static [&'static str],
 }
+pub struct RenamedLint {
+    pub old: &'static str,
+    pub new: &'static str,
+}
+pub struct RemovedLint {
+    pub label: &'static str,
+    pub reason: &'static str,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeatureStatus {
+    Unstable,
+    Accepted,
+    Removed,
+}
+#[derive(Clone)]
+pub struct Feature {
+    pub label: &'static str,
+    pub description: &'static str,
+    pub status: FeatureStatus,
+    /// The version the feature was added in, or stabilized or removed in for accepted and
+    /// removed features. Library features only declare it on the items they gate.
+    pub since: Option<&'static str>,
+}
 pub const DEFAULT_LINTS: &[Lint] = &[
     Lint {
         label: "absolute_paths_not_starting_with_crate",
         description: r##"fully qualified paths that start with a module name instead of `crate`, `self`, or an extern crate name"##,
+        default_level: LintLevel::Allow,
+    },
+    Lint {
+        label: "ambiguous_associated_items",
+        description: r##"ambiguous associated items"##,
+        default_level: LintLevel::Deny,
+    },
+    Lint {
+        label: "anonymous_parameters",
+        description: r##"detects anonymous parameters"##,
+        default_level: LintLevel::Warn,
+    },
+    Lint {
+        label: "arithmetic_overflow",
+        description: r##"arithmetic operation overflows"##,
+        default_level: LintLevel::Deny,
     },
-    Lint { label: "ambiguous_associated_items", description: r##"ambiguous associated items"## },
-    Lint { label: "anonymous_parameters", description: r##"detects anonymous parameters"## },
-    Lint { label: "arithmetic_overflow", description: r##"arithmetic operation overflows"## },
     Lint {
         label: "array_into_iter",
         description: r##"detects calling `into_iter` on arrays in Rust 2015 and 2018"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "asm_sub_register",
         description: r##"using only a subset of a register for inline asm inputs"##,
+        default_level: LintLevel::Warn,
+    },
+    Lint {
+        label: "bad_asm_style",
+        description: r##"incorrect use of inline assembly"##,
+        default_level: LintLevel::Warn,
     },
-    Lint { label: "bad_asm_style", description: r##"incorrect use of inline assembly"## },
     Lint {
         label: "bare_trait_objects",
         description: r##"suggest using `dyn Trait` for trait objects"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "bindings_with_variant_name",
         description: r##"detects pattern bindings with the same name as one of the matched variants"##,
+        default_level: LintLevel::Deny,
+    },
+    Lint {
+        label: "box_pointers",
+        description: r##"use of owned (Box type) heap memory"##,
+        default_level: LintLevel::Allow,
     },
-    Lint { label: "box_pointers", description: r##"use of owned (Box type) heap memory"## },
     Lint {
         label: "break_with_label_and_loop",
         description: r##"`break` expression with label and unlabeled loop as value expression"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "cenum_impl_drop_cast",
         description: r##"a C-like enum implementing Drop is cast"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clashing_extern_declarations",
         description: r##"detects when an extern fn has been declared with the same name but different types"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "coherence_leak_check",
         description: r##"distinct impls distinguished only by the leak-check code"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "conflicting_repr_hints",
         description: r##"conflicts between `#[repr(..)]` hints that were previously accepted and used in practice"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "confusable_idents",
         description: r##"detects visually confusable pairs between identifiers"##,
-    },
-    Lint {
-        label: "const_err",
-        description: r##"constant evaluation encountered erroneous expression"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "const_evaluatable_unchecked",
         description: r##"detects a generic constant is used in a type without a emitting a warning"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "const_item_mutation",
         description: r##"detects attempts to mutate a `const` item"##,
+        default_level: LintLevel::Warn,
+    },
+    Lint {
+        label: "dead_code",
+        description: r##"detect unused, unexported items"##,
+        default_level: LintLevel::Warn,
+    },
+    Lint {
+        label: "deprecated",
+        description: r##"detects use of deprecated items"##,
+        default_level: LintLevel::Warn,
     },
-    Lint { label: "dead_code", description: r##"detect unused, unexported items"## },
-    Lint { label: "deprecated", description: r##"detects use of deprecated items"## },
     Lint {
         label: "deprecated_in_future",
         description: r##"detects use of items that will be deprecated in a future version"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "deref_into_dyn_supertrait",
         description: r##"`Deref` implementation usage with a supertrait trait object for output might be shadowed in the future"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "deref_nullptr",
         description: r##"detects when an null pointer is dereferenced"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "drop_bounds",
         description: r##"bounds of the form `T: Drop` are most likely incorrect"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "dyn_drop",
         description: r##"trait objects of the form `dyn Drop` are useless"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "elided_lifetimes_in_paths",
         description: r##"hidden lifetime parameters in types are deprecated"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "ellipsis_inclusive_range_patterns",
         description: r##"`...` range patterns are deprecated"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "enum_intrinsics_non_enums",
         description: r##"detects calls to `core::mem::discriminant` and `core::mem::variant_count` with non-enum types"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "explicit_outlives_requirements",
         description: r##"outlives requirements can be inferred"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "exported_private_dependencies",
         description: r##"public interface leaks type from a private dependency"##,
+        default_level: LintLevel::Warn,
+    },
+    Lint {
+        label: "forbidden_lint_groups",
+        description: r##"applying forbid to lint-groups"##,
+        default_level: LintLevel::Warn,
     },
-    Lint { label: "forbidden_lint_groups", description: r##"applying forbid to lint-groups"## },
     Lint {
         label: "function_item_references",
         description: r##"suggest casting to a function pointer when attempting to take references to function items"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "future_incompatible",
         description: r##"lint group for: forbidden-lint-groups, illegal-floating-point-literal-pattern, private-in-public, pub-use-of-private-extern-crate, invalid-type-param-default, const-err, unaligned-references, patterns-in-fns-without-body, missing-fragment-specifier, late-bound-lifetime-arguments, order-dependent-trait-objects, coherence-leak-check, unstable-name-collisions, where-clauses-object-safety, proc-macro-derive-resolution-fallback, macro-expanded-macro-exports-accessed-by-absolute-paths, ill-formed-attribute-input, conflicting-repr-hints, ambiguous-associated-items, mutable-borrow-reservation-conflict, indirect-structural-match, pointer-structural-match, nontrivial-structural-match, soft-unstable, cenum-impl-drop-cast, const-evaluatable-unchecked, uninhabited-static, unsupported-naked-functions, invalid-doc-attributes, semicolon-in-expressions-from-macros, legacy-derive-helpers, proc-macro-back-compat, unsupported-calling-conventions, deref-into-dyn-supertrait"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "ill_formed_attribute_input",
         description: r##"ill-formed attribute inputs that were previously accepted and used in practice"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "illegal_floating_point_literal_pattern",
         description: r##"floating-point literals cannot be used in patterns"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "improper_ctypes",
         description: r##"proper use of libc types in foreign modules"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "improper_ctypes_definitions",
         description: r##"proper use of libc types in foreign item definitions"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "incomplete_features",
         description: r##"incomplete features that may function improperly in some or all cases"##,
+        default_level: LintLevel::Warn,
+    },
+    Lint {
+        label: "incomplete_include",
+        description: r##"trailing content in included file"##,
+        default_level: LintLevel::Deny,
     },
-    Lint { label: "incomplete_include", description: r##"trailing content in included file"## },
     Lint {
         label: "indirect_structural_match",
         description: r##"constant used in pattern contains value of non-structural-match type in a field or a variant"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "ineffective_unstable_trait_impl",
         description: r##"detects `#[unstable]` on stable trait implementations for stable types"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "inline_no_sanitize",
         description: r##"detects incompatible use of `#[inline(always)]` and `#[no_sanitize(...)]`"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "invalid_atomic_ordering",
         description: r##"usage of invalid atomic ordering in atomic operations and memory fences"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "invalid_doc_attributes",
         description: r##"detects invalid `#[doc(...)]` attributes"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "invalid_type_param_default",
         description: r##"type parameter default erroneously allowed in invalid location"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "invalid_value",
         description: r##"an invalid value is being created (such as a null reference)"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "irrefutable_let_patterns",
         description: r##"detects irrefutable patterns in `if let` and `while let` statements"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "keyword_idents",
         description: r##"detects edition keywords being used as an identifier"##,
+        default_level: LintLevel::Allow,
+    },
+    Lint {
+        label: "large_assignments",
+        description: r##"detects large moves or copies"##,
+        default_level: LintLevel::Warn,
     },
-    Lint { label: "large_assignments", description: r##"detects large moves or copies"## },
     Lint {
         label: "late_bound_lifetime_arguments",
         description: r##"detects generic lifetime arguments in path segments with late bound lifetime parameters"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "legacy_derive_helpers",
         description: r##"detects derive helper attributes that are used before they are introduced"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "macro_expanded_macro_exports_accessed_by_absolute_paths",
         description: r##"macro-expanded `macro_export` macros from the current crate cannot be referred to by absolute paths"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "macro_use_extern_crate",
         description: r##"the `#[macro_use]` attribute is now deprecated in favor of using macros via the module system"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "meta_variable_misuse",
         description: r##"possible meta-variable misuse at macro definition"##,
+        default_level: LintLevel::Allow,
+    },
+    Lint {
+        label: "missing_abi",
+        description: r##"No declared ABI for extern declaration"##,
+        default_level: LintLevel::Allow,
     },
-    Lint { label: "missing_abi", description: r##"No declared ABI for extern declaration"## },
     Lint {
         label: "missing_copy_implementations",
         description: r##"detects potentially-forgotten implementations of `Copy`"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "missing_debug_implementations",
         description: r##"detects missing implementations of Debug"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "missing_docs",
         description: r##"detects missing documentation for public members"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "missing_fragment_specifier",
         description: r##"detects missing fragment specifiers in unused `macro_rules!` patterns"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "mixed_script_confusables",
         description: r##"detects Unicode scripts whose mixed script confusables codepoints are solely used"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "must_not_suspend",
         description: r##"use of a `#[must_not_suspend]` value across a yield point"##,
-    },
-    Lint {
-        label: "mutable_borrow_reservation_conflict",
-        description: r##"reservation of a two-phased borrow conflicts with other shared borrows"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "mutable_transmutes",
         description: r##"mutating transmuted &mut T from &T may cause undefined behavior"##,
+        default_level: LintLevel::Deny,
+    },
+    Lint {
+        label: "named_asm_labels",
+        description: r##"named labels in inline assembly"##,
+        default_level: LintLevel::Deny,
     },
-    Lint { label: "named_asm_labels", description: r##"named labels in inline assembly"## },
     Lint {
         label: "no_mangle_const_items",
         description: r##"const items will not have their symbols exported"##,
+        default_level: LintLevel::Deny,
+    },
+    Lint {
+        label: "no_mangle_generic_items",
+        description: r##"generic items must be mangled"##,
+        default_level: LintLevel::Warn,
+    },
+    Lint {
+        label: "non_ascii_idents",
+        description: r##"detects non-ASCII identifiers"##,
+        default_level: LintLevel::Allow,
     },
-    Lint { label: "no_mangle_generic_items", description: r##"generic items must be mangled"## },
-    Lint { label: "non_ascii_idents", description: r##"detects non-ASCII identifiers"## },
     Lint {
         label: "non_camel_case_types",
         description: r##"types, variants, traits and type parameters should have camel case names"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "non_exhaustive_omitted_patterns",
         description: r##"detect when patterns of types marked `non_exhaustive` are missed"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "non_fmt_panics",
         description: r##"detect single-argument panic!() invocations in which the argument is not a format string"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "non_shorthand_field_patterns",
         description: r##"using `Struct { x: x }` instead of `Struct { x }` in a pattern"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "non_snake_case",
         description: r##"variables, methods, functions, lifetime parameters and modules should have snake case names"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "non_upper_case_globals",
         description: r##"static constants should have uppercase identifiers"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "nonstandard_style",
         description: r##"lint group for: non-camel-case-types, non-snake-case, non-upper-case-globals"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "nontrivial_structural_match",
         description: r##"constant used in pattern of non-structural-match type and the constant's initializer expression contains values of non-structural-match types"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "noop_method_call",
         description: r##"detects the use of well-known noop methods"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "order_dependent_trait_objects",
         description: r##"trait-object types were treated as different depending on marker-trait order"##,
+        default_level: LintLevel::Deny,
+    },
+    Lint {
+        label: "overflowing_literals",
+        description: r##"literal out of range for its type"##,
+        default_level: LintLevel::Deny,
     },
-    Lint { label: "overflowing_literals", description: r##"literal out of range for its type"## },
     Lint {
         label: "overlapping_range_endpoints",
         description: r##"detects range patterns with overlapping endpoints"##,
+        default_level: LintLevel::Warn,
+    },
+    Lint {
+        label: "path_statements",
+        description: r##"path statements with no effect"##,
+        default_level: LintLevel::Warn,
     },
-    Lint { label: "path_statements", description: r##"path statements with no effect"## },
     Lint {
         label: "patterns_in_fns_without_body",
         description: r##"patterns in functions without body were erroneously allowed"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "pointer_structural_match",
         description: r##"pointers are not structural-match"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "private_in_public",
         description: r##"detect private items in public interfaces not caught by the old implementation"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "proc_macro_back_compat",
         description: r##"detects usage of old versions of certain proc-macro crates"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "proc_macro_derive_resolution_fallback",
         description: r##"detects proc macro derives using inaccessible names from parent modules"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "pub_use_of_private_extern_crate",
         description: r##"detect public re-exports of private extern crates"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "redundant_semicolons",
         description: r##"detects unnecessary trailing semicolons"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "renamed_and_removed_lints",
         description: r##"lints that have been renamed or removed"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "rust_2018_compatibility",
         description: r##"lint group for: keyword-idents, anonymous-parameters, tyvar-behind-raw-pointer, absolute-paths-not-starting-with-crate"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "rust_2018_idioms",
         description: r##"lint group for: bare-trait-objects, unused-extern-crates, ellipsis-inclusive-range-patterns, elided-lifetimes-in-paths, explicit-outlives-requirements"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "rust_2021_compatibility",
         description: r##"lint group for: ellipsis-inclusive-range-patterns, bare-trait-objects, rust-2021-incompatible-closure-captures, rust-2021-incompatible-or-patterns, rust-2021-prelude-collisions, rust-2021-prefixes-incompatible-syntax, array-into-iter, non-fmt-panics"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "rust_2021_incompatible_closure_captures",
         description: r##"detects closures affected by Rust 2021 changes"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "rust_2021_incompatible_or_patterns",
         description: r##"detects usage of old versions of or-patterns"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "rust_2021_prefixes_incompatible_syntax",
         description: r##"identifiers that will be parsed as a prefix in Rust 2021"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "rust_2021_prelude_collisions",
         description: r##"detects the usage of trait methods which are ambiguous with traits added to the prelude in future editions"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "semicolon_in_expressions_from_macros",
         description: r##"trailing semicolon in macro body used as expression"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "single_use_lifetimes",
         description: r##"detects lifetime parameters that are only used once"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "soft_unstable",
         description: r##"a feature gate that doesn't break dependent crates"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "stable_features",
         description: r##"stable features found in `#[feature]` directive"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "temporary_cstring_as_ptr",
         description: r##"detects getting the inner pointer of a temporary `CString`"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "text_direction_codepoint_in_comment",
         description: r##"invisible directionality-changing codepoints in comment"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "text_direction_codepoint_in_literal",
         description: r##"detect special Unicode codepoints that affect the visual representation of text on screen, changing the direction in which text flows"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "trivial_bounds",
         description: r##"these bounds don't depend on an type parameters"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "trivial_casts",
         description: r##"detects trivial casts which could be removed"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "trivial_numeric_casts",
         description: r##"detects trivial casts of numeric types which could be removed"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "type_alias_bounds",
         description: r##"bounds in type aliases are not enforced"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "tyvar_behind_raw_pointer",
         description: r##"raw pointer to an inference variable"##,
-    },
-    Lint {
-        label: "unaligned_references",
-        description: r##"detects unaligned references to fields of packed structs"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "uncommon_codepoints",
         description: r##"detects uncommon Unicode codepoints in identifiers"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "unconditional_panic",
         description: r##"operation will cause a panic at runtime"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "unconditional_recursion",
         description: r##"functions that cannot return without calling themselves"##,
+        default_level: LintLevel::Warn,
+    },
+    Lint {
+        label: "uninhabited_static",
+        description: r##"uninhabited static"##,
+        default_level: LintLevel::Warn,
     },
-    Lint { label: "uninhabited_static", description: r##"uninhabited static"## },
     Lint {
         label: "unknown_crate_types",
         description: r##"unknown crate type found in `#[crate_type]` directive"##,
+        default_level: LintLevel::Deny,
+    },
+    Lint {
+        label: "unknown_lints",
+        description: r##"unrecognized lint attribute"##,
+        default_level: LintLevel::Warn,
     },
-    Lint { label: "unknown_lints", description: r##"unrecognized lint attribute"## },
     Lint {
         label: "unnameable_test_items",
         description: r##"detects an item that cannot be named being marked as `#[test_case]`"##,
+        default_level: LintLevel::Warn,
+    },
+    Lint {
+        label: "unreachable_code",
+        description: r##"detects unreachable code paths"##,
+        default_level: LintLevel::Warn,
+    },
+    Lint {
+        label: "unreachable_patterns",
+        description: r##"detects unreachable patterns"##,
+        default_level: LintLevel::Warn,
     },
-    Lint { label: "unreachable_code", description: r##"detects unreachable code paths"## },
-    Lint { label: "unreachable_patterns", description: r##"detects unreachable patterns"## },
     Lint {
         label: "unreachable_pub",
         description: r##"`pub` items not reachable from crate root"##,
+        default_level: LintLevel::Allow,
+    },
+    Lint {
+        label: "unsafe_code",
+        description: r##"usage of `unsafe` code"##,
+        default_level: LintLevel::Allow,
     },
-    Lint { label: "unsafe_code", description: r##"usage of `unsafe` code"## },
     Lint {
         label: "unsafe_op_in_unsafe_fn",
         description: r##"unsafe operations in unsafe functions without an explicit unsafe block are deprecated"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "unstable_features",
         description: r##"enabling unstable features (deprecated. do not use)"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "unstable_name_collisions",
         description: r##"detects name collision with an existing but unstable method"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "unsupported_calling_conventions",
         description: r##"use of unsupported calling convention"##,
-    },
-    Lint {
-        label: "unsupported_naked_functions",
-        description: r##"unsupported naked function definitions"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "unused",
         description: r##"lint group for: unused-imports, unused-variables, unused-assignments, dead-code, unused-mut, unreachable-code, unreachable-patterns, unused-must-use, unused-unsafe, path-statements, unused-attributes, unused-macros, unused-allocation, unused-doc-comments, unused-extern-crates, unused-features, unused-labels, unused-parens, unused-braces, redundant-semicolons"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "unused_allocation",
         description: r##"detects unnecessary allocations that can be eliminated"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "unused_assignments",
         description: r##"detect assignments that will never be read"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "unused_attributes",
         description: r##"detects attributes that were not used by the compiler"##,
+        default_level: LintLevel::Warn,
+    },
+    Lint {
+        label: "unused_braces",
+        description: r##"unnecessary braces around an expression"##,
+        default_level: LintLevel::Warn,
     },
-    Lint { label: "unused_braces", description: r##"unnecessary braces around an expression"## },
     Lint {
         label: "unused_comparisons",
         description: r##"comparisons made useless by limits of the types involved"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "unused_crate_dependencies",
         description: r##"crate dependencies that are never used"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "unused_doc_comments",
         description: r##"detects doc comments that aren't used by rustdoc"##,
+        default_level: LintLevel::Warn,
+    },
+    Lint {
+        label: "unused_extern_crates",
+        description: r##"extern crates that are never used"##,
+        default_level: LintLevel::Allow,
     },
-    Lint { label: "unused_extern_crates", description: r##"extern crates that are never used"## },
     Lint {
         label: "unused_features",
         description: r##"unused features found in crate-level `#[feature]` directives"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "unused_import_braces",
         description: r##"unnecessary braces around an imported item"##,
+        default_level: LintLevel::Allow,
+    },
+    Lint {
+        label: "unused_imports",
+        description: r##"imports that are never used"##,
+        default_level: LintLevel::Warn,
+    },
+    Lint {
+        label: "unused_labels",
+        description: r##"detects labels that are never used"##,
+        default_level: LintLevel::Warn,
     },
-    Lint { label: "unused_imports", description: r##"imports that are never used"## },
-    Lint { label: "unused_labels", description: r##"detects labels that are never used"## },
     Lint {
         label: "unused_lifetimes",
         description: r##"detects lifetime parameters that are never used"##,
+        default_level: LintLevel::Allow,
+    },
+    Lint {
+        label: "unused_macros",
+        description: r##"detects macros that were not used"##,
+        default_level: LintLevel::Warn,
     },
-    Lint { label: "unused_macros", description: r##"detects macros that were not used"## },
     Lint {
         label: "unused_must_use",
         description: r##"unused result of a type flagged as `#[must_use]`"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "unused_mut",
         description: r##"detect mut variables which don't need to be mutable"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "unused_parens",
         description: r##"`if`, `match`, `while` and `return` do not need parentheses"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "unused_qualifications",
         description: r##"detects unnecessarily qualified names"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "unused_results",
         description: r##"unused result of an expression in a statement"##,
+        default_level: LintLevel::Allow,
+    },
+    Lint {
+        label: "unused_unsafe",
+        description: r##"unnecessary use of an `unsafe` block"##,
+        default_level: LintLevel::Warn,
     },
-    Lint { label: "unused_unsafe", description: r##"unnecessary use of an `unsafe` block"## },
     Lint {
         label: "unused_variables",
         description: r##"detect variables which are not used in any way"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "useless_deprecated",
         description: r##"detects deprecation attributes with no effect"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "variant_size_differences",
         description: r##"detects enums with widely varying variant sizes"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "warnings",
         description: r##"mass-change the level for lints which produce warnings"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "warnings",
         description: r##"lint group for: all lints that are set to issue warnings"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "where_clauses_object_safety",
         description: r##"checks the object safety of where clauses"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "while_true",
         description: r##"suggest using `loop { }` instead of `while true { }`"##,
+        default_level: LintLevel::Warn,
     },
 ];
 pub const DEFAULT_LINT_GROUPS: &[LintGroup] = &[
@@ -547,6 +793,7 @@ pub struct LintGroup {
         lint: Lint {
             label: "future_incompatible",
             description: r##"lint group for: forbidden-lint-groups, illegal-floating-point-literal-pattern, private-in-public, pub-use-of-private-extern-crate, invalid-type-param-default, const-err, unaligned-references, patterns-in-fns-without-body, missing-fragment-specifier, late-bound-lifetime-arguments, order-dependent-trait-objects, coherence-leak-check, unstable-name-collisions, where-clauses-object-safety, proc-macro-derive-resolution-fallback, macro-expanded-macro-exports-accessed-by-absolute-paths, ill-formed-attribute-input, conflicting-repr-hints, ambiguous-associated-items, mutable-borrow-reservation-conflict, indirect-structural-match, pointer-structural-match, nontrivial-structural-match, soft-unstable, cenum-impl-drop-cast, const-evaluatable-unchecked, uninhabited-static, unsupported-naked-functions, invalid-doc-attributes, semicolon-in-expressions-from-macros, legacy-derive-helpers, proc-macro-back-compat, unsupported-calling-conventions, deref-into-dyn-supertrait"##,
+            default_level: LintLevel::Allow,
         },
         children: &[
             "forbidden_lint_groups",
@@ -589,6 +836,7 @@ pub struct LintGroup {
         lint: Lint {
             label: "nonstandard_style",
             description: r##"lint group for: non-camel-case-types, non-snake-case, non-upper-case-globals"##,
+            default_level: LintLevel::Allow,
         },
         children: &["non_camel_case_types", "non_snake_case", "non_upper_case_globals"],
     },
@@ -596,6 +844,7 @@ pub struct LintGroup {
         lint: Lint {
             label: "rust_2018_compatibility",
             description: r##"lint group for: keyword-idents, anonymous-parameters, tyvar-behind-raw-pointer, absolute-paths-not-starting-with-crate"##,
+            default_level: LintLevel::Allow,
         },
         children: &[
             "keyword_idents",
@@ -608,6 +857,7 @@ pub struct LintGroup {
         lint: Lint {
             label: "rust_2018_idioms",
             description: r##"lint group for: bare-trait-objects, unused-extern-crates, ellipsis-inclusive-range-patterns, elided-lifetimes-in-paths, explicit-outlives-requirements"##,
+            default_level: LintLevel::Allow,
         },
         children: &[
             "bare_trait_objects",
@@ -621,6 +871,7 @@ pub struct LintGroup {
         lint: Lint {
             label: "rust_2021_compatibility",
             description: r##"lint group for: ellipsis-inclusive-range-patterns, bare-trait-objects, rust-2021-incompatible-closure-captures, rust-2021-incompatible-or-patterns, rust-2021-prelude-collisions, rust-2021-prefixes-incompatible-syntax, array-into-iter, non-fmt-panics"##,
+            default_level: LintLevel::Allow,
         },
         children: &[
             "ellipsis_inclusive_range_patterns",
@@ -637,6 +888,7 @@ pub struct LintGroup {
         lint: Lint {
             label: "unused",
             description: r##"lint group for: unused-imports, unused-variables, unused-assignments, dead-code, unused-mut, unreachable-code, unreachable-patterns, unused-must-use, unused-unsafe, path-statements, unused-attributes, unused-macros, unused-allocation, unused-doc-comments, unused-extern-crates, unused-features, unused-labels, unused-parens, unused-braces, redundant-semicolons"##,
+            default_level: LintLevel::Allow,
         },
         children: &[
             "unused_imports",
@@ -665,6 +917,7 @@ pub struct LintGroup {
         lint: Lint {
             label: "warnings",
             description: r##"lint group for: all lints that are set to issue warnings"##,
+            default_level: LintLevel::Allow,
         },
         children: &[],
     },
@@ -674,45 +927,59 @@ pub struct LintGroup {
     Lint {
         label: "rustdoc::all",
         description: r##"lint group for: rustdoc::broken-intra-doc-links, rustdoc::private-intra-doc-links, rustdoc::missing-doc-code-examples, rustdoc::private-doc-tests, rustdoc::invalid-codeblock-attributes, rustdoc::invalid-rust-codeblocks, rustdoc::invalid-html-tags, rustdoc::bare-urls, rustdoc::missing-crate-level-docs"##,
+        default_level: LintLevel::Allow,
+    },
+    Lint {
+        label: "rustdoc::bare_urls",
+        description: r##"detects URLs that are not hyperlinks"##,
+        default_level: LintLevel::Warn,
     },
-    Lint { label: "rustdoc::bare_urls", description: r##"detects URLs that are not hyperlinks"## },
     Lint {
         label: "rustdoc::broken_intra_doc_links",
         description: r##"failures in resolving intra-doc link targets"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "rustdoc::invalid_codeblock_attributes",
         description: r##"codeblock attribute looks a lot like a known one"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "rustdoc::invalid_html_tags",
         description: r##"detects invalid HTML tags in doc comments"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "rustdoc::invalid_rust_codeblocks",
         description: r##"codeblock could not be parsed as valid Rust or is empty"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "rustdoc::missing_crate_level_docs",
         description: r##"detects crates with no crate-level documentation"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "rustdoc::missing_doc_code_examples",
         description: r##"detects publicly-exported items without code samples in their documentation"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "rustdoc::private_doc_tests",
         description: r##"detects code samples in docs of private items not documented by rustdoc"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "rustdoc::private_intra_doc_links",
         description: r##"linking from a public item to a private one"##,
+        default_level: LintLevel::Warn,
     },
 ];
 pub const RUSTDOC_LINT_GROUPS: &[LintGroup] = &[LintGroup {
     lint: Lint {
         label: "rustdoc::all",
         description: r##"lint group for: rustdoc::broken-intra-doc-links, rustdoc::private-intra-doc-links, rustdoc::missing-doc-code-examples, rustdoc::private-doc-tests, rustdoc::invalid-codeblock-attributes, rustdoc::invalid-rust-codeblocks, rustdoc::invalid-html-tags, rustdoc::bare-urls, rustdoc::missing-crate-level-docs"##,
+        default_level: LintLevel::Allow,
     },
     children: &[
         "rustdoc::broken_intra_doc_links",
@@ -727,8 +994,321 @@ pub struct LintGroup {
     ],
 }];
 
-pub const FEATURES: &[Lint] = &[
-    Lint {
+pub const RENAMED_LINTS: &[RenamedLint] = &[
+    RenamedLint { old: "async_idents", new: "keyword_idents" },
+    RenamedLint { old: "bare_trait_object", new: "bare_trait_objects" },
+    RenamedLint {
+        old: "clippy::almost_complete_letter_range",
+        new: "clippy::almost_complete_range",
+    },
+    RenamedLint { old: "clippy::blacklisted_name", new: "clippy::disallowed_names" },
+    RenamedLint {
+        old: "clippy::block_in_if_condition_expr",
+        new: "clippy::blocks_in_if_conditions",
+    },
+    RenamedLint {
+        old: "clippy::block_in_if_condition_stmt",
+        new: "clippy::blocks_in_if_conditions",
+    },
+    RenamedLint { old: "clippy::box_vec", new: "clippy::box_collection" },
+    RenamedLint { old: "clippy::cast_ref_to_mut", new: "invalid_reference_casting" },
+    RenamedLint { old: "clippy::clone_double_ref", new: "suspicious_double_ref_op" },
+    RenamedLint { old: "clippy::cmp_nan", new: "invalid_nan_comparisons" },
+    RenamedLint { old: "clippy::const_static_lifetime", new: "clippy::redundant_static_lifetimes" },
+    RenamedLint { old: "clippy::cyclomatic_complexity", new: "clippy::cognitive_complexity" },
+    RenamedLint { old: "clippy::derive_hash_xor_eq", new: "clippy::derived_hash_with_manual_eq" },
+    RenamedLint { old: "clippy::disallowed_method", new: "clippy::disallowed_methods" },
+    RenamedLint { old: "clippy::disallowed_type", new: "clippy::disallowed_types" },
+    RenamedLint { old: "clippy::drop_bounds", new: "drop_bounds" },
+    RenamedLint { old: "clippy::drop_copy", new: "dropping_copy_types" },
+    RenamedLint { old: "clippy::drop_ref", new: "dropping_references" },
+    RenamedLint {
+        old: "clippy::eval_order_dependence",
+        new: "clippy::mixed_read_write_in_expression",
+    },
+    RenamedLint { old: "clippy::for_loop_over_option", new: "for_loops_over_fallibles" },
+    RenamedLint { old: "clippy::for_loop_over_result", new: "for_loops_over_fallibles" },
+    RenamedLint { old: "clippy::for_loops_over_fallibles", new: "for_loops_over_fallibles" },
+    RenamedLint { old: "clippy::forget_copy", new: "forgetting_copy_types" },
+    RenamedLint { old: "clippy::forget_ref", new: "forgetting_references" },
+    RenamedLint { old: "clippy::identity_conversion", new: "clippy::useless_conversion" },
+    RenamedLint { old: "clippy::if_let_some_result", new: "clippy::match_result_ok" },
+    RenamedLint { old: "clippy::integer_arithmetic", new: "clippy::arithmetic_side_effects" },
+    RenamedLint { old: "clippy::into_iter_on_array", new: "array_into_iter" },
+    RenamedLint { old: "clippy::invalid_atomic_ordering", new: "invalid_atomic_ordering" },
+    RenamedLint { old: "clippy::invalid_ref", new: "invalid_value" },
+    RenamedLint { old: "clippy::invalid_utf8_in_unchecked", new: "invalid_from_utf8_unchecked" },
+    RenamedLint { old: "clippy::let_underscore_drop", new: "let_underscore_drop" },
+    RenamedLint { old: "clippy::logic_bug", new: "clippy::overly_complex_bool_expr" },
+    RenamedLint { old: "clippy::mem_discriminant_non_enum", new: "enum_intrinsics_non_enums" },
+    RenamedLint { old: "clippy::new_without_default_derive", new: "clippy::new_without_default" },
+    RenamedLint { old: "clippy::option_and_then_some", new: "clippy::bind_instead_of_map" },
+    RenamedLint { old: "clippy::option_expect_used", new: "clippy::expect_used" },
+    RenamedLint { old: "clippy::option_map_unwrap_or", new: "clippy::map_unwrap_or" },
+    RenamedLint { old: "clippy::option_map_unwrap_or_else", new: "clippy::map_unwrap_or" },
+    RenamedLint { old: "clippy::option_unwrap_used", new: "clippy::unwrap_used" },
+    RenamedLint { old: "clippy::panic_params", new: "non_fmt_panics" },
+    RenamedLint {
+        old: "clippy::positional_named_format_parameters",
+        new: "named_arguments_used_positionally",
+    },
+    RenamedLint { old: "clippy::ref_in_deref", new: "clippy::needless_borrow" },
+    RenamedLint { old: "clippy::result_expect_used", new: "clippy::expect_used" },
+    RenamedLint { old: "clippy::result_map_unwrap_or_else", new: "clippy::map_unwrap_or" },
+    RenamedLint { old: "clippy::result_unwrap_used", new: "clippy::unwrap_used" },
+    RenamedLint { old: "clippy::single_char_push_str", new: "clippy::single_char_add_str" },
+    RenamedLint { old: "clippy::stutter", new: "clippy::module_name_repetitions" },
+    RenamedLint { old: "clippy::temporary_cstring_as_ptr", new: "temporary_cstring_as_ptr" },
+    RenamedLint { old: "clippy::to_string_in_display", new: "clippy::recursive_format_impl" },
+    RenamedLint { old: "clippy::undropped_manually_drops", new: "undropped_manually_drops" },
+    RenamedLint { old: "clippy::unknown_clippy_lints", new: "unknown_lints" },
+    RenamedLint { old: "clippy::unused_label", new: "unused_labels" },
+    RenamedLint { old: "clippy::zero_width_space", new: "clippy::invisible_characters" },
+    RenamedLint {
+        old: "disjoint_capture_migration",
+        new: "rust_2021_incompatible_closure_captures",
+    },
+    RenamedLint { old: "elided_lifetime_in_path", new: "elided_lifetimes_in_paths" },
+    RenamedLint { old: "exceeding_bitshifts", new: "arithmetic_overflow" },
+    RenamedLint { old: "non_fmt_panic", new: "non_fmt_panics" },
+    RenamedLint { old: "or_patterns_back_compat", new: "rust_2021_incompatible_or_patterns" },
+    RenamedLint { old: "overlapping_patterns", new: "overlapping_range_endpoints" },
+    RenamedLint { old: "redundant_semicolon", new: "redundant_semicolons" },
+    RenamedLint { old: "single_use_lifetime", new: "single_use_lifetimes" },
+    RenamedLint { old: "unstable_name_collision", new: "unstable_name_collisions" },
+    RenamedLint { old: "unused_doc_comment", new: "unused_doc_comments" },
+];
+pub const REMOVED_LINTS: &[RemovedLint] = &[
+    RemovedLint { label: "bad_repr", reason: r##"replaced with a generic attribute input check"## },
+    RemovedLint {
+        label: "clippy::assign_ops",
+        reason: r##"using compound assignment operators (e.g., `+=`) is harmless"##,
+    },
+    RemovedLint {
+        label: "clippy::extend_from_slice",
+        reason: r##"`.extend_from_slice(_)` is a faster way to extend a Vec by a slice"##,
+    },
+    RemovedLint {
+        label: "clippy::filter_map",
+        reason: r##"this lint has been replaced by `manual_filter_map`, a more specific lint"##,
+    },
+    RemovedLint {
+        label: "clippy::find_map",
+        reason: r##"this lint has been replaced by `manual_find_map`, a more specific lint"##,
+    },
+    RemovedLint {
+        label: "clippy::if_let_redundant_pattern_matching",
+        reason: r##"this lint has been changed to redundant_pattern_matching"##,
+    },
+    RemovedLint {
+        label: "clippy::misaligned_transmute",
+        reason: r##"this lint has been split into cast_ptr_alignment and transmute_ptr_to_ptr"##,
+    },
+    RemovedLint {
+        label: "clippy::pub_enum_variant_names",
+        reason: r##"set the `avoid-breaking-exported-api` config option to `false` to enable the `enum_variant_names` lint for public items"##,
+    },
+    RemovedLint {
+        label: "clippy::range_step_by_zero",
+        reason: r##"`iterator.step_by(0)` panics nowadays"##,
+    },
+    RemovedLint {
+        label: "clippy::regex_macro",
+        reason: r##"the regex! macro has been removed from the regex crate in 2018"##,
+    },
+    RemovedLint {
+        label: "clippy::replace_consts",
+        reason: r##"associated-constants `MIN`/`MAX` of integers are preferred to `{min,max}_value()` and module constants"##,
+    },
+    RemovedLint {
+        label: "clippy::should_assert_eq",
+        reason: r##"`assert!()` will be more flexible with RFC 2011"##,
+    },
+    RemovedLint {
+        label: "clippy::unsafe_vector_initialization",
+        reason: r##"the replacement suggested by this lint had substantially different behavior"##,
+    },
+    RemovedLint {
+        label: "clippy::unstable_as_mut_slice",
+        reason: r##"`Vec::as_mut_slice` has been stabilized in 1.7"##,
+    },
+    RemovedLint {
+        label: "clippy::unstable_as_slice",
+        reason: r##"`Vec::as_slice` has been stabilized in 1.7"##,
+    },
+    RemovedLint {
+        label: "clippy::unused_collect",
+        reason: r##"`collect` has been marked as #[must_use] in rustc and that covers all cases of this lint"##,
+    },
+    RemovedLint {
+        label: "clippy::wrong_pub_self_convention",
+        reason: r##"set the `avoid-breaking-exported-api` config option to `false` to enable the `wrong_self_convention` lint for public items"##,
+    },
+    RemovedLint {
+        label: "coerce_never",
+        reason: r##"converted into hard error, see issue #48950 <https://github.com/rust-lang/rust/issues/48950> for more information"##,
+    },
+    RemovedLint {
+        label: "const_err",
+        reason: r##"converted into hard error, see issue #71800 <https://github.com/rust-lang/rust/issues/71800> for more information"##,
+    },
+    RemovedLint { label: "deprecated_attr", reason: r##"use `deprecated` instead"## },
+    RemovedLint { label: "drop_with_repr_extern", reason: r##"drop flags have been removed"## },
+    RemovedLint {
+        label: "duplicate_macro_exports",
+        reason: r##"converted into hard error, see issue #35896 <https://github.com/rust-lang/rust/issues/35896> for more information"##,
+    },
+    RemovedLint {
+        label: "duplicate_matcher_binding_name",
+        reason: r##"converted into hard error, see issue #57742 <https://github.com/rust-lang/rust/issues/57742> for more information"##,
+    },
+    RemovedLint {
+        label: "extra_requirement_in_impl",
+        reason: r##"converted into hard error, see issue #37166 <https://github.com/rust-lang/rust/issues/37166> for more information"##,
+    },
+    RemovedLint {
+        label: "fat_ptr_transmutes",
+        reason: r##"was accidentally removed back in 2014"##,
+    },
+    RemovedLint {
+        label: "hr_lifetime_in_assoc_type",
+        reason: r##"converted into hard error, see issue #33685 <https://github.com/rust-lang/rust/issues/33685> for more information"##,
+    },
+    RemovedLint {
+        label: "illegal_floating_point_constant_pattern",
+        reason: r##"converted into hard error, see issue #36890 <https://github.com/rust-lang/rust/issues/36890> for more information"##,
+    },
+    RemovedLint {
+        label: "illegal_struct_or_enum_constant_pattern",
+        reason: r##"converted into hard error, see issue #36891 <https://github.com/rust-lang/rust/issues/36891> for more information"##,
+    },
+    RemovedLint {
+        label: "inaccessible_extern_crate",
+        reason: r##"converted into hard error, see issue #36886 <https://github.com/rust-lang/rust/issues/36886> for more information"##,
+    },
+    RemovedLint {
+        label: "incoherent_fundamental_impls",
+        reason: r##"converted into hard error, see issue #46205 <https://github.com/rust-lang/rust/issues/46205> for more information"##,
+    },
+    RemovedLint {
+        label: "intra_doc_link_resolution_failure",
+        reason: r##"use `rustdoc::broken_intra_doc_links` instead"##,
+    },
+    RemovedLint {
+        label: "legacy_constructor_visibility",
+        reason: r##"converted into hard error, see issue #39207 <https://github.com/rust-lang/rust/issues/39207> for more information"##,
+    },
+    RemovedLint {
+        label: "legacy_directory_ownership",
+        reason: r##"converted into hard error, see issue #37872 <https://github.com/rust-lang/rust/issues/37872> for more information"##,
+    },
+    RemovedLint {
+        label: "legacy_imports",
+        reason: r##"converted into hard error, see issue #38260 <https://github.com/rust-lang/rust/issues/38260> for more information"##,
+    },
+    RemovedLint {
+        label: "lifetime_underscore",
+        reason: r##"converted into hard error, see issue #36892 <https://github.com/rust-lang/rust/issues/36892> for more information"##,
+    },
+    RemovedLint {
+        label: "mutable_borrow_reservation_conflict",
+        reason: r##"now allowed, see issue #59159 <https://github.com/rust-lang/rust/issues/59159> for more information"##,
+    },
+    RemovedLint { label: "negate_unsigned", reason: r##"cast a signed value instead"## },
+    RemovedLint {
+        label: "nested_impl_trait",
+        reason: r##"converted into hard error, see issue #59014 <https://github.com/rust-lang/rust/issues/59014> for more information"##,
+    },
+    RemovedLint {
+        label: "overlapping_inherent_impls",
+        reason: r##"converted into hard error, see issue #36889 <https://github.com/rust-lang/rust/issues/36889> for more information"##,
+    },
+    RemovedLint {
+        label: "parenthesized_params_in_types_and_modules",
+        reason: r##"converted into hard error, see issue #42238 <https://github.com/rust-lang/rust/issues/42238> for more information"##,
+    },
+    RemovedLint {
+        label: "plugin_as_library",
+        reason: r##"plugins have been deprecated and retired"##,
+    },
+    RemovedLint {
+        label: "private_no_mangle_fns",
+        reason: r##"no longer a warning, `#[no_mangle]` functions always exported"##,
+    },
+    RemovedLint {
+        label: "private_no_mangle_statics",
+        reason: r##"no longer a warning, `#[no_mangle]` statics always exported"##,
+    },
+    RemovedLint {
+        label: "raw_pointer_derive",
+        reason: r##"using derive with raw pointers is ok"##,
+    },
+    RemovedLint {
+        label: "raw_pointer_deriving",
+        reason: r##"using derive with raw pointers is ok"##,
+    },
+    RemovedLint {
+        label: "resolve_trait_on_defaulted_unit",
+        reason: r##"converted into hard error, see issue #48950 <https://github.com/rust-lang/rust/issues/48950> for more information"##,
+    },
+    RemovedLint { label: "rustdoc", reason: r##"use `rustdoc::all` instead"## },
+    RemovedLint {
+        label: "safe_extern_statics",
+        reason: r##"converted into hard error, see issue #36247 <https://github.com/rust-lang/rust/issues/36247> for more information"##,
+    },
+    RemovedLint {
+        label: "safe_packed_borrows",
+        reason: r##"converted into hard error, see issue #82523 <https://github.com/rust-lang/rust/issues/82523> for more information"##,
+    },
+    RemovedLint {
+        label: "super_or_self_in_global_path",
+        reason: r##"converted into hard error, see issue #36888 <https://github.com/rust-lang/rust/issues/36888> for more information"##,
+    },
+    RemovedLint {
+        label: "transmute_from_fn_item_types",
+        reason: r##"always cast functions before transmuting them"##,
+    },
+    RemovedLint {
+        label: "unaligned_references",
+        reason: r##"converted into hard error, see issue #82523 <https://github.com/rust-lang/rust/issues/82523> for more information"##,
+    },
+    RemovedLint { label: "unknown_features", reason: r##"replaced by an error"## },
+    RemovedLint {
+        label: "unsigned_negation",
+        reason: r##"replaced by negate_unsigned feature gate"##,
+    },
+    RemovedLint {
+        label: "unsupported_naked_functions",
+        reason: r##"converted into hard error, see RFC 2972 <https://github.com/rust-lang/rfcs/blob/master/text/2972-constrained-naked.md> for more information"##,
+    },
+];
+
+pub const FEATURES: &[Feature] = &[
+    Feature {
+        label: "aarch64_target_feature",
+        description: r##"Allows `#[target_feature(...)]` on aarch64 platforms"##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.61.0"),
+    },
+    Feature {
+        label: "aarch64_ver_target_feature",
+        description: r##""##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.27.0"),
+    },
+    Feature {
+        label: "abi_amdgpu_kernel",
+        description: r##"Allows using the `amdgpu-kernel` ABI."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.29.0"),
+    },
+    Feature {
+        label: "abi_avr_interrupt",
+        description: r##"Allows `extern "avr-interrupt" fn()` and `extern "avr-non-blocking-interrupt" fn()`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.45.0"),
+    },
+    Feature {
         label: "abi_c_cmse_nonsecure_call",
         description: r##"# `abi_c_cmse_nonsecure_call`
 
@@ -819,8 +1399,16 @@ pub fn call_nonsecure_function(addr: usize) -> u32 {
         pop     {r7, pc}
 ```
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.51.0"),
     },
-    Lint {
+    Feature {
+        label: "abi_efiapi",
+        description: r##"Allows using the `efiapi` ABI."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.68.0"),
+    },
+    Feature {
         label: "abi_msp430_interrupt",
         description: r##"# `abi_msp430_interrupt`
 
@@ -865,8 +1453,10 @@ extern "msp430-interrupt" fn tim0() {
     c000:       00 13           reti
 ```
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.16.0"),
     },
-    Lint {
+    Feature {
         label: "abi_ptx",
         description: r##"# `abi_ptx`
 
@@ -929,8 +1519,16 @@ pub fn device_function() {
 }
 ```
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.15.0"),
     },
-    Lint {
+    Feature {
+        label: "abi_sysv64",
+        description: r##"Allows the sysV64 ABI to be specified on all platforms instead of just the platforms on which it is the C ABI."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.24.0"),
+    },
+    Feature {
         label: "abi_thiscall",
         description: r##"# `abi_thiscall`
 
@@ -945,8 +1543,58 @@ pub fn device_function() {
 convention on x86 Windows except that the first parameter of the method,
 the `this` pointer, is passed in the ECX register.
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.19.0"),
+    },
+    Feature {
+        label: "abi_unadjusted",
+        description: r##"Allows using the `unadjusted` ABI; perma-unstable."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.16.0"),
+    },
+    Feature {
+        label: "abi_vectorcall",
+        description: r##"Allows using the `vectorcall` ABI."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.7.0"),
+    },
+    Feature {
+        label: "abi_x86_interrupt",
+        description: r##"Allows `extern "x86-interrupt" fn()`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.17.0"),
+    },
+    Feature {
+        label: "adt_const_params",
+        description: r##"Allows additional const parameter types, such as `&'static str` or user defined types"##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.56.0"),
+    },
+    Feature {
+        label: "advanced_slice_patterns",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "adx_target_feature",
+        description: r##"Allows using ADX intrinsics from `core::arch::{x86, x86_64}`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.61.0"),
+    },
+    Feature {
+        label: "alloc_error_handler",
+        description: r##"Allows defining an `#[alloc_error_handler]`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.29.0"),
+    },
+    Feature {
+        label: "allocator",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.0.0"),
+    },
+    Feature {
         label: "allocator_api",
         description: r##"# `allocator_api`
 
@@ -964,8 +1612,10 @@ pub fn device_function() {
 
 TBD
 "##,
+        status: FeatureStatus::Unstable,
+        since: None,
     },
-    Lint {
+    Feature {
         label: "allocator_internals",
         description: r##"# `allocator_internals`
 
@@ -975,55 +1625,58 @@ pub fn device_function() {
 
 ------------------------
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.20.0"),
+    },
+    Feature {
+        label: "allow_fail",
+        description: r##"Allows a test to fail without failing the whole suite."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.19.0"),
+    },
+    Feature {
+        label: "allow_internal_unsafe",
+        description: r##"Allows using `#[allow_internal_unsafe]`. This is an attribute on `macro_rules!` and can't use the attribute handling below (it has to be checked before expansion possibly makes macros disappear)."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "allow_internal_unstable",
+        description: r##"Allows using `#[allow_internal_unstable]`. This is an attribute on `macro_rules!` and can't use the attribute handling below (it has to be checked before expansion possibly makes macros disappear)."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "anonymous_lifetime_in_impl_trait",
+        description: r##"Allows using anonymous lifetimes in argument-position impl-trait."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.63.0"),
+    },
+    Feature {
         label: "arbitrary_enum_discriminant",
-        description: r##"# `arbitrary_enum_discriminant`
-
-The tracking issue for this feature is: [#60553]
-
-[#60553]: https://github.com/rust-lang/rust/issues/60553
-
-------------------------
-
-The `arbitrary_enum_discriminant` feature permits tuple-like and
-struct-like enum variants with `#[repr(<int-type>)]` to have explicit discriminants.
-
-## Examples
-
-```rust
-#![feature(arbitrary_enum_discriminant)]
-
-#[allow(dead_code)]
-#[repr(u8)]
-enum Enum {
-    Unit = 3,
-    Tuple(u16) = 2,
-    Struct {
-        a: u8,
-        b: u16,
-    } = 1,
-}
-
-impl Enum {
-    fn tag(&self) -> u8 {
-        unsafe { *(self as *const Self as *const u8) }
-    }
-}
-
-assert_eq!(3, Enum::Unit.tag());
-assert_eq!(2, Enum::Tuple(5).tag());
-assert_eq!(1, Enum::Struct{a: 7, b: 11}.tag());
-```
-"##,
-    },
-    Lint {
+        description: r##"Allows explicit discriminants on non-unit enum variants."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.66.0"),
+    },
+    Feature {
+        label: "arbitrary_self_types",
+        description: r##"Allows trait methods with arbitrary self types."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.23.0"),
+    },
+    Feature {
+        label: "arm_target_feature",
+        description: r##""##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.27.0"),
+    },
+    Feature {
         label: "asm_const",
         description: r##"# `asm_const`
 
-The tracking issue for this feature is: [#72016]
+The tracking issue for this feature is: [#93332]
 
-[#72016]: https://github.com/rust-lang/rust/issues/72016
+[#93332]: https://github.com/rust-lang/rust/issues/93332
 
 ------------------------
 
@@ -1031,14 +1684,16 @@ fn tag(&self) -> u8 {
 - `<expr>` must be an integer constant expression.
 - The value of the expression is formatted as a string and substituted directly into the asm template string.
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.58.0"),
     },
-    Lint {
+    Feature {
         label: "asm_experimental_arch",
         description: r##"# `asm_experimental_arch`
 
-The tracking issue for this feature is: [#72016]
+The tracking issue for this feature is: [#93335]
 
-[#72016]: https://github.com/rust-lang/rust/issues/72016
+[#93335]: https://github.com/rust-lang/rust/issues/93335
 
 ------------------------
 
@@ -1051,6 +1706,9 @@ fn tag(&self) -> u8 {
 - BPF
 - SPIR-V
 - AVR
+- MSP430
+- M68k
+- s390x
 
 ## Register classes
 
@@ -1075,6 +1733,12 @@ fn tag(&self) -> u8 {
 | AVR          | `reg_pair`     | `r3r2` .. `r25r24`, `X`, `Z`       | `r`                  |
 | AVR          | `reg_iw`       | `r25r24`, `X`, `Z`                 | `w`                  |
 | AVR          | `reg_ptr`      | `X`, `Z`                           | `e`                  |
+| MSP430       | `reg`          | `r[0-15]`                          | `r`                  |
+| M68k         | `reg`          | `d[0-7]`, `a[0-7]`                 | `r`                  |
+| M68k         | `reg_data`     | `d[0-7]`                           | `d`                  |
+| M68k         | `reg_addr`     | `a[0-3]`                           | `a`                  |
+| s390x        | `reg`          | `r[0-10]`, `r[12-14]`              | `r`                  |
+| s390x        | `freg`         | `f[0-15]`                          | `f`                  |
 
 > **Notes**:
 > - NVPTX doesn't have a fixed register set, so named registers are not supported.
@@ -1103,6 +1767,11 @@ fn tag(&self) -> u8 {
 | BPF          | `wreg`                          | `alu32`        | `i8` `i16` `i32`                        |
 | AVR          | `reg`, `reg_upper`              | None           | `i8`                                    |
 | AVR          | `reg_pair`, `reg_iw`, `reg_ptr` | None           | `i16`                                   |
+| MSP430       | `reg`                           | None           | `i8`, `i16`                             |
+| M68k         | `reg`, `reg_addr`               | None           | `i16`, `i32`                            |
+| M68k         | `reg_data`                      | None           | `i8`, `i16`, `i32`                      |
+| s390x        | `reg`                           | None           | `i8`, `i16`, `i32`, `i64`               |
+| s390x        | `freg`                          | None           | `f32`, `f64`                            |
 
 ## Register aliases
 
@@ -1116,13 +1785,25 @@ fn tag(&self) -> u8 {
 | AVR          | `XL`          | `r26`     |
 | AVR          | `ZH`          | `r31`     |
 | AVR          | `ZL`          | `r30`     |
+| MSP430       | `r0`          | `pc`      |
+| MSP430       | `r1`          | `sp`      |
+| MSP430       | `r2`          | `sr`      |
+| MSP430       | `r3`          | `cg`      |
+| MSP430       | `r4`          | `fp`      |
+| M68k         | `a5`          | `bp`      |
+| M68k         | `a6`          | `fp`      |
+| M68k         | `a7`          | `sp`, `usp`, `ssp`, `isp` |
+
+> **Notes**:
+> - TI does not mandate a frame pointer for MSP430, but toolchains are allowed
+    to use one; LLVM uses `r4`.
 
 ## Unsupported registers
 
 | Architecture | Unsupported register                    | Reason                                                                                                                                                                              |
 | ------------ | --------------------------------------- | ----------------------------------------------------------------------------------------------------------------------------------------------------------------------------------- |
-| All          | `sp`                                    | The stack pointer must be restored to its original value at the end of an asm code block.                                                                                           |
-| All          | `fr` (Hexagon), `$fp` (MIPS), `Y` (AVR) | The frame pointer cannot be used as an input or output.                                                                                                                             |
+| All          | `sp`, `r15` (s390x)                     | The stack pointer must be restored to its original value at the end of an asm code block.                                                                                           |
+| All          | `fr` (Hexagon), `$fp` (MIPS), `Y` (AVR), `r4` (MSP430), `a6` (M68k), `r11` (s390x) | The frame pointer cannot be used as an input or output.                                                                                                                             |
 | All          | `r19` (Hexagon)                         | This is used internally by LLVM as a "base pointer" for functions with complex stack frames.                                                                                        |
 | MIPS         | `$0` or `$zero`                         | This is a constant zero register which can't be modified.                                                                                                                           |
 | MIPS         | `$1` or `$at`                           | Reserved for assembler.                                                                                                                                                             |
@@ -1131,6 +1812,8 @@ fn tag(&self) -> u8 {
 | MIPS         | `$ra`                                   | Return address cannot be used as inputs or outputs.                                                                                                                                 |
 | Hexagon      | `lr`                                    | This is the link register which cannot be used as an input or output.                                                                                                               |
 | AVR          | `r0`, `r1`, `r1r0`                      | Due to an issue in LLVM, the `r0` and `r1` registers cannot be used as inputs or outputs.  If modified, they must be restored to their original values before the end of the block. |
+|MSP430        | `r0`, `r2`, `r3`                        | These are the program counter, status register, and constant generator respectively. Neither the status register nor constant generator can be written to.                          |
+| M68k         | `a4`, `a5`                              | Used internally by LLVM for the base pointer and global base pointer. |
 
 ## Template modifiers
 
@@ -1145,45 +1828,106 @@ fn tag(&self) -> u8 {
 | PowerPC      | `reg`          | None     | `0`            | None          |
 | PowerPC      | `reg_nonzero`  | None     | `3`            | `b`           |
 | PowerPC      | `freg`         | None     | `0`            | None          |
+| s390x        | `reg`          | None     | `%r0`          | None          |
+| s390x        | `freg`         | None     | `%f0`          | None          |
 
 # Flags covered by `preserves_flags`
 
 These flags registers must be restored upon exiting the asm block if the `preserves_flags` option is set:
 - AVR
   - The status register `SREG`.
+- MSP430
+  - The status register `r2`.
+- M68k
+  - The condition code register `ccr`.
+- s390x
+  - The condition code register `cc`.
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.58.0"),
     },
-    Lint {
+    Feature {
         label: "asm_sym",
-        description: r##"# `asm_sym`
-
-The tracking issue for this feature is: [#72016]
-
-[#72016]: https://github.com/rust-lang/rust/issues/72016
-
-------------------------
-
-This feature adds a `sym <path>` operand type to `asm!` and `global_asm!`.
-- `<path>` must refer to a `fn` or `static`.
-- A mangled symbol name referring to the item is substituted into the asm template string.
-- The substituted string does not include any modifiers (e.g. GOT, PLT, relocations, etc).
-- `<path>` is allowed to point to a `#[thread_local]` static, in which case the asm code can combine the symbol with relocations (e.g. `@plt`, `@TPOFF`) to read from thread-local data.
-"##,
+        description: r##"Allows using `sym` operands in inline assembly."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.66.0"),
     },
-    Lint {
+    Feature {
         label: "asm_unwind",
         description: r##"# `asm_unwind`
 
-The tracking issue for this feature is: [#72016]
+The tracking issue for this feature is: [#93334]
 
-[#72016]: https://github.com/rust-lang/rust/issues/72016
+[#93334]: https://github.com/rust-lang/rust/issues/93334
 
 ------------------------
 
 This feature adds a `may_unwind` option to `asm!` which allows an `asm` block to unwind stack and be part of the stack unwinding process. This option is only supported by the LLVM backend right now.
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.58.0"),
+    },
+    Feature {
+        label: "associated_const_equality",
+        description: r##"Allows users to enforce equality of associated constants `TraitImpl<AssocConst=3>`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.58.0"),
+    },
+    Feature {
+        label: "associated_consts",
+        description: r##"Allows the definition of associated constants in `trait` or `impl` blocks."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.20.0"),
+    },
+    Feature {
+        label: "associated_type_bounds",
+        description: r##"Allows the user of associated type bounds."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.34.0"),
+    },
+    Feature {
+        label: "associated_type_defaults",
+        description: r##"Allows associated type defaults."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.2.0"),
+    },
+    Feature {
+        label: "associated_types",
+        description: r##"Allows using associated `type`s in `trait`s."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "async_await",
+        description: r##"Allows free and inherent `async fn`s, `async` blocks, and `<expr>.await` expressions."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.39.0"),
+    },
+    Feature {
+        label: "async_closure",
+        description: r##"Allows `async || body` closures."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.37.0"),
+    },
+    Feature {
+        label: "async_fn_in_trait",
+        description: r##"Allows async functions to be declared, implemented, and used in traits."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.66.0"),
+    },
+    Feature {
+        label: "attr_literals",
+        description: r##"Allows all literals in attribute lists and values of key-value pairs."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.30.0"),
+    },
+    Feature {
+        label: "augmented_assignments",
+        description: r##"Allows overloading augmented assignment operations like `a += b`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.8.0"),
+    },
+    Feature {
         label: "auto_traits",
         description: r##"# `auto_traits`
 
@@ -1200,8 +1944,8 @@ fn tag(&self) -> u8 {
 has explicitly opted out via a negative impl. (Negative impls are separately controlled
 by the `negative_impls` feature.)
 
-[`Send`]: https://doc.rust-lang.org/std/marker/trait.Send.html
-[`Sync`]: https://doc.rust-lang.org/std/marker/trait.Sync.html
+[`Send`]: ../../std/marker/trait.Send.html
+[`Sync`]: ../../std/marker/trait.Sync.html
 
 ```rust,ignore (partial-example)
 impl !Trait for Type {}
@@ -1292,8 +2036,34 @@ trait matching, this cycle would be an error, but for an auto trait it
 
 Auto traits cannot have supertraits. This is for soundness reasons, as the interaction of coinduction with implied bounds is difficult to reconcile.
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.50.0"),
+    },
+    Feature {
+        label: "avx512_target_feature",
+        description: r##""##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.27.0"),
+    },
+    Feature {
+        label: "await_macro",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.38.0"),
+    },
+    Feature {
+        label: "bind_by_move_pattern_guards",
+        description: r##"Allows mixing bind-by-move in patterns and references to those identifiers in guards."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.39.0"),
+    },
+    Feature {
+        label: "bindings_after_at",
+        description: r##"Allows bindings in the subpattern of a binding pattern. For example, you can write `x @ Some(y)`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.56.0"),
+    },
+    Feature {
         label: "box_patterns",
         description: r##"# `box_patterns`
 
@@ -1301,8 +2071,6 @@ trait matching, this cycle would be an error, but for an auto trait it
 
 [#29641]: https://github.com/rust-lang/rust/issues/29641
 
-See also [`box_syntax`](box-syntax.md)
-
 ------------------------
 
 Box patterns let you match on `Box<T>`s:
@@ -1315,10 +2083,10 @@ fn main() {
     let b = Some(Box::new(5));
     match b {
         Some(box n) if n < 0 => {
-            println!("Box contains negative number {}", n);
+            println!("Box contains negative number {n}");
         },
         Some(box n) if n >= 0 => {
-            println!("Box contains non-negative number {}", n);
+            println!("Box contains non-negative number {n}");
         },
         None => {
             println!("No box");
@@ -1328,34 +2096,40 @@ fn main() {
 }
 ```
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.0.0"),
     },
-    Lint {
+    Feature {
         label: "box_syntax",
-        description: r##"# `box_syntax`
-
-The tracking issue for this feature is: [#49733]
-
-[#49733]: https://github.com/rust-lang/rust/issues/49733
-
-See also [`box_patterns`](box-patterns.md)
-
-------------------------
-
-Currently the only stable way to create a `Box` is via the `Box::new` method.
-Also it is not possible in stable Rust to destructure a `Box` in a match
-pattern. The unstable `box` keyword can be used to create a `Box`. An example
-usage would be:
-
-```rust
-#![feature(box_syntax)]
-
-fn main() {
-    let b = box 5;
-}
-```
-"##,
-    },
-    Lint {
+        description: r##"Allows using the `box $expr` syntax."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.70.0"),
+    },
+    Feature {
+        label: "bpf_target_feature",
+        description: r##""##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.54.0"),
+    },
+    Feature {
+        label: "braced_empty_structs",
+        description: r##"Allows empty structs and enum variants with braces."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.8.0"),
+    },
+    Feature {
+        label: "builtin_syntax",
+        description: r##"Allows builtin # foo() syntax"##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.71.0"),
+    },
+    Feature {
+        label: "c_str_literals",
+        description: r##"Allows `c"foo"` literals."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.71.0"),
+    },
+    Feature {
         label: "c_unwind",
         description: r##"# `c_unwind`
 
@@ -1365,44 +2139,29 @@ fn main() {
 
 ------------------------
 
-Introduces four new ABI strings: "C-unwind", "stdcall-unwind",
-"thiscall-unwind", and "system-unwind". These enable unwinding from other
-languages (such as C++) into Rust frames and from Rust into other languages.
+Introduces new ABI strings:
+- "C-unwind"
+- "cdecl-unwind"
+- "stdcall-unwind"
+- "fastcall-unwind"
+- "vectorcall-unwind"
+- "thiscall-unwind"
+- "aapcs-unwind"
+- "win64-unwind"
+- "sysv64-unwind"
+- "system-unwind"
+
+These enable unwinding from other languages (such as C++) into Rust frames and
+from Rust into other languages.
 
 See [RFC 2945] for more information.
 
 [RFC 2945]: https://github.com/rust-lang/rfcs/blob/master/text/2945-c-unwind-abi.md
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.52.0"),
     },
-    Lint {
-        label: "c_variadic",
-        description: r##"# `c_variadic`
-
-The tracking issue for this feature is: [#44930]
-
-[#44930]: https://github.com/rust-lang/rust/issues/44930
-
-------------------------
-
-The `c_variadic` language feature enables C-variadic functions to be
-defined in Rust. The may be called both from within Rust and via FFI.
-
-## Examples
-
-```rust
-#![feature(c_variadic)]
-
-pub unsafe extern "C" fn add(n: usize, mut args: ...) -> usize {
-    let mut sum = 0;
-    for _ in 0..n {
-        sum += args.arg::<usize>();
-    }
-    sum
-}
-```
-"##,
-    },
-    Lint {
+    Feature {
         label: "c_variadic",
         description: r##"# `c_variadic`
 
@@ -1431,8 +2190,10 @@ fn main() {
 }
 ```
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.34.0"),
     },
-    Lint {
+    Feature {
         label: "c_void_variant",
         description: r##"# `c_void_variant`
 
@@ -1440,50 +2201,40 @@ fn main() {
 
 ------------------------
 "##,
+        status: FeatureStatus::Unstable,
+        since: None,
+    },
+    Feature {
+        label: "capture_disjoint_fields",
+        description: r##"Allows capturing disjoint fields in a closure/generator (RFC 2229)."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.49.0"),
+    },
+    Feature {
+        label: "cfg_attr_multi",
+        description: r##"Allows `#[cfg_attr(predicate, multiple, attributes, here)]`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.33.0"),
+    },
+    Feature {
+        label: "cfg_doctest",
+        description: r##"Allows the use of `#[cfg(doctest)]`, set when rustdoc is collecting doctests."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.40.0"),
+    },
+    Feature {
+        label: "cfg_overflow_checks",
+        description: r##"Allows the use of `#[cfg(overflow_checks)` to check if integer overflow behaviour."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.71.0"),
+    },
+    Feature {
+        label: "cfg_panic",
+        description: r##"Enables `#[cfg(panic = "...")]` config key."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.60.0"),
     },
-    Lint {
-        label: "cfg_panic",
-        description: r##"# `cfg_panic`
-
-The tracking issue for this feature is: [#77443]
-
-[#77443]: https://github.com/rust-lang/rust/issues/77443
-
-------------------------
-
-The `cfg_panic` feature makes it possible to execute different code
-depending on the panic strategy.
-
-Possible values at the moment are `"unwind"` or `"abort"`, although
-it is possible that new panic strategies may be added to Rust in the
-future.
-
-## Examples
-
-```rust
-#![feature(cfg_panic)]
-
-#[cfg(panic = "unwind")]
-fn a() {
-    // ...
-}
-
-#[cfg(not(panic = "unwind"))]
-fn a() {
-    // ...
-}
-
-fn b() {
-    if cfg!(panic = "abort") {
-        // ...
-    } else {
-        // ...
-    }
-}
-```
-"##,
-    },
-    Lint {
+    Feature {
         label: "cfg_sanitize",
         description: r##"# `cfg_sanitize`
 
@@ -1520,8 +2271,52 @@ fn b() {
 }
 ```
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.41.0"),
+    },
+    Feature {
+        label: "cfg_target_abi",
+        description: r##"Allows `cfg(target_abi = "...")`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.55.0"),
+    },
+    Feature {
+        label: "cfg_target_compact",
+        description: r##"Allows `cfg(target(abi = "..."))`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.63.0"),
+    },
+    Feature {
+        label: "cfg_target_feature",
+        description: r##"Allows `cfg(target_feature = "...")`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.27.0"),
+    },
+    Feature {
+        label: "cfg_target_has_atomic",
+        description: r##"Allows `cfg(target_has_atomic_load_store = "...")`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.60.0"),
+    },
+    Feature {
+        label: "cfg_target_has_atomic_equal_alignment",
+        description: r##"Allows `cfg(target_has_atomic_equal_alignment = "...")`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.60.0"),
+    },
+    Feature {
+        label: "cfg_target_thread_local",
+        description: r##"Allows `cfg(target_thread_local)`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.7.0"),
+    },
+    Feature {
+        label: "cfg_target_vendor",
+        description: r##"Allows `cfg(target_vendor = "...")`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.33.0"),
+    },
+    Feature {
         label: "cfg_version",
         description: r##"# `cfg_version`
 
@@ -1559,17 +2354,59 @@ fn b() {
 }
 ```
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.45.0"),
     },
-    Lint {
-        label: "char_error_internals",
-        description: r##"# `char_error_internals`
+    Feature {
+        label: "cfi_encoding",
+        description: r##"# `cfi_encoding`
 
-This feature is internal to the Rust compiler and is not intended for general use.
+The tracking issue for this feature is: [#89653]
+
+[#89653]: https://github.com/rust-lang/rust/issues/89653
 
 ------------------------
+
+The `cfi_encoding` feature allows the user to define a CFI encoding for a type.
+It allows the user to use a different names for types that otherwise would be
+required to have the same name as used in externally defined C functions.
+
+## Examples
+
+```rust
+#![feature(cfi_encoding, extern_types)]
+
+#[cfi_encoding = "3Foo"]
+pub struct Type1(i32);
+
+extern {
+    #[cfi_encoding = "3Bar"]
+    type Type2;
+}
+```
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.71.0"),
+    },
+    Feature {
+        label: "clone_closures",
+        description: r##"Allows implementing `Clone` for closures where possible (RFC 2132)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.26.0"),
+    },
+    Feature {
+        label: "closure_lifetime_binder",
+        description: r##"Allows `for<...>` on closures and generators."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.64.0"),
+    },
+    Feature {
+        label: "closure_to_fn_coercion",
+        description: r##"Allows coercing non capturing closures to function pointers."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.19.0"),
+    },
+    Feature {
         label: "closure_track_caller",
         description: r##"# `closure_track_caller`
 
@@ -1584,8 +2421,16 @@ fn b() {
 available through `std::panic::Location::caller()`, just like using
 `#[track_caller]` on a function.
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.57.0"),
     },
-    Lint {
+    Feature {
+        label: "cmpxchg16b_target_feature",
+        description: r##"Allows using the CMPXCHG16B target feature."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.69.0"),
+    },
+    Feature {
         label: "cmse_nonsecure_entry",
         description: r##"# `cmse_nonsecure_entry`
 
@@ -1669,8 +2514,22 @@ pub extern "C" fn entry_function(input: u32) -> u32 {
   40:   defe            udf     #254    ; 0xfe
 ```
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.48.0"),
+    },
+    Feature {
+        label: "collapse_debuginfo",
+        description: r##"Allows use of the `#[collapse_debuginfo]` attribute."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.65.0"),
+    },
+    Feature {
+        label: "compile_error",
+        description: r##"Allows usage of the `compile_error!` macro."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.20.0"),
+    },
+    Feature {
         label: "compiler_builtins",
         description: r##"# `compiler_builtins`
 
@@ -1678,8 +2537,10 @@ pub extern "C" fn entry_function(input: u32) -> u32 {
 
 ------------------------
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.13.0"),
     },
-    Lint {
+    Feature {
         label: "concat_idents",
         description: r##"# `concat_idents`
 
@@ -1704,19 +2565,214 @@ fn foobar() -> u32 { 23 }
 }
 ```
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: None,
+    },
+    Feature {
+        label: "conservative_impl_trait",
+        description: r##"Allows `impl Trait` in function return types."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.26.0"),
+    },
+    Feature {
+        label: "const_async_blocks",
+        description: r##"Allows `async {}` expressions in const contexts."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.53.0"),
+    },
+    Feature {
+        label: "const_closures",
+        description: r##"Allows `const || {}` closures in const contexts."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.68.0"),
+    },
+    Feature {
+        label: "const_compare_raw_pointers",
+        description: r##"Allows comparing raw pointers during const eval."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.46.0"),
+    },
+    Feature {
+        label: "const_constructor",
+        description: r##"Allows calling constructor functions in `const fn`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.40.0"),
+    },
+    Feature {
         label: "const_eval_limit",
-        description: r##"# `const_eval_limit`
-
-The tracking issue for this feature is: [#67217]
-
-[#67217]: https://github.com/rust-lang/rust/issues/67217
-
-The `const_eval_limit` allows someone to limit the evaluation steps the CTFE undertakes to evaluate a `const fn`.
-"##,
-    },
-    Lint {
+        description: r##"Allows limiting the evaluation steps of const expressions"##,
+        status: FeatureStatus::Removed,
+        since: Some("1.43.0"),
+    },
+    Feature {
+        label: "const_evaluatable_checked",
+        description: r##"Allows non-trivial generic constants which have to be manually propagated upwards."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.48.0"),
+    },
+    Feature {
+        label: "const_extern_fn",
+        description: r##"Allows the definition of `const extern fn` and `const unsafe extern fn`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.40.0"),
+    },
+    Feature {
+        label: "const_fn",
+        description: r##"Allows the definition of `const` functions with some advanced features."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.54.0"),
+    },
+    Feature {
+        label: "const_fn_floating_point_arithmetic",
+        description: r##"Allows basic arithmetic on floating point types in a `const fn`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.48.0"),
+    },
+    Feature {
+        label: "const_fn_fn_ptr_basics",
+        description: r##"Allows using and casting function pointers in a `const fn`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.61.0"),
+    },
+    Feature {
+        label: "const_fn_trait_bound",
+        description: r##"Allows trait bounds in `const fn`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.61.0"),
+    },
+    Feature {
+        label: "const_fn_transmute",
+        description: r##"Allows calling `transmute` in const fn"##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.56.0"),
+    },
+    Feature {
+        label: "const_fn_union",
+        description: r##"Allows accessing fields of unions inside `const` functions."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.56.0"),
+    },
+    Feature {
+        label: "const_fn_unsize",
+        description: r##"Allows unsizing coercions in `const fn`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.54.0"),
+    },
+    Feature {
+        label: "const_for",
+        description: r##"Allows `for _ in _` loops in const contexts."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.56.0"),
+    },
+    Feature {
+        label: "const_generics",
+        description: r##"Allows const generic types (e.g. `struct Foo<const N: usize>(...);`)."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.34.0"),
+    },
+    Feature {
+        label: "const_generics_defaults",
+        description: r##"Allows const generics to have default values (e.g. `struct Foo<const N: usize = 3>(...);`)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.59.0"),
+    },
+    Feature {
+        label: "const_if_match",
+        description: r##"Allows the use of `if` and `match` in constants."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.46.0"),
+    },
+    Feature {
+        label: "const_impl_trait",
+        description: r##"Allows argument and return position `impl Trait` in a `const fn`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.61.0"),
+    },
+    Feature {
+        label: "const_in_array_repeat_expressions",
+        description: r##"Allows `[x; N]` where `x` is a constant (RFC 2203)."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.37.0"),
+    },
+    Feature {
+        label: "const_indexing",
+        description: r##"Allows indexing into constant arrays."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.26.0"),
+    },
+    Feature {
+        label: "const_let",
+        description: r##"Allows let bindings, assignments and destructuring in `const` functions and constants. As long as control flow is not implemented in const eval, `&&` and `||` may not be used at the same time as let bindings."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.33.0"),
+    },
+    Feature {
+        label: "const_loop",
+        description: r##"Allows the use of `loop` and `while` in constants."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.46.0"),
+    },
+    Feature {
+        label: "const_mut_refs",
+        description: r##"Allows using `&mut` in constant functions."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.41.0"),
+    },
+    Feature {
+        label: "const_panic",
+        description: r##"Allows panicking during const eval (producing compile-time errors)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.57.0"),
+    },
+    Feature {
+        label: "const_precise_live_drops",
+        description: r##"Be more precise when looking for live drops in a const context."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.46.0"),
+    },
+    Feature {
+        label: "const_raw_ptr_deref",
+        description: r##"Allows dereferencing raw pointers during const eval."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.58.0"),
+    },
+    Feature {
+        label: "const_raw_ptr_to_usize_cast",
+        description: r##"Allows casting raw pointers to `usize` during const eval."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.55.0"),
+    },
+    Feature {
+        label: "const_refs_to_cell",
+        description: r##"Allows references to types with interior mutability within constants"##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.51.0"),
+    },
+    Feature {
+        label: "const_trait_bound_opt_out",
+        description: r##"Allows `T: ?const Trait` syntax in bounds."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.42.0"),
+    },
+    Feature {
+        label: "const_trait_impl",
+        description: r##"Allows `impl const Trait for T` syntax."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.42.0"),
+    },
+    Feature {
+        label: "const_try",
+        description: r##"Allows the `?` operator in const contexts."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.56.0"),
+    },
+    Feature {
+        label: "copy_closures",
+        description: r##"Allows implementing `Copy` for closures where possible (RFC 2132)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.26.0"),
+    },
+    Feature {
         label: "core_intrinsics",
         description: r##"# `core_intrinsics`
 
@@ -1724,8 +2780,10 @@ fn foobar() -> u32 { 23 }
 
 ------------------------
 "##,
+        status: FeatureStatus::Unstable,
+        since: None,
     },
-    Lint {
+    Feature {
         label: "core_panic",
         description: r##"# `core_panic`
 
@@ -1733,8 +2791,10 @@ fn foobar() -> u32 { 23 }
 
 ------------------------
 "##,
+        status: FeatureStatus::Unstable,
+        since: None,
     },
-    Lint {
+    Feature {
         label: "core_private_bignum",
         description: r##"# `core_private_bignum`
 
@@ -1742,8 +2802,10 @@ fn foobar() -> u32 { 23 }
 
 ------------------------
 "##,
+        status: FeatureStatus::Unstable,
+        since: None,
     },
-    Lint {
+    Feature {
         label: "core_private_diy_float",
         description: r##"# `core_private_diy_float`
 
@@ -1751,32 +2813,46 @@ fn foobar() -> u32 { 23 }
 
 ------------------------
 "##,
+        status: FeatureStatus::Unstable,
+        since: None,
     },
-    Lint {
-        label: "crate_visibility_modifier",
-        description: r##"# `crate_visibility_modifier`
-
-The tracking issue for this feature is: [#53120]
-
-[#53120]: https://github.com/rust-lang/rust/issues/53120
-
------
-
-The `crate_visibility_modifier` feature allows the `crate` keyword to be used
-as a visibility modifier synonymous to `pub(crate)`, indicating that a type
-(function, _&c._) is to be visible to the entire enclosing crate, but not to
-other crates.
-
-```rust
-#![feature(crate_visibility_modifier)]
-
-crate struct Foo {
-    bar: usize,
-}
-```
-"##,
+    Feature {
+        label: "crate_in_paths",
+        description: r##"Allows `crate` in paths."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.30.0"),
     },
-    Lint {
+    Feature {
+        label: "crate_visibility_modifier",
+        description: r##"Allows using `crate` as visibility modifier, synonymous with `pub(crate)`."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.63.0"),
+    },
+    Feature {
+        label: "custom_attribute",
+        description: r##"Allows using custom attributes (RFC 572)."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "custom_derive",
+        description: r##"Allows the use of `#[derive(Anything)]` as sugar for `#[derive_Anything]`."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.32.0"),
+    },
+    Feature {
+        label: "custom_inner_attributes",
+        description: r##"Allows non-builtin attributes in inner attribute position."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.30.0"),
+    },
+    Feature {
+        label: "custom_mir",
+        description: r##"Allows writing custom MIR"##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.65.0"),
+    },
+    Feature {
         label: "custom_test_frameworks",
         description: r##"# `custom_test_frameworks`
 
@@ -1811,8 +2887,16 @@ fn my_runner(tests: &[&i32]) {
 const WILL_FAIL: i32 = 4;
 ```
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.30.0"),
     },
-    Lint {
+    Feature {
+        label: "debugger_visualizer",
+        description: r##"Allows using `#[debugger_visualizer]` attribute."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.71.0"),
+    },
+    Feature {
         label: "dec2flt",
         description: r##"# `dec2flt`
 
@@ -1820,8 +2904,22 @@ fn my_runner(tests: &[&i32]) {
 
 ------------------------
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: None,
+    },
+    Feature {
+        label: "decl_macro",
+        description: r##"Allows declarative macros 2.0 (`macro`)."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.17.0"),
+    },
+    Feature {
+        label: "default_alloc_error_handler",
+        description: r##"Allows rustc to inject a default alloc_error_handler"##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.68.0"),
+    },
+    Feature {
         label: "default_free_fn",
         description: r##"# `default_free_fn`
 
@@ -1835,7 +2933,7 @@ fn my_runner(tests: &[&i32]) {
 just forwards to [`Default::default()`], but may remove repetition of the word
 "default" from the call site.
 
-[`Default::default()`]: https://doc.rust-lang.org/nightly/std/default/trait.Default.html#tymethod.default
+[`Default::default()`]: ../../std/default/trait.Default.html#tymethod.default
 
 Here is an example:
 
@@ -1871,8 +2969,40 @@ fn main() {
 }
 ```
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: None,
+    },
+    Feature {
+        label: "default_type_parameter_fallback",
+        description: r##"Allows default type parameters to influence type inference."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.3.0"),
+    },
+    Feature {
+        label: "default_type_params",
+        description: r##"Allows using assigning a default type to type parameters in algebraic data type definitions."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "deprecated",
+        description: r##"Allows `#[deprecated]` attribute."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.9.0"),
+    },
+    Feature {
+        label: "deprecated_safe",
+        description: r##"Allows using `#[deprecated_safe]` to deprecate the safeness of a function or trait"##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.61.0"),
+    },
+    Feature {
+        label: "deprecated_suggestion",
+        description: r##"Allows having using `suggestion` in the `#[deprecated]` attribute."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.61.0"),
+    },
+    Feature {
         label: "derive_clone_copy",
         description: r##"# `derive_clone_copy`
 
@@ -1880,8 +3010,16 @@ fn main() {
 
 ------------------------
 "##,
+        status: FeatureStatus::Unstable,
+        since: None,
     },
-    Lint {
+    Feature {
+        label: "derive_default_enum",
+        description: r##"Allows `#[derive(Default)]` and `#[default]` on enums."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.62.0"),
+    },
+    Feature {
         label: "derive_eq",
         description: r##"# `derive_eq`
 
@@ -1889,8 +3027,34 @@ fn main() {
 
 ------------------------
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: None,
+    },
+    Feature {
+        label: "destructuring_assignment",
+        description: r##"Allows the use of destructuring assignments."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.59.0"),
+    },
+    Feature {
+        label: "do_not_recommend",
+        description: r##"Controls errors in trait implementations."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.67.0"),
+    },
+    Feature {
+        label: "doc_alias",
+        description: r##"Allows `#[doc(alias = "...")]`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.48.0"),
+    },
+    Feature {
+        label: "doc_auto_cfg",
+        description: r##"Tells rustdoc to automatically generate `#[doc(cfg(...))]`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.58.0"),
+    },
+    Feature {
         label: "doc_cfg",
         description: r##"# `doc_cfg`
 
@@ -1901,7 +3065,7 @@ fn main() {
 The `doc_cfg` feature allows an API be documented as only available in some specific platforms.
 This attribute has two effects:
 
-1. In the annotated item's documentation, there will be a message saying "This is supported on
+1. In the annotated item's documentation, there will be a message saying "Available on
     (platform) only".
 
 2. The item's doc-tests will only run on the specific platform.
@@ -1939,8 +3103,22 @@ pub struct Icon {
 [#43781]: https://github.com/rust-lang/rust/issues/43781
 [#43348]: https://github.com/rust-lang/rust/issues/43348
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.21.0"),
+    },
+    Feature {
+        label: "doc_cfg_hide",
+        description: r##"Allows `#[doc(cfg_hide(...))]`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.57.0"),
+    },
+    Feature {
+        label: "doc_keyword",
+        description: r##"Allows using `#[doc(keyword = "...")]`."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.28.0"),
+    },
+    Feature {
         label: "doc_masked",
         description: r##"# `doc_masked`
 
@@ -1967,8 +3145,10 @@ pub struct Icon {
 [#44026]: https://github.com/rust-lang/rust/pull/44026
 [#44027]: https://github.com/rust-lang/rust/pull/44027
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.21.0"),
     },
-    Lint {
+    Feature {
         label: "doc_notable_trait",
         description: r##"# `doc_notable_trait`
 
@@ -2004,8 +3184,76 @@ pub fn my_fn() -> MyStruct { MyStruct }
 [#45039]: https://github.com/rust-lang/rust/pull/45039
 [rustdoc-book-notable_trait]: ../../rustdoc/unstable-features.html#adding-your-trait-to-the-notable-traits-dialog
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.52.0"),
+    },
+    Feature {
+        label: "doc_primitive",
+        description: r##"Allows using `doc(primitive)` without a future-incompat warning."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.56.0"),
+    },
+    Feature {
+        label: "doc_spotlight",
+        description: r##"Allows `#[doc(spotlight)]`. The attribute was renamed to `#[doc(notable_trait)]` and the feature to `doc_notable_trait`."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.22.0"),
+    },
+    Feature {
+        label: "dotdot_in_tuple_patterns",
+        description: r##"Allows `..` in tuple (struct) patterns."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.14.0"),
+    },
+    Feature {
+        label: "dotdoteq_in_patterns",
+        description: r##"Allows `..=` in patterns (RFC 1192)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.26.0"),
+    },
+    Feature {
+        label: "drop_types_in_const",
+        description: r##"Allows `Drop` types in constants (RFC 1440)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.22.0"),
+    },
+    Feature {
+        label: "dropck_eyepatch",
+        description: r##"Allows using the `may_dangle` attribute (RFC 1327)."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.10.0"),
+    },
+    Feature {
+        label: "dropck_parametricity",
+        description: r##"Allows using `#[unsafe_destructor_blind_to_params]` (RFC 1238)."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.38.0"),
+    },
+    Feature {
+        label: "dyn_star",
+        description: r##"Allows `dyn* Trait` objects."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.65.0"),
+    },
+    Feature {
+        label: "dyn_trait",
+        description: r##"Allows using `dyn Trait` as a syntax for trait objects."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.27.0"),
+    },
+    Feature {
+        label: "effects",
+        description: r##""##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.72.0"),
+    },
+    Feature {
+        label: "ermsb_target_feature",
+        description: r##""##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.49.0"),
+    },
+    Feature {
         label: "exclusive_range_pattern",
         description: r##"# `exclusive_range_pattern`
 
@@ -2034,65 +3282,110 @@ pub fn my_fn() -> MyStruct { MyStruct }
     }
 ```
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.11.0"),
+    },
+    Feature {
+        label: "exhaustive_integer_patterns",
+        description: r##"Allows integer match exhaustiveness checking (RFC 2591)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.33.0"),
+    },
+    Feature {
+        label: "exhaustive_patterns",
+        description: r##"Allows exhaustive pattern matching on types that contain uninhabited types."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.13.0"),
+    },
+    Feature {
+        label: "existential_type",
+        description: r##"Allows defining `existential type`s."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.38.0"),
+    },
+    Feature {
         label: "explicit_generic_args_with_impl_trait",
-        description: r##"# `explicit_generic_args_with_impl_trait`
+        description: r##"Allows explicit generic arguments specification with `impl Trait` present."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.63.0"),
+    },
+    Feature {
+        label: "explicit_tail_calls",
+        description: r##"Allows explicit tail calls via `become` expression."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.72.0"),
+    },
+    Feature {
+        label: "extended_key_value_attributes",
+        description: r##"Allows arbitrary expressions in key-value attributes at parse time."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.54.0"),
+    },
+    Feature {
+        label: "extended_varargs_abi_support",
+        description: r##"# `extended_varargs_abi_support`
 
-The tracking issue for this feature is: [#83701]
+The tracking issue for this feature is: [#100189]
 
-[#83701]: https://github.com/rust-lang/rust/issues/83701
+[#100189]: https://github.com/rust-lang/rust/issues/100189
 
 ------------------------
 
-The `explicit_generic_args_with_impl_trait` feature gate lets you specify generic arguments even
-when `impl Trait` is used in argument position.
-
-A simple example is:
-
-```rust
-#![feature(explicit_generic_args_with_impl_trait)]
-
-fn foo<T: ?Sized>(_f: impl AsRef<T>) {}
-
-fn main() {
-    foo::<str>("".to_string());
-}
-```
-
-This is currently rejected:
-
-```text
-error[E0632]: cannot provide explicit generic arguments when `impl Trait` is used in argument position
- --> src/main.rs:6:11
-  |
-6 |     foo::<str>("".to_string());
-  |           ^^^ explicit generic argument not allowed
-
-```
-
-However it would compile if `explicit_generic_args_with_impl_trait` is enabled.
-
-Note that the synthetic type parameters from `impl Trait` are still implicit and you
-cannot explicitly specify these:
-
-```rust,compile_fail
-#![feature(explicit_generic_args_with_impl_trait)]
-
-fn foo<T: ?Sized>(_f: impl AsRef<T>) {}
-fn bar<T: ?Sized, F: AsRef<T>>(_f: F) {}
-
-fn main() {
-    bar::<str, _>("".to_string()); // Okay
-    bar::<str, String>("".to_string()); // Okay
-
-    foo::<str>("".to_string()); // Okay
-    foo::<str, String>("".to_string()); // Error, you cannot specify `impl Trait` explicitly
-}
-```
+This feature adds the possibility of using `sysv64`, `win64` or `efiapi` calling
+conventions on functions with varargs.
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.65.0"),
+    },
+    Feature {
+        label: "extern_absolute_paths",
+        description: r##"Allows resolving absolute paths as paths from other crates."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.30.0"),
+    },
+    Feature {
+        label: "extern_crate_item_prelude",
+        description: r##"Allows `extern crate foo as bar;`. This puts `bar` into extern prelude."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.31.0"),
+    },
+    Feature {
+        label: "extern_crate_self",
+        description: r##"Allows `extern crate self as foo;`. This puts local crate root into extern prelude under name `foo`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.34.0"),
+    },
+    Feature {
+        label: "extern_in_paths",
+        description: r##"Paths of the form: `extern::foo::bar`"##,
+        status: FeatureStatus::Removed,
+        since: Some("1.33.0"),
+    },
+    Feature {
+        label: "extern_prelude",
+        description: r##"Allows access to crate names passed via `--extern` through prelude."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.30.0"),
+    },
+    Feature {
+        label: "extern_types",
+        description: r##"Allows defining `extern type`s."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.23.0"),
+    },
+    Feature {
+        label: "external_doc",
+        description: r##"Allows `#[doc(include = "some-file")]`."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.54.0"),
+    },
+    Feature {
+        label: "f16c_target_feature",
+        description: r##"Allows using F16C intrinsics from `core::arch::{x86, x86_64}`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.68.0"),
+    },
+    Feature {
         label: "fd",
         description: r##"# `fd`
 
@@ -2100,8 +3393,10 @@ fn main() {
 
 ------------------------
 "##,
+        status: FeatureStatus::Unstable,
+        since: None,
     },
-    Lint {
+    Feature {
         label: "fd_read",
         description: r##"# `fd_read`
 
@@ -2109,8 +3404,10 @@ fn main() {
 
 ------------------------
 "##,
+        status: FeatureStatus::Unstable,
+        since: None,
     },
-    Lint {
+    Feature {
         label: "ffi_const",
         description: r##"# `ffi_const`
 
@@ -2165,8 +3462,10 @@ fn main() {
 [GCC]: https://gcc.gnu.org/onlinedocs/gcc/Common-Function-Attributes.html#index-const-function-attribute
 [IBM ILE C/C++]: https://www.ibm.com/support/knowledgecenter/fr/ssw_ibm_i_71/rzarg/fn_attrib_const.htm
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.45.0"),
     },
-    Lint {
+    Feature {
         label: "ffi_pure",
         description: r##"# `ffi_pure`
 
@@ -2225,8 +3524,22 @@ fn main() {
 [GCC]: https://gcc.gnu.org/onlinedocs/gcc/Common-Function-Attributes.html#index-pure-function-attribute
 [IBM ILE C/C++]: https://www.ibm.com/support/knowledgecenter/fr/ssw_ibm_i_71/rzarg/fn_attrib_pure.htm
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.45.0"),
+    },
+    Feature {
+        label: "ffi_returns_twice",
+        description: r##"Allows using `#[ffi_returns_twice]` on foreign functions."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.34.0"),
+    },
+    Feature {
+        label: "field_init_shorthand",
+        description: r##"Allows field shorthands (`x` meaning `x: x`) in struct literal expressions."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.17.0"),
+    },
+    Feature {
         label: "flt2dec",
         description: r##"# `flt2dec`
 
@@ -2234,8 +3547,10 @@ fn main() {
 
 ------------------------
 "##,
+        status: FeatureStatus::Unstable,
+        since: None,
     },
-    Lint {
+    Feature {
         label: "fmt_internals",
         description: r##"# `fmt_internals`
 
@@ -2243,8 +3558,22 @@ fn main() {
 
 ------------------------
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: None,
+    },
+    Feature {
+        label: "fn_align",
+        description: r##"Allows using `#[repr(align(...))]` on function items"##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.53.0"),
+    },
+    Feature {
+        label: "fn_must_use",
+        description: r##"Allows `#[must_use]` on functions, and introduces must-use operators (RFC 1940)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.27.0"),
+    },
+    Feature {
         label: "fn_traits",
         description: r##"# `fn_traits`
 
@@ -2259,7 +3588,7 @@ fn main() {
 The `fn_traits` feature allows for implementation of the [`Fn*`] traits
 for creating custom closure-like types.
 
-[`Fn*`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+[`Fn*`]: ../../std/ops/trait.Fn.html
 
 ```rust
 #![feature(unboxed_closures)]
@@ -2282,8 +3611,28 @@ fn main() {
 }
 ```
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: None,
+    },
+    Feature {
+        label: "format_args_capture",
+        description: r##"Allows capturing variables in scope using format_args!"##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.58.0"),
+    },
+    Feature {
+        label: "fundamental",
+        description: r##"Allows using the `#[fundamental]` attribute."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "generator_clone",
+        description: r##"Allows generators to be cloned."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.65.0"),
+    },
+    Feature {
         label: "generators",
         description: r##"# `generators`
 
@@ -2532,87 +3881,202 @@ fn resume(mut self: Pin<&mut Self>, resume: ()) -> GeneratorState<i32, &'static
 is just a rough desugaring, not a normative specification for what the compiler
 does.
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.21.0"),
+    },
+    Feature {
+        label: "generic_arg_infer",
+        description: r##"Infer generic args for both consts and types."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.55.0"),
+    },
+    Feature {
+        label: "generic_assert",
+        description: r##"Outputs useful `assert!` messages"##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.63.0"),
+    },
+    Feature {
+        label: "generic_associated_types",
+        description: r##"Allows associated types to be generic, e.g., `type Foo<T>;` (RFC 1598)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.65.0"),
+    },
+    Feature {
+        label: "generic_associated_types_extended",
+        description: r##"An extension to the `generic_associated_types` feature, allowing incomplete features."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.61.0"),
+    },
+    Feature {
+        label: "generic_const_exprs",
+        description: r##"Allows non-trivial generic constants which have to have wfness manually propagated to callers"##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.56.0"),
+    },
+    Feature {
+        label: "generic_param_attrs",
+        description: r##"Allows attributes on lifetime/type formal parameters in generics (RFC 1327)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.27.0"),
+    },
+    Feature {
+        label: "global_allocator",
+        description: r##"Allows the `#[global_allocator]` attribute."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.28.0"),
+    },
+    Feature {
+        label: "globs",
+        description: r##""##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.0.0"),
+    },
+    Feature {
         label: "half_open_range_patterns",
-        description: r##"# `half_open_range_patterns`
+        description: r##"Allows using `..=X` as a pattern."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.66.0"),
+    },
+    Feature {
+        label: "half_open_range_patterns_in_slices",
+        description: r##"# `half_open_range_patterns_in_slices`
 
 The tracking issue for this feature is: [#67264]
-It is part of the `#![exclusive_range_pattern]` feature,
+It is part of the `exclusive_range_pattern` feature,
 tracked at [#37854].
 
 [#67264]: https://github.com/rust-lang/rust/issues/67264
 [#37854]: https://github.com/rust-lang/rust/issues/37854
 -----
 
-The `half_open_range_patterns` feature allows RangeTo patterns
-(`..10`) to be used in appropriate pattern matching contexts.
-This requires also enabling the `exclusive_range_pattern` feature.
-
-It also enabled RangeFrom patterns but that has since been
-stabilized.
+This feature allow using top-level half-open range patterns in slices.
 
 ```rust
-#![feature(half_open_range_patterns)]
+#![feature(half_open_range_patterns_in_slices)]
 #![feature(exclusive_range_pattern)]
-    let x = 5;
-    match x {
-        ..0 => println!("negative!"), // "RangeTo" pattern. Unstable.
-        0 => println!("zero!"),
-        1.. => println!("positive!"), // "RangeFrom" pattern. Stable.
-    }
-```
-"##,
-    },
-    Lint {
-        label: "infer_static_outlives_requirements",
-        description: r##"# `infer_static_outlives_requirements`
 
-The tracking issue for this feature is: [#54185]
-
-[#54185]: https://github.com/rust-lang/rust/issues/54185
-
-------------------------
-The `infer_static_outlives_requirements` feature indicates that certain
-`'static` outlives requirements can be inferred by the compiler rather than
-stating them explicitly.
-
-Note: It is an accompanying feature to `infer_outlives_requirements`,
-which must be enabled to infer outlives requirements.
-
-For example, currently generic struct definitions that contain
-references, require where-clauses of the form T: 'static. By using
-this feature the outlives predicates will be inferred, although
-they may still be written explicitly.
-
-```rust,ignore (pseudo-Rust)
-struct Foo<U> where U: 'static { // <-- currently required
-    bar: Bar<U>
-}
-struct Bar<T: 'static> {
-    x: T,
+fn main() {
+    let xs = [13, 1, 5, 2, 3, 1, 21, 8];
+    let [a @ 3.., b @ ..3, c @ 4..6, ..] = xs else { return; };
 }
 ```
 
+Note that this feature is not required if the patterns are wrapped between parenthesis.
 
-## Examples:
-
-```rust,ignore (pseudo-Rust)
-#![feature(infer_outlives_requirements)]
-#![feature(infer_static_outlives_requirements)]
-
-#[rustc_outlives]
-// Implicitly infer U: 'static
-struct Foo<U> {
-    bar: Bar<U>
-}
-struct Bar<T: 'static> {
-    x: T,
+```rust
+fn main() {
+    let xs = [13, 1];
+    let [(a @ 3..), c] = xs else { return; };
 }
 ```
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.66.0"),
+    },
+    Feature {
+        label: "hexagon_target_feature",
+        description: r##""##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.27.0"),
+    },
+    Feature {
+        label: "i128_type",
+        description: r##"Allows using the `u128` and `i128` types."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.26.0"),
+    },
+    Feature {
+        label: "if_let",
+        description: r##"Allows the use of `if let` expressions."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "if_let_guard",
+        description: r##"Allows `if let` guard in match arms."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.47.0"),
+    },
+    Feature {
+        label: "if_while_or_patterns",
+        description: r##"Allows top level or-patterns (`p | q`) in `if let` and `while let`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.33.0"),
+    },
+    Feature {
+        label: "impl_header_lifetime_elision",
+        description: r##"Allows lifetime elision in `impl` headers. For example: + `impl<I:Iterator> Iterator for &mut Iterator` + `impl Debug for Foo<'_>`"##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.31.0"),
+    },
+    Feature {
+        label: "impl_trait_in_assoc_type",
+        description: r##"Allows `impl Trait` to be used inside associated types (RFC 2515)."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.70.0"),
+    },
+    Feature {
+        label: "impl_trait_in_bindings",
+        description: r##"Allows `impl Trait` in bindings (`let`, `const`, `static`)."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.55.0"),
+    },
+    Feature {
+        label: "impl_trait_in_fn_trait_return",
+        description: r##"Allows `impl Trait` as output type in `Fn` traits in return position of functions."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.64.0"),
+    },
+    Feature {
+        label: "impl_trait_projections",
+        description: r##"Allows referencing `Self` and projections in impl-trait."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.67.0"),
+    },
+    Feature {
+        label: "import_shadowing",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "imported_main",
+        description: r##"Allows using imported `main` function"##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.53.0"),
+    },
+    Feature {
+        label: "in_band_lifetimes",
+        description: r##"Allows in-band quantification of lifetime bindings (e.g., `fn foo(x: &'a u8) -> &'a u8`)."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.23.0"),
+    },
+    Feature {
+        label: "inclusive_range_syntax",
+        description: r##"Allows using `a..=b` and `..=b` as inclusive range syntaxes."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.26.0"),
+    },
+    Feature {
+        label: "infer_outlives_requirements",
+        description: r##"Allows inferring outlives requirements (RFC 2093)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.30.0"),
+    },
+    Feature {
+        label: "infer_static_outlives_requirements",
+        description: r##"Allows inferring `'static` outlives requirements (RFC 2093)."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.63.0"),
     },
-    Lint {
+    Feature {
+        label: "inherent_associated_types",
+        description: r##"Allows associated types in inherent impls."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.52.0"),
+    },
+    Feature {
         label: "inline_const",
         description: r##"# `inline_const`
 
@@ -2647,8 +4111,10 @@ fn main() {
 
 [#76001]: https://github.com/rust-lang/rust/issues/76001
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.49.0"),
     },
-    Lint {
+    Feature {
         label: "inline_const_pat",
         description: r##"# `inline_const_pat`
 
@@ -2675,17 +4141,10 @@ const fn one() -> i32 { 1 }
 
 [#76001]: https://github.com/rust-lang/rust/issues/76001
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.58.0"),
     },
-    Lint {
-        label: "int_error_internals",
-        description: r##"# `int_error_internals`
-
-This feature is internal to the Rust compiler and is not intended for general use.
-
-------------------------
-"##,
-    },
-    Lint {
+    Feature {
         label: "internal_output_capture",
         description: r##"# `internal_output_capture`
 
@@ -2693,8 +4152,10 @@ const fn one() -> i32 { 1 }
 
 ------------------------
 "##,
+        status: FeatureStatus::Unstable,
+        since: None,
     },
-    Lint {
+    Feature {
         label: "intra_doc_pointers",
         description: r##"# `intra-doc-pointers`
 
@@ -2712,8 +4173,10 @@ const fn one() -> i32 { 1 }
 //! [pointer::add]
 ```
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.51.0"),
     },
-    Lint {
+    Feature {
         label: "intrinsics",
         description: r##"# `intrinsics`
 
@@ -2739,14 +4202,22 @@ const fn one() -> i32 { 1 }
 extern "rust-intrinsic" {
     fn transmute<T, U>(x: T) -> U;
 
-    fn offset<T>(dst: *const T, offset: isize) -> *const T;
+    fn arith_offset<T>(dst: *const T, offset: isize) -> *const T;
 }
 ```
 
 As with any other FFI functions, these are always `unsafe` to call.
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.0.0"),
     },
-    Lint {
+    Feature {
+        label: "irrefutable_let_patterns",
+        description: r##"Allows irrefutable patterns in `if let` and `while let` statements (RFC 2086)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.33.0"),
+    },
+    Feature {
         label: "is_sorted",
         description: r##"# `is_sorted`
 
@@ -2760,8 +4231,34 @@ const fn one() -> i32 { 1 }
 add the methods `is_sorted`, `is_sorted_by` and `is_sorted_by_key` to
 `Iterator`.
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: None,
+    },
+    Feature {
+        label: "isa_attribute",
+        description: r##"Allows `#[instruction_set(_)]` attribute."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.67.0"),
+    },
+    Feature {
+        label: "issue_5723_bootstrap",
+        description: r##"A temporary feature gate used to enable parser extensions needed to bootstrap fix for #5723."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "item_like_imports",
+        description: r##"Allows some increased flexibility in the name resolution rules, especially around globs and shadowing (RFC 1560)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.15.0"),
+    },
+    Feature {
+        label: "label_break_value",
+        description: r##"Allows `'a: { break 'a; }`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.65.0"),
+    },
+    Feature {
         label: "lang_items",
         description: r##"# `lang_items`
 
@@ -2776,20 +4273,30 @@ const fn one() -> i32 { 1 }
 various different values of `...`, i.e. various different 'lang
 items'.
 
-For example, `Box` pointers require two lang items, one for allocation
-and one for deallocation. A freestanding program that uses the `Box`
+For example, `Box` pointers require a lang item for allocation.
+A freestanding program that uses the `Box`
 sugar for dynamic allocations via `malloc` and `free`:
 
 ```rust,ignore (libc-is-finicky)
-#![feature(lang_items, box_syntax, start, libc, core_intrinsics, rustc_private)]
+#![feature(lang_items, start, libc, core_intrinsics, rustc_private, rustc_attrs)]
 #![no_std]
 use core::intrinsics;
 use core::panic::PanicInfo;
+use core::ptr::NonNull;
 
 extern crate libc;
 
+struct Unique<T>(NonNull<T>);
+
 #[lang = "owned_box"]
-pub struct Box<T>(*mut T);
+pub struct Box<T>(Unique<T>);
+
+impl<T> Box<T> {
+    pub fn new(x: T) -> Self {
+        #[rustc_box]
+        Box::new(x)
+    }
+}
 
 #[lang = "exchange_malloc"]
 unsafe fn allocate(size: usize, _align: usize) -> *mut u8 {
@@ -2803,20 +4310,21 @@ unsafe fn allocate(size: usize, _align: usize) -> *mut u8 {
     p
 }
 
-#[lang = "box_free"]
-unsafe fn box_free<T: ?Sized>(ptr: *mut T) {
-    libc::free(ptr as *mut libc::c_void)
+impl<T> Drop for Box<T> {
+    fn drop(&mut self) {
+      libc::free(self.0.0.0 as *mut libc::c_void)
+    }
 }
 
 #[start]
 fn main(_argc: isize, _argv: *const *const u8) -> isize {
-    let _x = box 1;
+    let _x = Box::new(1);
 
     0
 }
 
 #[lang = "eh_personality"] extern fn rust_eh_personality() {}
-#[lang = "panic_impl"] extern fn rust_begin_panic(info: &PanicInfo) -> ! { unsafe { intrinsics::abort() } }
+#[lang = "panic_impl"] extern fn rust_begin_panic(_info: &PanicInfo) -> ! { intrinsics::abort() }
 #[no_mangle] pub extern fn rust_eh_register_frames () {}
 #[no_mangle] pub extern fn rust_eh_unregister_frames () {}
 ```
@@ -2839,8 +4347,8 @@ fn main(_argc: isize, _argv: *const *const u8) -> isize {
   `contravariant_lifetime`, etc.
 
 Lang items are loaded lazily by the compiler; e.g. if one never uses
-`Box` then there is no need to define functions for `exchange_malloc`
-and `box_free`. `rustc` will emit an error when an item is needed
+`Box` then there is no need to define a function for `exchange_malloc`.
+`rustc` will emit an error when an item is needed
 but not found in the current crate or any that it depends on.
 
 Most lang items are defined by `libcore`, but if you're trying to build
@@ -3005,7 +4513,6 @@ fn start(_argc: isize, _argv: *const *const u8) -> isize {
 - Allocations
   - `owned_box`: `liballoc/boxed.rs`
   - `exchange_malloc`: `liballoc/heap.rs`
-  - `box_free`: `liballoc/heap.rs`
 - Operands
   - `not`: `libcore/ops/bit.rs`
   - `bitand`: `libcore/ops/bit.rs`
@@ -3048,240 +4555,169 @@ fn start(_argc: isize, _argv: *const *const u8) -> isize {
   - `clone`: `libcore/clone.rs`
   - `copy`: `libcore/marker.rs`
   - `send`: `libcore/marker.rs`
-  - `sized`: `libcore/marker.rs`
-  - `unsize`: `libcore/marker.rs`
-  - `sync`: `libcore/marker.rs`
-  - `phantom_data`: `libcore/marker.rs`
-  - `discriminant_kind`: `libcore/marker.rs`
-  - `freeze`: `libcore/marker.rs`
-  - `debug_trait`: `libcore/fmt/mod.rs`
-  - `non_zero`: `libcore/nonzero.rs`
-  - `arc`: `liballoc/sync.rs`
-  - `rc`: `liballoc/rc.rs`
-"##,
-    },
-    Lint {
-        label: "libstd_sys_internals",
-        description: r##"# `libstd_sys_internals`
-
-This feature is internal to the Rust compiler and is not intended for general use.
-
-------------------------
-"##,
-    },
-    Lint {
-        label: "libstd_thread_internals",
-        description: r##"# `libstd_thread_internals`
-
-This feature is internal to the Rust compiler and is not intended for general use.
-
-------------------------
-"##,
-    },
-    Lint {
-        label: "link_cfg",
-        description: r##"# `link_cfg`
-
-This feature is internal to the Rust compiler and is not intended for general use.
-
-------------------------
-"##,
-    },
-    Lint {
-        label: "llvm_asm",
-        description: r##"# `llvm_asm`
-
-The tracking issue for this feature is: [#70173]
-
-[#70173]: https://github.com/rust-lang/rust/issues/70173
-
-------------------------
-
-For extremely low-level manipulations and performance reasons, one
-might wish to control the CPU directly. Rust supports using inline
-assembly to do this via the `llvm_asm!` macro.
-
-```rust,ignore (pseudo-code)
-llvm_asm!(assembly template
-   : output operands
-   : input operands
-   : clobbers
-   : options
-   );
-```
-
-Any use of `llvm_asm` is feature gated (requires `#![feature(llvm_asm)]` on the
-crate to allow) and of course requires an `unsafe` block.
-
-> **Note**: the examples here are given in x86/x86-64 assembly, but
-> all platforms are supported.
-
-## Assembly template
-
-The `assembly template` is the only required parameter and must be a
-literal string (i.e. `""`)
-
-```rust
-#![feature(llvm_asm)]
-
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-fn foo() {
-    unsafe {
-        llvm_asm!("NOP");
-    }
-}
-
-// Other platforms:
-#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
-fn foo() { /* ... */ }
-
-fn main() {
-    // ...
-    foo();
-    // ...
-}
-```
-
-(The `feature(llvm_asm)` and `#[cfg]`s are omitted from now on.)
-
-Output operands, input operands, clobbers and options are all optional
-but you must add the right number of `:` if you skip them:
-
-```rust
-# #![feature(llvm_asm)]
-# #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-# fn main() { unsafe {
-llvm_asm!("xor %eax, %eax"
-    :
-    :
-    : "eax"
-   );
-# } }
-# #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
-# fn main() {}
-```
-
-Whitespace also doesn't matter:
-
-```rust
-# #![feature(llvm_asm)]
-# #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-# fn main() { unsafe {
-llvm_asm!("xor %eax, %eax" ::: "eax");
-# } }
-# #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
-# fn main() {}
-```
-
-## Operands
-
-Input and output operands follow the same format: `:
-"constraints1"(expr1), "constraints2"(expr2), ..."`. Output operand
-expressions must be mutable place, or not yet assigned:
-
-```rust
-# #![feature(llvm_asm)]
-# #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-fn add(a: i32, b: i32) -> i32 {
-    let c: i32;
-    unsafe {
-        llvm_asm!("add $2, $0"
-             : "=r"(c)
-             : "0"(a), "r"(b)
-             );
-    }
-    c
-}
-# #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
-# fn add(a: i32, b: i32) -> i32 { a + b }
-
-fn main() {
-    assert_eq!(add(3, 14159), 14162)
-}
-```
-
-If you would like to use real operands in this position, however,
-you are required to put curly braces `{}` around the register that
-you want, and you are required to put the specific size of the
-operand. This is useful for very low level programming, where
-which register you use is important:
-
-```rust
-# #![feature(llvm_asm)]
-# #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-# unsafe fn read_byte_in(port: u16) -> u8 {
-let result: u8;
-llvm_asm!("in %dx, %al" : "={al}"(result) : "{dx}"(port));
-result
-# }
-```
-
-## Clobbers
-
-Some instructions modify registers which might otherwise have held
-different values so we use the clobbers list to indicate to the
-compiler not to assume any values loaded into those registers will
-stay valid.
-
-```rust
-# #![feature(llvm_asm)]
-# #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-# fn main() { unsafe {
-// Put the value 0x200 in eax:
-llvm_asm!("mov $$0x200, %eax" : /* no outputs */ : /* no inputs */ : "eax");
-# } }
-# #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
-# fn main() {}
-```
-
-Input and output registers need not be listed since that information
-is already communicated by the given constraints. Otherwise, any other
-registers used either implicitly or explicitly should be listed.
-
-If the assembly changes the condition code register `cc` should be
-specified as one of the clobbers. Similarly, if the assembly modifies
-memory, `memory` should also be specified.
-
-## Options
-
-The last section, `options` is specific to Rust. The format is comma
-separated literal strings (i.e. `:"foo", "bar", "baz"`). It's used to
-specify some extra info about the inline assembly:
-
-Current valid options are:
-
-1. `volatile` - specifying this is analogous to
-   `__asm__ __volatile__ (...)` in gcc/clang.
-2. `alignstack` - certain instructions expect the stack to be
-   aligned a certain way (i.e. SSE) and specifying this indicates to
-   the compiler to insert its usual stack alignment code
-3. `intel` - use intel syntax instead of the default AT&T.
+  - `sized`: `libcore/marker.rs`
+  - `unsize`: `libcore/marker.rs`
+  - `sync`: `libcore/marker.rs`
+  - `phantom_data`: `libcore/marker.rs`
+  - `discriminant_kind`: `libcore/marker.rs`
+  - `freeze`: `libcore/marker.rs`
+  - `debug_trait`: `libcore/fmt/mod.rs`
+  - `non_zero`: `libcore/nonzero.rs`
+  - `arc`: `liballoc/sync.rs`
+  - `rc`: `liballoc/rc.rs`
+"##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "large_assignments",
+        description: r##""##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.52.0"),
+    },
+    Feature {
+        label: "lazy_normalization_consts",
+        description: r##"Lazily evaluate constants. This allows constants to depend on type parameters."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.46.0"),
+    },
+    Feature {
+        label: "lazy_type_alias",
+        description: r##"Allow to have type alias types for inter-crate use."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.72.0"),
+    },
+    Feature {
+        label: "let_chains",
+        description: r##"Allows `if/while p && let q = r && ...` chains."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.37.0"),
+    },
+    Feature {
+        label: "let_else",
+        description: r##"Allows `let...else` statements."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.65.0"),
+    },
+    Feature {
+        label: "libstd_sys_internals",
+        description: r##"# `libstd_sys_internals`
 
-```rust
-# #![feature(llvm_asm)]
-# #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-# fn main() {
-let result: i32;
-unsafe {
-   llvm_asm!("mov eax, 2" : "={eax}"(result) : : : "intel")
-}
-println!("eax is currently {}", result);
-# }
-# #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
-# fn main() {}
-```
+This feature is internal to the Rust compiler and is not intended for general use.
 
-## More Information
+------------------------
+"##,
+        status: FeatureStatus::Unstable,
+        since: None,
+    },
+    Feature {
+        label: "link_args",
+        description: r##"Allows using the `#[link_args]` attribute."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.53.0"),
+    },
+    Feature {
+        label: "link_cfg",
+        description: r##"# `link_cfg`
 
-The current implementation of the `llvm_asm!` macro is a direct binding to [LLVM's
-inline assembler expressions][llvm-docs], so be sure to check out [their
-documentation as well][llvm-docs] for more information about clobbers,
-constraints, etc.
+This feature is internal to the Rust compiler and is not intended for general use.
 
-[llvm-docs]: http://llvm.org/docs/LangRef.html#inline-assembler-expressions
+------------------------
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.14.0"),
+    },
+    Feature {
+        label: "link_llvm_intrinsics",
+        description: r##"Allows using `#[link_name="llvm.*"]`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "linkage",
+        description: r##"Allows using the `#[linkage = ".."]` attribute."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "lint_reasons",
+        description: r##"Allows using `reason` in lint attributes and the `#[expect(lint)]` lint check."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.31.0"),
+    },
+    Feature {
+        label: "loop_break_value",
+        description: r##"Allows `break {expr}` with a value inside `loop`s."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.19.0"),
+    },
+    Feature {
+        label: "macro_at_most_once_rep",
+        description: r##"Allows use of `?` as the Kleene "at most one" operator in macros."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.32.0"),
+    },
+    Feature {
+        label: "macro_attributes_in_derive_output",
+        description: r##"Allows macro attributes to observe output of `#[derive]`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.57.0"),
+    },
+    Feature {
+        label: "macro_lifetime_matcher",
+        description: r##"Allows use of the `:lifetime` macro fragment specifier."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.27.0"),
+    },
+    Feature {
+        label: "macro_literal_matcher",
+        description: r##"Allows use of the `:literal` macro fragment specifier (RFC 1576)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.32.0"),
+    },
+    Feature {
+        label: "macro_metavar_expr",
+        description: r##"Give access to additional metadata about declarative macro meta-variables."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.61.0"),
+    },
+    Feature {
+        label: "macro_reexport",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "macro_rules",
+        description: r##"Allows `macro_rules!` items."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "macro_vis_matcher",
+        description: r##"Allows use of the `:vis` macro fragment specifier"##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.30.0"),
+    },
+    Feature {
+        label: "macros_in_extern",
+        description: r##"Allows macro invocations in `extern {}` blocks."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.40.0"),
+    },
+    Feature {
+        label: "main",
+        description: r##"Allows using `#[main]` to replace the entrypoint `#[lang = "start"]` calls."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.53.0"),
+    },
+    Feature {
+        label: "managed_boxes",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.0.0"),
+    },
+    Feature {
         label: "marker_trait_attr",
         description: r##"# `marker_trait_attr`
 
@@ -3319,8 +4755,64 @@ fn cheap_clone<T: CheapToClone>(t: T) -> T {
 This is expected to replace the unstable `overlapping_marker_traits`
 feature, which applied to all empty traits (without needing an opt-in).
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.30.0"),
+    },
+    Feature {
+        label: "match_beginning_vert",
+        description: r##"Allows '|' at beginning of match arms (RFC 1925)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.25.0"),
+    },
+    Feature {
+        label: "match_default_bindings",
+        description: r##"Allows default match binding modes (RFC 2005)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.26.0"),
+    },
+    Feature {
+        label: "member_constraints",
+        description: r##"Allows `impl Trait` with multiple unrelated lifetimes."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.54.0"),
+    },
+    Feature {
+        label: "min_const_fn",
+        description: r##"Allows the definition of `const fn` functions."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.31.0"),
+    },
+    Feature {
+        label: "min_const_generics",
+        description: r##"The smallest useful subset of const generics."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.51.0"),
+    },
+    Feature {
+        label: "min_const_unsafe_fn",
+        description: r##"Allows calling `const unsafe fn` inside `unsafe` blocks in `const fn` functions."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.33.0"),
+    },
+    Feature {
+        label: "min_specialization",
+        description: r##"A minimal, sound subset of specialization intended to be used by the standard library until the soundness issues with specialization are fixed."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.7.0"),
+    },
+    Feature {
+        label: "min_type_alias_impl_trait",
+        description: r##"Allows the use of type alias impl trait in function return positions"##,
+        status: FeatureStatus::Removed,
+        since: Some("1.56.0"),
+    },
+    Feature {
+        label: "mips_target_feature",
+        description: r##""##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.27.0"),
+    },
+    Feature {
         label: "more_qualified_paths",
         description: r##"# `more_qualified_paths`
 
@@ -3352,23 +4844,52 @@ impl A for Foo {
 }
 ```
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.54.0"),
+    },
+    Feature {
+        label: "more_struct_aliases",
+        description: r##"Allows using `Self` and associated types in struct expressions and patterns."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.16.0"),
+    },
+    Feature {
+        label: "movbe_target_feature",
+        description: r##"Allows using the MOVBE target feature."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.70.0"),
+    },
+    Feature {
+        label: "move_ref_pattern",
+        description: r##"Allows patterns with concurrent by-move and by-ref bindings. For example, you can write `Foo(a, ref b)` where `a` is by-move and `b` is by-ref."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.49.0"),
+    },
+    Feature {
+        label: "multiple_supertrait_upcastable",
+        description: r##"Allows the `multiple_supertrait_upcastable` lint."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.69.0"),
+    },
+    Feature {
+        label: "must_not_suspend",
+        description: r##"Allows the `#[must_not_suspend]` attribute."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.57.0"),
     },
-    Lint {
+    Feature {
+        label: "naked_functions",
+        description: r##"Allows using `#[naked]` on functions."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.9.0"),
+    },
+    Feature {
         label: "native_link_modifiers",
-        description: r##"# `native_link_modifiers`
-
-The tracking issue for this feature is: [#81490]
-
-[#81490]: https://github.com/rust-lang/rust/issues/81490
-
-------------------------
-
-The `native_link_modifiers` feature allows you to use the `modifiers` syntax with the `#[link(..)]` attribute.
-
-Modifiers are specified as a comma-delimited string with each modifier prefixed with either a `+` or `-` to indicate that the modifier is enabled or disabled, respectively. The last boolean value specified for a given modifier wins.
-"##,
+        description: r##"Allows specifying modifiers in the link attribute: `#[link(modifiers = "...")]`"##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.61.0"),
     },
-    Lint {
+    Feature {
         label: "native_link_modifiers_as_needed",
         description: r##"# `native_link_modifiers_as_needed`
 
@@ -3389,77 +4910,52 @@ impl A for Foo {
 
 The default for this modifier is unclear, some targets currently specify it as `+as-needed`, some do not. We may want to try making `+as-needed` a default for all targets.
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.53.0"),
     },
-    Lint {
+    Feature {
         label: "native_link_modifiers_bundle",
-        description: r##"# `native_link_modifiers_bundle`
-
-The tracking issue for this feature is: [#81490]
-
-[#81490]: https://github.com/rust-lang/rust/issues/81490
-
-------------------------
-
-The `native_link_modifiers_bundle` feature allows you to use the `bundle` modifier.
-
-Only compatible with the `static` linking kind. Using any other kind will result in a compiler error.
-
-`+bundle` means objects from the static library are bundled into the produced crate (a rlib, for example) and are used from this crate later during linking of the final binary.
-
-`-bundle` means the static library is included into the produced rlib "by name" and object files from it are included only during linking of the final binary, the file search by that name is also performed during final linking.
-
-This modifier is supposed to supersede the `static-nobundle` linking kind defined by [RFC 1717](https://github.com/rust-lang/rfcs/pull/1717).
-
-The default for this modifier is currently `+bundle`, but it could be changed later on some future edition boundary.
-"##,
+        description: r##"Allows specifying the bundle link modifier"##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.63.0"),
     },
-    Lint {
+    Feature {
         label: "native_link_modifiers_verbatim",
-        description: r##"# `native_link_modifiers_verbatim`
-
-The tracking issue for this feature is: [#81490]
-
-[#81490]: https://github.com/rust-lang/rust/issues/81490
-
-------------------------
-
-The `native_link_modifiers_verbatim` feature allows you to use the `verbatim` modifier.
-
-`+verbatim` means that rustc itself won't add any target-specified library prefixes or suffixes (like `lib` or `.a`) to the library name, and will try its best to ask for the same thing from the linker.
-
-For `ld`-like linkers rustc will use the `-l:filename` syntax (note the colon) when passing the library, so the linker won't add any prefixes or suffixes as well.
-See [`-l namespec`](https://sourceware.org/binutils/docs/ld/Options.html) in ld documentation for more details.
-For linkers not supporting any verbatim modifiers (e.g. `link.exe` or `ld64`) the library name will be passed as is.
-
-The default for this modifier is `-verbatim`.
-
-This RFC changes the behavior of `raw-dylib` linking kind specified by [RFC 2627](https://github.com/rust-lang/rfcs/pull/2627). The `.dll` suffix (or other target-specified suffixes for other targets) is now added automatically.
-If your DLL doesn't have the `.dll` suffix, it can be specified with `+verbatim`.
-"##,
+        description: r##"Allows specifying the verbatim link modifier"##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.67.0"),
     },
-    Lint {
+    Feature {
         label: "native_link_modifiers_whole_archive",
-        description: r##"# `native_link_modifiers_whole_archive`
-
-The tracking issue for this feature is: [#81490]
-
-[#81490]: https://github.com/rust-lang/rust/issues/81490
-
-------------------------
-
-The `native_link_modifiers_whole_archive` feature allows you to use the `whole-archive` modifier.
-
-Only compatible with the `static` linking kind. Using any other kind will result in a compiler error.
-
-`+whole-archive` means that the static library is linked as a whole archive without throwing any object files away.
-
-This modifier translates to `--whole-archive` for `ld`-like linkers, to `/WHOLEARCHIVE` for `link.exe`, and to `-force_load` for `ld64`.
-The modifier does nothing for linkers that don't support it.
-
-The default for this modifier is `-whole-archive`.
-"##,
-    },
-    Lint {
+        description: r##"Allows specifying the whole-archive link modifier"##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.61.0"),
+    },
+    Feature {
+        label: "needs_allocator",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.4.0"),
+    },
+    Feature {
+        label: "needs_panic_runtime",
+        description: r##"Allows declaring with `#![needs_panic_runtime]` that a panic runtime is needed."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.10.0"),
+    },
+    Feature {
+        label: "negate_unsigned",
+        description: r##"Allows use of unary negate on unsigned integers, e.g., -e for e: u8"##,
+        status: FeatureStatus::Removed,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "negative_bounds",
+        description: r##"Allow negative trait bounds. This is an internal-only feature for testing the trait solver!"##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.71.0"),
+    },
+    Feature {
         label: "negative_impls",
         description: r##"# `negative_impls`
 
@@ -3519,8 +5015,34 @@ impl at all, but that is not an option for auto traits. A workaround
 * For proving the correctness of unsafe code, we can use that impl as evidence that no `DerefMut` or `Clone` impl exists.
 * It prevents downstream crates from creating such impls.
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.44.0"),
+    },
+    Feature {
+        label: "never_type",
+        description: r##"Allows the `!` type. Does not imply 'exhaustive_patterns' (below) any more."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.13.0"),
+    },
+    Feature {
+        label: "never_type_fallback",
+        description: r##"Allows diverging expressions to fall back to `!` rather than `()`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.41.0"),
+    },
+    Feature {
+        label: "nll",
+        description: r##"Allows using non lexical lifetimes (RFC 2094)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.63.0"),
+    },
+    Feature {
+        label: "no_core",
+        description: r##"Allows `#![no_core]`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.3.0"),
+    },
+    Feature {
         label: "no_coverage",
         description: r##"# `no_coverage`
 
@@ -3553,8 +5075,16 @@ fn bar() {
 }
 ```
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.53.0"),
     },
-    Lint {
+    Feature {
+        label: "no_debug",
+        description: r##"Allows `#[no_debug]`."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.43.0"),
+    },
+    Feature {
         label: "no_sanitize",
         description: r##"# `no_sanitize`
 
@@ -3586,8 +5116,142 @@ fn foo() {
 }
 ```
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.42.0"),
+    },
+    Feature {
+        label: "no_stack_check",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "no_std",
+        description: r##"Allows using `#![no_std]`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.6.0"),
+    },
+    Feature {
+        label: "non_ascii_idents",
+        description: r##"Allows defining identifiers beyond ASCII."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.53.0"),
+    },
+    Feature {
+        label: "non_exhaustive",
+        description: r##"Allows future-proofing enums/structs with the `#[non_exhaustive]` attribute (RFC 2008)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.40.0"),
+    },
+    Feature {
+        label: "non_exhaustive_omitted_patterns_lint",
+        description: r##"Allows using the `non_exhaustive_omitted_patterns` lint."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.57.0"),
+    },
+    Feature {
+        label: "non_lifetime_binders",
+        description: r##"Allows `for<T>` binders in where-clauses"##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.69.0"),
+    },
+    Feature {
+        label: "non_modrs_mods",
+        description: r##"Allows `foo.rs` as an alternative to `foo/mod.rs`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.30.0"),
+    },
+    Feature {
+        label: "object_safe_for_dispatch",
+        description: r##"Allows making `dyn Trait` well-formed even if `Trait` is not object safe. In that case, `dyn Trait: Trait` does not hold. Moreover, coercions and casts in safe Rust to `dyn Trait` for such a `Trait` is also forbidden."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.40.0"),
+    },
+    Feature {
+        label: "omit_gdb_pretty_printer_section",
+        description: r##"Allows using `#[omit_gdb_pretty_printer_section]`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.5.0"),
+    },
+    Feature {
+        label: "on_unimplemented",
+        description: r##"Allows using `#[on_unimplemented(..)]` on traits. (Moved to `rustc_attrs`.)"##,
+        status: FeatureStatus::Removed,
+        since: Some("1.40.0"),
+    },
+    Feature {
+        label: "opt_out_copy",
+        description: r##"A way to temporarily opt out of opt-in copy. This will *never* be accepted."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "optimize_attribute",
+        description: r##"Allows using `#[optimize(X)]`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.34.0"),
+    },
+    Feature {
+        label: "optin_builtin_traits",
+        description: r##"Allows features specific to OIBIT (now called auto traits). Renamed to `auto_traits`."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "or_patterns",
+        description: r##"Allows the use of or-patterns (e.g., `0 | 1`)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.53.0"),
+    },
+    Feature {
+        label: "overlapping_marker_traits",
+        description: r##"Allows overlapping impls of marker traits."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.42.0"),
+    },
+    Feature {
+        label: "packed_bundled_libs",
+        description: r##"Allows using `+bundled,+whole-archive` native libs."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.69.0"),
+    },
+    Feature {
+        label: "panic_handler",
+        description: r##"Allows annotating functions conforming to `fn(&PanicInfo) -> !` with `#[panic_handler]`. This defines the behavior of panics."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.30.0"),
+    },
+    Feature {
+        label: "panic_implementation",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.28.0"),
+    },
+    Feature {
+        label: "panic_runtime",
+        description: r##"Allows using the `#![panic_runtime]` attribute."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.10.0"),
+    },
+    Feature {
+        label: "param_attrs",
+        description: r##"Allows attributes in formal function parameters."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.39.0"),
+    },
+    Feature {
+        label: "pattern_parentheses",
+        description: r##"Allows parentheses in patterns."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.31.0"),
+    },
+    Feature {
+        label: "platform_intrinsics",
+        description: r##"Allows `extern "platform-intrinsic" { ... }`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.4.0"),
+    },
+    Feature {
         label: "plugin",
         description: r##"# `plugin`
 
@@ -3628,7 +5292,7 @@ fn foo() {
 that warns about any item named `lintme`.
 
 ```rust,ignore (requires-stage-2)
-#![feature(box_syntax, rustc_private)]
+#![feature(rustc_private)]
 
 extern crate rustc_ast;
 
@@ -3659,7 +5323,7 @@ fn check_item(&mut self, cx: &EarlyContext, it: &ast::Item) {
 #[no_mangle]
 fn __rustc_plugin_registrar(reg: &mut Registry) {
     reg.lint_store.register_lints(&[&TEST_LINT]);
-    reg.lint_store.register_early_pass(|| box Pass);
+    reg.lint_store.register_early_pass(|| Box::new(Pass));
 }
 ```
 
@@ -3693,7 +5357,7 @@ fn lintme() { }
 
 Lint passes are syntax traversals, but they run at a late stage of compilation
 where type information is available. `rustc`'s [built-in
-lints](https://github.com/rust-lang/rust/blob/master/src/librustc_session/lint/builtin.rs)
+lints](https://github.com/rust-lang/rust/blob/master/compiler/rustc_lint_defs/src/builtin.rs)
 mostly use the same infrastructure as lint plugins, and provide examples of how
 to access type information.
 
@@ -3706,8 +5370,34 @@ fn lintme() { }
 You can run `rustc -W help foo.rs` to see a list of lints known to `rustc`,
 including those provided by plugins loaded by `foo.rs`.
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "plugin_registrar",
+        description: r##"Allows using `#[plugin_registrar]` on functions."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.54.0"),
+    },
+    Feature {
+        label: "powerpc_target_feature",
+        description: r##""##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.27.0"),
+    },
+    Feature {
+        label: "precise_pointer_size_matching",
+        description: r##"Allows exhaustive integer pattern matching on `usize` and `isize`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.32.0"),
+    },
+    Feature {
+        label: "prelude_import",
+        description: r##"Allows using `#[prelude_import]` on glob `use` items."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.2.0"),
+    },
+    Feature {
         label: "print_internals",
         description: r##"# `print_internals`
 
@@ -3715,8 +5405,52 @@ fn lintme() { }
 
 ------------------------
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: None,
+    },
+    Feature {
+        label: "proc_macro",
+        description: r##"Allows procedural macros in `proc-macro` crates."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.29.0"),
+    },
+    Feature {
+        label: "proc_macro_expr",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.27.0"),
+    },
+    Feature {
+        label: "proc_macro_gen",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.27.0"),
+    },
+    Feature {
+        label: "proc_macro_hygiene",
+        description: r##"Allows macro attributes on expressions, statements and non-inline modules."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.30.0"),
+    },
+    Feature {
+        label: "proc_macro_mod",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.27.0"),
+    },
+    Feature {
+        label: "proc_macro_non_items",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.27.0"),
+    },
+    Feature {
+        label: "proc_macro_path_invoc",
+        description: r##"Allows multi-segment paths in attributes and derives."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.30.0"),
+    },
+    Feature {
         label: "profiler_runtime",
         description: r##"# `profiler_runtime`
 
@@ -3724,8 +5458,10 @@ fn lintme() { }
 
 ------------------------
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.18.0"),
     },
-    Lint {
+    Feature {
         label: "profiler_runtime_lib",
         description: r##"# `profiler_runtime_lib`
 
@@ -3733,46 +5469,100 @@ fn lintme() { }
 
 ------------------------
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: None,
+    },
+    Feature {
+        label: "pub_macro_rules",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.53.0"),
+    },
+    Feature {
+        label: "pub_restricted",
+        description: r##"Allows `pub(restricted)` visibilities (RFC 1422)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.18.0"),
+    },
+    Feature {
+        label: "pushpop_unsafe",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.2.0"),
+    },
+    Feature {
+        label: "quad_precision_float",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "question_mark",
+        description: r##"Allows use of the postfix `?` operator in expressions."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.13.0"),
+    },
+    Feature {
+        label: "quote",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.33.0"),
+    },
+    Feature {
         label: "raw_dylib",
-        description: r##"# `raw_dylib`
-
-The tracking issue for this feature is: [#58713]
-
-[#58713]: https://github.com/rust-lang/rust/issues/58713
-
-------------------------
-
-The `raw_dylib` feature allows you to link against the implementations of functions in an `extern`
-block without, on Windows, linking against an import library.
-
-```rust,ignore (partial-example)
-#![feature(raw_dylib)]
-
-#[link(name="library", kind="raw-dylib")]
-extern {
-    fn extern_function(x: i32);
-}
-
-fn main() {
-    unsafe {
-        extern_function(14);
-    }
-}
-```
-
-## Limitations
-
-Currently, this feature is only supported on `-windows-msvc` targets.  Non-Windows platforms don't have import
-libraries, and an incompatibility between LLVM and the BFD linker means that it is not currently supported on
-`-windows-gnu` targets.
-
-On the `i686-pc-windows-msvc` target, this feature supports only the `cdecl`, `stdcall`, `system`, and `fastcall`
-calling conventions.
-"##,
-    },
-    Lint {
+        description: r##"Allows the use of raw-dylibs (RFC 2627)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.71.0"),
+    },
+    Feature {
+        label: "raw_identifiers",
+        description: r##"Allows keywords to be escaped for use as identifiers."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.30.0"),
+    },
+    Feature {
+        label: "raw_ref_op",
+        description: r##"Allows `&raw const $place_expr` and `&raw mut $place_expr` expressions."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.41.0"),
+    },
+    Feature {
+        label: "re_rebalance_coherence",
+        description: r##"Allows relaxing the coherence rules such that `impl<T> ForeignTrait<LocalType> for ForeignType<T>` is permitted."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.41.0"),
+    },
+    Feature {
+        label: "reflect",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "register_attr",
+        description: r##"Allows using the `#[register_attr]` attribute."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.65.0"),
+    },
+    Feature {
+        label: "register_tool",
+        description: r##"Allows using the `#[register_tool]` attribute."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.41.0"),
+    },
+    Feature {
+        label: "relaxed_adts",
+        description: r##"Allows numeric fields in struct expressions and patterns."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.19.0"),
+    },
+    Feature {
+        label: "relaxed_struct_unsize",
+        description: r##"Lessens the requirements for structs to implement `Unsize`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.58.0"),
+    },
+    Feature {
         label: "repr128",
         description: r##"# `repr128`
 
@@ -3793,8 +5583,58 @@ enum Foo {
 }
 ```
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.16.0"),
+    },
+    Feature {
+        label: "repr_align",
+        description: r##"Allows `repr(align(16))` struct attribute (RFC 1358)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.25.0"),
+    },
+    Feature {
+        label: "repr_align_enum",
+        description: r##"Allows using `#[repr(align(X))]` on enums with equivalent semantics to wrapping an enum in a wrapper struct with `#[repr(align(X))]`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.37.0"),
+    },
+    Feature {
+        label: "repr_packed",
+        description: r##"Allows `#[repr(packed(N))]` attribute on structs."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.33.0"),
+    },
+    Feature {
+        label: "repr_simd",
+        description: r##"Allows `repr(simd)` and importing the various simd intrinsics."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.4.0"),
+    },
+    Feature {
+        label: "repr_transparent",
+        description: r##"Allows `#[repr(transparent)]` attribute on newtype structs."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.28.0"),
+    },
+    Feature {
+        label: "return_position_impl_trait_in_trait",
+        description: r##"Allows return-position `impl Trait` in traits."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.65.0"),
+    },
+    Feature {
+        label: "return_type_notation",
+        description: r##"Allows bounding the return type of AFIT/RPITIT."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.70.0"),
+    },
+    Feature {
+        label: "riscv_target_feature",
+        description: r##""##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.45.0"),
+    },
+    Feature {
         label: "rt",
         description: r##"# `rt`
 
@@ -3802,8 +5642,28 @@ enum Foo {
 
 ------------------------
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: None,
+    },
+    Feature {
+        label: "rtm_target_feature",
+        description: r##""##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.35.0"),
+    },
+    Feature {
+        label: "rust_cold_cc",
+        description: r##"Allows `extern "rust-cold"`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.63.0"),
+    },
+    Feature {
+        label: "rustc_allow_const_fn_unstable",
+        description: r##"Allows using `#[rustc_allow_const_fn_unstable]`. This is an attribute on `const fn` for the same purpose as `#[allow_internal_unstable]`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.49.0"),
+    },
+    Feature {
         label: "rustc_attrs",
         description: r##"# `rustc_attrs`
 
@@ -3859,8 +5719,82 @@ pub enum X {
 error: aborting due to 2 previous errors
 ```
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "rustc_diagnostic_macros",
+        description: r##"Allows using the macros: + `__diagnostic_used` + `__register_diagnostic` +`__build_diagnostic_array`"##,
+        status: FeatureStatus::Removed,
+        since: Some("1.38.0"),
+    },
+    Feature {
+        label: "rustc_private",
+        description: r##"Allows using compiler's own crates."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "rustdoc_internals",
+        description: r##"Allows using internal rustdoc features like `doc(keyword)`."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.58.0"),
+    },
+    Feature {
+        label: "rustdoc_missing_doc_code_examples",
+        description: r##"Allows using the `rustdoc::missing_doc_code_examples` lint"##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.31.0"),
+    },
+    Feature {
+        label: "rvalue_static_promotion",
+        description: r##"Allows code like `let x: &'static u32 = &42` to work (RFC 1414)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.21.0"),
+    },
+    Feature {
+        label: "sanitizer_runtime",
+        description: r##"Allows identifying crates that contain sanitizer runtimes."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.17.0"),
+    },
+    Feature {
+        label: "self_in_typedefs",
+        description: r##"Allows `Self` in type definitions (RFC 2300)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.32.0"),
+    },
+    Feature {
+        label: "self_struct_ctor",
+        description: r##"Allows `Self` struct constructor (RFC 2302)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.32.0"),
+    },
+    Feature {
+        label: "simd",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "simd_ffi",
+        description: r##"Allows the use of SIMD types in functions declared in `extern` blocks."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "slice_patterns",
+        description: r##"Allows using subslice patterns, `[a, .., b]` and `[a, xs @ .., b]`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.42.0"),
+    },
+    Feature {
+        label: "slicing_syntax",
+        description: r##"Allows use of `&foo[a..b]` as a slicing syntax."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.0.0"),
+    },
+    Feature {
         label: "sort_internals",
         description: r##"# `sort_internals`
 
@@ -3868,8 +5802,58 @@ pub enum X {
 
 ------------------------
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: None,
+    },
+    Feature {
+        label: "specialization",
+        description: r##"Allows specialization of implementations (RFC 1210)."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.7.0"),
+    },
+    Feature {
+        label: "sse4a_target_feature",
+        description: r##""##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.27.0"),
+    },
+    Feature {
+        label: "staged_api",
+        description: r##"Allows using the `#[stable]` and `#[unstable]` attributes."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "start",
+        description: r##"Allows using `#[start]` on a function indicating that it is the program entrypoint."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "static_in_const",
+        description: r##"Allows elision of `'static` lifetimes in `static`s and `const`s."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.17.0"),
+    },
+    Feature {
+        label: "static_nobundle",
+        description: r##"Allows `#[link(kind = "static-nobundle", ...)]`."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.16.0"),
+    },
+    Feature {
+        label: "static_recursion",
+        description: r##"Allows the definition recursive static items."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.17.0"),
+    },
+    Feature {
+        label: "stmt_expr_attributes",
+        description: r##"Allows attributes on expressions and non-item statements."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.6.0"),
+    },
+    Feature {
         label: "str_internals",
         description: r##"# `str_internals`
 
@@ -3877,8 +5861,98 @@ pub enum X {
 
 ------------------------
 "##,
+        status: FeatureStatus::Unstable,
+        since: None,
     },
-    Lint {
+    Feature {
+        label: "strict_provenance",
+        description: r##"# `strict_provenance`
+
+The tracking issue for this feature is: [#95228]
+
+[#95228]: https://github.com/rust-lang/rust/issues/95228
+-----
+
+The `strict_provenance` feature allows to enable the `fuzzy_provenance_casts` and `lossy_provenance_casts` lints.
+These lint on casts between integers and pointers, that are recommended against or invalid in the strict provenance model.
+The same feature gate is also used for the experimental strict provenance API in `std` (actually `core`).
+
+## Example
+
+```rust
+#![feature(strict_provenance)]
+#![warn(fuzzy_provenance_casts)]
+
+fn main() {
+    let _dangling = 16_usize as *const u8;
+    //~^ WARNING: strict provenance disallows casting integer `usize` to pointer `*const u8`
+}
+```
+"##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.61.0"),
+    },
+    Feature {
+        label: "string_deref_patterns",
+        description: r##"Allows string patterns to dereference values to match them."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.67.0"),
+    },
+    Feature {
+        label: "struct_field_attributes",
+        description: r##"Allows attributes on struct literal fields."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.20.0"),
+    },
+    Feature {
+        label: "struct_inherit",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "struct_variant",
+        description: r##"Allows struct variants `Foo { baz: u8, .. }` in enums (RFC 418)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "structural_match",
+        description: r##"Allows using `#[structural_match]` which indicates that a type is structurally matchable. FIXME: Subsumed by trait `StructuralPartialEq`, cannot move to removed until a library feature with the same name exists."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.8.0"),
+    },
+    Feature {
+        label: "target_feature",
+        description: r##"Allows `#[target_feature(...)]`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.27.0"),
+    },
+    Feature {
+        label: "target_feature_11",
+        description: r##"Allows the use of `#[target_feature]` on safe functions."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.45.0"),
+    },
+    Feature {
+        label: "tbm_target_feature",
+        description: r##""##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.27.0"),
+    },
+    Feature {
+        label: "termination_trait",
+        description: r##"Allows `fn main()` with return types which implements `Termination` (RFC 1937)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.26.0"),
+    },
+    Feature {
+        label: "termination_trait_test",
+        description: r##"Allows `#[test]` functions where the return type implements `Termination` (RFC 1937)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.27.0"),
+    },
+    Feature {
         label: "test",
         description: r##"# `test`
 
@@ -4039,8 +6113,40 @@ fn bench_xor_1000_ints(b: &mut Bencher) {
 However, the optimizer can still modify a testcase in an undesirable manner
 even when using either of the above.
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: None,
+    },
+    Feature {
+        label: "test_2018_feature",
+        description: r##"Added for testing E0705; perma-unstable."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.31.0"),
+    },
+    Feature {
+        label: "test_accepted_feature",
+        description: r##"These are used to test this portion of the compiler, they don't actually mean anything."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "test_removed_feature",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "test_unstable_lint",
+        description: r##"Added for testing unstable lints; perma-unstable."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.60.0"),
+    },
+    Feature {
+        label: "thread_local",
+        description: r##"Allows using `#[thread_local]` on `static` items."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.0.0"),
+    },
+    Feature {
         label: "thread_local_internals",
         description: r##"# `thread_local_internals`
 
@@ -4048,8 +6154,22 @@ fn bench_xor_1000_ints(b: &mut Bencher) {
 
 ------------------------
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: None,
+    },
+    Feature {
+        label: "tool_attributes",
+        description: r##"Allows attributes scoped to tools."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.30.0"),
+    },
+    Feature {
+        label: "tool_lints",
+        description: r##"Allows scoped lints."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.31.0"),
+    },
+    Feature {
         label: "trace_macros",
         description: r##"# `trace_macros`
 
@@ -4091,8 +6211,16 @@ fn main() {
     Finished dev [unoptimized + debuginfo] target(s) in 0.60 secs
 ```
 "##,
+        status: FeatureStatus::Unstable,
+        since: None,
     },
-    Lint {
+    Feature {
+        label: "track_caller",
+        description: r##"Allows `#[track_caller]` to be used which provides accurate caller location reporting during panic (RFC 2091)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.46.0"),
+    },
+    Feature {
         label: "trait_alias",
         description: r##"# `trait_alias`
 
@@ -4129,8 +6257,10 @@ pub fn main() {
 }
 ```
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.24.0"),
     },
-    Lint {
+    Feature {
         label: "trait_upcasting",
         description: r##"# `trait_upcasting`
 
@@ -4160,8 +6290,22 @@ impl<T: Foo + ?Sized> Bar for T {}
 let foo: &dyn Foo = bar;
 ```
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.56.0"),
+    },
+    Feature {
+        label: "transmute_generic_consts",
+        description: r##"Allows for transmuting between arrays with sizes that contain generic consts."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.70.0"),
+    },
+    Feature {
+        label: "transparent_enums",
+        description: r##"Allows #[repr(transparent)] on univariant enums (RFC 2645)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.42.0"),
+    },
+    Feature {
         label: "transparent_unions",
         description: r##"# `transparent_unions`
 
@@ -4247,8 +6391,16 @@ pub union GenericUnion<T: Copy> { // Unions with non-`Copy` fields are unstable.
 possible, but is not required to, and different compiler versions may differ in
 their application of these optimizations.
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.37.0"),
     },
-    Lint {
+    Feature {
+        label: "trivial_bounds",
+        description: r##"Allows inconsistent bounds in where clauses."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.28.0"),
+    },
+    Feature {
         label: "try_blocks",
         description: r##"# `try_blocks`
 
@@ -4281,8 +6433,34 @@ pub union GenericUnion<T: Copy> { // Unions with non-`Copy` fields are unstable.
 assert!(result.is_err());
 ```
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.29.0"),
+    },
+    Feature {
+        label: "tuple_indexing",
+        description: r##"Allows indexing tuples."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "type_alias_enum_variants",
+        description: r##"Allows paths to enum variants on type aliases including `Self`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.37.0"),
+    },
+    Feature {
+        label: "type_alias_impl_trait",
+        description: r##"Allows `impl Trait` to be used inside type aliases (RFC 2515)."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.38.0"),
+    },
+    Feature {
+        label: "type_ascription",
+        description: r##"Allows the use of type ascription in expressions."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.6.0"),
+    },
+    Feature {
         label: "type_changing_struct_update",
         description: r##"# `type_changing_struct_update`
 
@@ -4318,8 +6496,22 @@ struct Foo<T, U> {
 }
 ```
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.58.0"),
+    },
+    Feature {
+        label: "type_macros",
+        description: r##"Allows macros to appear in the type position."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.13.0"),
+    },
+    Feature {
+        label: "type_privacy_lints",
+        description: r##"Allows using type privacy lints (`private_interfaces`, `private_bounds`, `unnameable_types`)."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.72.0"),
+    },
+    Feature {
         label: "unboxed_closures",
         description: r##"# `unboxed_closures`
 
@@ -4335,7 +6527,7 @@ struct Foo<T, U> {
 required for implementing the [`Fn*`] family of traits. `"rust-call"` functions must have
 exactly one (non self) argument, a tuple representing the argument list.
 
-[`Fn*`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+[`Fn*`]: ../../std/ops/trait.Fn.html
 
 ```rust
 #![feature(unboxed_closures)]
@@ -4347,8 +6539,144 @@ extern "rust-call" fn add_args(args: (u32, u32)) -> u32 {
 fn main() {}
 ```
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "underscore_const_names",
+        description: r##"Allows `const _: TYPE = VALUE`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.37.0"),
+    },
+    Feature {
+        label: "underscore_imports",
+        description: r##"Allows `use path as _;` and `extern crate c as _;`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.33.0"),
+    },
+    Feature {
+        label: "underscore_lifetimes",
+        description: r##"Allows `'_` placeholder lifetimes."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.26.0"),
+    },
+    Feature {
+        label: "uniform_paths",
+        description: r##"Allows `use x::y;` to search `x` in the current scope."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.32.0"),
+    },
+    Feature {
+        label: "universal_impl_trait",
+        description: r##"Allows `impl Trait` in function arguments."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.26.0"),
+    },
+    Feature {
+        label: "unix_sigpipe",
+        description: r##"# `unix_sigpipe`
+
+The tracking issue for this feature is: [#97889]
+
+[#97889]: https://github.com/rust-lang/rust/issues/97889
+
+---
+
+The `#[unix_sigpipe = "..."]` attribute on `fn main()` can be used to specify how libstd shall setup `SIGPIPE` on Unix platforms before invoking `fn main()`. This attribute is ignored on non-Unix targets. There are three variants:
+* `#[unix_sigpipe = "inherit"]`
+* `#[unix_sigpipe = "sig_dfl"]`
+* `#[unix_sigpipe = "sig_ign"]`
+
+## `#[unix_sigpipe = "inherit"]`
+
+Leave `SIGPIPE` untouched before entering `fn main()`. Unless the parent process has changed the default `SIGPIPE` handler from `SIG_DFL` to something else, this will behave the same as `#[unix_sigpipe = "sig_dfl"]`.
+
+## `#[unix_sigpipe = "sig_dfl"]`
+
+Set the `SIGPIPE` handler to `SIG_DFL`. This will result in your program getting killed if it tries to write to a closed pipe. This is normally what you want if your program produces textual output.
+
+### Example
+
+```rust,no_run
+#![feature(unix_sigpipe)]
+#[unix_sigpipe = "sig_dfl"]
+fn main() { loop { println!("hello world"); } }
+```
+
+```bash
+% ./main | head -n 1
+hello world
+```
+
+## `#[unix_sigpipe = "sig_ign"]`
+
+Set the `SIGPIPE` handler to `SIG_IGN` before invoking `fn main()`. This will result in `ErrorKind::BrokenPipe` errors if you program tries to write to a closed pipe. This is normally what you want if you for example write socket servers, socket clients, or pipe peers.
+
+This is what libstd has done by default since 2014. (However, see the note on child processes below.)
+
+### Example
+
+```rust,no_run
+#![feature(unix_sigpipe)]
+#[unix_sigpipe = "sig_ign"]
+fn main() { loop { println!("hello world"); } }
+```
+
+```bash
+% ./main | head -n 1
+hello world
+thread 'main' panicked at 'failed printing to stdout: Broken pipe (os error 32)', library/std/src/io/stdio.rs:1016:9
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+```
+
+### Note on child processes
+
+When spawning child processes, the legacy Rust behavior if `#[unix_sigpipe]` is not specified is to
+reset `SIGPIPE` to `SIG_DFL`.
+
+If `#[unix_sigpipe = "..."]` is specified, no matter what its value is, the signal disposition of
+`SIGPIPE` is no longer reset. This means that the child inherits the parent's `SIGPIPE` behavior.
+"##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.65.0"),
+    },
+    Feature {
+        label: "unmarked_api",
+        description: r##"Allows using items which are missing stability attributes"##,
+        status: FeatureStatus::Removed,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "unrestricted_attribute_tokens",
+        description: r##"Allows arbitrary delimited token streams in non-macro attributes."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.34.0"),
+    },
+    Feature {
+        label: "unsafe_block_in_unsafe_fn",
+        description: r##"The `unsafe_op_in_unsafe_fn` lint (allowed by default): no longer treat an unsafe function as an unsafe block."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.52.0"),
+    },
+    Feature {
+        label: "unsafe_no_drop_flag",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "unsafe_pin_internals",
+        description: r##"Allows non-`unsafe` —and thus, unsound— access to `Pin` constructions. Marked `incomplete` since perma-unstable and unsound."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.60.0"),
+    },
+    Feature {
+        label: "unsized_fn_params",
+        description: r##"Allows unsized fn parameters."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.49.0"),
+    },
+    Feature {
         label: "unsized_locals",
         description: r##"# `unsized_locals`
 
@@ -4526,8 +6854,10 @@ fn main() {
 
 will unnecessarily extend the stack frame.
 "##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.30.0"),
     },
-    Lint {
+    Feature {
         label: "unsized_tuple_coercion",
         description: r##"# `unsized_tuple_coercion`
 
@@ -4557,8 +6887,22 @@ fn main() {
 
 [RFC0401]: https://github.com/rust-lang/rfcs/blob/master/text/0401-coercions.md
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: Some("1.20.0"),
+    },
+    Feature {
+        label: "untagged_unions",
+        description: r##"Allows `union` fields that don't implement `Copy` as long as they don't have any drop glue."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.13.0"),
+    },
+    Feature {
+        label: "unwind_attributes",
+        description: r##"Allows `#[unwind(..)]`.  Permits specifying whether a function should permit unwinding or abort on unwind."##,
+        status: FeatureStatus::Removed,
+        since: Some("1.56.0"),
+    },
+    Feature {
         label: "update_panic_count",
         description: r##"# `update_panic_count`
 
@@ -4566,8 +6910,58 @@ fn main() {
 
 ------------------------
 "##,
-    },
-    Lint {
+        status: FeatureStatus::Unstable,
+        since: None,
+    },
+    Feature {
+        label: "use_extern_macros",
+        description: r##"Allows importing and reexporting macros with `use`, enables macro modularization in general."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.30.0"),
+    },
+    Feature {
+        label: "use_nested_groups",
+        description: r##"Allows nested groups in `use` items (RFC 2128)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.25.0"),
+    },
+    Feature {
+        label: "used",
+        description: r##"Allows `#[used]` to preserve symbols (see llvm.compiler.used)."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.30.0"),
+    },
+    Feature {
+        label: "used_with_arg",
+        description: r##"Allows using the `#[used(linker)]` (or `#[used(compiler)]`) attribute."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.60.0"),
+    },
+    Feature {
+        label: "visible_private_types",
+        description: r##""##,
+        status: FeatureStatus::Removed,
+        since: Some("1.0.0"),
+    },
+    Feature {
+        label: "wasm_abi",
+        description: r##"Allows `extern "wasm" fn`"##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.53.0"),
+    },
+    Feature {
+        label: "wasm_target_feature",
+        description: r##""##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.30.0"),
+    },
+    Feature {
+        label: "while_let",
+        description: r##"Allows the use of `while let` expressions."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.0.0"),
+    },
+    Feature {
         label: "windows_c",
         description: r##"# `windows_c`
 
@@ -4575,8 +6969,10 @@ fn main() {
 
 ------------------------
 "##,
+        status: FeatureStatus::Unstable,
+        since: None,
     },
-    Lint {
+    Feature {
         label: "windows_handle",
         description: r##"# `windows_handle`
 
@@ -4584,8 +6980,10 @@ fn main() {
 
 ------------------------
 "##,
+        status: FeatureStatus::Unstable,
+        since: None,
     },
-    Lint {
+    Feature {
         label: "windows_net",
         description: r##"# `windows_net`
 
@@ -4593,8 +6991,10 @@ fn main() {
 
 ------------------------
 "##,
+        status: FeatureStatus::Unstable,
+        since: None,
     },
-    Lint {
+    Feature {
         label: "windows_stdio",
         description: r##"# `windows_stdio`
 
@@ -4602,6 +7002,52 @@ fn main() {
 
 ------------------------
 "##,
+        status: FeatureStatus::Unstable,
+        since: None,
+    },
+    Feature {
+        label: "windows_subsystem",
+        description: r##"Allows `#![windows_subsystem]`."##,
+        status: FeatureStatus::Accepted,
+        since: Some("1.18.0"),
+    },
+    Feature {
+        label: "with_negative_coherence",
+        description: r##"Use for stable + negative coherence and strict coherence depending on trait's rustc_strict_coherence value."##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.60.0"),
+    },
+    Feature {
+        label: "yeet_expr",
+        description: r##"# `yeet_expr`
+
+The tracking issue for this feature is: [#96373]
+
+[#96373]: https://github.com/rust-lang/rust/issues/96373
+
+------------------------
+
+The `yeet_expr` feature adds support for `do yeet` expressions,
+which can be used to early-exit from a function or `try` block.
+
+These are highly experimental, thus the placeholder syntax.
+
+```rust,edition2021
+#![feature(yeet_expr)]
+
+fn foo() -> Result<String, i32> {
+    do yeet 4;
+}
+assert_eq!(foo(), Err(4));
+
+fn bar() -> Option<String> {
+    do yeet;
+}
+assert_eq!(bar(), None);
+```
+"##,
+        status: FeatureStatus::Unstable,
+        since: Some("1.62.0"),
     },
 ];
 
@@ -4612,10 +7058,12 @@ fn main() {
 either the minimum or maximum value for its type and warns if it involves a
 case that is always true or always false. Only integer and boolean types are
 checked."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::almost_swapped",
         description: r##"Checks for `foo = bar; bar = foo` sequences."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::approx_constant",
@@ -4625,6 +7073,7 @@ fn main() {
 or
 [`std::f64::consts`](https://doc.rust-lang.org/stable/std/f64/consts/#constants),
 respectively, suggesting to use the predefined constant."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::as_conversions",
@@ -4637,34 +7086,41 @@ fn main() {
 `fn_to_numeric_cast(_with_truncation)`, `char_lit_as_u8`, `ref_to_mut` and `ptr_as_ptr`.
 There is a good explanation the reason why this lint should work in this way and how it is useful
 [in this issue](https://github.com/rust-lang/rust-clippy/issues/5122)."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::assertions_on_constants",
         description: r##"Checks for `assert!(true)` and `assert!(false)` calls."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::assign_op_pattern",
         description: r##"Checks for `a = a op b` or `a = b commutative_op a`
 patterns."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::assign_ops",
         description: r##"Nothing. This lint has been deprecated."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::async_yields_async",
         description: r##"Checks for async blocks that yield values of types
 that can themselves be awaited."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::await_holding_lock",
         description: r##"Checks for calls to await while holding a
 non-async-aware MutexGuard."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::await_holding_refcell_ref",
         description: r##"Checks for calls to await while holding a
 `RefCell` `Ref` or `RefMut`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::bad_bit_mask",
@@ -4683,89 +7139,107 @@ fn main() {
 |`==` or `!=`| `|`  |`x | 1 == 0`|`false`  |`c | m != c`          |
 |`<`  or `>=`| `|`  |`x | 1 < 1` |`false`  |`m >= c`              |
 |`<=` or `>` | `|`  |`x | 1 > 0` |`true`   |`m > c`               |"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::bind_instead_of_map",
         description: r##"Checks for usage of `_.and_then(|x| Some(y))`, `_.and_then(|x| Ok(y))` or
 `_.or_else(|x| Err(y))`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::blacklisted_name",
         description: r##"Checks for usage of blacklisted names for variables, such
 as `foo`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::blanket_clippy_restriction_lints",
         description: r##"Checks for `warn`/`deny`/`forbid` attributes targeting the whole clippy::restriction category."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::blocks_in_if_conditions",
         description: r##"Checks for `if` conditions that use blocks containing an
 expression, statements or conditions that use closures with blocks."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::bool_assert_comparison",
         description: r##"This lint warns about boolean comparisons in assert-like macros."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::bool_comparison",
         description: r##"Checks for expressions of the form `x == true`,
 `x != true` and order comparisons such as `x < true` (or vice versa) and
 suggest using the variable directly."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::borrow_interior_mutable_const",
         description: r##"Checks if `const` items which is interior mutable (e.g.,
 contains a `Cell`, `Mutex`, `AtomicXxxx`, etc.) has been borrowed directly."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::borrowed_box",
         description: r##"Checks for use of `&Box<T>` anywhere in the code.
 Check the [Box documentation](https://doc.rust-lang.org/std/boxed/index.html) for more information."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::box_collection",
         description: r##"Checks for use of `Box<T>` where T is a collection such as Vec anywhere in the code.
 Check the [Box documentation](https://doc.rust-lang.org/std/boxed/index.html) for more information."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::boxed_local",
         description: r##"Checks for usage of `Box<T>` where an unboxed `T` would
 work fine."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::branches_sharing_code",
         description: r##"Checks if the `if` and `else` block contain shared code that can be
 moved out of the blocks."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::builtin_type_shadow",
         description: r##"Warns if a generic shadows a built-in type."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::bytes_nth",
         description: r##"Checks for the use of `.bytes().nth()`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::cargo_common_metadata",
         description: r##"Checks to see if all common metadata is defined in
 `Cargo.toml`. See: https://rust-lang-nursery.github.io/api-guidelines/documentation.html#cargotoml-includes-all-common-metadata-c-metadata"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::case_sensitive_file_extension_comparisons",
         description: r##"Checks for calls to `ends_with` with possible file extensions
 and suggests to use a case-insensitive approach instead."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::cast_lossless",
         description: r##"Checks for casts between numerical types that may
 be replaced by safe conversion functions."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::cast_possible_truncation",
         description: r##"Checks for casts between numerical types that may
 truncate large values. This is expected behavior, so the cast is `Allow` by
 default."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::cast_possible_wrap",
@@ -4775,6 +7249,7 @@ fn main() {
 the value is reinterpreted. This can cause wrapping if the value is too big
 for the target signed type. However, the cast works as defined, so this lint
 is `Allow` by default."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::cast_precision_loss",
@@ -4785,15 +7260,18 @@ fn main() {
 
 Basically, this warns on casting any integer with 32 or more bits to `f32`
 or any 64-bit integer to `f64`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::cast_ptr_alignment",
         description: r##"Checks for casts, using `as` or `pointer::cast`,
 from a less-strictly-aligned pointer to a more-strictly-aligned pointer"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::cast_ref_to_mut",
         description: r##"Checks for casts of `&T` to `&mut T` anywhere in the code."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::cast_sign_loss",
@@ -4801,68 +7279,86 @@ fn main() {
 type. In this case, negative values wrap around to large positive values,
 which can be quite surprising in practice. However, as the cast works as
 defined, this lint is `Allow` by default."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::char_lit_as_u8",
         description: r##"Checks for expressions where a character literal is cast
 to `u8` and suggests using a byte literal instead."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::chars_last_cmp",
         description: r##"Checks for usage of `_.chars().last()` or
 `_.chars().next_back()` on a `str` to check if it ends with a given char."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::chars_next_cmp",
         description: r##"Checks for usage of `.chars().next()` on a `str` to check
 if it starts with a given char."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::checked_conversions",
         description: r##"Checks for explicit bounds checking when casting."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::clone_double_ref",
         description: r##"Checks for usage of `.clone()` on an `&&T`."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::clone_on_copy",
         description: r##"Checks for usage of `.clone()` on a `Copy` type."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::clone_on_ref_ptr",
         description: r##"Checks for usage of `.clone()` on a ref-counted pointer,
 (`Rc`, `Arc`, `rc::Weak`, or `sync::Weak`), and suggests calling Clone via unified
 function syntax instead (e.g., `Rc::clone(foo)`)."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::cloned_instead_of_copied",
         description: r##"Checks for usages of `cloned()` on an `Iterator` or `Option` where
 `copied()` could be used instead."##,
+        default_level: LintLevel::Allow,
+    },
+    Lint {
+        label: "clippy::cmp_nan",
+        description: r##"Checks for comparisons to NaN."##,
+        default_level: LintLevel::Deny,
     },
-    Lint { label: "clippy::cmp_nan", description: r##"Checks for comparisons to NaN."## },
     Lint {
         label: "clippy::cmp_null",
         description: r##"This lint checks for equality comparisons with `ptr::null`"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::cmp_owned",
         description: r##"Checks for conversions to owned values just for the sake
 of a comparison."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::cognitive_complexity",
         description: r##"Checks for methods with high cognitive complexity."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::collapsible_else_if",
         description: r##"Checks for collapsible `else { if ... }` expressions
 that can be collapsed to `else if ...`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::collapsible_if",
         description: r##"Checks for nested `if` statements which can be collapsed
 by `&&`-combining their conditions."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::collapsible_match",
@@ -4871,44 +7367,57 @@ fn main() {
 
 Note that this lint is not intended to find _all_ cases where nested match patterns can be merged, but only
 cases where merging would most likely make the code more readable."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::comparison_chain",
         description: r##"Checks comparison chains written with `if` that can be
 rewritten with `match` and `cmp`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::comparison_to_empty",
         description: r##"Checks for comparing to an empty slice such as `` or `[]`,
 and suggests using `.is_empty()` where applicable."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::copy_iterator",
         description: r##"Checks for types that implement `Copy` as well as
 `Iterator`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::create_dir",
         description: r##"Checks usage of `std::fs::create_dir` and suggest using `std::fs::create_dir_all` instead."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::crosspointer_transmute",
         description: r##"Checks for transmutes between a type `T` and `*T`."##,
+        default_level: LintLevel::Warn,
+    },
+    Lint {
+        label: "clippy::dbg_macro",
+        description: r##"Checks for usage of dbg!() macro."##,
+        default_level: LintLevel::Allow,
     },
-    Lint { label: "clippy::dbg_macro", description: r##"Checks for usage of dbg!() macro."## },
     Lint {
         label: "clippy::debug_assert_with_mut_call",
         description: r##"Checks for function/method calls with a mutable
 parameter in `debug_assert!`, `debug_assert_eq!` and `debug_assert_ne!` macros."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::decimal_literal_representation",
         description: r##"Warns if there is a better representation for a numeric literal."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::declare_interior_mutable_const",
         description: r##"Checks for declaration of `const` items which is interior
 mutable (e.g., contains a `Cell`, `Mutex`, `AtomicXxxx`, etc.)."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::default_numeric_fallback",
@@ -4920,42 +7429,51 @@ fn main() {
 floating type is bound to `f64`.
 
 See [RFC0212](https://github.com/rust-lang/rfcs/blob/master/text/0212-restore-int-fallback.md) for more information about the fallback."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::default_trait_access",
         description: r##"Checks for literal calls to `Default::default()`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::deprecated_cfg_attr",
         description: r##"Checks for `#[cfg_attr(rustfmt, rustfmt_skip)]` and suggests to replace it
 with `#[rustfmt::skip]`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::deprecated_semver",
         description: r##"Checks for `#[deprecated]` annotations with a `since`
 field that is not a valid semantic version."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::deref_addrof",
         description: r##"Checks for usage of `*&` and `*&mut` in expressions."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::derivable_impls",
         description: r##"Detects manual `std::default::Default` implementations that are identical to a derived implementation."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::derive_hash_xor_eq",
         description: r##"Checks for deriving `Hash` but implementing `PartialEq`
 explicitly or vice versa."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::derive_ord_xor_partial_ord",
         description: r##"Checks for deriving `Ord` but implementing `PartialOrd`
 explicitly or vice versa."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::disallowed_methods",
         description: r##"Denies the configured methods and functions in clippy.toml"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::disallowed_script_idents",
@@ -4971,63 +7489,76 @@ fn main() {
 
 [aliases]: http://www.unicode.org/reports/tr24/tr24-31.html#Script_Value_Aliases
 [supported_scripts]: https://www.unicode.org/iso15924/iso15924-codes.html"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::disallowed_types",
         description: r##"Denies the configured types in clippy.toml."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::diverging_sub_expression",
         description: r##"Checks for diverging calls that are not match arms or
 statements."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::doc_markdown",
         description: r##"Checks for the presence of `_`, `::` or camel-case words
 outside ticks in documentation."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::double_comparisons",
         description: r##"Checks for double comparisons that could be simplified to a single expression."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::double_must_use",
         description: r##"Checks for a `#[must_use]` attribute without
 further information on functions and methods that return a type already
 marked as `#[must_use]`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::double_neg",
         description: r##"Detects expressions of the form `--x`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::double_parens",
         description: r##"Checks for unnecessary double parentheses."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::drop_copy",
         description: r##"Checks for calls to `std::mem::drop` with a value
 that derives the Copy trait"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::drop_ref",
         description: r##"Checks for calls to `std::mem::drop` with a reference
 instead of an owned value."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::duplicate_underscore_argument",
         description: r##"Checks for function arguments having the similar names
 differing by an underscore."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::duration_subsec",
         description: r##"Checks for calculation of subsecond microseconds or milliseconds
 from other `Duration` methods."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::else_if_without_else",
         description: r##"Checks for usage of if expressions with an `else if` branch,
 but without a final `else` branch."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::empty_enum",
@@ -5036,228 +7567,292 @@ fn main() {
 As of this writing, the `never_type` is still a
 nightly-only experimental API. Therefore, this lint is only triggered
 if the `never_type` is enabled."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::empty_line_after_outer_attr",
         description: r##"Checks for empty lines after outer attributes"##,
+        default_level: LintLevel::Allow,
+    },
+    Lint {
+        label: "clippy::empty_loop",
+        description: r##"Checks for empty `loop` expressions."##,
+        default_level: LintLevel::Warn,
     },
-    Lint { label: "clippy::empty_loop", description: r##"Checks for empty `loop` expressions."## },
     Lint {
         label: "clippy::enum_clike_unportable_variant",
         description: r##"Checks for C-like enumerations that are
 `repr(isize/usize)` and have values that don't fit into an `i32`."##,
+        default_level: LintLevel::Deny,
+    },
+    Lint {
+        label: "clippy::enum_glob_use",
+        description: r##"Checks for `use Enum::*`."##,
+        default_level: LintLevel::Allow,
     },
-    Lint { label: "clippy::enum_glob_use", description: r##"Checks for `use Enum::*`."## },
     Lint {
         label: "clippy::enum_variant_names",
         description: r##"Detects enumeration variants that are prefixed or suffixed
 by the same characters."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::eq_op",
         description: r##"Checks for equal operands to comparison, logical and
 bitwise, difference and division binary operators (`==`, `>`, etc., `&&`,
 `||`, `&`, `|`, `^`, `-` and `/`)."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::equatable_if_let",
         description: r##"Checks for pattern matchings that can be expressed using equality."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::erasing_op",
         description: r##"Checks for erasing operations, e.g., `x * 0`."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::eval_order_dependence",
         description: r##"Checks for a read and a write to the same variable where
 whether the read occurs before or after the write depends on the evaluation
 order of sub-expressions."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::excessive_precision",
         description: r##"Checks for float literals with a precision greater
 than that supported by the underlying type."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::exhaustive_enums",
         description: r##"Warns on any exported `enum`s that are not tagged `#[non_exhaustive]`"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::exhaustive_structs",
         description: r##"Warns on any exported `structs`s that are not tagged `#[non_exhaustive]`"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::exit",
         description: r##"`exit()`  terminates the program and doesn't provide a
 stack trace."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::expect_fun_call",
         description: r##"Checks for calls to `.expect(&format!(...))`, `.expect(foo(..))`,
 etc., and suggests to use `unwrap_or_else` instead"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::expect_used",
         description: r##"Checks for `.expect()` calls on `Option`s and `Result`s."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::expl_impl_clone_on_copy",
         description: r##"Checks for explicit `Clone` implementations for `Copy`
 types."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::explicit_counter_loop",
         description: r##"Checks `for` loops over slices with an explicit counter
 and suggests the use of `.enumerate()`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::explicit_deref_methods",
         description: r##"Checks for explicit `deref()` or `deref_mut()` method calls."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::explicit_into_iter_loop",
         description: r##"Checks for loops on `y.into_iter()` where `y` will do, and
 suggests the latter."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::explicit_iter_loop",
         description: r##"Checks for loops on `x.iter()` where `&x` will do, and
 suggests the latter."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::explicit_write",
         description: r##"Checks for usage of `write!()` / `writeln()!` which can be
 replaced with `(e)print!()` / `(e)println!()`"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::extend_from_slice",
         description: r##"Nothing. This lint has been deprecated."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::extend_with_drain",
         description: r##"Checks for occurrences where one vector gets extended instead of append"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::extra_unused_lifetimes",
         description: r##"Checks for lifetimes in generics that are never used
 anywhere else."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::fallible_impl_from",
         description: r##"Checks for impls of `From<..>` that contain `panic!()` or `unwrap()`"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::field_reassign_with_default",
         description: r##"Checks for immediate reassignment of fields initialized
 with Default::default()."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::filetype_is_file",
         description: r##"Checks for `FileType::is_file()`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::filter_map",
         description: r##"Nothing. This lint has been deprecated."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::filter_map_identity",
         description: r##"Checks for usage of `filter_map(|x| x)`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::filter_map_next",
         description: r##"Checks for usage of `_.filter_map(_).next()`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::filter_next",
         description: r##"Checks for usage of `_.filter(_).next()`."##,
+        default_level: LintLevel::Warn,
+    },
+    Lint {
+        label: "clippy::find_map",
+        description: r##"Nothing. This lint has been deprecated."##,
+        default_level: LintLevel::Allow,
     },
-    Lint { label: "clippy::find_map", description: r##"Nothing. This lint has been deprecated."## },
     Lint {
         label: "clippy::flat_map_identity",
         description: r##"Checks for usage of `flat_map(|x| x)`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::flat_map_option",
         description: r##"Checks for usages of `Iterator::flat_map()` where `filter_map()` could be
 used instead."##,
+        default_level: LintLevel::Allow,
+    },
+    Lint {
+        label: "clippy::float_arithmetic",
+        description: r##"Checks for float arithmetic."##,
+        default_level: LintLevel::Allow,
     },
-    Lint { label: "clippy::float_arithmetic", description: r##"Checks for float arithmetic."## },
     Lint {
         label: "clippy::float_cmp",
         description: r##"Checks for (in-)equality comparisons on floating-point
 values (apart from zero), except in functions called `*eq*` (which probably
 implement equality for a type involving floats)."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::float_cmp_const",
         description: r##"Checks for (in-)equality comparisons on floating-point
 value and constant, except in functions called `*eq*` (which probably
 implement equality for a type involving floats)."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::float_equality_without_abs",
         description: r##"Checks for statements of the form `(a - b) < f32::EPSILON` or
 `(a - b) < f64::EPSILON`. Notes the missing `.abs()`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::fn_address_comparisons",
         description: r##"Checks for comparisons with an address of a function item."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::fn_params_excessive_bools",
         description: r##"Checks for excessive use of
 bools in function definitions."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::fn_to_numeric_cast",
         description: r##"Checks for casts of function pointers to something other than usize"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::fn_to_numeric_cast_any",
         description: r##"Checks for casts of a function pointer to any integer type."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::fn_to_numeric_cast_with_truncation",
         description: r##"Checks for casts of a function pointer to a numeric type not wide enough to
 store address."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::for_kv_map",
         description: r##"Checks for iterating a map (`HashMap` or `BTreeMap`) and
 ignoring either the keys or values."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::for_loops_over_fallibles",
         description: r##"Checks for `for` loops over `Option` or `Result` values."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::forget_copy",
         description: r##"Checks for calls to `std::mem::forget` with a value that
 derives the Copy trait"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::forget_ref",
         description: r##"Checks for calls to `std::mem::forget` with a reference
 instead of an owned value."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::format_in_format_args",
         description: r##"Detects `format!` within the arguments of another macro that does
 formatting such as `format!` itself, `write!` or `println!`. Suggests
 inlining the `format!` call."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::from_iter_instead_of_collect",
         description: r##"Checks for `from_iter()` function calls on types that implement the `FromIterator`
 trait."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::from_over_into",
         description: r##"Searches for implementations of the `Into<..>` trait and suggests to implement `From<..>` instead."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::from_str_radix_10",
         description: r##"Checks for function invocations of the form `primitive::from_str_radix(s, 10)`"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::future_not_send",
@@ -5265,93 +7860,112 @@ fn main() {
 functions and methods to implement the `Send` marker trait. It is mostly
 used by library authors (public and internal) that target an audience where
 multithreaded executors are likely to be used for running these Futures."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::get_last_with_len",
         description: r##"Checks for using `x.get(x.len() - 1)` instead of
 `x.last()`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::get_unwrap",
         description: r##"Checks for use of `.get().unwrap()` (or
 `.get_mut().unwrap`) on a standard library type which implements `Index`"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::identity_op",
         description: r##"Checks for identity operations, e.g., `x + 0`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::if_let_mutex",
         description: r##"Checks for `Mutex::lock` calls in `if let` expression
 with lock calls in any of the else blocks."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::if_let_redundant_pattern_matching",
         description: r##"Nothing. This lint has been deprecated."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::if_not_else",
         description: r##"Checks for usage of `!` or `!=` in an if condition with an
 else branch."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::if_same_then_else",
         description: r##"Checks for `if/else` with the same body as the *then* part
 and the *else* part."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::if_then_some_else_none",
         description: r##"Checks for if-else that could be written to `bool::then`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::ifs_same_cond",
         description: r##"Checks for consecutive `if`s with the same condition."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::implicit_clone",
         description: r##"Checks for the usage of `_.to_owned()`, `vec.to_vec()`, or similar when calling `_.clone()` would be clearer."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::implicit_hasher",
         description: r##"Checks for public `impl` or `fn` missing generalization
 over different hashers and implicitly defaulting to the default hashing
 algorithm (`SipHash`)."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::implicit_return",
         description: r##"Checks for missing return statements at the end of a block."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::implicit_saturating_sub",
         description: r##"Checks for implicit saturating subtraction."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::imprecise_flops",
         description: r##"Looks for floating-point expressions that
 can be expressed using built-in methods to improve accuracy
 at the cost of performance."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::inconsistent_digit_grouping",
         description: r##"Warns if an integral or floating-point constant is
 grouped inconsistently with underscores."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::inconsistent_struct_constructor",
         description: r##"Checks for struct constructors where all fields are shorthand and
 the order of the field init shorthand in the constructor is inconsistent
 with the order in the struct definition."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::index_refutable_slice",
         description: r##"The lint checks for slice bindings in patterns that are only used to
 access individual slice values."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::indexing_slicing",
         description: r##"Checks for usage of indexing or slicing. Arrays are special cases, this lint
 does report on arrays if we can tell that slicing operations are in bounds and does not
 lint on constant `usize` indexing on arrays because that is handled by rustc's `const_err` lint."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::ineffective_bit_mask",
@@ -5363,53 +7977,65 @@ fn main() {
 |----------|---------|-----------|-------|
 |`>` / `<=`|`|` / `^`|`x | 2 > 3`|`x > 3`|
 |`<` / `>=`|`|` / `^`|`x ^ 1 < 4`|`x < 4`|"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::inefficient_to_string",
         description: r##"Checks for usage of `.to_string()` on an `&&T` where
 `T` implements `ToString` directly (like `&&str` or `&&String`)."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::infallible_destructuring_match",
         description: r##"Checks for matches being used to destructure a single-variant enum
 or tuple struct where a `let` will suffice."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::infinite_iter",
         description: r##"Checks for iteration that is guaranteed to be infinite."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::inherent_to_string",
         description: r##"Checks for the definition of inherent methods with a signature of `to_string(&self) -> String`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::inherent_to_string_shadow_display",
         description: r##"Checks for the definition of inherent methods with a signature of `to_string(&self) -> String` and if the type implementing this method also implements the `Display` trait."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::inline_always",
         description: r##"Checks for items annotated with `#[inline(always)]`,
 unless the annotated function is empty or simply panics."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::inline_asm_x86_att_syntax",
         description: r##"Checks for usage of AT&T x86 assembly syntax."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::inline_asm_x86_intel_syntax",
         description: r##"Checks for usage of Intel x86 assembly syntax."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::inline_fn_without_body",
         description: r##"Checks for `#[inline]` on trait methods without bodies"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::inspect_for_each",
         description: r##"Checks for usage of `inspect().for_each()`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::int_plus_one",
         description: r##"Checks for usage of `x >= y + 1` or `x - 1 >= y` (and `<=`) in a block"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::integer_arithmetic",
@@ -5420,96 +8046,124 @@ fn main() {
 Reference](https://doc.rust-lang.org/reference/expressions/operator-expr.html#overflow),
 or which can panic (`/`, `%`). No bounds analysis or sophisticated reasoning is
 attempted."##,
+        default_level: LintLevel::Allow,
+    },
+    Lint {
+        label: "clippy::integer_division",
+        description: r##"Checks for division of integers"##,
+        default_level: LintLevel::Allow,
     },
-    Lint { label: "clippy::integer_division", description: r##"Checks for division of integers"## },
     Lint {
         label: "clippy::into_iter_on_ref",
         description: r##"Checks for `into_iter` calls on references which should be replaced by `iter`
 or `iter_mut`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::invalid_null_ptr_usage",
         description: r##"This lint checks for invalid usages of `ptr::null`."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::invalid_regex",
         description: r##"Checks [regex](https://crates.io/crates/regex) creation
 (with `Regex::new`, `RegexBuilder::new`, or `RegexSet::new`) for correct
 regex syntax."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::invalid_upcast_comparisons",
         description: r##"Checks for comparisons where the relation is always either
 true or false, but where one side has been upcast so that the comparison is
 necessary. Only integer types are checked."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::invisible_characters",
         description: r##"Checks for invisible Unicode characters in the code."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::items_after_statements",
         description: r##"Checks for items declared after some statement in a block."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::iter_cloned_collect",
         description: r##"Checks for the use of `.cloned().collect()` on slice to
 create a `Vec`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::iter_count",
         description: r##"Checks for the use of `.iter().count()`."##,
+        default_level: LintLevel::Warn,
+    },
+    Lint {
+        label: "clippy::iter_next_loop",
+        description: r##"Checks for loops on `x.next()`."##,
+        default_level: LintLevel::Deny,
     },
-    Lint { label: "clippy::iter_next_loop", description: r##"Checks for loops on `x.next()`."## },
     Lint {
         label: "clippy::iter_next_slice",
         description: r##"Checks for usage of `iter().next()` on a Slice or an Array"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::iter_not_returning_iterator",
         description: r##"Detects methods named `iter` or `iter_mut` that do not have a return type that implements `Iterator`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::iter_nth",
         description: r##"Checks for use of `.iter().nth()` (and the related
 `.iter_mut().nth()`) on standard library types with *O*(1) element access."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::iter_nth_zero",
         description: r##"Checks for the use of `iter.nth(0)`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::iter_skip_next",
         description: r##"Checks for use of `.skip(x).next()` on iterators."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::iterator_step_by_zero",
         description: r##"Checks for calling `.step_by(0)` on iterators which panics."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::just_underscores_and_digits",
         description: r##"Checks if you have variables whose name consists of just
 underscores and digits."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::large_const_arrays",
         description: r##"Checks for large `const` arrays that should
 be defined as `static` instead."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::large_digit_groups",
         description: r##"Warns if the digits of an integral or floating-point
 constant are grouped into groups that
 are too large."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::large_enum_variant",
         description: r##"Checks for large size differences between variants on
 `enum`s."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::large_stack_arrays",
         description: r##"Checks for local arrays that may be too large."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::large_types_passed_by_value",
@@ -5518,402 +8172,504 @@ fn main() {
 passing by reference. Does not trigger if the function is being exported,
 because that might induce API breakage, if the parameter is declared as mutable,
 or if the argument is a `self`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::len_without_is_empty",
         description: r##"Checks for items that implement `.len()` but not
 `.is_empty()`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::len_zero",
         description: r##"Checks for getting the length of something via `.len()`
 just to compare to zero, and suggests using `.is_empty()` where applicable."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::let_and_return",
         description: r##"Checks for `let`-bindings, which are subsequently
 returned."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::let_underscore_drop",
         description: r##"Checks for `let _ = <expr>`
 where expr has a type that implements `Drop`"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::let_underscore_lock",
         description: r##"Checks for `let _ = sync_lock`.
 This supports `mutex` and `rwlock` in `std::sync` and `parking_lot`."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::let_underscore_must_use",
         description: r##"Checks for `let _ = <expr>` where expr is `#[must_use]`"##,
+        default_level: LintLevel::Allow,
+    },
+    Lint {
+        label: "clippy::let_unit_value",
+        description: r##"Checks for binding a unit value."##,
+        default_level: LintLevel::Allow,
     },
-    Lint { label: "clippy::let_unit_value", description: r##"Checks for binding a unit value."## },
     Lint {
         label: "clippy::linkedlist",
         description: r##"Checks for usage of any `LinkedList`, suggesting to use a
 `Vec` or a `VecDeque` (formerly called `RingBuf`)."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::logic_bug",
         description: r##"Checks for boolean expressions that contain terminals that
 can be eliminated."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::lossy_float_literal",
         description: r##"Checks for whole number float literals that
 cannot be represented as the underlying type without loss."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::macro_use_imports",
         description: r##"Checks for `#[macro_use] use...`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::main_recursion",
         description: r##"Checks for recursion using the entrypoint."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::manual_assert",
         description: r##"Detects `if`-then-`panic!` that can be replaced with `assert!`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::manual_async_fn",
         description: r##"It checks for manual implementations of `async` functions."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::manual_filter_map",
         description: r##"Checks for usage of `_.filter(_).map(_)` that can be written more simply
 as `filter_map(_)`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::manual_find_map",
         description: r##"Checks for usage of `_.find(_).map(_)` that can be written more simply
 as `find_map(_)`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::manual_flatten",
         description: r##"Check for unnecessary `if let` usage in a for loop
 where only the `Some` or `Ok` variant of the iterator element is used."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::manual_map",
         description: r##"Checks for usages of `match` which could be implemented using `map`"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::manual_memcpy",
         description: r##"Checks for for-loops that manually copy items between
 slices that could be optimized by having a memcpy."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::manual_non_exhaustive",
         description: r##"Checks for manual implementations of the non-exhaustive pattern."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::manual_ok_or",
         description: r##"Finds patterns that reimplement `Option::ok_or`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::manual_range_contains",
         description: r##"Checks for expressions like `x >= 3 && x < 8` that could
 be more readably expressed as `(3..8).contains(x)`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::manual_saturating_arithmetic",
         description: r##"Checks for `.checked_add/sub(x).unwrap_or(MAX/MIN)`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::manual_split_once",
         description: r##"Checks for usages of `str::splitn(2, _)`"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::manual_str_repeat",
         description: r##"Checks for manual implementations of `str::repeat`"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::manual_strip",
         description: r##"Suggests using `strip_{prefix,suffix}` over `str::{starts,ends}_with` and slicing using
 the pattern's length."##,
+        default_level: LintLevel::Warn,
+    },
+    Lint {
+        label: "clippy::manual_swap",
+        description: r##"Checks for manual swapping."##,
+        default_level: LintLevel::Warn,
     },
-    Lint { label: "clippy::manual_swap", description: r##"Checks for manual swapping."## },
     Lint {
         label: "clippy::manual_unwrap_or",
         description: r##"Finds patterns that reimplement `Option::unwrap_or` or `Result::unwrap_or`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::many_single_char_names",
         description: r##"Checks for too many variables whose name consists of a
 single character."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::map_clone",
         description: r##"Checks for usage of `map(|x| x.clone())` or
 dereferencing closures for `Copy` types, on `Iterator` or `Option`,
 and suggests `cloned()` or `copied()` instead"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::map_collect_result_unit",
         description: r##"Checks for usage of `_.map(_).collect::<Result<(), _>()`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::map_entry",
         description: r##"Checks for uses of `contains_key` + `insert` on `HashMap`
 or `BTreeMap`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::map_err_ignore",
         description: r##"Checks for instances of `map_err(|_| Some::Enum)`"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::map_flatten",
         description: r##"Checks for usage of `_.map(_).flatten(_)` on `Iterator` and `Option`"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::map_identity",
         description: r##"Checks for instances of `map(f)` where `f` is the identity function."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::map_unwrap_or",
         description: r##"Checks for usage of `option.map(_).unwrap_or(_)` or `option.map(_).unwrap_or_else(_)` or
 `result.map(_).unwrap_or_else(_)`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::match_as_ref",
         description: r##"Checks for match which is used to add a reference to an
 `Option` value."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::match_bool",
         description: r##"Checks for matches where match expression is a `bool`. It
 suggests to replace the expression with an `if...else` block."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::match_like_matches_macro",
         description: r##"Checks for `match`  or `if let` expressions producing a
 `bool` that could be written using `matches!`"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::match_on_vec_items",
         description: r##"Checks for `match vec[idx]` or `match vec[n..m]`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::match_overlapping_arm",
         description: r##"Checks for overlapping match arms."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::match_ref_pats",
         description: r##"Checks for matches where all arms match a reference,
 suggesting to remove the reference and deref the matched expression
 instead. It also checks for `if let &foo = bar` blocks."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::match_result_ok",
         description: r##"Checks for unnecessary `ok()` in `while let`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::match_same_arms",
         description: r##"Checks for `match` with identical arm bodies."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::match_single_binding",
         description: r##"Checks for useless match that binds to only one value."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::match_str_case_mismatch",
         description: r##"Checks for `match` expressions modifying the case of a string with non-compliant arms"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::match_wild_err_arm",
         description: r##"Checks for arm which matches all errors with `Err(_)`
 and take drastic actions like `panic!`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::match_wildcard_for_single_variants",
         description: r##"Checks for wildcard enum matches for a single variant."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::maybe_infinite_iter",
         description: r##"Checks for iteration that may be infinite."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::mem_forget",
         description: r##"Checks for usage of `std::mem::forget(t)` where `t` is
 `Drop`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::mem_replace_option_with_none",
         description: r##"Checks for `mem::replace()` on an `Option` with
 `None`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::mem_replace_with_default",
         description: r##"Checks for `std::mem::replace` on a value of type
 `T` with `T::default()`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::mem_replace_with_uninit",
         description: r##"Checks for `mem::replace(&mut _, mem::uninitialized())`
 and `mem::replace(&mut _, mem::zeroed())`."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::min_max",
         description: r##"Checks for expressions where `std::cmp::min` and `max` are
 used to clamp values, but switched so that the result is constant."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::misaligned_transmute",
         description: r##"Nothing. This lint has been deprecated."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::mismatched_target_os",
         description: r##"Checks for cfg attributes having operating systems used in target family position."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::misrefactored_assign_op",
         description: r##"Checks for `a op= a op b` or `a op= b op a` patterns."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::missing_const_for_fn",
         description: r##"Suggests the use of `const` in functions and methods where possible."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::missing_docs_in_private_items",
         description: r##"Warns if there is missing doc for any documentable item
 (public or private)."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::missing_enforced_import_renames",
         description: r##"Checks for imports that do not rename the item as specified
 in the `enforce-import-renames` config option."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::missing_errors_doc",
         description: r##"Checks the doc comments of publicly visible functions that
 return a `Result` type and warns if there is no `# Errors` section."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::missing_inline_in_public_items",
         description: r##"It lints if an exported function, method, trait method with default impl,
 or trait method impl is not `#[inline]`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::missing_panics_doc",
         description: r##"Checks the doc comments of publicly visible functions that
 may panic and warns if there is no `# Panics` section."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::missing_safety_doc",
         description: r##"Checks for the doc comments of publicly visible
 unsafe functions and warns if there is no `# Safety` section."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::mistyped_literal_suffixes",
         description: r##"Warns for mistyped suffix in literals"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::mixed_case_hex_literals",
         description: r##"Warns on hexadecimal literals with mixed-case letter
 digits."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::mod_module_files",
         description: r##"Checks that module layout uses only self named module files, bans mod.rs files."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::module_inception",
         description: r##"Checks for modules that have the same name as their
 parent module"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::module_name_repetitions",
         description: r##"Detects type names that are prefixed or suffixed by the
 containing module's name."##,
+        default_level: LintLevel::Allow,
+    },
+    Lint {
+        label: "clippy::modulo_arithmetic",
+        description: r##"Checks for modulo arithmetic."##,
+        default_level: LintLevel::Allow,
     },
-    Lint { label: "clippy::modulo_arithmetic", description: r##"Checks for modulo arithmetic."## },
     Lint {
         label: "clippy::modulo_one",
         description: r##"Checks for getting the remainder of a division by one or minus
 one."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::multiple_crate_versions",
         description: r##"Checks to see if multiple versions of a crate are being
 used."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::multiple_inherent_impl",
         description: r##"Checks for multiple inherent implementations of a struct"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::must_use_candidate",
         description: r##"Checks for public functions that have no
 `#[must_use]` attribute, but return something not already marked
 must-use, have no mutable arg and mutate no statics."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::must_use_unit",
         description: r##"Checks for a `#[must_use]` attribute on
 unit-returning functions and methods."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::mut_from_ref",
         description: r##"This lint checks for functions that take immutable
 references and return mutable ones."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::mut_mut",
         description: r##"Checks for instances of `mut mut` references."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::mut_mutex_lock",
         description: r##"Checks for `&mut Mutex::lock` calls"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::mut_range_bound",
         description: r##"Checks for loops which have a range bound that is a mutable variable"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::mutable_key_type",
         description: r##"Checks for sets/maps with mutable key types."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::mutex_atomic",
         description: r##"Checks for usages of `Mutex<X>` where an atomic will do."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::mutex_integer",
         description: r##"Checks for usages of `Mutex<X>` where `X` is an integral
 type."##,
+        default_level: LintLevel::Allow,
+    },
+    Lint {
+        label: "clippy::naive_bytecount",
+        description: r##"Checks for naive byte counts"##,
+        default_level: LintLevel::Allow,
     },
-    Lint { label: "clippy::naive_bytecount", description: r##"Checks for naive byte counts"## },
     Lint {
         label: "clippy::needless_arbitrary_self_type",
         description: r##"The lint checks for `self` in fn parameters that
 specify the `Self`-type explicitly"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::needless_bitwise_bool",
         description: r##"Checks for uses of bitwise and/or operators between booleans, where performance may be improved by using
 a lazy and."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::needless_bool",
         description: r##"Checks for expressions of the form `if c { true } else {
 false }` (or vice versa) and suggests using the condition directly."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::needless_borrow",
         description: r##"Checks for address of operations (`&`) that are going to
 be dereferenced immediately by the compiler."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::needless_borrowed_reference",
         description: r##"Checks for bindings that destructure a reference and borrow the inner
 value with `&ref`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::needless_collect",
         description: r##"Checks for functions collecting an iterator when collect
 is not needed."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::needless_continue",
@@ -5921,53 +8677,64 @@ fn main() {
 that contain a `continue` statement in either their main blocks or their
 `else`-blocks, when omitting the `else`-block possibly with some
 rearrangement of code can make the code easier to understand."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::needless_doctest_main",
         description: r##"Checks for `fn main() { .. }` in doctests"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::needless_for_each",
         description: r##"Checks for usage of `for_each` that would be more simply written as a
 `for` loop."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::needless_late_init",
         description: r##"Checks for late initializations that can be replaced by a `let` statement
 with an initializer."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::needless_lifetimes",
         description: r##"Checks for lifetime annotations which can be removed by
 relying on lifetime elision."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::needless_option_as_deref",
         description: r##"Checks for no-op uses of Option::{as_deref,as_deref_mut},
 for example, `Option<&T>::as_deref()` returns the same type."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::needless_pass_by_value",
         description: r##"Checks for functions taking arguments by value, but not
 consuming them in its
 body."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::needless_question_mark",
         description: r##"Suggests alternatives for useless applications of `?` in terminating expressions"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::needless_range_loop",
         description: r##"Checks for looping over the range of `0..len` of some
 collection just to get the values by index."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::needless_return",
         description: r##"Checks for return statements at the end of a block."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::needless_splitn",
         description: r##"Checks for usages of `str::splitn` (or `str::rsplitn`) where using `str::split` would be the same."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::needless_update",
@@ -5976,50 +8743,61 @@ fn main() {
 
 This lint is not applied to structs marked with
 [non_exhaustive](https://doc.rust-lang.org/reference/attributes/type_system.html)."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::neg_cmp_op_on_partial_ord",
         description: r##"Checks for the usage of negated comparison operators on types which only implement
 `PartialOrd` (e.g., `f64`)."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::neg_multiply",
         description: r##"Checks for multiplication by -1 as a form of negation."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::negative_feature_names",
         description: r##"Checks for negative feature names with prefix `no-` or `not-`"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::never_loop",
         description: r##"Checks for loops that will always `break`, `return` or
 `continue` an outer loop."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::new_ret_no_self",
         description: r##"Checks for `new` not returning a type that contains `Self`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::new_without_default",
         description: r##"Checks for types with a `fn new() -> Self` method and no
 implementation of
 [`Default`](https://doc.rust-lang.org/std/default/trait.Default.html)."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::no_effect",
         description: r##"Checks for statements which have no effect."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::no_effect_underscore_binding",
         description: r##"Checks for binding to underscore prefixed variable without side-effects."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::non_ascii_literal",
         description: r##"Checks for non-ASCII characters in string and char literals."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::non_octal_unix_permissions",
         description: r##"Checks for non-octal values used to set Unix file permissions."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::non_send_fields_in_send_ty",
@@ -6028,50 +8806,64 @@ fn main() {
 It tries to detect fields that can cause a soundness issue
 when sent to another thread (e.g., `Rc`) while allowing `!Send` fields
 that are expected to exist in a `Send` type, such as raw pointers."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::nonminimal_bool",
         description: r##"Checks for boolean expressions that can be written more
 concisely."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::nonsensical_open_options",
         description: r##"Checks for duplicate open options as well as combinations
 that make no sense."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::nonstandard_macro_braces",
         description: r##"Checks that common macros are used with consistent bracing."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::not_unsafe_ptr_arg_deref",
         description: r##"Checks for public functions that dereference raw pointer
 arguments but are not marked `unsafe`."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::octal_escapes",
         description: r##"Checks for `\\0` escapes in string and byte literals that look like octal
 character escapes in C."##,
+        default_level: LintLevel::Warn,
+    },
+    Lint {
+        label: "clippy::ok_expect",
+        description: r##"Checks for usage of `ok().expect(..)`."##,
+        default_level: LintLevel::Warn,
     },
-    Lint { label: "clippy::ok_expect", description: r##"Checks for usage of `ok().expect(..)`."## },
     Lint {
         label: "clippy::op_ref",
         description: r##"Checks for arguments to `==` which have their address
 taken to satisfy a bound
 and suggests to dereference the other argument instead"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::option_as_ref_deref",
         description: r##"Checks for usage of `_.as_ref().map(Deref::deref)` or it's aliases (such as String::as_str)."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::option_env_unwrap",
         description: r##"Checks for usage of `option_env!(...).unwrap()` and
 suggests usage of the `env!` macro."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::option_filter_map",
         description: r##"Checks for indirect collection of populated `Option`"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::option_if_let_else",
@@ -6079,53 +8871,68 @@ fn main() {
 idiomatically done with `Option::map_or` (if the else bit is a pure
 expression) or `Option::map_or_else` (if the else bit is an impure
 expression)."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::option_map_or_none",
         description: r##"Checks for usage of `_.map_or(None, _)`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::option_map_unit_fn",
         description: r##"Checks for usage of `option.map(f)` where f is a function
 or closure that returns the unit type `()`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::option_option",
         description: r##"Checks for use of `Option<Option<_>>` in function signatures and type
 definitions"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::or_fun_call",
         description: r##"Checks for calls to `.or(foo(..))`, `.unwrap_or(foo(..))`,
 etc., and suggests to use `or_else`, `unwrap_or_else`, etc., or
 `unwrap_or_default` instead."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::out_of_bounds_indexing",
         description: r##"Checks for out of bounds array indexing with a constant
 index."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::overflow_check_conditional",
         description: r##"Detects classic underflow/overflow checks."##,
+        default_level: LintLevel::Warn,
+    },
+    Lint {
+        label: "clippy::panic",
+        description: r##"Checks for usage of `panic!`."##,
+        default_level: LintLevel::Allow,
     },
-    Lint { label: "clippy::panic", description: r##"Checks for usage of `panic!`."## },
     Lint {
         label: "clippy::panic_in_result_fn",
         description: r##"Checks for usage of `panic!`, `unimplemented!`, `todo!`, `unreachable!` or assertions in a function of type result."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::panicking_unwrap",
         description: r##"Checks for calls of `unwrap[_err]()` that will always fail."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::partialeq_ne_impl",
         description: r##"Checks for manual re-implementations of `PartialEq::ne`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::path_buf_push_overwrite",
         description: r##"* Checks for [push](https://doc.rust-lang.org/std/path/struct.PathBuf.html#method.push)
 calls on `PathBuf` that can cause overwrites."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::pattern_type_mismatch",
@@ -6147,11 +8954,13 @@ fn main() {
 in a general way even outside of the various pattern matching mechanics. Of course
 this lint can still be used to highlight areas of interest and ensure a good understanding
 of ownership semantics."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::possible_missing_comma",
         description: r##"Checks for possible missing comma in an array. It lints if
 an array element is a binary operator expression and it lies on two lines."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::precedence",
@@ -6162,30 +8971,36 @@ fn main() {
 * a negative numeric literal (which is really a unary `-` followed by a
 numeric literal)
   followed by a method call"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::print_literal",
         description: r##"This lint warns about the use of literals as `print!`/`println!` args."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::print_stderr",
         description: r##"Checks for printing on *stderr*. The purpose of this lint
 is to catch debugging remnants."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::print_stdout",
         description: r##"Checks for printing on *stdout*. The purpose of this lint
 is to catch debugging remnants."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::print_with_newline",
         description: r##"This lint warns when you use `print!()` with a format
 string that ends in a newline."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::println_empty_string",
         description: r##"This lint warns when you use `println!()` to
 print a newline."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::ptr_arg",
@@ -6193,127 +9008,162 @@ fn main() {
 or `&Vec` unless the references are mutable. It will also suggest you
 replace `.clone()` calls with the appropriate `.to_owned()`/`to_string()`
 calls."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::ptr_as_ptr",
         description: r##"Checks for `as` casts between raw pointers without changing its mutability,
 namely `*const T` to `*const U` and `*mut T` to `*mut U`."##,
+        default_level: LintLevel::Allow,
+    },
+    Lint {
+        label: "clippy::ptr_eq",
+        description: r##"Use `std::ptr::eq` when applicable"##,
+        default_level: LintLevel::Warn,
     },
-    Lint { label: "clippy::ptr_eq", description: r##"Use `std::ptr::eq` when applicable"## },
     Lint {
         label: "clippy::ptr_offset_with_cast",
         description: r##"Checks for usage of the `offset` pointer method with a `usize` casted to an
 `isize`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::pub_enum_variant_names",
         description: r##"Nothing. This lint has been deprecated."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::question_mark",
         description: r##"Checks for expressions that could be replaced by the question mark operator."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::range_minus_one",
         description: r##"Checks for inclusive ranges where 1 is subtracted from
 the upper bound, e.g., `x..=(y-1)`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::range_plus_one",
         description: r##"Checks for exclusive ranges where 1 is added to the
 upper bound, e.g., `x..(y+1)`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::range_step_by_zero",
         description: r##"Nothing. This lint has been deprecated."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::range_zip_with_len",
         description: r##"Checks for zipping a collection with the range of
 `0.._.len()`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::rc_buffer",
         description: r##"Checks for `Rc<T>` and `Arc<T>` when `T` is a mutable buffer type such as `String` or `Vec`."##,
+        default_level: LintLevel::Allow,
+    },
+    Lint {
+        label: "clippy::rc_mutex",
+        description: r##"Checks for `Rc<Mutex<T>>`."##,
+        default_level: LintLevel::Allow,
     },
-    Lint { label: "clippy::rc_mutex", description: r##"Checks for `Rc<Mutex<T>>`."## },
     Lint {
         label: "clippy::redundant_allocation",
         description: r##"Checks for use of redundant allocations anywhere in the code."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::redundant_clone",
         description: r##"Checks for a redundant `clone()` (and its relatives) which clones an owned
 value that is going to be dropped without further use."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::redundant_closure",
         description: r##"Checks for closures which just call another function where
 the function can be called directly. `unsafe` functions or calls where types
 get adjusted are ignored."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::redundant_closure_call",
         description: r##"Detects closures called in the same expression where they
 are defined."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::redundant_closure_for_method_calls",
         description: r##"Checks for closures which only invoke a method on the closure
 argument and can be replaced by referencing the method directly."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::redundant_else",
         description: r##"Checks for `else` blocks that can be removed without changing semantics."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::redundant_feature_names",
         description: r##"Checks for feature names with prefix `use-`, `with-` or suffix `-support`"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::redundant_field_names",
         description: r##"Checks for fields in struct literals where shorthands
 could be used."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::redundant_pattern",
         description: r##"Checks for patterns in the form `name @ _`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::redundant_pattern_matching",
         description: r##"Lint for redundant pattern matching over `Result`, `Option`,
 `std::task::Poll` or `std::net::IpAddr`"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::redundant_pub_crate",
         description: r##"Checks for items declared `pub(crate)` that are not crate visible because they
 are inside a private module."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::redundant_slicing",
         description: r##"Checks for redundant slicing expressions which use the full range, and
 do not change the type."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::redundant_static_lifetimes",
         description: r##"Checks for constants and statics with an explicit `'static` lifetime."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::ref_binding_to_reference",
         description: r##"Checks for `ref` bindings which create a reference to a reference."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::ref_in_deref",
         description: r##"Checks for references in expressions that use
 auto dereference."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::ref_option_ref",
         description: r##"Checks for usage of `&Option<&T>`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::regex_macro",
         description: r##"Nothing. This lint has been deprecated."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::repeat_once",
@@ -6324,110 +9174,133 @@ fn main() {
 
 The lint will evaluate constant expressions and values as arguments of `.repeat(..)` and emit a message if
 they are equivalent to `1`. (Related discussion in [rust-clippy#7306](https://github.com/rust-lang/rust-clippy/issues/7306))"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::replace_consts",
         description: r##"Nothing. This lint has been deprecated."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::rest_pat_in_fully_bound_structs",
         description: r##"Checks for unnecessary '..' pattern binding on struct when all fields are explicitly matched."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::result_map_or_into_option",
         description: r##"Checks for usage of `_.map_or(None, Some)`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::result_map_unit_fn",
         description: r##"Checks for usage of `result.map(f)` where f is a function
 or closure that returns the unit type `()`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::result_unit_err",
         description: r##"Checks for public functions that return a `Result`
 with an `Err` type of `()`. It suggests using a custom type that
 implements `std::error::Error`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::return_self_not_must_use",
         description: r##"This lint warns when a method returning `Self` doesn't have the `#[must_use]` attribute."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::reversed_empty_ranges",
         description: r##"Checks for range expressions `x..y` where both `x` and `y`
 are constant and `x` is greater or equal to `y`."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::same_functions_in_if_condition",
         description: r##"Checks for consecutive `if`s with the same function call."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::same_item_push",
         description: r##"Checks whether a for loop is being used to push a constant
 value into a Vec."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::same_name_method",
         description: r##"It lints if a struct has two methods with the same name:
 one from a trait, another not from trait."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::search_is_some",
         description: r##"Checks for an iterator or string search (such as `find()`,
 `position()`, or `rposition()`) followed by a call to `is_some()` or `is_none()`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::self_assignment",
         description: r##"Checks for explicit self-assignments."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::self_named_constructors",
         description: r##"Warns when constructors have the same name as their types."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::self_named_module_files",
         description: r##"Checks that module layout uses only mod.rs files."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::semicolon_if_nothing_returned",
         description: r##"Looks for blocks of expressions and fires if the last expression returns
 `()` but is not followed by a semicolon."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::separated_literal_suffix",
         description: r##"Warns if literal suffixes are separated by an underscore.
 To enforce separated literal suffix style,
 see the `unseparated_literal_suffix` lint."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::serde_api_misuse",
         description: r##"Checks for mis-uses of the serde API."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::shadow_reuse",
         description: r##"Checks for bindings that shadow other bindings already in
 scope, while reusing the original value."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::shadow_same",
         description: r##"Checks for bindings that shadow other bindings already in
 scope, while just changing reference level or mutability."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::shadow_unrelated",
         description: r##"Checks for bindings that shadow other bindings already in
 scope, either without an initialization or with one that does not even use
 the original value."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::short_circuit_statement",
         description: r##"Checks for the use of short circuit boolean conditions as
 a
 statement."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::should_assert_eq",
         description: r##"Nothing. This lint has been deprecated."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::should_implement_trait",
@@ -6435,325 +9308,404 @@ fn main() {
 implementation of a `std` trait (see [llogiq's blog
 post](http://llogiq.github.io/2015/07/30/traits.html) for further
 information) instead of an inherent implementation."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::similar_names",
         description: r##"Checks for names that are very similar and thus confusing."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::single_char_add_str",
         description: r##"Warns when using `push_str`/`insert_str` with a single-character string literal
 where `push`/`insert` with a `char` would work fine."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::single_char_pattern",
         description: r##"Checks for string methods that receive a single-character
 `str` as an argument, e.g., `_.split(x)`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::single_component_path_imports",
         description: r##"Checking for imports with single component use path."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::single_element_loop",
         description: r##"Checks whether a for loop has a single element."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::single_match",
         description: r##"Checks for matches with a single arm where an `if let`
 will usually suffice."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::single_match_else",
         description: r##"Checks for matches with two arms where an `if let else` will
 usually suffice."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::size_of_in_element_count",
         description: r##"Detects expressions where
 `size_of::<T>` or `size_of_val::<T>` is used as a
 count of elements of type `T`"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::skip_while_next",
         description: r##"Checks for usage of `_.skip_while(condition).next()`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::slow_vector_initialization",
         description: r##"Checks slow zero-filled vector initialization"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::stable_sort_primitive",
         description: r##"When sorting primitive values (integers, bools, chars, as well
 as arrays, slices, and tuples of such items), it is better to
 use an unstable sort than a stable sort."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::str_to_string",
         description: r##"This lint checks for `.to_string()` method calls on values of type `&str`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::string_add",
         description: r##"Checks for all instances of `x + _` where `x` is of type
 `String`, but only if [`string_add_assign`](#string_add_assign) does *not*
 match."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::string_add_assign",
         description: r##"Checks for string appends of the form `x = x + y` (without
 `let`!)."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::string_extend_chars",
         description: r##"Checks for the use of `.extend(s.chars())` where s is a
 `&str` or `String`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::string_from_utf8_as_bytes",
         description: r##"Check if the string is transformed to byte array and casted back to string."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::string_lit_as_bytes",
         description: r##"Checks for the `as_bytes` method called on string literals
 that contain only ASCII characters."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::string_slice",
         description: r##"Checks for slice operations on strings"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::string_to_string",
         description: r##"This lint checks for `.to_string()` method calls on values of type `String`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::strlen_on_c_strings",
         description: r##"Checks for usage of `libc::strlen` on a `CString` or `CStr` value,
 and suggest calling `as_bytes().len()` or `to_bytes().len()` respectively instead."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::struct_excessive_bools",
         description: r##"Checks for excessive
 use of bools in structs."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::suboptimal_flops",
         description: r##"Looks for floating-point expressions that
 can be expressed using built-in methods to improve both
 accuracy and performance."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::suspicious_arithmetic_impl",
         description: r##"Lints for suspicious operations in impls of arithmetic operators, e.g.
 subtracting elements in an Add impl."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::suspicious_assignment_formatting",
         description: r##"Checks for use of the nonexistent `=*`, `=!` and `=-`
 operators."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::suspicious_else_formatting",
         description: r##"Checks for formatting of `else`. It lints if the `else`
 is followed immediately by a newline or the `else` seems to be missing."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::suspicious_map",
         description: r##"Checks for calls to `map` followed by a `count`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::suspicious_op_assign_impl",
         description: r##"Lints for suspicious operations in impls of OpAssign, e.g.
 subtracting elements in an AddAssign impl."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::suspicious_operation_groupings",
         description: r##"Checks for unlikely usages of binary operators that are almost
 certainly typos and/or copy/paste errors, given the other usages
 of binary operators nearby."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::suspicious_splitn",
         description: r##"Checks for calls to [`splitn`]
 (https://doc.rust-lang.org/std/primitive.str.html#method.splitn) and
 related functions with either zero or one splits."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::suspicious_unary_op_formatting",
         description: r##"Checks the formatting of a unary operator on the right hand side
 of a binary operator. It lints if there is no space between the binary and unary operators,
 but there is a space between the unary and its operand."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::tabs_in_doc_comments",
         description: r##"Checks doc comments for usage of tab characters."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::temporary_assignment",
         description: r##"Checks for construction of a structure or tuple just to
 assign a value in it."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::to_digit_is_some",
         description: r##"Checks for `.to_digit(..).is_some()` on `char`s."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::to_string_in_display",
         description: r##"Checks for uses of `to_string()` in `Display` traits."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::to_string_in_format_args",
         description: r##"Checks for [`ToString::to_string`](https://doc.rust-lang.org/std/string/trait.ToString.html#tymethod.to_string)
 applied to a type that implements [`Display`](https://doc.rust-lang.org/std/fmt/trait.Display.html)
 in a macro that does formatting."##,
+        default_level: LintLevel::Warn,
+    },
+    Lint {
+        label: "clippy::todo",
+        description: r##"Checks for usage of `todo!`."##,
+        default_level: LintLevel::Allow,
     },
-    Lint { label: "clippy::todo", description: r##"Checks for usage of `todo!`."## },
     Lint {
         label: "clippy::too_many_arguments",
         description: r##"Checks for functions with too many parameters."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::too_many_lines",
         description: r##"Checks for functions with a large amount of lines."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::toplevel_ref_arg",
         description: r##"Checks for function arguments and let bindings denoted as
 `ref`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::trailing_empty_array",
         description: r##"Displays a warning when a struct with a trailing zero-sized array is declared without a `repr` attribute."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::trait_duplication_in_bounds",
         description: r##"Checks for cases where generics are being used and multiple
 syntax specifications for trait bounds are used simultaneously."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::transmute_bytes_to_str",
         description: r##"Checks for transmutes from a `&[u8]` to a `&str`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::transmute_float_to_int",
         description: r##"Checks for transmutes from a float to an integer."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::transmute_int_to_bool",
         description: r##"Checks for transmutes from an integer to a `bool`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::transmute_int_to_char",
         description: r##"Checks for transmutes from an integer to a `char`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::transmute_int_to_float",
         description: r##"Checks for transmutes from an integer to a float."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::transmute_num_to_bytes",
         description: r##"Checks for transmutes from a number to an array of `u8`"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::transmute_ptr_to_ptr",
         description: r##"Checks for transmutes from a pointer to a pointer, or
 from a reference to a reference."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::transmute_ptr_to_ref",
         description: r##"Checks for transmutes from a pointer to a reference."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::transmutes_expressible_as_ptr_casts",
         description: r##"Checks for transmutes that could be a pointer cast."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::transmuting_null",
         description: r##"Checks for transmute calls which would receive a null pointer."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::trivial_regex",
         description: r##"Checks for trivial [regex](https://crates.io/crates/regex)
 creation (with `Regex::new`, `RegexBuilder::new`, or `RegexSet::new`)."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::trivially_copy_pass_by_ref",
         description: r##"Checks for functions taking arguments by reference, where
 the argument type is `Copy` and small enough to be more efficient to always
 pass by value."##,
+        default_level: LintLevel::Allow,
+    },
+    Lint {
+        label: "clippy::try_err",
+        description: r##"Checks for usages of `Err(x)?`."##,
+        default_level: LintLevel::Warn,
     },
-    Lint { label: "clippy::try_err", description: r##"Checks for usages of `Err(x)?`."## },
     Lint {
         label: "clippy::type_complexity",
         description: r##"Checks for types used in structs, parameters and `let`
 declarations above a certain complexity threshold."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::type_repetition_in_bounds",
         description: r##"This lint warns about unnecessary type repetitions in trait bounds"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::undocumented_unsafe_blocks",
         description: r##"Checks for `unsafe` blocks without a `// Safety: ` comment
 explaining why the unsafe operations performed inside
 the block are safe."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::undropped_manually_drops",
         description: r##"Prevents the safe `std::mem::drop` function from being called on `std::mem::ManuallyDrop`."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::unicode_not_nfc",
         description: r##"Checks for string literals that contain Unicode in a form
 that is not equal to its
 [NFC-recomposition](http://www.unicode.org/reports/tr15/#Norm_Forms)."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::unimplemented",
         description: r##"Checks for usage of `unimplemented!`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::uninit_assumed_init",
         description: r##"Checks for `MaybeUninit::uninit().assume_init()`."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::uninit_vec",
         description: r##"Checks for `set_len()` call that creates `Vec` with uninitialized elements.
 This is commonly caused by calling `set_len()` right after allocating or
 reserving a buffer with `new()`, `default()`, `with_capacity()`, or `reserve()`."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::unit_arg",
         description: r##"Checks for passing a unit value as an argument to a function without using a
 unit literal (`()`)."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::unit_cmp",
         description: r##"Checks for comparisons to unit. This includes all binary
 comparisons (like `==` and `<`) and asserts."##,
+        default_level: LintLevel::Deny,
+    },
+    Lint {
+        label: "clippy::unit_hash",
+        description: r##"Detects `().hash(_)`."##,
+        default_level: LintLevel::Deny,
     },
-    Lint { label: "clippy::unit_hash", description: r##"Detects `().hash(_)`."## },
     Lint {
         label: "clippy::unit_return_expecting_ord",
         description: r##"Checks for functions that expect closures of type
 Fn(...) -> Ord where the implemented closure returns the unit type.
 The lint also suggests to remove the semi-colon at the end of the statement if present."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::unnecessary_cast",
         description: r##"Checks for casts to the same type, casts of int literals to integer types
 and casts of float literals to float types."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::unnecessary_filter_map",
         description: r##"Checks for `filter_map` calls which could be replaced by `filter` or `map`.
 More specifically it checks if the closure provided is only performing one of the
 filter or map operations and suggests the appropriate option."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::unnecessary_fold",
         description: r##"Checks for using `fold` when a more succinct alternative exists.
 Specifically, this checks for `fold`s which could be replaced by `any`, `all`,
 `sum` or `product`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::unnecessary_lazy_evaluations",
@@ -6767,42 +9719,51 @@ fn main() {
  - `or_else` to `or`
  - `get_or_insert_with` to `get_or_insert`
  - `ok_or_else` to `ok_or`"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::unnecessary_mut_passed",
         description: r##"Detects passing a mutable reference to a function that only
 requires an immutable reference."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::unnecessary_operation",
         description: r##"Checks for expression statements that can be reduced to a
 sub-expression."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::unnecessary_self_imports",
         description: r##"Checks for imports ending in `::{self}`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::unnecessary_sort_by",
         description: r##"Detects uses of `Vec::sort_by` passing in a closure
 which compares the two arguments, either directly or indirectly."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::unnecessary_to_owned",
         description: r##"Checks for unnecessary calls to [`ToOwned::to_owned`](https://doc.rust-lang.org/std/borrow/trait.ToOwned.html#tymethod.to_owned)
 and other `to_owned`-like functions."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::unnecessary_unwrap",
         description: r##"Checks for calls of `unwrap[_err]()` that cannot fail."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::unnecessary_wraps",
         description: r##"Checks for private functions that only return `Ok` or `Some`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::unneeded_field_pattern",
         description: r##"Checks for structure field patterns bound to wildcards."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::unneeded_wildcard_pattern",
@@ -6813,6 +9774,7 @@ fn main() {
 means there are 0 or more elements left. This can make a difference
 when refactoring, but shouldn't result in errors in the refactored code,
 since the wildcard pattern isn't used anyway."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::unnested_or_patterns",
@@ -6821,26 +9783,35 @@ fn main() {
 
 Another way to think of this is that it rewrites patterns in
 *disjunctive normal form (DNF)* into *conjunctive normal form (CNF)*."##,
+        default_level: LintLevel::Allow,
+    },
+    Lint {
+        label: "clippy::unreachable",
+        description: r##"Checks for usage of `unreachable!`."##,
+        default_level: LintLevel::Allow,
     },
-    Lint { label: "clippy::unreachable", description: r##"Checks for usage of `unreachable!`."## },
     Lint {
         label: "clippy::unreadable_literal",
         description: r##"Warns if a long integral or floating-point constant does
 not contain underscores."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::unsafe_derive_deserialize",
         description: r##"Checks for deriving `serde::Deserialize` on a type that
 has methods using `unsafe`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::unsafe_removed_from_name",
         description: r##"Checks for imports that remove unsafe from an item's
 name."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::unsafe_vector_initialization",
         description: r##"Nothing. This lint has been deprecated."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::unseparated_literal_suffix",
@@ -6848,81 +9819,99 @@ fn main() {
 underscore.
 To enforce unseparated literal suffix style,
 see the `separated_literal_suffix` lint."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::unsound_collection_transmute",
         description: r##"Checks for transmutes between collections whose
 types have different ABI, size or alignment."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::unstable_as_mut_slice",
         description: r##"Nothing. This lint has been deprecated."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::unstable_as_slice",
         description: r##"Nothing. This lint has been deprecated."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::unused_async",
         description: r##"Checks for functions that are declared `async` but have no `.await`s inside of them."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::unused_collect",
         description: r##"Nothing. This lint has been deprecated."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::unused_io_amount",
         description: r##"Checks for unused written/read amount."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::unused_self",
         description: r##"Checks methods that contain a `self` argument but don't use it"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::unused_unit",
         description: r##"Checks for unit (`()`) expressions that can be removed."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::unusual_byte_groupings",
         description: r##"Warns if hexadecimal or binary literals are not grouped
 by nibble or byte."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::unwrap_in_result",
         description: r##"Checks for functions of type `Result` that contain `expect()` or `unwrap()`"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::unwrap_or_else_default",
         description: r##"Checks for usages of `_.unwrap_or_else(Default::default)` on `Option` and
 `Result` values."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::unwrap_used",
         description: r##"Checks for `.unwrap()` calls on `Option`s and on `Result`s."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::upper_case_acronyms",
         description: r##"Checks for fully capitalized names and optionally names containing a capitalized acronym."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::use_debug",
         description: r##"Checks for use of `Debug` formatting. The purpose of this
 lint is to catch debugging remnants."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::use_self",
         description: r##"Checks for unnecessary repetition of structure name when a
 replacement with `Self` is applicable."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::used_underscore_binding",
         description: r##"Checks for the use of bindings with a single leading
 underscore."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::useless_asref",
         description: r##"Checks for usage of `.as_ref()` or `.as_mut()` where the
 types before and after the call are the same."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::useless_attribute",
@@ -6933,106 +9922,129 @@ fn main() {
 `#[allow(unreachable_pub)]`, `#[allow(clippy::wildcard_imports)]` and
 `#[allow(clippy::enum_glob_use)]` on `use` items and `#[allow(unused_imports)]` on
 `extern crate` items with a `#[macro_use]` attribute."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::useless_conversion",
         description: r##"Checks for `Into`, `TryInto`, `From`, `TryFrom`, or `IntoIter` calls
 which uselessly convert to the same type."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::useless_format",
         description: r##"Checks for the use of `format!(string literal with no
 argument)` and `format!({}, foo)` where `foo` is a string."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::useless_let_if_seq",
         description: r##"Checks for variable declarations immediately followed by a
 conditional affectation."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::useless_transmute",
         description: r##"Checks for transmutes to the original type of the object
 and transmutes that could be a cast."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::useless_vec",
         description: r##"Checks for usage of `&vec![..]` when using `&[..]` would
 be possible."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::vec_box",
         description: r##"Checks for use of `Vec<Box<T>>` where T: Sized anywhere in the code.
 Check the [Box documentation](https://doc.rust-lang.org/std/boxed/index.html) for more information."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::vec_init_then_push",
         description: r##"Checks for calls to `push` immediately after creating a new `Vec`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::vec_resize_to_zero",
         description: r##"Finds occurrences of `Vec::resize(0, an_int)`"##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::verbose_bit_mask",
         description: r##"Checks for bit masks that can be replaced by a call
 to `trailing_zeros`"##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::verbose_file_reads",
         description: r##"Checks for use of File::read_to_end and File::read_to_string."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::vtable_address_comparisons",
         description: r##"Checks for comparisons with an address of a trait vtable."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::while_immutable_condition",
         description: r##"Checks whether variables used within while loop condition
 can be (and are) mutated in the body."##,
+        default_level: LintLevel::Deny,
     },
     Lint {
         label: "clippy::while_let_loop",
         description: r##"Detects `loop + match` combinations that are easier
 written as a `while let` loop."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::while_let_on_iterator",
         description: r##"Checks for `while let` expressions on iterators."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::wildcard_dependencies",
         description: r##"Checks for wildcard dependencies in the `Cargo.toml`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::wildcard_enum_match_arm",
         description: r##"Checks for wildcard enum matches using `_`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::wildcard_imports",
         description: r##"Checks for wildcard imports `use _::*`."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::wildcard_in_or_patterns",
         description: r##"Checks for wildcard pattern used with others patterns in same match arm."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::write_literal",
         description: r##"This lint warns about the use of literals as `write!`/`writeln!` args."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::write_with_newline",
         description: r##"This lint warns when you use `write!()` with a format
 string that
 ends in a newline."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::writeln_empty_string",
         description: r##"This lint warns when you use `writeln!(buf, )` to
 print a newline."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::wrong_pub_self_convention",
         description: r##"Nothing. This lint has been deprecated."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::wrong_self_convention",
@@ -7060,29 +10072,39 @@ fn main() {
 
 Please find more info here:
 https://rust-lang.github.io/api-guidelines/naming.html#ad-hoc-conversions-follow-as_-to_-into_-conventions-c-conv"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::wrong_transmute",
         description: r##"Checks for transmutes that can't ever be correct on any
 architecture."##,
+        default_level: LintLevel::Deny,
+    },
+    Lint {
+        label: "clippy::zero_divided_by_zero",
+        description: r##"Checks for `0.0 / 0.0`."##,
+        default_level: LintLevel::Warn,
     },
-    Lint { label: "clippy::zero_divided_by_zero", description: r##"Checks for `0.0 / 0.0`."## },
     Lint {
         label: "clippy::zero_prefixed_literal",
         description: r##"Warns if an integral constant literal starts with `0`."##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::zero_ptr",
         description: r##"Catch casts from `0` to some pointer type"##,
+        default_level: LintLevel::Warn,
     },
     Lint {
         label: "clippy::zero_sized_map_values",
         description: r##"Checks for maps with zero-sized value types anywhere in the code."##,
+        default_level: LintLevel::Allow,
     },
     Lint {
         label: "clippy::zst_offset",
         description: r##"Checks for `offset(_)`, `wrapping_`{`add`, `sub`}, etc. on raw pointers to
 zero-sized types"##,
+        default_level: LintLevel::Deny,
     },
 ];
 pub const CLIPPY_LINT_GROUPS: &[LintGroup] = &[
@@ -7090,6 +10112,7 @@ fn main() {
         lint: Lint {
             label: "clippy::cargo",
             description: r##"lint group for: clippy::cargo_common_metadata, clippy::multiple_crate_versions, clippy::negative_feature_names, clippy::redundant_feature_names, clippy::wildcard_dependencies"##,
+            default_level: LintLevel::Allow,
         },
         children: &[
             "clippy::cargo_common_metadata",
@@ -7103,6 +10126,7 @@ fn main() {
         lint: Lint {
             label: "clippy::complexity",
             description: r##"lint group for: clippy::bind_instead_of_map, clippy::bool_comparison, clippy::borrowed_box, clippy::char_lit_as_u8, clippy::clone_on_copy, clippy::crosspointer_transmute, clippy::deprecated_cfg_attr, clippy::deref_addrof, clippy::derivable_impls, clippy::diverging_sub_expression, clippy::double_comparisons, clippy::double_parens, clippy::duration_subsec, clippy::explicit_counter_loop, clippy::explicit_write, clippy::extra_unused_lifetimes, clippy::filter_map_identity, clippy::filter_next, clippy::flat_map_identity, clippy::get_last_with_len, clippy::identity_op, clippy::inspect_for_each, clippy::int_plus_one, clippy::iter_count, clippy::manual_filter_map, clippy::manual_find_map, clippy::manual_flatten, clippy::manual_split_once, clippy::manual_strip, clippy::manual_swap, clippy::manual_unwrap_or, clippy::map_flatten, clippy::map_identity, clippy::match_as_ref, clippy::match_single_binding, clippy::needless_arbitrary_self_type, clippy::needless_bool, clippy::needless_borrowed_reference, clippy::needless_lifetimes, clippy::needless_option_as_deref, clippy::needless_question_mark, clippy::needless_splitn, clippy::needless_update, clippy::neg_cmp_op_on_partial_ord, clippy::no_effect, clippy::nonminimal_bool, clippy::option_as_ref_deref, clippy::option_filter_map, clippy::option_map_unit_fn, clippy::overflow_check_conditional, clippy::partialeq_ne_impl, clippy::precedence, clippy::ptr_offset_with_cast, clippy::range_zip_with_len, clippy::redundant_closure_call, clippy::redundant_slicing, clippy::ref_in_deref, clippy::repeat_once, clippy::result_map_unit_fn, clippy::search_is_some, clippy::short_circuit_statement, clippy::single_element_loop, clippy::skip_while_next, clippy::string_from_utf8_as_bytes, clippy::strlen_on_c_strings, clippy::temporary_assignment, clippy::too_many_arguments, clippy::transmute_bytes_to_str, clippy::transmute_float_to_int, clippy::transmute_int_to_bool, clippy::transmute_int_to_char, clippy::transmute_int_to_float, clippy::transmute_num_to_bytes, clippy::transmute_ptr_to_ref, clippy::transmutes_expressible_as_ptr_casts, clippy::type_complexity, clippy::unit_arg, clippy::unnecessary_cast, clippy::unnecessary_filter_map, clippy::unnecessary_operation, clippy::unnecessary_sort_by, clippy::unnecessary_unwrap, clippy::unneeded_wildcard_pattern, clippy::useless_asref, clippy::useless_conversion, clippy::useless_format, clippy::vec_box, clippy::while_let_loop, clippy::wildcard_in_or_patterns, clippy::zero_divided_by_zero, clippy::zero_prefixed_literal"##,
+            default_level: LintLevel::Allow,
         },
         children: &[
             "clippy::bind_instead_of_map",
@@ -7202,6 +10226,7 @@ fn main() {
         lint: Lint {
             label: "clippy::correctness",
             description: r##"lint group for: clippy::absurd_extreme_comparisons, clippy::almost_swapped, clippy::approx_constant, clippy::async_yields_async, clippy::bad_bit_mask, clippy::cast_ref_to_mut, clippy::clone_double_ref, clippy::cmp_nan, clippy::deprecated_semver, clippy::derive_hash_xor_eq, clippy::derive_ord_xor_partial_ord, clippy::drop_copy, clippy::drop_ref, clippy::enum_clike_unportable_variant, clippy::eq_op, clippy::erasing_op, clippy::fn_address_comparisons, clippy::forget_copy, clippy::forget_ref, clippy::if_let_mutex, clippy::if_same_then_else, clippy::ifs_same_cond, clippy::ineffective_bit_mask, clippy::infinite_iter, clippy::inherent_to_string_shadow_display, clippy::inline_fn_without_body, clippy::invalid_null_ptr_usage, clippy::invalid_regex, clippy::invisible_characters, clippy::iter_next_loop, clippy::iterator_step_by_zero, clippy::let_underscore_lock, clippy::logic_bug, clippy::match_str_case_mismatch, clippy::mem_replace_with_uninit, clippy::min_max, clippy::mismatched_target_os, clippy::mistyped_literal_suffixes, clippy::modulo_one, clippy::mut_from_ref, clippy::never_loop, clippy::non_octal_unix_permissions, clippy::nonsensical_open_options, clippy::not_unsafe_ptr_arg_deref, clippy::option_env_unwrap, clippy::out_of_bounds_indexing, clippy::panicking_unwrap, clippy::possible_missing_comma, clippy::reversed_empty_ranges, clippy::self_assignment, clippy::serde_api_misuse, clippy::size_of_in_element_count, clippy::suspicious_splitn, clippy::to_string_in_display, clippy::transmuting_null, clippy::undropped_manually_drops, clippy::uninit_assumed_init, clippy::uninit_vec, clippy::unit_cmp, clippy::unit_hash, clippy::unit_return_expecting_ord, clippy::unsound_collection_transmute, clippy::unused_io_amount, clippy::useless_attribute, clippy::vec_resize_to_zero, clippy::vtable_address_comparisons, clippy::while_immutable_condition, clippy::wrong_transmute, clippy::zst_offset"##,
+            default_level: LintLevel::Allow,
         },
         children: &[
             "clippy::absurd_extreme_comparisons",
@@ -7279,6 +10304,7 @@ fn main() {
         lint: Lint {
             label: "clippy::deprecated",
             description: r##"lint group for: clippy::assign_ops, clippy::extend_from_slice, clippy::filter_map, clippy::find_map, clippy::if_let_redundant_pattern_matching, clippy::misaligned_transmute, clippy::pub_enum_variant_names, clippy::range_step_by_zero, clippy::regex_macro, clippy::replace_consts, clippy::should_assert_eq, clippy::unsafe_vector_initialization, clippy::unstable_as_mut_slice, clippy::unstable_as_slice, clippy::unused_collect, clippy::wrong_pub_self_convention"##,
+            default_level: LintLevel::Allow,
         },
         children: &[
             "clippy::assign_ops",
@@ -7303,6 +10329,7 @@ fn main() {
         lint: Lint {
             label: "clippy::nursery",
             description: r##"lint group for: clippy::branches_sharing_code, clippy::cognitive_complexity, clippy::debug_assert_with_mut_call, clippy::disallowed_methods, clippy::disallowed_types, clippy::empty_line_after_outer_attr, clippy::equatable_if_let, clippy::fallible_impl_from, clippy::future_not_send, clippy::imprecise_flops, clippy::index_refutable_slice, clippy::missing_const_for_fn, clippy::mutex_integer, clippy::non_send_fields_in_send_ty, clippy::nonstandard_macro_braces, clippy::option_if_let_else, clippy::path_buf_push_overwrite, clippy::redundant_pub_crate, clippy::string_lit_as_bytes, clippy::suboptimal_flops, clippy::suspicious_operation_groupings, clippy::trailing_empty_array, clippy::trivial_regex, clippy::use_self, clippy::useless_let_if_seq, clippy::useless_transmute"##,
+            default_level: LintLevel::Allow,
         },
         children: &[
             "clippy::branches_sharing_code",
@@ -7337,6 +10364,7 @@ fn main() {
         lint: Lint {
             label: "clippy::pedantic",
             description: r##"lint group for: clippy::await_holding_lock, clippy::await_holding_refcell_ref, clippy::case_sensitive_file_extension_comparisons, clippy::cast_lossless, clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_precision_loss, clippy::cast_ptr_alignment, clippy::cast_sign_loss, clippy::checked_conversions, clippy::cloned_instead_of_copied, clippy::copy_iterator, clippy::default_trait_access, clippy::doc_markdown, clippy::empty_enum, clippy::enum_glob_use, clippy::expl_impl_clone_on_copy, clippy::explicit_deref_methods, clippy::explicit_into_iter_loop, clippy::explicit_iter_loop, clippy::filter_map_next, clippy::flat_map_option, clippy::float_cmp, clippy::fn_params_excessive_bools, clippy::from_iter_instead_of_collect, clippy::if_not_else, clippy::implicit_clone, clippy::implicit_hasher, clippy::implicit_saturating_sub, clippy::inconsistent_struct_constructor, clippy::inefficient_to_string, clippy::inline_always, clippy::invalid_upcast_comparisons, clippy::items_after_statements, clippy::iter_not_returning_iterator, clippy::large_digit_groups, clippy::large_stack_arrays, clippy::large_types_passed_by_value, clippy::let_underscore_drop, clippy::let_unit_value, clippy::linkedlist, clippy::macro_use_imports, clippy::manual_assert, clippy::manual_ok_or, clippy::many_single_char_names, clippy::map_unwrap_or, clippy::match_bool, clippy::match_on_vec_items, clippy::match_same_arms, clippy::match_wild_err_arm, clippy::match_wildcard_for_single_variants, clippy::maybe_infinite_iter, clippy::missing_errors_doc, clippy::missing_panics_doc, clippy::module_name_repetitions, clippy::must_use_candidate, clippy::mut_mut, clippy::naive_bytecount, clippy::needless_bitwise_bool, clippy::needless_continue, clippy::needless_for_each, clippy::needless_pass_by_value, clippy::no_effect_underscore_binding, clippy::option_option, clippy::ptr_as_ptr, clippy::range_minus_one, clippy::range_plus_one, clippy::redundant_closure_for_method_calls, clippy::redundant_else, clippy::ref_binding_to_reference, clippy::ref_option_ref, clippy::same_functions_in_if_condition, clippy::semicolon_if_nothing_returned, clippy::similar_names, clippy::single_match_else, clippy::string_add_assign, clippy::struct_excessive_bools, clippy::too_many_lines, clippy::trait_duplication_in_bounds, clippy::transmute_ptr_to_ptr, clippy::trivially_copy_pass_by_ref, clippy::type_repetition_in_bounds, clippy::unicode_not_nfc, clippy::unnecessary_wraps, clippy::unnested_or_patterns, clippy::unreadable_literal, clippy::unsafe_derive_deserialize, clippy::unused_async, clippy::unused_self, clippy::used_underscore_binding, clippy::verbose_bit_mask, clippy::wildcard_imports, clippy::zero_sized_map_values"##,
+            default_level: LintLevel::Allow,
         },
         children: &[
             "clippy::await_holding_lock",
@@ -7438,6 +10466,7 @@ fn main() {
         lint: Lint {
             label: "clippy::perf",
             description: r##"lint group for: clippy::box_collection, clippy::boxed_local, clippy::cmp_owned, clippy::expect_fun_call, clippy::extend_with_drain, clippy::format_in_format_args, clippy::iter_nth, clippy::large_const_arrays, clippy::large_enum_variant, clippy::manual_memcpy, clippy::manual_str_repeat, clippy::map_entry, clippy::mutex_atomic, clippy::needless_collect, clippy::or_fun_call, clippy::redundant_allocation, clippy::redundant_clone, clippy::single_char_pattern, clippy::slow_vector_initialization, clippy::stable_sort_primitive, clippy::to_string_in_format_args, clippy::unnecessary_to_owned, clippy::useless_vec, clippy::vec_init_then_push"##,
+            default_level: LintLevel::Allow,
         },
         children: &[
             "clippy::box_collection",
@@ -7470,6 +10499,7 @@ fn main() {
         lint: Lint {
             label: "clippy::restriction",
             description: r##"lint group for: clippy::as_conversions, clippy::clone_on_ref_ptr, clippy::create_dir, clippy::dbg_macro, clippy::decimal_literal_representation, clippy::default_numeric_fallback, clippy::disallowed_script_idents, clippy::else_if_without_else, clippy::exhaustive_enums, clippy::exhaustive_structs, clippy::exit, clippy::expect_used, clippy::filetype_is_file, clippy::float_arithmetic, clippy::float_cmp_const, clippy::fn_to_numeric_cast_any, clippy::get_unwrap, clippy::if_then_some_else_none, clippy::implicit_return, clippy::indexing_slicing, clippy::inline_asm_x86_att_syntax, clippy::inline_asm_x86_intel_syntax, clippy::integer_arithmetic, clippy::integer_division, clippy::let_underscore_must_use, clippy::lossy_float_literal, clippy::map_err_ignore, clippy::mem_forget, clippy::missing_docs_in_private_items, clippy::missing_enforced_import_renames, clippy::missing_inline_in_public_items, clippy::mod_module_files, clippy::modulo_arithmetic, clippy::multiple_inherent_impl, clippy::non_ascii_literal, clippy::panic, clippy::panic_in_result_fn, clippy::pattern_type_mismatch, clippy::print_stderr, clippy::print_stdout, clippy::rc_buffer, clippy::rc_mutex, clippy::rest_pat_in_fully_bound_structs, clippy::same_name_method, clippy::self_named_module_files, clippy::separated_literal_suffix, clippy::shadow_reuse, clippy::shadow_same, clippy::shadow_unrelated, clippy::str_to_string, clippy::string_add, clippy::string_slice, clippy::string_to_string, clippy::todo, clippy::undocumented_unsafe_blocks, clippy::unimplemented, clippy::unnecessary_self_imports, clippy::unneeded_field_pattern, clippy::unreachable, clippy::unseparated_literal_suffix, clippy::unwrap_in_result, clippy::unwrap_used, clippy::use_debug, clippy::verbose_file_reads, clippy::wildcard_enum_match_arm"##,
+            default_level: LintLevel::Allow,
         },
         children: &[
             "clippy::as_conversions",
@@ -7543,6 +10573,7 @@ fn main() {
         lint: Lint {
             label: "clippy::style",
             description: r##"lint group for: clippy::assertions_on_constants, clippy::assign_op_pattern, clippy::blacklisted_name, clippy::blocks_in_if_conditions, clippy::bool_assert_comparison, clippy::borrow_interior_mutable_const, clippy::builtin_type_shadow, clippy::bytes_nth, clippy::chars_last_cmp, clippy::chars_next_cmp, clippy::cmp_null, clippy::collapsible_else_if, clippy::collapsible_if, clippy::collapsible_match, clippy::comparison_chain, clippy::comparison_to_empty, clippy::declare_interior_mutable_const, clippy::double_must_use, clippy::double_neg, clippy::duplicate_underscore_argument, clippy::enum_variant_names, clippy::excessive_precision, clippy::field_reassign_with_default, clippy::fn_to_numeric_cast, clippy::fn_to_numeric_cast_with_truncation, clippy::for_kv_map, clippy::from_over_into, clippy::from_str_radix_10, clippy::inconsistent_digit_grouping, clippy::infallible_destructuring_match, clippy::inherent_to_string, clippy::into_iter_on_ref, clippy::iter_cloned_collect, clippy::iter_next_slice, clippy::iter_nth_zero, clippy::iter_skip_next, clippy::just_underscores_and_digits, clippy::len_without_is_empty, clippy::len_zero, clippy::let_and_return, clippy::main_recursion, clippy::manual_async_fn, clippy::manual_map, clippy::manual_non_exhaustive, clippy::manual_range_contains, clippy::manual_saturating_arithmetic, clippy::map_clone, clippy::map_collect_result_unit, clippy::match_like_matches_macro, clippy::match_overlapping_arm, clippy::match_ref_pats, clippy::match_result_ok, clippy::mem_replace_option_with_none, clippy::mem_replace_with_default, clippy::missing_safety_doc, clippy::mixed_case_hex_literals, clippy::module_inception, clippy::must_use_unit, clippy::mut_mutex_lock, clippy::needless_borrow, clippy::needless_doctest_main, clippy::needless_late_init, clippy::needless_range_loop, clippy::needless_return, clippy::neg_multiply, clippy::new_ret_no_self, clippy::new_without_default, clippy::ok_expect, clippy::op_ref, clippy::option_map_or_none, clippy::print_literal, clippy::print_with_newline, clippy::println_empty_string, clippy::ptr_arg, clippy::ptr_eq, clippy::question_mark, clippy::redundant_closure, clippy::redundant_field_names, clippy::redundant_pattern, clippy::redundant_pattern_matching, clippy::redundant_static_lifetimes, clippy::result_map_or_into_option, clippy::result_unit_err, clippy::same_item_push, clippy::self_named_constructors, clippy::should_implement_trait, clippy::single_char_add_str, clippy::single_component_path_imports, clippy::single_match, clippy::string_extend_chars, clippy::tabs_in_doc_comments, clippy::to_digit_is_some, clippy::toplevel_ref_arg, clippy::try_err, clippy::unnecessary_fold, clippy::unnecessary_lazy_evaluations, clippy::unnecessary_mut_passed, clippy::unsafe_removed_from_name, clippy::unused_unit, clippy::unusual_byte_groupings, clippy::unwrap_or_else_default, clippy::upper_case_acronyms, clippy::while_let_on_iterator, clippy::write_literal, clippy::write_with_newline, clippy::writeln_empty_string, clippy::wrong_self_convention, clippy::zero_ptr"##,
+            default_level: LintLevel::Allow,
         },
         children: &[
             "clippy::assertions_on_constants",
@@ -7659,6 +10690,7 @@ fn main() {
         lint: Lint {
             label: "clippy::suspicious",
             description: r##"lint group for: clippy::blanket_clippy_restriction_lints, clippy::empty_loop, clippy::eval_order_dependence, clippy::float_equality_without_abs, clippy::for_loops_over_fallibles, clippy::misrefactored_assign_op, clippy::mut_range_bound, clippy::mutable_key_type, clippy::octal_escapes, clippy::return_self_not_must_use, clippy::suspicious_arithmetic_impl, clippy::suspicious_assignment_formatting, clippy::suspicious_else_formatting, clippy::suspicious_map, clippy::suspicious_op_assign_impl, clippy::suspicious_unary_op_formatting"##,
+            default_level: LintLevel::Allow,
         },
         children: &[
             "clippy::blanket_clippy_restriction_lints",
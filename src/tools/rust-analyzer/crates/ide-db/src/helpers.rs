@@ -2,14 +2,26 @@
 
 use std::collections::VecDeque;
 
-use base_db::{FileId, SourceDatabaseExt};
-use hir::{Crate, ItemInNs, ModuleDef, Name, Semantics};
+use base_db::{FileId, FilePosition, SourceDatabaseExt};
+use hir::{
+    AsAssocItem, AssocItemContainer, Crate, ItemInNs, ModPath, ModuleDef, Name, PathKind,
+    Semantics, SemanticsScope,
+};
 use syntax::{
     ast::{self, make},
-    AstToken, SyntaxKind, SyntaxToken, TokenAtOffset,
+    AstNode, AstToken, SyntaxKind, SyntaxToken, TokenAtOffset,
 };
 
-use crate::{defs::Definition, generated, RootDatabase};
+use crate::{
+    defs::Definition,
+    generated::lints::{
+        Feature, Lint, LintGroup, RemovedLint, RenamedLint, CLIPPY_LINTS, CLIPPY_LINT_GROUPS,
+        DEFAULT_LINTS, DEFAULT_LINT_GROUPS, FEATURES, REMOVED_LINTS, RENAMED_LINTS, RUSTDOC_LINTS,
+        RUSTDOC_LINT_GROUPS,
+    },
+    parallel::par_map,
+    RootDatabase,
+};
 
 pub fn item_name(db: &RootDatabase, item: ItemInNs) -> Option<Name> {
     match item {
@@ -54,17 +66,169 @@ pub fn mod_path_to_ast(path: &hir::ModPath) -> ast::Path {
     make::path_from_segments(segments, is_abs)
 }
 
+/// The scope at `position`, as seen by the token before it.
+pub fn scope_at_position<'db>(
+    sema: &Semantics<'db, RootDatabase>,
+    position: FilePosition,
+) -> Option<SemanticsScope<'db>> {
+    let file = sema.parse(position.file_id);
+    let token = file.syntax().token_at_offset(position.offset).left_biased()?;
+    sema.scope_at_offset(&token.parent()?, position.offset)
+}
+
+/// Computes the shortest path to `def` that is valid in `scope`.
+///
+/// Names that are already in scope, e.g. through an existing import, are used as is, even if the
+/// import renames the definition. Otherwise
+/// the shortest importable path is used, which goes through public re-exports like `a::Foo`
+/// rather than the module the definition is declared in, like `a::internal::Foo`. As a last
+/// resort the path is built from the modules the definition is declared in, starting at the root
+/// of its crate.
+pub fn path_to_definition(
+    scope: &SemanticsScope<'_>,
+    def: Definition,
+    prefer_no_std: bool,
+) -> Option<ModPath> {
+    let db = scope.db;
+    let module_def = match def {
+        Definition::Macro(it) => ModuleDef::Macro(it),
+        Definition::Module(it) => ModuleDef::Module(it),
+        Definition::Function(it) => ModuleDef::Function(it),
+        Definition::Adt(it) => ModuleDef::Adt(it),
+        Definition::Variant(it) => ModuleDef::Variant(it),
+        Definition::Const(it) => ModuleDef::Const(it),
+        Definition::Static(it) => ModuleDef::Static(it),
+        Definition::Trait(it) => ModuleDef::Trait(it),
+        Definition::TraitAlias(it) => ModuleDef::TraitAlias(it),
+        Definition::TypeAlias(it) => ModuleDef::TypeAlias(it),
+        Definition::BuiltinType(it) => ModuleDef::BuiltinType(it),
+        _ => return None,
+    };
+    if let Some(name) = def.name_in_scope(scope) {
+        return Some(ModPath::from_segments(PathKind::Plain, Some(name)));
+    }
+    let name = module_def.name(db);
+
+    // Associated items aren't importable, they are reached through their trait or type.
+    if let Some(assoc) = module_def.as_assoc_item(db) {
+        let container = match assoc.container(db) {
+            AssocItemContainer::Trait(it) => Definition::Trait(it),
+            AssocItemContainer::Impl(it) => Definition::Adt(it.self_ty(db).as_adt()?),
+        };
+        let mut path = path_to_definition(scope, container, prefer_no_std)?;
+        path.push_segment(name?);
+        return Some(path);
+    }
+
+    let item = match module_def {
+        ModuleDef::Macro(it) => ItemInNs::Macros(it),
+        it => it.into(),
+    };
+    if let Some(path) = scope.module().find_use_path(db.upcast(), item, prefer_no_std) {
+        return Some(path);
+    }
+
+    let module = match module_def {
+        ModuleDef::Variant(it) => {
+            let mut path = path_to_definition(
+                scope,
+                Definition::Adt(it.parent_enum(db).into()),
+                prefer_no_std,
+            )?;
+            path.push_segment(name?);
+            return Some(path);
+        }
+        _ => module_def.module(db)?,
+    };
+    let mut modules = module.path_to_root(db);
+    // The crate root has no name, it's the start of the path.
+    modules.pop();
+    let mut segments =
+        modules.into_iter().rev().map(|it| it.name(db)).collect::<Option<Vec<_>>>()?;
+    segments.push(name?);
+    let krate = module.krate();
+    if krate == scope.krate() {
+        return Some(ModPath::from_segments(PathKind::Crate, segments));
+    }
+    let dep = scope.krate().dependencies(db).into_iter().find(|dep| dep.krate == krate)?;
+    segments.insert(0, dep.name);
+    Some(ModPath::from_segments(PathKind::Abs, segments))
+}
+
+/// A kind of definition [`visit_file_defs_of_kinds`] can be restricted to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefKind {
+    Module,
+    Function,
+    Adt,
+    Const,
+    Static,
+    Trait,
+    TraitAlias,
+    TypeAlias,
+    Impl,
+    Macro,
+}
+
+impl DefKind {
+    pub const ALL: &'static [DefKind] = &[
+        DefKind::Module,
+        DefKind::Function,
+        DefKind::Adt,
+        DefKind::Const,
+        DefKind::Static,
+        DefKind::Trait,
+        DefKind::TraitAlias,
+        DefKind::TypeAlias,
+        DefKind::Impl,
+        DefKind::Macro,
+    ];
+
+    fn of(def: Definition) -> Option<DefKind> {
+        let kind = match def {
+            Definition::Module(_) => DefKind::Module,
+            Definition::Function(_) => DefKind::Function,
+            Definition::Adt(_) => DefKind::Adt,
+            Definition::Const(_) => DefKind::Const,
+            Definition::Static(_) => DefKind::Static,
+            Definition::Trait(_) => DefKind::Trait,
+            Definition::TraitAlias(_) => DefKind::TraitAlias,
+            Definition::TypeAlias(_) => DefKind::TypeAlias,
+            Definition::SelfType(_) => DefKind::Impl,
+            Definition::Macro(_) => DefKind::Macro,
+            _ => return None,
+        };
+        Some(kind)
+    }
+}
+
 /// Iterates all `ModuleDef`s and `Impl` blocks of the given file.
 pub fn visit_file_defs(
     sema: &Semantics<'_, RootDatabase>,
     file_id: FileId,
     cb: &mut dyn FnMut(Definition),
+) {
+    visit_file_defs_of_kinds(sema, file_id, DefKind::ALL, cb)
+}
+
+/// Iterates the `ModuleDef`s and `Impl` blocks of the given file that are of one of `kinds`.
+/// Inline modules are visited even if modules aren't included, to get to their definitions.
+pub fn visit_file_defs_of_kinds(
+    sema: &Semantics<'_, RootDatabase>,
+    file_id: FileId,
+    kinds: &[DefKind],
+    cb: &mut dyn FnMut(Definition),
 ) {
     let db = sema.db;
     let module = match sema.to_module_def(file_id) {
         Some(it) => it,
         None => return,
     };
+    let mut cb = |def: Definition| {
+        if DefKind::of(def).map_or(false, |it| kinds.contains(&it)) {
+            cb(def)
+        }
+    };
     let mut defs: VecDeque<_> = module.declarations(db).into();
     while let Some(def) = defs.pop_front() {
         if let ModuleDef::Module(submodule) = def {
@@ -77,6 +241,9 @@ pub fn visit_file_defs(
     }
     module.impl_defs(db).into_iter().for_each(|impl_| cb(impl_.into()));
 
+    if !kinds.contains(&DefKind::Macro) {
+        return;
+    }
     let is_root = module.is_crate_root();
     module
         .legacy_macros(db)
@@ -86,16 +253,45 @@ pub fn visit_file_defs(
         .for_each(|mac| cb(mac.into()));
 }
 
+/// Visits the definitions of `kinds` in all files of `krate` like [`visit_file_defs_of_kinds`],
+/// mapping them with `f`. The files are visited in parallel, each on its own snapshot of the
+/// database.
+pub fn par_visit_crate_defs<T: Send>(
+    db: &RootDatabase,
+    krate: Crate,
+    kinds: &[DefKind],
+    f: impl Fn(&Semantics<'_, RootDatabase>, FileId, Definition) -> Option<T> + Sync,
+) -> Vec<(FileId, Vec<T>)> {
+    let _p = profile::span("par_visit_crate_defs");
+    let mut files: Vec<FileId> = krate
+        .modules(db)
+        .into_iter()
+        .map(|module| module.definition_source(db))
+        .filter(|src| matches!(src.value, hir::ModuleSource::SourceFile(_)))
+        .filter_map(|src| src.file_id.file_id())
+        .collect();
+    files.sort_unstable();
+    files.dedup();
+    par_map(db, files, |db, file_id| {
+        let sema = Semantics::new(db);
+        let mut res = Vec::new();
+        visit_file_defs_of_kinds(&sema, file_id, kinds, &mut |def| {
+            res.extend(f(&sema, file_id, def))
+        });
+        (file_id, res)
+    })
+}
+
 /// Checks if the given lint is equal or is contained by the other lint which may or may not be a group.
 pub fn lint_eq_or_in_group(lint: &str, lint_is: &str) -> bool {
     if lint == lint_is {
         return true;
     }
 
-    if let Some(group) = generated::lints::DEFAULT_LINT_GROUPS
+    if let Some(group) = DEFAULT_LINT_GROUPS
         .iter()
-        .chain(generated::lints::CLIPPY_LINT_GROUPS.iter())
-        .chain(generated::lints::RUSTDOC_LINT_GROUPS.iter())
+        .chain(CLIPPY_LINT_GROUPS.iter())
+        .chain(RUSTDOC_LINT_GROUPS.iter())
         .find(|&check| check.lint.label == lint_is)
     {
         group.children.contains(&lint)
@@ -104,6 +300,38 @@ pub fn lint_eq_or_in_group(lint: &str, lint_is: &str) -> bool {
     }
 }
 
+/// What a lint name, as written in a lint attribute like `#[allow(...)]`, refers to.
+#[derive(Clone, Copy)]
+pub enum LintInfo {
+    Lint(&'static Lint),
+    Group(&'static LintGroup),
+    Renamed(&'static RenamedLint),
+    Removed(&'static RemovedLint),
+}
+
+/// Looks up a lint of rustc, rustdoc or clippy by its name, qualified with its tool like
+/// `clippy::needless_return` for tool lints.
+pub fn lookup_lint(name: &str) -> Option<LintInfo> {
+    let groups = [DEFAULT_LINT_GROUPS, RUSTDOC_LINT_GROUPS, CLIPPY_LINT_GROUPS];
+    let lints = [DEFAULT_LINTS, RUSTDOC_LINTS, CLIPPY_LINTS];
+    // The lint tables of rustc contain its groups as well.
+    if let Some(group) = groups.into_iter().flatten().find(|it| it.lint.label == name) {
+        return Some(LintInfo::Group(group));
+    }
+    if let Some(lint) = lints.into_iter().flatten().find(|it| it.label == name) {
+        return Some(LintInfo::Lint(lint));
+    }
+    if let Some(renamed) = RENAMED_LINTS.iter().find(|it| it.old == name) {
+        return Some(LintInfo::Renamed(renamed));
+    }
+    REMOVED_LINTS.iter().find(|it| it.label == name).map(LintInfo::Removed)
+}
+
+/// Looks up a feature gate, as written in `#![feature(...)]`, by its name.
+pub fn lookup_feature(name: &str) -> Option<&'static Feature> {
+    FEATURES.binary_search_by_key(&name, |it| it.label).ok().map(|idx| &FEATURES[idx])
+}
+
 pub fn is_editable_crate(krate: Crate, db: &RootDatabase) -> bool {
     let root_file = krate.root_file(db);
     let source_root_id = db.file_source_root(root_file);
@@ -1,4 +1,6 @@
 //! Look up accessible paths for items.
+use std::cmp::Reverse;
+
 use hir::{
     AsAssocItem, AssocItem, AssocItemContainer, Crate, ItemInNs, ModPath, Module, ModuleDef,
     PathResolution, PrefixKind, ScopeDef, Semantics, SemanticsScope, Type,
@@ -13,6 +15,7 @@
 
 use crate::{
     helpers::item_name,
+    imports::insert_use::ImportPrefs,
     items_locator::{self, AssocItemSearch, DEFAULT_QUERY_SEARCH_LIMIT},
     RootDatabase,
 };
@@ -211,21 +214,20 @@ pub fn import_candidate(&self) -> &ImportCandidate {
     pub fn search_for_imports(
         &self,
         sema: &Semantics<'_, RootDatabase>,
-        prefix_kind: PrefixKind,
-        prefer_no_std: bool,
+        prefs: &ImportPrefs,
     ) -> Vec<LocatedImport> {
         let _p = profile::span("import_assets::search_for_imports");
-        self.search_for(sema, Some(prefix_kind), prefer_no_std)
+        self.search_for(sema, Some(prefs.prefix_kind), prefs.prefer_no_std)
     }
 
     /// This may return non-absolute paths if a part of the returned path is already imported into scope.
     pub fn search_for_relative_paths(
         &self,
         sema: &Semantics<'_, RootDatabase>,
-        prefer_no_std: bool,
+        prefs: &ImportPrefs,
     ) -> Vec<LocatedImport> {
         let _p = profile::span("import_assets::search_for_relative_paths");
-        self.search_for(sema, None, prefer_no_std)
+        self.search_for(sema, None, prefs.prefer_no_std)
     }
 
     pub fn path_fuzzy_name_to_exact(&mut self, case_sensitive: bool) {
@@ -279,10 +281,40 @@ fn search_for(
         .into_iter()
         .filter(|import| import.import_path.len() > 1)
         .filter(|import| !scope_definitions.contains(&ScopeDef::from(import.item_to_import)))
-        .sorted_by(|a, b| a.import_path.cmp(&b.import_path))
+        // Traits are ordered by how likely they're the one meant, everything else by path.
+        .sorted_by_cached_key(|import| {
+            (Reverse(self.trait_import_score(sema.db, import)), import.import_path.clone())
+        })
         .collect()
     }
 
+    /// Scores how likely the trait imported by `import` is the one whose method or associated
+    /// item is accessed, higher scores being more likely. Returns `None` for imports of path
+    /// candidates.
+    ///
+    /// Short paths, extension traits like `FutureExt`, traits of crates that many crates in the
+    /// workspace depend on and traits exported by a `prelude` module score higher.
+    pub fn trait_import_score(&self, db: &RootDatabase, import: &LocatedImport) -> Option<u32> {
+        if let ImportCandidate::Path(_) = self.import_candidate {
+            return None;
+        }
+        let Some(ModuleDef::Trait(trait_)) = import.item_to_import.as_module_def() else {
+            return Some(0);
+        };
+        let krate = trait_.module(db).krate();
+
+        let path_len = import.import_path.len() as u32;
+        let mut score = 2 * 8u32.saturating_sub(path_len);
+        if trait_.name(db).to_smol_str().ends_with("Ext") {
+            score += 8;
+        }
+        score += (krate.reverse_dependencies(db).len() as u32).min(8);
+        if is_in_prelude(db, krate, trait_) {
+            score += 6;
+        }
+        Some(score)
+    }
+
     fn scope_definitions(&self, sema: &Semantics<'_, RootDatabase>) -> FxHashSet<ScopeDef> {
         let _p = profile::span("import_assets::scope_definitions");
         let mut scope_definitions = FxHashSet::default();
@@ -295,6 +327,20 @@ fn scope_definitions(&self, sema: &Semantics<'_, RootDatabase>) -> FxHashSet<Sco
     }
 }
 
+/// Whether a `prelude` module at the root of `krate`, or one of its submodules, exports `trait_`.
+fn is_in_prelude(db: &RootDatabase, krate: Crate, trait_: hir::Trait) -> bool {
+    let exports_trait = |module: Module| {
+        module.scope(db, None).into_iter().any(
+            |(_, def)| matches!(def, ScopeDef::ModuleDef(ModuleDef::Trait(it)) if it == trait_),
+        )
+    };
+    krate
+        .root_module(db)
+        .children(db)
+        .filter(|module| module.name(db).map_or(false, |name| name.to_smol_str() == "prelude"))
+        .any(|prelude| exports_trait(prelude) || prelude.children(db).any(exports_trait))
+}
+
 fn path_applicable_imports(
     sema: &Semantics<'_, RootDatabase>,
     current_crate: Crate,
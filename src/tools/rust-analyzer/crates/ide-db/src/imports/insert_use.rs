@@ -4,7 +4,8 @@
 
 use std::cmp::Ordering;
 
-use hir::Semantics;
+use hir::{Semantics, SemanticsScope};
+use stdx::to_upper_camel_case;
 use syntax::{
     algo,
     ast::{
@@ -34,15 +35,70 @@ pub enum ImportGranularity {
     Module,
     /// Flatten imports so that each has its own use statement.
     Item,
+    /// Merge all imports into a single use statement as long as they have the same visibility
+    /// and attributes.
+    One,
 }
 
+/// Which groups imports are sorted into when grouping them.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ImportGroupStyle {
+    /// A group each for `std` and `core`, external crates, and paths starting with `crate`,
+    /// `self` and `super`.
+    PathKind,
+    /// The groups of rustfmt's `group_imports = "StdExternalCrate"`: `std`, `core` and `alloc`,
+    /// external crates, and paths local to the crate.
+    StdExternalCrate,
+}
+
+/// Where imports are inserted.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ImportLocation {
+    /// At the top of the enclosing module.
+    Module,
+    /// As the first statements of the innermost block enclosing the usage, or at the top of the
+    /// enclosing module outside of blocks.
+    Block,
+}
+
+/// How imports are written, shared by everything that inserts imports or computes the paths
+/// they import.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct InsertUseConfig {
+pub struct ImportPrefs {
     pub granularity: ImportGranularity,
     pub enforce_granularity: bool,
     pub prefix_kind: PrefixKind,
     pub group: bool,
+    pub group_style: ImportGroupStyle,
     pub skip_glob_imports: bool,
+    pub location: ImportLocation,
+    /// Whether to import items from `core` instead of `std` where possible.
+    pub prefer_no_std: bool,
+}
+
+/// Changes to the [`ImportPrefs`] for a crate whose imports are written in a different style
+/// than the rest of the workspace, like a vendored crate.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ImportPrefsOverride {
+    pub granularity: Option<ImportGranularity>,
+    pub prefix_kind: Option<PrefixKind>,
+    pub group: Option<bool>,
+    pub prefer_no_std: Option<bool>,
+    pub skip_glob_imports: Option<bool>,
+}
+
+impl ImportPrefs {
+    /// The preferences with the ones set by `override_` replaced.
+    pub fn with_override(self, override_: &ImportPrefsOverride) -> ImportPrefs {
+        ImportPrefs {
+            granularity: override_.granularity.unwrap_or(self.granularity),
+            prefix_kind: override_.prefix_kind.unwrap_or(self.prefix_kind),
+            group: override_.group.unwrap_or(self.group),
+            prefer_no_std: override_.prefer_no_std.unwrap_or(self.prefer_no_std),
+            skip_glob_imports: override_.skip_glob_imports.unwrap_or(self.skip_glob_imports),
+            ..self
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -138,6 +194,30 @@ fn contains_cfg_attr(attrs: &dyn HasAttrs) -> bool {
         None
     }
 
+    /// Determines the containing syntax node in which to insert a `use` statement affecting
+    /// `position`, taking the configured [`ImportLocation`] into account.
+    pub fn find_insert_use_container_with_config(
+        position: &SyntaxNode,
+        sema: &Semantics<'_, RootDatabase>,
+        cfg: &ImportPrefs,
+    ) -> Option<Self> {
+        if cfg.location == ImportLocation::Block {
+            for syntax in sema.ancestors_with_macros(position.clone()) {
+                match ast::Item::cast(syntax.clone()) {
+                    // Don't look past the item containing `position`.
+                    Some(ast::Item::MacroCall(_)) | None => (),
+                    Some(_) => break,
+                }
+                if let Some(stmt_list) = ast::StmtList::cast(syntax) {
+                    if let Some(stmt_list) = sema.original_ast_node(stmt_list) {
+                        return Some(ImportScope::Block(stmt_list));
+                    }
+                }
+            }
+        }
+        ImportScope::find_insert_use_container(position, sema)
+    }
+
     pub fn as_syntax_node(&self) -> &SyntaxNode {
         match self {
             ImportScope::File(file) => file.syntax(),
@@ -156,11 +236,62 @@ pub fn clone_for_update(&self) -> Self {
 }
 
 /// Insert an import path into the given file/node. A `merge` value of none indicates that no import merging is allowed to occur.
-pub fn insert_use(scope: &ImportScope, path: ast::Path, cfg: &InsertUseConfig) {
+pub fn insert_use(scope: &ImportScope, path: ast::Path, cfg: &ImportPrefs) {
     let _p = profile::span("insert_use");
+    insert_use_with_rename(scope, path, None, cfg)
+}
+
+/// Inserts an import of `path` renamed to `alias`, like `use path::Item as Alias;`. Renamed
+/// imports are never merged into existing ones.
+pub fn insert_use_as_alias(
+    scope: &ImportScope,
+    path: ast::Path,
+    alias: ast::Name,
+    cfg: &ImportPrefs,
+) {
+    let _p = profile::span("insert_use_as_alias");
+    insert_use_with_rename(scope, path, Some(make::rename(alias)), cfg)
+}
+
+/// Picks the name to import `path` as in `scope`, if importing it under its own name would shadow
+/// or conflict with a different item of the same name that's already in scope. The alias is
+/// made up of the name and the module it's imported from, like `IoResult` for `std::io::Result`.
+pub fn import_alias_on_conflict(scope: &SemanticsScope<'_>, path: &ast::Path) -> Option<ast::Name> {
+    let name = path.segment()?.name_ref()?;
+    let imported = scope.speculative_resolve(path)?;
+    let in_scope = |name: &str| scope.speculative_resolve(&make::ext::ident_path(name));
+    match in_scope(name.text().as_str()) {
+        Some(existing) if existing != imported => (),
+        _ => return None,
+    }
+
+    let module = path.qualifier().and_then(|it| match it.segment()?.kind()? {
+        PathSegmentKind::Name(name_ref) => Some(name_ref),
+        _ => None,
+    });
+    let is_type = name.text().starts_with(char::is_uppercase);
+    let alias = match (module, is_type) {
+        (Some(module), true) => format!("{}{name}", to_upper_camel_case(module.text().as_str())),
+        (Some(module), false) => format!("{module}_{name}"),
+        (None, true) => format!("{name}Alias"),
+        (None, false) => format!("{name}_alias"),
+    };
+    let alias = (1..)
+        .map(|idx| if idx == 1 { alias.clone() } else { format!("{alias}{idx}") })
+        .find(|it| in_scope(it).is_none())?;
+    Some(make::name(&alias))
+}
+
+fn insert_use_with_rename(
+    scope: &ImportScope,
+    path: ast::Path,
+    rename: Option<ast::Rename>,
+    cfg: &ImportPrefs,
+) {
     let mut mb = match cfg.granularity {
         ImportGranularity::Crate => Some(MergeBehavior::Crate),
         ImportGranularity::Module => Some(MergeBehavior::Module),
+        ImportGranularity::One => Some(MergeBehavior::One),
         ImportGranularity::Item | ImportGranularity::Preserve => None,
     };
     if !cfg.enforce_granularity {
@@ -172,11 +303,21 @@ pub fn insert_use(scope: &ImportScope, path: ast::Path, cfg: &InsertUseConfig) {
             ImportGranularityGuess::ModuleOrItem => mb.and(Some(MergeBehavior::Module)),
             ImportGranularityGuess::Crate => Some(MergeBehavior::Crate),
             ImportGranularityGuess::CrateOrModule => mb.or(Some(MergeBehavior::Crate)),
+            ImportGranularityGuess::One => Some(MergeBehavior::One),
         };
     }
 
+    if rename.is_some() {
+        mb = None;
+    }
+
     let use_item =
-        make::use_(None, make::use_tree(path.clone(), None, None, false)).clone_for_update();
+        make::use_(None, make::use_tree(path.clone(), None, rename, false)).clone_for_update();
+    if mb == Some(MergeBehavior::One) {
+        if let Some(use_tree) = use_item.use_tree() {
+            use_tree.wrap_in_tree_list();
+        }
+    }
     // merge into existing imports if possible
     if let Some(mb) = mb {
         let filter = |it: &_| !(cfg.skip_glob_imports && ast::Use::is_simple_glob(it));
@@ -192,7 +333,7 @@ pub fn insert_use(scope: &ImportScope, path: ast::Path, cfg: &InsertUseConfig) {
 
     // either we weren't allowed to merge or there is no import that fits the merge conditions
     // so look for the place we have to insert to
-    insert_use_(scope, &path, cfg.group, use_item);
+    insert_use_(scope, &path, cfg.group.then_some(cfg.group_style), use_item);
 }
 
 pub fn ast_to_remove_for_path_in_use_stmt(path: &ast::Path) -> Option<Box<dyn Removable>> {
@@ -217,7 +358,7 @@ pub fn remove_path_if_in_use_stmt(path: &ast::Path) {
 }
 
 #[derive(Eq, PartialEq, PartialOrd, Ord)]
-enum ImportGroup {
+pub(super) enum ImportGroup {
     // the order here defines the order of new group inserts
     Std,
     ExternCrate,
@@ -227,7 +368,24 @@ enum ImportGroup {
 }
 
 impl ImportGroup {
-    fn new(path: &ast::Path) -> ImportGroup {
+    pub(super) fn new(path: &ast::Path, style: ImportGroupStyle) -> ImportGroup {
+        let group = ImportGroup::of_path_kind(path);
+        if style == ImportGroupStyle::PathKind {
+            return group;
+        }
+        let is_alloc = || {
+            path.first_segment()
+                .and_then(|it| it.name_ref())
+                .map_or(false, |it| it.text() == "alloc")
+        };
+        match group {
+            ImportGroup::ThisModule | ImportGroup::SuperModule => ImportGroup::ThisCrate,
+            ImportGroup::ExternCrate if is_alloc() => ImportGroup::Std,
+            _ => group,
+        }
+    }
+
+    fn of_path_kind(path: &ast::Path) -> ImportGroup {
         let default = ImportGroup::ExternCrate;
 
         let first_segment = match path.first_segment() {
@@ -260,6 +418,7 @@ enum ImportGranularityGuess {
     ModuleOrItem,
     Crate,
     CrateOrModule,
+    One,
 }
 
 fn guess_granularity_from_scope(scope: &ImportScope) -> ImportGranularityGuess {
@@ -284,6 +443,10 @@ fn guess_granularity_from_scope(scope: &ImportScope) -> ImportGranularityGuess {
         None => return res,
     };
     loop {
+        if prev.path().is_none() && prev.use_tree_list().is_some() {
+            // Only one style puts imports into a tree list without a common prefix.
+            break ImportGranularityGuess::One;
+        }
         if let Some(use_tree_list) = prev.use_tree_list() {
             if use_tree_list.use_trees().any(|tree| tree.use_tree_list().is_some()) {
                 // Nested tree lists can only occur in crate style, or with no proper style being enforced in the file.
@@ -329,11 +492,10 @@ fn guess_granularity_from_scope(scope: &ImportScope) -> ImportGranularityGuess {
 fn insert_use_(
     scope: &ImportScope,
     insert_path: &ast::Path,
-    group_imports: bool,
+    group_style: Option<ImportGroupStyle>,
     use_item: ast::Use,
 ) {
     let scope_syntax = scope.as_syntax_node();
-    let group = ImportGroup::new(insert_path);
     let path_node_iter = scope_syntax
         .children()
         .filter_map(|node| ast::Use::cast(node.clone()).zip(Some(node)))
@@ -344,13 +506,14 @@ fn insert_use_(
             Some((path, has_tl, node))
         });
 
-    if group_imports {
+    if let Some(style) = group_style {
+        let group = ImportGroup::new(insert_path, style);
         // Iterator that discards anything thats not in the required grouping
         // This implementation allows the user to rearrange their import groups as this only takes the first group that fits
         let group_iter = path_node_iter
             .clone()
-            .skip_while(|(path, ..)| ImportGroup::new(path) != group)
-            .take_while(|(path, ..)| ImportGroup::new(path) == group);
+            .skip_while(|(path, ..)| ImportGroup::new(path, style) != group)
+            .take_while(|(path, ..)| ImportGroup::new(path, style) == group);
 
         // track the last element we iterated over, if this is still None after the iteration then that means we never iterated in the first place
         let mut last = None;
@@ -378,7 +541,7 @@ fn insert_use_(
         // find the group that comes after where we want to insert
         let post_group = path_node_iter
             .inspect(|(.., node)| last = Some(node.clone()))
-            .find(|(p, ..)| ImportGroup::new(p) > group);
+            .find(|(p, ..)| ImportGroup::new(p, style) > group);
         if let Some((.., node)) = post_group {
             cov_mark::hit!(insert_group_new_group);
             ted::insert(ted::Position::before(&node), use_item.syntax());
@@ -59,6 +59,63 @@ fn respects_cfg_attr_const() {
     );
 }
 
+#[test]
+fn insert_in_block() {
+    check_block(
+        "foo::bar",
+        r"
+fn main() {
+    $0bar();
+}
+",
+        r"
+fn main() {
+    use foo::bar;
+
+    bar();
+}
+",
+    );
+}
+
+#[test]
+fn insert_in_block_merges_local_imports() {
+    check_block(
+        "foo::bar",
+        r"
+use std::fmt;
+
+fn main() {
+    use foo::baz;
+    $0bar();
+}
+",
+        r"
+use std::fmt;
+
+fn main() {
+    use foo::{baz, bar};
+    bar();
+}
+",
+    );
+}
+
+#[test]
+fn insert_in_block_outside_of_blocks() {
+    check_block(
+        "foo::bar",
+        r"
+struct S(bar$0);
+",
+        r"
+use foo::bar;
+
+struct S(bar);
+",
+    );
+}
+
 #[test]
 fn insert_skips_lone_glob_imports() {
     check(
@@ -92,16 +149,63 @@ fn insert_not_group() {
 use self::bar::A;
 use super::bar::A;
 use external_crate2::bar::A;",
-        &InsertUseConfig {
+        &ImportPrefs {
             granularity: ImportGranularity::Item,
             enforce_granularity: true,
             prefix_kind: PrefixKind::Plain,
             group: false,
+            group_style: ImportGroupStyle::PathKind,
             skip_glob_imports: true,
+            location: ImportLocation::Module,
+            prefer_no_std: false,
         },
     );
 }
 
+#[test]
+fn insert_std_external_crate_groups() {
+    let config = ImportPrefs {
+        granularity: ImportGranularity::Item,
+        enforce_granularity: true,
+        prefix_kind: PrefixKind::Plain,
+        group: true,
+        group_style: ImportGroupStyle::StdExternalCrate,
+        skip_glob_imports: true,
+        location: ImportLocation::Module,
+        prefer_no_std: false,
+    };
+    check_with_config(
+        "alloc::vec::Vec",
+        r"
+use std::fmt;
+
+use external_crate::A;",
+        r"
+use alloc::vec::Vec;
+use std::fmt;
+
+use external_crate::A;",
+        &config,
+    );
+    check_with_config(
+        "self::foo::B",
+        r"
+use std::fmt;
+
+use external_crate::A;
+
+use crate::bar::A;",
+        r"
+use std::fmt;
+
+use external_crate::A;
+
+use crate::bar::A;
+use self::foo::B;",
+        &config,
+    );
+}
+
 #[test]
 fn insert_existing() {
     check_crate("std::fs", "use std::fs;", "use std::fs;")
@@ -340,12 +444,15 @@ fn insert_empty_file() {
         r"use external_crate2::bar::A;
 
 ",
-        &InsertUseConfig {
+        &ImportPrefs {
             granularity: ImportGranularity::Item,
             enforce_granularity: true,
             prefix_kind: PrefixKind::Plain,
             group: false,
+            group_style: ImportGroupStyle::PathKind,
             skip_glob_imports: true,
+            location: ImportLocation::Module,
+            prefer_no_std: false,
         },
     );
 }
@@ -375,12 +482,15 @@ mod x {
         r"mod x {
     use foo::bar;
 }",
-        &InsertUseConfig {
+        &ImportPrefs {
             granularity: ImportGranularity::Item,
             enforce_granularity: true,
             prefix_kind: PrefixKind::Plain,
             group: false,
+            group_style: ImportGroupStyle::PathKind,
             skip_glob_imports: true,
+            location: ImportLocation::Module,
+            prefer_no_std: false,
         },
     );
 }
@@ -405,12 +515,15 @@ fn insert_after_inner_attr() {
         r"#![allow(unused_imports)]
 
 use foo::bar;",
-        &InsertUseConfig {
+        &ImportPrefs {
             granularity: ImportGranularity::Item,
             enforce_granularity: true,
             prefix_kind: PrefixKind::Plain,
             group: false,
+            group_style: ImportGroupStyle::PathKind,
             skip_glob_imports: true,
+            location: ImportLocation::Module,
+            prefer_no_std: false,
         },
     );
 }
@@ -734,12 +847,15 @@ fn merge_mod_into_glob() {
         "token::TokenKind",
         r"use token::TokenKind::*;",
         r"use token::TokenKind::{*, self};",
-        &InsertUseConfig {
+        &ImportPrefs {
             granularity: ImportGranularity::Crate,
             enforce_granularity: true,
             prefix_kind: PrefixKind::Plain,
             group: false,
+            group_style: ImportGroupStyle::PathKind,
             skip_glob_imports: false,
+            location: ImportLocation::Module,
+            prefer_no_std: false,
         },
     )
     // FIXME: have it emit `use token::TokenKind::{self, *}`?
@@ -751,12 +867,15 @@ fn merge_self_glob() {
         "self",
         r"use self::*;",
         r"use self::{*, self};",
-        &InsertUseConfig {
+        &ImportPrefs {
             granularity: ImportGranularity::Crate,
             enforce_granularity: true,
             prefix_kind: PrefixKind::Plain,
             group: false,
+            group_style: ImportGroupStyle::PathKind,
             skip_glob_imports: false,
+            location: ImportLocation::Module,
+            prefer_no_std: false,
         },
     )
     // FIXME: have it emit `use {self, *}`?
@@ -857,6 +976,31 @@ fn merge_last_fail3() {
     );
 }
 
+#[test]
+fn insert_one_style() {
+    check_one(
+        "std::fmt",
+        "fn main() {}",
+        r"use {std::fmt};
+
+fn main() {}",
+    );
+}
+
+#[test]
+fn merge_one_style_other_crate() {
+    check_one("std::fmt", r"use foo::Bar;", r"use {foo::Bar, std::fmt};");
+}
+
+#[test]
+fn merge_one_style_same_crate() {
+    check_one(
+        "std::fmt::Display",
+        r"use {std::fmt::Debug, foo::Bar};",
+        r"use {std::fmt::{Debug, Display}, foo::Bar};",
+    );
+}
+
 #[test]
 fn guess_empty() {
     check_guess("", ImportGranularityGuess::Unknown);
@@ -867,6 +1011,7 @@ fn guess_single() {
     check_guess(r"use foo::{baz::{qux, quux}, bar};", ImportGranularityGuess::Crate);
     check_guess(r"use foo::bar;", ImportGranularityGuess::Unknown);
     check_guess(r"use foo::bar::{baz, qux};", ImportGranularityGuess::CrateOrModule);
+    check_guess(r"use {foo::bar, baz::qux};", ImportGranularityGuess::One);
 }
 
 #[test]
@@ -997,7 +1142,7 @@ fn check_with_config(
     path: &str,
     ra_fixture_before: &str,
     ra_fixture_after: &str,
-    config: &InsertUseConfig,
+    config: &ImportPrefs,
 ) {
     let (db, file_id, pos) = if ra_fixture_before.contains(CURSOR_MARKER) {
         let (db, file_id, range_or_offset) = RootDatabase::with_range_or_offset(ra_fixture_before);
@@ -1011,7 +1156,7 @@ fn check_with_config(
     let syntax = source_file.syntax().clone_for_update();
     let file = pos
         .and_then(|pos| syntax.token_at_offset(pos.expect_offset()).next()?.parent())
-        .and_then(|it| ImportScope::find_insert_use_container(&it, sema))
+        .and_then(|it| ImportScope::find_insert_use_container_with_config(&it, sema, config))
         .or_else(|| ImportScope::from(syntax))
         .unwrap();
     let path = ast::SourceFile::parse(&format!("use {path};"))
@@ -1036,12 +1181,33 @@ fn check(
         path,
         ra_fixture_before,
         ra_fixture_after,
-        &InsertUseConfig {
+        &ImportPrefs {
             granularity,
             enforce_granularity: true,
             prefix_kind: PrefixKind::Plain,
             group: true,
+            group_style: ImportGroupStyle::PathKind,
             skip_glob_imports: true,
+            location: ImportLocation::Module,
+            prefer_no_std: false,
+        },
+    )
+}
+
+fn check_block(path: &str, ra_fixture_before: &str, ra_fixture_after: &str) {
+    check_with_config(
+        path,
+        ra_fixture_before,
+        ra_fixture_after,
+        &ImportPrefs {
+            granularity: ImportGranularity::Crate,
+            enforce_granularity: true,
+            prefix_kind: PrefixKind::Plain,
+            group: true,
+            group_style: ImportGroupStyle::PathKind,
+            skip_glob_imports: true,
+            location: ImportLocation::Block,
+            prefer_no_std: false,
         },
     )
 }
@@ -1058,6 +1224,10 @@ fn check_none(path: &str, ra_fixture_before: &str, ra_fixture_after: &str) {
     check(path, ra_fixture_before, ra_fixture_after, ImportGranularity::Item)
 }
 
+fn check_one(path: &str, ra_fixture_before: &str, ra_fixture_after: &str) {
+    check(path, ra_fixture_before, ra_fixture_after, ImportGranularity::One)
+}
+
 fn check_merge_only_fail(ra_fixture0: &str, ra_fixture1: &str, mb: MergeBehavior) {
     let use0 = ast::SourceFile::parse(ra_fixture0)
         .tree()
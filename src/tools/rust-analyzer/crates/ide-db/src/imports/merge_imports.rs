@@ -16,12 +16,15 @@ pub enum MergeBehavior {
     Crate,
     /// Merge imports from the same module into a single use statement.
     Module,
+    /// Merge all imports into a single use statement, as long as they have the same visibility
+    /// and attributes.
+    One,
 }
 
 impl MergeBehavior {
     fn is_tree_allowed(&self, tree: &ast::UseTree) -> bool {
         match self {
-            MergeBehavior::Crate => true,
+            MergeBehavior::Crate | MergeBehavior::One => true,
             // only simple single segment paths are allowed
             MergeBehavior::Module => {
                 tree.use_tree_list().is_none() && tree.path().map(path_len) <= Some(1)
@@ -67,6 +70,12 @@ pub fn try_merge_trees(
 }
 
 fn try_merge_trees_mut(lhs: &ast::UseTree, rhs: &ast::UseTree, merge: MergeBehavior) -> Option<()> {
+    if merge == MergeBehavior::One {
+        // Trees without a common prefix are merged as items of a single tree list.
+        lhs.wrap_in_tree_list();
+        rhs.wrap_in_tree_list();
+        return recursive_merge(lhs, rhs, merge);
+    }
     let lhs_path = lhs.path()?;
     let rhs_path = rhs.path()?;
 
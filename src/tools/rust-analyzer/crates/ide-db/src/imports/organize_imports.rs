@@ -0,0 +1,425 @@
+//! Normalization of all the `use` items of a file or crate at once: flattening and merging them
+//! per the configured granularity, sorting them into groups, removing duplicates and pruning
+//! unused imports.
+
+use std::cmp::Ordering;
+
+use hir::{Crate, ModuleSource, Semantics};
+use itertools::Itertools;
+use rustc_hash::FxHashSet;
+use stdx::format_to;
+use syntax::{
+    ast::{
+        self, edit::IndentLevel, edit_in_place::Removable, HasAttrs, HasVisibility, PathSegmentKind,
+    },
+    AstNode, SyntaxKind, SyntaxNode, TextRange,
+};
+use text_edit::{TextEdit, TextEditBuilder};
+
+use crate::{
+    base_db::FileId,
+    imports::{
+        insert_use::{ImportGranularity, ImportGroup, ImportPrefs},
+        merge_imports::{try_merge_imports, use_tree_path_cmp, MergeBehavior},
+        remove_unused::unused_imports,
+    },
+    source_change::SourceChange,
+    RootDatabase,
+};
+
+/// Organizes the imports of every file of `krate`, see [`organize_imports`].
+pub fn organize_crate_imports(
+    sema: &Semantics<'_, RootDatabase>,
+    krate: Crate,
+    cfg: &ImportPrefs,
+) -> SourceChange {
+    let db = sema.db;
+    let files: FxHashSet<FileId> = krate
+        .modules(db)
+        .into_iter()
+        .map(|module| module.definition_source(db))
+        .filter(|src| matches!(src.value, ModuleSource::SourceFile(_)))
+        .filter_map(|src| src.file_id.file_id())
+        .collect();
+    files
+        .into_iter()
+        .filter_map(|file_id| organize_imports(sema, file_id, cfg))
+        .fold(SourceChange::default(), SourceChange::merge)
+}
+
+/// Organizes the imports of every module and block of a file: unused imports are removed, the
+/// remaining ones are flattened and merged per the configured granularity, deduplicated, and
+/// sorted into groups where the first `use` item of the module or block was.
+///
+/// Returns `None` if the imports are organized already.
+pub fn organize_imports(
+    sema: &Semantics<'_, RootDatabase>,
+    file_id: FileId,
+    cfg: &ImportPrefs,
+) -> Option<SourceChange> {
+    let file = sema.parse(file_id);
+    let unused: FxHashSet<ast::UseTree> = unused_imports(sema, file_id).into_iter().collect();
+    let mut edit = TextEdit::builder();
+    for scope in file.syntax().descendants() {
+        let kind = scope.kind();
+        if ast::SourceFile::can_cast(kind)
+            || ast::ItemList::can_cast(kind)
+            || ast::StmtList::can_cast(kind)
+        {
+            organize_scope(&scope, &unused, cfg, &mut edit);
+        }
+    }
+    let edit = edit.finish();
+    (!edit.is_empty()).then(|| SourceChange::from_text_edit(file_id, edit))
+}
+
+fn organize_scope(
+    scope: &SyntaxNode,
+    unused: &FxHashSet<ast::UseTree>,
+    cfg: &ImportPrefs,
+    edit: &mut TextEditBuilder,
+) {
+    let uses: Vec<ast::Use> = scope.children().filter_map(ast::Use::cast).collect();
+    let (Some(first), Some(last)) = (uses.first(), uses.last()) else { return };
+    let indent = IndentLevel::from_node(first.syntax());
+
+    let mut organized: Vec<ast::Use> = match cfg.granularity {
+        ImportGranularity::Preserve => uses.iter().filter_map(|it| prune(it, unused)).collect(),
+        _ => uses.iter().flat_map(|it| flatten(it, unused, indent)).collect(),
+    };
+    let mut seen = FxHashSet::default();
+    organized.retain(|it| seen.insert(it.to_string()));
+    organized.sort_by(|a, b| use_cmp(a, b, cfg));
+    let merge = match cfg.granularity {
+        ImportGranularity::Crate => Some(MergeBehavior::Crate),
+        ImportGranularity::Module => Some(MergeBehavior::Module),
+        ImportGranularity::One => Some(MergeBehavior::One),
+        ImportGranularity::Item | ImportGranularity::Preserve => None,
+    };
+    if let Some(merge) = merge {
+        let mut merged: Vec<ast::Use> = Vec::new();
+        for use_ in organized {
+            let merged_into = merged
+                .iter()
+                .enumerate()
+                .find_map(|(idx, it)| Some((idx, try_merge_imports(it, &use_, merge)?)));
+            match merged_into {
+                Some((idx, it)) => merged[idx] = it,
+                None => merged.push(use_),
+            }
+        }
+        organized = merged;
+        organized.sort_by(|a, b| use_cmp(a, b, cfg));
+    }
+
+    let mut block = String::new();
+    for (idx, use_) in organized.iter().enumerate() {
+        if idx > 0 {
+            let prev = &organized[idx - 1];
+            match cfg.group && group(prev, cfg) != group(use_, cfg) {
+                true => format_to!(block, "\n\n{indent}"),
+                false => format_to!(block, "\n{indent}"),
+            }
+        }
+        format_to!(block, "{use_}");
+    }
+
+    // Nothing to do if the uses are already next to each other in the organized order.
+    let range =
+        TextRange::new(first.syntax().text_range().start(), last.syntax().text_range().end());
+    let is_contiguous = scope
+        .children_with_tokens()
+        .filter(|it| range.contains_range(it.text_range()))
+        .all(|it| it.kind() == SyntaxKind::WHITESPACE || it.kind() == SyntaxKind::USE);
+    if is_contiguous && scope.text().slice(range - scope.text_range().start()) == block.as_str() {
+        return;
+    }
+
+    let whitespace_around = |use_: &ast::Use, prev: bool| {
+        let sibling = match prev {
+            true => use_.syntax().prev_sibling_or_token(),
+            false => use_.syntax().next_sibling_or_token(),
+        };
+        sibling.filter(|it| it.kind() == SyntaxKind::WHITESPACE).map(|it| it.text_range())
+    };
+    if block.is_empty() {
+        for use_ in &uses {
+            let range = use_.syntax().text_range();
+            let range = whitespace_around(use_, false).map_or(range, |it| range.cover(it));
+            edit.delete(range);
+        }
+        return;
+    }
+    edit.replace(first.syntax().text_range(), block);
+    for use_ in &uses[1..] {
+        let range = use_.syntax().text_range();
+        let range = whitespace_around(use_, true).map_or(range, |it| range.cover(it));
+        edit.delete(range);
+    }
+}
+
+/// Removes the unused imports from `use_`, returning `None` if none of its imports are used.
+fn prune(use_: &ast::Use, unused: &FxHashSet<ast::UseTree>) -> Option<ast::Use> {
+    let offset = use_.syntax().text_range().start();
+    let pruned: Vec<TextRange> = use_
+        .syntax()
+        .descendants()
+        .filter_map(ast::UseTree::cast)
+        .filter(|it| unused.contains(it))
+        .map(|it| it.syntax().text_range() - offset)
+        .collect();
+    if pruned.is_empty() {
+        return Some(use_.clone());
+    }
+    let use_ = use_.clone_subtree().clone_for_update();
+    let trees: Vec<ast::UseTree> = use_
+        .syntax()
+        .descendants()
+        .filter_map(ast::UseTree::cast)
+        .filter(|it| pruned.contains(&it.syntax().text_range()))
+        .collect();
+    for mut tree in trees {
+        while let Some(list) = tree.syntax().parent().and_then(ast::UseTreeList::cast) {
+            if list.use_trees().count() > 1 {
+                break;
+            }
+            tree = list.parent_use_tree();
+        }
+        if tree.syntax().parent().map_or(true, |it| ast::Use::can_cast(it.kind())) {
+            return None;
+        }
+        tree.remove();
+    }
+    Some(use_)
+}
+
+/// Splits `use_` into a `use` item per import, leaving out the unused ones.
+fn flatten(
+    use_: &ast::Use,
+    unused: &FxHashSet<ast::UseTree>,
+    indent: IndentLevel,
+) -> Vec<ast::Use> {
+    let Some(tree) = use_.use_tree() else { return Vec::new() };
+    let mut leaves = Vec::new();
+    collect_leaves(&tree, String::new(), unused, &mut leaves);
+
+    let mut head = String::new();
+    for attr in use_.attrs() {
+        format_to!(head, "{attr}\n{indent}");
+    }
+    if let Some(vis) = use_.visibility() {
+        format_to!(head, "{vis} ");
+    }
+    leaves
+        .into_iter()
+        .filter_map(|leaf| {
+            let file = ast::SourceFile::parse(&format!("{head}use {leaf};")).tree();
+            file.syntax().descendants().find_map(ast::Use::cast)
+        })
+        .collect()
+}
+
+fn collect_leaves(
+    tree: &ast::UseTree,
+    prefix: String,
+    unused: &FxHashSet<ast::UseTree>,
+    acc: &mut Vec<String>,
+) {
+    let join = |prefix: &str, suffix: &str| match (prefix.is_empty(), suffix.is_empty()) {
+        (true, _) => suffix.to_owned(),
+        (false, true) => prefix.to_owned(),
+        (false, false) => format!("{prefix}::{suffix}"),
+    };
+    let path = tree.path().map(|it| it.segments().join("::")).unwrap_or_default();
+    if let Some(list) = tree.use_tree_list() {
+        let prefix = join(&prefix, &path);
+        for tree in list.use_trees() {
+            collect_leaves(&tree, prefix.clone(), unused, acc);
+        }
+        return;
+    }
+    if unused.contains(tree) {
+        return;
+    }
+    let rename = tree.rename().map(|it| format!(" {it}")).unwrap_or_default();
+    let is_self = tree
+        .path()
+        .and_then(|it| it.segment())
+        .map_or(false, |it| matches!(it.kind(), Some(PathSegmentKind::SelfKw)));
+    let leaf = if tree.star_token().is_some() {
+        join(&join(&prefix, &path), "*")
+    } else if is_self && !prefix.is_empty() && path == "self" {
+        // `use foo::self;` isn't valid.
+        format!("{prefix}::{{self{rename}}}")
+    } else {
+        format!("{}{rename}", join(&prefix, &path))
+    };
+    acc.push(leaf);
+}
+
+fn group(use_: &ast::Use, cfg: &ImportPrefs) -> Option<ImportGroup> {
+    let path = use_.use_tree()?.path()?;
+    Some(ImportGroup::new(&path, cfg.group_style))
+}
+
+fn use_cmp(a: &ast::Use, b: &ast::Use, cfg: &ImportPrefs) -> Ordering {
+    let group_ord = match cfg.group {
+        true => group(a, cfg).cmp(&group(b, cfg)),
+        false => Ordering::Equal,
+    };
+    let path = |use_: &ast::Use| {
+        let tree = use_.use_tree()?;
+        Some((tree.path()?, tree.use_tree_list().is_some() || tree.star_token().is_some()))
+    };
+    let path_ord = match (path(a), path(b)) {
+        (Some((a, a_has_tl)), Some((b, b_has_tl))) => use_tree_path_cmp(&a, a_has_tl, &b, b_has_tl),
+        (a, b) => a.is_some().cmp(&b.is_some()),
+    };
+    group_ord.then(path_ord).then_with(|| a.to_string().cmp(&b.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::fixture::WithFixture;
+    use expect_test::{expect, Expect};
+
+    use crate::imports::insert_use::{ImportGroupStyle, ImportLocation, PrefixKind};
+
+    use super::*;
+
+    fn check(granularity: ImportGranularity, ra_fixture: &str, expect: Expect) {
+        let (db, file_id) = RootDatabase::with_single_file(ra_fixture);
+        let sema = Semantics::new(&db);
+        let cfg = ImportPrefs {
+            granularity,
+            enforce_granularity: true,
+            prefix_kind: PrefixKind::Plain,
+            group: true,
+            group_style: ImportGroupStyle::StdExternalCrate,
+            skip_glob_imports: true,
+            location: ImportLocation::Module,
+            prefer_no_std: false,
+        };
+        let mut text = sema.parse(file_id).to_string();
+        if let Some(change) = organize_imports(&sema, file_id, &cfg) {
+            change.get_source_edit(file_id).unwrap().apply(&mut text);
+        }
+        expect.assert_eq(&text);
+    }
+
+    #[test]
+    fn sorts_groups_and_dedupes() {
+        check(
+            ImportGranularity::Item,
+            r#"
+mod foo {
+    pub struct Foo;
+    pub struct Bar;
+}
+use foo::Foo;
+use std::fmt;
+use foo::Bar;
+use foo::Foo;
+
+fn f(_: Foo, _: Bar, _: fmt::Error) {}
+"#,
+            expect![[r#"
+                mod foo {
+                    pub struct Foo;
+                    pub struct Bar;
+                }
+                use std::fmt;
+
+                use foo::Bar;
+                use foo::Foo;
+
+                fn f(_: Foo, _: Bar, _: fmt::Error) {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn merges_and_prunes() {
+        check(
+            ImportGranularity::Crate,
+            r#"
+mod foo {
+    pub struct Foo;
+    pub struct Bar;
+    pub struct Baz;
+}
+use foo::Foo;
+use foo::{Bar, Baz};
+
+fn f(_: Foo, _: Baz) {}
+"#,
+            expect![[r#"
+                mod foo {
+                    pub struct Foo;
+                    pub struct Bar;
+                    pub struct Baz;
+                }
+                use foo::{Baz, Foo};
+
+                fn f(_: Foo, _: Baz) {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn flattens_nested_imports() {
+        check(
+            ImportGranularity::Item,
+            r#"
+mod foo {
+    pub mod bar { pub struct Bar; }
+    pub struct Foo;
+}
+fn f() {
+    use foo::{self, bar::Bar, Foo};
+    let _: (Foo, Bar, foo::Foo);
+}
+"#,
+            expect![[r#"
+                mod foo {
+                    pub mod bar { pub struct Bar; }
+                    pub struct Foo;
+                }
+                fn f() {
+                    use foo::Foo;
+                    use foo::bar::Bar;
+                    use foo::{self};
+                    let _: (Foo, Bar, foo::Foo);
+                }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn organized_imports_are_left_alone() {
+        let (db, file_id) = RootDatabase::with_single_file(
+            r#"
+mod foo {
+    pub struct Foo;
+}
+use std::fmt;
+
+use foo::Foo;
+
+fn f(_: Foo, _: fmt::Error) {}
+"#,
+        );
+        let sema = Semantics::new(&db);
+        let cfg = ImportPrefs {
+            granularity: ImportGranularity::Crate,
+            enforce_granularity: true,
+            prefix_kind: PrefixKind::Plain,
+            group: true,
+            group_style: ImportGroupStyle::StdExternalCrate,
+            skip_glob_imports: true,
+            location: ImportLocation::Module,
+            prefer_no_std: false,
+        };
+        assert!(organize_imports(&sema, file_id, &cfg).is_none());
+    }
+}
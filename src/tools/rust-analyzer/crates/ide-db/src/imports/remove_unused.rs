@@ -0,0 +1,256 @@
+//! Removal of imports that became unused because the code referring to them was removed, e.g. by
+//! inlining, deleting or moving it.
+
+use hir::{ScopeDef, Semantics};
+use syntax::{
+    ast::{self, edit_in_place::Removable, HasAttrs, HasName, HasVisibility, PathSegmentKind},
+    AstNode, SyntaxKind, SyntaxNode, TextRange,
+};
+
+use crate::{
+    base_db::FileId,
+    defs::{Definition, NameRefClass},
+    source_change::SourceChangeBuilder,
+    RootDatabase,
+};
+
+/// Collects the definitions `node` refers to by name, which are the ones whose imports may
+/// become unused when `node` is removed.
+pub fn referenced_defs(sema: &Semantics<'_, RootDatabase>, node: &SyntaxNode) -> Vec<Definition> {
+    let mut res = Vec::new();
+    for name_ref in node.descendants().filter_map(ast::NameRef::cast) {
+        if let Some(NameRefClass::Definition(def)) = NameRefClass::classify(sema, &name_ref) {
+            if !res.contains(&def) {
+                res.push(def);
+            }
+        }
+    }
+    res
+}
+
+/// Finds the imports of `defs` in `file_id` that are unused once the code in the `removed`
+/// ranges is deleted.
+///
+/// Imports of traits are never reported, as method calls and other uses of their associated
+/// items don't mention their name.
+pub fn unused_imports_after_removal(
+    sema: &Semantics<'_, RootDatabase>,
+    file_id: FileId,
+    defs: &[Definition],
+    removed: &[TextRange],
+) -> Vec<ast::UseTree> {
+    let is_removed = |range: TextRange| removed.iter().any(|it| it.contains_range(range));
+    unused_use_trees(sema, file_id, &|def| defs.contains(&def), &is_removed)
+}
+
+/// Finds the private imports in `file_id` that aren't used. Like for
+/// [`unused_imports_after_removal`], imports of traits are never reported, and neither are
+/// imports with attributes, which may be configured out.
+pub fn unused_imports(sema: &Semantics<'_, RootDatabase>, file_id: FileId) -> Vec<ast::UseTree> {
+    let mut res = unused_use_trees(sema, file_id, &|_| true, &|_| false);
+    res.retain(|tree| {
+        let use_ = tree.syntax().ancestors().find_map(ast::Use::cast);
+        use_.map_or(false, |it| it.visibility().is_none() && it.attrs().next().is_none())
+    });
+    res
+}
+
+fn unused_use_trees(
+    sema: &Semantics<'_, RootDatabase>,
+    file_id: FileId,
+    is_candidate: &dyn Fn(Definition) -> bool,
+    is_removed: &dyn Fn(TextRange) -> bool,
+) -> Vec<ast::UseTree> {
+    sema.parse(file_id)
+        .syntax()
+        .descendants()
+        .filter_map(ast::UseTree::cast)
+        .filter(|tree| !is_removed(tree.syntax().text_range()))
+        .filter(|tree| match imported_def(sema, tree) {
+            Some(Definition::Trait(_) | Definition::TraitAlias(_)) | None => false,
+            Some(def) => is_candidate(def) && !is_used(sema, tree, def, is_removed),
+        })
+        .collect()
+}
+
+/// Removes `imports` from the file being edited by `builder`, together with the use trees and
+/// items that would be left empty.
+pub fn remove_imports(builder: &mut SourceChangeBuilder, imports: Vec<ast::UseTree>) {
+    let imports: Vec<_> = imports.into_iter().map(|it| builder.make_mut(it)).collect();
+    for mut tree in imports {
+        while let Some(list) = tree.syntax().parent().and_then(ast::UseTreeList::cast) {
+            if list.use_trees().count() > 1 {
+                break;
+            }
+            tree = list.parent_use_tree();
+        }
+        match tree.syntax().parent().and_then(ast::Use::cast) {
+            Some(use_) => use_.remove(),
+            None => tree.remove(),
+        }
+    }
+}
+
+/// The definition a use tree imports by name, not counting globs and `self` imports.
+fn imported_def(sema: &Semantics<'_, RootDatabase>, tree: &ast::UseTree) -> Option<Definition> {
+    if tree.use_tree_list().is_some() || tree.star_token().is_some() {
+        return None;
+    }
+    let path = tree.path()?;
+    match path.segment()?.kind()? {
+        PathSegmentKind::Name(_) => sema.resolve_path(&path).map(Definition::from),
+        _ => None,
+    }
+}
+
+/// Whether the name imported by `tree` is still used outside of the removed code, in the item
+/// list or block containing the import.
+fn is_used(
+    sema: &Semantics<'_, RootDatabase>,
+    tree: &ast::UseTree,
+    def: Definition,
+    is_removed: &dyn Fn(TextRange) -> bool,
+) -> bool {
+    let name = match tree.rename() {
+        Some(rename) => match rename.name() {
+            Some(name) => name.text().to_string(),
+            // `as _` imports are only used for the associated items of traits.
+            None => return true,
+        },
+        None => match tree.path().and_then(|it| it.segment()?.name_ref()) {
+            Some(name_ref) => name_ref.text().to_string(),
+            None => return true,
+        },
+    };
+    let use_ = tree.syntax().ancestors().find_map(ast::Use::cast);
+    let Some(container) = use_.and_then(|it| it.syntax().parent()) else { return true };
+    // Modules in other files may use the import through a glob import of their parent.
+    let used_by_outlined_module = container
+        .children()
+        .filter_map(ast::Module::cast)
+        .filter(|it| it.item_list().is_none())
+        .filter_map(|it| sema.to_def(&it))
+        .flat_map(|it| it.scope(sema.db, None))
+        .any(|(scope_name, scope_def)| {
+            scope_name.to_smol_str() == name.as_str()
+                && matches!(scope_def, ScopeDef::ModuleDef(it) if Definition::from(it) == def)
+        });
+    if used_by_outlined_module {
+        return true;
+    }
+    container
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|token| token.kind() == SyntaxKind::IDENT && token.text() == name)
+        .filter(|token| !is_removed(token.text_range()))
+        .filter(|token| !token.parent_ancestors().any(|it| ast::Use::can_cast(it.kind())))
+        .any(|token| {
+            let Some(parent) = token.parent() else { return false };
+            if parent.kind() == SyntaxKind::TOKEN_TREE {
+                // The name may be used by the expansion of the macro call.
+                return true;
+            }
+            let Some(name_ref) = ast::NameRef::cast(parent) else { return false };
+            // Only unqualified paths can refer to the import.
+            let is_unqualified = name_ref
+                .syntax()
+                .parent()
+                .and_then(ast::PathSegment::cast)
+                .map_or(false, |it| it.parent_path().qualifier().is_none());
+            if !is_unqualified {
+                return false;
+            }
+            match NameRefClass::classify(sema, &name_ref) {
+                Some(NameRefClass::Definition(it)) => it == def,
+                Some(NameRefClass::FieldShorthand { .. }) => false,
+                None => true,
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::fixture::WithFixture;
+    use expect_test::{expect, Expect};
+
+    use super::*;
+
+    /// Checks the imports that become unused when the code in the `$0` range is removed.
+    fn check(ra_fixture: &str, expect: Expect) {
+        let (db, range) = RootDatabase::with_range(ra_fixture);
+        let sema = Semantics::new(&db);
+        let file = sema.parse(range.file_id);
+        let removed = file.syntax().covering_element(range.range);
+        let removed = match removed {
+            syntax::NodeOrToken::Node(it) => it,
+            syntax::NodeOrToken::Token(it) => it.parent().unwrap(),
+        };
+        let defs = referenced_defs(&sema, &removed);
+        let unused =
+            unused_imports_after_removal(&sema, range.file_id, &defs, &[removed.text_range()]);
+        let actual = unused.iter().map(|it| format!("{it}\n")).collect::<String>();
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn unused_after_removal() {
+        check(
+            r#"
+mod foo {
+    pub struct Foo;
+    pub struct Bar;
+    pub fn baz() {}
+}
+use foo::{Bar, Foo};
+use foo::baz;
+
+fn used() -> Bar { Bar }
+$0fn removed() -> Foo {
+    baz();
+    Foo
+}$0
+"#,
+            expect![[r#"
+                Foo
+                foo::baz
+            "#]],
+        );
+    }
+
+    #[test]
+    fn qualified_uses_dont_count() {
+        check(
+            r#"
+mod foo {
+    pub struct Foo;
+}
+use foo::Foo;
+
+fn used() -> foo::Foo { foo::Foo }
+$0fn removed() -> Foo { Foo }$0
+"#,
+            expect![[r#"
+                foo::Foo
+            "#]],
+        );
+    }
+
+    #[test]
+    fn keeps_imports_used_by_macros_and_traits() {
+        check(
+            r#"
+mod foo {
+    pub struct Foo;
+    pub trait Trait { fn method(&self) {} }
+    impl Trait for Foo {}
+}
+use foo::{Foo, Trait};
+
+macro_rules! m { ($e:expr) => { $e }; }
+fn used() { m!(Foo); }
+$0fn removed() -> Foo { Foo.method(); Foo }$0
+"#,
+            expect![[r#""#]],
+        );
+    }
+}
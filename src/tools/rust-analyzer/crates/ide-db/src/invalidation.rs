@@ -0,0 +1,184 @@
+//! Statistics of the work each change causes, for chasing incrementality regressions.
+//!
+//! Salsa invalidates lazily: a change only bumps the revision, and the entries depending on the
+//! changed inputs are found out of date when they are next read. So instead of the entries a
+//! change invalidates, which aren't known when it is applied, the statistics count the entries
+//! recomputed after it, attributing each recomputation to the last change applied before it.
+//! Entries computed for the first time aren't counted, but entries evicted by the LRU and computed
+//! again are, as salsa doesn't tell them apart from invalidated ones.
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Mutex,
+};
+
+use base_db::{
+    salsa::{DatabaseKeyIndex, Event, EventKind},
+    Change,
+};
+
+use crate::{FxHashMap, FxHashSet, RootDatabase};
+
+/// The number of changes whose statistics are kept.
+const MAX_RECORDED_CHANGES: usize = 32;
+
+/// The work caused by a change applied with [`RootDatabase::apply_change`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeInvalidation {
+    /// The number of the change, counting from the moment the tracking was enabled.
+    pub sequence: u64,
+    /// The inputs set by the change, like `"file_text"` or `"crate_graph"`.
+    pub inputs: Vec<&'static str>,
+    /// The number of files whose text was set by the change.
+    pub files_changed: usize,
+    /// The entries recomputed since the change, by query, ordered by their number, largest
+    /// first.
+    pub recomputed: Vec<QueryRecomputations>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryRecomputations {
+    pub query: String,
+    pub entries: usize,
+}
+
+/// Records the statistics, shared by a database and its snapshots.
+#[derive(Debug, Default)]
+pub(crate) struct InvalidationTracker {
+    /// Checked before locking, so that the tracking costs nothing while disabled.
+    enabled: AtomicBool,
+    state: Mutex<TrackerState>,
+}
+
+#[derive(Debug, Default)]
+struct TrackerState {
+    /// The entries computed at least once since the tracking was enabled.
+    computed: FxHashSet<DatabaseKeyIndex>,
+    changes: Vec<ChangeState>,
+    next_sequence: u64,
+}
+
+#[derive(Debug)]
+struct ChangeState {
+    sequence: u64,
+    inputs: Vec<&'static str>,
+    files_changed: usize,
+    recomputed: FxHashMap<String, usize>,
+}
+
+impl InvalidationTracker {
+    pub(crate) fn begin_change(&self, change: &Change) {
+        if !self.enabled.load(Ordering::Acquire) {
+            return;
+        }
+        let mut inputs = Vec::new();
+        if change.roots.is_some() {
+            inputs.extend(["source_root", "file_source_root"]);
+        }
+        if !change.files_changed.is_empty() {
+            inputs.push("file_text");
+        }
+        if change.crate_graph.is_some() {
+            inputs.push("crate_graph");
+        }
+        if change.proc_macros.is_some() {
+            inputs.push("proc_macros");
+        }
+
+        let mut state = self.state.lock().unwrap();
+        let sequence = state.next_sequence;
+        state.next_sequence += 1;
+        if state.changes.len() == MAX_RECORDED_CHANGES {
+            state.changes.remove(0);
+        }
+        state.changes.push(ChangeState {
+            sequence,
+            inputs,
+            files_changed: change.files_changed.len(),
+            recomputed: FxHashMap::default(),
+        });
+    }
+
+    pub(crate) fn record_event(&self, db: &RootDatabase, event: &Event) {
+        if !self.enabled.load(Ordering::Acquire) {
+            return;
+        }
+        let EventKind::WillExecute { database_key } = event.kind else { return };
+        let mut state = self.state.lock().unwrap();
+        if state.computed.insert(database_key) {
+            return;
+        }
+        if let Some(change) = state.changes.last_mut() {
+            // The debug output of a key is the name of its query followed by the key itself.
+            let key = format!("{:?}", database_key.debug(db));
+            let query = key.split('(').next().unwrap_or_default();
+            *change.recomputed.entry(query.to_owned()).or_default() += 1;
+        }
+    }
+}
+
+impl RootDatabase {
+    /// Enables or disables the recording of [`RootDatabase::invalidation_stats`]. Disabling it
+    /// discards the statistics recorded so far.
+    pub fn set_invalidation_tracking(&self, enabled: bool) {
+        self.invalidation.enabled.store(enabled, Ordering::Release);
+        if !enabled {
+            *self.invalidation.state.lock().unwrap() = TrackerState::default();
+        }
+    }
+
+    /// The statistics of the last changes applied while the tracking was enabled, oldest first.
+    pub fn invalidation_stats(&self) -> Vec<ChangeInvalidation> {
+        let state = self.invalidation.state.lock().unwrap();
+        state
+            .changes
+            .iter()
+            .map(|change| {
+                let mut recomputed: Vec<_> = change
+                    .recomputed
+                    .iter()
+                    .map(|(query, &entries)| QueryRecomputations { query: query.clone(), entries })
+                    .collect();
+                recomputed.sort_by(|a, b| b.entries.cmp(&a.entries).then(a.query.cmp(&b.query)));
+                ChangeInvalidation {
+                    sequence: change.sequence,
+                    inputs: change.inputs.clone(),
+                    files_changed: change.files_changed,
+                    recomputed,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::{fixture::WithFixture, SourceDatabase};
+    use triomphe::Arc;
+
+    use super::*;
+
+    #[test]
+    fn counts_recomputed_entries() {
+        let (mut db, file_id) = RootDatabase::with_single_file("fn foo() {}");
+        db.set_invalidation_tracking(true);
+        db.parse(file_id);
+
+        let mut change = Change::new();
+        change.change_file(file_id, Some(Arc::from("fn bar() {}")));
+        db.apply_change(change);
+        db.parse(file_id);
+
+        let stats = db.invalidation_stats();
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].inputs, ["file_text"]);
+        assert_eq!(stats[0].files_changed, 1);
+        assert_eq!(
+            stats[0].recomputed,
+            [QueryRecomputations { query: "parse".to_owned(), entries: 1 }]
+        );
+
+        db.set_invalidation_tracking(false);
+        assert!(db.invalidation_stats().is_empty());
+    }
+}
@@ -2,14 +2,19 @@
 //! by its name and a few criteria.
 //! The main reason for this module to exist is the fact that project's items and dependencies' items
 //! are located in different caches, with different APIs.
+use base_db::FilePosition;
 use either::Either;
 use hir::{
     import_map::{self, ImportKind},
-    AsAssocItem, Crate, ItemInNs, Semantics,
+    AsAssocItem, Crate, ItemInNs, ModPath, Semantics,
 };
+use itertools::Itertools;
 use limit::Limit;
 
-use crate::{imports::import_assets::NameToImport, symbol_index, RootDatabase};
+use crate::{
+    defs::Definition, helpers::scope_at_position, imports::import_assets::NameToImport,
+    symbol_index, RootDatabase,
+};
 
 /// A value to use, when uncertain which limit to pick.
 pub static DEFAULT_QUERY_SEARCH_LIMIT: Limit = Limit::new(40);
@@ -90,6 +95,68 @@ pub fn items_with_name<'a>(
     find_items(sema, krate, assoc_item_search, local_query, external_query)
 }
 
+/// A definition an ambiguous name may refer to.
+#[derive(Debug, Clone)]
+pub struct DisambiguationCandidate {
+    pub def: Definition,
+    /// The path referring to the definition where the name is used, without adding an import.
+    pub path: ModPath,
+    /// Whether the definition is in scope, in which case the path is the name it's in scope as.
+    pub in_scope: bool,
+}
+
+/// Finds the definitions `name` may refer to at `position`, together with the path each of them
+/// can be referred to by.
+///
+/// The candidates are ranked: the one the name is in scope as comes first, followed by the ones
+/// of the crate of the position, and then the ones with the shortest paths.
+pub fn disambiguation_candidates(
+    db: &RootDatabase,
+    position: FilePosition,
+    name: &str,
+    prefer_no_std: bool,
+) -> Vec<DisambiguationCandidate> {
+    let _p = profile::span("disambiguation_candidates");
+    let sema = Semantics::new(db);
+    let Some(scope) = scope_at_position(&sema, position) else { return Vec::new() };
+    let krate = scope.krate();
+
+    let mut defs = Vec::new();
+    scope.process_all_names(&mut |it, scope_def| {
+        if it.to_smol_str() == name {
+            defs.extend(Definition::from_scope_def(scope_def));
+        }
+    });
+    let items = items_with_name(
+        &sema,
+        krate,
+        NameToImport::exact_case_sensitive(name.to_owned()),
+        AssocItemSearch::Exclude,
+        Some(DEFAULT_QUERY_SEARCH_LIMIT.inner()),
+    );
+    defs.extend(items.map(|item| match item {
+        ItemInNs::Macros(it) => Definition::Macro(it),
+        ItemInNs::Types(it) | ItemInNs::Values(it) => it.into(),
+    }));
+
+    defs.into_iter()
+        .unique()
+        .filter_map(|def| {
+            let path = def.path_in_scope(&scope, prefer_no_std)?;
+            let in_scope = path.kind == hir::PathKind::Plain && path.segments().len() == 1;
+            Some(DisambiguationCandidate { def, path, in_scope })
+        })
+        .sorted_by_cached_key(|it| {
+            (
+                !it.in_scope,
+                it.def.krate(db) != Some(krate),
+                it.path.segments().len(),
+                it.path.display(db).to_string(),
+            )
+        })
+        .collect()
+}
+
 fn find_items<'a>(
     sema: &'a Semantics<'_, RootDatabase>,
     krate: Crate,
@@ -110,7 +177,7 @@ fn find_items<'a>(
 
     // Query the local crate using the symbol index.
     let local_results = local_query
-        .search(&symbol_index::crate_symbols(db, krate))
+        .search(db, &symbol_index::crate_symbols(db, krate))
         .into_iter()
         .filter_map(|local_candidate| match local_candidate.def {
             hir::ModuleDef::Macro(macro_def) => Some(ItemInNs::Macros(macro_def)),
@@ -127,3 +194,42 @@ fn find_items<'a>(
 fn is_assoc_item(item: ItemInNs, db: &RootDatabase) -> bool {
     item.as_module_def().and_then(|module_def| module_def.as_assoc_item(db)).is_some()
 }
+
+#[cfg(test)]
+mod tests {
+    use base_db::fixture::WithFixture;
+    use expect_test::expect;
+
+    use super::*;
+
+    #[test]
+    fn ranked_disambiguation_candidates() {
+        let (db, position) = RootDatabase::with_position(
+            r#"
+//- /main.rs crate:main deps:dep
+mod a {
+    pub struct Foo;
+}
+mod b {
+    pub mod c {
+        pub struct Foo;
+    }
+}
+use b::c::Foo;
+fn f() { $0 }
+//- /dep.rs crate:dep
+pub struct Foo;
+"#,
+        );
+        let actual = disambiguation_candidates(&db, position, "Foo", false)
+            .into_iter()
+            .map(|it| format!("{} {}\n", it.path.display(&db), it.in_scope))
+            .collect::<String>();
+        expect![[r#"
+            Foo true
+            a::Foo false
+            dep::Foo false
+        "#]]
+        .assert_eq(&actual);
+    }
+}
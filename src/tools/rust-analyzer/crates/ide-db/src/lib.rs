@@ -8,25 +8,53 @@
 
 pub mod active_parameter;
 pub mod assists;
+pub mod call_graph;
+pub mod call_index;
+pub mod clones;
+pub mod dead_code;
 pub mod defs;
+pub mod dependency_graph;
+pub mod drop_sites;
+pub mod enclosing_defs;
+pub mod eviction;
 pub mod famous_defs;
+pub mod ffi_index;
 pub mod helpers;
+pub mod invalidation;
 pub mod items_locator;
 pub mod label;
+pub mod lru;
+pub mod memory_usage;
+pub mod overlay;
+pub mod parallel;
 pub mod path_transform;
+pub mod pinned;
+pub mod priority;
 pub mod rename;
 pub mod rust_doc;
+pub mod scip;
 pub mod search;
+pub mod side_table;
 pub mod source_change;
+pub mod ssr;
 pub mod symbol_index;
+pub mod test_index;
+pub mod text_index;
+pub mod token_classes;
 pub mod traits;
 pub mod ty_filter;
+pub mod ty_search;
+pub mod type_hierarchy;
+pub mod unsafe_index;
 pub mod use_trivial_constructor;
+pub mod visibility;
 
 pub mod imports {
     pub mod import_assets;
     pub mod insert_use;
     pub mod merge_imports;
+    pub mod organize_imports;
+    pub mod remove_unused;
 }
 
 pub mod generated {
@@ -51,7 +79,14 @@ pub mod syntax_helpers {
 use hir::db::{DefDatabase, ExpandDatabase, HirDatabase};
 use triomphe::Arc;
 
-use crate::{line_index::LineIndex, symbol_index::SymbolsDatabase};
+use crate::{
+    invalidation::InvalidationTracker,
+    line_index::LineIndex,
+    priority::{ForegroundWork, Priority},
+    side_table::SideTables,
+    symbol_index::SymbolsDatabase,
+    test_index::{TestHarness, TestIndexDatabase},
+};
 pub use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 
 pub use ::line_index;
@@ -70,8 +105,16 @@ pub mod syntax_helpers {
     hir::db::DefDatabaseStorage,
     hir::db::HirDatabaseStorage,
     hir::db::InternDatabaseStorage,
+    call_index::CallIndexDatabaseStorage,
+    defs::DefinitionDatabaseStorage,
+    dependency_graph::DependencyGraphDatabaseStorage,
+    ffi_index::FfiIndexDatabaseStorage,
     LineIndexDatabaseStorage,
-    symbol_index::SymbolsDatabaseStorage
+    symbol_index::SymbolsDatabaseStorage,
+    test_index::TestIndexDatabaseStorage,
+    text_index::TextIndexDatabaseStorage,
+    type_hierarchy::TypeHierarchyDatabaseStorage,
+    unsafe_index::UnsafeIndexDatabaseStorage
 )]
 pub struct RootDatabase {
     // We use `ManuallyDrop` here because every codegen unit that contains a
@@ -79,10 +122,18 @@ pub struct RootDatabase {
     // which duplicates `Weak::drop` and `Arc::drop` tens of thousands of times, which makes
     // compile times of all `ide_*` and downstream crates suffer greatly.
     storage: ManuallyDrop<salsa::Storage<RootDatabase>>,
+    priority: Priority,
+    foreground_work: Arc<ForegroundWork>,
+    invalidation: Arc<InvalidationTracker>,
+    active_files: Arc<FxHashSet<FileId>>,
+    side_tables: Arc<SideTables>,
+    /// The LRU capacities set for each query, which salsa doesn't report back.
+    lru_capacities: Arc<FxHashMap<Box<str>, usize>>,
 }
 
 impl Drop for RootDatabase {
     fn drop(&mut self) {
+        self.end_work();
         unsafe { ManuallyDrop::drop(&mut self.storage) };
     }
 }
@@ -111,6 +162,12 @@ fn upcast(&self) -> &(dyn HirDatabase + 'static) {
     }
 }
 
+impl Upcast<RootDatabase> for RootDatabase {
+    fn upcast(&self) -> &RootDatabase {
+        self
+    }
+}
+
 impl FileLoader for RootDatabase {
     fn file_text(&self, file_id: FileId) -> Arc<str> {
         FileLoaderDelegate(self).file_text(file_id)
@@ -123,7 +180,11 @@ fn relevant_crates(&self, file_id: FileId) -> Arc<FxHashSet<CrateId>> {
     }
 }
 
-impl salsa::Database for RootDatabase {}
+impl salsa::Database for RootDatabase {
+    fn salsa_event(&self, event: salsa::Event) {
+        self.invalidation.record_event(self, &event);
+    }
+}
 
 impl Default for RootDatabase {
     fn default() -> RootDatabase {
@@ -133,12 +194,21 @@ fn default() -> RootDatabase {
 
 impl RootDatabase {
     pub fn new(lru_capacity: Option<usize>) -> RootDatabase {
-        let mut db = RootDatabase { storage: ManuallyDrop::new(salsa::Storage::default()) };
+        let mut db = RootDatabase {
+            storage: ManuallyDrop::new(salsa::Storage::default()),
+            priority: Priority::Normal,
+            foreground_work: Arc::default(),
+            invalidation: Arc::default(),
+            active_files: Arc::default(),
+            side_tables: Arc::default(),
+            lru_capacities: Arc::default(),
+        };
         db.set_crate_graph_with_durability(Default::default(), Durability::HIGH);
         db.set_proc_macros_with_durability(Default::default(), Durability::HIGH);
         db.set_local_roots_with_durability(Default::default(), Durability::HIGH);
         db.set_library_roots_with_durability(Default::default(), Durability::HIGH);
         db.set_expand_proc_attr_macros_with_durability(false, Durability::HIGH);
+        db.set_test_harnesses_with_durability(TestHarness::defaults(), Durability::HIGH);
         db.update_parse_query_lru_capacity(lru_capacity);
         db
     }
@@ -153,34 +223,42 @@ pub fn update_parse_query_lru_capacity(&mut self, lru_capacity: Option<usize>) {
         // macro expansions are usually rather small, so we can afford to keep more of them alive
         hir::db::ParseMacroExpansionQuery.in_db_mut(self).set_lru_capacity(4 * lru_capacity);
         hir::db::MacroExpandQuery.in_db_mut(self).set_lru_capacity(4 * lru_capacity);
+        self.record_lru_capacity(stringify!(ParseQuery), lru_capacity);
+        self.record_lru_capacity(stringify!(ParseMacroExpansionQuery), 4 * lru_capacity);
+        self.record_lru_capacity(stringify!(MacroExpandQuery), 4 * lru_capacity);
+    }
+
+    pub(crate) fn record_lru_capacity(&mut self, query: &str, capacity: usize) {
+        Arc::make_mut(&mut self.lru_capacities).insert(query.into(), capacity);
     }
 
     pub fn update_lru_capacities(&mut self, lru_capacities: &FxHashMap<Box<str>, usize>) {
         use hir::db as hir_db;
 
-        base_db::ParseQuery.in_db_mut(self).set_lru_capacity(
-            lru_capacities
-                .get(stringify!(ParseQuery))
-                .copied()
-                .unwrap_or(base_db::DEFAULT_PARSE_LRU_CAP),
-        );
-        hir_db::ParseMacroExpansionQuery.in_db_mut(self).set_lru_capacity(
-            lru_capacities
-                .get(stringify!(ParseMacroExpansionQuery))
-                .copied()
-                .unwrap_or(4 * base_db::DEFAULT_PARSE_LRU_CAP),
-        );
-        hir_db::MacroExpandQuery.in_db_mut(self).set_lru_capacity(
-            lru_capacities
-                .get(stringify!(MacroExpandQuery))
-                .copied()
-                .unwrap_or(4 * base_db::DEFAULT_PARSE_LRU_CAP),
-        );
+        let parse = lru_capacities
+            .get(stringify!(ParseQuery))
+            .copied()
+            .unwrap_or(base_db::DEFAULT_PARSE_LRU_CAP);
+        base_db::ParseQuery.in_db_mut(self).set_lru_capacity(parse);
+        self.record_lru_capacity(stringify!(ParseQuery), parse);
+        let parse_macro_expansion = lru_capacities
+            .get(stringify!(ParseMacroExpansionQuery))
+            .copied()
+            .unwrap_or(4 * base_db::DEFAULT_PARSE_LRU_CAP);
+        hir_db::ParseMacroExpansionQuery.in_db_mut(self).set_lru_capacity(parse_macro_expansion);
+        self.record_lru_capacity(stringify!(ParseMacroExpansionQuery), parse_macro_expansion);
+        let macro_expand = lru_capacities
+            .get(stringify!(MacroExpandQuery))
+            .copied()
+            .unwrap_or(4 * base_db::DEFAULT_PARSE_LRU_CAP);
+        hir_db::MacroExpandQuery.in_db_mut(self).set_lru_capacity(macro_expand);
+        self.record_lru_capacity(stringify!(MacroExpandQuery), macro_expand);
 
         macro_rules! update_lru_capacity_per_query {
             ($( $module:ident :: $query:ident )*) => {$(
                 if let Some(&cap) = lru_capacities.get(stringify!($query)) {
                     $module::$query.in_db_mut(self).set_lru_capacity(cap);
+                    self.record_lru_capacity(stringify!($query), cap);
                 }
             )*}
         }
@@ -298,6 +376,9 @@ macro_rules! update_lru_capacity_per_query {
             // LineIndexDatabase
             crate::LineIndexQuery
 
+            // TextIndexDatabase
+            text_index::FileTextIndexQuery
+
             // InternDatabase
             // hir_db::InternFunctionQuery
             // hir_db::InternStructQuery
@@ -320,7 +401,7 @@ macro_rules! update_lru_capacity_per_query {
 
 impl salsa::ParallelDatabase for RootDatabase {
     fn snapshot(&self) -> salsa::Snapshot<RootDatabase> {
-        salsa::Snapshot::new(RootDatabase { storage: ManuallyDrop::new(self.storage.snapshot()) })
+        self.snapshot_with_priority(self.priority)
     }
 }
 
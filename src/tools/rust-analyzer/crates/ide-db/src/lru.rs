@@ -0,0 +1,130 @@
+//! LRU capacities of groups of related queries, which can be tuned together at runtime.
+
+use base_db::DEFAULT_PARSE_LRU_CAP;
+
+use crate::RootDatabase;
+
+/// The smallest capacity a group is shrunk to under memory pressure, below which the recomputation
+/// of evicted entries would dominate the time spent on every request.
+const MIN_CAPACITY: usize = 16;
+
+/// The LRU capacities of groups of queries. A capacity of `None` keeps all the entries of the
+/// queries of a group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LruCapacities {
+    /// The syntax trees of files.
+    pub parse: Option<usize>,
+    /// The expansions of macro calls and their syntax trees.
+    pub macro_expansion: Option<usize>,
+    /// The lowered bodies of functions and constants, and their inference, MIR and borrowck
+    /// results.
+    pub body_analysis: Option<usize>,
+}
+
+impl Default for LruCapacities {
+    fn default() -> LruCapacities {
+        LruCapacities::with_parse_capacity(DEFAULT_PARSE_LRU_CAP)
+    }
+}
+
+impl LruCapacities {
+    /// The capacities with the given capacity for syntax trees. Macro expansions are usually
+    /// rather small, so more of them are kept alive.
+    pub fn with_parse_capacity(parse: usize) -> LruCapacities {
+        LruCapacities { parse: Some(parse), macro_expansion: Some(4 * parse), body_analysis: None }
+    }
+
+    /// The capacities suited for a workspace of `file_count` local files. Small workspaces keep
+    /// the default capacities, larger ones keep the syntax trees of a share of their files, and
+    /// very large ones also bound the body analysis results, which otherwise grow with every
+    /// function looked at during a session.
+    pub fn for_workspace(file_count: usize) -> LruCapacities {
+        let parse = (file_count / 8).clamp(DEFAULT_PARSE_LRU_CAP, 8 * DEFAULT_PARSE_LRU_CAP);
+        let mut res = LruCapacities::with_parse_capacity(parse);
+        if file_count > 10_000 {
+            res.body_analysis = Some(16 * parse);
+        }
+        res
+    }
+
+    /// Shrinks the capacities proportionally if `used_bytes` exceeds `budget_bytes`, bounding the
+    /// groups that aren't bounded yet as well.
+    pub fn under_memory_pressure(self, used_bytes: usize, budget_bytes: usize) -> LruCapacities {
+        if used_bytes <= budget_bytes {
+            return self;
+        }
+        let shrink = |capacity: usize| {
+            let shrunk = (capacity as u128 * budget_bytes as u128 / used_bytes as u128) as usize;
+            shrunk.max(MIN_CAPACITY)
+        };
+        let parse = self.parse.unwrap_or(8 * DEFAULT_PARSE_LRU_CAP);
+        LruCapacities {
+            parse: Some(shrink(parse)),
+            macro_expansion: Some(shrink(self.macro_expansion.unwrap_or(4 * parse))),
+            body_analysis: Some(shrink(self.body_analysis.unwrap_or(16 * parse))),
+        }
+    }
+}
+
+impl RootDatabase {
+    /// Sets the LRU capacities of all the queries of each group. Capacities of single queries set
+    /// with [`RootDatabase::update_lru_capacities`] are overwritten, so they should be set
+    /// afterwards.
+    pub fn set_lru_capacities(&mut self, capacities: &LruCapacities) {
+        use hir::db as hir_db;
+
+        // Salsa doesn't evict anything with a capacity of 0.
+        let parse = capacities.parse.unwrap_or(0);
+        let macro_expansion = capacities.macro_expansion.unwrap_or(0);
+        let body_analysis = capacities.body_analysis.unwrap_or(0);
+
+        macro_rules! set_lru_capacity {
+            ($cap:ident: $( $module:ident :: $query:ident )*) => {$(
+                $module::$query.in_db_mut(self).set_lru_capacity($cap);
+                self.record_lru_capacity(stringify!($query), $cap);
+            )*}
+        }
+        set_lru_capacity![parse: base_db::ParseQuery];
+        set_lru_capacity![macro_expansion:
+            hir_db::ParseMacroExpansionQuery
+            hir_db::MacroExpandQuery
+        ];
+        set_lru_capacity![body_analysis:
+            hir_db::BodyWithSourceMapQuery
+            hir_db::BodyQuery
+            hir_db::ExprScopesQuery
+            hir_db::InferQueryQuery
+            hir_db::MirBodyQuery
+            hir_db::BorrowckQuery
+        ];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scales_with_workspace_and_memory() {
+        assert_eq!(LruCapacities::for_workspace(100), LruCapacities::default());
+        assert_eq!(
+            LruCapacities::for_workspace(20_000),
+            LruCapacities {
+                parse: Some(1024),
+                macro_expansion: Some(4096),
+                body_analysis: Some(16384)
+            }
+        );
+
+        let caps = LruCapacities::default();
+        assert_eq!(caps.under_memory_pressure(1000, 2000), caps);
+        assert_eq!(
+            caps.under_memory_pressure(2000, 1000),
+            LruCapacities {
+                parse: Some(64),
+                macro_expansion: Some(256),
+                body_analysis: Some(1024)
+            }
+        );
+    }
+}
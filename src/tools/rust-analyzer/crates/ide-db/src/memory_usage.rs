@@ -0,0 +1,400 @@
+//! Memory usage of the database, broken down by query group and by crate.
+
+use base_db::{
+    salsa::{
+        debug::{DebugQueryTable, TableEntry},
+        Query, QueryTable,
+    },
+    CrateId, FileId, FileLoader, SourceDatabase,
+};
+use profile::{memory_usage, Bytes};
+use rustc_hash::FxHashMap;
+
+use crate::RootDatabase;
+
+/// The memory used by the database, as measured by [`RootDatabase::memory_usage_report`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryUsageReport {
+    /// The query groups, ordered by the memory they use, largest first.
+    pub groups: Vec<QueryGroupMemoryUsage>,
+    /// The crates, ordered by the memory attributed to them, largest first.
+    pub crates: Vec<CrateMemoryUsage>,
+}
+
+/// The memory used by the queries of a query group, like `DefDatabase`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryGroupMemoryUsage {
+    pub name: &'static str,
+    pub bytes: Bytes,
+    pub entries: usize,
+    /// The queries of the group, ordered by the memory they use, largest first.
+    pub queries: Vec<QueryMemoryUsage>,
+}
+
+/// The memory used by the entries of a query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryMemoryUsage {
+    pub name: String,
+    pub bytes: Bytes,
+    pub entries: usize,
+}
+
+/// The memory attributed to a crate, which is an estimate: only the entries of queries keyed by
+/// a crate or a file are attributed, each using an equal share of the memory of its query. The
+/// entries of files belonging to several crates are shared among them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrateMemoryUsage {
+    pub krate: CrateId,
+    pub display_name: Option<String>,
+    pub bytes: Bytes,
+    /// The number of entries attributed to the crate, counting shared ones for each crate.
+    pub entries: usize,
+}
+
+impl RootDatabase {
+    /// Measures the memory used by each query by clearing its entries, so the database is
+    /// empty afterwards, like for [`RootDatabase::per_query_memory_usage`].
+    pub fn memory_usage_report(&mut self) -> MemoryUsageReport {
+        let _p = profile::span("RootDatabase::memory_usage_report");
+        // The crate graph is one of the inputs cleared below.
+        let crate_graph = self.crate_graph();
+
+        // The crates of the entries of each query keyed by a crate or a file, which have to be
+        // collected before the queries are cleared.
+        let mut crates_of_entries: FxHashMap<String, Vec<Vec<CrateId>>> = FxHashMap::default();
+        macro_rules! collect_entry_crates {
+            ($($q:path => $crates:expr,)*) => {$(
+                let keys = collect_query_keys(&$q.in_db(self));
+                let crates = keys.into_iter().map(|key| $crates(key)).collect();
+                let q: $q = Default::default();
+                crates_of_entries.insert(format!("{:?}", q), crates);
+            )*}
+        }
+        let crates_of_file = |db: &RootDatabase, file_id: FileId| -> Vec<CrateId> {
+            db.relevant_crates(file_id).iter().copied().collect()
+        };
+        collect_entry_crates![
+            base_db::ParseQuery => |key| crates_of_file(self, key),
+            base_db::FileTextQuery => |key| crates_of_file(self, key),
+            crate::LineIndexQuery => |key| crates_of_file(self, key),
+            crate::text_index::FileTextIndexQuery => |key| crates_of_file(self, key),
+            hir::db::CrateDefMapQueryQuery => |key| vec![key],
+            hir::db::ImportMapQuery => |key| vec![key],
+            hir::db::CrateLangItemsQuery => |key| vec![key],
+            hir::db::CrateSupportsNoStdQuery => |key| vec![key],
+            hir::db::TargetDataLayoutQuery => |key| vec![key],
+            hir::db::InherentImplsInCrateQuery => |key| vec![key],
+            hir::db::TraitImplsInCrateQuery => |key| vec![key],
+            hir::db::TraitImplsInDepsQuery => |key| vec![key],
+            crate::call_index::CrateCallsByCalleeQuery => |key| vec![key],
+            crate::type_hierarchy::CrateImplIndexQuery => |key| vec![key],
+            crate::ffi_index::CrateFfiSymbolsQuery => |key| vec![key],
+            crate::unsafe_index::CrateUnsafeSitesQuery => |key| vec![key],
+        ];
+
+        let mut groups = Vec::new();
+        macro_rules! purge_each_query {
+            ($($group:ident => [$($q:path)*])*) => {$(
+                let mut queries = Vec::new();
+                $(
+                    let before = memory_usage().allocated;
+                    let table = $q.in_db(self);
+                    let count = collect_query_count(&table);
+                    table.purge();
+                    let after = memory_usage().allocated;
+                    let q: $q = Default::default();
+                    let name = format!("{:?}", q);
+                    queries.push(QueryMemoryUsage { name, bytes: before - after, entries: count });
+                )*
+                queries.sort_by_key(|it| std::cmp::Reverse(it.bytes));
+                groups.push(QueryGroupMemoryUsage {
+                    name: stringify!($group),
+                    bytes: queries.iter().fold(Bytes::default(), |acc, it| acc + it.bytes),
+                    entries: queries.iter().map(|it| it.entries).sum(),
+                    queries,
+                });
+            )*}
+        }
+        purge_each_query![
+            SourceDatabase => [
+                base_db::ParseQuery
+                base_db::CrateGraphQuery
+                base_db::ProcMacrosQuery
+            ]
+            SourceDatabaseExt => [
+                base_db::FileTextQuery
+                base_db::FileSourceRootQuery
+                base_db::SourceRootQuery
+                base_db::SourceRootCratesQuery
+            ]
+            ExpandDatabase => [
+                hir::db::AstIdMapQuery
+                hir::db::ParseMacroExpansionQuery
+                hir::db::InternMacroCallQuery
+                hir::db::MacroArgTextQuery
+                hir::db::MacroDefQuery
+                hir::db::MacroExpandQuery
+                hir::db::ExpandProcMacroQuery
+                hir::db::HygieneFrameQuery
+            ]
+            DefDatabase => [
+                hir::db::FileItemTreeQuery
+                hir::db::CrateDefMapQueryQuery
+                hir::db::BlockDefMapQuery
+                hir::db::StructDataQuery
+                hir::db::StructDataWithDiagnosticsQuery
+                hir::db::UnionDataQuery
+                hir::db::UnionDataWithDiagnosticsQuery
+                hir::db::EnumDataQuery
+                hir::db::EnumDataWithDiagnosticsQuery
+                hir::db::ImplDataQuery
+                hir::db::ImplDataWithDiagnosticsQuery
+                hir::db::TraitDataQuery
+                hir::db::TraitDataWithDiagnosticsQuery
+                hir::db::TraitAliasDataQuery
+                hir::db::TypeAliasDataQuery
+                hir::db::FunctionDataQuery
+                hir::db::ConstDataQuery
+                hir::db::StaticDataQuery
+                hir::db::Macro2DataQuery
+                hir::db::MacroRulesDataQuery
+                hir::db::ProcMacroDataQuery
+                hir::db::BodyWithSourceMapQuery
+                hir::db::BodyQuery
+                hir::db::ExprScopesQuery
+                hir::db::GenericParamsQuery
+                hir::db::VariantsAttrsQuery
+                hir::db::FieldsAttrsQuery
+                hir::db::VariantsAttrsSourceMapQuery
+                hir::db::FieldsAttrsSourceMapQuery
+                hir::db::AttrsQuery
+                hir::db::CrateLangItemsQuery
+                hir::db::LangItemQuery
+                hir::db::ImportMapQuery
+                hir::db::FieldVisibilitiesQuery
+                hir::db::FunctionVisibilityQuery
+                hir::db::ConstVisibilityQuery
+                hir::db::CrateSupportsNoStdQuery
+            ]
+            HirDatabase => [
+                hir::db::InferQueryQuery
+                hir::db::MirBodyQuery
+                hir::db::BorrowckQuery
+                hir::db::TyQuery
+                hir::db::ValueTyQuery
+                hir::db::ImplSelfTyQuery
+                hir::db::ConstParamTyQuery
+                hir::db::ConstEvalQuery
+                hir::db::ConstEvalDiscriminantQuery
+                hir::db::ImplTraitQuery
+                hir::db::FieldTypesQuery
+                hir::db::LayoutOfAdtQuery
+                hir::db::TargetDataLayoutQuery
+                hir::db::CallableItemSignatureQuery
+                hir::db::ReturnTypeImplTraitsQuery
+                hir::db::GenericPredicatesForParamQuery
+                hir::db::GenericPredicatesQuery
+                hir::db::TraitEnvironmentQuery
+                hir::db::GenericDefaultsQuery
+                hir::db::InherentImplsInCrateQuery
+                hir::db::InherentImplsInBlockQuery
+                hir::db::IncoherentInherentImplCratesQuery
+                hir::db::TraitImplsInCrateQuery
+                hir::db::TraitImplsInBlockQuery
+                hir::db::TraitImplsInDepsQuery
+                hir::db::InternCallableDefQuery
+                hir::db::InternLifetimeParamIdQuery
+                hir::db::InternImplTraitIdQuery
+                hir::db::InternTypeOrConstParamIdQuery
+                hir::db::InternClosureQuery
+                hir::db::InternGeneratorQuery
+                hir::db::AssociatedTyDataQuery
+                hir::db::TraitDatumQuery
+                hir::db::StructDatumQuery
+                hir::db::ImplDatumQuery
+                hir::db::FnDefDatumQuery
+                hir::db::FnDefVarianceQuery
+                hir::db::AdtVarianceQuery
+                hir::db::AssociatedTyValueQuery
+                hir::db::TraitSolveQueryQuery
+                hir::db::ProgramClausesForChalkEnvQuery
+            ]
+            SymbolsDatabase => [
+                crate::symbol_index::ModuleSymbolsQuery
+                crate::symbol_index::LibrarySymbolsQuery
+                crate::symbol_index::LocalRootsQuery
+                crate::symbol_index::LibraryRootsQuery
+            ]
+            LineIndexDatabase => [
+                crate::LineIndexQuery
+            ]
+            TestIndexDatabase => [
+                crate::test_index::TestHarnessesQuery
+                crate::test_index::ModuleRunnablesQuery
+            ]
+            TextIndexDatabase => [
+                crate::text_index::FileTextIndexQuery
+            ]
+            DefinitionDatabase => [
+                crate::defs::InternDefinitionQuery
+            ]
+            DependencyGraphDatabase => [
+                crate::dependency_graph::ModuleReferencesQuery
+            ]
+            FfiIndexDatabase => [
+                crate::ffi_index::CrateFfiSymbolsQuery
+            ]
+            CallIndexDatabase => [
+                crate::call_index::OutgoingCallsQuery
+                crate::call_index::CrateCallsByCalleeQuery
+            ]
+            TypeHierarchyDatabase => [
+                crate::type_hierarchy::CrateImplIndexQuery
+            ]
+            UnsafeIndexDatabase => [
+                crate::unsafe_index::UnsafeSitesQuery
+                crate::unsafe_index::CrateUnsafeSitesQuery
+            ]
+            InternDatabase => [
+                hir::db::InternFunctionQuery
+                hir::db::InternStructQuery
+                hir::db::InternUnionQuery
+                hir::db::InternEnumQuery
+                hir::db::InternConstQuery
+                hir::db::InternStaticQuery
+                hir::db::InternTraitQuery
+                hir::db::InternTraitAliasQuery
+                hir::db::InternTypeAliasQuery
+                hir::db::InternImplQuery
+                hir::db::InternExternBlockQuery
+                hir::db::InternBlockQuery
+                hir::db::InternMacro2Query
+                hir::db::InternProcMacroQuery
+                hir::db::InternMacroRulesQuery
+            ]
+        ];
+        groups.sort_by_key(|it| std::cmp::Reverse(it.bytes));
+
+        let mut crates: FxHashMap<CrateId, CrateMemoryUsage> = FxHashMap::default();
+        for query in groups.iter().flat_map(|it| &it.queries) {
+            let Some(crates_of_entries) = crates_of_entries.get(&query.name) else { continue };
+            let bytes_per_entry = query.bytes.bytes() / crates_of_entries.len().max(1) as isize;
+            for entry_crates in crates_of_entries {
+                let share = bytes_per_entry / entry_crates.len().max(1) as isize;
+                for &krate in entry_crates {
+                    let usage = crates.entry(krate).or_insert_with(|| CrateMemoryUsage {
+                        krate,
+                        display_name: crate_graph[krate]
+                            .display_name
+                            .as_ref()
+                            .map(|it| it.to_string()),
+                        bytes: Bytes::default(),
+                        entries: 0,
+                    });
+                    usage.bytes = usage.bytes + Bytes::new(share);
+                    usage.entries += 1;
+                }
+            }
+        }
+        let mut crates: Vec<_> = crates.into_values().collect();
+        crates.sort_by_key(|it| (std::cmp::Reverse(it.bytes), it.krate));
+
+        MemoryUsageReport { groups, crates }
+    }
+
+    // Feature: Memory Usage
+    //
+    // Clears rust-analyzer's internal database and prints memory usage statistics.
+    //
+    // |===
+    // | Editor  | Action Name
+    //
+    // | VS Code | **rust-analyzer: Memory Usage (Clears Database)**
+    // |===
+    // image::https://user-images.githubusercontent.com/48062697/113065592-08559f00-91b1-11eb-8c96-64b88068ec02.gif[]
+    pub fn per_query_memory_usage(&mut self) -> Vec<(String, Bytes, usize)> {
+        let report = self.memory_usage_report();
+        let mut acc: Vec<_> = report
+            .groups
+            .into_iter()
+            .flat_map(|it| it.queries)
+            .map(|it| (it.name, it.bytes, it.entries))
+            .collect();
+        acc.sort_by_key(|it| std::cmp::Reverse(it.1));
+        acc
+    }
+}
+
+fn collect_query_count<'q, Q>(table: &QueryTable<'q, Q>) -> usize
+where
+    QueryTable<'q, Q>: DebugQueryTable,
+    Q: Query,
+    <Q as Query>::Storage: 'q,
+{
+    struct EntryCounter(usize);
+    impl<K, V> FromIterator<TableEntry<K, V>> for EntryCounter {
+        fn from_iter<T>(iter: T) -> EntryCounter
+        where
+            T: IntoIterator<Item = TableEntry<K, V>>,
+        {
+            EntryCounter(iter.into_iter().count())
+        }
+    }
+    table.entries::<EntryCounter>().0
+}
+
+fn collect_query_keys<'q, Q>(table: &QueryTable<'q, Q>) -> Vec<Q::Key>
+where
+    QueryTable<'q, Q>: DebugQueryTable<Key = Q::Key>,
+    Q: Query,
+    <Q as Query>::Storage: 'q,
+{
+    struct KeyCollector<K>(Vec<K>);
+    impl<K, V> FromIterator<TableEntry<K, V>> for KeyCollector<K> {
+        fn from_iter<T>(iter: T) -> KeyCollector<K>
+        where
+            T: IntoIterator<Item = TableEntry<K, V>>,
+        {
+            KeyCollector(iter.into_iter().map(|it| it.key).collect())
+        }
+    }
+    table.entries::<KeyCollector<Q::Key>>().0
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::fixture::WithFixture;
+    use hir::db::DefDatabase;
+
+    use super::*;
+
+    #[test]
+    fn attributes_entries_to_crates() {
+        let (mut db, files) = RootDatabase::with_many_files(
+            r#"
+//- /main.rs crate:main deps:dep
+fn main() {}
+//- /dep.rs crate:dep
+pub fn dep() {}
+"#,
+        );
+        for &file_id in &files {
+            db.parse(file_id);
+        }
+        for krate in db.crate_graph().iter() {
+            db.crate_def_map(krate);
+        }
+
+        let report = db.memory_usage_report();
+        let def_db = report.groups.iter().find(|it| it.name == "DefDatabase").unwrap();
+        assert!(def_db.queries.iter().any(|it| it.name == "CrateDefMapQueryQuery"));
+
+        let mut crates: Vec<_> = report
+            .crates
+            .iter()
+            .map(|it| (it.display_name.clone().unwrap(), it.entries > 0))
+            .collect();
+        crates.sort();
+        assert_eq!(crates, [("dep".to_owned(), true), ("main".to_owned(), true)]);
+    }
+}
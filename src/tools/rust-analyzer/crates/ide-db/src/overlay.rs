@@ -0,0 +1,150 @@
+//! Bookkeeping of the unsaved contents of files, for embedders that don't get it from an LSP
+//! client.
+//!
+//! The database only knows the current text of every file. Editors also need to know which files
+//! have unsaved changes, and what to go back to when an unsaved buffer is closed, so the
+//! [`Overlay`] keeps the text on disk of the files it overlays, and records the changes to apply
+//! to the database in a [`Change`].
+
+use base_db::{Change, FileId};
+use triomphe::Arc;
+
+use crate::FxHashMap;
+
+/// The unsaved contents of the files open in an editor.
+#[derive(Debug, Default)]
+pub struct Overlay {
+    /// The text on disk of every file, as reported by [`Overlay::set_disk_text`].
+    disk: FxHashMap<FileId, Arc<str>>,
+    open: FxHashMap<FileId, OverlayFile>,
+    /// Bumped by every change of the text of a file seen by the database.
+    sequence: u64,
+}
+
+#[derive(Debug)]
+struct OverlayFile {
+    text: Arc<str>,
+    /// The sequence number of the last change of the file.
+    sequence: u64,
+}
+
+impl Overlay {
+    pub fn new() -> Overlay {
+        Overlay::default()
+    }
+
+    /// The sequence number of the last change, which increases with every change of the text of
+    /// a file.
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// The sequence number of the last change of the unsaved text of `file_id`, if it is open.
+    pub fn file_sequence(&self, file_id: FileId) -> Option<u64> {
+        self.open.get(&file_id).map(|file| file.sequence)
+    }
+
+    /// The text the database sees for `file_id`: the unsaved text if the file is open, and the
+    /// text on disk otherwise.
+    pub fn text(&self, file_id: FileId) -> Option<Arc<str>> {
+        match self.open.get(&file_id) {
+            Some(file) => Some(file.text.clone()),
+            None => self.disk.get(&file_id).cloned(),
+        }
+    }
+
+    pub fn is_open(&self, file_id: FileId) -> bool {
+        self.open.contains_key(&file_id)
+    }
+
+    /// Whether the unsaved text of `file_id` differs from the text on disk.
+    pub fn is_dirty(&self, file_id: FileId) -> bool {
+        match self.open.get(&file_id) {
+            Some(file) => self.disk.get(&file_id) != Some(&file.text),
+            None => false,
+        }
+    }
+
+    /// The open files whose unsaved text differs from the text on disk, sorted.
+    pub fn dirty_files(&self) -> Vec<FileId> {
+        let mut res: Vec<_> =
+            self.open.keys().copied().filter(|&file_id| self.is_dirty(file_id)).collect();
+        res.sort_unstable();
+        res
+    }
+
+    /// Records the text on disk of `file_id`, `None` if it was deleted. The database only sees
+    /// the new text if the file isn't open.
+    pub fn set_disk_text(&mut self, file_id: FileId, text: Option<Arc<str>>, change: &mut Change) {
+        match text {
+            Some(text) => self.disk.insert(file_id, text.clone()),
+            None => self.disk.remove(&file_id),
+        };
+        if !self.is_open(file_id) {
+            self.sequence += 1;
+            change.change_file(file_id, self.disk.get(&file_id).cloned());
+        }
+    }
+
+    /// Opens `file_id` with the given unsaved text, or replaces its unsaved text if it is open
+    /// already. Returns the sequence number of the change.
+    pub fn set_text(&mut self, file_id: FileId, text: Arc<str>, change: &mut Change) -> u64 {
+        self.sequence += 1;
+        self.open.insert(file_id, OverlayFile { text: text.clone(), sequence: self.sequence });
+        change.change_file(file_id, Some(text));
+        self.sequence
+    }
+
+    /// Records that the unsaved text of `file_id` has been written to disk.
+    pub fn save(&mut self, file_id: FileId) {
+        if let Some(file) = self.open.get(&file_id) {
+            self.disk.insert(file_id, file.text.clone());
+        }
+    }
+
+    /// Closes `file_id`, discarding its unsaved text, if any, in favor of the text on disk.
+    pub fn close(&mut self, file_id: FileId, change: &mut Change) {
+        if let Some(file) = self.open.remove(&file_id) {
+            let disk = self.disk.get(&file_id).cloned();
+            if disk.as_ref() != Some(&file.text) {
+                self.sequence += 1;
+                change.change_file(file_id, disk);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracks_dirty_files() {
+        let (a, b) = (FileId(0), FileId(1));
+        let mut overlay = Overlay::new();
+        let mut change = Change::new();
+        overlay.set_disk_text(a, Some(Arc::from("fn a() {}")), &mut change);
+        overlay.set_disk_text(b, Some(Arc::from("fn b() {}")), &mut change);
+        assert_eq!(change.files_changed.len(), 2);
+        assert_eq!(overlay.sequence(), 2);
+
+        let mut change = Change::new();
+        assert_eq!(overlay.set_text(a, Arc::from("fn a() { 92 }"), &mut change), 3);
+        overlay.set_text(b, Arc::from("fn b() {}"), &mut change);
+        assert_eq!(overlay.dirty_files(), [a]);
+        assert_eq!(overlay.file_sequence(a), Some(3));
+
+        // Changes on disk are hidden by the unsaved text.
+        overlay.set_disk_text(a, Some(Arc::from("fn a() { 1 }")), &mut change);
+        assert_eq!(overlay.text(a).as_deref(), Some("fn a() { 92 }"));
+        assert_eq!(change.files_changed.len(), 2);
+
+        overlay.save(a);
+        assert!(!overlay.is_dirty(a));
+        overlay.set_text(b, Arc::from("fn b() { 92 }"), &mut change);
+        overlay.close(b, &mut change);
+        assert_eq!(change.files_changed.last(), Some(&(b, Some(Arc::from("fn b() {}")))));
+        assert!(overlay.dirty_files().is_empty());
+        assert_eq!(overlay.sequence(), 6);
+    }
+}
@@ -0,0 +1,87 @@
+//! Parallel execution of work over many files, crates or modules of the workspace.
+//!
+//! The items are distributed over rayon's work-stealing thread pool, so that a few expensive
+//! items don't leave the other threads idle, and every worker thread uses its own snapshot of the
+//! database. Cancellation works like for sequential work: a worker whose snapshot is cancelled
+//! unwinds, and rayon resumes the unwinding on the calling thread once the other workers are done,
+//! which they are quickly as their snapshots are cancelled as well.
+
+use base_db::salsa::{self, Database, ParallelDatabase};
+use rayon::prelude::*;
+
+use crate::RootDatabase;
+
+/// Maps `items` with `f` in parallel, keeping their order.
+pub fn par_map<T: Send, R: Send>(
+    db: &RootDatabase,
+    items: Vec<T>,
+    f: impl Fn(&RootDatabase, T) -> R + Sync,
+) -> Vec<R> {
+    items
+        .into_par_iter()
+        .map_with(Snap::new(db), |snap, item| {
+            snap.unwind_if_cancelled();
+            f(snap, item)
+        })
+        .collect()
+}
+
+/// Maps `items` with `f` in parallel and flattens the results, keeping their order.
+pub fn par_flat_map<T: Send, R: Send, I: IntoIterator<Item = R>>(
+    db: &RootDatabase,
+    items: Vec<T>,
+    f: impl Fn(&RootDatabase, T) -> I + Sync,
+) -> Vec<R> {
+    par_map(db, items, |db, item| f(db, item).into_iter().collect::<Vec<_>>())
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Need to wrap Snapshot to provide `Clone` impl for `map_with`
+pub(crate) struct Snap<DB>(DB);
+impl<DB: ParallelDatabase> Snap<salsa::Snapshot<DB>> {
+    pub(crate) fn new(db: &DB) -> Self {
+        Self(db.snapshot())
+    }
+}
+impl<DB: ParallelDatabase> Clone for Snap<salsa::Snapshot<DB>> {
+    fn clone(&self) -> Snap<salsa::Snapshot<DB>> {
+        Snap(self.0.snapshot())
+    }
+}
+impl<DB> std::ops::Deref for Snap<DB> {
+    type Target = DB;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::{fixture::WithFixture, SourceDatabaseExt};
+
+    use super::*;
+
+    #[test]
+    fn keeps_order() {
+        let (db, files) = RootDatabase::with_many_files(
+            r#"
+//- /main.rs
+mod a;
+mod b;
+//- /a.rs
+fn a() {}
+//- /b.rs
+fn b() {}
+"#,
+        );
+        let lens = par_map(&db, files.clone(), |db, file_id| db.file_text(file_id).len());
+        assert_eq!(lens, [14, 10, 10]);
+        let texts = par_flat_map(&db, files, |db, file_id| {
+            db.file_text(file_id).lines().map(str::to_owned).collect::<Vec<_>>()
+        });
+        assert_eq!(texts, ["mod a;", "mod b;", "fn a() {}", "fn b() {}"]);
+    }
+}
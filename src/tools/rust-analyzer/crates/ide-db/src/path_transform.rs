@@ -1,12 +1,15 @@
 //! See [`PathTransform`].
 
-use crate::helpers::mod_path_to_ast;
+use crate::{
+    defs::Definition,
+    helpers::{mod_path_to_ast, path_to_definition},
+};
 use either::Either;
-use hir::{AsAssocItem, HirDisplay, SemanticsScope};
-use rustc_hash::FxHashMap;
+use hir::{db::HirDatabase, AsAssocItem, HasSource, HirDisplay, SemanticsScope};
+use rustc_hash::{FxHashMap, FxHashSet};
 use syntax::{
     ast::{self, AstNode},
-    ted, SyntaxNode,
+    ted, SyntaxElement, SyntaxKind, SyntaxNode, SyntaxToken, T,
 };
 
 #[derive(Default)]
@@ -50,6 +53,8 @@ pub struct PathTransform<'a> {
     substs: AstSubsts,
     target_scope: &'a SemanticsScope<'a>,
     source_scope: &'a SemanticsScope<'a>,
+    /// The self type of the target impl, which `Self` refers to in the transformed code.
+    target_self_ty: Option<ast::Type>,
 }
 
 impl<'a> PathTransform<'a> {
@@ -63,7 +68,8 @@ pub fn trait_impl(
             source_scope,
             target_scope,
             generic_def: Some(trait_.into()),
-            substs: get_syntactic_substs(impl_).unwrap_or_default(),
+            substs: get_syntactic_substs(impl_.clone()).unwrap_or_default(),
+            target_self_ty: impl_.self_ty(),
         }
     }
 
@@ -78,6 +84,7 @@ pub fn function_call(
             target_scope,
             generic_def: Some(function.into()),
             substs: get_type_args_from_arg_list(generic_arg_list).unwrap_or_default(),
+            target_self_ty: None,
         }
     }
 
@@ -90,6 +97,7 @@ pub fn generic_transformation(
             target_scope,
             generic_def: None,
             substs: AstSubsts::default(),
+            target_self_ty: None,
         }
     }
 
@@ -106,7 +114,6 @@ pub fn apply_all<'b>(&self, nodes: impl IntoIterator<Item = &'b SyntaxNode>) {
 
     fn build_ctx(&self) -> Ctx<'a> {
         let db = self.source_scope.db;
-        let target_module = self.target_scope.module();
         let source_module = self.source_scope.module();
         let skip = match self.generic_def {
             // this is a trait impl, so we need to skip the first type parameter (i.e. Self) -- this is a bit hacky
@@ -116,6 +123,7 @@ fn build_ctx(&self) -> Ctx<'a> {
         let mut type_substs: FxHashMap<hir::TypeParam, ast::Type> = Default::default();
         let mut const_substs: FxHashMap<hir::ConstParam, SyntaxNode> = Default::default();
         let mut default_types: Vec<hir::TypeParam> = Default::default();
+        let mut default_consts: Vec<hir::ConstParam> = Default::default();
         self.generic_def
             .into_iter()
             .flat_map(|it| it.type_params(db))
@@ -158,34 +166,70 @@ fn build_ctx(&self) -> Ctx<'a> {
                         const_substs.insert(k, expr.syntax().clone());
                     }
                 }
-                (Either::Left(_), None) => (), // FIXME: get default const value
-                _ => (),                       // ignore mismatching params
+                (Either::Left(k), None) => {
+                    if let Some(default) = const_param_default(db, k) {
+                        const_substs.insert(k, default.syntax().clone_subtree().clone_for_update());
+                        default_consts.push(k);
+                    }
+                }
+                _ => (), // ignore mismatching params
             });
+        // Lifetimes that aren't given, like the ones of a function called without them in the
+        // turbofish, are elided.
         let lifetime_substs: FxHashMap<_, _> = self
             .generic_def
             .into_iter()
             .flat_map(|it| it.lifetime_params(db))
-            .zip(self.substs.lifetimes.clone())
-            .filter_map(|(k, v)| Some((k.name(db).display(db.upcast()).to_string(), v.lifetime()?)))
+            .zip(self.substs.lifetimes.iter().map(Some).chain(std::iter::repeat(None)))
+            .filter_map(|(k, v)| {
+                let subst = match v {
+                    Some(v) => v.lifetime()?,
+                    None => ast::make::lifetime("'_").clone_for_update(),
+                };
+                Some((k.name(db).display(db.upcast()).to_string(), subst))
+            })
             .collect();
+        let target_self_ty = match &self.target_self_ty {
+            Some(ast::Type::PathType(it)) => {
+                match it.path().and_then(|it| self.target_scope.speculative_resolve(&it)) {
+                    Some(hir::PathResolution::Def(hir::ModuleDef::Adt(it))) => Some(it.ty(db)),
+                    Some(hir::PathResolution::Def(hir::ModuleDef::BuiltinType(it))) => {
+                        Some(it.ty(db))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
         let ctx = Ctx {
             type_substs,
             const_substs,
             lifetime_substs,
-            target_module,
+            target_scope: self.target_scope,
             source_scope: self.source_scope,
+            target_self_ty,
         };
         ctx.transform_default_type_substs(default_types);
+        ctx.transform_default_const_substs(default_consts);
         ctx
     }
 }
 
+/// The default value of a const parameter, as written in its declaration.
+fn const_param_default(db: &dyn HirDatabase, param: hir::ConstParam) -> Option<ast::Expr> {
+    match param.merge().source(db)?.value {
+        Either::Left(ast::TypeOrConstParam::Const(it)) => it.default_val(),
+        _ => None,
+    }
+}
+
 struct Ctx<'a> {
     type_substs: FxHashMap<hir::TypeParam, ast::Type>,
     const_substs: FxHashMap<hir::ConstParam, SyntaxNode>,
     lifetime_substs: FxHashMap<LifetimeName, ast::Lifetime>,
-    target_module: hir::Module,
+    target_scope: &'a SemanticsScope<'a>,
     source_scope: &'a SemanticsScope<'a>,
+    target_self_ty: Option<hir::Type>,
 }
 
 fn postorder(item: &SyntaxNode) -> impl Iterator<Item = SyntaxNode> {
@@ -205,6 +249,20 @@ fn apply(&self, item: &SyntaxNode) {
             self.transform_path(path);
         }
 
+        // Paths in macro calls are just tokens, so they are transformed separately.
+        let token_paths = item
+            .descendants()
+            .filter_map(ast::MacroCall::cast)
+            .filter_map(|it| it.token_tree())
+            .flat_map(|tt| {
+                tt.syntax().descendants().filter_map(ast::TokenTree::cast).collect::<Vec<_>>()
+            })
+            .flat_map(|tt| token_paths(&tt))
+            .collect::<Vec<_>>();
+        for tokens in token_paths {
+            self.transform_token_path(tokens);
+        }
+
         postorder(item).filter_map(ast::Lifetime::cast).for_each(|lifetime| {
             if let Some(subst) = self.lifetime_substs.get(&lifetime.syntax().text().to_string()) {
                 ted::replace(lifetime.syntax(), subst.clone_subtree().clone_for_update().syntax());
@@ -225,10 +283,44 @@ fn transform_default_type_substs(&self, default_types: Vec<hir::TypeParam>) {
         }
     }
 
+    fn transform_default_const_substs(&self, default_consts: Vec<hir::ConstParam>) {
+        for k in default_consts {
+            let v = self.const_substs.get(&k).unwrap();
+            let paths = postorder(v).filter_map(ast::Path::cast).collect::<Vec<_>>();
+            for path in paths {
+                self.transform_path(path);
+            }
+        }
+    }
+
+    /// Transforms the path written as `tokens` in a macro call by parsing it on its own and
+    /// replacing the tokens with the ones of the transformed path.
+    fn transform_token_path(&self, tokens: Vec<SyntaxToken>) -> Option<()> {
+        let text = tokens.iter().map(|it| it.text()).collect::<String>();
+        // Wrap the path, so that it can be replaced as a whole.
+        let expr = ast::make::expr_path(ast::make::path_from_text(&text)).clone_for_update();
+        let paths = postorder(expr.syntax()).filter_map(ast::Path::cast).collect::<Vec<_>>();
+        for path in paths {
+            self.transform_path(path);
+        }
+        let new_text = expr.syntax().to_string();
+        if new_text == text {
+            return None;
+        }
+        let new_tokens = tokens_from_text(&new_text)?;
+        let first = SyntaxElement::Token(tokens.first()?.clone());
+        let last = SyntaxElement::Token(tokens.last()?.clone());
+        ted::replace_all(first..=last, new_tokens);
+        Some(())
+    }
+
     fn transform_path(&self, path: ast::Path) -> Option<()> {
         if path.qualifier().is_some() {
             return None;
         }
+        if path.parent_path().is_some() && self.transform_qualified_path(&path).is_some() {
+            return Some(());
+        }
         if path.segment().map_or(false, |s| {
             s.param_list().is_some() || (s.self_token().is_some() && path.parent_path().is_none())
         }) {
@@ -258,9 +350,9 @@ fn transform_path(&self, path: ast::Path) -> Option<()> {
                             parent.segment()?.name_ref()?,
                         )
                         .and_then(|trait_ref| {
-                            let found_path = self.target_module.find_use_path(
-                                self.source_scope.db.upcast(),
-                                hir::ModuleDef::Trait(trait_ref),
+                            let found_path = path_to_definition(
+                                self.target_scope,
+                                Definition::Trait(trait_ref),
                                 false,
                             )?;
                             match ast::make::ty_path(mod_path_to_ast(&found_path)) {
@@ -284,6 +376,8 @@ fn transform_path(&self, path: ast::Path) -> Option<()> {
                             subst.clone_subtree().clone_for_update().syntax(),
                         );
                     }
+                } else if let Some(parent) = path.syntax().parent().and_then(ast::Path::cast) {
+                    self.qualify_self_assoc_item(&path, &parent);
                 }
             }
             hir::PathResolution::Def(def) if def.as_assoc_item(self.source_scope.db).is_none() => {
@@ -297,8 +391,7 @@ fn transform_path(&self, path: ast::Path) -> Option<()> {
                     }
                 }
 
-                let found_path =
-                    self.target_module.find_use_path(self.source_scope.db.upcast(), def, false)?;
+                let found_path = path_to_definition(self.target_scope, def.into(), false)?;
                 let res = mod_path_to_ast(&found_path).clone_for_update();
                 if let Some(args) = path.segment().and_then(|it| it.generic_arg_list()) {
                     if let Some(segment) = res.segment() {
@@ -324,6 +417,146 @@ fn transform_path(&self, path: ast::Path) -> Option<()> {
     }
 }
 
+impl Ctx<'_> {
+    /// Replaces the whole path starting with `first`, like `internal::Foo`, with the path to the
+    /// definition it resolves to, so that imports and re-exports of the definition itself are used
+    /// rather than the ones of the first segment.
+    fn transform_qualified_path(&self, first: &ast::Path) -> Option<()> {
+        let path = first.top_path();
+        let is_plain = path.segments().all(|segment| {
+            let is_last = Some(&segment) == path.segment().as_ref();
+            matches!(
+                segment.kind(),
+                Some(
+                    ast::PathSegmentKind::Name(_)
+                        | ast::PathSegmentKind::CrateKw
+                        | ast::PathSegmentKind::SelfKw
+                        | ast::PathSegmentKind::SuperKw
+                )
+            ) && (is_last || segment.generic_arg_list().is_none())
+        });
+        if !is_plain {
+            return None;
+        }
+        let def = match self.source_scope.speculative_resolve(&path)? {
+            hir::PathResolution::Def(def) if def.as_assoc_item(self.source_scope.db).is_none() => {
+                def
+            }
+            _ => return None,
+        };
+        let found_path = path_to_definition(self.target_scope, def.into(), false)?;
+        let res = mod_path_to_ast(&found_path).clone_for_update();
+        if let Some(args) = path.segment().and_then(|it| it.generic_arg_list()) {
+            if let Some(segment) = res.segment() {
+                let old = segment.get_or_create_generic_arg_list();
+                ted::replace(old.syntax(), args.clone_subtree().syntax().clone_for_update())
+            }
+        }
+        ted::replace(path.syntax(), res.syntax());
+        Some(())
+    }
+
+    /// Rewrites the `Self` of `Self::Assoc` into `<Self as Trait>` if the self type of the target
+    /// impl also implements another trait in scope with an item named `Assoc`, which would make
+    /// the path ambiguous there.
+    fn qualify_self_assoc_item(&self, self_path: &ast::Path, parent: &ast::Path) -> Option<()> {
+        let db = self.source_scope.db;
+        if !matches!(self_path.segment()?.kind()?, ast::PathSegmentKind::SelfTypeKw) {
+            return None;
+        }
+        let name = parent.segment()?.name_ref()?;
+        let assoc_item = match self.source_scope.speculative_resolve(parent)? {
+            hir::PathResolution::Def(def) => def.as_assoc_item(db)?,
+            _ => return None,
+        };
+        let trait_ = assoc_item.containing_trait(db)?;
+
+        let self_ty = self.target_self_ty.clone()?;
+        let visible_traits: FxHashSet<hir::Trait> =
+            self.target_scope.visible_traits().0.into_iter().map(hir::Trait::from).collect();
+        let is_ambiguous = hir::Impl::all_for_type(db, self_ty)
+            .into_iter()
+            .filter_map(|impl_| impl_.trait_(db))
+            .filter(|&it| it != trait_ && visible_traits.contains(&it))
+            .flat_map(|it| it.items(db))
+            .any(|item| item.name(db).map_or(false, |it| it.to_smol_str() == name.text().as_str()));
+        if !is_ambiguous {
+            return None;
+        }
+
+        let trait_path = path_to_definition(self.target_scope, Definition::Trait(trait_), false)?;
+        let trait_ref = match ast::make::ty_path(mod_path_to_ast(&trait_path)) {
+            ast::Type::PathType(path_ty) => path_ty,
+            _ => return None,
+        };
+        let segment = ast::make::path_segment_ty(ast::make::ty("Self"), Some(trait_ref));
+        let qualified = ast::make::path_from_segments(std::iter::once(segment), false);
+        ted::replace(self_path.syntax(), qualified.clone_for_update().syntax());
+        Some(())
+    }
+}
+
+/// Finds the token sequences in `tt` that look like plain paths, like `foo`, `Self::Assoc` or
+/// `crate::foo::Bar`. Method and field names, macro names, metavariables and paths with generic
+/// arguments aren't included.
+fn token_paths(tt: &ast::TokenTree) -> Vec<Vec<SyntaxToken>> {
+    let tokens: Vec<_> =
+        tt.syntax().children_with_tokens().filter_map(|it| it.into_token()).collect();
+    let is_segment = |idx: usize| {
+        tokens.get(idx).map_or(false, |it| {
+            matches!(it.kind(), SyntaxKind::IDENT | T![self] | T![super] | T![crate] | T![Self])
+        })
+    };
+    // The number of tokens of the path separator at `idx`, as `::` is lexed as two colons in
+    // token trees.
+    let separator_len = |idx: usize| match tokens.get(idx).map(|it| it.kind()) {
+        Some(T![::]) => 1,
+        Some(T![:]) if tokens.get(idx + 1).map(|it| it.kind()) == Some(T![:]) => 2,
+        _ => 0,
+    };
+
+    let mut res = Vec::new();
+    let mut idx = 0;
+    while idx < tokens.len() {
+        if !is_segment(idx) {
+            idx += 1;
+            continue;
+        }
+        let start = idx;
+        let mut end = idx + 1;
+        loop {
+            let sep = separator_len(end);
+            if sep == 0 || !is_segment(end + sep) {
+                break;
+            }
+            end += sep + 1;
+        }
+        idx = end;
+
+        let prev = tokens[..start].iter().rev().find(|it| !it.kind().is_trivia());
+        let next = tokens[end..].iter().find(|it| !it.kind().is_trivia());
+        let is_qualified = matches!(prev.map(|it| it.kind()), Some(T![.] | T![$] | T![::]))
+            || (start >= 2 && separator_len(start - 2) == 2);
+        // Names followed by a colon are field names or bindings with a type.
+        let is_path =
+            !is_qualified && !matches!(next.map(|it| it.kind()), Some(T![!] | T![:] | T![::]));
+        if is_path {
+            res.push(tokens[start..end].to_vec());
+        }
+    }
+    res
+}
+
+/// Lexes `text` into the tokens of a token tree.
+fn tokens_from_text(text: &str) -> Option<Vec<SyntaxElement>> {
+    let parse = ast::SourceFile::parse(&format!("m!({text});"));
+    let tt = parse.tree().syntax().descendants().find_map(ast::TokenTree::cast)?;
+    let tt = tt.clone_for_update();
+    let elements: Vec<_> = tt.syntax().children_with_tokens().collect();
+    // Skip the parentheses.
+    Some(elements[1..elements.len().checked_sub(1)?].to_vec())
+}
+
 // FIXME: It would probably be nicer if we could get this via HIR (i.e. get the
 // trait ref, and then go from the types in the substs back to the syntax).
 fn get_syntactic_substs(impl_def: ast::Impl) -> Option<AstSubsts> {
@@ -0,0 +1,108 @@
+//! Pinned copies of the database for long-running analyses.
+//!
+//! The snapshots of a database are cancelled by every change, and a change waits for them to be
+//! dropped before it's applied. That's fine for requests, which are retried, but jobs taking
+//! minutes, like exporting an index of the workspace, would never finish while the user types,
+//! and would delay every keystroke meanwhile.
+
+use base_db::{salsa::Durability, SourceDatabase, SourceDatabaseExt, SourceRootId};
+use hir::db::DefDatabase;
+use rustc_hash::FxHashSet;
+use triomphe::Arc;
+
+use crate::{symbol_index::SymbolsDatabase, test_index::TestIndexDatabase, RootDatabase};
+
+impl RootDatabase {
+    /// Creates a database with the inputs this one has now, which changes to this database don't
+    /// affect. The file texts and other inputs are shared rather than copied, but derived data is
+    /// not, so the pinned database computes what it needs anew.
+    ///
+    /// The LRU capacities, active files and side tables are carried over too, the side tables as
+    /// copies of their current entries since they follow the texts of their database.
+    pub fn pin(&self) -> RootDatabase {
+        let _p = profile::span("RootDatabase::pin");
+        let mut db = RootDatabase::new(None);
+        db.update_lru_capacities(&self.lru_capacities);
+        db.active_files = self.active_files.clone();
+        db.side_tables = Arc::new(self.side_tables.copy());
+        db.set_test_harnesses_with_durability(self.test_harnesses(), Durability::HIGH);
+        db.set_crate_graph_with_durability(self.crate_graph(), Durability::HIGH);
+        db.set_proc_macros_with_durability(self.proc_macros(), Durability::HIGH);
+        db.set_local_roots_with_durability(self.local_roots(), Durability::HIGH);
+        db.set_library_roots_with_durability(self.library_roots(), Durability::HIGH);
+        db.set_expand_proc_attr_macros_with_durability(
+            self.expand_proc_attr_macros(),
+            Durability::HIGH,
+        );
+
+        // Databases set up without `apply_change` don't know their roots, the roots of the crates
+        // are the ones that matter anyway.
+        let crate_graph = self.crate_graph();
+        let mut roots: FxHashSet<SourceRootId> =
+            self.local_roots().iter().chain(self.library_roots().iter()).copied().collect();
+        roots.extend(
+            crate_graph.iter().map(|it| self.file_source_root(crate_graph[it].root_file_id)),
+        );
+        for root_id in roots {
+            let root = self.source_root(root_id);
+            let durability = if root.is_library { Durability::HIGH } else { Durability::LOW };
+            for file_id in root.iter() {
+                db.set_file_source_root_with_durability(file_id, root_id, durability);
+                db.set_file_text_with_durability(file_id, self.file_text(file_id), durability);
+            }
+            db.set_source_root_with_durability(root_id, root, durability);
+        }
+        db
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::{fixture::WithFixture, salsa::ParallelDatabase, Change, FileRange};
+    use syntax::TextRange;
+
+    use crate::{test_index::TestHarness, FxHashMap};
+
+    use super::*;
+
+    #[test]
+    fn pinned_database_is_unaffected_by_changes() {
+        let (mut db, file_id) = RootDatabase::with_single_file("fn foo() {}");
+        let pinned = db.pin();
+        // Changes would wait for snapshots of the database itself forever.
+        let snap = pinned.snapshot();
+
+        let mut change = Change::new();
+        change.change_file(file_id, Some(Arc::from("fn bar() {}")));
+        db.apply_change(change);
+
+        assert_eq!(&*db.file_text(file_id), "fn bar() {}");
+        assert_eq!(&*snap.file_text(file_id), "fn foo() {}");
+        assert_eq!(snap.crate_graph().iter().count(), 1);
+    }
+
+    #[test]
+    fn pinned_database_keeps_settings() {
+        let (mut db, file_id) = RootDatabase::with_single_file("fn foo() {}");
+        let capacities = FxHashMap::from_iter([(Box::from("BodyQuery"), 8)]);
+        db.update_lru_capacities(&capacities);
+        let mut harnesses = TestHarness::defaults().to_vec();
+        harnesses.push(TestHarness::test("my_harness::check"));
+        db.set_test_harnesses_with_durability(Arc::from(harnesses), Durability::HIGH);
+        let range = FileRange { file_id, range: TextRange::new(3.into(), 6.into()) };
+        db.register_side_table::<u32>("owners").insert(range, 1);
+
+        let pinned = db.pin();
+        assert_eq!(pinned.lru_capacities, db.lru_capacities);
+        assert_eq!(pinned.lru_capacities.get("BodyQuery"), Some(&8));
+        assert_eq!(pinned.test_harnesses(), db.test_harnesses());
+        let owners = pinned.side_table::<u32>("owners").unwrap();
+        assert_eq!(owners.entries(file_id), vec![(range.range, 1)]);
+
+        // The entries of the pinned database stay attached to the text it has.
+        let mut change = Change::new();
+        change.change_file(file_id, Some(Arc::from("fn bar_baz() {}")));
+        db.apply_change(change);
+        assert_eq!(owners.entries(file_id), vec![(range.range, 1)]);
+    }
+}
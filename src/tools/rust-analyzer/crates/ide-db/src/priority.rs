@@ -0,0 +1,89 @@
+//! Priorities of the work done with database snapshots, which let latency-sensitive requests
+//! preempt background work.
+//!
+//! The database counts the foreground snapshots that are alive. Background work like priming
+//! caches is cancelled through salsa when foreground work starts, like on a change, and is only
+//! restarted once [`RootDatabase::has_foreground_work`] is false again.
+
+use std::{
+    mem::ManuallyDrop,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use base_db::salsa;
+
+use crate::RootDatabase;
+
+/// The priority of the work done with a database snapshot.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Priority {
+    /// Work that doesn't preempt anything.
+    #[default]
+    Normal,
+    /// Latency-sensitive work, like classifying the tokens the user is looking at, which
+    /// preempts background work while its snapshot is alive.
+    Foreground,
+}
+
+/// The number of foreground snapshots alive, shared by a database and its snapshots.
+#[derive(Debug, Default)]
+pub(crate) struct ForegroundWork(AtomicUsize);
+
+impl RootDatabase {
+    pub fn priority(&self) -> Priority {
+        self.priority
+    }
+
+    /// Takes a snapshot of the database for work of the given priority. Snapshots taken from
+    /// the returned one with [`salsa::ParallelDatabase::snapshot`] have the same priority.
+    pub fn snapshot_with_priority(&self, priority: Priority) -> salsa::Snapshot<RootDatabase> {
+        if priority == Priority::Foreground {
+            self.foreground_work.0.fetch_add(1, Ordering::AcqRel);
+        }
+        salsa::Snapshot::new(RootDatabase {
+            storage: ManuallyDrop::new(self.storage.snapshot()),
+            priority,
+            foreground_work: self.foreground_work.clone(),
+            invalidation: self.invalidation.clone(),
+            active_files: self.active_files.clone(),
+            side_tables: self.side_tables.clone(),
+            lru_capacities: self.lru_capacities.clone(),
+        })
+    }
+
+    /// Whether a foreground snapshot of this database is alive.
+    pub fn has_foreground_work(&self) -> bool {
+        self.foreground_work.0.load(Ordering::Acquire) > 0
+    }
+
+    /// Called when the database is dropped.
+    pub(crate) fn end_work(&self) {
+        if self.priority == Priority::Foreground {
+            self.foreground_work.0.fetch_sub(1, Ordering::AcqRel);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::salsa::ParallelDatabase;
+
+    use super::*;
+
+    #[test]
+    fn foreground_snapshots_are_counted() {
+        let db = RootDatabase::default();
+        let normal = db.snapshot();
+        assert!(!db.has_foreground_work());
+
+        let foreground = normal.snapshot_with_priority(Priority::Foreground);
+        let nested = foreground.snapshot();
+        assert_eq!(nested.priority(), Priority::Foreground);
+        assert!(db.has_foreground_work());
+
+        drop(foreground);
+        assert!(normal.has_foreground_work());
+        drop(nested);
+        assert!(!db.has_foreground_work());
+    }
+}
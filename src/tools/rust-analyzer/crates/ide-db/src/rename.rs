@@ -20,27 +20,37 @@
 //!
 //! The correct behavior in such cases is probably to show a dialog to the user.
 //! Our current behavior is ¯\_(ツ)_/¯.
-use std::fmt;
+use std::{collections::hash_map::Entry, fmt, iter};
 
-use base_db::{AnchoredPathBuf, FileId, FileRange};
+use base_db::{
+    AnchoredPath, AnchoredPathBuf, Edition, FileId, FileRange, SourceDatabase, SourceDatabaseExt,
+    SourceRoot, VfsPath,
+};
 use either::Either;
-use hir::{FieldSource, HasSource, InFile, ModuleSource, Semantics};
+use hir::{AsAssocItem, FieldSource, HasSource, HasVisibility, InFile, ModuleSource, Semantics};
+use memchr::memmem::Finder;
+use nohash_hasher::IntMap;
 use stdx::never;
 use syntax::{
-    ast::{self, HasName},
-    AstNode, SyntaxKind, TextRange, T,
+    ast::{self, edit::IndentLevel, HasAttrs, HasName, IsString},
+    match_ast,
+    utils::is_raw_identifier,
+    AstNode, AstToken, NodeOrToken, SyntaxKind, SyntaxNode, SyntaxToken, TextRange, TextSize, T,
 };
 use text_edit::{TextEdit, TextEditBuilder};
 
 use crate::{
-    defs::Definition,
-    search::FileReference,
+    defs::{Definition, NameRefClass},
+    rust_doc,
+    search::{crate_usages, FileReference},
     source_change::{FileSystemEdit, SourceChange},
     syntax_helpers::node_ext::expr_as_name_ref,
     traits::convert_to_def_in_trait,
     RootDatabase,
 };
 
+mod manifest;
+
 pub type Result<T, E = RenameError> = std::result::Result<T, E>;
 
 #[derive(Debug)]
@@ -71,14 +81,58 @@ pub fn rename(
         sema: &Semantics<'_, RootDatabase>,
         new_name: &str,
     ) -> Result<SourceChange> {
-        match *self {
+        let mut source_change = match *self {
             Definition::Module(module) => rename_mod(sema, module, new_name),
             Definition::BuiltinType(_) => {
                 bail!("Cannot rename builtin type")
             }
             Definition::SelfType(_) => bail!("Cannot rename `Self`"),
             def => rename_reference(sema, def, new_name),
+        }?;
+        let mut doctest_edits: IntMap<FileId, TextEditBuilder> = IntMap::default();
+        for (file_id, range, resolves) in doctest_occurrences(sema, *self) {
+            if resolves {
+                let new_name = new_name_for_edition(new_name, file_edition(sema, file_id))?;
+                doctest_edits.entry(file_id).or_default().replace(range, new_name);
+            }
+        }
+        for (file_id, edit) in doctest_edits {
+            source_change.insert_source_edit(file_id, edit.finish());
+        }
+        Ok(source_change)
+    }
+
+    /// Renames the occurrences of the name of the definition in comments and string literals,
+    /// see [`FindUsages::textual_occurrences`](crate::search::FindUsages::textual_occurrences).
+    ///
+    /// Unlike the edits of [`Definition::rename`] these are merely guesses, so they are returned
+    /// separately for the client to offer them as a group the user can opt into.
+    pub fn rename_textual_occurrences(
+        &self,
+        sema: &Semantics<'_, RootDatabase>,
+        new_name: &str,
+    ) -> Result<SourceChange> {
+        IdentifierKind::classify(new_name)?;
+        let new_name = new_name.trim_start_matches("r#");
+        let def = convert_to_def_in_trait(sema.db, *self);
+        // Names in format strings are actual references, which `rename` takes care of.
+        let references = def.usages(sema).all();
+        let mut source_change = SourceChange::default();
+        for (file_id, ranges) in def.usages(sema).textual_occurrences() {
+            let references = references.references.get(&file_id).map_or(&[][..], |it| it);
+            let mut edit = TextEdit::builder();
+            for range in ranges {
+                if references.iter().any(|it| it.range.intersect(range).is_some()) {
+                    continue;
+                }
+                edit.replace(range, new_name.to_owned());
+            }
+            let edit = edit.finish();
+            if !edit.is_empty() {
+                source_change.insert_source_edit(file_id, edit);
+            }
         }
+        Ok(source_change)
     }
 
     /// Textual range of the identifier which will change when renaming this
@@ -167,6 +221,1052 @@ fn name_range<D>(def: D, sema: &Semantics<'_, RootDatabase>) -> Option<FileRange
     }
 }
 
+/// A definition the renamed definition would collide with, see
+/// [`Definition::rename_conflicts`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenameConflict {
+    pub kind: RenameConflictKind,
+    /// The definition that is in the way of the rename.
+    pub existing: Definition,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RenameConflictKind {
+    /// An item, field, variant or generic parameter with the new name already exists next to
+    /// the renamed definition.
+    Duplicate,
+    /// A local or generic parameter with the new name is in scope at a reference to the renamed
+    /// definition, the reference would resolve to it after the rename.
+    Shadowed,
+    /// A local with the new name is in scope at a call of the renamed function, the call would go
+    /// to the local after the rename.
+    ShadowedCall,
+    /// The renamed definition is an item of a trait impl whose trait is not part of the
+    /// workspace, the impl would no longer match the trait.
+    TraitImplMismatch,
+}
+
+impl RenameConflict {
+    /// Whether the conflict makes the renamed code fail to compile. The other conflicts may
+    /// silently change what the code refers to and should be treated as warnings.
+    pub fn is_error(&self) -> bool {
+        match self.kind {
+            RenameConflictKind::Duplicate
+            | RenameConflictKind::ShadowedCall
+            | RenameConflictKind::TraitImplMismatch => true,
+            RenameConflictKind::Shadowed => false,
+        }
+    }
+}
+
+impl Definition {
+    /// Checks whether renaming the definition to `new_name` makes it collide with other
+    /// definitions. This does not validate `new_name` itself, see [`IdentifierKind::classify`].
+    ///
+    /// [`Definition::rename`] does not check for conflicts, callers are expected to run this
+    /// first and report the conflicts before applying any edits.
+    // FIXME: References to the existing definition that end up in the scope of the renamed one
+    // are not checked, for example a local that is renamed to the name of a shadowed local.
+    pub fn rename_conflicts(
+        &self,
+        sema: &Semantics<'_, RootDatabase>,
+        new_name: &str,
+    ) -> Vec<RenameConflict> {
+        let _p = profile::span("Definition::rename_conflicts");
+        let db = sema.db;
+        let new_name = new_name.trim_start_matches("r#");
+        let has_new_name = |def: &Definition| {
+            def.name(db).map_or(false, |it| it.unescaped().to_smol_str() == new_name)
+        };
+        let mut res = Vec::new();
+        let mut push = |kind, existing| {
+            let conflict = RenameConflict { kind, existing };
+            if !res.contains(&conflict) {
+                res.push(conflict);
+            }
+        };
+
+        if let Some(trait_) = self.as_assoc_item(db).and_then(|it| it.containing_trait_impl(db)) {
+            if !trait_.module(db).krate().origin(db).is_local() {
+                push(RenameConflictKind::TraitImplMismatch, convert_to_def_in_trait(db, *self));
+            }
+        }
+
+        let def = convert_to_def_in_trait(db, *self);
+        let def_namespaces = namespaces(db, def);
+        for sibling in siblings(db, def) {
+            if sibling != def
+                && has_new_name(&sibling)
+                && namespaces(db, sibling).iter().any(|it| def_namespaces.contains(it))
+            {
+                push(RenameConflictKind::Duplicate, sibling);
+            }
+        }
+
+        for (_, references) in def.usages(sema).all() {
+            for reference in references {
+                // Only plain names are resolved through the scope, qualified paths, fields and
+                // method calls can't be shadowed.
+                let Some(name_ref) = reference.name.as_name_ref() else { continue };
+                let Some(path) = name_ref
+                    .syntax()
+                    .parent()
+                    .and_then(ast::PathSegment::cast)
+                    .map(|it| it.parent_path())
+                    .filter(|it| it.qualifier().is_none())
+                else {
+                    continue;
+                };
+                let namespace = path_namespace(&path);
+                let is_call =
+                    path.syntax().parent().and_then(ast::PathExpr::cast).map_or(false, |expr| {
+                        let call = expr.syntax().parent().and_then(ast::CallExpr::cast);
+                        call.and_then(|it| it.expr())
+                            .map_or(false, |it| it.syntax() == expr.syntax())
+                    });
+                let Some(scope) = sema.scope(name_ref.syntax()) else { continue };
+                scope.process_all_names(&mut |name, scope_def| {
+                    if name.unescaped().to_smol_str() != new_name {
+                        return;
+                    }
+                    let (existing, existing_namespace) = match scope_def {
+                        hir::ScopeDef::Local(it) => (Definition::Local(it), Namespace::Values),
+                        hir::ScopeDef::GenericParam(it @ hir::GenericParam::TypeParam(_)) => {
+                            (Definition::GenericParam(it), Namespace::Types)
+                        }
+                        hir::ScopeDef::GenericParam(it @ hir::GenericParam::ConstParam(_)) => {
+                            (Definition::GenericParam(it), Namespace::Values)
+                        }
+                        _ => return,
+                    };
+                    if existing == def || existing_namespace != namespace {
+                        return;
+                    }
+                    let kind = match existing {
+                        Definition::Local(_) if is_call => RenameConflictKind::ShadowedCall,
+                        _ => RenameConflictKind::Shadowed,
+                    };
+                    push(kind, existing);
+                });
+            }
+        }
+        res
+    }
+}
+
+/// An impact of a rename outside of the renamed code, see [`Definition::rename_warnings`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RenameWarning {
+    /// The definition is public in a crate of the workspace and reachable from outside of it,
+    /// crates outside of the workspace using it break.
+    PublicApi,
+    /// The definition is `#[no_mangle]`, so the symbol it is exported under changes with its
+    /// name, see [`Definition::export_name_edit`].
+    ExportedSymbol,
+    /// The name of the definition appears in doctests where it doesn't resolve to the
+    /// definition, these occurrences are not updated.
+    UsedInDoctests,
+}
+
+impl Definition {
+    /// Checks whether renaming the definition has an effect outside of the code that
+    /// [`Definition::rename`] edits. These don't keep the rename from being applied, but should
+    /// be shown to the user.
+    pub fn rename_warnings(&self, sema: &Semantics<'_, RootDatabase>) -> Vec<RenameWarning> {
+        let _p = profile::span("Definition::rename_warnings");
+        let db = sema.db;
+        let def = convert_to_def_in_trait(db, *self);
+        let mut res = Vec::new();
+
+        let is_local = def.krate(db).map_or(false, |krate| krate.origin(db).is_local());
+        let is_reachable = def.visibility(db) == Some(hir::Visibility::Public)
+            && def.module(db).map_or(false, |module| {
+                module
+                    .path_to_root(db)
+                    .into_iter()
+                    .all(|it| it.is_crate_root() || it.visibility(db) == hir::Visibility::Public)
+            });
+        if is_local && is_reachable {
+            res.push(RenameWarning::PublicApi);
+        }
+
+        if no_mangle_attr(sema, def).is_some() {
+            res.push(RenameWarning::ExportedSymbol);
+        }
+
+        if doctest_occurrences(sema, def).into_iter().any(|(_, _, resolves)| !resolves) {
+            res.push(RenameWarning::UsedInDoctests);
+        }
+        res
+    }
+
+    /// Replaces the `#[no_mangle]` of the definition with an `#[export_name]` attribute holding
+    /// its current name, so that it keeps its symbol when it is renamed.
+    pub fn export_name_edit(&self, sema: &Semantics<'_, RootDatabase>) -> Option<SourceChange> {
+        let (file_id, attr) = no_mangle_attr(sema, *self)?;
+        let name = self.name(sema.db)?;
+        let edit = TextEdit::replace(
+            attr.syntax().text_range(),
+            format!("#[export_name = \"{}\"]", name.unescaped().to_smol_str()),
+        );
+        Some(SourceChange::from_text_edit(file_id, edit))
+    }
+}
+
+/// Finds the occurrences of the name of `def` in doctests, along with whether they resolve to
+/// `def` from the scope of the documented item.
+// FIXME: Each occurrence is resolved as the path it ends, so locals, methods and fields of the
+// doctest are taken for items of the same name.
+fn doctest_occurrences(
+    sema: &Semantics<'_, RootDatabase>,
+    def: Definition,
+) -> Vec<(FileId, TextRange, bool)> {
+    let db = sema.db;
+    let def = convert_to_def_in_trait(db, def);
+    let mut res = Vec::new();
+    let Some(name) = def.name(db).map(|it| it.unescaped().to_smol_str()) else { return res };
+    let finder = Finder::new(name.as_str());
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+    for (file_id, search_range) in def.search_scope(db) {
+        let text = db.file_text(file_id);
+        // Only files mentioning the name in their doc comments are parsed.
+        if !is_mentioned_in_docs(&text, &finder) {
+            continue;
+        }
+        let search_range = search_range.unwrap_or_else(|| TextRange::up_to(TextSize::of(&*text)));
+        let source_file = sema.parse(file_id);
+        for line in rust_doc::doctest_code_ranges(source_file.syntax()) {
+            let ranges = finder.find_iter(text[line].as_bytes()).filter_map(|idx| {
+                let idx = usize::from(line.start()) + idx;
+                let is_word = !text[..idx].chars().next_back().map_or(false, is_word_char)
+                    && !text[idx + name.len()..].chars().next().map_or(false, is_word_char);
+                let range = TextRange::at(idx.try_into().unwrap(), TextSize::of(name.as_str()));
+                (is_word && search_range.contains_range(range)).then_some(range)
+            });
+            for range in ranges {
+                let before = &text[TextRange::new(line.start(), range.start())];
+                let is_path_char = |c: char| c.is_alphanumeric() || c == '_' || c == ':';
+                let qualifier = &before[before.trim_end_matches(is_path_char).len()..];
+                let path_start = range.start() - TextSize::of(qualifier);
+                let path = &text[TextRange::new(path_start, range.end())];
+                let resolves = source_file
+                    .syntax()
+                    .token_at_offset(range.start())
+                    .next()
+                    .and_then(|comment| {
+                        comment
+                            .parent_ancestors()
+                            .find_map(|it| rust_doc::doc_attributes(sema, &it))
+                    })
+                    .and_then(|(_, owner)| {
+                        // Doctests are compiled as a separate crate, so they refer to the items of
+                        // the crate by its name.
+                        let path = path.trim_start_matches("::");
+                        let crate_name = owner.krate(db)?.display_name(db);
+                        let path = match (path.split_once("::"), crate_name) {
+                            (Some((first, rest)), Some(name))
+                                if name.crate_name().as_smol_str() == first =>
+                            {
+                                format!("crate::{rest}")
+                            }
+                            _ => path.to_owned(),
+                        };
+                        rust_doc::resolve_doc_path_for_def(db, owner, &path, None)
+                    })
+                    .map_or(false, |it| convert_to_def_in_trait(db, it) == def);
+                res.push((file_id, range, resolves));
+            }
+        }
+    }
+    res
+}
+
+/// Whether the text found by `finder` is on a line of a `///` or `//!` doc comment. Block doc
+/// comments can't be told apart from the text of a line, so files with them are always searched.
+fn is_mentioned_in_docs(text: &str, finder: &Finder<'_>) -> bool {
+    text.contains("/**")
+        || text.contains("/*!")
+        || finder.find_iter(text.as_bytes()).any(|idx| {
+            let line_start = text[..idx].rfind('\n').map_or(0, |it| it + 1);
+            let line = text[line_start..idx].trim_start();
+            line.starts_with("///") || line.starts_with("//!")
+        })
+}
+
+fn no_mangle_attr(
+    sema: &Semantics<'_, RootDatabase>,
+    def: Definition,
+) -> Option<(FileId, ast::Attr)> {
+    let InFile { file_id, value: attrs } = match def {
+        Definition::Function(it) => sema.source(it)?.map(|it| it.attrs().collect::<Vec<_>>()),
+        Definition::Static(it) => sema.source(it)?.map(|it| it.attrs().collect::<Vec<_>>()),
+        _ => return None,
+    };
+    let attr = attrs.into_iter().find(|it| it.simple_name().as_deref() == Some("no_mangle"))?;
+    Some((file_id.file_id()?, attr))
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Namespace {
+    Types,
+    Values,
+    Macros,
+}
+
+fn namespaces(db: &RootDatabase, def: Definition) -> &'static [Namespace] {
+    match def {
+        Definition::Macro(_) => &[Namespace::Macros],
+        Definition::Function(_) | Definition::Const(_) | Definition::Static(_) => {
+            &[Namespace::Values]
+        }
+        Definition::Adt(hir::Adt::Struct(it)) if it.kind(db) != hir::StructKind::Record => {
+            &[Namespace::Types, Namespace::Values]
+        }
+        Definition::Module(_)
+        | Definition::Adt(_)
+        | Definition::Trait(_)
+        | Definition::TraitAlias(_)
+        | Definition::TypeAlias(_)
+        | Definition::BuiltinType(_) => &[Namespace::Types],
+        // Fields, variants and generic parameters can't share their names with each other at all.
+        _ => &[Namespace::Types, Namespace::Values],
+    }
+}
+
+/// The namespace an unqualified path is resolved in.
+fn path_namespace(path: &ast::Path) -> Namespace {
+    match path.syntax().parent().map(|it| it.kind()) {
+        Some(SyntaxKind::PATH_EXPR | SyntaxKind::PATH_PAT | SyntaxKind::TUPLE_STRUCT_PAT) => {
+            Namespace::Values
+        }
+        Some(SyntaxKind::MACRO_CALL) => Namespace::Macros,
+        _ => Namespace::Types,
+    }
+}
+
+/// The definitions that share a scope with `def` and can't have the same name as it.
+fn siblings(db: &RootDatabase, def: Definition) -> Vec<Definition> {
+    if let Some(assoc) = def.as_assoc_item(db) {
+        let items = match assoc.container(db) {
+            hir::AssocItemContainer::Trait(it) => it.items(db),
+            hir::AssocItemContainer::Impl(it) => it.items(db),
+        };
+        return items.into_iter().map(Definition::from).collect();
+    }
+    match def {
+        Definition::Field(it) => {
+            it.parent_def(db).fields(db).into_iter().map(Definition::Field).collect()
+        }
+        Definition::Variant(it) => {
+            it.parent_enum(db).variants(db).into_iter().map(Definition::Variant).collect()
+        }
+        Definition::GenericParam(it) => {
+            it.parent().params(db).into_iter().map(Definition::GenericParam).collect()
+        }
+        Definition::Module(_)
+        | Definition::Function(_)
+        | Definition::Adt(_)
+        | Definition::Const(_)
+        | Definition::Static(_)
+        | Definition::Trait(_)
+        | Definition::TraitAlias(_)
+        | Definition::TypeAlias(_)
+        | Definition::Macro(_) => match def.module(db) {
+            Some(module) => module
+                .scope(db, None)
+                .into_iter()
+                .filter_map(|(_, it)| match it {
+                    hir::ScopeDef::ModuleDef(it) => Some(Definition::from(it)),
+                    _ => None,
+                })
+                .collect(),
+            None => Vec::new(),
+        },
+        _ => Vec::new(),
+    }
+}
+
+/// Renames several definitions at once. Unlike calling [`Definition::rename`] for each of them,
+/// this checks the new names for conflicts with each other, rewrites field shorthands whose field
+/// and local are both renamed and merges all edits into a single [`SourceChange`].
+pub fn batch_rename(
+    sema: &Semantics<'_, RootDatabase>,
+    renames: &[(Definition, &str)],
+) -> Result<SourceChange> {
+    let _p = profile::span("batch_rename");
+    let db = sema.db;
+    let display_name =
+        |def: Definition| def.name(db).map_or_else(String::new, |it| it.display(db).to_string());
+
+    let mut renamed: Vec<(Definition, &str)> = Vec::with_capacity(renames.len());
+    for &(def, new_name) in renames {
+        // Trait items and their implementations are renamed together.
+        let def = convert_to_def_in_trait(db, def);
+        match renamed.iter().find(|(it, _)| *it == def) {
+            Some(&(_, prev)) if prev != new_name => {
+                bail!("Cannot rename `{}` to both `{}` and `{}`", display_name(def), prev, new_name)
+            }
+            Some(_) => (),
+            None => renamed.push((def, new_name)),
+        }
+    }
+    let new_name_of =
+        |def: Definition| renamed.iter().find(|(it, _)| *it == def).map(|&(_, new_name)| new_name);
+
+    for (idx, &(def, new_name)) in renamed.iter().enumerate() {
+        for conflict in def.rename_conflicts(sema, new_name) {
+            // Definitions that are renamed themselves are out of the way, unless they are
+            // renamed to the same name, which is checked below.
+            if conflict.is_error() && new_name_of(conflict.existing).is_none() {
+                bail!(
+                    "Cannot rename `{}` to `{}`: it would conflict with `{}`",
+                    display_name(def),
+                    new_name,
+                    display_name(conflict.existing)
+                );
+            }
+        }
+        let def_namespaces = namespaces(db, def);
+        let def_siblings = siblings(db, def);
+        for &(other, other_new_name) in &renamed[idx + 1..] {
+            if other_new_name.trim_start_matches("r#") == new_name.trim_start_matches("r#")
+                && def_siblings.contains(&other)
+                && namespaces(db, other).iter().any(|it| def_namespaces.contains(it))
+            {
+                bail!(
+                    "Cannot rename both `{}` and `{}` to `{}`",
+                    display_name(def),
+                    display_name(other),
+                    new_name
+                );
+            }
+        }
+    }
+
+    let shorthands = field_shorthand_edits(sema, &renamed, new_name_of)?;
+    let mut source_change = SourceChange::default();
+    let mut file_system_edits = Vec::new();
+    let mut merge = |file_id: FileId, edit: TextEdit| -> Result<()> {
+        match source_change.source_file_edits.entry(file_id) {
+            Entry::Occupied(mut entry) => {
+                if entry.get_mut().union(edit).is_err() {
+                    bail!("Cannot rename the definitions together: their edits overlap");
+                }
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(edit);
+            }
+        }
+        Ok(())
+    };
+    for &(def, new_name) in &renamed {
+        let change = def.rename(sema, new_name)?;
+        for (file_id, edit) in change.source_file_edits {
+            // The shorthands are rewritten as a whole below.
+            let edit = match shorthands.get(&file_id) {
+                Some(shorthands) => {
+                    let mut builder = TextEdit::builder();
+                    edit.into_iter()
+                        .filter(|indel| {
+                            !shorthands.iter().any(|(range, _)| range.contains_range(indel.delete))
+                        })
+                        .for_each(|indel| builder.replace(indel.delete, indel.insert));
+                    builder.finish()
+                }
+                None => edit,
+            };
+            merge(file_id, edit)?;
+        }
+        file_system_edits.extend(change.file_system_edits);
+    }
+    for (file_id, shorthands) in shorthands {
+        for (range, text) in shorthands {
+            merge(file_id, TextEdit::replace(range, text))?;
+        }
+    }
+    // Moving a module directory first would invalidate the paths of the files moved inside of it.
+    file_system_edits.sort_by_key(|it| matches!(it, FileSystemEdit::MoveDir { .. }));
+    source_change.file_system_edits = file_system_edits;
+    Ok(source_change)
+}
+
+/// The record fields and patterns binding a renamed field to a renamed local, and what to replace
+/// them with. Renaming either on its own would turn the other into an explicit `field: local`, or
+/// make use of a shorthand, which is wrong once both are renamed.
+fn field_shorthand_edits<'a>(
+    sema: &Semantics<'_, RootDatabase>,
+    renamed: &[(Definition, &str)],
+    new_name_of: impl Fn(Definition) -> Option<&'a str>,
+) -> Result<IntMap<FileId, Vec<(TextRange, String)>>> {
+    let mut res: IntMap<FileId, Vec<(TextRange, String)>> = IntMap::default();
+    for &(def, field_new_name) in renamed {
+        if !matches!(def, Definition::Field(_)) {
+            continue;
+        }
+        for (file_id, references) in def.usages(sema).all() {
+            let edition = file_edition(sema, file_id);
+            for reference in references {
+                // Shorthands in macro calls are left to the individual renames.
+                if reference.name.syntax().text_range() != reference.range {
+                    continue;
+                }
+                let (range, ident_pat, local) = match &reference.name {
+                    ast::NameLike::NameRef(name_ref) => {
+                        if let Some(record_field) = ast::RecordExprField::for_name_ref(name_ref) {
+                            let Some(init) = record_field.expr() else { continue };
+                            let Some(init_ref) = expr_as_name_ref(&init) else { continue };
+                            let local = match NameRefClass::classify(sema, &init_ref) {
+                                Some(NameRefClass::Definition(Definition::Local(it))) => it,
+                                Some(NameRefClass::FieldShorthand { local_ref, .. }) => local_ref,
+                                _ => continue,
+                            };
+                            let start = record_field.name_ref().unwrap_or(init_ref);
+                            let range = TextRange::new(
+                                start.syntax().text_range().start(),
+                                init.syntax().text_range().end(),
+                            );
+                            (range, None, local)
+                        } else if let Some(record_field) =
+                            ast::RecordPatField::for_field_name_ref(name_ref)
+                        {
+                            let Some(ast::Pat::IdentPat(ident_pat)) = record_field.pat() else {
+                                continue;
+                            };
+                            let Some(local) = sema.to_def(&ident_pat) else { continue };
+                            (name_ref.syntax().text_range(), Some(ident_pat), local)
+                        } else {
+                            continue;
+                        }
+                    }
+                    ast::NameLike::Name(name) => {
+                        if ast::RecordPatField::for_field_name(name).is_none() {
+                            continue;
+                        }
+                        let Some(ident_pat) = name.syntax().parent().and_then(ast::IdentPat::cast)
+                        else {
+                            continue;
+                        };
+                        let Some(local) = sema.to_def(&ident_pat) else { continue };
+                        (ident_pat.syntax().text_range(), Some(ident_pat), local)
+                    }
+                    ast::NameLike::Lifetime(_) => continue,
+                };
+                let Some(local_new_name) = new_name_of(Definition::Local(local)) else { continue };
+                let field_new_name = new_name_for_edition(field_new_name, edition)?;
+                let local_new_name = new_name_for_edition(local_new_name, edition)?;
+                let (range, text) = match ident_pat {
+                    // `ref mut` and the like
+                    Some(ident_pat) => {
+                        if ident_pat.at_token().is_some() {
+                            continue;
+                        }
+                        let Some(name) = ident_pat.name() else { continue };
+                        let pat_range = ident_pat.syntax().text_range();
+                        let modifiers = &ident_pat.syntax().text().to_string()
+                            [..usize::from(name.syntax().text_range().start() - pat_range.start())];
+                        let range = range.cover(name.syntax().text_range());
+                        (range, format!("{modifiers}{local_new_name}"))
+                    }
+                    None => (range, local_new_name.clone()),
+                };
+                let text = if field_new_name == local_new_name {
+                    text
+                } else {
+                    format!("{field_new_name}: {text}")
+                };
+                res.entry(file_id).or_default().push((range, text));
+            }
+        }
+    }
+    Ok(res)
+}
+
+/// The cases of the naming conventions of Rust, see [`rename_case`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NameCase {
+    /// `lower_snake_case`, for modules, functions, fields and locals.
+    LowerSnake,
+    /// `UPPER_SNAKE_CASE`, for constants and statics.
+    UpperSnake,
+    /// `UpperCamelCase`, for types, traits and variants.
+    UpperCamel,
+}
+
+impl NameCase {
+    /// The case the name of `def` is conventionally written in.
+    pub fn of(def: Definition) -> Option<NameCase> {
+        let case = match def {
+            Definition::Module(_)
+            | Definition::Function(_)
+            | Definition::Field(_)
+            | Definition::Local(_)
+            | Definition::Macro(_) => NameCase::LowerSnake,
+            Definition::Const(_)
+            | Definition::Static(_)
+            | Definition::GenericParam(hir::GenericParam::ConstParam(_)) => NameCase::UpperSnake,
+            Definition::Adt(_)
+            | Definition::Variant(_)
+            | Definition::Trait(_)
+            | Definition::TraitAlias(_)
+            | Definition::TypeAlias(_)
+            | Definition::GenericParam(hir::GenericParam::TypeParam(_)) => NameCase::UpperCamel,
+            _ => return None,
+        };
+        Some(case)
+    }
+
+    pub fn convert(self, name: &str) -> String {
+        match self {
+            NameCase::LowerSnake => stdx::to_lower_snake_case(name),
+            NameCase::UpperSnake => stdx::to_upper_snake_case(name),
+            NameCase::UpperCamel => stdx::to_upper_camel_case(name),
+        }
+    }
+}
+
+/// Converts the name of `def` to `case`. Definitions next to it with the same name in another
+/// case, like the module `foo_bar` of a struct `FooBar` or the `fn foo_bar()` accessor of a
+/// `const FOO_BAR`, are converted to the case of their naming convention along with it.
+pub fn rename_case(
+    sema: &Semantics<'_, RootDatabase>,
+    def: Definition,
+    case: NameCase,
+) -> Result<SourceChange> {
+    let db = sema.db;
+    let def = convert_to_def_in_trait(db, def);
+    let Some(name) = def.name(db) else { bail!("No name to convert") };
+    let name = name.unescaped().to_smol_str();
+    let words = stdx::to_lower_snake_case(&name);
+
+    let mut renames = vec![(def, case.convert(&name))];
+    for sibling in siblings(db, def) {
+        let (Some(sibling_name), Some(sibling_case)) = (sibling.name(db), NameCase::of(sibling))
+        else {
+            continue;
+        };
+        let sibling_name = sibling_name.unescaped().to_smol_str();
+        // Module scopes also contain the items imported into them.
+        let is_local = sibling.module(db) == def.module(db);
+        if sibling != def && is_local && stdx::to_lower_snake_case(&sibling_name) == words {
+            renames.push((sibling, sibling_case.convert(&sibling_name)));
+        }
+    }
+    renames.retain(|(def, new_name)| {
+        def.name(db).map_or(false, |it| it.unescaped().to_smol_str() != new_name.as_str())
+    });
+    let renames: Vec<_> = renames.iter().map(|(def, new_name)| (*def, new_name.as_str())).collect();
+    batch_rename(sema, &renames)
+}
+
+/// The default naming patterns of the accessors of a field, see [`field_accessors`]. `{}` stands
+/// for the name of the field.
+pub const DEFAULT_ACCESSOR_PATTERNS: &[&str] = &["{}", "{}_mut", "set_{}"];
+
+/// Finds the methods in the inherent impls of the struct or union owning `field` that are named
+/// after it according to one of `patterns`, like `fn foo_mut(&mut self)` for a field `foo`.
+/// Returns each method along with the pattern its name follows.
+pub fn field_accessors<'p>(
+    sema: &Semantics<'_, RootDatabase>,
+    field: hir::Field,
+    patterns: &[&'p str],
+) -> Vec<(hir::Function, &'p str)> {
+    let db = sema.db;
+    let adt = match field.parent_def(db) {
+        hir::VariantDef::Struct(it) => hir::Adt::from(it),
+        hir::VariantDef::Union(it) => hir::Adt::from(it),
+        hir::VariantDef::Variant(_) => return Vec::new(),
+    };
+    let field_name = field.name(db).unescaped().to_smol_str();
+    let mut res = Vec::new();
+    for imp in hir::Impl::all_for_type(db, adt.ty(db)) {
+        if imp.trait_(db).is_some() {
+            continue;
+        }
+        for item in imp.items(db) {
+            let hir::AssocItem::Function(func) = item else { continue };
+            if !func.has_self_param(db) {
+                continue;
+            }
+            let name = func.name(db).unescaped().to_smol_str();
+            let pattern = patterns.iter().find(|it| it.replace("{}", &field_name) == name.as_str());
+            if let Some(&pattern) = pattern {
+                res.push((func, pattern));
+            }
+        }
+    }
+    res
+}
+
+/// Renames `field` to `new_name` along with its accessors, see [`field_accessors`], keeping the
+/// accessors' names in line with the pattern they follow.
+pub fn rename_field_with_accessors(
+    sema: &Semantics<'_, RootDatabase>,
+    field: hir::Field,
+    new_name: &str,
+    patterns: &[&str],
+) -> Result<SourceChange> {
+    let unescaped = new_name.trim_start_matches("r#");
+    let accessors: Vec<_> = field_accessors(sema, field, patterns)
+        .into_iter()
+        .map(|(func, pattern)| {
+            let name = pattern.replace("{}", unescaped);
+            let name = if is_raw_identifier(&name) { format!("r#{name}") } else { name };
+            (Definition::Function(func), name)
+        })
+        .collect();
+    let mut renames = vec![(Definition::Field(field), new_name)];
+    renames.extend(accessors.iter().map(|(def, name)| (*def, name.as_str())));
+    batch_rename(sema, &renames)
+}
+
+/// Renames the crate `krate` in all crates depending on it: `extern crate` items, imports and
+/// paths, including paths to the crate in the macro definitions of its dependants. Dependants that
+/// know the crate under another name, through a renamed dependency or an alias, are left alone.
+///
+/// The `Cargo.toml` of the crate's package and the ones of its dependants are updated as well.
+/// Manifests aren't part of the crate graph, so their texts are read with `read_manifest` unless
+/// they are loaded in the VFS.
+pub fn rename_crate(
+    sema: &Semantics<'_, RootDatabase>,
+    krate: hir::Crate,
+    new_name: &str,
+    read_manifest: &dyn Fn(&AnchoredPathBuf) -> Option<String>,
+) -> Result<SourceChange> {
+    let _p = profile::span("rename_crate");
+    if !is_valid_package_name(new_name) {
+        bail!("Invalid name `{0}`: `{0}` is not a valid Cargo package name", new_name);
+    }
+    let db = sema.db;
+    if !krate.origin(db).is_local() {
+        bail!("Cannot rename a crate that is not part of the workspace");
+    }
+    let Some(display_name) = krate.display_name(db) else {
+        bail!("Cannot rename a crate without a name");
+    };
+    let old_name = display_name.crate_name().as_smol_str().clone();
+    let new_crate_name = new_name.trim_start_matches("r#");
+
+    let dependants: Vec<hir::Crate> = krate
+        .reverse_dependencies(db)
+        .into_iter()
+        .filter(|dependant| {
+            dependant
+                .dependencies(db)
+                .iter()
+                .any(|dep| dep.krate == krate && dep.name.unescaped().to_smol_str() == old_name)
+        })
+        .collect();
+    let is_in_dependant = |file_id: FileId| {
+        sema.to_module_def(file_id).map_or(false, |module| dependants.contains(&module.krate()))
+    };
+
+    let mut source_change = SourceChange::default();
+    let root = Definition::Module(krate.root_module(db));
+    for (file_id, references) in crate_usages(sema, krate).references {
+        if !is_in_dependant(file_id) {
+            continue;
+        }
+        let references: Vec<_> = references
+            .into_iter()
+            .filter(|it| it.name.text().trim_start_matches("r#") == old_name)
+            .collect();
+        check_macro_references(sema, file_id, &references)?;
+        let new_name = new_name_for_edition(new_name, file_edition(sema, file_id))?;
+        source_change
+            .insert_source_edit(file_id, source_edit_from_references(&references, root, &new_name));
+    }
+    let mut source_roots: Vec<_> =
+        dependants.iter().map(|it| db.file_source_root(it.root_file(db))).collect();
+    source_roots.sort_unstable();
+    source_roots.dedup();
+    for source_root in source_roots {
+        for file_id in db.source_root(source_root).iter() {
+            if !db.file_text(file_id).contains(old_name.as_str()) {
+                continue;
+            }
+            let new_name = new_name_for_edition(new_name, file_edition(sema, file_id))?;
+            let edit = macro_definition_edits(sema, file_id, &old_name, &new_name);
+            if !edit.is_empty() {
+                source_change.insert_source_edit(file_id, edit);
+            }
+        }
+    }
+
+    // Package names may use dashes where the crate name has underscores.
+    let old_package = display_name.canonical_name();
+    let new_package = match old_package == old_name {
+        true => new_crate_name.to_owned(),
+        false => new_crate_name.replace('_', "-"),
+    };
+    // The crates of a package share its manifest, which is found through the first of them.
+    let mut manifests: Vec<(String, AnchoredPathBuf, Vec<hir::Crate>)> = Vec::new();
+    for it in iter::once(krate).chain(dependants.iter().copied()) {
+        let Some((dir, path)) = manifest_of(db, it) else { continue };
+        match manifests.iter_mut().find(|(manifest_dir, ..)| *manifest_dir == dir) {
+            Some((.., crates)) => crates.push(it),
+            None => manifests.push((dir, path, vec![it])),
+        }
+    }
+    for (_, path, crates) in manifests {
+        let Some(text) = manifest_text(db, &path, read_manifest) else { continue };
+        let mut edit = TextEdit::default();
+        if crates.contains(&krate) {
+            edit =
+                manifest::package_manifest_edit(&text, old_package, &new_package, new_crate_name);
+        }
+        if crates.iter().any(|&it| it != krate) {
+            let dependency_edit =
+                manifest::dependency_manifest_edit(&text, old_package, &new_package);
+            never!(edit.union(dependency_edit).is_err(), "overlapping manifest edits");
+        }
+        if !edit.is_empty() {
+            source_change.insert_other_file_edit(path, edit);
+        }
+    }
+    Ok(source_change)
+}
+
+/// Whether Cargo accepts `name` as the name of a package whose crate is referred to by it: an
+/// ASCII identifier without a leading digit that is neither a keyword, including the reserved
+/// ones, nor `test`.
+fn is_valid_package_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    let is_ident = chars.next().map_or(false, |it| it.is_ascii_alphabetic() || it == '_')
+        && chars.all(|it| it.is_ascii_alphanumeric() || it == '_');
+    is_ident
+        && name != "_"
+        && name != "test"
+        && SyntaxKind::from_keyword(name).is_none()
+        && !matches!(
+            name,
+            "abstract"
+                | "async"
+                | "await"
+                | "become"
+                | "box"
+                | "do"
+                | "dyn"
+                | "final"
+                | "macro"
+                | "override"
+                | "priv"
+                | "try"
+                | "typeof"
+                | "unsized"
+                | "virtual"
+                | "yield"
+        )
+}
+
+/// Paths starting with the crate's name in the `macro_rules!` and `macro` definitions of `file_id`.
+/// These aren't resolved until the macro is expanded, and not necessarily in the crate defining it.
+fn macro_definition_edits(
+    sema: &Semantics<'_, RootDatabase>,
+    file_id: FileId,
+    old_name: &str,
+    new_name: &str,
+) -> TextEdit {
+    let mut edit = TextEdit::builder();
+    let bodies = sema.parse(file_id).syntax().descendants().filter_map(|it| {
+        match_ast! {
+            match it {
+                ast::MacroRules(it) => it.token_tree().map(|it| it.syntax().clone()),
+                ast::MacroDef(it) => Some(it.syntax().clone()),
+                _ => None,
+            }
+        }
+    });
+    for body in bodies {
+        // Token trees hold `::` as two `:` tokens, which are glued here to match paths.
+        let mut tokens: Vec<(SyntaxKind, SyntaxToken)> = Vec::new();
+        for token in body.descendants_with_tokens().filter_map(|it| it.into_token()) {
+            if token.kind().is_trivia() {
+                continue;
+            }
+            match tokens.last_mut() {
+                Some((kind @ T![:], prev))
+                    if token.kind() == T![:]
+                        && prev.text_range().end() == token.text_range().start() =>
+                {
+                    *kind = T![::]
+                }
+                _ => tokens.push((token.kind(), token)),
+            }
+        }
+        let kind_at = |idx: Option<usize>| idx.and_then(|it| tokens.get(it)).map(|(kind, _)| *kind);
+        for (idx, (kind, token)) in tokens.iter().enumerate() {
+            if *kind != SyntaxKind::IDENT
+                || token.text().trim_start_matches("r#") != old_name
+                || kind_at(Some(idx + 1)) != Some(T![::])
+            {
+                continue;
+            }
+            // Only the first segment of a path can name a crate, `$name` is a metavariable.
+            let is_path_start = match kind_at(idx.checked_sub(1)) {
+                Some(T![::]) => !matches!(
+                    kind_at(idx.checked_sub(2)),
+                    Some(SyntaxKind::IDENT | T![>] | T![self] | T![super] | T![crate])
+                ),
+                Some(prev) => prev != T![$],
+                None => true,
+            };
+            if is_path_start {
+                edit.replace(token.text_range(), new_name.to_owned());
+            }
+        }
+    }
+    edit.finish()
+}
+
+/// The directory of the `Cargo.toml` of `krate` and its path relative to the root file of the
+/// crate, if the crate is part of a Cargo package.
+fn manifest_of(db: &RootDatabase, krate: hir::Crate) -> Option<(String, AnchoredPathBuf)> {
+    let manifest_dir = db.crate_graph()[krate.into()].env.get("CARGO_MANIFEST_DIR")?;
+    let root_file = krate.root_file(db);
+    let source_root = db.source_root(db.file_source_root(root_file));
+    let mut dir = source_root.path_for_file(&root_file)?.parent()?;
+    let mut path = String::new();
+    while dir.to_string().trim_end_matches('/') != manifest_dir.trim_end_matches('/') {
+        dir = dir.parent()?;
+        path.push_str("../");
+    }
+    path.push_str("Cargo.toml");
+    Some((manifest_dir, AnchoredPathBuf { anchor: root_file, path }))
+}
+
+/// The text of the manifest at `path`, from the VFS if it's loaded there.
+fn manifest_text(
+    db: &RootDatabase,
+    path: &AnchoredPathBuf,
+    read_manifest: &dyn Fn(&AnchoredPathBuf) -> Option<String>,
+) -> Option<String> {
+    let anchored = AnchoredPath { anchor: path.anchor, path: &path.path };
+    match base_db::FileLoader::resolve_path(db, anchored) {
+        Some(file_id) => Some(db.file_text(file_id).to_string()),
+        None => read_manifest(path),
+    }
+}
+
+/// The kind of an edit made by a rename, see [`RenamePreview`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RenameEditKind {
+    /// The name of a renamed definition.
+    Definition,
+    /// A reference to a renamed definition.
+    Reference,
+    /// A field shorthand that is expanded or introduced, like `Foo { field }` becoming
+    /// `Foo { field: local }`.
+    ShorthandExpansion,
+    /// A file or directory that is moved, the texts of the edit are paths.
+    FileMove,
+    /// An attribute that is updated or added, like `#[path]` or `#[serde(rename)]`.
+    AttributeUpdate,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenamePreviewEdit {
+    pub kind: RenameEditKind,
+    /// The range of the replaced text, empty for file moves.
+    pub range: TextRange,
+    pub old_text: String,
+    pub new_text: String,
+}
+
+/// The edits of a rename, grouped by file and by kind, for clients that let the user review a
+/// rename before applying it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RenamePreview {
+    /// The edits of each file, sorted by kind and then by range. Moves are listed under the
+    /// file or directory anchor that is moved.
+    pub files: Vec<(FileId, Vec<RenamePreviewEdit>)>,
+}
+
+impl RenamePreview {
+    /// Classifies the edits of `source_change`, which is the result of a rename.
+    pub fn new(sema: &Semantics<'_, RootDatabase>, source_change: &SourceChange) -> RenamePreview {
+        let mut files: IntMap<FileId, Vec<RenamePreviewEdit>> = IntMap::default();
+        for (&file_id, edit) in &source_change.source_file_edits {
+            let text = sema.db.file_text(file_id);
+            let source_file = sema.parse(file_id);
+            let edits = files.entry(file_id).or_default();
+            for indel in edit.iter() {
+                edits.push(RenamePreviewEdit {
+                    kind: rename_edit_kind(source_file.syntax(), indel.delete, &indel.insert),
+                    range: indel.delete,
+                    old_text: text[indel.delete].to_owned(),
+                    new_text: indel.insert.clone(),
+                });
+            }
+        }
+        for edit in &source_change.file_system_edits {
+            let (file_id, old_text, new_text) = match edit {
+                FileSystemEdit::MoveFile { src, dst } => {
+                    let source_root = sema.db.source_root(sema.db.file_source_root(*src));
+                    let old_name = source_root
+                        .path_for_file(src)
+                        .and_then(|path| path.name_and_extension())
+                        .map(|(name, ext)| match ext {
+                            Some(ext) => format!("{name}.{ext}"),
+                            None => name.to_owned(),
+                        })
+                        .unwrap_or_default();
+                    (*src, old_name, dst.path.clone())
+                }
+                FileSystemEdit::MoveDir { src, dst, .. } => {
+                    (src.anchor, src.path.clone(), dst.path.clone())
+                }
+                FileSystemEdit::CreateFile { .. } | FileSystemEdit::DeleteFile { .. } => continue,
+            };
+            files.entry(file_id).or_default().push(RenamePreviewEdit {
+                kind: RenameEditKind::FileMove,
+                range: TextRange::empty(0.into()),
+                old_text,
+                new_text,
+            });
+        }
+        let mut files: Vec<_> = files.into_iter().collect();
+        files.sort_by_key(|&(file_id, _)| file_id);
+        for (_, edits) in &mut files {
+            edits.sort_by_key(|it| (it.kind, it.range.start()));
+        }
+        RenamePreview { files }
+    }
+
+    /// The edits of the given kind, by file.
+    pub fn edits_of_kind(
+        &self,
+        kind: RenameEditKind,
+    ) -> impl Iterator<Item = (FileId, &RenamePreviewEdit)> + '_ {
+        self.files.iter().flat_map(move |(file_id, edits)| {
+            edits.iter().filter(move |it| it.kind == kind).map(move |it| (*file_id, it))
+        })
+    }
+}
+
+fn rename_edit_kind(syntax: &SyntaxNode, range: TextRange, insert: &str) -> RenameEditKind {
+    let element = syntax.covering_element(range);
+    if insert.starts_with("#[") || element.ancestors().any(|it| ast::Attr::can_cast(it.kind())) {
+        return RenameEditKind::AttributeUpdate;
+    }
+    // Shorthands are expanded by inserting `field: ` or `: local`, and introduced by deleting
+    // either of them.
+    if insert.is_empty() || insert.contains(':') {
+        return RenameEditKind::ShorthandExpansion;
+    }
+    let is_definition = element.parent().map_or(false, |parent| {
+        ast::Name::can_cast(parent.kind())
+            || ast::Lifetime::can_cast(parent.kind())
+                && parent.parent().map_or(false, |it| {
+                    matches!(it.kind(), SyntaxKind::LIFETIME_PARAM | SyntaxKind::LABEL)
+                })
+    });
+    if is_definition {
+        RenameEditKind::Definition
+    } else {
+        RenameEditKind::Reference
+    }
+}
+
 fn rename_mod(
     sema: &Semantics<'_, RootDatabase>,
     module: hir::Module,
@@ -182,45 +1282,114 @@ fn rename_mod(
         return Ok(source_change);
     }
 
-    let InFile { file_id, value: def_source } = module.definition_source(sema.db);
-    if let ModuleSource::SourceFile(..) = def_source {
-        let new_name = new_name.trim_start_matches("r#");
-        let anchor = file_id.original_file(sema.db);
-
-        let is_mod_rs = module.is_mod_rs(sema.db);
-        let has_detached_child = module.children(sema.db).any(|child| !child.is_inline(sema.db));
-
-        // Module exists in a named file
-        if !is_mod_rs {
-            let path = format!("{new_name}.rs");
-            let dst = AnchoredPathBuf { anchor, path };
-            source_change.push_file_system_edit(FileSystemEdit::MoveFile { src: anchor, dst })
-        }
-
-        // Rename the dir if:
-        //  - Module source is in mod.rs
-        //  - Module has submodules defined in separate files
-        let dir_paths = match (is_mod_rs, has_detached_child, module.name(sema.db)) {
-            // Go up one level since the anchor is inside the dir we're trying to rename
-            (true, _, Some(mod_name)) => Some((
-                format!("../{}", mod_name.unescaped().display(sema.db)),
-                format!("../{new_name}"),
-            )),
-            // The anchor is on the same level as target dir
-            (false, true, Some(mod_name)) => {
-                Some((mod_name.unescaped().display(sema.db).to_string(), new_name.to_owned()))
+    let db = sema.db;
+    let InFile { file_id, value: def_source } = module.definition_source(db);
+    let new_file_name = new_name.trim_start_matches("r#");
+    let old_name = match module.name(db) {
+        Some(it) => it.unescaped().display(db).to_string(),
+        None => return Ok(source_change),
+    };
+    let source_root = db.source_root(db.file_source_root(file_id.original_file(db)));
+
+    // The directory holding the submodules of the module, as its path and the paths to move it
+    // from and to relative to a file in or next to it.
+    let mut module_dir = None;
+    // Whether the module directory has to be moved even if no file in the tree refers into it.
+    let mut move_module_dir = false;
+    match def_source {
+        ModuleSource::SourceFile(..) => {
+            let anchor = file_id.original_file(db);
+            let is_mod_rs = module.is_mod_rs(db);
+            let has_detached_child = module.children(db).any(|child| !child.is_inline(db));
+            let file_path = source_root.path_for_file(&anchor).and_then(|it| it.parent());
+
+            let path_attr = module
+                .declaration_source(db)
+                .and_then(|src| Some((src.file_id.file_id(), path_attr(&src.value)?)));
+            match path_attr {
+                // The file is located by the attribute, it is only renamed along with the module
+                // if the attribute names it after the module.
+                Some((decl_file_id, path_attr)) => {
+                    // Attributes in macro expansions can't be edited, so their files are left
+                    // alone as well.
+                    let edits = decl_file_id.zip(rename_path_attr(
+                        &path_attr,
+                        anchor,
+                        &old_name,
+                        new_file_name,
+                    ));
+                    if let Some((decl_file_id, (text_edit, fs_edit))) = edits {
+                        source_change.insert_source_edit(decl_file_id, text_edit);
+                        source_change.push_file_system_edit(fs_edit);
+                    }
+                }
+                None => {
+                    // Module exists in a named file
+                    if !is_mod_rs {
+                        let path = format!("{new_file_name}.rs");
+                        let dst = AnchoredPathBuf { anchor, path };
+                        source_change
+                            .push_file_system_edit(FileSystemEdit::MoveFile { src: anchor, dst })
+                    }
+
+                    // Rename the dir if:
+                    //  - Module source is in mod.rs
+                    //  - Module has submodules defined in separate files
+                    //  - Files in the dir are included by `include!` and the like
+                    module_dir = if is_mod_rs {
+                        // Go up one level since the anchor is inside the dir we're trying to
+                        // rename
+                        file_path.map(|dir| {
+                            (dir, anchor, format!("../{old_name}"), format!("../{new_file_name}"))
+                        })
+                    } else {
+                        // The anchor is on the same level as target dir
+                        file_path
+                            .and_then(|it| it.join(&old_name))
+                            .map(|dir| (dir, anchor, old_name.clone(), new_file_name.to_owned()))
+                    };
+                    move_module_dir = is_mod_rs || has_detached_child;
+                }
             }
-            _ => None,
-        };
+        }
+        ModuleSource::Module(_) => {
+            // The submodules of an inline module that are in separate files live in a directory
+            // named after it, which is anchored at one of them.
+            module_dir = module
+                .children(db)
+                .filter(|child| !child.is_inline(db))
+                .filter(|child| {
+                    child.declaration_source(db).map_or(true, |src| path_attr(&src.value).is_none())
+                })
+                .find_map(|child| {
+                    let anchor = child.definition_source(db).file_id.original_file(db);
+                    let mut dir = source_root.path_for_file(&anchor)?.parent()?;
+                    let up = if child.is_mod_rs(db) {
+                        dir = dir.parent()?;
+                        "../.."
+                    } else {
+                        ".."
+                    };
+                    Some((dir, anchor, format!("{up}/{old_name}"), format!("{up}/{new_file_name}")))
+                });
+            move_module_dir = module_dir.is_some();
+        }
+        ModuleSource::BlockExpr(_) => (),
+    }
 
-        if let Some((src, dst)) = dir_paths {
+    if let Some((dir, anchor, src, dst)) = module_dir {
+        let include_edits = include_edits(sema, &source_root, &dir, new_file_name);
+        if move_module_dir || !include_edits.is_empty() {
             let src = AnchoredPathBuf { anchor, path: src };
             let dst = AnchoredPathBuf { anchor, path: dst };
             source_change.push_file_system_edit(FileSystemEdit::MoveDir {
                 src,
                 src_id: anchor,
                 dst,
-            })
+            });
+            for (file_id, edit) in include_edits {
+                source_change.insert_source_edit(file_id, edit);
+            }
         }
     }
 
@@ -231,10 +1400,9 @@ fn rename_mod(
                 if let Some(file_range) =
                     src.with_value(name.syntax()).original_file_range_opt(sema.db)
                 {
-                    source_change.insert_source_edit(
-                        file_id,
-                        TextEdit::replace(file_range.range, new_name.to_owned()),
-                    )
+                    let new_name = new_name_for_edition(new_name, file_edition(sema, file_id))?;
+                    source_change
+                        .insert_source_edit(file_id, TextEdit::replace(file_range.range, new_name))
                 };
             }
             _ => never!("Module source node is missing a name"),
@@ -243,14 +1411,134 @@ fn rename_mod(
 
     let def = Definition::Module(module);
     let usages = def.usages(sema).all();
-    let ref_edits = usages.iter().map(|(&file_id, references)| {
-        (file_id, source_edit_from_references(references, def, new_name))
-    });
+    let ref_edits = usages
+        .iter()
+        .map(|(&file_id, references)| {
+            check_macro_references(sema, file_id, references)?;
+            let new_name = new_name_for_edition(new_name, file_edition(sema, file_id))?;
+            Ok((file_id, source_edit_from_references(references, def, &new_name)))
+        })
+        .collect::<Result<Vec<_>>>()?;
     source_change.extend(ref_edits);
 
     Ok(source_change)
 }
 
+/// The `#[path = "..."]` attribute of a module declaration.
+fn path_attr(module: &ast::Module) -> Option<ast::String> {
+    module.attrs().filter(|attr| attr.simple_name().as_deref() == Some("path")).find_map(|attr| {
+        match attr.meta()?.expr()? {
+            ast::Expr::Literal(literal) => ast::String::cast(literal.token()),
+            _ => None,
+        }
+    })
+}
+
+/// Renames the file a `#[path]` attribute points to if it is named after the module, that is
+/// `old_name.rs` or `old_name/mod.rs`. Returns the edit of the attribute and the move of the
+/// module file, anchored at `anchor`.
+fn rename_path_attr(
+    path_attr: &ast::String,
+    anchor: FileId,
+    old_name: &str,
+    new_name: &str,
+) -> Option<(TextEdit, FileSystemEdit)> {
+    // Escaped paths are rare enough to not bother with them.
+    if path_attr.text().contains('\\') {
+        return None;
+    }
+    let range = path_attr.text_range_between_quotes()?;
+    let value = path_attr.value()?;
+    let new_file_name = format!("{new_name}.rs");
+    let mut components = value.split('/').collect::<Vec<_>>();
+    let fs_edit = match components.as_mut_slice() {
+        [.., file_name] if *file_name == format!("{old_name}.rs") => {
+            *file_name = new_file_name.as_str();
+            let dst = AnchoredPathBuf { anchor, path: new_file_name.clone() };
+            FileSystemEdit::MoveFile { src: anchor, dst }
+        }
+        [.., dir_name, "mod.rs"] if *dir_name == old_name => {
+            *dir_name = new_name;
+            FileSystemEdit::MoveDir {
+                src: AnchoredPathBuf { anchor, path: format!("../{old_name}") },
+                src_id: anchor,
+                dst: AnchoredPathBuf { anchor, path: format!("../{new_name}") },
+            }
+        }
+        _ => return None,
+    };
+    let text_edit = TextEdit::replace(range, components.join("/"));
+    Some((text_edit, fs_edit))
+}
+
+/// Rewrites the paths of `include!`, `include_str!` and `include_bytes!` calls in the files of
+/// `source_root` that point into the directory `dir`, which is renamed to `new_name`.
+fn include_edits(
+    sema: &Semantics<'_, RootDatabase>,
+    source_root: &SourceRoot,
+    dir: &VfsPath,
+    new_name: &str,
+) -> Vec<(FileId, TextEdit)> {
+    let mut res = Vec::new();
+    for file_id in source_root.iter() {
+        if !sema.db.file_text(file_id).contains("include") {
+            continue;
+        }
+        let Some(file_dir) = source_root.path_for_file(&file_id).and_then(|it| it.parent()) else {
+            continue;
+        };
+        let mut edit = TextEdit::builder();
+        let mut has_edits = false;
+        let macro_calls =
+            sema.parse(file_id).syntax().descendants().filter_map(ast::MacroCall::cast);
+        for macro_call in macro_calls {
+            let Some(name_ref) = macro_call.path().and_then(|it| it.segment()?.name_ref()) else {
+                continue;
+            };
+            if !matches!(name_ref.text().as_str(), "include" | "include_str" | "include_bytes") {
+                continue;
+            }
+            let Some(path) = macro_call.token_tree().and_then(|tt| {
+                tt.syntax()
+                    .children_with_tokens()
+                    .filter_map(|it| it.into_token())
+                    .find_map(ast::String::cast)
+            }) else {
+                continue;
+            };
+            if path.text().contains('\\') {
+                continue;
+            }
+            let (Some(value), Some(range)) = (path.value(), path.text_range_between_quotes())
+            else {
+                continue;
+            };
+            // Walk the path to find the component naming the renamed directory.
+            let mut current = file_dir.clone();
+            let mut components: Vec<&str> = value.split('/').collect();
+            let renamed = components.iter_mut().any(|component| {
+                match current.join(component) {
+                    Some(it) => current = it,
+                    None => return false,
+                }
+                if current == *dir {
+                    *component = new_name;
+                    return true;
+                }
+                false
+            });
+            if renamed {
+                edit.replace(range, components.join("/"));
+                has_edits = true;
+            }
+        }
+        if has_edits {
+            res.push((file_id, edit.finish()));
+        }
+    }
+    res
+}
+
 fn rename_reference(
     sema: &Semantics<'_, RootDatabase>,
     def: Definition,
@@ -266,6 +1554,9 @@ fn rename_reference(
             IdentifierKind::Underscore => {
                 bail!("Invalid name `{}`: not a lifetime identifier", new_name);
             }
+            _ if is_raw_identifier(new_name.trim_start_matches('\'')) => {
+                bail!("Invalid name `{}`: lifetimes cannot use keyword names", new_name);
+            }
             _ => cov_mark::hit!(rename_lifetime),
         }
     } else {
@@ -287,19 +1578,231 @@ fn rename_reference(
         bail!("Cannot rename reference to `_` as it is being referenced multiple times");
     }
     let mut source_change = SourceChange::default();
-    source_change.extend(usages.iter().map(|(&file_id, references)| {
-        (file_id, source_edit_from_references(references, def, new_name))
-    }));
+    let ref_edits = usages
+        .iter()
+        .map(|(&file_id, references)| {
+            check_macro_references(sema, file_id, references)?;
+            let new_name = new_name_for_edition(new_name, file_edition(sema, file_id))?;
+            Ok((file_id, source_edit_from_references(references, def, &new_name)))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    source_change.extend(ref_edits);
 
-    let mut insert_def_edit = |def| {
-        let (file_id, edit) = source_edit_from_def(sema, def, new_name)?;
+    if let Definition::Field(field) = def {
+        // Has to go in before the definition edit, as both start at the field for plain fields.
+        if let Some((file_id, edit)) = serde_rename_edit(sema, field) {
+            source_change.insert_source_edit(file_id, edit);
+        }
+    }
+    let mut insert_def_edit = |def: Definition| {
+        let edition = def.krate(sema.db).map_or(Edition::CURRENT, |it| it.edition(sema.db));
+        let new_name = new_name_for_edition(new_name, edition)?;
+        let (file_id, edit) = source_edit_from_def(sema, def, &new_name)?;
         source_change.insert_source_edit(file_id, edit);
         Ok(())
     };
     insert_def_edit(def)?;
+    // The implementations of trait items are renamed along with them. Most of them have already
+    // been found by the usage search, but the ones created by macros can't be renamed, so we
+    // rather fail than leave them behind.
+    if let Some(trait_) = def.as_assoc_item(sema.db).and_then(|it| it.containing_trait(sema.db)) {
+        for impl_ in hir::Impl::all_for_trait(sema.db, trait_) {
+            for item in impl_.items(sema.db).into_iter().map(Definition::from) {
+                if convert_to_def_in_trait(sema.db, item) == def && insert_def_edit(item).is_err() {
+                    bail!(
+                        "Cannot rename `{}` as one of its implementations is created by a macro",
+                        def.name(sema.db)
+                            .map_or_else(String::new, |it| it.display(sema.db).to_string())
+                    );
+                }
+            }
+        }
+    }
     Ok(source_change)
 }
 
+/// Renaming a field of a type deriving `Serialize` or `Deserialize` changes the name the field is
+/// serialized under. To keep the serialized format intact, we pin the old name with an explicit
+/// `#[serde(rename = "...")]` attribute, taking a `rename_all` of the owner into account.
+fn serde_rename_edit(
+    sema: &Semantics<'_, RootDatabase>,
+    field: hir::Field,
+) -> Option<(FileId, TextEdit)> {
+    let src = field.source(sema.db)?;
+    let file_id = src.file_id.file_id()?;
+    let record_field = match src.value {
+        FieldSource::Named(it) => it,
+        FieldSource::Pos(_) => return None,
+    };
+    let adt = record_field.syntax().ancestors().find_map(ast::Adt::cast)?;
+    if !derives_serde(&adt) {
+        return None;
+    }
+    // The field is either already pinned to a name or not serialized under its own name at all.
+    if serde_args(&record_field)
+        .iter()
+        .any(|(key, _)| matches!(key.as_str(), "rename" | "skip" | "flatten"))
+    {
+        return None;
+    }
+    // `rename_all` on an enum applies to the variants, `rename_all` on a variant to its fields.
+    let rename_all = match record_field.syntax().ancestors().find_map(ast::Variant::cast) {
+        Some(variant) => serde_args(&variant),
+        None => serde_args(&adt),
+    }
+    .into_iter()
+    .find(|(key, _)| key == "rename_all");
+    let old_name = record_field.name()?;
+    let old_name = old_name.text();
+    let old_name = old_name.trim_start_matches("r#");
+    let serialized_name = match rename_all {
+        // FIXME: `rename_all(serialize = "...", deserialize = "...")`
+        Some((_, rule)) => apply_rename_all(old_name, &rule?)?,
+        None => old_name.to_owned(),
+    };
+    let on_own_line = record_field
+        .syntax()
+        .prev_sibling_or_token()
+        .map_or(false, |it| it.kind() == SyntaxKind::WHITESPACE && it.to_string().contains('\n'));
+    let separator = match on_own_line {
+        true => format!("\n{}", IndentLevel::from_node(record_field.syntax())),
+        false => " ".to_owned(),
+    };
+    let edit = TextEdit::insert(
+        record_field.syntax().text_range().start(),
+        format!("#[serde(rename = \"{serialized_name}\")]{separator}"),
+    );
+    Some((file_id, edit))
+}
+
+fn derives_serde(adt: &ast::Adt) -> bool {
+    adt.attrs().filter(|attr| attr.simple_name().as_deref() == Some("derive")).any(|attr| {
+        attr.token_tree().map_or(false, |tt| {
+            tt.syntax().descendants_with_tokens().filter_map(|it| it.into_token()).any(|it| {
+                it.kind() == SyntaxKind::IDENT && matches!(it.text(), "Serialize" | "Deserialize")
+            })
+        })
+    })
+}
+
+/// The top-level arguments of all `#[serde(...)]` attributes of `owner`, as keys and their string
+/// values, if any.
+fn serde_args(owner: &impl HasAttrs) -> Vec<(String, Option<String>)> {
+    let mut args = Vec::new();
+    for tt in owner
+        .attrs()
+        .filter(|attr| attr.simple_name().as_deref() == Some("serde"))
+        .filter_map(|attr| attr.token_tree())
+    {
+        let mut expects_value = false;
+        for it in tt.token_trees_and_tokens() {
+            let token = match it {
+                NodeOrToken::Token(token) if !token.kind().is_trivia() => token,
+                _ => continue,
+            };
+            match token.kind() {
+                SyntaxKind::IDENT => {
+                    args.push((token.text().to_owned(), None));
+                    expects_value = false;
+                }
+                T![=] => expects_value = true,
+                _ if expects_value => {
+                    if let (Some(string), Some((_, value))) =
+                        (ast::String::cast(token), args.last_mut())
+                    {
+                        *value = string.value().map(|it| it.into_owned());
+                    }
+                    expects_value = false;
+                }
+                _ => (),
+            }
+        }
+    }
+    args
+}
+
+/// Applies a serde `rename_all` rule to a field name, following serde's `RenameRule::apply_to_field`.
+fn apply_rename_all(field: &str, rule: &str) -> Option<String> {
+    let pascal_case = || {
+        field
+            .split('_')
+            .map(|word| {
+                let mut chars = word.chars();
+                chars.next().map_or_else(String::new, |first| {
+                    first.to_uppercase().chain(chars).collect::<String>()
+                })
+            })
+            .collect::<String>()
+    };
+    let res = match rule {
+        "lowercase" | "snake_case" => field.to_owned(),
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => field.to_ascii_uppercase(),
+        "PascalCase" => pascal_case(),
+        "camelCase" => {
+            let pascal = pascal_case();
+            let mut chars = pascal.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_lowercase().chain(chars).collect::<String>()
+            })
+        }
+        "kebab-case" => field.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => field.to_ascii_uppercase().replace('_', "-"),
+        _ => return None,
+    };
+    Some(res)
+}
+
+/// The text `new_name` is written as in code of the given edition.
+///
+/// Names that are keywords in the edition are written as raw identifiers, renaming to `type`
+/// writes `r#type`. Rust 2015 has no raw identifiers, renaming to one of its keywords is an
+/// error there while the keywords added in later editions, like `async`, are plain identifiers.
+pub fn new_name_for_edition(new_name: &str, edition: Edition) -> Result<String> {
+    let name = new_name.trim_start_matches("r#");
+    if !is_raw_identifier(name) {
+        return Ok(new_name.to_owned());
+    }
+    if edition == Edition::Edition2015 {
+        if matches!(name, "async" | "await" | "dyn" | "try") {
+            return Ok(name.to_owned());
+        }
+        bail!("Invalid name `{}`: `{}` is a keyword in Rust 2015", new_name, name);
+    }
+    Ok(format!("r#{name}"))
+}
+
+/// References in macro calls are only renamed if the token in the call is the identifier, we can't
+/// do anything about identifiers the macro puts together.
+fn check_macro_references(
+    sema: &Semantics<'_, RootDatabase>,
+    file_id: FileId,
+    references: &[FileReference],
+) -> Result<()> {
+    let constructed = references.iter().find(|reference| {
+        !spells_name(sema, FileRange { file_id, range: reference.range }, &reference.name.text())
+    });
+    match constructed {
+        Some(reference) => bail!(
+            "Cannot rename `{}` as it is constructed by a macro",
+            reference.name.text().trim_start_matches("r#")
+        ),
+        None => Ok(()),
+    }
+}
+
+/// Whether the text at `frange` is the identifier `name`, modulo raw identifier prefixes. This is
+/// not the case for identifiers that don't map back one-to-one to a token of a macro call, e.g.
+/// ones built with `concat_idents!` or `paste!`.
+fn spells_name(sema: &Semantics<'_, RootDatabase>, frange: FileRange, name: &str) -> bool {
+    let text = sema.db.file_text(frange.file_id);
+    text.get(std::ops::Range::<usize>::from(frange.range))
+        .map_or(false, |it| it.trim_start_matches("r#") == name.trim_start_matches("r#"))
+}
+
+fn file_edition(sema: &Semantics<'_, RootDatabase>, file_id: FileId) -> Edition {
+    sema.to_module_def(file_id).map_or(Edition::CURRENT, |it| it.krate().edition(sema.db))
+}
+
 pub fn source_edit_from_references(
     references: &[FileReference],
     def: Definition,
@@ -466,6 +1969,15 @@ fn source_edit_from_def(
             let source = match source.source.clone().original_ast_node(sema.db) {
                 Some(source) => source,
                 None => match source.source.syntax().original_file_range_opt(sema.db) {
+                    Some(frange)
+                        if !spells_name(
+                            sema,
+                            frange,
+                            local.name(sema.db).as_str().unwrap_or_default(),
+                        ) =>
+                    {
+                        bail!("Cannot rename local that is constructed by a macro")
+                    }
                     Some(FileRange { file_id: file_id2, range }) => {
                         file_id = Some(file_id2);
                         edit.replace(range, new_name.to_owned());
@@ -521,6 +2033,12 @@ fn source_edit_from_def(
     let FileRange { file_id, range } = def
         .range_for_rename(sema)
         .ok_or_else(|| format_err!("No identifier available to rename"))?;
+    if let Some(name) = def.name(sema.db) {
+        let name = name.unescaped().to_smol_str();
+        if !spells_name(sema, FileRange { file_id, range }, &name) {
+            bail!("Cannot rename `{}` as it is constructed by a macro", name);
+        }
+    }
     let (range, new_name) = match def {
         Definition::GenericParam(hir::GenericParam::LifetimeParam(_)) | Definition::Label(_) => (
             TextRange::new(range.start() + syntax::TextSize::from(1), range.end()),
@@ -541,6 +2059,10 @@ pub enum IdentifierKind {
 
 impl IdentifierKind {
     pub fn classify(new_name: &str) -> Result<IdentifierKind> {
+        // Keywords are renamed to raw identifiers, see `new_name_for_edition`.
+        if is_raw_identifier(new_name) {
+            return Ok(IdentifierKind::Ident);
+        }
         match parser::LexedStr::single_token(new_name) {
             Some(res) => match res {
                 (SyntaxKind::IDENT, _) => {
@@ -562,3 +2084,464 @@ pub fn classify(new_name: &str) -> Result<IdentifierKind> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use base_db::fixture::WithFixture;
+    use expect_test::{expect, Expect};
+
+    use super::*;
+    use crate::defs::NameClass;
+
+    fn check_conflicts(new_name: &str, ra_fixture: &str, expect: Expect) {
+        let (db, position) = RootDatabase::with_position(ra_fixture);
+        let sema = Semantics::new(&db);
+        let file = sema.parse(position.file_id);
+        let name: ast::Name =
+            sema.find_node_at_offset_with_descend(file.syntax(), position.offset).unwrap();
+        let def = NameClass::classify(&sema, &name).and_then(NameClass::defined).unwrap();
+        let mut conflicts = def
+            .rename_conflicts(&sema, new_name)
+            .into_iter()
+            .map(|it| {
+                let name = it.existing.name(&db).unwrap();
+                format!("{:?} {} {}\n", it.kind, name.display(&db), it.is_error())
+            })
+            .collect::<Vec<_>>();
+        conflicts.sort();
+        expect.assert_eq(&conflicts.concat());
+    }
+
+    fn check_warnings(ra_fixture: &str, expect: Expect) {
+        let (db, position) = RootDatabase::with_position(ra_fixture);
+        let sema = Semantics::new(&db);
+        let file = sema.parse(position.file_id);
+        let name: ast::Name =
+            sema.find_node_at_offset_with_descend(file.syntax(), position.offset).unwrap();
+        let def = NameClass::classify(&sema, &name).and_then(NameClass::defined).unwrap();
+        expect.assert_debug_eq(&def.rename_warnings(&sema));
+    }
+
+    #[test]
+    fn rename_warnings() {
+        check_warnings(
+            r#"
+pub mod m {
+    /// ```
+    /// lib::m::foo();
+    /// ```
+    #[no_mangle]
+    pub fn foo$0() {}
+}
+"#,
+            expect![[r#"
+                [
+                    PublicApi,
+                    ExportedSymbol,
+                    UsedInDoctests,
+                ]
+            "#]],
+        );
+        check_warnings(
+            r#"
+mod m {
+    /// The foo, but not in a doctest.
+    /// ```text
+    /// foo
+    /// ```
+    pub fn foo$0() {}
+}
+"#,
+            expect![[r#"
+                []
+            "#]],
+        );
+        check_warnings(
+            r#"
+mod m {
+    /// Fences like ``` only start a code block at the start of a line.
+    /// ```text
+    /// foo
+    /// ```
+    /// other::foo
+    pub fn foo$0() {}
+}
+"#,
+            expect![[r#"
+                []
+            "#]],
+        );
+    }
+
+    #[test]
+    fn export_name_edit() {
+        let (db, position) = RootDatabase::with_position(
+            r#"
+#[no_mangle]
+pub extern "C" fn foo$0() {}
+"#,
+        );
+        let sema = Semantics::new(&db);
+        let file = sema.parse(position.file_id);
+        let name: ast::Name =
+            sema.find_node_at_offset_with_descend(file.syntax(), position.offset).unwrap();
+        let def = NameClass::classify(&sema, &name).and_then(NameClass::defined).unwrap();
+        let source_change = def.export_name_edit(&sema).unwrap();
+        let mut text = db.file_text(position.file_id).to_string();
+        source_change.get_source_edit(position.file_id).unwrap().apply(&mut text);
+        expect![[r#"
+            #[export_name = "foo"]
+            pub extern "C" fn foo() {}
+        "#]]
+        .assert_eq(&text);
+    }
+
+    /// Renames the definitions of the names matching the old names of `renames`, `name:n` picks
+    /// the `n`th (zero-based) name instead of the first one.
+    fn check_batch(renames: &[(&str, &str)], ra_fixture: &str, expect: Expect) {
+        let (db, file_id) = RootDatabase::with_single_file(ra_fixture);
+        let sema = Semantics::new(&db);
+        let file = sema.parse(file_id);
+        let renames = renames
+            .iter()
+            .map(|&(old_name, new_name)| {
+                let (old_name, nth) = match old_name.split_once(':') {
+                    Some((name, nth)) => (name, nth.parse().unwrap()),
+                    None => (old_name, 0),
+                };
+                let name = file
+                    .syntax()
+                    .descendants()
+                    .filter_map(ast::Name::cast)
+                    .filter(|it| it.text() == old_name)
+                    .nth(nth)
+                    .unwrap();
+                let def = NameClass::classify(&sema, &name).and_then(NameClass::defined).unwrap();
+                (def, new_name)
+            })
+            .collect::<Vec<_>>();
+        let actual = match batch_rename(&sema, &renames) {
+            Ok(source_change) => {
+                let mut text = db.file_text(file_id).to_string();
+                if let Some(edit) = source_change.get_source_edit(file_id) {
+                    edit.apply(&mut text);
+                }
+                text
+            }
+            Err(err) => format!("error: {err}"),
+        };
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn batch_rename_field_and_local() {
+        check_batch(
+            &[("field", "a"), ("field:1", "b")],
+            r#"
+struct Foo { field: u32 }
+fn f(field: u32) -> Foo {
+    let Foo { field: ref other } = Foo { field };
+    Foo { field: field + 1 }
+}
+"#,
+            expect![[r#"
+                struct Foo { a: u32 }
+                fn f(b: u32) -> Foo {
+                    let Foo { a: ref other } = Foo { a: b };
+                    Foo { a: b + 1 }
+                }
+            "#]],
+        );
+        check_batch(
+            &[("field", "x"), ("local", "x"), ("field:1", "y"), ("field", "x")],
+            r#"
+struct Foo { field: u32 }
+fn f(local: u32) -> Foo {
+    Foo { field: local }
+}
+fn g(Foo { ref field }: Foo) {}
+"#,
+            expect![[r#"
+                struct Foo { x: u32 }
+                fn f(x: u32) -> Foo {
+                    Foo { x }
+                }
+                fn g(Foo { x: ref y }: Foo) {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn batch_rename_conflicts() {
+        check_batch(
+            &[("a", "c"), ("b", "c")],
+            r#"
+struct S { a: u32, b: u32 }
+"#,
+            expect![[r#"error: Cannot rename both `a` and `b` to `c`"#]],
+        );
+        check_batch(
+            &[("a", "b"), ("b", "a")],
+            r#"
+struct S { a: u32, b: u32 }
+fn f(s: S) -> u32 { s.a + s.b }
+"#,
+            expect![[r#"
+                struct S { b: u32, a: u32 }
+                fn f(s: S) -> u32 { s.b + s.a }
+            "#]],
+        );
+        check_batch(
+            &[("a", "b"), ("a", "c")],
+            r#"
+struct S { a: u32 }
+"#,
+            expect![[r#"error: Cannot rename `a` to both `b` and `c`"#]],
+        );
+        check_batch(
+            &[("foo", "bar")],
+            r#"
+fn foo() {}
+fn bar() {}
+"#,
+            expect![[r#"error: Cannot rename `foo` to `bar`: it would conflict with `bar`"#]],
+        );
+    }
+
+    fn check_accessors(new_name: &str, ra_fixture: &str, expect: Expect) {
+        let (db, position) = RootDatabase::with_position(ra_fixture);
+        let sema = Semantics::new(&db);
+        let file = sema.parse(position.file_id);
+        let name: ast::Name =
+            sema.find_node_at_offset_with_descend(file.syntax(), position.offset).unwrap();
+        let Some(Definition::Field(field)) =
+            NameClass::classify(&sema, &name).and_then(NameClass::defined)
+        else {
+            panic!("not a field")
+        };
+        let actual =
+            match rename_field_with_accessors(&sema, field, new_name, DEFAULT_ACCESSOR_PATTERNS) {
+                Ok(source_change) => {
+                    let mut text = db.file_text(position.file_id).to_string();
+                    if let Some(edit) = source_change.get_source_edit(position.file_id) {
+                        edit.apply(&mut text);
+                    }
+                    text
+                }
+                Err(err) => format!("error: {err}"),
+            };
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn field_accessors() {
+        check_accessors(
+            "len",
+            r#"
+struct S { size$0: usize }
+impl S {
+    fn size(&self) -> usize { self.size }
+    fn size_mut(&mut self) -> &mut usize { &mut self.size }
+    fn set_size(&mut self, size: usize) { self.size = size; }
+    fn new_size() -> usize { 0 }
+}
+trait T { fn size(&self) -> usize; }
+impl T for S { fn size(&self) -> usize { S::size(self) } }
+fn f(mut s: S) { s.set_size(s.size() + 1); }
+"#,
+            expect![[r#"
+                struct S { len: usize }
+                impl S {
+                    fn len(&self) -> usize { self.len }
+                    fn len_mut(&mut self) -> &mut usize { &mut self.len }
+                    fn set_len(&mut self, size: usize) { self.len = size; }
+                    fn new_size() -> usize { 0 }
+                }
+                trait T { fn size(&self) -> usize; }
+                impl T for S { fn size(&self) -> usize { S::len(self) } }
+                fn f(mut s: S) { s.set_len(s.len() + 1); }
+            "#]],
+        );
+        check_accessors(
+            "r#type",
+            r#"
+struct S { kind$0: u8 }
+impl S {
+    fn kind(&self) -> u8 { self.kind }
+    fn set_kind(&mut self, kind: u8) { self.kind = kind; }
+}
+"#,
+            expect![[r#"
+                struct S { r#type: u8 }
+                impl S {
+                    fn r#type(&self) -> u8 { self.r#type }
+                    fn set_type(&mut self, kind: u8) { self.r#type = kind; }
+                }
+            "#]],
+        );
+    }
+
+    fn check_case(case: NameCase, ra_fixture: &str, expect: Expect) {
+        let (db, position) = RootDatabase::with_position(ra_fixture);
+        let sema = Semantics::new(&db);
+        let file = sema.parse(position.file_id);
+        let name: ast::Name =
+            sema.find_node_at_offset_with_descend(file.syntax(), position.offset).unwrap();
+        let def = NameClass::classify(&sema, &name).and_then(NameClass::defined).unwrap();
+        let actual = match rename_case(&sema, def, case) {
+            Ok(source_change) => {
+                let mut text = db.file_text(position.file_id).to_string();
+                if let Some(edit) = source_change.get_source_edit(position.file_id) {
+                    edit.apply(&mut text);
+                }
+                text
+            }
+            Err(err) => format!("error: {err}"),
+        };
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn case_conversion() {
+        check_case(
+            NameCase::UpperCamel,
+            r#"
+mod foo_bar {}
+struct fooBar$0 {}
+fn fooBar() -> fooBar { fooBar {} }
+"#,
+            expect![[r#"
+                mod foo_bar {}
+                struct FooBar {}
+                fn foo_bar() -> FooBar { FooBar {} }
+            "#]],
+        );
+        check_case(
+            NameCase::UpperSnake,
+            r#"
+struct S;
+impl S {
+    const maxLen$0: usize = 8;
+    fn maxLen(&self) -> usize { Self::maxLen }
+}
+fn f() -> usize { S.maxLen() + S::maxLen }
+"#,
+            expect![[r#"
+                struct S;
+                impl S {
+                    const MAX_LEN: usize = 8;
+                    fn max_len(&self) -> usize { Self::MAX_LEN }
+                }
+                fn f() -> usize { S.max_len() + S::MAX_LEN }
+            "#]],
+        );
+        check_case(
+            NameCase::LowerSnake,
+            r#"
+fn fooBar$0() {}
+fn foo_bar() {}
+"#,
+            expect![[
+                r#"error: Cannot rename `fooBar` to `foo_bar`: it would conflict with `foo_bar`"#
+            ]],
+        );
+    }
+
+    #[test]
+    fn duplicate_items() {
+        check_conflicts(
+            "baz",
+            r#"
+fn bar$0() {}
+fn baz() {}
+struct baz {}
+"#,
+            expect![[r#"
+                Duplicate baz true
+            "#]],
+        );
+        check_conflicts(
+            "b",
+            r#"
+struct S { a$0: u32, b: u32 }
+"#,
+            expect![[r#"
+                Duplicate b true
+            "#]],
+        );
+        check_conflicts(
+            "c",
+            r#"
+struct S { a$0: u32, b: u32 }
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn shadowed_references() {
+        check_conflicts(
+            "bar",
+            r#"
+fn foo$0() {}
+fn main() {
+    let bar = 0;
+    foo();
+    self::foo();
+}
+fn other<bar>() {
+    foo();
+}
+"#,
+            expect![[r#"
+                ShadowedCall bar true
+            "#]],
+        );
+        check_conflicts(
+            "bar",
+            r#"
+fn foo$0() {}
+fn main() {
+    let bar = 0;
+    let _ = foo;
+}
+"#,
+            expect![[r#"
+                Shadowed bar false
+            "#]],
+        );
+        check_conflicts(
+            "Bar",
+            r#"
+struct Foo$0;
+fn f<Bar>(foo: Foo) -> Foo {
+    Foo
+}
+fn g<const Bar: usize>() {
+    let Foo = Foo;
+}
+"#,
+            expect![[r#"
+                Shadowed Bar false
+                Shadowed Bar false
+            "#]],
+        );
+    }
+
+    #[test]
+    fn foreign_trait_impl() {
+        check_conflicts(
+            "new",
+            r#"
+//- minicore: default
+struct S;
+impl Default for S {
+    fn default$0() -> Self { S }
+}
+"#,
+            expect![[r#"
+                TraitImplMismatch default true
+            "#]],
+        );
+    }
+}
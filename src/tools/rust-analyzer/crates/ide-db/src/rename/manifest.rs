@@ -0,0 +1,471 @@
+//! Renames of packages in `Cargo.toml` manifests, for [`super::rename_crate`].
+//!
+//! Manifests are parsed into the list of their keys with the ranges of the keys and of their
+//! string values, which is all that's needed to edit them without touching their formatting.
+//! Only the parts of TOML that manifests use are supported: tables, arrays of tables, dotted and
+//! quoted keys, inline tables, arrays, comments and the four kinds of strings.
+
+use rustc_hash::FxHashSet;
+use syntax::{TextRange, TextSize};
+use text_edit::TextEdit;
+
+/// Renames the package in the `[package]` table of its manifest, and its library target in the
+/// `[lib]` table, if that is named explicitly.
+pub(super) fn package_manifest_edit(
+    text: &str,
+    old_package: &str,
+    new_package: &str,
+    new_crate_name: &str,
+) -> TextEdit {
+    let mut edit = TextEdit::builder();
+    for entry in parse(text).unwrap_or_default() {
+        let Value::String { text, range } = &entry.value else { continue };
+        match entry.names()[..] {
+            ["package", "name"] if text == old_package => {
+                edit.replace(*range, new_package.to_owned())
+            }
+            ["lib", "name"] => edit.replace(*range, new_crate_name.to_owned()),
+            _ => (),
+        }
+    }
+    edit.finish()
+}
+
+/// Renames the dependencies on the package in a manifest. Dependencies that are renamed with
+/// `package = "..."` keep their name.
+pub(super) fn dependency_manifest_edit(
+    text: &str,
+    old_package: &str,
+    new_package: &str,
+) -> TextEdit {
+    let Some(entries) = parse(text) else { return TextEdit::default() };
+    // The dependencies whose name isn't the one of the package, like `alias` in
+    // `alias = { package = "old-package" }`.
+    let renamed: FxHashSet<Vec<&str>> = entries
+        .iter()
+        .filter_map(|entry| {
+            let names = entry.names();
+            let name = dependency_name_idx(&names)?;
+            (names.len() == name + 2 && names[name + 1] == "package")
+                .then(|| names[..=name].to_vec())
+        })
+        .collect();
+
+    let mut edit = TextEdit::builder();
+    for entry in &entries {
+        let names = entry.names();
+        let Some(name) = dependency_name_idx(&names) else { continue };
+        let is_renamed = renamed.contains(&names[..=name]);
+        if names[name] == old_package && !is_renamed && name >= entry.own_keys {
+            // `old-package = "1.0"`, `old-package.workspace = true` or
+            // `[dependencies.old-package]`
+            edit.replace(entry.path[name].range, new_package.to_owned());
+        }
+        if let Value::String { text, range } = &entry.value {
+            // `alias = { package = "old-package" }`
+            if names.len() == name + 2 && names[name + 1] == "package" && text == old_package {
+                edit.replace(*range, new_package.to_owned());
+            }
+        }
+    }
+    edit.finish()
+}
+
+/// The index of the name of the dependency in the keys of an entry of a dependency table, like
+/// `foo` in `dependencies.foo.version` or `target.'cfg(unix)'.dev-dependencies.foo`.
+fn dependency_name_idx(names: &[&str]) -> Option<usize> {
+    let is_dependency_table =
+        |name: &str| matches!(name, "dependencies" | "dev-dependencies" | "build-dependencies");
+    let idx = match names {
+        [table, ..] if is_dependency_table(table) => 1,
+        ["workspace", "dependencies", ..] => 2,
+        ["target", _, table, ..] if is_dependency_table(table) => 3,
+        _ => return None,
+    };
+    (idx < names.len()).then_some(idx)
+}
+
+/// A key of a manifest with its value, or a table header.
+#[derive(Debug)]
+struct Entry {
+    /// The keys leading to the value, starting with the ones of the table the entry is in.
+    path: Vec<Key>,
+    /// The index of the first key of `path` that is written at this entry, the ones before it are
+    /// the ones of the table header or of the enclosing inline tables.
+    own_keys: usize,
+    value: Value,
+}
+
+impl Entry {
+    fn names(&self) -> Vec<&str> {
+        self.path.iter().map(|it| it.name.as_str()).collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Key {
+    /// The name of the key, without the quotes of quoted keys.
+    name: String,
+    /// The range of the name, without the quotes.
+    range: TextRange,
+}
+
+#[derive(Debug)]
+enum Value {
+    /// A string, with the range of its contents. Escapes are kept as they are written.
+    String {
+        text: String,
+        range: TextRange,
+    },
+    /// A table header or an inline table.
+    Table,
+    Other,
+}
+
+/// Parses the entries of a manifest, or returns `None` if it isn't valid TOML.
+fn parse(text: &str) -> Option<Vec<Entry>> {
+    let mut p = Parser { text, pos: 0, array_depth: 0, entries: Vec::new() };
+    let mut table = Vec::new();
+    loop {
+        p.skip_trivia();
+        if p.pos == text.len() {
+            break;
+        }
+        if p.eat("[") {
+            // `[[bin]]` is an array of tables.
+            let is_array = p.eat("[");
+            p.skip_whitespace();
+            table = p.keys()?;
+            p.expect("]")?;
+            if is_array {
+                p.expect("]")?;
+            }
+            p.entries.push(Entry { path: table.clone(), own_keys: 0, value: Value::Table });
+        } else {
+            p.key_value(&table)?;
+        }
+        p.line_end()?;
+    }
+    Some(p.entries)
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+    /// Entries of inline tables in arrays aren't recorded, they don't have a path.
+    array_depth: usize,
+    entries: Vec<Entry>,
+}
+
+impl Parser<'_> {
+    fn rest(&self) -> &str {
+        &self.text[self.pos..]
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        let res = self.rest().starts_with(s);
+        if res {
+            self.pos += s.len();
+        }
+        res
+    }
+
+    fn expect(&mut self, s: &str) -> Option<()> {
+        self.skip_whitespace();
+        self.eat(s).then_some(())
+    }
+
+    fn range(&self, start: usize, end: usize) -> TextRange {
+        TextRange::new(TextSize::from(start as u32), TextSize::from(end as u32))
+    }
+
+    /// Skips spaces and tabs.
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start_matches([' ', '\t']).len();
+    }
+
+    /// Skips whitespace, newlines and comments.
+    fn skip_trivia(&mut self) {
+        loop {
+            let rest = self.rest();
+            self.pos += rest.len() - rest.trim_start().len();
+            if !self.rest().starts_with('#') {
+                break;
+            }
+            self.pos += self.rest().find('\n').unwrap_or(self.rest().len());
+        }
+    }
+
+    /// Skips the rest of a line, which may only hold a comment.
+    fn line_end(&mut self) -> Option<()> {
+        self.skip_whitespace();
+        if self.rest().starts_with('#') {
+            self.pos += self.rest().find('\n').unwrap_or(self.rest().len());
+        }
+        (self.pos == self.text.len() || self.eat("\n") || self.eat("\r\n")).then_some(())
+    }
+
+    /// Parses `key = value`, with the keys of the enclosing tables in `parent`.
+    fn key_value(&mut self, parent: &[Key]) -> Option<()> {
+        let mut path = parent.to_vec();
+        let own_keys = path.len();
+        path.extend(self.keys()?);
+        self.expect("=")?;
+        self.skip_whitespace();
+        let idx = self.entries.len();
+        let value = self.value(&path)?;
+        if self.array_depth == 0 {
+            // Inline tables record their entries after the one of their own key.
+            self.entries.insert(idx, Entry { path, own_keys, value });
+        }
+        Some(())
+    }
+
+    /// Parses a dotted key, like `a."b".c`.
+    fn keys(&mut self) -> Option<Vec<Key>> {
+        let mut res = Vec::new();
+        loop {
+            self.skip_whitespace();
+            res.push(self.key()?);
+            self.skip_whitespace();
+            if !self.eat(".") {
+                return Some(res);
+            }
+        }
+    }
+
+    fn key(&mut self) -> Option<Key> {
+        if self.rest().starts_with(['"', '\'']) {
+            let (name, range) = self.string()?;
+            return Some(Key { name, range });
+        }
+        let start = self.pos;
+        let len = self
+            .rest()
+            .find(|it: char| !(it.is_ascii_alphanumeric() || it == '-' || it == '_'))
+            .unwrap_or(self.rest().len());
+        if len == 0 {
+            return None;
+        }
+        self.pos += len;
+        Some(Key {
+            name: self.text[start..self.pos].to_owned(),
+            range: self.range(start, self.pos),
+        })
+    }
+
+    fn value(&mut self, path: &[Key]) -> Option<Value> {
+        if self.rest().starts_with(['"', '\'']) {
+            let (text, range) = self.string()?;
+            return Some(Value::String { text, range });
+        }
+        if self.eat("{") {
+            // Inline tables may span several lines, which Cargo accepts.
+            loop {
+                self.skip_trivia();
+                if self.eat("}") {
+                    break;
+                }
+                self.key_value(path)?;
+                self.skip_trivia();
+                if !self.eat(",") {
+                    self.skip_trivia();
+                    self.eat("}").then_some(())?;
+                    break;
+                }
+            }
+            return Some(Value::Table);
+        }
+        if self.eat("[") {
+            self.array_depth += 1;
+            loop {
+                self.skip_trivia();
+                if self.eat("]") {
+                    break;
+                }
+                self.value(&[])?;
+                self.skip_trivia();
+                if !self.eat(",") {
+                    self.skip_trivia();
+                    self.eat("]").then_some(())?;
+                    break;
+                }
+            }
+            self.array_depth -= 1;
+            return Some(Value::Other);
+        }
+        // Numbers, booleans and dates.
+        let len = self.rest().find([',', '}', ']', '#', '\n']).unwrap_or(self.rest().len());
+        if self.rest()[..len].trim().is_empty() {
+            return None;
+        }
+        self.pos += self.rest()[..len].trim_end().len();
+        Some(Value::Other)
+    }
+
+    /// Parses a basic, literal or multi-line string, returning its contents and their range.
+    fn string(&mut self) -> Option<(String, TextRange)> {
+        let quote =
+            ["\"\"\"", "'''", "\"", "'"].into_iter().find(|it| self.rest().starts_with(it))?;
+        self.pos += quote.len();
+        let start = self.pos;
+        let is_basic = quote.starts_with('"');
+        let mut escaped = false;
+        let end = loop {
+            let rest = self.rest();
+            if !escaped && rest.starts_with(quote) {
+                break self.pos;
+            }
+            let c = rest.chars().next()?;
+            if c == '\n' && quote.len() == 1 {
+                return None;
+            }
+            escaped = is_basic && c == '\\' && !escaped;
+            self.pos += c.len_utf8();
+        };
+        self.pos += quote.len();
+        Some((self.text[start..end].to_owned(), self.range(start, end)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use super::*;
+
+    fn check_dependencies(text: &str, expect: Expect) {
+        let mut text = text.trim_start().to_owned();
+        dependency_manifest_edit(&text, "foo", "bar").apply(&mut text);
+        expect.assert_eq(&text);
+    }
+
+    #[test]
+    fn package() {
+        let mut text = r#"
+[package] # the package
+name = 'foo'
+version = "0.1.0"
+[lib]
+name = "foo_lib"
+[[bin]]
+name = "foo"
+"#
+        .trim_start()
+        .to_owned();
+        package_manifest_edit(&text, "foo", "bar", "bar").apply(&mut text);
+        expect![[r#"
+            [package] # the package
+            name = 'bar'
+            version = "0.1.0"
+            [lib]
+            name = "bar"
+            [[bin]]
+            name = "foo"
+        "#]]
+        .assert_eq(&text);
+    }
+
+    #[test]
+    fn dependencies() {
+        check_dependencies(
+            r#"
+[dependencies]
+# foo = "0.1"
+foo = { version = "0.1", features = ["foo"] } # foo
+"foo-bar" = "1.0"
+other = "foo"
+
+[dev-dependencies]
+foo.workspace = true
+
+[target.'cfg(unix)'.build-dependencies]
+foo = {
+    path = "../foo",
+}
+
+[workspace.dependencies]
+"foo" = { path = "foo" }
+"#,
+            expect![[r#"
+                [dependencies]
+                # foo = "0.1"
+                bar = { version = "0.1", features = ["foo"] } # foo
+                "foo-bar" = "1.0"
+                other = "foo"
+
+                [dev-dependencies]
+                bar.workspace = true
+
+                [target.'cfg(unix)'.build-dependencies]
+                bar = {
+                    path = "../foo",
+                }
+
+                [workspace.dependencies]
+                "bar" = { path = "foo" }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn dependency_tables() {
+        check_dependencies(
+            r#"
+[dependencies.foo]
+path = "../foo"
+
+[dev-dependencies.alias]
+package = "foo"
+path = "../foo"
+
+[dependencies.foo-sys]
+version = "1.0"
+"#,
+            expect![[r#"
+                [dependencies.bar]
+                path = "../foo"
+
+                [dev-dependencies.alias]
+                package = "bar"
+                path = "../foo"
+
+                [dependencies.foo-sys]
+                version = "1.0"
+            "#]],
+        );
+    }
+
+    #[test]
+    fn renamed_dependencies() {
+        check_dependencies(
+            r#"
+[dependencies]
+alias = { path = "../foo", package = "foo" }
+foo = { package = "other", version = "1.0" }
+other = { package = "foo" } # package = "foo"
+"#,
+            expect![[r#"
+                [dependencies]
+                alias = { path = "../foo", package = "bar" }
+                foo = { package = "other", version = "1.0" }
+                other = { package = "bar" } # package = "foo"
+            "#]],
+        );
+    }
+
+    #[test]
+    fn invalid_manifest() {
+        check_dependencies(
+            r#"
+[dependencies]
+foo = "1.0
+"#,
+            expect![[r#"
+                [dependencies]
+                foo = "1.0
+            "#]],
+        );
+    }
+}
@@ -1,5 +1,18 @@
 //! Rustdoc specific doc comment handling
 
+mod intra_doc_links;
+
+use hir::{db::HirDatabase, HasAttrs, Semantics};
+use pulldown_cmark::{BrokenLink, CowStr, Event, Options, Parser, Tag};
+use syntax::{
+    ast::{self, AstToken},
+    match_ast, AstNode, SyntaxNode, TextRange, TextSize,
+};
+
+use crate::{defs::Definition, RootDatabase};
+
+pub use intra_doc_links::{parse_intra_doc_link, strip_prefixes_suffixes};
+
 // stripped down version of https://github.com/rust-lang/rust/blob/392ba2ba1a7d6c542d2459fb8133bebf62a4a423/src/librustdoc/html/markdown.rs#L810-L933
 pub fn is_rust_fence(s: &str) -> bool {
     let mut seen_rust_tags = false;
@@ -32,3 +45,171 @@ pub fn is_rust_fence(s: &str) -> bool {
 
     !seen_other_tags || seen_rust_tags
 }
+
+const RUSTDOC_FENCES: [&str; 2] = ["```", "~~~"];
+const RUSTDOC_CODE_BLOCK_ATTRIBUTES_RUNNABLE: &[&str] =
+    &["", "rust", "should_panic", "edition2015", "edition2018", "edition2021"];
+
+/// Finds the code of the doctests, the Rust code blocks of doc comments, in `node`. Returns the
+/// range of each line of code, without the comment prefix and the `# ` of hidden lines.
+// FIXME: Doctests in `#[doc = "..."]` attributes are not found.
+pub fn doctest_code_ranges(node: &SyntaxNode) -> Vec<TextRange> {
+    let mut res = Vec::new();
+    let mut owner = None;
+    let mut is_codeblock = false;
+    let mut is_doctest = false;
+    let comments = node
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter_map(ast::Comment::cast)
+        .filter(|it| it.kind().doc.is_some());
+    for comment in comments {
+        // Code blocks don't carry over from the docs of one item to the ones of the next.
+        let parent = comment.syntax().parent();
+        if parent != owner {
+            owner = parent;
+            is_codeblock = false;
+            is_doctest = false;
+        }
+        let prefix_len = TextSize::of(comment.prefix());
+        let mut line_start = comment.syntax().text_range().start() + prefix_len;
+        for line in comment.text()[prefix_len.into()..].split('\n') {
+            let line_range = TextRange::at(line_start, TextSize::of(line));
+            line_start = line_range.end() + TextSize::of('\n');
+            let trimmed = line.trim_start();
+            if let Some(header) =
+                RUSTDOC_FENCES.into_iter().find_map(|fence| trimmed.strip_prefix(fence))
+            {
+                is_codeblock = !is_codeblock;
+                is_doctest = is_codeblock && is_rust_fence(header);
+                continue;
+            }
+            if !is_doctest {
+                continue;
+            }
+            let code = line.trim_start();
+            let code = match code.strip_prefix("# ") {
+                Some(hidden) => hidden,
+                None if code == "#" => "",
+                None => code,
+            };
+            let start = line_range.end() - TextSize::of(code);
+            res.push(TextRange::new(start, line_range.end()));
+        }
+    }
+    res
+}
+
+/// Whether the docs in `attrs` have a code block rustdoc runs as a test.
+pub fn has_runnable_doc_test(attrs: &hir::Attrs) -> bool {
+    attrs.docs().map_or(false, |doc| {
+        let mut in_code_block = false;
+
+        for line in String::from(doc).lines() {
+            if let Some(header) =
+                RUSTDOC_FENCES.into_iter().find_map(|fence| line.strip_prefix(fence))
+            {
+                in_code_block = !in_code_block;
+
+                if in_code_block
+                    && header
+                        .split(',')
+                        .all(|sub| RUSTDOC_CODE_BLOCK_ATTRIBUTES_RUNNABLE.contains(&sub.trim()))
+                {
+                    return true;
+                }
+            }
+        }
+
+        false
+    })
+}
+
+pub const MARKDOWN_OPTIONS: Options =
+    Options::ENABLE_FOOTNOTES.union(Options::ENABLE_TABLES).union(Options::ENABLE_TASKLISTS);
+
+/// Extracts all links from a given markdown text returning the definition text range, link-text
+/// and the namespace if known.
+pub fn extract_definitions_from_docs(
+    docs: &hir::Documentation,
+) -> Vec<(TextRange, String, Option<hir::Namespace>)> {
+    Parser::new_with_broken_link_callback(
+        docs.as_str(),
+        MARKDOWN_OPTIONS,
+        Some(&mut broken_link_clone_cb),
+    )
+    .into_offset_iter()
+    .filter_map(|(event, range)| match event {
+        Event::Start(Tag::Link(_, target, _)) => {
+            let (link, ns) = parse_intra_doc_link(&target);
+            Some((
+                TextRange::new(range.start.try_into().ok()?, range.end.try_into().ok()?),
+                link.to_string(),
+                ns,
+            ))
+        }
+        _ => None,
+    })
+    .collect()
+}
+
+pub fn resolve_doc_path_for_def(
+    db: &dyn HirDatabase,
+    def: Definition,
+    link: &str,
+    ns: Option<hir::Namespace>,
+) -> Option<Definition> {
+    match def {
+        Definition::Module(it) => it.resolve_doc_path(db, link, ns),
+        Definition::Function(it) => it.resolve_doc_path(db, link, ns),
+        Definition::Adt(it) => it.resolve_doc_path(db, link, ns),
+        Definition::Variant(it) => it.resolve_doc_path(db, link, ns),
+        Definition::Const(it) => it.resolve_doc_path(db, link, ns),
+        Definition::Static(it) => it.resolve_doc_path(db, link, ns),
+        Definition::Trait(it) => it.resolve_doc_path(db, link, ns),
+        Definition::TraitAlias(it) => it.resolve_doc_path(db, link, ns),
+        Definition::TypeAlias(it) => it.resolve_doc_path(db, link, ns),
+        Definition::Macro(it) => it.resolve_doc_path(db, link, ns),
+        Definition::Field(it) => it.resolve_doc_path(db, link, ns),
+        Definition::SelfType(it) => it.resolve_doc_path(db, link, ns),
+        Definition::BuiltinAttr(_)
+        | Definition::ToolModule(_)
+        | Definition::BuiltinType(_)
+        | Definition::Local(_)
+        | Definition::GenericParam(_)
+        | Definition::Label(_)
+        | Definition::DeriveHelper(_) => None,
+    }
+    .map(Definition::from)
+}
+
+pub fn doc_attributes(
+    sema: &Semantics<'_, RootDatabase>,
+    node: &SyntaxNode,
+) -> Option<(hir::AttrsWithOwner, Definition)> {
+    match_ast! {
+        match node {
+            ast::SourceFile(it)  => sema.to_def(&it).map(|def| (def.attrs(sema.db), Definition::Module(def))),
+            ast::Module(it)      => sema.to_def(&it).map(|def| (def.attrs(sema.db), Definition::Module(def))),
+            ast::Fn(it)          => sema.to_def(&it).map(|def| (def.attrs(sema.db), Definition::Function(def))),
+            ast::Struct(it)      => sema.to_def(&it).map(|def| (def.attrs(sema.db), Definition::Adt(hir::Adt::Struct(def)))),
+            ast::Union(it)       => sema.to_def(&it).map(|def| (def.attrs(sema.db), Definition::Adt(hir::Adt::Union(def)))),
+            ast::Enum(it)        => sema.to_def(&it).map(|def| (def.attrs(sema.db), Definition::Adt(hir::Adt::Enum(def)))),
+            ast::Variant(it)     => sema.to_def(&it).map(|def| (def.attrs(sema.db), Definition::Variant(def))),
+            ast::Trait(it)       => sema.to_def(&it).map(|def| (def.attrs(sema.db), Definition::Trait(def))),
+            ast::Static(it)      => sema.to_def(&it).map(|def| (def.attrs(sema.db), Definition::Static(def))),
+            ast::Const(it)       => sema.to_def(&it).map(|def| (def.attrs(sema.db), Definition::Const(def))),
+            ast::TypeAlias(it)   => sema.to_def(&it).map(|def| (def.attrs(sema.db), Definition::TypeAlias(def))),
+            ast::Impl(it)        => sema.to_def(&it).map(|def| (def.attrs(sema.db), Definition::SelfType(def))),
+            ast::RecordField(it) => sema.to_def(&it).map(|def| (def.attrs(sema.db), Definition::Field(def))),
+            ast::TupleField(it)  => sema.to_def(&it).map(|def| (def.attrs(sema.db), Definition::Field(def))),
+            ast::Macro(it)       => sema.to_def(&it).map(|def| (def.attrs(sema.db), Definition::Macro(def))),
+            // ast::Use(it) => sema.to_def(&it).map(|def| (Box::new(it) as _, def.attrs(sema.db))),
+            _ => None
+        }
+    }
+}
+
+pub fn broken_link_clone_cb(link: BrokenLink<'_>) -> Option<(CowStr<'_>, CowStr<'_>)> {
+    Some((/*url*/ link.reference.clone(), /*title*/ link.reference))
+}
@@ -13,7 +13,7 @@
 /// * `struct MyStruct` -> ("MyStruct", `Namespace::Types`)
 /// * `panic!` -> ("panic", `Namespace::Macros`)
 /// * `fn@from_intra_spec` -> ("from_intra_spec", `Namespace::Values`)
-pub(super) fn parse_intra_doc_link(s: &str) -> (&str, Option<hir::Namespace>) {
+pub fn parse_intra_doc_link(s: &str) -> (&str, Option<hir::Namespace>) {
     let s = s.trim_matches('`');
 
     [
@@ -35,7 +35,7 @@ pub(super) fn parse_intra_doc_link(s: &str) -> (&str, Option<hir::Namespace>) {
     .map_or((s, None), |(s, ns)| (s, Some(ns)))
 }
 
-pub(super) fn strip_prefixes_suffixes(s: &str) -> &str {
+pub fn strip_prefixes_suffixes(s: &str) -> &str {
     [
         (TYPES.0.iter(), TYPES.1.iter()),
         (VALUES.0.iter(), VALUES.1.iter()),
@@ -0,0 +1,540 @@
+//! Indices of the workspace for precise code navigation outside of the editor, in the
+//! [SCIP](https://github.com/sourcegraph/scip) format and, for older consumers, in the
+//! [LSIF](https://microsoft.github.io/language-server-protocol/specifications/lsif/0.6.0/specification/)
+//! format.
+//!
+//! Every identifier of the workspace files is classified like for semantic highlighting, including
+//! the identifiers in macro calls, and becomes an occurrence of the symbol of its definition.
+//! Public definitions get global symbols which other indices can refer to. The other ones get
+//! document-local symbols if their search scope is the file they are used in, like locals or
+//! private items of a module without submodules in other files, and global symbols too otherwise.
+//! Like in the monikers of the editor, builtin types get document-local symbols.
+//!
+//! The paths of the files aren't known to the database, so the encoders ask for them.
+
+use std::fmt::Write;
+
+use base_db::{CrateOrigin, FileId, SourceDatabase, SourceDatabaseExt};
+use hir::{AsAssocItem, AssocItemContainer, Crate, HasAttrs, Semantics, Visibility};
+use line_index::{LineCol, LineIndex, WideEncoding};
+use stdx::format_to;
+use syntax::TextRange;
+
+use crate::{
+    defs::Definition,
+    parallel::par_map,
+    token_classes::{classify_file, TokenModifier},
+    FxHashMap, FxHashSet, LineIndexDatabase, RootDatabase,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScipIndex {
+    /// The documents of the workspace files with at least one occurrence, ordered by file.
+    pub documents: Vec<ScipDocument>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScipDocument {
+    pub file_id: FileId,
+    pub occurrences: Vec<ScipOccurrence>,
+    /// The symbols defined in the document.
+    pub symbols: Vec<ScipSymbol>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScipOccurrence {
+    pub range: TextRange,
+    /// The zero-based line and UTF-8 column of the start and end of the range.
+    pub start: LineCol,
+    pub end: LineCol,
+    /// The symbol in the SCIP syntax, like `rust-analyzer cargo foo 0.1.0 bar/baz().` or
+    /// `local 0`.
+    pub symbol: String,
+    pub is_definition: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScipSymbol {
+    pub symbol: String,
+    pub documentation: Option<String>,
+}
+
+/// Indexes the files of the source roots of the workspace crates.
+pub fn index_workspace(db: &RootDatabase) -> ScipIndex {
+    let _p = profile::span("index_workspace");
+    let crate_graph = db.crate_graph();
+    let roots: FxHashSet<_> = crate_graph
+        .iter()
+        .filter(|&krate| crate_graph[krate].origin.is_local())
+        .map(|krate| db.file_source_root(crate_graph[krate].root_file_id))
+        .collect();
+    let mut files: Vec<FileId> = roots
+        .into_iter()
+        .flat_map(|root| db.source_root(root).iter().collect::<Vec<_>>())
+        .collect();
+    files.sort_unstable();
+    let documents = par_map(db, files, index_file);
+    ScipIndex { documents: documents.into_iter().filter(|it| !it.occurrences.is_empty()).collect() }
+}
+
+/// Indexes the occurrences of the identifiers of `file_id`.
+pub fn index_file(db: &RootDatabase, file_id: FileId) -> ScipDocument {
+    let sema = Semantics::new(db);
+    let line_index = db.line_index(file_id);
+    let mut document = ScipDocument { file_id, occurrences: Vec::new(), symbols: Vec::new() };
+    let mut symbols: FxHashMap<Definition, String> = FxHashMap::default();
+    let mut locals = 0;
+
+    for token in classify_file(&sema, file_id) {
+        let def = token.definition;
+        let symbol = symbols
+            .entry(def)
+            .or_insert_with(|| {
+                global_symbol(db, def, file_id).unwrap_or_else(|| {
+                    locals += 1;
+                    format!("local {}", locals - 1)
+                })
+            })
+            .clone();
+        let is_definition = token.modifiers.contains(TokenModifier::Definition);
+        if is_definition {
+            document
+                .symbols
+                .push(ScipSymbol { symbol: symbol.clone(), documentation: documentation(db, def) });
+        }
+        document.occurrences.push(ScipOccurrence {
+            range: token.range,
+            start: line_index.line_col(token.range.start()),
+            end: line_index.line_col(token.range.end()),
+            symbol,
+            is_definition,
+        });
+    }
+    document
+}
+
+/// The global symbol of `def`, or `None` if it can only be referred to from `file_id`.
+fn global_symbol(db: &RootDatabase, def: Definition, file_id: FileId) -> Option<String> {
+    if matches!(
+        def,
+        Definition::Local(_)
+            | Definition::GenericParam(_)
+            | Definition::Label(_)
+            | Definition::DeriveHelper(_)
+            | Definition::BuiltinAttr(_)
+            | Definition::ToolModule(_)
+            | Definition::BuiltinType(_)
+    ) {
+        return None;
+    }
+    let is_public = def.visibility(db) == Some(Visibility::Public);
+    if !is_public && def.search_scope(db).into_iter().all(|(it, _)| it == file_id) {
+        return None;
+    }
+
+    let module = def.module(db)?;
+    let krate = module.krate();
+    let mut descriptors = String::new();
+    for module in module.path_to_root(db).into_iter().rev() {
+        if let Some(name) = module.name(db) {
+            push_descriptor(&mut descriptors, &name.display(db).to_string(), "/");
+        }
+    }
+    if let Some(assoc) = def.as_assoc_item(db) {
+        match assoc.container(db) {
+            AssocItemContainer::Trait(it) => {
+                push_descriptor(&mut descriptors, &it.name(db).display(db).to_string(), "#")
+            }
+            AssocItemContainer::Impl(it) => {
+                if let Some(adt) = it.self_ty(db).as_adt() {
+                    push_descriptor(&mut descriptors, &adt.name(db).display(db).to_string(), "#");
+                }
+                if let Some(trait_) = it.trait_(db) {
+                    push_descriptor(
+                        &mut descriptors,
+                        &trait_.name(db).display(db).to_string(),
+                        "#",
+                    );
+                }
+            }
+        }
+    }
+    if let Definition::Field(it) = def {
+        push_descriptor(&mut descriptors, &it.parent_def(db).name(db).display(db).to_string(), "#");
+    }
+    let (name, suffix) = match def {
+        Definition::Module(it) => (it.name(db)?, "/"),
+        Definition::Function(it) => (it.name(db), "()."),
+        Definition::Adt(it) => (it.name(db), "#"),
+        Definition::Variant(it) => (it.name(db), "#"),
+        Definition::Const(it) => (it.name(db)?, "."),
+        Definition::Static(it) => (it.name(db), "."),
+        Definition::Trait(it) => (it.name(db), "#"),
+        Definition::TraitAlias(it) => (it.name(db), "#"),
+        Definition::TypeAlias(it) => (it.name(db), "#"),
+        Definition::Macro(it) => (it.name(db), "!"),
+        Definition::Field(it) => (it.name(db), "."),
+        Definition::SelfType(it) => (it.self_ty(db).as_adt()?.name(db), "#"),
+        Definition::BuiltinType(_)
+        | Definition::Local(_)
+        | Definition::GenericParam(_)
+        | Definition::Label(_)
+        | Definition::DeriveHelper(_)
+        | Definition::BuiltinAttr(_)
+        | Definition::ToolModule(_) => return None,
+    };
+    push_descriptor(&mut descriptors, &name.display(db).to_string(), suffix);
+
+    let version = krate.version(db).unwrap_or_else(|| ".".to_owned());
+    Some(format!("rust-analyzer cargo {} {version} {descriptors}", package_name(db, krate)?))
+}
+
+fn package_name(db: &RootDatabase, krate: Crate) -> Option<String> {
+    let name = match krate.origin(db) {
+        CrateOrigin::Library { name, .. } | CrateOrigin::Rustc { name } => name,
+        CrateOrigin::Local { name: Some(name), .. } => name,
+        CrateOrigin::Local { name: None, .. } | CrateOrigin::Lang(_) => {
+            krate.display_name(db)?.canonical_name().to_owned()
+        }
+    };
+    Some(name)
+}
+
+/// Appends the descriptor of `name`, escaping it with backticks unless it only consists of
+/// identifier characters.
+fn push_descriptor(descriptors: &mut String, name: &str, suffix: &str) {
+    if name.chars().all(|c| c.is_alphanumeric() || matches!(c, '_' | '+' | '-' | '$')) {
+        descriptors.push_str(name);
+    } else {
+        format_to!(descriptors, "`{}`", name.replace('`', "``"));
+    }
+    descriptors.push_str(suffix);
+}
+
+fn documentation(db: &RootDatabase, def: Definition) -> Option<String> {
+    let docs = match def {
+        Definition::Module(it) => it.docs(db),
+        Definition::Function(it) => it.docs(db),
+        Definition::Adt(it) => it.docs(db),
+        Definition::Variant(it) => it.docs(db),
+        Definition::Const(it) => it.docs(db),
+        Definition::Static(it) => it.docs(db),
+        Definition::Trait(it) => it.docs(db),
+        Definition::TraitAlias(it) => it.docs(db),
+        Definition::TypeAlias(it) => it.docs(db),
+        Definition::Macro(it) => it.docs(db),
+        Definition::Field(it) => it.docs(db),
+        _ => None,
+    };
+    docs.map(|it| it.as_str().to_owned())
+}
+
+impl ScipIndex {
+    /// Encodes the index as a SCIP protobuf message. `relative_path` gives the paths of the files
+    /// relative to `project_root`, the URI of the root of the project, and the documents without
+    /// a path are left out.
+    pub fn encode_scip(
+        &self,
+        project_root: &str,
+        relative_path: impl Fn(FileId) -> Option<String>,
+    ) -> Vec<u8> {
+        let mut index = ProtoWriter::default();
+        index.message(1, |metadata| {
+            metadata.message(2, |tool_info| tool_info.string(1, "rust-analyzer"));
+            metadata.string(3, project_root);
+            // `UTF8` of `TextEncoding`.
+            metadata.varint_field(4, 1);
+        });
+        for document in &self.documents {
+            let Some(path) = relative_path(document.file_id) else { continue };
+            index.message(2, |writer| {
+                writer.string(1, &path);
+                for occurrence in &document.occurrences {
+                    writer.message(2, |writer| {
+                        let ScipOccurrence { start, end, .. } = occurrence;
+                        let range = if start.line == end.line {
+                            vec![start.line, start.col, end.col]
+                        } else {
+                            vec![start.line, start.col, end.line, end.col]
+                        };
+                        for it in range {
+                            writer.varint_field(1, it.into());
+                        }
+                        writer.string(2, &occurrence.symbol);
+                        if occurrence.is_definition {
+                            // `Definition` of `SymbolRole`.
+                            writer.varint_field(3, 1);
+                        }
+                    });
+                }
+                for symbol in &document.symbols {
+                    writer.message(3, |writer| {
+                        writer.string(1, &symbol.symbol);
+                        if let Some(docs) = &symbol.documentation {
+                            writer.string(3, docs);
+                        }
+                    });
+                }
+                writer.string(4, "rust");
+            });
+        }
+        index.buf
+    }
+
+    /// Dumps the index in the LSIF format, one JSON object per line, with the definitions and
+    /// references of every symbol and monikers for the global ones. `uri` gives the URIs of the
+    /// files, and the documents without a URI are left out.
+    pub fn to_lsif(&self, db: &RootDatabase, uri: impl Fn(FileId) -> Option<String>) -> String {
+        let mut lsif = LsifWriter::default();
+        lsif.vertex(
+            "metaData",
+            r#""version":"0.6.0","positionEncoding":"utf-16","toolInfo":{"name":"rust-analyzer"}"#,
+        );
+
+        struct SymbolResults {
+            definitions: FxHashMap<u32, Vec<u32>>,
+            references: FxHashMap<u32, Vec<u32>>,
+        }
+        let mut results: Vec<(String, u32, SymbolResults)> = Vec::new();
+        let mut result_sets: FxHashMap<String, usize> = FxHashMap::default();
+        let mut documents = Vec::new();
+        for document in &self.documents {
+            let Some(uri) = uri(document.file_id) else { continue };
+            let line_index = db.line_index(document.file_id);
+            let document_id = lsif
+                .vertex("document", &format!(r#""uri":{},"languageId":"rust""#, json_string(&uri)));
+            let mut ranges = Vec::new();
+            for occurrence in &document.occurrences {
+                let range_id = lsif.vertex(
+                    "range",
+                    &format!(
+                        r#""start":{},"end":{}"#,
+                        lsif_position(&line_index, occurrence.start),
+                        lsif_position(&line_index, occurrence.end),
+                    ),
+                );
+                ranges.push(range_id);
+                // Document-local symbols of different documents are different symbols.
+                let key = if is_local_symbol(&occurrence.symbol) {
+                    format!("{} {}", occurrence.symbol, document.file_id.0)
+                } else {
+                    occurrence.symbol.clone()
+                };
+                let idx = *result_sets.entry(key).or_insert_with(|| {
+                    let result_set = lsif.vertex("resultSet", "");
+                    results.push((
+                        occurrence.symbol.clone(),
+                        result_set,
+                        SymbolResults {
+                            definitions: FxHashMap::default(),
+                            references: FxHashMap::default(),
+                        },
+                    ));
+                    results.len() - 1
+                });
+                let (_, result_set, symbol_results) = &mut results[idx];
+                lsif.edge("next", range_id, &[*result_set], None);
+                let by_document = if occurrence.is_definition {
+                    &mut symbol_results.definitions
+                } else {
+                    &mut symbol_results.references
+                };
+                by_document.entry(document_id).or_default().push(range_id);
+            }
+            documents.push((document_id, ranges));
+        }
+        for (document_id, ranges) in documents {
+            lsif.edge("contains", document_id, &ranges, None);
+        }
+
+        for (symbol, result_set, symbol_results) in results {
+            if !is_local_symbol(&symbol) {
+                let moniker = lsif.vertex(
+                    "moniker",
+                    &format!(
+                        r#""scheme":"rust-analyzer","identifier":{},"kind":"export""#,
+                        json_string(&symbol)
+                    ),
+                );
+                lsif.edge("moniker", result_set, &[moniker], None);
+            }
+            if !symbol_results.definitions.is_empty() {
+                let result = lsif.vertex("definitionResult", "");
+                lsif.edge("textDocument/definition", result_set, &[result], None);
+                for (document_id, ranges) in sorted(&symbol_results.definitions) {
+                    lsif.edge(
+                        "item",
+                        result,
+                        ranges,
+                        Some(&format!(r#","document":{document_id}"#)),
+                    );
+                }
+            }
+            let result = lsif.vertex("referenceResult", "");
+            lsif.edge("textDocument/references", result_set, &[result], None);
+            for (property, ranges) in [
+                ("definitions", &symbol_results.definitions),
+                ("references", &symbol_results.references),
+            ] {
+                for (document_id, ranges) in sorted(ranges) {
+                    let extra = format!(r#","document":{document_id},"property":"{property}""#);
+                    lsif.edge("item", result, ranges, Some(&extra));
+                }
+            }
+        }
+        lsif.buf
+    }
+}
+
+fn is_local_symbol(symbol: &str) -> bool {
+    symbol.starts_with("local ")
+}
+
+fn sorted(ranges: &FxHashMap<u32, Vec<u32>>) -> Vec<(u32, &[u32])> {
+    let mut res: Vec<_> =
+        ranges.iter().map(|(&document, ranges)| (document, &ranges[..])).collect();
+    res.sort_unstable_by_key(|&(document, _)| document);
+    res
+}
+
+fn lsif_position(line_index: &LineIndex, line_col: LineCol) -> String {
+    let wide = line_index.to_wide(WideEncoding::Utf16, line_col);
+    let (line, character) = wide.map_or((line_col.line, line_col.col), |it| (it.line, it.col));
+    format!(r#"{{"line":{line},"character":{character}}}"#)
+}
+
+fn json_string(s: &str) -> String {
+    let mut res = String::with_capacity(s.len() + 2);
+    res.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            c if c.is_control() => format_to!(res, "\\u{:04x}", c as u32),
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    res
+}
+
+#[derive(Default)]
+struct LsifWriter {
+    buf: String,
+    next_id: u32,
+}
+
+impl LsifWriter {
+    fn vertex(&mut self, label: &str, fields: &str) -> u32 {
+        self.next_id += 1;
+        let separator = if fields.is_empty() { "" } else { "," };
+        let _ = writeln!(
+            self.buf,
+            r#"{{"id":{},"type":"vertex","label":"{label}"{separator}{fields}}}"#,
+            self.next_id
+        );
+        self.next_id
+    }
+
+    fn edge(&mut self, label: &str, out_v: u32, in_vs: &[u32], extra: Option<&str>) {
+        self.next_id += 1;
+        let in_vs = in_vs.iter().map(|it| it.to_string()).collect::<Vec<_>>().join(",");
+        let _ = writeln!(
+            self.buf,
+            r#"{{"id":{},"type":"edge","label":"{label}","outV":{out_v},"inVs":[{in_vs}]{}}}"#,
+            self.next_id,
+            extra.unwrap_or_default()
+        );
+    }
+}
+
+/// Writes the fields of a protobuf message.
+#[derive(Default)]
+struct ProtoWriter {
+    buf: Vec<u8>,
+}
+
+impl ProtoWriter {
+    fn varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.buf.push(value as u8 | 0x80);
+            value >>= 7;
+        }
+        self.buf.push(value as u8);
+    }
+
+    fn varint_field(&mut self, field: u32, value: u64) {
+        self.varint(u64::from(field) << 3);
+        self.varint(value);
+    }
+
+    fn bytes(&mut self, field: u32, bytes: &[u8]) {
+        self.varint(u64::from(field) << 3 | 2);
+        self.varint(bytes.len() as u64);
+        self.buf.extend_from_slice(bytes);
+    }
+
+    fn string(&mut self, field: u32, s: &str) {
+        self.bytes(field, s.as_bytes());
+    }
+
+    fn message(&mut self, field: u32, f: impl FnOnce(&mut ProtoWriter)) {
+        let mut message = ProtoWriter::default();
+        f(&mut message);
+        self.bytes(field, &message.buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::fixture::WithFixture;
+
+    use super::*;
+
+    #[test]
+    fn indexes_definitions_and_references() {
+        let (db, file_id) = RootDatabase::with_single_file(
+            r#"
+/// Docs.
+pub fn foo(x: u32) -> u32 { x }
+fn bar() { let y = foo(1); }
+"#,
+        );
+        let index = index_workspace(&db);
+        assert_eq!(index.documents.len(), 1);
+        let document = &index.documents[0];
+        assert_eq!(document.file_id, file_id);
+        let occurrences: Vec<_> = document
+            .occurrences
+            .iter()
+            .map(|it| {
+                let role = if it.is_definition { " definition" } else { "" };
+                format!("{}:{} {}{role}", it.start.line, it.start.col, it.symbol)
+            })
+            .collect();
+        assert_eq!(
+            occurrences,
+            [
+                "1:7 rust-analyzer cargo test . foo(). definition",
+                "1:11 local 0 definition",
+                "1:14 local 1",
+                "1:22 local 1",
+                "1:28 local 0",
+                "2:3 local 2 definition",
+                "2:15 local 3 definition",
+                "2:19 rust-analyzer cargo test . foo().",
+            ]
+        );
+        let docs = document.symbols[0].documentation.as_deref();
+        assert!(docs.map_or(false, |it| it.contains("Docs.")));
+
+        let scip = index.encode_scip("file:///", |_| Some("lib.rs".to_owned()));
+        assert!(scip.windows(6).any(|it| it == b"lib.rs"));
+        let lsif = index.to_lsif(&db, |_| Some("file:///lib.rs".to_owned()));
+        assert!(lsif.lines().all(|it| it.starts_with(r#"{"id":"#) && it.ends_with('}')));
+        assert_eq!(lsif.matches(r#""type":"vertex","label":"moniker""#).count(), 1);
+    }
+}
@@ -2,23 +2,33 @@
 //!
 //! It is based on the standard ide trick: first, we run a fast text search to
 //! get a super-set of matches. Then, we we confirm each match using precise
-//! name resolution.
+//! name resolution. The text search itself is skipped for files whose
+//! [`crate::text_index`] shows that they can't contain the name in question.
 
-use std::mem;
+use std::{cell::Cell, iter, mem, rc::Rc, time::Instant};
 
-use base_db::{FileId, FileRange, SourceDatabase, SourceDatabaseExt};
+use base_db::{FileId, FilePosition, FileRange, SourceDatabase, SourceDatabaseExt, VfsPath};
 use hir::{
-    AsAssocItem, DefWithBody, HasAttrs, HasSource, InFile, ModuleSource, Semantics, Visibility,
+    AsAssocItem, CfgAtom, CfgExpr, CfgOptions, DefWithBody, HasAttrs, HasSource, InFile,
+    ModuleSource, Semantics, Visibility,
 };
 use memchr::memmem::Finder;
 use nohash_hasher::IntMap;
 use once_cell::unsync::Lazy;
 use parser::SyntaxKind;
-use syntax::{ast, match_ast, AstNode, TextRange, TextSize};
+use rustc_hash::{FxHashMap, FxHashSet};
+use syntax::{
+    ast::{self, HasAttrs as _, HasName},
+    match_ast, AstNode, AstToken, NodeOrToken, SmolStr, SyntaxNode, SyntaxToken, TextRange,
+    TextSize, T,
+};
 use triomphe::Arc;
 
 use crate::{
-    defs::{Definition, NameClass, NameRefClass},
+    defs::{Definition, DefinitionId, NameClass, NameRefClass},
+    parallel::par_map,
+    rust_doc::{doc_attributes, extract_definitions_from_docs, resolve_doc_path_for_def},
+    text_index::TextIndexDatabase,
     traits::{as_trait_assoc_def, convert_to_def_in_trait},
     RootDatabase,
 };
@@ -26,6 +36,9 @@
 #[derive(Debug, Default, Clone)]
 pub struct UsageSearchResult {
     pub references: IntMap<FileId, Vec<FileReference>>,
+    /// Whether the search was cut short by [`FindUsages::with_limit`] or
+    /// [`FindUsages::with_deadline`], in which case there may be more references.
+    pub is_incomplete: bool,
 }
 
 impl UsageSearchResult {
@@ -46,6 +59,64 @@ pub fn file_ranges(&self) -> impl Iterator<Item = FileRange> + '_ {
             refs.iter().map(move |&FileReference { range, .. }| FileRange { file_id, range })
         })
     }
+
+    /// Orders the references by their relevance to `origin`, usually the position the search
+    /// was started from.
+    ///
+    /// References in the same function as `origin` come first, followed by the ones in the same
+    /// file, the same crate and everything else. Within those groups, writes come before reads
+    /// and imports come last, and references in macro expansions come after the ones written
+    /// out in the source.
+    pub fn ranked(
+        self,
+        sema: &Semantics<'_, RootDatabase>,
+        origin: FilePosition,
+    ) -> Vec<(FileId, FileReference)> {
+        let _p = profile::span("UsageSearchResult::ranked");
+        let origin_fn = sema
+            .parse(origin.file_id)
+            .syntax()
+            .token_at_offset(origin.offset)
+            .left_biased()
+            .and_then(|token| token.parent_ancestors().find_map(ast::Fn::cast))
+            .map(|it| it.syntax().text_range());
+        let origin_crate = sema.to_module_def(origin.file_id).map(|it| it.krate());
+        let mut crates = IntMap::default();
+        let mut is_same_crate = |file_id: FileId| {
+            *crates
+                .entry(file_id)
+                .or_insert_with(|| sema.to_module_def(file_id).map(|it| it.krate()) == origin_crate)
+        };
+
+        let mut res: Vec<_> = self
+            .references
+            .into_iter()
+            .flat_map(|(file_id, refs)| refs.into_iter().map(move |it| (file_id, it)))
+            .map(|(file_id, reference)| {
+                let proximity = if file_id != origin.file_id {
+                    if is_same_crate(file_id) {
+                        2
+                    } else {
+                        3
+                    }
+                } else if origin_fn.map_or(false, |it| it.contains_range(reference.range)) {
+                    0
+                } else {
+                    1
+                };
+                let category = match reference.category {
+                    Some(ReferenceCategory::Write) => 0,
+                    Some(ReferenceCategory::Read) | None => 1,
+                    Some(ReferenceCategory::Import) => 2,
+                };
+                let is_generated = sema.hir_file_for(reference.name.syntax()).is_macro();
+                let key = (proximity, category, is_generated, file_id, reference.range.start());
+                (key, (file_id, reference))
+            })
+            .collect();
+        res.sort_by_key(|(key, _)| *key);
+        res.into_iter().map(|(_, it)| it).collect()
+    }
 }
 
 impl IntoIterator for UsageSearchResult {
@@ -57,6 +128,24 @@ fn into_iter(self) -> Self::IntoIter {
     }
 }
 
+/// The number of usages of a definition, see [`FindUsages::count`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct UsageCounts {
+    pub per_file: IntMap<FileId, usize>,
+    pub total: usize,
+    /// Whether the search was cut short by [`FindUsages::with_limit`] or
+    /// [`FindUsages::with_deadline`], in which case there may be more usages.
+    pub is_incomplete: bool,
+}
+
+/// A reference found by [`FindUsages::all_under_cfgs`].
+#[derive(Debug, Clone)]
+pub struct CfgReference {
+    pub reference: FileReference,
+    /// The indices of the cfg sets the reference is active under, never empty.
+    pub active_under: Vec<usize>,
+}
+
 #[derive(Debug, Clone)]
 pub struct FileReference {
     /// The range of the reference in the original file
@@ -64,6 +153,47 @@ pub struct FileReference {
     /// The node of the reference in the (macro-)file
     pub name: ast::NameLike,
     pub category: Option<ReferenceCategory>,
+    /// Whether the reference is in code disabled by `#[cfg]`. Such references can't be resolved,
+    /// they are only found by name, see [`FindUsages::include_inactive_code`].
+    pub inactive: bool,
+    /// The alias the reference goes through, if it does not name the definition directly.
+    /// Only set when searching with [`FindUsages::include_aliases`].
+    pub alias: Option<ReferenceAlias>,
+    /// The item the reference is in, a function, impl, module and so on. Only set when
+    /// searching with [`FindUsages::include_containers`]. Interned, as many references share
+    /// their container.
+    pub container: Option<DefinitionId>,
+    /// The source line(s) around the reference. Only set when searching with
+    /// [`FindUsages::include_snippets`].
+    pub snippet: Option<UsageSnippet>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageSnippet {
+    /// The full lines of the original file the reference is on.
+    pub text: String,
+    /// The range of the reference, relative to the start of `text`.
+    pub range: TextRange,
+}
+
+impl UsageSnippet {
+    fn new(file_text: &str, range: TextRange) -> UsageSnippet {
+        let start = file_text[..usize::from(range.start())].rfind('\n').map_or(0, |it| it + 1);
+        let end = file_text[usize::from(range.end())..]
+            .find('\n')
+            .map_or(file_text.len(), |it| usize::from(range.end()) + it);
+        let text = file_text[start..end].trim_end_matches('\r').to_owned();
+        let start = TextSize::try_from(start).unwrap();
+        UsageSnippet { text, range: range - start }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReferenceAlias {
+    /// A renaming import or re-export, `use foo::Bar as Baz;`. This is the range of `Baz`.
+    Rename(FileRange),
+    /// A type alias, `type Baz = Bar;`.
+    TypeAlias(hir::TypeAlias),
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -78,6 +208,52 @@ pub enum ReferenceCategory {
     // DocComment
 }
 
+/// The syntactic position a field is used in, see [`FindUsages::field_usages`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FieldUsageKind {
+    /// `foo.field`, this includes writes like `foo.field = 0`.
+    Read,
+    /// `Foo { field: 0 }` or `Foo { field }`.
+    Construct,
+    /// `let Foo { field, .. } = foo;`
+    Pattern,
+    /// `Foo { ..base }`, the field is implicitly moved or copied out of `base`.
+    StructUpdate,
+}
+
+#[derive(Debug, Clone)]
+pub struct FieldUsage {
+    /// The range of the usage in the original file. For [`FieldUsageKind::StructUpdate`] this
+    /// is the range of the `..base` spread.
+    pub range: TextRange,
+    pub kind: FieldUsageKind,
+}
+
+/// Whether an enum variant is created or matched on, see [`FindUsages::variant_usages`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum VariantUsageKind {
+    /// `E::V`, `E::V(0)`, `E::V { field: 0 }` or `E::V` used as a constructor function.
+    Construct,
+    /// `E::V`, `E::V(_)` or `E::V { .. }` in a pattern.
+    Pattern,
+}
+
+#[derive(Debug, Clone)]
+pub struct VariantUsage {
+    pub range: TextRange,
+    pub kind: VariantUsageKind,
+}
+
+/// An occurrence of a derive helper attribute, see [`FindUsages::derive_helper_usages`].
+#[derive(Debug, Clone)]
+pub struct DeriveHelperUsage {
+    /// The range of the whole attribute in the original file, `#[helper(arg)]`.
+    pub range: TextRange,
+    /// The item whose derive the attribute is passed to, the attribute itself may also be on a
+    /// field or variant of it.
+    pub adt: hir::Adt,
+}
+
 /// Generally, `search_scope` returns files that might contain references for the element.
 /// For `pub(crate)` things it's a crate, for `pub` things it's a crate and dependant crates.
 /// In some cases, the location of the references is known to within a `TextRange`,
@@ -85,15 +261,17 @@ pub enum ReferenceCategory {
 #[derive(Clone, Debug)]
 pub struct SearchScope {
     entries: IntMap<FileId, Option<TextRange>>,
+    /// whether to skip test code in the files of the scope, see [`SearchScope::exclude_tests`]
+    exclude_tests: bool,
 }
 
 impl SearchScope {
     fn new(entries: IntMap<FileId, Option<TextRange>>) -> SearchScope {
-        SearchScope { entries }
+        SearchScope { entries, exclude_tests: false }
     }
 
     /// Build a search scope spanning the entire crate graph of files.
-    fn crate_graph(db: &RootDatabase) -> SearchScope {
+    pub(crate) fn crate_graph(db: &RootDatabase) -> SearchScope {
         let mut entries = IntMap::default();
 
         let graph = db.crate_graph();
@@ -103,7 +281,7 @@ fn crate_graph(db: &RootDatabase) -> SearchScope {
             let source_root = db.source_root(source_root_id);
             entries.extend(source_root.iter().map(|id| (id, None)));
         }
-        SearchScope { entries }
+        SearchScope::new(entries)
     }
 
     /// Build a search scope spanning all the reverse dependencies of the given crate.
@@ -115,7 +293,7 @@ fn reverse_dependencies(db: &RootDatabase, of: hir::Crate) -> SearchScope {
             let source_root = db.source_root(source_root_id);
             entries.extend(source_root.iter().map(|id| (id, None)));
         }
-        SearchScope { entries }
+        SearchScope::new(entries)
     }
 
     /// Build a search scope spanning the given crate.
@@ -123,7 +301,7 @@ fn krate(db: &RootDatabase, of: hir::Crate) -> SearchScope {
         let root_file = of.root_file(db);
         let source_root_id = db.file_source_root(root_file);
         let source_root = db.source_root(source_root_id);
-        SearchScope { entries: source_root.iter().map(|id| (id, None)).collect() }
+        SearchScope::new(source_root.iter().map(|id| (id, None)).collect())
     }
 
     /// Build a search scope spanning the given module and all its submodules.
@@ -156,7 +334,7 @@ fn module_and_children(db: &RootDatabase, module: hir::Module) -> SearchScope {
             }
             to_visit.extend(module.children(db));
         }
-        SearchScope { entries }
+        SearchScope::new(entries)
     }
 
     /// Build an empty search scope.
@@ -201,8 +379,49 @@ pub fn intersection(&self, other: &SearchScope) -> SearchScope {
             })
             .collect();
 
-        SearchScope::new(res)
+        SearchScope { entries: res, exclude_tests: self.exclude_tests || other.exclude_tests }
+    }
+
+    /// Remove test code from the search scope.
+    ///
+    /// This drops the files of the crates built as integration tests, benchmarks or examples
+    /// right away. Items marked with `#[test]` or `#[cfg(test)]`, including out-of-line modules,
+    /// are skipped by [`FindUsages`] when searching this scope.
+    pub fn exclude_tests(mut self, db: &RootDatabase) -> SearchScope {
+        let sema = Semantics::new(db);
+        let mut test_targets = FxHashMap::default();
+        self.entries.retain(|&file_id, _| {
+            let mut modules = sema.to_module_defs(file_id).peekable();
+            if modules.peek().is_none() {
+                return true;
+            }
+            !modules.all(|module| {
+                let krate = module.krate();
+                *test_targets.entry(krate).or_insert_with(|| is_test_target(db, krate))
+            })
+        });
+        self.exclude_tests = true;
+        self
+    }
+}
+
+/// Whether the crate is the target of an integration test, a benchmark or an example, whose root
+/// is `{tests,benches,examples}/<name>.rs` or `{tests,benches,examples}/<name>/main.rs`.
+fn is_test_target(db: &RootDatabase, krate: hir::Crate) -> bool {
+    let root_file = krate.root_file(db);
+    let source_root = db.source_root(db.file_source_root(root_file));
+    let Some(path) = source_root.path_for_file(&root_file) else { return false };
+    let is_target_dir = |dir: Option<VfsPath>| {
+        dir.as_ref()
+            .and_then(VfsPath::name_and_extension)
+            .map_or(false, |it| matches!(it, ("tests" | "benches" | "examples", None)))
+    };
+    let dir = path.parent();
+    if is_target_dir(dir.clone()) {
+        return true;
     }
+    path.name_and_extension() == Some(("main", Some("rs")))
+        && is_target_dir(dir.as_ref().and_then(VfsPath::parent))
 }
 
 impl IntoIterator for SearchScope {
@@ -215,7 +434,7 @@ fn into_iter(self) -> Self::IntoIter {
 }
 
 impl Definition {
-    fn search_scope(&self, db: &RootDatabase) -> SearchScope {
+    pub(crate) fn search_scope(&self, db: &RootDatabase) -> SearchScope {
         let _p = profile::span("search_scope");
 
         if let Definition::BuiltinType(_) = self {
@@ -323,6 +542,14 @@ pub fn usages<'a>(self, sema: &'a Semantics<'_, RootDatabase>) -> FindUsages<'a>
             scope: None,
             include_self_kw_refs: None,
             search_self_mod: false,
+            include_inactive_code: false,
+            include_aliases: false,
+            limit: None,
+            deadline: None,
+            include_containers: false,
+            include_snippets: false,
+            search_dollar_crate_paths: true,
+            skipped_files: Rc::default(),
         }
     }
 }
@@ -338,6 +565,24 @@ pub struct FindUsages<'a> {
     include_self_kw_refs: Option<hir::Type>,
     /// whether to search for the `self` module
     search_self_mod: bool,
+    /// whether to report name matches in code disabled by `#[cfg]`
+    include_inactive_code: bool,
+    /// whether to follow renaming imports and type aliases
+    include_aliases: bool,
+    /// the maximum number of references to collect
+    limit: Option<usize>,
+    /// the point in time after which no more files are searched
+    deadline: Option<Instant>,
+    /// whether to determine the item enclosing each reference
+    include_containers: bool,
+    /// whether to extract the source lines around each reference
+    include_snippets: bool,
+    /// whether to search for `$crate::name!` in other macros, this is disabled when searching for
+    /// the calls of those macros
+    search_dollar_crate_paths: bool,
+    /// whether files were left unsearched because the search was stopped, this is shared with
+    /// the copies made for the search
+    skipped_files: Rc<Cell<bool>>,
 }
 
 impl<'a> FindUsages<'a> {
@@ -348,6 +593,59 @@ pub fn include_self_refs(mut self) -> FindUsages<'a> {
         self
     }
 
+    /// Also report references in code that is disabled by `#[cfg]` attributes.
+    ///
+    /// Disabled code is never lowered, so these references are found purely by name and
+    /// are not guaranteed to refer to the definition. They are flagged with
+    /// [`FileReference::inactive`].
+    pub fn include_inactive_code(mut self) -> FindUsages<'a> {
+        self.include_inactive_code = true;
+        self
+    }
+
+    /// Also report usages that go through renaming imports and re-exports (`pub use foo::Bar as
+    /// Baz;`) or type aliases (`type Baz = Bar;`) of the definition, transitively.
+    ///
+    /// Such references are tagged with the alias they were found through, see
+    /// [`FileReference::alias`].
+    pub fn include_aliases(mut self) -> FindUsages<'a> {
+        self.include_aliases = true;
+        self
+    }
+
+    /// Determine the item each reference is in, see [`FileReference::container`].
+    ///
+    /// This allows grouping the references by function, impl or module.
+    pub fn include_containers(mut self) -> FindUsages<'a> {
+        self.include_containers = true;
+        self
+    }
+
+    /// Extract the lines the references are on, see [`FileReference::snippet`].
+    pub fn include_snippets(mut self) -> FindUsages<'a> {
+        self.include_snippets = true;
+        self
+    }
+
+    /// Stop the search after `limit` references have been found.
+    ///
+    /// [`FindUsages::all`] then returns the references found so far and sets
+    /// [`UsageSearchResult::is_incomplete`].
+    pub fn with_limit(mut self, limit: usize) -> FindUsages<'a> {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Stop the search once `deadline` has passed.
+    ///
+    /// The deadline is checked before every file that is searched, so a search can overrun it
+    /// by the time it takes to search a single file. [`FindUsages::all`] then returns the
+    /// references found so far and sets [`UsageSearchResult::is_incomplete`].
+    pub fn with_deadline(mut self, deadline: Instant) -> FindUsages<'a> {
+        self.deadline = Some(deadline);
+        self
+    }
+
     /// Limit the search to a given [`SearchScope`].
     pub fn in_scope(self, scope: SearchScope) -> FindUsages<'a> {
         self.set_scope(Some(scope))
@@ -371,31 +669,371 @@ pub fn at_least_one(&self) -> bool {
 
     pub fn all(self) -> UsageSearchResult {
         let mut res = UsageSearchResult::default();
+        let mut n_references = 0;
         self.search(&mut |file_id, reference| {
+            if self.limit.map_or(false, |limit| n_references >= limit) {
+                res.is_incomplete = true;
+                return true;
+            }
+            n_references += 1;
             res.references.entry(file_id).or_default().push(reference);
             false
         });
+        res.is_incomplete |= self.skipped_files.get();
         res
     }
 
-    fn search(&self, sink: &mut dyn FnMut(FileId, FileReference) -> bool) {
-        let _p = profile::span("FindUsages:search");
+    /// Counts the usages without collecting them, this is cheaper than [`FindUsages::all`] if
+    /// only the number of usages is needed.
+    pub fn count(self) -> UsageCounts {
+        let mut res = UsageCounts::default();
+        self.search(&mut |file_id, _| {
+            if self.limit.map_or(false, |limit| res.total >= limit) {
+                res.is_incomplete = true;
+                return true;
+            }
+            res.total += 1;
+            *res.per_file.entry(file_id).or_default() += 1;
+            false
+        });
+        res.is_incomplete |= self.skipped_files.get();
+        res
+    }
+
+    /// Searches for the usages under several cfg sets at once, for example the cfg options of
+    /// the crate with different combinations of features enabled.
+    ///
+    /// Returns the union of the references found under each set, every reference is annotated
+    /// with the sets it is active under. Only one configuration of the crate graph is ever
+    /// lowered, so references in code that is disabled in it are found by name, see
+    /// [`FindUsages::include_inactive_code`].
+    // FIXME: References in files whose `mod` declaration is disabled are dropped, the cfg of the
+    // declaration should be checked instead.
+    pub fn all_under_cfgs(self, cfgs: &[CfgOptions]) -> IntMap<FileId, Vec<CfgReference>> {
+        let mut res: IntMap<FileId, Vec<CfgReference>> = IntMap::default();
+        let this = FindUsages { include_inactive_code: true, ..self };
+        this.search(&mut |file_id, reference| {
+            let node = reference.name.syntax();
+            let active_under = cfgs
+                .iter()
+                .enumerate()
+                .filter(|(_, cfg)| {
+                    // Files outside of the module tree can't be active under any set.
+                    let is_outside_module_tree =
+                        reference.inactive && self.sema.to_module_def(file_id).is_none();
+                    !(is_outside_module_tree || is_cfg_disabled(cfg, node))
+                })
+                .map(|(idx, _)| idx)
+                .collect::<Vec<_>>();
+            if !active_under.is_empty() {
+                res.entry(file_id).or_default().push(CfgReference { reference, active_under });
+            }
+            false
+        });
+        res
+    }
+
+    /// Searches for the usages of an enum variant, split into the places the variant is
+    /// constructed at and the patterns matching on it.
+    ///
+    /// Other references, like imports, are not reported. Returns an empty result if the
+    /// definition is not a [`Definition::Variant`].
+    pub fn variant_usages(self) -> IntMap<FileId, Vec<VariantUsage>> {
+        let mut res: IntMap<FileId, Vec<VariantUsage>> = IntMap::default();
+        if !matches!(self.def, Definition::Variant(_)) {
+            return res;
+        }
+        self.search(&mut |file_id, reference| {
+            if let Some(kind) = variant_usage_kind(&reference.name) {
+                res.entry(file_id).or_default().push(VariantUsage { range: reference.range, kind });
+            }
+            false
+        });
+        res
+    }
+
+    /// Searches for the attributes using a derive helper, like `#[serde(rename = "foo")]` for the
+    /// `serde` helper of `Serialize`, together with the item they belong to.
+    ///
+    /// An attribute is reported once, even if it is passed to several derives. Returns an empty
+    /// result if the definition is not a [`Definition::DeriveHelper`].
+    pub fn derive_helper_usages(self) -> IntMap<FileId, Vec<DeriveHelperUsage>> {
+        let mut res: IntMap<FileId, Vec<DeriveHelperUsage>> = IntMap::default();
+        if !matches!(self.def, Definition::DeriveHelper(_)) {
+            return res;
+        }
         let sema = self.sema;
+        self.search(&mut |file_id, reference| {
+            let Some(attr) = reference.name.syntax().ancestors().find_map(ast::Attr::cast) else {
+                return false;
+            };
+            let Some(adt) =
+                attr.syntax().ancestors().find_map(ast::Adt::cast).and_then(|it| sema.to_def(&it))
+            else {
+                return false;
+            };
+            // References in derive expansions map back to the attribute they were copied from.
+            let range = sema.original_range(attr.syntax()).range;
+            let usages = res.entry(file_id).or_default();
+            if !usages.iter().any(|it| it.range == range) {
+                usages.push(DeriveHelperUsage { range, adt });
+            }
+            false
+        });
+        res
+    }
+
+    /// Searches for the usages of a field, classified by the position they appear in.
+    ///
+    /// On top of the references found by [`FindUsages::all`], this also reports struct update
+    /// expressions `Foo { ..base }` that implicitly cover the field. Returns an empty result if
+    /// the definition is not a [`Definition::Field`].
+    pub fn field_usages(self) -> IntMap<FileId, Vec<FieldUsage>> {
+        let mut res: IntMap<FileId, Vec<FieldUsage>> = IntMap::default();
+        let Definition::Field(field) = self.def else { return res };
 
-        let search_scope = {
-            // FIXME: Is the trait scope needed for trait impl assoc items?
-            let base =
-                as_trait_assoc_def(sema.db, self.def).unwrap_or(self.def).search_scope(sema.db);
-            match &self.scope {
-                None => base,
-                Some(scope) => base.intersection(scope),
+        self.search(&mut |file_id, reference| {
+            if let Some(kind) = field_usage_kind(&reference.name) {
+                res.entry(file_id).or_default().push(FieldUsage { range: reference.range, kind });
             }
+            false
+        });
+
+        // Struct update syntax is only allowed for structs, not for enum variants or unions.
+        let hir::VariantDef::Struct(strukt) = field.parent_def(self.sema.db) else { return res };
+        // FIXME: This misses struct update expressions that name the struct through a type alias.
+        let struct_usages = Definition::Adt(hir::Adt::Struct(strukt))
+            .usages(self.sema)
+            .include_self_refs()
+            .set_scope(self.scope.clone())
+            .all();
+        let field_name = field.name(self.sema.db).to_smol_str();
+        for (file_id, references) in struct_usages {
+            for reference in references {
+                let Some(name_ref) = reference.name.as_name_ref() else { continue };
+                let Some(record_expr) = name_ref
+                    .syntax()
+                    .ancestors()
+                    .find_map(ast::Path::cast)
+                    .map(|path| path.top_path())
+                    .and_then(|path| path.syntax().parent())
+                    .and_then(ast::RecordExpr::cast)
+                else {
+                    continue;
+                };
+                let Some(field_list) = record_expr.record_expr_field_list() else { continue };
+                let Some(spread) = field_list.spread() else { continue };
+                let mentions_field = field_list.fields().any(|it| {
+                    it.field_name().map_or(false, |name| {
+                        name.text().trim_start_matches("r#") == field_name.as_str()
+                    })
+                });
+                if mentions_field {
+                    continue;
+                }
+                let spread_start = field_list
+                    .dotdot_token()
+                    .map_or(spread.syntax().text_range().start(), |it| it.text_range().start());
+                let spread_range = TextRange::new(spread_start, spread.syntax().text_range().end());
+                // Inside of macro calls we can't point at the spread, so point at the path instead.
+                let range = if self.sema.hir_file_for(record_expr.syntax()).is_macro() {
+                    reference.range
+                } else {
+                    spread_range
+                };
+                res.entry(file_id)
+                    .or_default()
+                    .push(FieldUsage { range, kind: FieldUsageKind::StructUpdate });
+            }
+        }
+        res
+    }
+
+    /// Searches for intra-doc links resolving to the definition, like `[Foo]` or
+    /// ``[`Foo::bar`]``, returning the ranges of the links.
+    ///
+    /// Only documentation is looked at, code references are not reported. Links in
+    /// documentation that is generated by macros are not found.
+    pub fn doc_link_usages(self) -> IntMap<FileId, Vec<TextRange>> {
+        let _p = profile::span("FindUsages:doc_link_usages");
+        let mut res: IntMap<FileId, Vec<TextRange>> = IntMap::default();
+        let sema = self.sema;
+        let Some(name) = self.def.name(sema.db).map(|it| it.unescaped().to_smol_str()) else {
+            return res;
+        };
+        let finder = Finder::new(name.as_str());
+
+        // The text index only knows about identifiers, so it can't be used to skip files here.
+        for (file_id, search_range) in self.search_scope() {
+            if self.is_past_deadline() {
+                break;
+            }
+            let text = sema.db.file_text(file_id);
+            let search_range =
+                search_range.unwrap_or_else(|| TextRange::up_to(TextSize::of(&*text)));
+            let tree = Lazy::new(|| sema.parse(file_id));
+            let mut seen_owners = FxHashSet::default();
+            let mut ranges = Vec::new();
+            for idx in finder.find_iter(text.as_bytes()) {
+                let offset: TextSize = idx.try_into().unwrap();
+                if !search_range.contains(offset) {
+                    continue;
+                }
+                let Some(token) = tree.syntax().token_at_offset(offset).next() else { continue };
+                if !is_doc_token(&token) {
+                    continue;
+                }
+                let Some((owner, (attrs, owner_def))) = token
+                    .parent_ancestors()
+                    .find_map(|it| Some((it.clone(), doc_attributes(sema, &it)?)))
+                else {
+                    continue;
+                };
+                if !seen_owners.insert(owner.text_range()) {
+                    continue;
+                }
+                let Some((docs, doc_mapping)) = attrs.docs_with_rangemap(sema.db) else { continue };
+                for (range, link, ns) in extract_definitions_from_docs(&docs) {
+                    let Some(mapped) = doc_mapping.map(range) else { continue };
+                    if mapped.file_id != file_id.into()
+                        || !search_range.contains_range(mapped.value)
+                    {
+                        continue;
+                    }
+                    if resolve_doc_path_for_def(sema.db, owner_def, &link, ns) == Some(self.def) {
+                        ranges.push(mapped.value);
+                    }
+                }
+            }
+            if !ranges.is_empty() {
+                ranges.sort_by_key(|it| it.start());
+                ranges.dedup();
+                res.insert(file_id, ranges);
+            }
+        }
+        res
+    }
+
+    /// Searches for the name of the definition as a whole word in comments, including doc
+    /// comments, and string literals, returning the ranges of the words.
+    ///
+    /// These are not references, so there is no telling whether they actually mention the
+    /// definition or something else that happens to share its name.
+    pub fn textual_occurrences(self) -> IntMap<FileId, Vec<TextRange>> {
+        let _p = profile::span("FindUsages:textual_occurrences");
+        let mut res: IntMap<FileId, Vec<TextRange>> = IntMap::default();
+        let sema = self.sema;
+        let Some(name) = self.def.name(sema.db).map(|it| it.unescaped().to_smol_str()) else {
+            return res;
         };
+        let finder = Finder::new(name.as_str());
+        let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+
+        // The text index only knows about identifiers, so it can't be used to skip files here.
+        for (file_id, search_range) in self.search_scope() {
+            if self.is_past_deadline() {
+                break;
+            }
+            let text = sema.db.file_text(file_id);
+            let search_range =
+                search_range.unwrap_or_else(|| TextRange::up_to(TextSize::of(&*text)));
+            let tree = Lazy::new(|| sema.parse(file_id));
+            let mut ranges = Vec::new();
+            for idx in finder.find_iter(text.as_bytes()) {
+                let range = TextRange::at(idx.try_into().unwrap(), TextSize::of(name.as_str()));
+                if !search_range.contains_range(range)
+                    || text[..idx].chars().next_back().map_or(false, is_word_char)
+                    || text[idx + name.len()..].chars().next().map_or(false, is_word_char)
+                {
+                    continue;
+                }
+                let NodeOrToken::Token(token) = tree.syntax().covering_element(range) else {
+                    continue;
+                };
+                if matches!(
+                    token.kind(),
+                    SyntaxKind::COMMENT
+                        | SyntaxKind::STRING
+                        | SyntaxKind::BYTE_STRING
+                        | SyntaxKind::C_STRING
+                ) {
+                    ranges.push(range);
+                }
+            }
+            if !ranges.is_empty() {
+                res.insert(file_id, ranges);
+            }
+        }
+        res
+    }
+
+    fn is_past_deadline(&self) -> bool {
+        self.deadline.map_or(false, |deadline| Instant::now() >= deadline)
+    }
+
+    /// Checks [`FindUsages::is_past_deadline`] before searching another file, recording that the file
+    /// is skipped if the search stops.
+    fn should_skip_file(&self) -> bool {
+        let stop = self.is_past_deadline();
+        if stop {
+            self.skipped_files.set(true);
+        }
+        stop
+    }
+
+    fn search_scope(&self) -> SearchScope {
+        // FIXME: Is the trait scope needed for trait impl assoc items?
+        let base = as_trait_assoc_def(self.sema.db, self.def)
+            .unwrap_or(self.def)
+            .search_scope(self.sema.db);
+        match &self.scope {
+            None => base,
+            Some(scope) => base.intersection(scope),
+        }
+    }
+
+    fn search(&self, sink: &mut dyn FnMut(FileId, FileReference) -> bool) {
+        if self.scope.as_ref().map_or(false, |scope| scope.exclude_tests) {
+            let mut this = self.clone();
+            if let Some(scope) = &mut this.scope {
+                scope.exclude_tests = false;
+            }
+            return this.search(&mut |file_id, reference| {
+                if is_test_code(self.sema, reference.name.syntax()) {
+                    return false;
+                }
+                sink(file_id, reference)
+            });
+        }
+        if self.include_snippets {
+            let this = FindUsages { include_snippets: false, ..self.clone() };
+            return this.search(&mut |file_id, mut reference| {
+                let text = self.sema.db.file_text(file_id);
+                reference.snippet = Some(UsageSnippet::new(&text, reference.range));
+                sink(file_id, reference)
+            });
+        }
+        if self.include_containers {
+            let this = FindUsages { include_containers: false, ..self.clone() };
+            return this.search(&mut |file_id, mut reference| {
+                reference.container = enclosing_item(self.sema, reference.name.syntax())
+                    .map(|it| it.intern(self.sema.db));
+                sink(file_id, reference)
+            });
+        }
+        if self.include_aliases {
+            return self.search_with_aliases(sink);
+        }
+        let _p = profile::span("FindUsages:search");
+        let sema = self.sema;
+
+        let search_scope = self.search_scope();
 
         let name = match self.def {
             // special case crate modules as these do not have a proper name
             Definition::Module(module) if module.is_crate_root() => {
-                // FIXME: This assumes the crate name is always equal to its display name when it really isn't
+                // FIXME: This assumes the crate name is always equal to its display name when it really isn't,
+                // see `crate_usages` for a search that handles this properly
                 module
                     .krate()
                     .display_name(self.sema.db)
@@ -469,11 +1107,42 @@ fn scope_files<'a>(
                 })
         };
 
+        // Lexing the files for their text indices is most of the work of searching the files that
+        // don't mention the name, so the missing indices are computed in parallel first. Searches
+        // with a deadline skip this, as they may only get to look at a few files.
+        if self.deadline.is_none() {
+            let files: Vec<_> = search_scope.entries.keys().copied().collect();
+            par_map(sema.db, files, |db, file_id| {
+                db.file_text_index(file_id);
+            });
+        }
+
         for (text, file_id, search_range) in scope_files(sema, &search_scope) {
+            if self.should_skip_file() {
+                return;
+            }
             let tree = Lazy::new(move || sema.parse(file_id).syntax().clone());
+            let text_index = sema.db.file_text_index(file_id);
+
+            // Files that are not part of any module tree are disabled as a whole.
+            let cfg_options =
+                Lazy::new(|| sema.to_module_def(file_id).map(|module| module.krate().cfg(sema.db)));
+            let is_inactive = |node: &SyntaxNode| {
+                self.include_inactive_code
+                    && cfg_options.as_ref().map_or(true, |cfg| is_cfg_disabled(cfg, node))
+            };
+            let sink = &mut |file_id, mut reference: FileReference| {
+                reference.inactive = is_inactive(reference.name.syntax());
+                sink(file_id, reference)
+            };
 
             // Search for occurrences of the items name
-            for offset in match_indices(&text, finder, search_range) {
+            let name_offsets = text_index
+                .may_contain(name)
+                .then(|| match_indices(&text, finder, search_range))
+                .into_iter()
+                .flatten();
+            for offset in name_offsets {
                 for name in find_nodes(name, &tree, offset).filter_map(ast::NameLike::cast) {
                     if match name {
                         ast::NameLike::NameRef(name_ref) => self.found_name_ref(&name_ref, sink),
@@ -483,9 +1152,41 @@ fn scope_files<'a>(
                         return;
                     }
                 }
+                if !self.include_inactive_code {
+                    continue;
+                }
+                // Code disabled by `#[cfg]` is not lowered, so references in there that don't
+                // resolve are reported by name.
+                let name_ref = tree
+                    .token_at_offset(offset)
+                    .find(|it| it.text().trim_start_matches("r#") == name)
+                    .and_then(|token| token.parent())
+                    .and_then(ast::NameRef::cast)
+                    .filter(|name_ref| {
+                        is_inactive(name_ref.syntax())
+                            && NameRefClass::classify(sema, name_ref).is_none()
+                    });
+                if let Some(name_ref) = name_ref {
+                    let reference = FileReference {
+                        range: name_ref.syntax().text_range(),
+                        category: is_name_ref_in_import(&name_ref)
+                            .then_some(ReferenceCategory::Import),
+                        name: ast::NameLike::NameRef(name_ref),
+                        inactive: true,
+                        alias: None,
+                        container: None,
+                        snippet: None,
+                    };
+                    if sink(file_id, reference) {
+                        return;
+                    }
+                }
             }
             // Search for occurrences of the `Self` referring to our type
             if let Some((self_ty, finder)) = &include_self_kw_refs {
+                if !text_index.may_contain("Self") {
+                    continue;
+                }
                 for offset in match_indices(&text, finder, search_range) {
                     for name_ref in find_nodes("Self", &tree, offset).filter_map(ast::NameRef::cast)
                     {
@@ -497,6 +1198,40 @@ fn scope_files<'a>(
             }
         }
 
+        // Search for `$crate::name!` in the bodies of the macros of our crate. These paths are only
+        // resolved in the expansions of the macros, so we need a call of the macro containing them.
+        if let Some(krate) = self.dollar_crate_krate() {
+            let scope = search_scope.intersection(&SearchScope::krate(sema.db, krate));
+            for (text, file_id, search_range) in scope_files(sema, &scope) {
+                if !sema.db.file_text_index(file_id).may_contain(name) {
+                    continue;
+                }
+                let tree = sema.parse(file_id);
+                for offset in match_indices(&text, finder, search_range) {
+                    let token = tree.syntax().token_at_offset(offset).find(|it| it.text() == name);
+                    let Some(token) = token.filter(is_dollar_crate_macro_path) else { continue };
+                    let Some(macro_) = token.parent_ancestors().find_map(ast::Macro::cast) else {
+                        continue;
+                    };
+                    let Some(name_ref) = self.expanded_dollar_crate_path(&macro_, name) else {
+                        continue;
+                    };
+                    let reference = FileReference {
+                        range: token.text_range(),
+                        name: ast::NameLike::NameRef(name_ref),
+                        category: None,
+                        inactive: false,
+                        alias: None,
+                        container: None,
+                        snippet: None,
+                    };
+                    if sink(file_id, reference) {
+                        return;
+                    }
+                }
+            }
+        }
+
         // Search for `super` and `crate` resolving to our module
         if let Definition::Module(module) = self.def {
             let scope =
@@ -506,6 +1241,9 @@ fn scope_files<'a>(
             let finder = &Finder::new("super");
 
             for (text, file_id, search_range) in scope_files(sema, &scope) {
+                if self.should_skip_file() {
+                    return;
+                }
                 let tree = Lazy::new(move || sema.parse(file_id).syntax().clone());
 
                 for offset in match_indices(&text, finder, search_range) {
@@ -589,6 +1327,10 @@ fn found_self_ty_name_ref(
                     range,
                     name: ast::NameLike::NameRef(name_ref.clone()),
                     category: None,
+                    inactive: false,
+                    alias: None,
+                    container: None,
+                    snippet: None,
                 };
                 sink(file_id, reference)
             }
@@ -608,6 +1350,10 @@ fn found_self_module_name_ref(
                     range,
                     name: ast::NameLike::NameRef(name_ref.clone()),
                     category: is_name_ref_in_import(name_ref).then_some(ReferenceCategory::Import),
+                    inactive: false,
+                    alias: None,
+                    container: None,
+                    snippet: None,
                 };
                 sink(file_id, reference)
             }
@@ -627,6 +1373,10 @@ fn found_lifetime(
                     range,
                     name: ast::NameLike::Lifetime(lifetime.clone()),
                     category: None,
+                    inactive: false,
+                    alias: None,
+                    container: None,
+                    snippet: None,
                 };
                 sink(file_id, reference)
             }
@@ -634,6 +1384,137 @@ fn found_lifetime(
         }
     }
 
+    /// The crate of our definition if it is a `#[macro_export]`ed `macro_rules!` macro.
+    fn dollar_crate_krate(&self) -> Option<hir::Crate> {
+        let db = self.sema.db;
+        match self.def {
+            Definition::Macro(macro_)
+                if self.search_dollar_crate_paths
+                    && macro_.kind(db) == hir::MacroKind::Declarative
+                    && macro_.attrs(db).by_key("macro_export").exists() =>
+            {
+                Some(macro_.module(db).krate())
+            }
+            _ => None,
+        }
+    }
+
+    /// Finds the path `$crate::name!` of `macro_` in one of its expansions.
+    fn expanded_dollar_crate_path(&self, macro_: &ast::Macro, name: &str) -> Option<ast::NameRef> {
+        let sema = self.sema;
+        let mut calls = Definition::Macro(sema.to_def(macro_)?).usages(sema);
+        calls.search_dollar_crate_paths = false;
+        let calls = calls.all();
+        let mut expansions = calls
+            .iter()
+            .flat_map(|(_, refs)| refs)
+            .filter_map(|it| it.name.syntax().ancestors().find_map(ast::MacroCall::cast))
+            .filter_map(|call| sema.expand(&call));
+        expansions.find_map(|expansion| {
+            expansion.descendants().filter_map(ast::NameRef::cast).find(|name_ref| {
+                name_ref.text() == name
+                    && matches!(
+                        NameRefClass::classify(sema, name_ref),
+                        Some(NameRefClass::Definition(def)) if def == self.def
+                    )
+            })
+        })
+    }
+
+    fn search_with_aliases(&self, sink: &mut dyn FnMut(FileId, FileReference) -> bool) {
+        let _p = profile::span("FindUsages:search_with_aliases");
+        let sema = self.sema;
+        let direct = FindUsages { include_aliases: false, ..self.clone() };
+
+        let mut renames = Vec::new();
+        let mut type_aliases = Vec::new();
+        let mut collect_aliases = |reference: &FileReference, renames: &mut Vec<ast::Name>| {
+            let Some(name_ref) = reference.name.as_name_ref() else { return };
+            if let Some(rename) = renaming_use_tree(name_ref) {
+                renames.push(rename);
+            } else if let Some(type_alias) = aliasing_type_alias(name_ref) {
+                type_aliases.extend(sema.to_def(&type_alias));
+            }
+        };
+
+        let mut stop = false;
+        direct.search(&mut |file_id, reference| {
+            collect_aliases(&reference, &mut renames);
+            stop = sink(file_id, reference);
+            stop
+        });
+        if stop {
+            return;
+        }
+
+        // Renaming imports resolve to our definition, so usages of the new name are found with
+        // a plain text search for it.
+        let search_scope = self.search_scope();
+        let mut seen = FxHashSet::default();
+        while let Some(rename) = renames.pop() {
+            let alias_range = sema.original_range(rename.syntax());
+            if !seen.insert(alias_range) {
+                continue;
+            }
+            let alias = ReferenceAlias::Rename(alias_range);
+            let alias_name = rename.text();
+            let alias_name = alias_name.as_str().trim_start_matches("r#");
+            let finder = Finder::new(alias_name);
+            for (&file_id, &search_range) in search_scope.entries.iter() {
+                if self.should_skip_file() {
+                    return;
+                }
+                if !sema.db.file_text_index(file_id).may_contain(alias_name) {
+                    continue;
+                }
+                let text = sema.db.file_text(file_id);
+                let search_range =
+                    search_range.unwrap_or_else(|| TextRange::up_to(TextSize::of(&*text)));
+                let tree = sema.parse(file_id);
+                for idx in finder.find_iter(text.as_bytes()) {
+                    let offset: TextSize = idx.try_into().unwrap();
+                    if !search_range.contains_inclusive(offset) {
+                        continue;
+                    }
+                    let name_refs = tree
+                        .syntax()
+                        .token_at_offset(offset)
+                        .filter(|it| it.text().trim_start_matches("r#") == alias_name)
+                        .flat_map(|token| sema.descend_into_macros(token))
+                        .filter_map(|it| it.parent().and_then(ast::NameRef::cast));
+                    for name_ref in name_refs {
+                        let mut stop = false;
+                        direct.found_name_ref(&name_ref, &mut |file_id, mut reference| {
+                            collect_aliases(&reference, &mut renames);
+                            reference.alias = Some(alias.clone());
+                            stop = sink(file_id, reference);
+                            stop
+                        });
+                        if stop {
+                            return;
+                        }
+                    }
+                }
+            }
+        }
+
+        for type_alias in type_aliases {
+            let mut usages = Definition::TypeAlias(type_alias).usages(sema).include_aliases();
+            usages.scope = self.scope.clone();
+            usages.include_inactive_code = self.include_inactive_code;
+            usages.deadline = self.deadline;
+            let mut stop = false;
+            usages.search(&mut |file_id, mut reference| {
+                reference.alias.get_or_insert(ReferenceAlias::TypeAlias(type_alias));
+                stop = sink(file_id, reference);
+                stop
+            });
+            if stop {
+                return;
+            }
+        }
+    }
+
     fn found_name_ref(
         &self,
         name_ref: &ast::NameRef,
@@ -651,6 +1532,10 @@ fn found_name_ref(
                     range,
                     name: ast::NameLike::NameRef(name_ref.clone()),
                     category: ReferenceCategory::new(&def, name_ref),
+                    inactive: false,
+                    alias: None,
+                    container: None,
+                    snippet: None,
                 };
                 sink(file_id, reference)
             }
@@ -667,6 +1552,10 @@ fn found_name_ref(
                     range,
                     name: ast::NameLike::NameRef(name_ref.clone()),
                     category: ReferenceCategory::new(&def, name_ref),
+                    inactive: false,
+                    alias: None,
+                    container: None,
+                    snippet: None,
                 };
                 sink(file_id, reference)
             }
@@ -677,6 +1566,10 @@ fn found_name_ref(
                         range,
                         name: ast::NameLike::NameRef(name_ref.clone()),
                         category: ReferenceCategory::new(&def, name_ref),
+                        inactive: false,
+                        alias: None,
+                        container: None,
+                        snippet: None,
                     };
                     sink(file_id, reference)
                 } else {
@@ -701,6 +1594,27 @@ fn found_name_ref(
                     range,
                     name: ast::NameLike::NameRef(name_ref.clone()),
                     category: access,
+                    inactive: false,
+                    alias: None,
+                    container: None,
+                    snippet: None,
+                };
+                sink(file_id, reference)
+            }
+            // Inference gives up on some field accesses through `Deref` impls, so we walk the
+            // autoderef chain of the receiver ourselves.
+            None if matches!(self.def, Definition::Field(field)
+                if field_through_autoderef(self.sema, name_ref) == Some(field)) =>
+            {
+                let FileRange { file_id, range } = self.sema.original_range(name_ref.syntax());
+                let reference = FileReference {
+                    range,
+                    name: ast::NameLike::NameRef(name_ref.clone()),
+                    category: ReferenceCategory::new(&self.def, name_ref),
+                    inactive: false,
+                    alias: None,
+                    container: None,
+                    snippet: None,
                 };
                 sink(file_id, reference)
             }
@@ -725,6 +1639,10 @@ fn found_name(
                     name: ast::NameLike::Name(name.clone()),
                     // FIXME: mutable patterns should have `Write` access
                     category: Some(ReferenceCategory::Read),
+                    inactive: false,
+                    alias: None,
+                    container: None,
+                    snippet: None,
                 };
                 sink(file_id, reference)
             }
@@ -734,6 +1652,10 @@ fn found_name(
                     range,
                     name: ast::NameLike::Name(name.clone()),
                     category: None,
+                    inactive: false,
+                    alias: None,
+                    container: None,
+                    snippet: None,
                 };
                 sink(file_id, reference)
             }
@@ -759,6 +1681,10 @@ fn found_name(
                     range,
                     name: ast::NameLike::Name(name.clone()),
                     category: None,
+                    inactive: false,
+                    alias: None,
+                    container: None,
+                    snippet: None,
                 };
                 sink(file_id, reference)
             }
@@ -767,6 +1693,259 @@ fn found_name(
     }
 }
 
+/// Finds all usages of `krate` in the crates that directly depend on it: `extern crate` items,
+/// imports and qualified paths whose first segment resolves to the crate's root module.
+///
+/// Unlike a usage search for the crate's root module, this takes into account all names the
+/// crate is known by in its dependants, that is renamed dependencies as well as `extern crate`
+/// and `use` aliases of the crate.
+pub fn crate_usages(sema: &Semantics<'_, RootDatabase>, krate: hir::Crate) -> UsageSearchResult {
+    let _p = profile::span("crate_usages");
+    let db = sema.db;
+    let root = Definition::Module(krate.root_module(db));
+    let mut res = UsageSearchResult::default();
+
+    for rev_dep in krate.reverse_dependencies(db) {
+        let mut names: Vec<SmolStr> = rev_dep
+            .dependencies(db)
+            .into_iter()
+            .filter(|dep| dep.krate == krate)
+            .map(|dep| dep.name.unescaped().to_smol_str())
+            .collect();
+        let scope = SearchScope::krate(db, rev_dep);
+
+        // Aliases found along the way are pushed onto `names` and searched for as well.
+        let mut idx = 0;
+        while let Some(name) = names.get(idx).cloned() {
+            idx += 1;
+            let finder = Finder::new(name.as_str());
+            for &file_id in scope.entries.keys() {
+                if !db.file_text_index(file_id).may_contain(&name) {
+                    continue;
+                }
+                let text = db.file_text(file_id);
+                let tree = Lazy::new(|| sema.parse(file_id).syntax().clone());
+                for idx in finder.find_iter(text.as_bytes()) {
+                    let offset: TextSize = idx.try_into().unwrap();
+                    let name_refs = tree
+                        .token_at_offset(offset)
+                        .filter(|it| it.text().trim_start_matches("r#") == name)
+                        .flat_map(|token| sema.descend_into_macros(token))
+                        .filter_map(|it| it.parent().and_then(ast::NameRef::cast));
+                    for name_ref in name_refs {
+                        if !is_crate_usage_site(&name_ref) {
+                            continue;
+                        }
+                        match NameRefClass::classify(sema, &name_ref) {
+                            Some(NameRefClass::Definition(def)) if def == root => {}
+                            _ => continue,
+                        }
+                        if let Some(alias) = crate_alias(&name_ref) {
+                            if !names.contains(&alias) {
+                                names.push(alias);
+                            }
+                        }
+                        let FileRange { file_id, range } = sema.original_range(name_ref.syntax());
+                        let is_import = is_name_ref_in_import(&name_ref)
+                            || name_ref
+                                .syntax()
+                                .parent()
+                                .map_or(false, |it| it.kind() == SyntaxKind::EXTERN_CRATE);
+                        let reference = FileReference {
+                            range,
+                            name: ast::NameLike::NameRef(name_ref),
+                            category: is_import.then_some(ReferenceCategory::Import),
+                            inactive: false,
+                            alias: None,
+                            container: None,
+                            snippet: None,
+                        };
+                        res.references.entry(file_id).or_default().push(reference);
+                    }
+                }
+            }
+        }
+    }
+    res
+}
+
+/// Whether `name_ref` is the name of an `extern crate` item or the first segment of a path.
+fn is_crate_usage_site(name_ref: &ast::NameRef) -> bool {
+    let Some(parent) = name_ref.syntax().parent() else { return false };
+    if parent.kind() == SyntaxKind::EXTERN_CRATE {
+        return true;
+    }
+    ast::PathSegment::cast(parent)
+        .map_or(false, |segment| segment.parent_path().qualifier().is_none())
+}
+
+/// The alias the crate referred to by `name_ref` is given by an `extern crate foo as bar;` or
+/// a `use foo as bar;` item.
+fn crate_alias(name_ref: &ast::NameRef) -> Option<SmolStr> {
+    let parent = name_ref.syntax().parent()?;
+    let rename = match ast::ExternCrate::cast(parent.clone()) {
+        Some(extern_crate) => extern_crate.rename()?,
+        None => {
+            let path = ast::PathSegment::cast(parent)?.parent_path();
+            let use_tree = path.syntax().parent().and_then(ast::UseTree::cast)?;
+            use_tree.rename()?
+        }
+    };
+    Some(rename.name()?.text().as_str().trim_start_matches("r#").into())
+}
+
+fn field_usage_kind(name: &ast::NameLike) -> Option<FieldUsageKind> {
+    match name {
+        ast::NameLike::NameRef(name_ref) => {
+            let parent = name_ref.syntax().parent()?;
+            if ast::FieldExpr::can_cast(parent.kind()) {
+                Some(FieldUsageKind::Read)
+            } else if ast::RecordExprField::for_field_name(name_ref).is_some() {
+                Some(FieldUsageKind::Construct)
+            } else if ast::RecordPatField::for_field_name_ref(name_ref).is_some() {
+                Some(FieldUsageKind::Pattern)
+            } else {
+                None
+            }
+        }
+        ast::NameLike::Name(name) => {
+            ast::RecordPatField::for_field_name(name).map(|_| FieldUsageKind::Pattern)
+        }
+        ast::NameLike::Lifetime(_) => None,
+    }
+}
+
+fn variant_usage_kind(name: &ast::NameLike) -> Option<VariantUsageKind> {
+    match name {
+        ast::NameLike::NameRef(name_ref) => {
+            let path = name_ref.syntax().ancestors().find_map(ast::Path::cast)?;
+            let parent = path.syntax().parent()?;
+            match parent.kind() {
+                SyntaxKind::PATH_EXPR | SyntaxKind::RECORD_EXPR => {
+                    Some(VariantUsageKind::Construct)
+                }
+                SyntaxKind::PATH_PAT | SyntaxKind::TUPLE_STRUCT_PAT | SyntaxKind::RECORD_PAT => {
+                    Some(VariantUsageKind::Pattern)
+                }
+                _ => None,
+            }
+        }
+        // A unit variant that is in scope is matched on by an `IdentPat`.
+        ast::NameLike::Name(name) => ast::IdentPat::can_cast(name.syntax().parent()?.kind())
+            .then_some(VariantUsageKind::Pattern),
+        ast::NameLike::Lifetime(_) => None,
+    }
+}
+
+/// Whether `node` is inside of an item, statement or expression whose `#[cfg]` attribute is
+/// disabled by `cfg_options`.
+fn is_cfg_disabled(cfg_options: &CfgOptions, node: &SyntaxNode) -> bool {
+    node.ancestors().filter_map(ast::AnyHasAttrs::cast).any(|owner| {
+        owner
+            .attrs()
+            .filter(|attr| attr.simple_name().as_deref() == Some("cfg"))
+            .filter_map(|attr| attr.token_tree())
+            .any(|tt| cfg_options.check(&hir::parse_cfg(&tt)) == Some(false))
+    })
+}
+
+/// The field `name_ref` refers to if it is the name of a field expression, found by going through
+/// the autoderef chain of the receiver.
+fn field_through_autoderef(
+    sema: &Semantics<'_, RootDatabase>,
+    name_ref: &ast::NameRef,
+) -> Option<hir::Field> {
+    let field_expr = name_ref.syntax().parent().and_then(ast::FieldExpr::cast)?;
+    let receiver = sema.type_of_expr(&field_expr.expr()?)?.original;
+    // Names keep the `r#` of keywords only, so both sides are compared unescaped.
+    let name = name_ref.text();
+    let name = name.trim_start_matches("r#");
+    let field = receiver.autoderef(sema.db).find_map(|ty| {
+        let (field, _) = ty
+            .fields(sema.db)
+            .into_iter()
+            .find(|(field, _)| field.name(sema.db).unescaped().to_smol_str() == name)?;
+        Some(field)
+    });
+    field
+}
+
+/// Whether `token` is the `name` in `$crate::name!`.
+fn is_dollar_crate_macro_path(token: &SyntaxToken) -> bool {
+    let prev_tokens = iter::successors(token.prev_token(), |it| it.prev_token())
+        .filter(|it| !it.kind().is_trivia())
+        .map(|it| it.kind());
+    let is_dollar_crate = prev_tokens.take(4).eq([T![:], T![:], T![crate], T![$]]);
+    let next_token = iter::successors(token.next_token(), |it| it.next_token())
+        .find(|it| !it.kind().is_trivia());
+    is_dollar_crate && next_token.map_or(false, |it| it.kind() == T![!])
+}
+
+/// Whether `node` is inside of a `#[test]` function or an item or module marked `#[cfg(test)]`.
+fn is_test_code(sema: &Semantics<'_, RootDatabase>, node: &SyntaxNode) -> bool {
+    let is_test_attr = |attr: ast::Attr| match attr.simple_name().as_deref() {
+        Some("test") => true,
+        Some("cfg") => attr.token_tree().map_or(false, |tt| requires_test(&hir::parse_cfg(&tt))),
+        _ => false,
+    };
+    let in_test_item = sema
+        .ancestors_with_macros(node.clone())
+        .filter_map(ast::AnyHasAttrs::cast)
+        .any(|owner| owner.attrs().any(is_test_attr));
+    if in_test_item {
+        return true;
+    }
+    // `#[cfg(test)] mod tests;` puts the attribute into a different file.
+    let Some(module) = sema.scope(node).map(|it| it.module()) else { return false };
+    iter::successors(Some(module), |it| it.parent(sema.db))
+        .any(|module| module.attrs(sema.db).cfg().map_or(false, |cfg| requires_test(&cfg)))
+}
+
+/// Whether `cfg` can only be enabled with `cfg(test)`.
+pub(crate) fn requires_test(cfg: &CfgExpr) -> bool {
+    match cfg {
+        CfgExpr::Atom(CfgAtom::Flag(flag)) => flag == "test",
+        CfgExpr::All(preds) => preds.iter().any(requires_test),
+        _ => false,
+    }
+}
+
+/// The innermost item containing `node`, falling back to the module `node` is in.
+fn enclosing_item(sema: &Semantics<'_, RootDatabase>, node: &SyntaxNode) -> Option<Definition> {
+    let item = sema.ancestors_with_macros(node.clone()).skip(1).find_map(|node| {
+        match_ast! {
+            match node {
+                ast::Fn(it) => sema.to_def(&it).map(Definition::from),
+                ast::Const(it) => sema.to_def(&it).map(Definition::from),
+                ast::Static(it) => sema.to_def(&it).map(Definition::from),
+                ast::Impl(it) => sema.to_def(&it).map(Definition::from),
+                ast::Trait(it) => sema.to_def(&it).map(Definition::from),
+                ast::TypeAlias(it) => sema.to_def(&it).map(Definition::from),
+                ast::Adt(it) => sema.to_def(&it).map(Definition::from),
+                ast::Variant(it) => sema.to_def(&it).map(Definition::from),
+                ast::Module(it) => sema.to_def(&it).map(Definition::from),
+                _ => None,
+            }
+        }
+    });
+    item.or_else(|| Some(Definition::Module(sema.scope(node)?.module())))
+}
+
+/// The rename of `use foo::Bar as Baz;` if `name_ref` is the `Bar` in there.
+fn renaming_use_tree(name_ref: &ast::NameRef) -> Option<ast::Name> {
+    let path = name_ref.syntax().parent().and_then(ast::PathSegment::cast)?.parent_path();
+    let use_tree = path.syntax().parent().and_then(ast::UseTree::cast)?;
+    use_tree.rename()?.name()
+}
+
+/// The type alias `type Baz = Bar;` if `name_ref` is the `Bar` in there.
+fn aliasing_type_alias(name_ref: &ast::NameRef) -> Option<ast::TypeAlias> {
+    let path = name_ref.syntax().parent().and_then(ast::PathSegment::cast)?.parent_path();
+    let path_type = path.syntax().parent().and_then(ast::PathType::cast)?;
+    let type_alias = path_type.syntax().parent().and_then(ast::TypeAlias::cast)?;
+    (type_alias.ty()? == ast::Type::PathType(path_type)).then_some(type_alias)
+}
+
 fn def_to_ty(sema: &Semantics<'_, RootDatabase>, def: &Definition) -> Option<hir::Type> {
     match def {
         Definition::Adt(adt) => Some(adt.ty(sema.db)),
@@ -778,7 +1957,7 @@ fn def_to_ty(sema: &Semantics<'_, RootDatabase>, def: &Definition) -> Option<hir
 }
 
 impl ReferenceCategory {
-    fn new(def: &Definition, r: &ast::NameRef) -> Option<ReferenceCategory> {
+    pub(crate) fn new(def: &Definition, r: &ast::NameRef) -> Option<ReferenceCategory> {
         // Only Locals and Fields have accesses for now.
         if !matches!(def, Definition::Local(_) | Definition::Field(_)) {
             return is_name_ref_in_import(r).then_some(ReferenceCategory::Import);
@@ -809,6 +1988,18 @@ fn new(def: &Definition, r: &ast::NameRef) -> Option<ReferenceCategory> {
     }
 }
 
+/// Whether `token` is (part of) documentation, a doc comment or the string of a `#[doc]` attribute.
+fn is_doc_token(token: &SyntaxToken) -> bool {
+    if let Some(comment) = ast::Comment::cast(token.clone()) {
+        return comment.kind().doc.is_some();
+    }
+    ast::String::can_cast(token.kind())
+        && token
+            .parent_ancestors()
+            .find_map(ast::Attr::cast)
+            .map_or(false, |attr| attr.simple_name().as_deref() == Some("doc"))
+}
+
 fn is_name_ref_in_import(name_ref: &ast::NameRef) -> bool {
     name_ref
         .syntax()
@@ -817,3 +2008,6 @@ fn is_name_ref_in_import(name_ref: &ast::NameRef) -> bool {
         .and_then(|it| it.parent_path().top_path().syntax().parent())
         .map_or(false, |it| it.kind() == SyntaxKind::USE_TREE)
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,916 @@
+use std::fmt;
+
+use base_db::{fixture::WithFixture, FilePosition};
+use expect_test::{expect, Expect};
+use hir::Semantics;
+use itertools::Itertools;
+
+use super::*;
+
+/// The definition whose name is under the cursor.
+fn def_at(sema: &Semantics<'_, RootDatabase>, position: FilePosition) -> Definition {
+    let file = sema.parse(position.file_id);
+    let name: ast::Name =
+        sema.find_node_at_offset_with_descend(file.syntax(), position.offset).unwrap();
+    NameClass::classify(sema, &name).and_then(NameClass::defined).unwrap()
+}
+
+fn format_usages(db: &RootDatabase, usages: UsageSearchResult) -> String {
+    usages
+        .into_iter()
+        .sorted_by_key(|(file_id, _)| *file_id)
+        .flat_map(|(file_id, refs)| {
+            let text = db.file_text(file_id);
+            refs.into_iter().sorted_by_key(|it| it.range.start()).map(move |it| {
+                let category = it.category.map(|it| format!(" {it:?}")).unwrap_or_default();
+                let inactive = if it.inactive { " inactive" } else { "" };
+                format!("{file_id:?} {:?} {}{category}{inactive}\n", it.range, &text[it.range])
+            })
+        })
+        .collect()
+}
+
+fn check_crate_usages(ra_fixture: &str, krate: &str, expect: Expect) {
+    let (db, _) = RootDatabase::with_many_files(ra_fixture);
+    let sema = Semantics::new(&db);
+    let krate = hir::Crate::all(&db)
+        .into_iter()
+        .find(|it| {
+            it.display_name(&db).map_or(false, |name| name.crate_name().as_smol_str() == krate)
+        })
+        .unwrap();
+    expect.assert_eq(&format_usages(&db, crate_usages(&sema, krate)));
+}
+
+#[test]
+fn crate_usages_paths_and_imports() {
+    check_crate_usages(
+        r#"
+//- /main.rs crate:main deps:foo
+extern crate foo;
+use foo::Struct;
+use ::foo::func as _;
+
+fn main() {
+    foo::func();
+    let foo = 92;
+    let _: foo::Struct;
+}
+mod foo_mod {
+    fn f() { crate::foo::func(); }
+}
+//- /lib.rs crate:foo
+pub struct Struct;
+pub fn func() {}
+fn f() { crate::func() }
+"#,
+        "foo",
+        expect![[r#"
+            FileId(0) 13..16 foo Import
+            FileId(0) 22..25 foo Import
+            FileId(0) 41..44 foo Import
+            FileId(0) 74..77 foo
+            FileId(0) 116..119 foo
+        "#]],
+    );
+}
+
+#[test]
+fn crate_usages_renamed() {
+    check_crate_usages(
+        r#"
+//- /main.rs crate:main deps:foo
+extern crate foo as bar;
+use foo as baz;
+
+fn main() {
+    bar::func();
+    baz::func();
+}
+//- /lib.rs crate:foo
+pub fn func() {}
+"#,
+        "foo",
+        expect![[r#"
+            FileId(0) 13..16 foo Import
+            FileId(0) 29..32 foo Import
+            FileId(0) 58..61 bar
+            FileId(0) 75..78 baz
+        "#]],
+    );
+}
+
+/// Formats usages that are classified by kind, like the usages of fields and variants.
+fn format_usage_kinds<U, K: fmt::Debug>(
+    db: &RootDatabase,
+    usages: IntMap<FileId, Vec<U>>,
+    range_and_kind: impl Fn(&U) -> (TextRange, K) + Copy,
+) -> String {
+    usages
+        .into_iter()
+        .sorted_by_key(|(file_id, _)| *file_id)
+        .flat_map(|(file_id, usages)| {
+            let text = db.file_text(file_id);
+            usages
+                .into_iter()
+                .map(move |it| range_and_kind(&it))
+                .sorted_by_key(|(range, _)| range.start())
+                .map(move |(range, kind)| format!("{range:?} {} {kind:?}\n", &text[range]))
+        })
+        .collect()
+}
+
+fn check_field_usages(ra_fixture: &str, expect: Expect) {
+    let (db, position) = RootDatabase::with_position(ra_fixture);
+    let sema = Semantics::new(&db);
+    let usages = def_at(&sema, position).usages(&sema).field_usages();
+    expect.assert_eq(&format_usage_kinds(&db, usages, |it| (it.range, it.kind)));
+}
+
+fn check_variant_usages(ra_fixture: &str, expect: Expect) {
+    let (db, position) = RootDatabase::with_position(ra_fixture);
+    let sema = Semantics::new(&db);
+    let usages = def_at(&sema, position).usages(&sema).variant_usages();
+    expect.assert_eq(&format_usage_kinds(&db, usages, |it| (it.range, it.kind)));
+}
+
+#[test]
+fn variant_usages_by_position() {
+    check_variant_usages(
+        r#"
+enum E { A$0(u32), B { a: u32 }, C }
+use E::A;
+fn f(e: E) -> Option<E> {
+    let _ = E::A(0);
+    let _ = Some(0).map(A);
+    if let E::A(_) | A(_) = e {}
+    match e {
+        E::A(a) => Some(A(a)),
+        _ => None,
+    }
+}
+"#,
+        expect![[r#"
+            86..87 A Construct
+            116..117 A Construct
+            134..135 A Pattern
+            141..142 A Pattern
+            178..179 A Pattern
+            191..192 A Construct
+        "#]],
+    );
+}
+
+#[test]
+fn unit_variant_usages() {
+    check_variant_usages(
+        r#"
+enum E { A, B { a: u32 }, C$0 }
+use E::*;
+impl E {
+    fn f(self) -> E {
+        match self {
+            Self::C => C,
+            E::C | C => Self::C,
+            _ => E::C,
+        }
+    }
+}
+"#,
+        expect![[r#"
+            110..111 C Pattern
+            115..116 C Construct
+            133..134 C Pattern
+            137..138 C Pattern
+            148..149 C Construct
+            171..172 C Construct
+        "#]],
+    );
+}
+
+fn check_derive_helper_usages(ra_fixture: &str, expect: Expect) {
+    let (db, position) = RootDatabase::with_position(ra_fixture);
+    let sema = Semantics::new(&db);
+    let file = sema.parse(position.file_id);
+    let name_ref: ast::NameRef =
+        syntax::algo::find_node_at_offset(file.syntax(), position.offset).unwrap();
+    let def = NameRefClass::classify(&sema, &name_ref)
+        .map(|it| match it {
+            NameRefClass::Definition(it) => it,
+            NameRefClass::FieldShorthand { .. } => unreachable!(),
+        })
+        .unwrap();
+    let usages = def.usages(&sema).derive_helper_usages();
+    let actual = usages
+        .into_iter()
+        .sorted_by_key(|(file_id, _)| *file_id)
+        .flat_map(|(file_id, usages)| {
+            let text = db.file_text(file_id);
+            let db = &db;
+            usages.into_iter().sorted_by_key(|it| it.range.start()).map(move |it| {
+                format!("{:?} {} {}\n", it.range, &text[it.range], it.adt.name(db).display(db))
+            })
+        })
+        .collect::<String>();
+    expect.assert_eq(&actual);
+}
+
+#[test]
+fn derive_helper_usages() {
+    check_derive_helper_usages(
+        r#"
+//- proc_macros: derive_with_helper
+//- minicore: derive
+#[derive(proc_macros::DeriveWithHelper)]
+#[helper$0(rename_all)]
+struct Foo {
+    #[helper(skip)]
+    field: u32,
+    #[allow(unused)]
+    other: u32,
+}
+#[derive(proc_macros::DeriveWithHelper)]
+enum Bar {
+    #[helper]
+    Baz,
+}
+"#,
+        expect![[r#"
+            41..62 #[helper(rename_all)] Foo
+            80..95 #[helper(skip)] Foo
+            207..216 #[helper] Bar
+        "#]],
+    );
+}
+
+fn check_cfg_usages(ra_fixture: &str, features: &[&[&str]], expect: Expect) {
+    let (db, position) = RootDatabase::with_position(ra_fixture);
+    let sema = Semantics::new(&db);
+    let cfgs = features
+        .iter()
+        .map(|features| {
+            let mut cfg = CfgOptions::default();
+            for &feature in *features {
+                cfg.insert_key_value("feature".into(), feature.into());
+            }
+            cfg
+        })
+        .collect::<Vec<_>>();
+    let usages = def_at(&sema, position).usages(&sema).all_under_cfgs(&cfgs);
+    let actual = usages
+        .into_iter()
+        .sorted_by_key(|(file_id, _)| *file_id)
+        .flat_map(|(file_id, references)| {
+            references.into_iter().sorted_by_key(|it| it.reference.range.start()).map(move |it| {
+                format!("{file_id:?} {:?} {:?}\n", it.reference.range, it.active_under)
+            })
+        })
+        .collect::<String>();
+    expect.assert_eq(&actual);
+}
+
+#[test]
+fn usages_under_cfgs() {
+    check_cfg_usages(
+        r#"
+//- /lib.rs crate:lib cfg:feature=a
+pub fn foo$0() {}
+#[cfg(feature = "a")]
+fn with_a() { foo(); }
+#[cfg(feature = "b")]
+fn with_b() { foo(); }
+#[cfg(not(feature = "a"))]
+fn without_a() { foo(); }
+fn always() {
+    foo();
+    #[cfg(all(feature = "a", feature = "b"))]
+    foo();
+}
+"#,
+        &[&["a"], &["b"], &["a", "b"]],
+        expect![[r#"
+            FileId(0) 52..55 [0, 2]
+            FileId(0) 97..100 [1, 2]
+            FileId(0) 150..153 [1]
+            FileId(0) 177..180 [0, 1, 2]
+            FileId(0) 234..237 [2]
+        "#]],
+    );
+}
+
+fn check_doc_link_usages(ra_fixture: &str, expect: Expect) {
+    let (db, position) = RootDatabase::with_position(ra_fixture);
+    let sema = Semantics::new(&db);
+    let usages = def_at(&sema, position).usages(&sema).doc_link_usages();
+    let actual = usages
+        .into_iter()
+        .sorted_by_key(|(file_id, _)| *file_id)
+        .flat_map(|(file_id, ranges)| {
+            let text = db.file_text(file_id);
+            ranges.into_iter().map(move |range| format!("{file_id:?} {range:?} {}\n", &text[range]))
+        })
+        .collect::<String>();
+    expect.assert_eq(&actual);
+}
+
+#[test]
+fn doc_link_usages() {
+    check_doc_link_usages(
+        r#"
+//- /lib.rs crate:lib
+//! See [`Foo`] and [crate::m::Foo], but not [m::Bar].
+pub struct Foo$0 {}
+impl Foo {
+    /// Creates a [`Foo`], unlike [Self::Foo]. Foo is not a link.
+    #[doc = "Or a [Foo]"]
+    pub fn new() -> Foo { Foo {} }
+}
+pub mod m {
+    pub use super::Foo;
+    /// [Foo] is [`super::Foo`], but [fn@Foo] is not.
+    pub struct Bar;
+    // [Foo] is not rendered.
+}
+//- /main.rs crate:main deps:lib
+/// Uses [lib::Foo].
+fn main() { let _ = lib::Foo {}; }
+"#,
+        expect![[r#"
+            FileId(0) 8..15 [`Foo`]
+            FileId(0) 20..35 [crate::m::Foo]
+            FileId(0) 102..109 [`Foo`]
+            FileId(0) 168..173 [Foo]
+            FileId(0) 257..262 [Foo]
+            FileId(0) 266..280 [`super::Foo`]
+            FileId(1) 9..19 [lib::Foo]
+        "#]],
+    );
+}
+
+fn check_textual_occurrences(ra_fixture: &str, expect: Expect) {
+    let (db, position) = RootDatabase::with_position(ra_fixture);
+    let sema = Semantics::new(&db);
+    let occurrences = def_at(&sema, position).usages(&sema).textual_occurrences();
+    let actual = occurrences
+        .into_iter()
+        .sorted_by_key(|(file_id, _)| *file_id)
+        .flat_map(|(file_id, ranges)| {
+            ranges.into_iter().map(move |range| format!("{file_id:?} {range:?}\n"))
+        })
+        .collect::<String>();
+    expect.assert_eq(&actual);
+}
+
+#[test]
+fn textual_occurrences() {
+    check_textual_occurrences(
+        r#"
+fn f() {
+    // The count, not the counter.
+    let count$0 = 0;
+    let _ = "count: 0";
+    let _ = b"count";
+    /// No doc on a count.
+    let _ = count_ones;
+}
+// The count outside of the scope.
+"#,
+        expect![[r#"
+            FileId(0) 20..25
+            FileId(0) 76..81
+            FileId(0) 101..106
+            FileId(0) 129..134
+        "#]],
+    );
+}
+
+#[test]
+fn field_usages_by_position() {
+    check_field_usages(
+        r#"
+struct Foo { field$0: u32, other: u32 }
+fn f(foo: Foo) {
+    let _ = foo.field;
+    let _ = Foo { field: 0, other: 0 };
+    let field = 0;
+    let _ = Foo { field, other: 0 };
+    let Foo { field, .. } = foo;
+    let Foo { field: _, .. } = foo;
+    let _ = Foo { other: 0, ..foo };
+    let _ = Foo { field: 0, ..foo };
+}
+impl Foo {
+    fn new(base: Foo) -> Self {
+        Self { ..base }
+    }
+}
+"#,
+        expect![[r#"
+            71..76 field Read
+            96..101 field Construct
+            155..160 field Construct
+            188..193 field Pattern
+            221..226 field Pattern
+            271..276 ..foo StructUpdate
+            298..303 field Construct
+            377..383 ..base StructUpdate
+        "#]],
+    );
+}
+
+#[test]
+fn field_usages_enum_variant() {
+    check_field_usages(
+        r#"
+enum E { V { field$0: u32 } }
+fn f(e: E) {
+    let E::V { field } = e;
+    let _ = E::V { field: 0 };
+}
+"#,
+        expect![[r#"
+            56..61 field Pattern
+            88..93 field Construct
+        "#]],
+    );
+}
+
+fn check_inactive_usages(ra_fixture: &str, expect: Expect) {
+    let (db, position) = RootDatabase::with_position(ra_fixture);
+    let sema = Semantics::new(&db);
+    let usages = def_at(&sema, position).usages(&sema).include_inactive_code().all();
+    expect.assert_eq(&format_usages(&db, usages));
+}
+
+#[test]
+fn inactive_usages() {
+    check_inactive_usages(
+        r#"
+//- /main.rs cfg:feature=enabled,enabled_flag
+struct S;
+impl S {
+    fn method$0(&self) {}
+}
+
+fn active(s: S) { s.method(); }
+#[cfg(FALSE)]
+fn disabled(s: S) { s.method(); }
+#[cfg(all(enabled_flag, not(feature = "enabled")))]
+fn disabled_all(s: S) { s.method(); }
+#[cfg(any(enabled_flag, FALSE))]
+fn enabled(s: S) { s.method(); }
+fn statement(s: S) {
+    #[cfg(feature = "disabled")]
+    s.method();
+    #[cfg(feature = "enabled")]
+    unresolved.method();
+}
+"#,
+        expect![[r#"
+            FileId(0) 66..72 method
+            FileId(0) 114..120 method inactive
+            FileId(0) 204..210 method inactive
+            FileId(0) 270..276 method
+            FileId(0) 342..348 method inactive
+        "#]],
+    );
+}
+
+#[test]
+fn inactive_usages_resolved() {
+    check_inactive_usages(
+        r#"
+fn func$0() {}
+#[cfg(FALSE)]
+mod disabled {
+    use crate::func;
+}
+"#,
+        expect![[r#"
+            FileId(0) 57..61 func Import inactive
+        "#]],
+    );
+}
+
+fn check_alias_usages(ra_fixture: &str, expect: Expect) {
+    let (db, position) = RootDatabase::with_position(ra_fixture);
+    let sema = Semantics::new(&db);
+    let usages = def_at(&sema, position).usages(&sema).include_aliases().all();
+    let actual = usages
+        .into_iter()
+        .sorted_by_key(|(file_id, _)| *file_id)
+        .flat_map(|(file_id, refs)| {
+            let db = &db;
+            let text = db.file_text(file_id);
+            refs.into_iter().sorted_by_key(|it| it.range.start()).map(move |it| {
+                let alias = match it.alias {
+                    Some(ReferenceAlias::Rename(range)) => {
+                        format!(" via {}", &db.file_text(range.file_id)[range.range])
+                    }
+                    Some(ReferenceAlias::TypeAlias(alias)) => {
+                        format!(" via type {}", alias.name(db).display(db))
+                    }
+                    None => String::new(),
+                };
+                format!("{file_id:?} {:?} {}{alias}\n", it.range, &text[it.range])
+            })
+        })
+        .collect::<String>();
+    expect.assert_eq(&actual);
+}
+
+#[test]
+fn alias_usages_through_renaming_reexports() {
+    check_alias_usages(
+        r#"
+//- /main.rs crate:main deps:lib
+use lib::Renamed;
+use lib::Renamed as Again;
+
+fn main() {
+    let _: Renamed;
+    let _: Again;
+    let _: lib::Direct;
+}
+//- /lib.rs crate:lib
+mod inner {
+    pub struct Struct$0;
+}
+pub use inner::Struct as Renamed;
+pub use inner::Struct as Direct;
+"#,
+        expect![[r#"
+            FileId(0) 9..16 Renamed via Renamed
+            FileId(0) 27..34 Renamed via Renamed
+            FileId(0) 69..76 Renamed via Renamed
+            FileId(0) 89..94 Again via Again
+            FileId(0) 112..118 Direct via Direct
+            FileId(1) 52..58 Struct
+            FileId(1) 86..92 Struct
+        "#]],
+    );
+}
+
+#[test]
+fn alias_usages_through_type_aliases() {
+    check_alias_usages(
+        r#"
+struct Struct$0;
+type Alias = Struct;
+type AliasAlias = Alias;
+type Generic<T> = (Struct, T);
+
+fn f(_: Alias, _: AliasAlias, _: Generic<()>) {}
+"#,
+        expect![[r#"
+            FileId(0) 28..34 Struct
+            FileId(0) 54..59 Alias via type Alias
+            FileId(0) 80..86 Struct
+            FileId(0) 101..106 Alias via type Alias
+            FileId(0) 111..121 AliasAlias via type AliasAlias
+        "#]],
+    );
+}
+
+#[test]
+fn usages_with_budget() {
+    let (db, position) = RootDatabase::with_position(
+        r#"
+fn func$0() {}
+fn f() {
+    func();
+    func();
+    func();
+}
+"#,
+    );
+    let sema = Semantics::new(&db);
+    let def = def_at(&sema, position);
+
+    let usages = def.usages(&sema).with_limit(2).all();
+    assert!(usages.is_incomplete);
+    assert_eq!(usages.file_ranges().count(), 2);
+
+    let usages = def.usages(&sema).with_limit(3).all();
+    assert!(!usages.is_incomplete);
+    assert_eq!(usages.file_ranges().count(), 3);
+
+    let usages = def.usages(&sema).with_deadline(std::time::Instant::now()).all();
+    assert!(usages.is_incomplete);
+    assert!(usages.is_empty());
+}
+
+#[test]
+fn usages_grouped_by_container() {
+    let (db, position) = RootDatabase::with_position(
+        r#"
+//- /main.rs
+mod inner;
+struct S$0;
+use S as _;
+const C: S = S;
+impl S {
+    fn new() -> S { S }
+}
+//- /inner.rs
+use crate::S;
+mod nested {
+    fn f() -> crate::S { loop {} }
+}
+"#,
+    );
+    let sema = Semantics::new(&db);
+    let usages = def_at(&sema, position).usages(&sema).include_containers().all();
+    let actual = usages
+        .into_iter()
+        .sorted_by_key(|(file_id, _)| *file_id)
+        .flat_map(|(file_id, refs)| {
+            let db = &db;
+            refs.into_iter().sorted_by_key(|it| it.range.start()).map(move |it| {
+                let container = match it.container.map(|it| it.lookup(db)) {
+                    Some(Definition::SelfType(_)) => "impl".to_owned(),
+                    Some(Definition::Module(module)) if module.is_crate_root() => {
+                        "crate".to_owned()
+                    }
+                    Some(container) => container.name(db).unwrap().display(db).to_string(),
+                    None => "-".to_owned(),
+                };
+                format!("{file_id:?} {:?} {container}\n", it.range)
+            })
+        })
+        .collect::<String>();
+    expect![[r#"
+        FileId(0) 25..26 crate
+        FileId(0) 42..43 C
+        FileId(0) 46..47 C
+        FileId(0) 54..55 impl
+        FileId(0) 74..75 new
+        FileId(0) 78..79 new
+        FileId(1) 11..12 inner
+        FileId(1) 48..49 f
+    "#]]
+    .assert_eq(&actual);
+}
+
+#[test]
+fn usages_excluding_tests() {
+    let (db, position) = RootDatabase::with_position(
+        r#"
+//- /src/lib.rs crate:lib
+mod tests;
+#[path = "../tests/common.rs"]
+mod common;
+#[cfg(test)]
+mod unit;
+
+pub fn func$0() {}
+fn production() { func(); }
+#[test]
+fn test() { func(); }
+#[cfg(all(test, feature = "extra"))]
+mod inline_tests {
+    fn f() { super::func(); }
+}
+//- /src/tests/mod.rs
+fn f() { crate::func(); }
+//- /tests/common.rs
+fn f() { crate::func(); }
+//- /src/unit.rs
+fn f() { crate::func(); }
+//- /src/main.rs crate:main deps:lib
+fn main() { lib::func(); }
+//- /tests/integration.rs crate:integration deps:lib
+fn f() { lib::func(); }
+//- /examples/example/main.rs crate:example deps:lib
+fn main() { lib::func(); }
+"#,
+    );
+    let sema = Semantics::new(&db);
+    let def = def_at(&sema, position);
+    let scope = def.search_scope(&db).exclude_tests(&db);
+    let usages = def.usages(&sema).in_scope(scope).all();
+    expect![[r#"
+        FileId(0) 113..117 func
+        FileId(1) 16..20 func
+        FileId(2) 16..20 func
+        FileId(4) 17..21 func
+    "#]]
+    .assert_eq(&format_usages(&db, usages));
+}
+
+#[test]
+fn exported_macro_usages_through_dollar_crate() {
+    let (db, position) = RootDatabase::with_position(
+        r#"
+//- /main.rs crate:main deps:lib
+#[macro_use]
+extern crate lib;
+
+fn main() {
+    lib::outer!();
+    lib::inner!();
+    inner!();
+}
+//- /lib.rs crate:lib
+#[macro_export]
+macro_rules! inner$0 { () => {} }
+#[macro_export]
+macro_rules! outer { () => { $crate::inner!() } }
+#[macro_export]
+macro_rules! unused { () => { $crate::inner!() } }
+"#,
+    );
+    let sema = Semantics::new(&db);
+    let usages = def_at(&sema, position).usages(&sema).all();
+    expect![[r#"
+        FileId(0) 72..77 inner
+        FileId(0) 86..91 inner
+        FileId(1) 101..106 inner
+    "#]]
+    .assert_eq(&format_usages(&db, usages));
+}
+
+#[test]
+fn field_usages_through_deref() {
+    check_field_usages(
+        r#"
+//- minicore: deref
+struct Inner { field$0: u32 }
+struct Wrapper { inner: Inner }
+impl core::ops::Deref for Wrapper {
+    type Target = Inner;
+    fn deref(&self) -> &Inner { &self.inner }
+}
+struct Outer { wrapper: Wrapper }
+struct Generic<T>(T);
+impl<T> core::ops::Deref for Generic<T> {
+    type Target = T;
+    fn deref(&self) -> &T { &self.0 }
+}
+fn f(outer: &Outer, boxed: &&Wrapper, generic: Generic<Generic<Wrapper>>) {
+    let _ = outer.wrapper.field;
+    let _ = boxed.field;
+    let Inner { field } = *outer.wrapper;
+    let _ = generic.field;
+}
+fn g<T: core::ops::Deref<Target = Inner>>(t: T) {
+    let _ = t.field;
+}
+fn h<T: core::ops::Deref<Target = W>, W: core::ops::Deref<Target = Inner>>(t: T) {
+    let _ = t.field;
+}
+fn opaque() -> impl core::ops::Deref<Target = Inner> { loop {} }
+fn dyn_(d: &dyn core::ops::Deref<Target = Inner>) {
+    let _ = opaque().field;
+    let _ = d.field;
+    let _ = (*d).field;
+}
+fn late(x: Wrapper) {
+    let _ = x.field.unknown_method();
+    let _ = core::ops::Deref::deref(&x).field;
+    let closure = |w| w.field;
+    // the type of `w` is only known after `w.field` has been inferred
+    closure(x);
+}
+"#,
+        expect![[r#"
+            430..435 field Read
+            455..460 field Read
+            478..483 field Pattern
+            524..529 field Read
+            597..602 field Read
+            703..708 field Read
+            850..855 field Read
+            871..876 field Read
+            895..900 field Read
+            940..945 field Read
+            1004..1009 field Read
+            1035..1040 field Read
+        "#]],
+    );
+}
+
+#[test]
+fn raw_field_usages_through_deref() {
+    check_field_usages(
+        r#"
+//- minicore: deref
+struct Inner { field$0: u32 }
+struct Wrapper { inner: Inner }
+impl core::ops::Deref for Wrapper {
+    type Target = Inner;
+    fn deref(&self) -> &Inner { &self.inner }
+}
+fn late(x: Wrapper) {
+    let closure = |w| w.r#field;
+    closure(x);
+}
+"#,
+        expect![[r#"
+            215..222 r#field Read
+        "#]],
+    );
+}
+
+#[test]
+fn usage_counts() {
+    let (db, position) = RootDatabase::with_position(
+        r#"
+//- /main.rs
+mod other;
+fn func$0() {}
+fn f() {
+    func();
+    func();
+}
+//- /other.rs
+fn f() { crate::func(); }
+"#,
+    );
+    let sema = Semantics::new(&db);
+    let def = def_at(&sema, position);
+    let counts = def.usages(&sema).count();
+    assert_eq!(counts.total, 3);
+    assert_eq!(counts.per_file.values().sorted().collect::<Vec<_>>(), [&1, &2]);
+    assert!(!counts.is_incomplete);
+
+    let counts = def.usages(&sema).with_limit(1).count();
+    assert_eq!(counts.total, 1);
+    assert!(counts.is_incomplete);
+}
+
+#[test]
+fn ranked_usages() {
+    let (db, files) = RootDatabase::with_many_files(
+        r#"
+//- /main.rs crate:main deps:lib
+use lib::Struct;
+fn far() -> Struct { Struct }
+fn origin() {
+    let _: Struct;
+    macro_rules! m { ($t:ty) => { let _: $t; } }
+    m!(Struct);
+}
+//- /other.rs crate:lib
+pub struct Struct;
+fn other_crate() -> Struct { Struct }
+"#,
+    );
+    let sema = Semantics::new(&db);
+    let origin = FilePosition { file_id: files[0], offset: 65.into() };
+    let def = Definition::Adt(
+        hir::Crate::all(&db)
+            .into_iter()
+            .find_map(|krate| {
+                krate.root_module(&db).declarations(&db).into_iter().find_map(|it| match it {
+                    hir::ModuleDef::Adt(it) => Some(it),
+                    _ => None,
+                })
+            })
+            .unwrap(),
+    );
+    let actual = def
+        .usages(&sema)
+        .all()
+        .ranked(&sema, origin)
+        .into_iter()
+        .map(|(file_id, it)| {
+            let category = it.category.map(|it| format!(" {it:?}")).unwrap_or_default();
+            format!("{file_id:?} {:?} {}{category}\n", it.range, &db.file_text(file_id)[it.range])
+        })
+        .collect::<String>();
+    expect![[r#"
+        FileId(0) 72..78 Struct
+        FileId(0) 136..142 Struct
+        FileId(0) 29..35 Struct
+        FileId(0) 38..44 Struct
+        FileId(0) 9..15 Struct Import
+        FileId(1) 39..45 Struct
+        FileId(1) 48..54 Struct
+    "#]]
+    .assert_eq(&actual);
+}
+
+#[test]
+fn usage_snippets() {
+    let (db, position) = RootDatabase::with_position(
+        "fn func$0() {}\r\nfn f() {\r\n    func(); func();\r\n}\r\nfn g() { func() }",
+    );
+    let sema = Semantics::new(&db);
+    let usages = def_at(&sema, position).usages(&sema).include_snippets().all();
+    let actual = usages
+        .into_iter()
+        .flat_map(|(_, refs)| refs)
+        .sorted_by_key(|it| it.range.start())
+        .map(|it| {
+            let snippet = it.snippet.unwrap();
+            format!("{:?} {:?} {:?}\n", snippet.text, snippet.range, &snippet.text[snippet.range])
+        })
+        .collect::<String>();
+    expect![[r#"
+        "    func(); func();" 4..8 "func"
+        "    func(); func();" 12..16 "func"
+        "fn g() { func() }" 9..13 "func"
+    "#]]
+    .assert_eq(&actual);
+}
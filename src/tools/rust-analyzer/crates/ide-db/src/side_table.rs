@@ -0,0 +1,321 @@
+//! Auxiliary data attached to ranges of files, like coverage, blame or ownership, which tools can
+//! join with semantic results.
+//!
+//! Side tables aren't salsa inputs: they aren't used by any query, and their entries follow the
+//! edits of their files instead of being invalidated. [`RootDatabase::apply_change`] moves the
+//! entries after an edited region of a file, grows or clips the ones overlapping it, and drops the
+//! ones whose text is gone.
+
+use std::{
+    any::Any,
+    sync::{Arc, RwLock},
+};
+
+use base_db::{FileId, FileRange};
+use hir::{FieldSource, HasSource, InFile, ModuleSource};
+use syntax::{AstNode, SyntaxNode, TextRange, TextSize};
+use text_edit::TextEdit;
+
+use crate::{defs::Definition, FxHashMap, RootDatabase};
+
+/// The entries of type `T` attached to ranges of files, registered with
+/// [`RootDatabase::register_side_table`].
+#[derive(Debug)]
+pub struct SideTable<T> {
+    name: &'static str,
+    files: RwLock<FxHashMap<FileId, Vec<(TextRange, T)>>>,
+}
+
+impl<T: Clone + Send + Sync + 'static> SideTable<T> {
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn insert(&self, range: FileRange, value: T) {
+        let mut files = self.files.write().unwrap();
+        let entries = files.entry(range.file_id).or_default();
+        let idx = entries.partition_point(|(it, _)| it.start() <= range.range.start());
+        entries.insert(idx, (range.range, value));
+    }
+
+    /// Removes the entries of `file_id`, like before inserting new ones computed for its current
+    /// text.
+    pub fn clear_file(&self, file_id: FileId) {
+        self.files.write().unwrap().remove(&file_id);
+    }
+
+    /// The entries of `file_id`, ordered by their start.
+    pub fn entries(&self, file_id: FileId) -> Vec<(TextRange, T)> {
+        self.files.read().unwrap().get(&file_id).cloned().unwrap_or_default()
+    }
+
+    /// The entries overlapping `range`, ordered by their start. Empty ranges overlap the ranges
+    /// containing them.
+    pub fn overlapping(&self, range: FileRange) -> Vec<(TextRange, T)> {
+        let files = self.files.read().unwrap();
+        let Some(entries) = files.get(&range.file_id) else { return Vec::new() };
+        entries.iter().filter(|(it, _)| overlaps(*it, range.range)).cloned().collect()
+    }
+
+    /// The entries overlapping the whole source of `def`, like the ones attached to the lines of
+    /// a function.
+    pub fn for_definition(&self, db: &RootDatabase, def: Definition) -> Vec<(TextRange, T)> {
+        match definition_range(db, def) {
+            Some(range) => self.overlapping(range),
+            None => Vec::new(),
+        }
+    }
+
+    /// Adjusts the ranges of the entries of `file_id` to `edit`, which is expressed in terms of
+    /// the text the entries were attached to.
+    pub fn apply_edit(&self, file_id: FileId, edit: &TextEdit) {
+        let mut files = self.files.write().unwrap();
+        let Some(entries) = files.get_mut(&file_id) else { return };
+        // The indels are sorted and disjoint, so going backwards keeps the offsets of the
+        // remaining ones valid.
+        for indel in edit.iter().rev() {
+            let insert_len = TextSize::of(indel.insert.as_str());
+            entries.retain_mut(|(range, _)| match adjust_range(*range, indel.delete, insert_len) {
+                Some(it) => {
+                    *range = it;
+                    true
+                }
+                None => false,
+            });
+        }
+        entries.sort_by_key(|(range, _)| range.start());
+    }
+}
+
+/// The side tables of a database, shared with its snapshots.
+#[derive(Default)]
+pub(crate) struct SideTables {
+    tables: RwLock<FxHashMap<&'static str, Arc<dyn AnySideTable>>>,
+}
+
+trait AnySideTable: Send + Sync {
+    fn as_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync>;
+    fn has_entries(&self, file_id: FileId) -> bool;
+    fn apply_edit(&self, file_id: FileId, edit: &TextEdit);
+    fn copy(&self) -> Arc<dyn AnySideTable>;
+}
+
+impl<T: Clone + Send + Sync + 'static> AnySideTable for SideTable<T> {
+    fn as_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self
+    }
+    fn has_entries(&self, file_id: FileId) -> bool {
+        self.files.read().unwrap().get(&file_id).map_or(false, |it| !it.is_empty())
+    }
+    fn apply_edit(&self, file_id: FileId, edit: &TextEdit) {
+        SideTable::apply_edit(self, file_id, edit)
+    }
+    fn copy(&self) -> Arc<dyn AnySideTable> {
+        let files = self.files.read().unwrap().clone();
+        Arc::new(SideTable { name: self.name, files: RwLock::new(files) })
+    }
+}
+
+impl SideTables {
+    /// Adjusts the entries of `file_id` to its change from `old_text` to `new_text`.
+    pub(crate) fn file_changed(&self, file_id: FileId, old_text: &str, new_text: &str) {
+        let tables = self.tables.read().unwrap();
+        if let Some(edit) = text_diff(old_text, new_text) {
+            for table in tables.values() {
+                table.apply_edit(file_id, &edit);
+            }
+        }
+    }
+
+    pub(crate) fn has_entries(&self, file_id: FileId) -> bool {
+        self.tables.read().unwrap().values().any(|table| table.has_entries(file_id))
+    }
+
+    /// Copies the tables with their current entries, for a database whose texts stop following
+    /// the changes of this one.
+    pub(crate) fn copy(&self) -> SideTables {
+        let tables = self.tables.read().unwrap();
+        let tables = tables.iter().map(|(&name, table)| (name, table.copy())).collect();
+        SideTables { tables: RwLock::new(tables) }
+    }
+}
+
+impl RootDatabase {
+    /// Registers a side table with entries of type `T`, or returns the one registered under
+    /// `name` already.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a side table with entries of another type is registered under `name`.
+    pub fn register_side_table<T: Clone + Send + Sync + 'static>(
+        &mut self,
+        name: &'static str,
+    ) -> Arc<SideTable<T>> {
+        let table = self
+            .side_tables
+            .tables
+            .write()
+            .unwrap()
+            .entry(name)
+            .or_insert_with(|| {
+                Arc::new(SideTable::<T> { name, files: RwLock::new(FxHashMap::default()) })
+            })
+            .clone();
+        match table.as_any().downcast() {
+            Ok(it) => it,
+            Err(_) => panic!("side table `{name}` is registered with another type of entries"),
+        }
+    }
+
+    /// The side table registered under `name`, if its entries are of type `T`.
+    pub fn side_table<T: Clone + Send + Sync + 'static>(
+        &self,
+        name: &str,
+    ) -> Option<Arc<SideTable<T>>> {
+        let table = self.side_tables.tables.read().unwrap().get(name)?.clone();
+        table.as_any().downcast().ok()
+    }
+}
+
+/// The range of the whole source of `def`, in the file it's written in.
+pub fn definition_range(db: &RootDatabase, def: Definition) -> Option<FileRange> {
+    let node: InFile<SyntaxNode> = match def {
+        Definition::Module(it) => it.definition_source(db).map(|it| match it {
+            ModuleSource::SourceFile(it) => it.syntax().clone(),
+            ModuleSource::Module(it) => it.syntax().clone(),
+            ModuleSource::BlockExpr(it) => it.syntax().clone(),
+        }),
+        Definition::Function(it) => it.source(db)?.map(|it| it.syntax().clone()),
+        Definition::Adt(it) => it.source(db)?.map(|it| it.syntax().clone()),
+        Definition::Variant(it) => it.source(db)?.map(|it| it.syntax().clone()),
+        Definition::Const(it) => it.source(db)?.map(|it| it.syntax().clone()),
+        Definition::Static(it) => it.source(db)?.map(|it| it.syntax().clone()),
+        Definition::Trait(it) => it.source(db)?.map(|it| it.syntax().clone()),
+        Definition::TraitAlias(it) => it.source(db)?.map(|it| it.syntax().clone()),
+        Definition::TypeAlias(it) => it.source(db)?.map(|it| it.syntax().clone()),
+        Definition::Macro(it) => it.source(db)?.map(|it| it.syntax().clone()),
+        Definition::Field(it) => it.source(db)?.map(|it| match it {
+            FieldSource::Named(it) => it.syntax().clone(),
+            FieldSource::Pos(it) => it.syntax().clone(),
+        }),
+        Definition::SelfType(it) => it.source(db)?.map(|it| it.syntax().clone()),
+        Definition::Local(it) => {
+            let src = it.primary_source(db);
+            InFile::new(src.file(), src.syntax().clone())
+        }
+        Definition::GenericParam(hir::GenericParam::LifetimeParam(it)) => {
+            it.source(db)?.map(|it| it.syntax().clone())
+        }
+        Definition::GenericParam(hir::GenericParam::TypeParam(it)) => {
+            it.merge().source(db)?.map(|it| it.syntax().clone())
+        }
+        Definition::GenericParam(hir::GenericParam::ConstParam(it)) => {
+            it.merge().source(db)?.map(|it| it.syntax().clone())
+        }
+        Definition::Label(_)
+        | Definition::BuiltinType(_)
+        | Definition::DeriveHelper(_)
+        | Definition::BuiltinAttr(_)
+        | Definition::ToolModule(_) => return None,
+    };
+    Some(node.as_ref().original_file_range_full(db))
+}
+
+fn overlaps(a: TextRange, b: TextRange) -> bool {
+    match a.intersect(b) {
+        Some(it) => !it.is_empty() || a.is_empty() || b.is_empty(),
+        None => false,
+    }
+}
+
+/// The edit turning `old` into `new`, replacing the region between their common prefix and
+/// suffix, or `None` if they are equal.
+fn text_diff(old: &str, new: &str) -> Option<TextEdit> {
+    if old == new {
+        return None;
+    }
+    let mut prefix = old.bytes().zip(new.bytes()).take_while(|(a, b)| a == b).count();
+    while !old.is_char_boundary(prefix) || !new.is_char_boundary(prefix) {
+        prefix -= 1;
+    }
+    let max_suffix = old.len().min(new.len()) - prefix;
+    let mut suffix = old
+        .bytes()
+        .rev()
+        .zip(new.bytes().rev())
+        .take(max_suffix)
+        .take_while(|(a, b)| a == b)
+        .count();
+    while !old.is_char_boundary(old.len() - suffix) || !new.is_char_boundary(new.len() - suffix) {
+        suffix -= 1;
+    }
+    let delete =
+        TextRange::new(TextSize::from(prefix as u32), TextSize::from((old.len() - suffix) as u32));
+    Some(TextEdit::replace(delete, new[prefix..new.len() - suffix].to_owned()))
+}
+
+/// The range of the text of `range` once `delete` is replaced by `insert_len` bytes, or `None` if
+/// all of its text is deleted.
+fn adjust_range(range: TextRange, delete: TextRange, insert_len: TextSize) -> Option<TextRange> {
+    let shift = |offset: TextSize| offset - delete.end() + delete.start() + insert_len;
+    if range.end() <= delete.start() {
+        return Some(range);
+    }
+    if range.start() >= delete.end() {
+        return Some(TextRange::new(shift(range.start()), shift(range.end())));
+    }
+    if !delete.is_empty() && delete.contains_range(range) {
+        return None;
+    }
+    let start =
+        if range.start() <= delete.start() { range.start() } else { delete.start() + insert_len };
+    let end = if range.end() >= delete.end() { shift(range.end()) } else { delete.start() };
+    Some(TextRange::new(start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::{fixture::WithFixture, Change};
+    use hir::Semantics;
+
+    use super::*;
+
+    #[test]
+    fn entries_follow_edits() {
+        let (mut db, file_id) = RootDatabase::with_single_file("fn foo() {}\nfn bar() {}\n");
+        let coverage = db.register_side_table::<u32>("coverage");
+        let range = |start: u32, end: u32| FileRange {
+            file_id,
+            range: TextRange::new(start.into(), end.into()),
+        };
+        coverage.insert(range(0, 11), 1);
+        coverage.insert(range(12, 23), 2);
+        coverage.insert(range(3, 6), 3);
+
+        let mut change = Change::new();
+        change.change_file(file_id, Some(triomphe::Arc::from("fn fo() { 92 }\nfn bar() {}\n")));
+        db.apply_change(change);
+
+        assert!(db.side_table::<String>("coverage").is_none());
+        let coverage = db.side_table::<u32>("coverage").unwrap();
+        assert_eq!(
+            coverage.entries(file_id),
+            [(range(0, 14).range, 1), (range(3, 5).range, 3), (range(15, 26).range, 2)]
+        );
+
+        let sema = Semantics::new(&db);
+        let module = sema.to_module_def(file_id).unwrap();
+        let bar = module
+            .declarations(&db)
+            .into_iter()
+            .find_map(|it| match it {
+                hir::ModuleDef::Function(it) if it.name(&db).to_smol_str() == "bar" => Some(it),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(
+            coverage.for_definition(&db, Definition::Function(bar)),
+            [(range(15, 26).range, 2)]
+        );
+    }
+}
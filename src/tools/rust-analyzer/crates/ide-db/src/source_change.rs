@@ -3,20 +3,53 @@
 //!
 //! It can be viewed as a dual for `Change`.
 
-use std::{collections::hash_map::Entry, iter, mem};
+use std::{collections::hash_map::Entry, iter, mem, ops::Range};
 
-use crate::SnippetCap;
-use base_db::{AnchoredPathBuf, FileId};
+use crate::{FxHashMap, RootDatabase, SnippetCap};
+use base_db::{AnchoredPathBuf, FileId, FileLoader};
+use itertools::Itertools;
 use nohash_hasher::IntMap;
-use stdx::never;
+use stdx::{format_to, never};
 use syntax::{algo, ast, ted, AstNode, SyntaxNode, SyntaxNodePtr, TextRange, TextSize};
-use text_edit::{TextEdit, TextEditBuilder};
+use text_edit::{ChangeAnnotationId, Indel, TextEdit, TextEditBuilder};
+use triomphe::Arc;
+
+mod diff;
 
 #[derive(Default, Debug, Clone)]
 pub struct SourceChange {
     pub source_file_edits: IntMap<FileId, TextEdit>,
     pub file_system_edits: Vec<FileSystemEdit>,
     pub is_snippet: bool,
+    /// The snippets to place in the files after their edits are applied. The text of the edits
+    /// of these files is plain text, which is escaped when the snippets are placed into it.
+    pub snippet_edits: IntMap<FileId, SnippetEdit>,
+    /// Edits of files that aren't part of the crate graph, like `Cargo.toml`, which can't be
+    /// identified by a [`FileId`].
+    pub other_file_edits: Vec<(AnchoredPathBuf, TextEdit)>,
+    /// The edits undoing the ones of `source_file_edits`, which hold the text those replace.
+    /// Recorded by [`SourceChange::record_replaced_text`], and dropped for a file whose edits
+    /// change afterwards.
+    pub undo_edits: IntMap<FileId, TextEdit>,
+    /// The descriptions of the reasons the indels of `source_file_edits` are annotated with.
+    pub annotations: FxHashMap<ChangeAnnotationId, String>,
+    /// What kind of change this is, for clients to group changes or decide which ones to
+    /// apply without asking.
+    pub kind: Option<ChangeKind>,
+    /// The feature the change comes from, like the id of an assist.
+    pub origin: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangeKind {
+    Rename,
+    /// Adds an import for an unresolved name.
+    ImportFix,
+    Refactor,
+    /// Fixes a diagnostic or an obvious mistake.
+    QuickFix,
+    /// Generates new code, like an impl or a getter.
+    Generated,
 }
 
 impl SourceChange {
@@ -26,7 +59,24 @@ pub fn from_edits(
         source_file_edits: IntMap<FileId, TextEdit>,
         file_system_edits: Vec<FileSystemEdit>,
     ) -> Self {
-        SourceChange { source_file_edits, file_system_edits, is_snippet: false }
+        SourceChange {
+            source_file_edits,
+            file_system_edits,
+            is_snippet: false,
+            snippet_edits: Default::default(),
+            other_file_edits: Vec::new(),
+            undo_edits: Default::default(),
+            annotations: Default::default(),
+            kind: None,
+            origin: None,
+        }
+    }
+
+    /// Sets the kind of the change and the feature it comes from.
+    pub fn with_kind(mut self, kind: ChangeKind, origin: impl Into<String>) -> SourceChange {
+        self.kind = Some(kind);
+        self.origin = Some(origin.into());
+        self
     }
 
     pub fn from_text_edit(file_id: FileId, edit: TextEdit) -> Self {
@@ -38,7 +88,13 @@ pub fn from_text_edit(file_id: FileId, edit: TextEdit) -> Self {
 
     /// Inserts a [`TextEdit`] for the given [`FileId`]. This properly handles merging existing
     /// edits for a file if some already exist.
+    ///
+    /// Edits of a file this change deletes are dropped.
     pub fn insert_source_edit(&mut self, file_id: FileId, edit: TextEdit) {
+        if self.deletes_file(file_id) {
+            return;
+        }
+        self.undo_edits.remove(&file_id);
         match self.source_file_edits.entry(file_id) {
             Entry::Occupied(mut entry) => {
                 never!(entry.get_mut().union(edit).is_err(), "overlapping edits for same file");
@@ -49,10 +105,102 @@ pub fn insert_source_edit(&mut self, file_id: FileId, edit: TextEdit) {
         }
     }
 
+    /// Inserts a [`TextEdit`] like [`SourceChange::insert_source_edit`], annotating all of its
+    /// indels with the reason they are made.
+    pub fn insert_annotated_source_edit(
+        &mut self,
+        file_id: FileId,
+        edit: TextEdit,
+        annotation: EditAnnotation,
+    ) {
+        if self.deletes_file(file_id) {
+            return;
+        }
+        let mut edit = edit;
+        edit.annotate(&annotation.id);
+        self.annotations.insert(annotation.id, annotation.description);
+        self.insert_source_edit(file_id, edit);
+    }
+
+    /// The annotation of `indel`, if it is annotated.
+    pub fn annotation(&self, indel: &Indel) -> Option<EditAnnotation> {
+        let id = indel.annotation.as_ref()?;
+        let description = self.annotations.get(id)?;
+        Some(EditAnnotation { id: id.clone(), description: description.clone() })
+    }
+
     pub fn push_file_system_edit(&mut self, edit: FileSystemEdit) {
+        if let FileSystemEdit::DeleteFile { file } = edit {
+            self.source_file_edits.remove(&file);
+            self.snippet_edits.remove(&file);
+            self.undo_edits.remove(&file);
+        }
         self.file_system_edits.push(edit);
     }
 
+    /// Inserts a [`TextEdit`] for a file that isn't part of the crate graph, merging it with the
+    /// edits of the same path if some already exist.
+    pub fn insert_other_file_edit(&mut self, path: AnchoredPathBuf, edit: TextEdit) {
+        match self.other_file_edits.iter_mut().find(|(it, _)| *it == path) {
+            Some((_, existing)) => {
+                never!(existing.union(edit).is_err(), "overlapping edits for same file");
+            }
+            None => self.other_file_edits.push((path, edit)),
+        }
+    }
+
+    /// Inserts a [`SnippetEdit`] for the given [`FileId`], adding its snippets to the ones
+    /// already there.
+    pub fn insert_snippet_edit(&mut self, file_id: FileId, edit: SnippetEdit) {
+        if self.deletes_file(file_id) {
+            return;
+        }
+        self.is_snippet = true;
+        match self.snippet_edits.entry(file_id) {
+            Entry::Occupied(mut entry) => entry.get_mut().0.extend(edit.0),
+            Entry::Vacant(entry) => {
+                entry.insert(edit);
+            }
+        }
+    }
+
+    /// Records the text replaced by the edits of every file, from the texts of the files before
+    /// the change is applied, so that the change can be undone with [`SourceChange::inverse`].
+    pub fn record_replaced_text(&mut self, files: &dyn FileTextProvider) {
+        self.undo_edits = self
+            .source_file_edits
+            .iter()
+            .map(|(&file_id, edit)| (file_id, undo_edit(&files.file_text(file_id), edit)))
+            .collect();
+    }
+
+    /// The change undoing this one once it's applied, if the replaced text of all edited files
+    /// is recorded. Changes with file system edits or edits of files outside of the crate graph
+    /// can't be undone, and neither can edits written in snippet syntax, as the client changes
+    /// their text.
+    pub fn inverse(&self) -> Option<SourceChange> {
+        if !self.file_system_edits.is_empty() || !self.other_file_edits.is_empty() {
+            return None;
+        }
+        self.source_file_edits
+            .keys()
+            .map(|file_id| {
+                if self.is_snippet && !self.snippet_edits.contains_key(file_id) {
+                    return None;
+                }
+                Some((*file_id, self.undo_edits.get(file_id)?.clone()))
+            })
+            .collect::<Option<IntMap<_, _>>>()
+            .map(SourceChange::from)
+    }
+
+    /// Whether this change deletes the given file.
+    pub fn deletes_file(&self, file_id: FileId) -> bool {
+        self.file_system_edits
+            .iter()
+            .any(|it| matches!(it, FileSystemEdit::DeleteFile { file } if *file == file_id))
+    }
+
     pub fn get_source_edit(&self, file_id: FileId) -> Option<&TextEdit> {
         self.source_file_edits.get(&file_id)
     }
@@ -60,9 +208,260 @@ pub fn get_source_edit(&self, file_id: FileId) -> Option<&TextEdit> {
     pub fn merge(mut self, other: SourceChange) -> SourceChange {
         self.extend(other.source_file_edits);
         self.extend(other.file_system_edits);
+        for (file_id, edit) in other.snippet_edits {
+            self.insert_snippet_edit(file_id, edit);
+        }
+        for (path, edit) in other.other_file_edits {
+            self.insert_other_file_edit(path, edit);
+        }
+        self.annotations.extend(other.annotations);
         self.is_snippet |= other.is_snippet;
+        // The merged change is of the kind of the first change that has one.
+        if self.kind.is_none() {
+            self.kind = other.kind;
+            self.origin = other.origin;
+        }
         self
     }
+
+    /// Merges the changes contributed by several features, each identified by a label. Unlike
+    /// [`SourceChange::merge`], which assumes the changes don't collide, this reports every pair
+    /// of edits that overlap, and every edit of a file another change deletes.
+    pub fn merge_all<L: Clone>(
+        changes: impl IntoIterator<Item = (L, SourceChange)>,
+    ) -> Result<SourceChange, Vec<MergeConflict<L>>> {
+        let mut res = SourceChange::default();
+        let mut merged_edits: IntMap<FileId, Vec<(L, Indel)>> = IntMap::default();
+        let mut merged_other_edits: Vec<(AnchoredPathBuf, Vec<(L, Indel)>)> = Vec::new();
+        let mut deleted_files: IntMap<FileId, L> = IntMap::default();
+        let mut conflicts = Vec::new();
+        for (label, change) in changes {
+            for (&file_id, edit) in &change.source_file_edits {
+                if let Some(delete) = deleted_files.get(&file_id) {
+                    conflicts.push(MergeConflict::EditOfDeletedFile {
+                        file_id,
+                        edit: label.clone(),
+                        delete: delete.clone(),
+                    });
+                    continue;
+                }
+                let Some(merged) = merged_edits.get(&file_id) else { continue };
+                for indel in edit.iter() {
+                    for (merged_label, merged_indel) in merged {
+                        if indels_overlap(merged_indel, indel) {
+                            conflicts.push(MergeConflict::OverlappingEdits {
+                                file_id,
+                                existing: (merged_label.clone(), merged_indel.clone()),
+                                incoming: (label.clone(), indel.clone()),
+                            });
+                        }
+                    }
+                }
+            }
+            for edit in &change.file_system_edits {
+                let &FileSystemEdit::DeleteFile { file: file_id } = edit else { continue };
+                if let Some((edit, _)) = merged_edits.get(&file_id).and_then(|it| it.first()) {
+                    conflicts.push(MergeConflict::EditOfDeletedFile {
+                        file_id,
+                        edit: edit.clone(),
+                        delete: label.clone(),
+                    });
+                }
+                deleted_files.entry(file_id).or_insert_with(|| label.clone());
+            }
+            for (path, edit) in &change.other_file_edits {
+                let Some((_, merged)) = merged_other_edits.iter().find(|(it, _)| it == path) else {
+                    continue;
+                };
+                for indel in edit.iter() {
+                    for (merged_label, merged_indel) in merged {
+                        if indels_overlap(merged_indel, indel) {
+                            conflicts.push(MergeConflict::OverlappingOtherFileEdits {
+                                path: path.clone(),
+                                existing: (merged_label.clone(), merged_indel.clone()),
+                                incoming: (label.clone(), indel.clone()),
+                            });
+                        }
+                    }
+                }
+            }
+
+            for (&file_id, edit) in &change.source_file_edits {
+                merged_edits
+                    .entry(file_id)
+                    .or_default()
+                    .extend(edit.iter().map(|indel| (label.clone(), indel.clone())));
+            }
+            for (path, edit) in &change.other_file_edits {
+                let indels = edit.iter().map(|indel| (label.clone(), indel.clone()));
+                match merged_other_edits.iter_mut().find(|(it, _)| it == path) {
+                    Some((_, merged)) => merged.extend(indels),
+                    None => merged_other_edits.push((path.clone(), indels.collect())),
+                }
+            }
+            if conflicts.is_empty() {
+                res = res.merge(change);
+            }
+        }
+        if conflicts.is_empty() {
+            Ok(res)
+        } else {
+            Err(conflicts)
+        }
+    }
+}
+
+/// Two edits of [`SourceChange::merge_all`] that can't both be applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeConflict<L> {
+    /// Two changes edit overlapping ranges of a file. The edits are given with the labels of the
+    /// changes they come from, the one merged first being `existing`.
+    OverlappingEdits { file_id: FileId, existing: (L, Indel), incoming: (L, Indel) },
+    /// Like [`MergeConflict::OverlappingEdits`], for a file that isn't part of the crate graph.
+    OverlappingOtherFileEdits { path: AnchoredPathBuf, existing: (L, Indel), incoming: (L, Indel) },
+    /// A change edits a file another one deletes.
+    EditOfDeletedFile { file_id: FileId, edit: L, delete: L },
+}
+
+/// Whether two indels touch the same text. Identical indels don't conflict, and neither do
+/// insertions at the same offset, or an insertion at the end of another edit. An insertion at
+/// the start of another edit does, as it's unclear which one goes first.
+fn indels_overlap(a: &Indel, b: &Indel) -> bool {
+    let (a_range, b_range) = (a.delete, b.delete);
+    if a == b {
+        false
+    } else if a_range.start() == b_range.start() {
+        !(a_range.is_empty() && b_range.is_empty())
+    } else {
+        a_range.start() < b_range.end() && b_range.start() < a_range.end()
+    }
+}
+
+impl SourceChange {
+    /// Applies the change in memory, without going through a client. Snippet syntax in the
+    /// edits of a change that `is_snippet` is kept as is, and edits of files that aren't part of
+    /// the crate graph are dropped if `files` can't provide their text.
+    pub fn apply(&self, files: &dyn FileTextProvider) -> AppliedChange {
+        let mut res = AppliedChange::default();
+        for (&file_id, edit) in &self.source_file_edits {
+            let mut text = files.file_text(file_id).to_string();
+            edit.apply(&mut text);
+            res.file_texts.insert(file_id, text);
+        }
+        for (path, edit) in &self.other_file_edits {
+            if let Some(mut text) = files.other_file_text(path) {
+                edit.apply(&mut text);
+                res.other_file_texts.push((path.clone(), text));
+            }
+        }
+        for edit in &self.file_system_edits {
+            match edit {
+                FileSystemEdit::CreateFile { dst, initial_contents } => {
+                    res.new_files.push((dst.clone(), initial_contents.clone()))
+                }
+                FileSystemEdit::MoveFile { src, dst } => res.moved_files.push((*src, dst.clone())),
+                FileSystemEdit::MoveDir { src, src_id: _, dst } => {
+                    res.moved_dirs.push((src.clone(), dst.clone()))
+                }
+                FileSystemEdit::DeleteFile { file } => res.deleted_files.push(*file),
+            }
+        }
+        res
+    }
+
+    /// The edits formatting the items changed by this change, once it's applied. Each top-level
+    /// item containing an edit is passed to `format`, which returns the item formatted, or
+    /// `None` to leave it as is, so that generated code can follow the style of the rest of the
+    /// file without the whole file being reformatted.
+    pub fn formatting_edits(
+        &self,
+        files: &dyn FileTextProvider,
+        format: &dyn Fn(&str) -> Option<String>,
+    ) -> SourceChange {
+        let mut res = SourceChange::default();
+        for (&file_id, edit) in &self.source_file_edits {
+            let mut text = files.file_text(file_id).to_string();
+            edit.apply(&mut text);
+            let file = ast::SourceFile::parse(&text).tree();
+            let items = inserted_ranges(edit)
+                .into_iter()
+                .filter_map(|range| {
+                    file.syntax()
+                        .covering_element(range)
+                        .ancestors()
+                        .filter_map(ast::Item::cast)
+                        .last()
+                })
+                .unique();
+            let mut builder = TextEdit::builder();
+            for item in items {
+                let item_text = item.syntax().text().to_string();
+                let Some(formatted) = format(&item_text) else { continue };
+                let formatted = formatted.trim_end();
+                if formatted != item_text {
+                    builder.replace(item.syntax().text_range(), formatted.to_owned());
+                }
+            }
+            let formatting_edit = builder.finish();
+            if !formatting_edit.is_empty() {
+                res.insert_source_edit(file_id, formatting_edit);
+            }
+        }
+        res
+    }
+}
+
+/// The edit restoring `text` once `edit` is applied to it.
+fn undo_edit(text: &str, edit: &TextEdit) -> TextEdit {
+    let mut builder = TextEdit::builder();
+    for (indel, inserted) in edit.iter().zip(inserted_ranges(edit)) {
+        builder.replace(inserted, text[indel.delete].to_owned());
+    }
+    builder.finish()
+}
+
+/// The ranges of the text inserted by the indels of `edit`, once it's applied.
+fn inserted_ranges(edit: &TextEdit) -> Vec<TextRange> {
+    let mut delta: i64 = 0;
+    edit.iter()
+        .map(|indel| {
+            let start = TextSize::from((u32::from(indel.delete.start()) as i64 + delta) as u32);
+            let inserted = TextRange::at(start, TextSize::of(indel.insert.as_str()));
+            delta +=
+                i64::from(u32::from(inserted.len())) - i64::from(u32::from(indel.delete.len()));
+            inserted
+        })
+        .collect()
+}
+
+/// Gives [`SourceChange::apply`] and [`SourceChange::record_replaced_text`] the texts of the
+/// files they need.
+pub trait FileTextProvider {
+    fn file_text(&self, file_id: FileId) -> Arc<str>;
+
+    /// The text of a file that isn't part of the crate graph, if it can be read.
+    fn other_file_text(&self, _path: &AnchoredPathBuf) -> Option<String> {
+        None
+    }
+}
+
+impl FileTextProvider for RootDatabase {
+    fn file_text(&self, file_id: FileId) -> Arc<str> {
+        FileLoader::file_text(self, file_id)
+    }
+}
+
+/// The result of applying a [`SourceChange`] in memory.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AppliedChange {
+    /// The new texts of the edited files. Moved files keep their [`FileId`] here.
+    pub file_texts: IntMap<FileId, String>,
+    pub other_file_texts: Vec<(AnchoredPathBuf, String)>,
+    /// The created files with their contents.
+    pub new_files: Vec<(AnchoredPathBuf, String)>,
+    pub moved_files: Vec<(FileId, AnchoredPathBuf)>,
+    pub moved_dirs: Vec<(AnchoredPathBuf, AnchoredPathBuf)>,
+    pub deleted_files: Vec<FileId>,
 }
 
 impl Extend<(FileId, TextEdit)> for SourceChange {
@@ -77,9 +476,169 @@ fn extend<T: IntoIterator<Item = FileSystemEdit>>(&mut self, iter: T) {
     }
 }
 
+/// Why an edit is made, for clients that let users review the edits of a change grouped by it,
+/// like "rename of field `foo`" or "import insertion".
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EditAnnotation {
+    /// Identifies the group of the edit, and is the same for all edits made for the same reason.
+    pub id: ChangeAnnotationId,
+    pub description: String,
+}
+
+impl EditAnnotation {
+    pub fn new(id: impl Into<String>, description: impl Into<String>) -> EditAnnotation {
+        EditAnnotation { id: ChangeAnnotationId(id.into()), description: description.into() }
+    }
+}
+
 impl From<IntMap<FileId, TextEdit>> for SourceChange {
     fn from(source_file_edits: IntMap<FileId, TextEdit>) -> SourceChange {
-        SourceChange { source_file_edits, file_system_edits: Vec::new(), is_snippet: false }
+        SourceChange::from_edits(source_file_edits, Vec::new())
+    }
+}
+
+/// A place for the cursor in a snippet, in the text after the edits of the file are applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Snippet {
+    /// A tab stop, `$n`.
+    Tabstop(TextSize),
+    /// A placeholder selecting the text in the range, `${n:text}`.
+    Placeholder(TextRange),
+}
+
+impl Snippet {
+    fn range(self) -> TextRange {
+        match self {
+            Snippet::Tabstop(offset) => TextRange::empty(offset),
+            Snippet::Placeholder(range) => range,
+        }
+    }
+}
+
+/// The snippets of a file with their indices. Snippets with the same index are linked, so
+/// editing one of them edits all of them, and index 0 is the final cursor position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnippetEdit(Vec<(u32, Snippet)>);
+
+impl SnippetEdit {
+    /// Numbers the snippets in order, except for the last one which becomes the final cursor
+    /// position.
+    pub fn new(snippets: Vec<Snippet>) -> SnippetEdit {
+        let last = snippets.len().saturating_sub(1);
+        let snippets = snippets
+            .into_iter()
+            .enumerate()
+            .map(|(idx, snippet)| (if idx == last { 0 } else { idx as u32 + 1 }, snippet))
+            .collect();
+        SnippetEdit(snippets)
+    }
+
+    /// Uses the given indices, giving linked snippets the same one.
+    pub fn with_indices(snippets: Vec<(u32, Snippet)>) -> SnippetEdit {
+        SnippetEdit(snippets)
+    }
+
+    pub fn snippets(&self) -> &[(u32, Snippet)] {
+        &self.0
+    }
+
+    /// Turns `edit`, whose inserted text is plain text, into an edit inserting text in the LSP
+    /// snippet syntax: the text is escaped and the snippets are placed into it. Snippets outside
+    /// of the inserted text get edits of their own. `text` is the text of the file before `edit`
+    /// is applied.
+    pub fn into_snippet_text_edit(self, text: &str, edit: TextEdit) -> TextEdit {
+        // The indels, with their range in the text after the edit and the snippets inside them,
+        // relative to the start of their inserted text.
+        let mut indels: Vec<(Indel, TextRange, SnippetEdit)> = Vec::new();
+        let mut delta: i64 = 0;
+        for indel in edit {
+            let start = TextSize::from((u32::from(indel.delete.start()) as i64 + delta) as u32);
+            let range = TextRange::at(start, TextSize::of(indel.insert.as_str()));
+            delta += i64::from(u32::from(range.len())) - i64::from(u32::from(indel.delete.len()));
+            indels.push((indel, range, SnippetEdit(Vec::new())));
+        }
+
+        for (index, snippet) in self.0 {
+            let range = snippet.range();
+            if let Some((_, indel_range, snippets)) =
+                indels.iter_mut().find(|(_, it, _)| it.contains_range(range))
+            {
+                let relative = range - indel_range.start();
+                snippets.0.push((index, relative_snippet(snippet, relative)));
+                continue;
+            }
+            // The snippet is in text the edit leaves as is, replace that text with itself.
+            let delta: i64 = indels
+                .iter()
+                .filter(|(_, it, _)| it.end() <= range.start())
+                .map(|(indel, it, _)| {
+                    i64::from(u32::from(it.len())) - i64::from(u32::from(indel.delete.len()))
+                })
+                .sum();
+            let start = TextSize::from((u32::from(range.start()) as i64 - delta) as u32);
+            let original = TextRange::at(start, range.len());
+            let overlaps =
+                |it: TextRange| it.start() < original.end() && original.start() < it.end();
+            if never!(
+                indels.iter().any(|(indel, _, _)| overlaps(indel.delete)),
+                "snippet overlaps an edit"
+            ) {
+                continue;
+            }
+            let Some(original_text) = text.get(Range::<usize>::from(original)) else { continue };
+            let indel = Indel::replace(original, original_text.to_owned());
+            let snippet = relative_snippet(snippet, original - original.start());
+            indels.push((indel, range, SnippetEdit(vec![(index, snippet)])));
+        }
+
+        let mut builder = TextEdit::builder();
+        for (indel, _, SnippetEdit(mut snippets)) in indels {
+            snippets.sort_by_key(|(_, snippet)| snippet.range().start());
+            let mut insert = String::new();
+            let mut offset = TextSize::from(0);
+            for (index, snippet) in snippets {
+                let range = snippet.range();
+                if range.start() < offset {
+                    // Overlapping snippets can't be expressed.
+                    continue;
+                }
+                escape_snippet_text(
+                    &mut insert,
+                    &indel.insert[TextRange::new(offset, range.start())],
+                );
+                match snippet {
+                    Snippet::Tabstop(_) => format_to!(insert, "${index}"),
+                    Snippet::Placeholder(_) => {
+                        format_to!(insert, "${{{index}:");
+                        escape_snippet_text(&mut insert, &indel.insert[range]);
+                        insert.push('}');
+                    }
+                }
+                offset = range.end();
+            }
+            escape_snippet_text(&mut insert, &indel.insert[usize::from(offset)..]);
+            builder.push(Indel {
+                annotation: indel.annotation,
+                ..Indel::replace(indel.delete, insert)
+            });
+        }
+        builder.finish()
+    }
+}
+
+fn relative_snippet(snippet: Snippet, range: TextRange) -> Snippet {
+    match snippet {
+        Snippet::Tabstop(_) => Snippet::Tabstop(range.start()),
+        Snippet::Placeholder(_) => Snippet::Placeholder(range),
+    }
+}
+
+fn escape_snippet_text(buf: &mut String, text: &str) {
+    for c in text.chars() {
+        if matches!(c, '\\' | '$' | '}') {
+            buf.push('\\');
+        }
+        buf.push(c);
     }
 }
 
@@ -230,6 +789,16 @@ pub fn move_file(&mut self, src: FileId, dst: AnchoredPathBuf) {
         let file_system_edit = FileSystemEdit::MoveFile { src, dst };
         self.source_change.push_file_system_edit(file_system_edit);
     }
+    pub fn delete_file(&mut self, file: FileId) {
+        self.source_change.push_file_system_edit(FileSystemEdit::DeleteFile { file });
+    }
+    /// Overrides the kind the change would get from the feature building it.
+    pub fn set_kind(&mut self, kind: ChangeKind) {
+        self.source_change.kind = Some(kind);
+    }
+    pub fn edit_other_file(&mut self, path: AnchoredPathBuf, edit: TextEdit) {
+        self.source_change.insert_other_file_edit(path, edit);
+    }
     pub fn trigger_signature_help(&mut self) {
         self.trigger_signature_help = true;
     }
@@ -269,15 +838,12 @@ pub enum FileSystemEdit {
     CreateFile { dst: AnchoredPathBuf, initial_contents: String },
     MoveFile { src: FileId, dst: AnchoredPathBuf },
     MoveDir { src: AnchoredPathBuf, src_id: FileId, dst: AnchoredPathBuf },
+    DeleteFile { file: FileId },
 }
 
 impl From<FileSystemEdit> for SourceChange {
     fn from(edit: FileSystemEdit) -> SourceChange {
-        SourceChange {
-            source_file_edits: Default::default(),
-            file_system_edits: vec![edit],
-            is_snippet: false,
-        }
+        SourceChange::from_edits(Default::default(), vec![edit])
     }
 }
 
@@ -343,3 +909,150 @@ fn make_tab_stop(order: usize) -> SyntaxNode {
         stop
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_all_reports_conflicts() {
+        let file_id = FileId(0);
+        let replace = |range: TextRange, text: &str| {
+            SourceChange::from_text_edit(file_id, TextEdit::replace(range, text.to_owned()))
+        };
+        let insert = |offset: u32, text: &str| {
+            SourceChange::from_text_edit(file_id, TextEdit::insert(offset.into(), text.to_owned()))
+        };
+
+        let merged = SourceChange::merge_all([
+            ("a", replace(TextRange::new(0.into(), 4.into()), "x")),
+            ("b", insert(4, "y")),
+            ("c", insert(4, "z")),
+            ("d", replace(TextRange::new(0.into(), 4.into()), "x")),
+        ])
+        .unwrap();
+        assert_eq!(merged.get_source_edit(file_id).unwrap().len(), 3);
+
+        let conflicts = SourceChange::merge_all([
+            ("a", replace(TextRange::new(0.into(), 4.into()), "x")),
+            ("b", insert(2, "y")),
+            ("c", SourceChange::from(FileSystemEdit::DeleteFile { file: file_id })),
+        ])
+        .unwrap_err();
+        assert_eq!(
+            conflicts,
+            vec![
+                MergeConflict::OverlappingEdits {
+                    file_id,
+                    existing: ("a", Indel::replace(TextRange::new(0.into(), 4.into()), "x".into())),
+                    incoming: ("b", Indel::insert(2.into(), "y".into())),
+                },
+                MergeConflict::EditOfDeletedFile { file_id, edit: "a", delete: "c" },
+            ]
+        );
+    }
+
+    #[test]
+    fn apply_in_memory() {
+        struct Files;
+        impl FileTextProvider for Files {
+            fn file_text(&self, file_id: FileId) -> Arc<str> {
+                format!("file {}", file_id.0).into()
+            }
+        }
+
+        let mut change = SourceChange::from_text_edit(
+            FileId(0),
+            TextEdit::replace(TextRange::new(0.into(), 4.into()), "text of".to_owned()),
+        );
+        let dst = AnchoredPathBuf { anchor: FileId(0), path: "new.rs".to_owned() };
+        change.push_file_system_edit(FileSystemEdit::CreateFile {
+            dst: dst.clone(),
+            initial_contents: "fn new() {}".to_owned(),
+        });
+        change.push_file_system_edit(FileSystemEdit::DeleteFile { file: FileId(1) });
+
+        let applied = change.apply(&Files);
+        assert_eq!(applied.file_texts[&FileId(0)], "text of 0");
+        assert_eq!(applied.new_files, vec![(dst, "fn new() {}".to_owned())]);
+        assert_eq!(applied.deleted_files, vec![FileId(1)]);
+    }
+    #[test]
+    fn inverse_restores_text() {
+        struct Files;
+        impl FileTextProvider for Files {
+            fn file_text(&self, _: FileId) -> Arc<str> {
+                "fn foo() { foo(); }".into()
+            }
+        }
+
+        let mut builder = TextEdit::builder();
+        builder.replace(TextRange::new(3.into(), 6.into()), "renamed".to_owned());
+        builder.insert(11.into(), "bar(); ".to_owned());
+        builder.replace(TextRange::new(11.into(), 14.into()), "renamed".to_owned());
+        let mut change = SourceChange::from_text_edit(FileId(0), builder.finish());
+        assert!(change.inverse().is_none());
+
+        change.record_replaced_text(&Files);
+        let applied = change.apply(&Files);
+        assert_eq!(applied.file_texts[&FileId(0)], "fn renamed() { bar(); renamed(); }");
+
+        struct Applied(AppliedChange);
+        impl FileTextProvider for Applied {
+            fn file_text(&self, file_id: FileId) -> Arc<str> {
+                self.0.file_texts[&file_id].as_str().into()
+            }
+        }
+        let undone = change.inverse().unwrap().apply(&Applied(applied));
+        assert_eq!(undone.file_texts[&FileId(0)], "fn foo() { foo(); }");
+    }
+    #[test]
+    fn formatting_edits_format_changed_items() {
+        struct Files;
+        impl FileTextProvider for Files {
+            fn file_text(&self, _: FileId) -> Arc<str> {
+                "fn a() {  }\nfn b() {}\n".into()
+            }
+        }
+
+        let change = SourceChange::from_text_edit(
+            FileId(0),
+            TextEdit::insert(20.into(), "let  x =  1;".to_owned()),
+        );
+        let format = |text: &str| Some(text.split_whitespace().join(" ") + "\n");
+        let formatting = change.formatting_edits(&Files, &format);
+
+        struct Applied(AppliedChange);
+        impl FileTextProvider for Applied {
+            fn file_text(&self, file_id: FileId) -> Arc<str> {
+                self.0.file_texts[&file_id].as_str().into()
+            }
+        }
+        let applied = formatting.apply(&Applied(change.apply(&Files)));
+        assert_eq!(applied.file_texts[&FileId(0)], "fn a() {  }\nfn b() {let x = 1;}\n");
+    }
+    #[test]
+    fn annotations_follow_indels() {
+        let file_id = FileId(0);
+        let mut change = SourceChange::default();
+        change.insert_annotated_source_edit(
+            file_id,
+            TextEdit::insert(8.into(), "x".to_owned()),
+            EditAnnotation::new("a", "first"),
+        );
+        change.insert_annotated_source_edit(
+            file_id,
+            TextEdit::insert(8.into(), "$y".to_owned()),
+            EditAnnotation::new("b", "second"),
+        );
+
+        let edit = change.source_file_edits.remove(&file_id).unwrap();
+        let snippet_edit = SnippetEdit::new(vec![Snippet::Tabstop(8.into())]);
+        let edit = snippet_edit.into_snippet_text_edit("fn a() {}", edit);
+        let annotated = edit
+            .iter()
+            .map(|indel| (indel.insert.as_str(), change.annotation(indel).unwrap().description))
+            .collect::<Vec<_>>();
+        assert_eq!(annotated, vec![("$0x", "first".to_owned()), ("\\$y", "second".to_owned())]);
+    }
+}
@@ -0,0 +1,330 @@
+//! Rendering of [`SourceChange`]s as unified diffs, for tools that show or review changes
+//! outside of an editor.
+
+use std::ops::Range;
+
+use base_db::{AnchoredPathBuf, FileId, SourceDatabaseExt};
+use rustc_hash::FxHashMap;
+use stdx::format_to;
+use syntax::{TextRange, TextSize};
+use text_edit::TextEdit;
+
+use crate::{
+    source_change::{inserted_ranges, FileSystemEdit, SourceChange},
+    RootDatabase,
+};
+
+/// The number of unchanged lines shown around the changed ones.
+const CONTEXT_LINES: usize = 3;
+
+impl SourceChange {
+    /// Renders the change as a unified diff of all the files it affects, using the extended
+    /// headers of git for created, deleted and moved files. Edits of files that aren't part of
+    /// the crate graph are left out, as their text is unknown.
+    pub fn to_unified_diff(&self, db: &RootDatabase) -> String {
+        let file_path = |file_id: FileId| {
+            let source_root = db.source_root(db.file_source_root(file_id));
+            let path = source_root.path_for_file(&file_id);
+            path.map_or_else(|| format!("{file_id:?}"), |it| it.to_string())
+        };
+        let anchored_path = |path: &AnchoredPathBuf| {
+            let source_root = db.source_root(db.file_source_root(path.anchor));
+            let dst = source_root.path_for_file(&path.anchor).and_then(|anchor| {
+                let mut dir = anchor.clone();
+                dir.pop();
+                dir.join(&path.path)
+            });
+            dst.map_or_else(|| path.path.clone(), |it| it.to_string())
+        };
+        let moved_files: FxHashMap<FileId, String> = self
+            .file_system_edits
+            .iter()
+            .filter_map(|edit| match edit {
+                FileSystemEdit::MoveFile { src, dst } => Some((*src, anchored_path(dst))),
+                _ => None,
+            })
+            .collect();
+
+        // The diffs of the files, with the paths they are sorted by.
+        let mut diffs = Vec::new();
+        for (&file_id, edit) in &self.source_file_edits {
+            let old_path = file_path(file_id);
+            let new_path = moved_files.get(&file_id).unwrap_or(&old_path);
+            let mut diff = git_header(&old_path, new_path);
+            if *new_path != old_path {
+                format_to!(
+                    diff,
+                    "rename from {}\nrename to {}\n",
+                    relative(&old_path),
+                    relative(new_path)
+                );
+            }
+            format_to!(diff, "--- a/{}\n+++ b/{}\n", relative(&old_path), relative(new_path));
+            diff.push_str(&hunks(&db.file_text(file_id), edit));
+            diffs.push((old_path, diff));
+        }
+        for edit in &self.file_system_edits {
+            match edit {
+                FileSystemEdit::CreateFile { dst, initial_contents } => {
+                    let path = anchored_path(dst);
+                    let mut diff = git_header(&path, &path);
+                    diff.push_str("new file mode 100644\n");
+                    if !initial_contents.is_empty() {
+                        format_to!(diff, "--- /dev/null\n+++ b/{}\n", relative(&path));
+                        let edit = TextEdit::insert(0.into(), initial_contents.clone());
+                        diff.push_str(&hunks("", &edit));
+                    }
+                    diffs.push((path, diff));
+                }
+                FileSystemEdit::MoveFile { src, dst } => {
+                    // Moves of edited files are part of the diff of their edits.
+                    if !self.source_file_edits.contains_key(src) {
+                        diffs.push(rename_diff(file_path(*src), anchored_path(dst)));
+                    }
+                }
+                FileSystemEdit::MoveDir { src, src_id: _, dst } => {
+                    diffs.push(rename_diff(anchored_path(src), anchored_path(dst)));
+                }
+                FileSystemEdit::DeleteFile { file } => {
+                    let path = file_path(*file);
+                    let text = db.file_text(*file);
+                    let mut diff = git_header(&path, &path);
+                    diff.push_str("deleted file mode 100644\n");
+                    if !text.is_empty() {
+                        format_to!(diff, "--- a/{}\n+++ /dev/null\n", relative(&path));
+                        let edit = TextEdit::delete(TextRange::up_to(TextSize::of(&*text)));
+                        diff.push_str(&hunks(&text, &edit));
+                    }
+                    diffs.push((path, diff));
+                }
+            }
+        }
+        diffs.sort_by(|(a, _), (b, _)| a.cmp(b));
+        diffs.into_iter().map(|(_, diff)| diff).collect()
+    }
+}
+
+/// Paths are shown relative to the root of the file system, as that's the only root a change
+/// knows about.
+fn relative(path: &str) -> &str {
+    path.trim_start_matches('/')
+}
+
+fn git_header(old_path: &str, new_path: &str) -> String {
+    format!("diff --git a/{} b/{}\n", relative(old_path), relative(new_path))
+}
+
+fn rename_diff(old_path: String, new_path: String) -> (String, String) {
+    let mut diff = git_header(&old_path, &new_path);
+    format_to!(diff, "rename from {}\nrename to {}\n", relative(&old_path), relative(&new_path));
+    (old_path, diff)
+}
+
+/// The lines changed by one or more indels, in the text before and after they're applied.
+struct Block {
+    old: Range<usize>,
+    new: Range<usize>,
+}
+
+/// The hunks of the diff between `text` and the text `edit` turns it into.
+fn hunks(text: &str, edit: &TextEdit) -> String {
+    let mut new_text = text.to_owned();
+    edit.apply(&mut new_text);
+    let old_lines = Lines::new(text);
+    let new_lines = Lines::new(&new_text);
+
+    let mut blocks: Vec<Block> = Vec::new();
+    for (indel, inserted) in edit.iter().zip(inserted_ranges(edit)) {
+        let block =
+            Block { old: old_lines.spanning(indel.delete), new: new_lines.spanning(inserted) };
+        match blocks.last_mut() {
+            // Indels on the same lines change them together.
+            Some(last) if block.old.start < last.old.end || block.new.start < last.new.end => {
+                last.old.end = last.old.end.max(block.old.end);
+                last.new.end = last.new.end.max(block.new.end);
+            }
+            _ => blocks.push(block),
+        }
+    }
+    // The lines spanned by an indel can start or end with lines it doesn't change.
+    for block in &mut blocks {
+        while !block.old.is_empty()
+            && !block.new.is_empty()
+            && old_lines.line(block.old.start) == new_lines.line(block.new.start)
+        {
+            block.old.start += 1;
+            block.new.start += 1;
+        }
+        while !block.old.is_empty()
+            && !block.new.is_empty()
+            && old_lines.line(block.old.end - 1) == new_lines.line(block.new.end - 1)
+        {
+            block.old.end -= 1;
+            block.new.end -= 1;
+        }
+    }
+    blocks.retain(|block| !block.old.is_empty() || !block.new.is_empty());
+
+    let mut res = String::new();
+    let mut blocks = blocks.into_iter().peekable();
+    while let Some(first) = blocks.next() {
+        let mut hunk = vec![first];
+        while let Some(block) = blocks
+            .next_if(|block| block.old.start - hunk[hunk.len() - 1].old.end <= 2 * CONTEXT_LINES)
+        {
+            hunk.push(block);
+        }
+        let (first, last) = (&hunk[0], &hunk[hunk.len() - 1]);
+        let context_before = first.old.start.min(CONTEXT_LINES);
+        let context_after = (old_lines.len() - last.old.end).min(CONTEXT_LINES);
+        let old = first.old.start - context_before..last.old.end + context_after;
+        let new = first.new.start - context_before..last.new.end + context_after;
+        format_to!(res, "@@ -{} +{} @@\n", hunk_range(&old), hunk_range(&new));
+
+        let mut unchanged = old.start;
+        for block in &hunk {
+            for line in unchanged..block.old.start {
+                push_line(&mut res, ' ', old_lines.line(line));
+            }
+            for line in block.old.clone() {
+                push_line(&mut res, '-', old_lines.line(line));
+            }
+            for line in block.new.clone() {
+                push_line(&mut res, '+', new_lines.line(line));
+            }
+            unchanged = block.old.end;
+        }
+        for line in unchanged..old.end {
+            push_line(&mut res, ' ', old_lines.line(line));
+        }
+    }
+    res
+}
+
+fn hunk_range(lines: &Range<usize>) -> String {
+    if lines.is_empty() {
+        format!("{},0", lines.start)
+    } else {
+        format!("{},{}", lines.start + 1, lines.len())
+    }
+}
+
+fn push_line(buf: &mut String, prefix: char, line: &str) {
+    buf.push(prefix);
+    buf.push_str(line);
+    if !line.ends_with('\n') {
+        buf.push_str("\n\\ No newline at end of file\n");
+    }
+}
+
+/// The lines of a text, with their line endings.
+struct Lines<'a> {
+    lines: Vec<&'a str>,
+    starts: Vec<TextSize>,
+}
+
+impl<'a> Lines<'a> {
+    fn new(text: &'a str) -> Lines<'a> {
+        let lines: Vec<_> = text.split_inclusive('\n').collect();
+        let starts = lines
+            .iter()
+            .scan(TextSize::from(0), |start, line| {
+                let res = *start;
+                *start += TextSize::of(*line);
+                Some(res)
+            })
+            .collect();
+        Lines { lines, starts }
+    }
+
+    fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    fn line(&self, idx: usize) -> &'a str {
+        self.lines[idx]
+    }
+
+    /// The lines containing the start and the end of `range`, and the ones in between.
+    fn spanning(&self, range: TextRange) -> Range<usize> {
+        let line_of = |offset| self.starts.partition_point(|&start| start <= offset).max(1) - 1;
+        let end = (line_of(range.end()) + 1).min(self.len());
+        line_of(range.start()).min(end)..end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::fixture::WithFixture;
+    use expect_test::expect;
+
+    use super::*;
+
+    #[test]
+    fn unified_diff() {
+        let (db, files) = RootDatabase::with_many_files(
+            r#"
+//- /main.rs
+mod foo;
+fn main() {
+    foo::foo();
+}
+//- /foo.rs
+pub fn foo() {}
+//- /bar.rs
+pub fn bar() {}
+"#,
+        );
+        let (main, foo, bar) = (files[0], files[1], files[2]);
+        let mut change = SourceChange::from_text_edit(
+            main,
+            TextEdit::replace(TextRange::new(25.into(), 33.into()), "baz::baz".to_owned()),
+        );
+        change.insert_source_edit(
+            foo,
+            TextEdit::replace(TextRange::new(7.into(), 10.into()), "baz".to_owned()),
+        );
+        change.push_file_system_edit(FileSystemEdit::MoveFile {
+            src: foo,
+            dst: AnchoredPathBuf { anchor: foo, path: "baz.rs".to_owned() },
+        });
+        change.push_file_system_edit(FileSystemEdit::DeleteFile { file: bar });
+        change.push_file_system_edit(FileSystemEdit::CreateFile {
+            dst: AnchoredPathBuf { anchor: main, path: "qux.rs".to_owned() },
+            initial_contents: "pub fn qux() {}\n".to_owned(),
+        });
+
+        expect![[r#"
+            diff --git a/bar.rs b/bar.rs
+            deleted file mode 100644
+            --- a/bar.rs
+            +++ /dev/null
+            @@ -1,1 +0,0 @@
+            -pub fn bar() {}
+            diff --git a/foo.rs b/baz.rs
+            rename from foo.rs
+            rename to baz.rs
+            --- a/foo.rs
+            +++ b/baz.rs
+            @@ -1,1 +1,1 @@
+            -pub fn foo() {}
+            +pub fn baz() {}
+            diff --git a/main.rs b/main.rs
+            --- a/main.rs
+            +++ b/main.rs
+            @@ -1,4 +1,4 @@
+             mod foo;
+             fn main() {
+            -    foo::foo();
+            +    baz::baz();
+             }
+            diff --git a/qux.rs b/qux.rs
+            new file mode 100644
+            --- /dev/null
+            +++ b/qux.rs
+            @@ -0,0 +1,1 @@
+            +pub fn qux() {}
+        "#]]
+        .assert_eq(&change.to_unified_diff(&db));
+    }
+}
@@ -3,8 +3,6 @@
 //! Allows searching the AST for code that matches one or more patterns and then replacing that code
 //! based on a template.
 
-#![warn(rust_2018_idioms, unused_lifetimes, semicolon_in_expressions_from_macros)]
-
 // Feature: Structural Search and Replace
 //
 // Search and replace with named wildcards that will match any expression, type, path, pattern or item.
@@ -39,6 +37,7 @@
 // | Constraint    | Restricts placeholder
 //
 // | kind(literal) | Is a literal (e.g. `42` or `"forty two"`)
+// | type(path)    | Has the type named by `path`, with any generic arguments (e.g. `type(Option)`)
 // | not(a)        | Negates the constraint `a`
 // |===
 //
@@ -82,16 +81,21 @@
 #[cfg(test)]
 mod tests;
 
-pub use crate::{errors::SsrError, from_comment::ssr_from_comment, matching::Match};
+pub use self::{errors::SsrError, from_comment::ssr_from_comment, matching::Match};
 
-use crate::{errors::bail, matching::MatchFailureReason};
 use hir::Semantics;
-use ide_db::base_db::{FileId, FilePosition, FileRange};
 use nohash_hasher::IntMap;
-use resolving::ResolvedRule;
 use syntax::{ast, AstNode, SyntaxNode, TextRange};
 use text_edit::TextEdit;
 
+use crate::{
+    base_db::{FileId, FilePosition, FileRange},
+    source_change::SourceChange,
+    RootDatabase,
+};
+
+use self::{errors::bail, matching::MatchFailureReason, resolving::ResolvedRule};
+
 // A structured search replace rule. Create by calling `parse` on a str.
 #[derive(Debug)]
 pub struct SsrRule {
@@ -115,7 +119,7 @@ pub struct SsrMatches {
 /// Searches a crate for pattern matches and possibly replaces them with something else.
 pub struct MatchFinder<'db> {
     /// Our source of information about the user's code.
-    sema: Semantics<'db, ide_db::RootDatabase>,
+    sema: Semantics<'db, RootDatabase>,
     rules: Vec<ResolvedRule>,
     resolution_scope: resolving::ResolutionScope<'db>,
     restrict_ranges: Vec<FileRange>,
@@ -125,7 +129,7 @@ impl<'db> MatchFinder<'db> {
     /// Constructs a new instance where names will be looked up as if they appeared at
     /// `lookup_context`.
     pub fn in_context(
-        db: &'db ide_db::RootDatabase,
+        db: &'db RootDatabase,
         lookup_context: FilePosition,
         mut restrict_ranges: Vec<FileRange>,
     ) -> Result<MatchFinder<'db>, SsrError> {
@@ -137,9 +141,9 @@ pub fn in_context(
     }
 
     /// Constructs an instance using the start of the first file in `db` as the lookup context.
-    pub fn at_first_file(db: &'db ide_db::RootDatabase) -> Result<MatchFinder<'db>, SsrError> {
-        use ide_db::base_db::SourceDatabaseExt;
-        use ide_db::symbol_index::SymbolsDatabase;
+    pub fn at_first_file(db: &'db RootDatabase) -> Result<MatchFinder<'db>, SsrError> {
+        use crate::base_db::SourceDatabaseExt;
+        use crate::symbol_index::SymbolsDatabase;
         if let Some(first_file_id) =
             db.local_roots().iter().next().and_then(|root| db.source_root(*root).iter().next())
         {
@@ -169,7 +173,7 @@ pub fn add_rule(&mut self, rule: SsrRule) -> Result<(), SsrError> {
 
     /// Finds matches for all added rules and returns edits for all found matches.
     pub fn edits(&self) -> IntMap<FileId, TextEdit> {
-        use ide_db::base_db::SourceDatabaseExt;
+        use crate::base_db::SourceDatabaseExt;
         let mut matches_by_file = IntMap::default();
         for m in self.matches().matches {
             matches_by_file
@@ -194,6 +198,11 @@ pub fn edits(&self) -> IntMap<FileId, TextEdit> {
             .collect()
     }
 
+    /// Finds matches for all added rules and returns a source change replacing all of them.
+    pub fn source_change(&self) -> SourceChange {
+        SourceChange::from(self.edits())
+    }
+
     /// Adds a search pattern. For use if you intend to only call `find_matches_in_file`. If you
     /// intend to do replacement, use `add_rule` instead.
     pub fn add_search_pattern(&mut self, pattern: SsrPattern) -> Result<(), SsrError> {
@@ -221,7 +230,7 @@ pub fn matches(&self) -> SsrMatches {
     /// them, while recording reasons why they don't match. This API is useful for command
     /// line-based debugging where providing a range is difficult.
     pub fn debug_where_text_equal(&self, file_id: FileId, snippet: &str) -> Vec<MatchDebugInfo> {
-        use ide_db::base_db::SourceDatabaseExt;
+        use crate::base_db::SourceDatabaseExt;
         let file = self.sema.parse(file_id);
         let mut res = Vec::new();
         let file_text = self.sema.db.file_text(file_id);
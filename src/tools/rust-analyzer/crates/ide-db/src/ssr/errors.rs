@@ -2,14 +2,14 @@
 
 /// Constructs an SsrError taking arguments like the format macro.
 macro_rules! _error {
-    ($fmt:expr) => {$crate::SsrError::new(format!($fmt))};
-    ($fmt:expr, $($arg:tt)+) => {$crate::SsrError::new(format!($fmt, $($arg)+))}
+    ($fmt:expr) => {$crate::ssr::SsrError::new(format!($fmt))};
+    ($fmt:expr, $($arg:tt)+) => {$crate::ssr::SsrError::new(format!($fmt, $($arg)+))}
 }
 pub(crate) use _error as error;
 
 /// Returns from the current function with an error, supplied by arguments as for format!
 macro_rules! _bail {
-    ($($tokens:tt)*) => {return Err(crate::errors::error!($($tokens)*))}
+    ($($tokens:tt)*) => {return Err(crate::ssr::errors::error!($($tokens)*))}
 }
 pub(crate) use _bail as bail;
 
@@ -1,16 +1,16 @@
 //! This module allows building an SSR MatchFinder by parsing the SSR rule
 //! from a comment.
 
-use ide_db::{
-    base_db::{FilePosition, FileRange, SourceDatabase},
-    RootDatabase,
-};
 use syntax::{
     ast::{self, AstNode, AstToken},
     TextRange,
 };
 
-use crate::MatchFinder;
+use crate::{
+    base_db::{FilePosition, FileRange, SourceDatabase},
+    ssr::MatchFinder,
+    RootDatabase,
+};
 
 /// Attempts to build an SSR MatchFinder from a comment at the given file
 /// range. If successful, returns the MatchFinder and a TextRange covering
@@ -1,19 +1,24 @@
 //! This module is responsible for matching a search pattern against a node in the AST. In the
 //! process of matching, placeholder values are recorded.
 
-use crate::{
-    parsing::{Constraint, NodeKind, Placeholder, Var},
-    resolving::{ResolvedPattern, ResolvedRule, UfcsCallInfo},
-    SsrMatches,
-};
-use hir::Semantics;
-use ide_db::{base_db::FileRange, FxHashMap};
 use std::{cell::Cell, iter::Peekable};
+
+use hir::Semantics;
 use syntax::{
     ast::{self, AstNode, AstToken},
     SmolStr, SyntaxElement, SyntaxElementChildren, SyntaxKind, SyntaxNode, SyntaxToken,
 };
 
+use crate::{
+    base_db::FileRange,
+    ssr::{
+        parsing::{Constraint, NodeKind, Placeholder, Var},
+        resolving::{ResolvedPattern, ResolvedRule, UfcsCallInfo},
+        SsrMatches,
+    },
+    FxHashMap, RootDatabase,
+};
+
 // Creates a match error. If we're currently attempting to match some code that we thought we were
 // going to match, as indicated by the --debug-snippet flag, then populate the reason field.
 macro_rules! match_error {
@@ -89,7 +94,7 @@ pub(crate) fn get_match(
     rule: &ResolvedRule,
     code: &SyntaxNode,
     restrict_range: &Option<FileRange>,
-    sema: &Semantics<'_, ide_db::RootDatabase>,
+    sema: &Semantics<'_, RootDatabase>,
 ) -> Result<Match, MatchFailed> {
     record_match_fails_reasons_scope(debug_active, || {
         Matcher::try_match(rule, code, restrict_range, sema)
@@ -98,7 +103,7 @@ pub(crate) fn get_match(
 
 /// Checks if our search pattern matches a particular node of the AST.
 struct Matcher<'db, 'sema> {
-    sema: &'sema Semantics<'db, ide_db::RootDatabase>,
+    sema: &'sema Semantics<'db, RootDatabase>,
     /// If any placeholders come from anywhere outside of this range, then the match will be
     /// rejected.
     restrict_range: Option<FileRange>,
@@ -120,7 +125,7 @@ fn try_match(
         rule: &ResolvedRule,
         code: &SyntaxNode,
         restrict_range: &Option<FileRange>,
-        sema: &'sema Semantics<'db, ide_db::RootDatabase>,
+        sema: &'sema Semantics<'db, RootDatabase>,
     ) -> Result<Match, MatchFailed> {
         let match_state = Matcher { sema, restrict_range: *restrict_range, rule };
         // First pass at matching, where we check that node types and idents match.
@@ -319,6 +324,25 @@ fn check_constraint(
             Constraint::Kind(kind) => {
                 kind.matches(code)?;
             }
+            Constraint::Type(path) => {
+                let Some(expected) = self.rule.pattern.constraint_types.get(path) else {
+                    fail_match!("Type constraint `{}` wasn't resolved", path);
+                };
+                let ty = if let Some(expr) = ast::Expr::cast(code.clone()) {
+                    self.sema.type_of_expr(&expr)
+                } else if let Some(pat) = ast::Pat::cast(code.clone()) {
+                    self.sema.type_of_pat(&pat)
+                } else {
+                    None
+                };
+                let ty = match ty {
+                    Some(ty) if !ty.original.is_unknown() => ty.original,
+                    _ => fail_match!("Couldn't determine the type of '{}'", code.text()),
+                };
+                if !ty.could_unify_with(self.sema.db, expected) {
+                    fail_match!("Code '{}' doesn't have type `{}`", code.text(), path);
+                }
+            }
             Constraint::Not(sub) => {
                 if self.check_constraint(&*sub, code).is_ok() {
                     fail_match!("Constraint {:?} failed for '{}'", constraint, code.text());
@@ -640,7 +664,7 @@ impl Match {
     fn render_template_paths(
         &mut self,
         template: &ResolvedPattern,
-        sema: &Semantics<'_, ide_db::RootDatabase>,
+        sema: &Semantics<'_, RootDatabase>,
     ) -> Result<(), MatchFailed> {
         let module = sema
             .scope(&self.matched_node)
@@ -779,14 +803,14 @@ fn new(parent: &SyntaxNode) -> Self {
 
 #[cfg(test)]
 mod tests {
-    use crate::{MatchFinder, SsrRule};
+    use crate::ssr::{MatchFinder, SsrRule};
 
     #[test]
     fn parse_match_replace() {
         let rule: SsrRule = "foo($x) ==>> bar($x)".parse().unwrap();
         let input = "fn foo() {} fn bar() {} fn main() { foo(1+2); }";
 
-        let (db, position, selections) = crate::tests::single_file(input);
+        let (db, position, selections) = crate::ssr::tests::single_file(input);
         let mut match_finder = MatchFinder::in_context(&db, position, selections).unwrap();
         match_finder.add_rule(rule).unwrap();
         let matches = match_finder.matches();
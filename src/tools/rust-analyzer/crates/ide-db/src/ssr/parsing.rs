@@ -4,12 +4,14 @@
 //! placeholders, which start with `$`. For replacement templates, this is the final form. For
 //! search patterns, we go further and parse the pattern as each kind of thing that we can match.
 //! e.g. expressions, type references etc.
-use ide_db::{FxHashMap, FxHashSet};
 use std::{fmt::Display, str::FromStr};
-use syntax::{SmolStr, SyntaxKind, SyntaxNode, T};
 
-use crate::errors::bail;
-use crate::{fragments, SsrError, SsrPattern, SsrRule};
+use syntax::{ast, AstNode, SmolStr, SyntaxKind, SyntaxNode, T};
+
+use crate::{
+    ssr::{errors::bail, fragments, SsrError, SsrPattern, SsrRule},
+    FxHashMap, FxHashSet,
+};
 
 #[derive(Debug)]
 pub(crate) struct ParsedRule {
@@ -46,6 +48,8 @@ pub(crate) struct Placeholder {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub(crate) enum Constraint {
     Kind(NodeKind),
+    /// The code has the type named by the path, whatever its generic arguments.
+    Type(ast::Path),
     Not(Box<Constraint>),
 }
 
@@ -320,6 +324,29 @@ fn parse_constraint(tokens: &mut std::vec::IntoIter<Token>) -> Result<Constraint
             expect_token(tokens, ")")?;
             Ok(Constraint::Kind(NodeKind::from(&t.text)?))
         }
+        "type" => {
+            expect_token(tokens, "(")?;
+            let mut text = String::new();
+            let mut depth = 0;
+            loop {
+                let t = tokens.next().ok_or_else(|| {
+                    SsrError::new("Unexpected end of constraint while looking for type")
+                })?;
+                match t.kind {
+                    T!['('] => depth += 1,
+                    T![')'] if depth == 0 => break,
+                    T![')'] => depth -= 1,
+                    _ => (),
+                }
+                text.push_str(&t.text);
+            }
+            let path = fragments::ty(&text)
+                .ok()
+                .and_then(ast::PathType::cast)
+                .and_then(|it| it.path())
+                .ok_or_else(|| SsrError::new(format!("Expected a type path, found '{text}'")))?;
+            Ok(Constraint::Type(path))
+        }
         "not" => {
             expect_token(tokens, "(")?;
             let sub = parse_constraint(tokens)?;
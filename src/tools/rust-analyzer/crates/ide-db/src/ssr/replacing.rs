@@ -1,6 +1,5 @@
 //! Code for applying replacement templates for matches that have previously been found.
 
-use ide_db::{FxHashMap, FxHashSet};
 use itertools::Itertools;
 use syntax::{
     ast::{self, AstNode, AstToken},
@@ -8,7 +7,10 @@
 };
 use text_edit::TextEdit;
 
-use crate::{fragments, resolving::ResolvedRule, Match, SsrMatches};
+use crate::{
+    ssr::{fragments, resolving::ResolvedRule, Match, SsrMatches},
+    FxHashMap, FxHashSet,
+};
 
 /// Returns a text edit that will replace each match in `matches` with its corresponding replacement
 /// template. Placeholders in the template will have been substituted with whatever they matched to
@@ -1,11 +1,17 @@
 //! This module is responsible for resolving paths within rules.
 
 use hir::AsAssocItem;
-use ide_db::{base_db::FilePosition, FxHashMap};
-use parsing::Placeholder;
 use syntax::{ast, SmolStr, SyntaxKind, SyntaxNode, SyntaxToken};
 
-use crate::{errors::error, parsing, SsrError};
+use crate::{
+    base_db::FilePosition,
+    ssr::{
+        errors::error,
+        parsing::{self, Constraint, Placeholder},
+        SsrError,
+    },
+    FxHashMap, RootDatabase,
+};
 
 pub(crate) struct ResolutionScope<'db> {
     scope: hir::SemanticsScope<'db>,
@@ -24,6 +30,8 @@ pub(crate) struct ResolvedPattern {
     // Paths in `node` that we've resolved.
     pub(crate) resolved_paths: FxHashMap<SyntaxNode, ResolvedPath>,
     pub(crate) ufcs_function_calls: FxHashMap<SyntaxNode, UfcsCallInfo>,
+    // The types named by the type constraints of the placeholders.
+    pub(crate) constraint_types: FxHashMap<ast::Path, hir::Type>,
     pub(crate) contains_self: bool,
 }
 
@@ -104,15 +112,45 @@ fn resolve_pattern_tree(&self, pattern: SyntaxNode) -> Result<ResolvedPattern, S
                 SyntaxElement::Token(t) => t.kind() == T![self],
                 _ => false,
             });
+        let mut constraint_types = FxHashMap::default();
+        for placeholder in self.placeholders_by_stand_in.values() {
+            for constraint in &placeholder.constraints {
+                self.resolve_constraint(constraint, &mut constraint_types)?;
+            }
+        }
         Ok(ResolvedPattern {
             node: pattern,
             resolved_paths,
             placeholders_by_stand_in: self.placeholders_by_stand_in.clone(),
             ufcs_function_calls,
+            constraint_types,
             contains_self,
         })
     }
 
+    fn resolve_constraint(
+        &self,
+        constraint: &Constraint,
+        constraint_types: &mut FxHashMap<ast::Path, hir::Type>,
+    ) -> Result<(), SsrError> {
+        match constraint {
+            Constraint::Kind(_) => {}
+            Constraint::Type(path) => {
+                let db = self.resolution_scope.scope.db;
+                let ty = match self.resolution_scope.resolve_path(path) {
+                    Some(hir::PathResolution::Def(hir::ModuleDef::Adt(it))) => it.ty(db),
+                    Some(hir::PathResolution::Def(hir::ModuleDef::BuiltinType(it))) => it.ty(db),
+                    Some(hir::PathResolution::Def(hir::ModuleDef::TypeAlias(it))) => it.ty(db),
+                    Some(_) => return Err(error!("`{}` is not a type", path)),
+                    None => return Err(error!("Failed to resolve path `{}`", path)),
+                };
+                constraint_types.insert(path.clone(), ty);
+            }
+            Constraint::Not(sub) => self.resolve_constraint(sub, constraint_types)?,
+        }
+        Ok(())
+    }
+
     fn resolve(
         &self,
         node: SyntaxNode,
@@ -191,7 +229,7 @@ fn ok_to_use_path_resolution(&self, resolution: &hir::PathResolution) -> bool {
 
 impl<'db> ResolutionScope<'db> {
     pub(crate) fn new(
-        sema: &hir::Semantics<'db, ide_db::RootDatabase>,
+        sema: &hir::Semantics<'db, RootDatabase>,
         resolve_context: FilePosition,
     ) -> Option<ResolutionScope<'db>> {
         use syntax::ast::AstNode;
@@ -1,17 +1,18 @@
 //! Searching for matches.
 
+use syntax::{ast, AstNode, SyntaxKind, SyntaxNode};
+
 use crate::{
-    matching,
-    resolving::{ResolvedPath, ResolvedPattern, ResolvedRule},
-    Match, MatchFinder,
-};
-use ide_db::{
     base_db::{FileId, FileRange},
     defs::Definition,
     search::{SearchScope, UsageSearchResult},
+    ssr::{
+        matching,
+        resolving::{ResolvedPath, ResolvedPattern, ResolvedRule},
+        Match, MatchFinder,
+    },
     FxHashSet,
 };
-use syntax::{ast, AstNode, SyntaxKind, SyntaxNode};
 
 /// A cache for the results of find_usages. This is for when we have multiple patterns that have the
 /// same path. e.g. if the pattern was `foo::Bar` that can parse as a path, an expression, a type
@@ -152,8 +153,8 @@ fn slow_scan(&self, rule: &ResolvedRule, matches_out: &mut Vec<Match>) {
     fn search_files_do(&self, mut callback: impl FnMut(FileId)) {
         if self.restrict_ranges.is_empty() {
             // Unrestricted search.
-            use ide_db::base_db::SourceDatabaseExt;
-            use ide_db::symbol_index::SymbolsDatabase;
+            use crate::base_db::SourceDatabaseExt;
+            use crate::symbol_index::SymbolsDatabase;
             for &root in self.sema.db.local_roots().iter() {
                 let sr = self.sema.db.source_root(root);
                 for file_id in sr.iter() {
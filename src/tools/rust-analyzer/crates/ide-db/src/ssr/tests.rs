@@ -1,12 +1,12 @@
 use expect_test::{expect, Expect};
-use ide_db::{
-    base_db::{salsa::Durability, FileId, FilePosition, FileRange, SourceDatabaseExt},
-    FxHashSet,
-};
 use test_utils::RangeOrOffset;
 use triomphe::Arc;
 
-use crate::{MatchFinder, SsrRule};
+use crate::{
+    base_db::{salsa::Durability, FileId, FilePosition, FileRange, SourceDatabaseExt},
+    ssr::{MatchFinder, SsrRule},
+    FxHashSet, RootDatabase,
+};
 
 fn parse_error_text(query: &str) -> String {
     format!("{}", query.parse::<SsrRule>().unwrap_err())
@@ -64,13 +64,12 @@ fn parser_undefined_placeholder_in_replacement() {
 
 /// `code` may optionally contain a cursor marker `$0`. If it doesn't, then the position will be
 /// the start of the file. If there's a second cursor marker, then we'll return a single range.
-pub(crate) fn single_file(code: &str) -> (ide_db::RootDatabase, FilePosition, Vec<FileRange>) {
-    use ide_db::base_db::fixture::WithFixture;
-    use ide_db::symbol_index::SymbolsDatabase;
+pub(crate) fn single_file(code: &str) -> (RootDatabase, FilePosition, Vec<FileRange>) {
+    use crate::{base_db::fixture::WithFixture, symbol_index::SymbolsDatabase};
     let (mut db, file_id, range_or_offset) = if code.contains(test_utils::CURSOR_MARKER) {
-        ide_db::RootDatabase::with_range_or_offset(code)
+        RootDatabase::with_range_or_offset(code)
     } else {
-        let (db, file_id) = ide_db::RootDatabase::with_single_file(code);
+        let (db, file_id) = RootDatabase::with_single_file(code);
         (db, file_id, RangeOrOffset::Offset(0.into()))
     };
     let selections;
@@ -86,7 +85,7 @@ pub(crate) fn single_file(code: &str) -> (ide_db::RootDatabase, FilePosition, Ve
         }
     }
     let mut local_roots = FxHashSet::default();
-    local_roots.insert(ide_db::base_db::fixture::WORKSPACE);
+    local_roots.insert(crate::base_db::fixture::WORKSPACE);
     db.set_local_roots_with_durability(Arc::new(local_roots), Durability::HIGH);
     (db, position, selections)
 }
@@ -536,6 +535,28 @@ fn f1() {
     assert_matches("Some(${a:not(kind(literal))})", code, &["Some(x1)", "Some(40 + 2)"]);
 }
 
+#[test]
+fn type_constraint() {
+    let code = r#"
+        enum Option<T> { Some(T), None }
+        struct Foo;
+        fn g<T>(t: T) {}
+        fn f1(foo: Foo, x: Option<Foo>) {
+            g(foo);
+            g(x);
+            g(42);
+        }
+        "#;
+    assert_matches("g(${a:type(Foo)})", code, &["g(foo)"]);
+    assert_matches("g(${a:type(Option)})", code, &["g(x)"]);
+    assert_matches("g(${a:type(i32)})", code, &["g(42)"]);
+    assert_matches("g(${a:not(type(Foo))})", code, &["g(x)", "g(42)"]);
+    assert_eq!(
+        parse_error_text("g(${a:type(1)}) ==>> ()"),
+        "Parse error: Expected a type path, found '1'"
+    );
+}
+
 #[test]
 fn match_reordered_struct_instantiation() {
     assert_matches(
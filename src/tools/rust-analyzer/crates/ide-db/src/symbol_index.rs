@@ -21,37 +21,54 @@
 //! those FSTs.
 
 use std::{
-    cmp::Ordering,
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
     fmt,
     hash::{Hash, Hasher},
     mem,
 };
 
-use base_db::{
-    salsa::{self, ParallelDatabase},
-    SourceDatabaseExt, SourceRootId, Upcast,
-};
+use base_db::{salsa, CrateOrigin, SourceDatabase, SourceDatabaseExt, SourceRootId, Upcast};
 use fst::{self, Streamer};
 use hir::{
     db::HirDatabase,
     symbols::{FileSymbol, SymbolCollector},
-    Crate, Module,
+    Crate, HasVisibility, Module, Semantics,
 };
 use rayon::prelude::*;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use triomphe::Arc;
 
-use crate::RootDatabase;
+use crate::{
+    defs::{Definition, DefinitionId},
+    parallel::{par_flat_map, par_map},
+    RootDatabase,
+};
+
+use self::signature::Signature;
+
+mod signature;
 
 #[derive(Debug)]
 pub struct Query {
     query: String,
     lowercased: String,
     only_types: bool,
-    libs: bool,
+    scope: QueryScope,
     exact: bool,
     case_sensitive: bool,
+    exclude_deprecated: bool,
     limit: usize,
+    /// The filters of the query, see [`Query::parse`].
+    kinds: Vec<KindFilter>,
+    krate: Option<String>,
+    only_public: Option<bool>,
+    path: Vec<String>,
+    /// The module the query is made from, see [`Query::near`].
+    near: Option<Module>,
+    rank_by_usages: bool,
+    /// The sketch of a function signature to search for, see [`Query::parse`].
+    signature: Option<Signature>,
 }
 
 impl Query {
@@ -61,19 +78,76 @@ pub fn new(query: String) -> Query {
             query,
             lowercased,
             only_types: false,
-            libs: false,
+            scope: QueryScope::Workspace,
             exact: false,
             case_sensitive: false,
+            exclude_deprecated: false,
             limit: usize::max_value(),
+            kinds: Vec::new(),
+            krate: None,
+            only_public: None,
+            path: Vec::new(),
+            near: None,
+            rank_by_usages: false,
+            signature: None,
         }
     }
 
+    /// Parses a query made up of the name to search for and filters, like `foo kind:fn`:
+    ///
+    /// - `kind:` keeps the symbols of a kind, one of `mod`, `fn`, `struct`, `enum`, `union`,
+    ///   `trait`, `trait_alias`, `type`, `const`, `static` and `macro`. Giving several kinds
+    ///   keeps the symbols of any of them.
+    /// - `crate:` keeps the symbols of the crate with the given name.
+    /// - `vis:pub` keeps the public symbols, `vis:priv` all others.
+    /// - `path:` keeps the symbols in modules whose path, starting with the crate name, contains
+    ///   the given path, like `path:collections::hash`.
+    ///
+    /// The words that are not filters make up the name. If they make up the sketch of a function
+    /// signature instead, like `fn(&str) -> Option<u32>`, the query searches for the functions
+    /// and methods whose signatures unify with it. Single uppercase letters in the sketch stand
+    /// for generic parameters and `_` for any type.
+    pub fn parse(text: &str) -> Query {
+        let mut words = Vec::new();
+        let mut kinds = Vec::new();
+        let mut krate = None;
+        let mut only_public = None;
+        let mut path = Vec::new();
+        for word in text.split_whitespace() {
+            match word.split_once(':') {
+                Some(("kind", kind)) if KindFilter::from_name(kind).is_some() => {
+                    kinds.extend(KindFilter::from_name(kind))
+                }
+                Some(("crate", it)) if !it.is_empty() => krate = Some(it.replace('-', "_")),
+                Some(("vis", "pub")) => only_public = Some(true),
+                Some(("vis", "priv")) => only_public = Some(false),
+                Some(("path", it)) if !it.is_empty() => {
+                    path =
+                        it.split("::").filter(|it| !it.is_empty()).map(ToOwned::to_owned).collect()
+                }
+                _ => words.push(word),
+            }
+        }
+        let signature = match words.first() {
+            Some(word) if word.starts_with("fn(") || *word == "fn" => {
+                Signature::parse(&words.join(" "))
+            }
+            _ => None,
+        };
+        let name = if signature.is_some() { String::new() } else { words.concat() };
+        Query { kinds, krate, only_public, path, signature, ..Query::new(name) }
+    }
+
     pub fn only_types(&mut self) {
         self.only_types = true;
     }
 
     pub fn libs(&mut self) {
-        self.libs = true;
+        self.scope = QueryScope::Libraries;
+    }
+
+    pub fn scope(&mut self, scope: QueryScope) {
+        self.scope = scope;
     }
 
     pub fn exact(&mut self) {
@@ -84,15 +158,104 @@ pub fn case_sensitive(&mut self) {
         self.case_sensitive = true;
     }
 
+    /// Leaves out the symbols marked `#[deprecated]`.
+    pub fn exclude_deprecated(&mut self) {
+        self.exclude_deprecated = true;
+    }
+
     pub fn limit(&mut self, limit: usize) {
         self.limit = limit
     }
+
+    /// Ranks the symbols close to `module` higher: the ones in the module itself, then the ones
+    /// in its crate and then the ones in the crates it directly depends on.
+    pub fn near(&mut self, module: Module) {
+        self.near = Some(module);
+    }
+
+    /// Ranks the results that are otherwise equally good by how often they are referenced.
+    ///
+    /// This searches for the usages of every result, so it should only be used with a limit.
+    pub fn rank_by_usages(&mut self) {
+        self.rank_by_usages = true;
+    }
+}
+
+/// The crates a [`Query`] searches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryScope {
+    /// The crates of the current workspace.
+    Workspace,
+    /// All crates outside of the current workspace, including the sysroot.
+    Libraries,
+    /// The crates outside of the current workspace, excluding the sysroot.
+    Dependencies,
+    /// The crates provided by the language, like `std` and `core`.
+    Sysroot,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KindFilter {
+    Module,
+    Function,
+    Struct,
+    Enum,
+    Union,
+    Trait,
+    TraitAlias,
+    TypeAlias,
+    Const,
+    Static,
+    Macro,
+}
+
+impl KindFilter {
+    fn from_name(name: &str) -> Option<KindFilter> {
+        let kind = match name {
+            "mod" | "module" => KindFilter::Module,
+            "fn" | "function" => KindFilter::Function,
+            "struct" => KindFilter::Struct,
+            "enum" => KindFilter::Enum,
+            "union" => KindFilter::Union,
+            "trait" => KindFilter::Trait,
+            "trait_alias" => KindFilter::TraitAlias,
+            "type" => KindFilter::TypeAlias,
+            "const" => KindFilter::Const,
+            "static" => KindFilter::Static,
+            "macro" => KindFilter::Macro,
+            _ => return None,
+        };
+        Some(kind)
+    }
+
+    fn matches(self, def: hir::ModuleDef) -> bool {
+        match def {
+            hir::ModuleDef::Module(_) => self == KindFilter::Module,
+            hir::ModuleDef::Function(_) => self == KindFilter::Function,
+            hir::ModuleDef::Adt(hir::Adt::Struct(_)) => self == KindFilter::Struct,
+            hir::ModuleDef::Adt(hir::Adt::Enum(_)) => self == KindFilter::Enum,
+            hir::ModuleDef::Adt(hir::Adt::Union(_)) => self == KindFilter::Union,
+            hir::ModuleDef::Trait(_) => self == KindFilter::Trait,
+            hir::ModuleDef::TraitAlias(_) => self == KindFilter::TraitAlias,
+            hir::ModuleDef::TypeAlias(_) => self == KindFilter::TypeAlias,
+            hir::ModuleDef::Const(_) => self == KindFilter::Const,
+            hir::ModuleDef::Static(_) => self == KindFilter::Static,
+            hir::ModuleDef::Macro(_) => self == KindFilter::Macro,
+            hir::ModuleDef::Variant(_) | hir::ModuleDef::BuiltinType(_) => false,
+        }
+    }
 }
 
 #[salsa::query_group(SymbolsDatabaseStorage)]
 pub trait SymbolsDatabase: HirDatabase + SourceDatabaseExt + Upcast<dyn HirDatabase> {
     /// The symbol index for a given module. These modules should only be in source roots that
     /// are inside local_roots.
+    ///
+    /// Each module is indexed on its own, so editing a file only rebuilds the indices of the
+    /// modules whose symbols changed.
+    // FIXME: Collecting the symbols goes through the def map of the whole crate, so all modules of
+    // a crate are re-collected when its items change, even though only the changed indices are
+    // rebuilt downstream.
     fn module_symbols(&self, module: Module) -> Arc<SymbolIndex>;
 
     /// The symbol index for a given source root within library_roots.
@@ -143,32 +306,15 @@ pub fn crate_symbols(db: &dyn SymbolsDatabase, krate: Crate) -> Box<[Arc<SymbolI
     krate.modules(db.upcast()).into_iter().map(|module| db.module_symbols(module)).collect()
 }
 
-/// Need to wrap Snapshot to provide `Clone` impl for `map_with`
-struct Snap<DB>(DB);
-impl<DB: ParallelDatabase> Snap<salsa::Snapshot<DB>> {
-    fn new(db: &DB) -> Self {
-        Self(db.snapshot())
-    }
-}
-impl<DB: ParallelDatabase> Clone for Snap<salsa::Snapshot<DB>> {
-    fn clone(&self) -> Snap<salsa::Snapshot<DB>> {
-        Snap(self.0.snapshot())
-    }
-}
-impl<DB> std::ops::Deref for Snap<DB> {
-    type Target = DB;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
 // Feature: Workspace Symbol
 //
 // Uses fuzzy-search to find types, modules and functions by name across your
 // project and dependencies. This is **the** most useful feature, which improves code
-// navigation tremendously. It mostly works on top of the built-in LSP
-// functionality, however `#` and `*` symbols can be used to narrow down the
+// navigation tremendously. The characters of the query have to appear in the
+// name in order, results matching them at the start of words come first, so
+// `HaMa` finds `HashMap` and `hash_map`. Items can also be found by their
+// `#[doc(alias)]`s, ranking below matches of names. It mostly works on top of the built-in
+// LSP functionality, however `#` and `*` symbols can be used to narrow down the
 // search. Specifically,
 //
 // - `Foo` searches for `Foo` type in the current workspace
@@ -179,6 +325,16 @@ fn deref(&self) -> &Self::Target {
 // That is, `#` switches from "types" to all symbols, `*` switches from the current
 // workspace to dependencies.
 //
+// The search can be narrowed down further with filters next to the name:
+//
+// - `foo kind:fn` searches for functions, see `Query::parse` for the other kinds
+// - `Foo crate:serde` searches in the `serde` crate
+// - `Foo vis:pub` searches for public symbols, `vis:priv` for the others
+// - `Foo path:collections::hash` searches in modules whose path contains `collections::hash`
+//
+// Functions can also be searched by a sketch of their signature instead of their name, like
+// `fn(&str) -> Option<u32>`, where `_` stands for any type and `T` for a generic parameter.
+//
 // Note that filtering does not currently work in VSCode due to the editor never
 // sending the special symbols to the language server. Instead, you can configure
 // the filtering via the `rust-analyzer.workspace.symbol.search.scope` and
@@ -192,25 +348,40 @@ fn deref(&self) -> &Self::Target {
 pub fn world_symbols(db: &RootDatabase, query: Query) -> Vec<FileSymbol> {
     let _p = profile::span("world_symbols").detail(|| query.query.clone());
 
-    let indices: Vec<_> = if query.libs {
-        db.library_roots()
-            .par_iter()
-            .map_with(Snap::new(db), |snap, &root| snap.library_symbols(root))
-            .collect()
+    let indices: Vec<_> = if query.scope != QueryScope::Workspace {
+        let roots: Vec<_> = db
+            .library_roots()
+            .iter()
+            .copied()
+            .filter(|&root| match query.scope {
+                QueryScope::Workspace | QueryScope::Libraries => true,
+                QueryScope::Dependencies => !is_sysroot(db, root),
+                QueryScope::Sysroot => is_sysroot(db, root),
+            })
+            .collect();
+        par_map(db, roots, |db, root| db.library_symbols(root))
     } else {
         let mut crates = Vec::new();
 
         for &root in db.local_roots().iter() {
             crates.extend(db.source_root_crates(root).iter().copied())
         }
-        let indices: Vec<_> = crates
-            .into_par_iter()
-            .map_with(Snap::new(db), |snap, krate| snap.crate_symbols(krate.into()))
-            .collect();
-        indices.iter().flat_map(|indices| indices.iter().cloned()).collect()
+        // Index the modules of all crates in parallel rather than crate by crate, so that a
+        // crate with many modules doesn't leave the other threads idle. The module indices are
+        // only merged when searching them.
+        let modules = par_flat_map(db, crates, |db, krate| Crate::from(krate).modules(db));
+        par_map(db, modules, |db, module| db.module_symbols(module))
     };
 
-    query.search(&indices)
+    query.search(db, &indices)
+}
+
+/// Whether the source root holds crates provided by the language.
+fn is_sysroot(db: &RootDatabase, root: SourceRootId) -> bool {
+    let crate_graph = db.crate_graph();
+    db.source_root_crates(root)
+        .iter()
+        .any(|&krate| matches!(crate_graph[krate].origin, CrateOrigin::Lang(_)))
 }
 
 #[derive(Default)]
@@ -302,15 +473,28 @@ fn map_value_to_range(value: u64) -> (usize, usize) {
 }
 
 impl Query {
-    pub(crate) fn search(self, indices: &[Arc<SymbolIndex>]) -> Vec<FileSymbol> {
+    /// Searches the indices for the symbols matching the query. Unless the query is exact, the
+    /// results are ranked by how well their names match it, see [`fuzzy_score`], and by their
+    /// proximity to the module given by [`Query::near`].
+    pub(crate) fn search(self, db: &RootDatabase, indices: &[Arc<SymbolIndex>]) -> Vec<FileSymbol> {
         let _p = profile::span("symbol_index::Query::search");
         let mut op = fst::map::OpBuilder::new();
         for file_symbols in indices.iter() {
             let automaton = fst::automaton::Subsequence::new(&self.lowercased);
             op = op.add(file_symbols.map.search(automaton))
         }
+        // Without a name to match or a module to be close to there is nothing to rank by, so we
+        // can stop at the limit.
+        let rank_by_name = !self.exact && !self.query.is_empty();
+        let is_ranked = rank_by_name || self.near.is_some();
+        let near_deps: Vec<Crate> = match self.near {
+            Some(near) => near.krate().dependencies(db).into_iter().map(|dep| dep.krate).collect(),
+            None => Vec::new(),
+        };
         let mut stream = op.union();
-        let mut res = Vec::new();
+        // The best `limit` matches so far, the worst one on top.
+        let mut best = BinaryHeap::new();
+        let mut matches = 0;
         while let Some((_, indexed_values)) = stream.next() {
             for indexed_value in indexed_values {
                 let symbol_index = &indices[indexed_value.index];
@@ -336,27 +520,271 @@ pub(crate) fn search(self, indices: &[Arc<SymbolIndex>]) -> Vec<FileSymbol> {
                     {
                         continue;
                     }
+                    if self.exclude_deprecated && symbol.is_deprecated {
+                        continue;
+                    }
+                    if let Some(signature) = &self.signature {
+                        // Signatures are only computed for the symbols a sketch is matched with.
+                        match symbol.def {
+                            hir::ModuleDef::Function(it)
+                                if !symbol.is_alias
+                                    && Signature::of_function(db, it).matches(signature) => {}
+                            _ => continue,
+                        }
+                    }
+                    if !self.matches_filters(db, symbol.def) {
+                        continue;
+                    }
 
-                    res.push(symbol.clone());
-                    if res.len() >= self.limit {
-                        return res;
+                    let score = if rank_by_name {
+                        let Some(score) = fuzzy_score(&self.query, &symbol.name) else { continue };
+                        // A match of a doc alias ranks below an equally good match of a name.
+                        if symbol.is_alias {
+                            score - ALIAS_PENALTY
+                        } else {
+                            score
+                        }
+                    } else {
+                        0
+                    };
+                    let score = score + self.proximity_score(db, symbol.def, &near_deps);
+                    let ranked = RankedSymbol { score, seq: matches, symbol: symbol.clone() };
+                    best.push(Reverse(ranked));
+                    matches += 1;
+                    if best.len() > self.limit {
+                        best.pop();
+                    }
+                    if !is_ranked && best.len() >= self.limit {
+                        let best = best.into_sorted_vec().into_iter();
+                        return best.map(|Reverse(it)| it.symbol).collect();
                     }
                 }
             }
         }
-        res
+        let res: Vec<_> =
+            best.into_sorted_vec().into_iter().map(|Reverse(it)| (it.score, it.symbol)).collect();
+        if self.rank_by_usages {
+            let sema = Semantics::new(db);
+            // Doc aliases share the definition of the symbol they are an alias of, which is only
+            // searched for once.
+            let mut usage_counts: FxHashMap<DefinitionId, usize> = FxHashMap::default();
+            let mut res: Vec<_> = res
+                .into_iter()
+                .map(|(score, symbol)| {
+                    let def = Definition::from(symbol.def);
+                    let usages = *usage_counts.entry(def.intern(db)).or_insert_with(|| {
+                        def.usages(&sema)
+                            .with_limit(USAGE_COUNT_LIMIT)
+                            .all()
+                            .iter()
+                            .map(|(_, refs)| refs.len())
+                            .sum::<usize>()
+                    });
+                    (score, usages, symbol)
+                })
+                .collect();
+            res.sort_by_key(|&(score, usages, _)| Reverse((score, usages)));
+            return res.into_iter().map(|(_, _, symbol)| symbol).collect();
+        }
+        res.into_iter().map(|(_, symbol)| symbol).collect()
+    }
+}
+
+/// A match of a query, ordered by how good it is. Equally good matches are ordered by their
+/// position in the indices, earlier ones are better.
+struct RankedSymbol {
+    score: i64,
+    /// The number of matches found before this one.
+    seq: usize,
+    symbol: FileSymbol,
+}
+
+impl RankedSymbol {
+    fn key(&self) -> (i64, Reverse<usize>) {
+        (self.score, Reverse(self.seq))
+    }
+}
+
+impl PartialEq for RankedSymbol {
+    fn eq(&self, other: &RankedSymbol) -> bool {
+        self.key() == other.key()
     }
 }
 
+impl Eq for RankedSymbol {}
+
+impl PartialOrd for RankedSymbol {
+    fn partial_cmp(&self, other: &RankedSymbol) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RankedSymbol {
+    fn cmp(&self, other: &RankedSymbol) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+/// How much lower a match of a `#[doc(alias)]` ranks than the same match of a name.
+const ALIAS_PENALTY: i64 = 8;
+
+/// How many usages of a symbol are counted at most when ranking by usages, symbols used more
+/// often than that are all ranked the same.
+const USAGE_COUNT_LIMIT: usize = 100;
+
+impl Query {
+    /// The bonus for a symbol defined close to the module the query is made from, large enough
+    /// for a close partial match to outrank a distant exact one.
+    fn proximity_score(&self, db: &RootDatabase, def: hir::ModuleDef, near_deps: &[Crate]) -> i64 {
+        const SAME_MODULE: i64 = 48;
+        const SAME_CRATE: i64 = 32;
+        const DEPENDENCY: i64 = 16;
+
+        let (Some(near), Some(module)) = (self.near, def.module(db)) else { return 0 };
+        if module == near {
+            SAME_MODULE
+        } else if module.krate() == near.krate() {
+            SAME_CRATE
+        } else if near_deps.contains(&module.krate()) {
+            DEPENDENCY
+        } else {
+            0
+        }
+    }
+
+    fn matches_filters(&self, db: &RootDatabase, def: hir::ModuleDef) -> bool {
+        if !self.kinds.is_empty() && !self.kinds.iter().any(|kind| kind.matches(def)) {
+            return false;
+        }
+        if self.krate.is_none() && self.only_public.is_none() && self.path.is_empty() {
+            return true;
+        }
+        let Some(module) = def.module(db) else { return false };
+        let crate_name = module.krate().display_name(db);
+        let crate_name = crate_name.as_ref().map(|it| it.crate_name().as_smol_str().as_str());
+        if self.krate.is_some() && self.krate.as_deref() != crate_name {
+            return false;
+        }
+        if let Some(only_public) = self.only_public {
+            if (def.visibility(db) == hir::Visibility::Public) != only_public {
+                return false;
+            }
+        }
+        if !self.path.is_empty() {
+            let mut module_path: Vec<String> =
+                crate_name.into_iter().map(ToOwned::to_owned).collect();
+            module_path.extend(
+                module
+                    .path_to_root(db)
+                    .into_iter()
+                    .rev()
+                    .filter_map(|it| Some(it.name(db)?.to_smol_str().to_string())),
+            );
+            if !module_path.windows(self.path.len()).any(|it| it == self.path.as_slice()) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Scores how well `name` matches the fuzzy `query`, higher is better. The characters of the
+/// query have to appear in the name in order, ignoring case. Matches at the start of words, like
+/// the `H` and `M` of `HashMap` for `HaMa` or `hash_map` for `hm`, runs of consecutive matches
+/// and matches of the exact case score higher, skipped characters lower.
+///
+/// Returns `None` if the query is not a subsequence of the name.
+fn fuzzy_score(query: &str, name: &str) -> Option<i64> {
+    const MATCH: i64 = 16;
+    const EXACT_CASE: i64 = 1;
+    const WORD_START: i64 = 8;
+    const NAME_START: i64 = 12;
+    const CONSECUTIVE: i64 = 6;
+
+    let query: Vec<char> = query.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let is_word_start = |j: usize| {
+        j == 0 || {
+            let (prev, cur) = (name[j - 1], name[j]);
+            !prev.is_alphanumeric() || (prev.is_lowercase() && cur.is_uppercase())
+        }
+    };
+
+    // `best[j]` is the best score of matching the query so far with its last character matched
+    // at `name[j]`.
+    let mut best: Vec<Option<i64>> = vec![None; name.len()];
+    for (i, &q) in query.iter().enumerate() {
+        let mut next = vec![None; name.len()];
+        // The best `best[k] + k` for `k < j - 1`, to charge the gap between `k` and `j`.
+        let mut best_before_gap: Option<i64> = None;
+        for (j, &c) in name.iter().enumerate() {
+            if j >= 2 {
+                if let Some(prev) = best[j - 2] {
+                    let candidate = prev + (j - 2) as i64;
+                    best_before_gap =
+                        Some(best_before_gap.map_or(candidate, |it| it.max(candidate)));
+                }
+            }
+            if !c.to_lowercase().eq(q.to_lowercase()) {
+                continue;
+            }
+            let mut score = MATCH;
+            if c == q {
+                score += EXACT_CASE;
+            }
+            if j == 0 {
+                score += NAME_START;
+            } else if is_word_start(j) {
+                score += WORD_START;
+            }
+            let prefix = if i == 0 {
+                // Characters skipped before the first match are cheap, the bonuses above
+                // already prefer matches at the start.
+                Some(-(j as i64))
+            } else if j == 0 {
+                None
+            } else {
+                let consecutive = best[j - 1].map(|it| it + CONSECUTIVE);
+                // Each skipped character costs one.
+                let with_gap = best_before_gap.map(|it| it - (j as i64 - 1));
+                consecutive.max(with_gap)
+            };
+            next[j] = prefix.map(|it| it + score);
+        }
+        best = next;
+    }
+    let score = best.into_iter().flatten().max()?;
+    // Prefer names that don't have much more to them than the query.
+    Some(score - (name.len() - query.len()) as i64)
+}
+
 #[cfg(test)]
 mod tests {
 
     use base_db::fixture::WithFixture;
-    use expect_test::expect_file;
+    use expect_test::{expect, expect_file, Expect};
     use hir::symbols::SymbolCollector;
+    use itertools::Itertools;
 
     use super::*;
 
+    /// The fixtures don't set up the local and library roots that `world_symbols` searches.
+    fn set_roots(db: &mut RootDatabase) {
+        let mut local_roots = FxHashSet::default();
+        let mut library_roots = FxHashSet::default();
+        let crate_graph = db.crate_graph();
+        for krate in crate_graph.iter() {
+            let root = db.file_source_root(crate_graph[krate].root_file_id);
+            if db.source_root(root).is_library {
+                library_roots.insert(root);
+            } else {
+                local_roots.insert(root);
+            }
+        }
+        db.set_local_roots(Arc::new(local_roots));
+        db.set_library_roots(Arc::new(library_roots));
+    }
+
     #[test]
     fn test_symbol_index_collection() {
         let (db, _) = RootDatabase::with_many_files(
@@ -435,6 +863,315 @@ mod a_mod {
         expect_file!["./test_data/test_symbol_index_collection.txt"].assert_debug_eq(&symbols);
     }
 
+    #[test]
+    fn test_fuzzy_ranking() {
+        let (mut db, _) = RootDatabase::with_single_file(
+            r#"
+struct HandleManager;
+struct Hama;
+fn hash_map() {}
+struct HashMap;
+struct Chamomile;
+"#,
+        );
+        set_roots(&mut db);
+
+        let names: Vec<_> = world_symbols(&db, Query::new("HaMa".to_owned()))
+            .into_iter()
+            .map(|it| it.name)
+            .collect();
+        expect![[r#"
+            [
+                "Hama",
+                "HashMap",
+                "hash_map",
+                "HandleManager",
+            ]
+        "#]]
+        .assert_debug_eq(&names);
+    }
+
+    #[test]
+    fn test_query_filters() {
+        let (mut db, _) = RootDatabase::with_many_files(
+            r#"
+//- /main.rs crate:main deps:dep
+pub struct Foo;
+fn foo() {}
+mod inner {
+    pub fn foo() {}
+    struct FooInner;
+}
+//- /dep.rs crate:dep
+pub struct Foo;
+"#,
+        );
+        set_roots(&mut db);
+        let check = |text: &str, expect: Expect| {
+            let mut symbols: Vec<_> = world_symbols(&db, Query::parse(text))
+                .into_iter()
+                .map(|it| {
+                    let path = it.def.canonical_module_path(&db).unwrap();
+                    let krate = it.def.module(&db).unwrap().krate().display_name(&db).unwrap();
+                    let path: Vec<_> = path.filter_map(|it| it.name(&db)).collect();
+                    let path = path.iter().map(|it| format!("{}::", it.display(&db))).join("");
+                    format!("{krate}::{path}{}\n", it.name)
+                })
+                .collect();
+            symbols.sort();
+            expect.assert_eq(&symbols.concat());
+        };
+
+        check(
+            "foo kind:fn",
+            expect![[r#"
+                main::foo
+                main::inner::foo
+            "#]],
+        );
+        check(
+            "foo crate:dep",
+            expect![[r#"
+                dep::Foo
+            "#]],
+        );
+        check(
+            "foo vis:pub",
+            expect![[r#"
+                dep::Foo
+                main::Foo
+                main::inner::foo
+            "#]],
+        );
+        check(
+            "path:main::inner",
+            expect![[r#"
+                main::inner::FooInner
+                main::inner::foo
+            "#]],
+        );
+        check("kind:closure", expect![[""]]);
+    }
+
+    #[test]
+    fn test_query_scope() {
+        let (mut db, _) = RootDatabase::with_many_files(
+            r#"
+//- /main.rs crate:main deps:dep,core
+pub struct Foo;
+//- /dep.rs crate:dep new_source_root:library
+pub struct FooDep;
+//- /core.rs crate:core new_source_root:library
+pub struct FooCore;
+"#,
+        );
+        set_roots(&mut db);
+        let check = |scope: QueryScope, expect: Expect| {
+            let mut query = Query::new("Foo".to_owned());
+            query.scope(scope);
+            let mut names: Vec<_> =
+                world_symbols(&db, query).into_iter().map(|it| it.name.to_string()).collect();
+            names.sort();
+            expect.assert_debug_eq(&names);
+        };
+
+        check(
+            QueryScope::Workspace,
+            expect![[r#"
+                [
+                    "Foo",
+                ]
+            "#]],
+        );
+        check(
+            QueryScope::Libraries,
+            expect![[r#"
+                [
+                    "FooCore",
+                    "FooDep",
+                ]
+            "#]],
+        );
+        check(
+            QueryScope::Dependencies,
+            expect![[r#"
+                [
+                    "FooDep",
+                ]
+            "#]],
+        );
+        check(
+            QueryScope::Sysroot,
+            expect![[r#"
+                [
+                    "FooCore",
+                ]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_proximity_ranking() {
+        let (mut db, _) = RootDatabase::with_many_files(
+            r#"
+//- /main.rs crate:main deps:dep
+struct FooBar;
+mod inner {
+    struct Foo;
+}
+//- /dep.rs crate:dep
+pub struct Foo;
+//- /other.rs crate:other
+pub struct Foo;
+"#,
+        );
+        set_roots(&mut db);
+        let main = Crate::all(&db)
+            .into_iter()
+            .find(|it| it.display_name(&db).unwrap().to_string() == "main")
+            .unwrap();
+        let inner = main
+            .modules(&db)
+            .into_iter()
+            .find(|it| it.name(&db).map_or(false, |name| name.display(&db).to_string() == "inner"))
+            .unwrap();
+
+        let mut query = Query::new("Foo".to_owned());
+        query.near(inner);
+        let symbols: Vec<_> = world_symbols(&db, query)
+            .into_iter()
+            .map(|it| {
+                let krate = it.def.module(&db).unwrap().krate().display_name(&db).unwrap();
+                format!("{krate}::{}", it.name)
+            })
+            .collect();
+        expect![[r#"
+            [
+                "main::Foo",
+                "main::FooBar",
+                "dep::Foo",
+                "other::Foo",
+            ]
+        "#]]
+        .assert_debug_eq(&symbols);
+    }
+
+    #[test]
+    fn test_doc_alias_ranking() {
+        let (mut db, _) = RootDatabase::with_single_file(
+            r#"
+struct Items;
+impl Items {
+    #[doc(alias = "len")]
+    fn count(&self) {}
+    fn len(&self) {}
+    fn length(&self) {}
+}
+"#,
+        );
+        set_roots(&mut db);
+
+        let symbols: Vec<_> = world_symbols(&db, Query::new("len".to_owned()))
+            .into_iter()
+            .map(|it| format!("{} {}", it.name, it.def.name(&db).unwrap().display(&db)))
+            .collect();
+        expect![[r#"
+            [
+                "len len",
+                "length length",
+                "len count",
+            ]
+        "#]]
+        .assert_debug_eq(&symbols);
+    }
+
+    #[test]
+    fn test_exclude_deprecated() {
+        let (mut db, _) = RootDatabase::with_single_file(
+            r#"
+#[deprecated]
+struct OldFoo;
+struct Foo;
+"#,
+        );
+        set_roots(&mut db);
+
+        let mut query = Query::new("Foo".to_owned());
+        query.exclude_deprecated();
+        let names: Vec<_> = world_symbols(&db, query).into_iter().map(|it| it.name).collect();
+        expect![[r#"
+            [
+                "Foo",
+            ]
+        "#]]
+        .assert_debug_eq(&names);
+    }
+
+    #[test]
+    fn test_signature_search() {
+        let (mut db, _) = RootDatabase::with_single_file(
+            r#"
+enum Option<T> { Some(T), None }
+struct Parser;
+impl Parser {
+    fn parse(&self, text: &str) -> Option<u32> { Option::None }
+}
+fn parse_u32(text: &str) -> Option<u32> { Option::None }
+fn first<T>(items: &[T]) -> Option<&T> { Option::None }
+fn len(text: &str) -> usize { 0 }
+"#,
+        );
+        set_roots(&mut db);
+        let check = |text: &str, expect: Expect| {
+            let mut names: Vec<_> = world_symbols(&db, Query::parse(text))
+                .into_iter()
+                .map(|it| it.name.to_string())
+                .collect();
+            names.sort();
+            expect.assert_debug_eq(&names);
+        };
+
+        check(
+            "fn(&str) -> Option<u32>",
+            expect![[r#"
+                [
+                    "parse_u32",
+                ]
+            "#]],
+        );
+        check(
+            "fn(&Parser, &str) -> Option<_>",
+            expect![[r#"
+                [
+                    "parse",
+                ]
+            "#]],
+        );
+        check(
+            "fn(&[u32]) -> Option<&u32>",
+            expect![[r#"
+                [
+                    "first",
+                ]
+            "#]],
+        );
+        check(
+            "fn(&[u32]) -> Option<&u8>",
+            expect![[r#"
+                []
+            "#]],
+        );
+        check(
+            "fn(&str)",
+            expect![[r#"
+                [
+                    "len",
+                    "parse_u32",
+                ]
+            "#]],
+        );
+    }
+
     #[test]
     fn test_doc_alias() {
         let (db, _) = RootDatabase::with_single_file(
@@ -0,0 +1,190 @@
+//! Normalized function signatures, for searching functions by a sketch of their type like
+//! `fn(&str) -> Option<u32>`.
+//!
+//! Both the sketches and the signatures of functions are turned into [`SigTy`]s through their
+//! syntax, the latter by displaying their types first. This keeps only what a sketch can say
+//! about a type: the name of a path without its qualifier, references, tuples, slices and arrays.
+
+use hir::{db::HirDatabase, AsAssocItem, AssocItemContainer, GenericDef, HirDisplay};
+use rustc_hash::FxHashMap;
+use syntax::{ast, AstNode, SmolStr};
+
+/// A type of a signature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum SigTy {
+    /// `_`, or a type that can't be described, like `impl Trait`. Unifies with any type.
+    Infer,
+    /// A generic parameter, which unifies with any type, as long as it's the same one for all
+    /// occurrences of the parameter.
+    Param(SmolStr),
+    Ref {
+        mutable: bool,
+        inner: Box<SigTy>,
+    },
+    Path {
+        name: SmolStr,
+        args: Vec<SigTy>,
+    },
+    Tuple(Vec<SigTy>),
+    Slice(Box<SigTy>),
+    Array(Box<SigTy>),
+}
+
+/// The parameter and return types of a function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Signature {
+    params: Vec<SigTy>,
+    ret: SigTy,
+}
+
+impl Signature {
+    /// Parses a sketch of a signature like `fn(&str) -> Option<u32>`. Single uppercase letters
+    /// are generic parameters and `_` stands for any type. Leaving out the return type matches
+    /// any return type.
+    pub(crate) fn parse(text: &str) -> Option<Signature> {
+        let ast::Type::FnPtrType(fn_ptr) = parse_type(text)? else { return None };
+        let is_param = |name: &str| {
+            let mut chars = name.chars();
+            matches!((chars.next(), chars.next()), (Some(c), None) if c.is_ascii_uppercase())
+        };
+        let params = fn_ptr
+            .param_list()?
+            .params()
+            .map(|param| param.ty().map_or(SigTy::Infer, |ty| SigTy::from_ast(&ty, &is_param)))
+            .collect();
+        let ret = match fn_ptr.ret_type() {
+            Some(ret) => ret.ty().map_or(SigTy::Infer, |ty| SigTy::from_ast(&ty, &is_param)),
+            None => SigTy::Infer,
+        };
+        Some(Signature { params, ret })
+    }
+
+    /// The signature of `func`, with the type of its self parameter first.
+    pub(crate) fn of_function(db: &dyn HirDatabase, func: hir::Function) -> Signature {
+        let type_param_names = |def: GenericDef| {
+            def.type_params(db).into_iter().map(|it| it.name(db).to_smol_str()).collect::<Vec<_>>()
+        };
+        let mut generic_params = type_param_names(func.into());
+        match func.as_assoc_item(db).map(|it| it.container(db)) {
+            Some(AssocItemContainer::Impl(impl_)) => {
+                generic_params.extend(type_param_names(impl_.into()))
+            }
+            Some(AssocItemContainer::Trait(trait_)) => {
+                generic_params.extend(type_param_names(trait_.into()));
+                generic_params.push(SmolStr::new_inline("Self"));
+            }
+            None => (),
+        }
+        let is_param = |name: &str| generic_params.iter().any(|it| it == name);
+        let sig_ty = |ty: &hir::Type| match parse_type(&ty.display(db).to_string()) {
+            Some(ty) => SigTy::from_ast(&ty, &is_param),
+            None => SigTy::Infer,
+        };
+
+        let params = func.assoc_fn_params(db).iter().map(|param| sig_ty(param.ty())).collect();
+        let ret = sig_ty(&func.ret_type(db));
+        Signature { params, ret }
+    }
+
+    /// Whether the signature of a function unifies with the sketch `query`.
+    pub(crate) fn matches(&self, query: &Signature) -> bool {
+        if self.params.len() != query.params.len() {
+            return false;
+        }
+        let mut unifier = Unifier::default();
+        self.params.iter().zip(&query.params).all(|(it, query)| unifier.unify(it, query))
+            && unifier.unify(&self.ret, &query.ret)
+    }
+}
+
+fn parse_type(text: &str) -> Option<ast::Type> {
+    let file = ast::SourceFile::parse(&format!("type T = {text};")).tree();
+    file.syntax().descendants().find_map(ast::TypeAlias::cast)?.ty()
+}
+
+impl SigTy {
+    fn from_ast(ty: &ast::Type, is_param: &dyn Fn(&str) -> bool) -> SigTy {
+        let from_ast = |ty: Option<ast::Type>| match ty {
+            Some(ty) => SigTy::from_ast(&ty, is_param),
+            None => SigTy::Infer,
+        };
+        match ty {
+            ast::Type::RefType(it) => {
+                SigTy::Ref { mutable: it.mut_token().is_some(), inner: Box::new(from_ast(it.ty())) }
+            }
+            ast::Type::PathType(it) => {
+                let Some(segment) = it.path().and_then(|it| it.segment()) else {
+                    return SigTy::Infer;
+                };
+                let Some(name) = segment.name_ref() else { return SigTy::Infer };
+                let name = SmolStr::from(name.text().as_str());
+                if is_param(&name) {
+                    return SigTy::Param(name);
+                }
+                let args = segment
+                    .generic_arg_list()
+                    .into_iter()
+                    .flat_map(|it| it.generic_args())
+                    .filter_map(|arg| match arg {
+                        ast::GenericArg::TypeArg(arg) => Some(from_ast(arg.ty())),
+                        _ => None,
+                    })
+                    .collect();
+                SigTy::Path { name, args }
+            }
+            ast::Type::TupleType(it) => {
+                SigTy::Tuple(it.fields().map(|it| from_ast(Some(it))).collect())
+            }
+            ast::Type::SliceType(it) => SigTy::Slice(Box::new(from_ast(it.ty()))),
+            ast::Type::ArrayType(it) => SigTy::Array(Box::new(from_ast(it.ty()))),
+            ast::Type::ParenType(it) => from_ast(it.ty()),
+            _ => SigTy::Infer,
+        }
+    }
+}
+
+/// Unifies the types of a function with the ones of a query, keeping track of what the generic
+/// parameters of both sides stand for.
+#[derive(Default)]
+struct Unifier {
+    function_params: FxHashMap<SmolStr, SigTy>,
+    query_params: FxHashMap<SmolStr, SigTy>,
+}
+
+impl Unifier {
+    fn unify(&mut self, ty: &SigTy, query: &SigTy) -> bool {
+        match (ty, query) {
+            (SigTy::Infer, _) | (_, SigTy::Infer) => true,
+            (SigTy::Param(name), _) => Self::bind(&mut self.function_params, name, query),
+            (_, SigTy::Param(name)) => Self::bind(&mut self.query_params, name, ty),
+            (
+                SigTy::Ref { mutable, inner },
+                SigTy::Ref { mutable: query_mutable, inner: query_inner },
+            ) => mutable == query_mutable && self.unify(inner, query_inner),
+            (SigTy::Path { name, args }, SigTy::Path { name: query_name, args: query_args }) => {
+                // `Option` in a query matches any `Option<T>`.
+                name == query_name
+                    && (query_args.is_empty()
+                        || args.len() == query_args.len()
+                            && args.iter().zip(query_args).all(|(it, query)| self.unify(it, query)))
+            }
+            (SigTy::Tuple(fields), SigTy::Tuple(query_fields)) => {
+                fields.len() == query_fields.len()
+                    && fields.iter().zip(query_fields).all(|(it, query)| self.unify(it, query))
+            }
+            (SigTy::Slice(inner), SigTy::Slice(query_inner))
+            | (SigTy::Array(inner), SigTy::Array(query_inner)) => self.unify(inner, query_inner),
+            _ => false,
+        }
+    }
+
+    fn bind(params: &mut FxHashMap<SmolStr, SigTy>, name: &SmolStr, ty: &SigTy) -> bool {
+        match params.get(name) {
+            Some(bound) => bound == ty,
+            None => {
+                params.insert(name.clone(), ty.clone());
+                true
+            }
+        }
+    }
+}
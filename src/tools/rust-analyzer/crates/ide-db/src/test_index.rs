@@ -0,0 +1,380 @@
+//! An index of the runnables of the workspace: binaries, tests, benchmarks and doctests.
+//!
+//! Tests and benchmarks are the functions with the attribute of one of the configured test
+//! harnesses, like `#[test]` or `#[tokio::test]`. Harnesses are an input of the database, so
+//! clients can add the ones of the frameworks they use. The runnables of each module are collected
+//! by a query, so only the modules of edited files are visited again.
+
+use std::fmt;
+
+use base_db::{salsa, SourceDatabase, Upcast};
+use hir::{
+    db::HirDatabase, Attrs, CfgAtom, CfgExpr, Crate, HasAttrs, Module, ModuleDef, ModuleSource,
+};
+use syntax::SmolStr;
+use triomphe::Arc;
+
+use crate::{
+    defs::Definition, parallel::par_flat_map, rust_doc::has_runnable_doc_test, RootDatabase,
+};
+
+#[salsa::query_group(TestIndexDatabaseStorage)]
+pub trait TestIndexDatabase: HirDatabase + Upcast<RootDatabase> {
+    /// The attributes marking tests and benchmarks. Defaults to [`TestHarness::defaults`].
+    #[salsa::input]
+    fn test_harnesses(&self) -> Arc<[TestHarness]>;
+
+    /// The runnables declared in the given module. The module itself is a runnable if its docs have
+    /// a doctest.
+    fn module_runnables(&self, module: Module) -> Arc<[RunnableDescriptor]>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RunnableKind {
+    /// The `main` function of a crate.
+    Bin,
+    Test,
+    Bench,
+    DocTest,
+}
+
+/// An attribute marking functions as tests or benchmarks.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TestHarness {
+    /// The path of the attribute, like `tokio::test`. A path of a single segment also matches the
+    /// attributes whose path ends with it, so `rstest` matches `#[rstest::rstest]`.
+    pub attribute: SmolStr,
+    /// Either [`RunnableKind::Test`] or [`RunnableKind::Bench`].
+    pub kind: RunnableKind,
+}
+
+impl TestHarness {
+    pub fn test(attribute: &str) -> TestHarness {
+        TestHarness { attribute: attribute.into(), kind: RunnableKind::Test }
+    }
+
+    pub fn bench(attribute: &str) -> TestHarness {
+        TestHarness { attribute: attribute.into(), kind: RunnableKind::Bench }
+    }
+
+    /// The harness of libtest and the ones of the most common test frameworks.
+    pub fn defaults() -> Arc<[TestHarness]> {
+        Arc::from(vec![
+            TestHarness::test("test"),
+            TestHarness::bench("bench"),
+            TestHarness::test("tokio::test"),
+            TestHarness::test("async_std::test"),
+            TestHarness::test("rstest"),
+            TestHarness::test("test_case"),
+        ])
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RunnableDescriptor {
+    pub kind: RunnableKind,
+    pub definition: Definition,
+    /// The path of the definition in its crate, without the name of the crate, as understood by
+    /// the test harness.
+    pub path: String,
+    /// The conditions under which the runnable is compiled, including the ones of the modules it
+    /// is in.
+    pub cfg: Option<CfgExpr>,
+    /// The attribute of the harness of tests and benchmarks.
+    pub harness: Option<SmolStr>,
+    /// Whether the test is `#[ignore]`d.
+    pub ignored: bool,
+}
+
+impl RunnableDescriptor {
+    /// The features the runnable is only compiled with.
+    pub fn required_features(&self) -> Vec<SmolStr> {
+        fn collect(cfg: &CfgExpr, acc: &mut Vec<SmolStr>) {
+            match cfg {
+                CfgExpr::Atom(CfgAtom::KeyValue { key, value }) if key == "feature" => {
+                    if !acc.contains(value) {
+                        acc.push(value.clone());
+                    }
+                }
+                CfgExpr::All(preds) => preds.iter().for_each(|pred| collect(pred, acc)),
+                _ => (),
+            }
+        }
+        let mut res = Vec::new();
+        if let Some(cfg) = &self.cfg {
+            collect(cfg, &mut res);
+        }
+        res
+    }
+}
+
+impl fmt::Display for RunnableKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            RunnableKind::Bin => "bin",
+            RunnableKind::Test => "test",
+            RunnableKind::Bench => "bench",
+            RunnableKind::DocTest => "doctest",
+        })
+    }
+}
+
+/// The harness of the test or benchmark with the given attributes. Harnesses whose path matches
+/// exactly are preferred over the ones only matching the last segment.
+pub fn find_harness(db: &dyn TestIndexDatabase, attrs: &Attrs) -> Option<TestHarness> {
+    let harnesses = db.test_harnesses();
+    let paths: Vec<String> = attrs
+        .iter()
+        .map(|attr| {
+            let segments = attr.path.segments().iter().map(|it| it.to_smol_str());
+            segments.collect::<Vec<_>>().join("::")
+        })
+        .collect();
+    let exact = harnesses.iter().find(|harness| paths.iter().any(|it| *it == harness.attribute));
+    let harness = exact.or_else(|| {
+        harnesses.iter().filter(|harness| !harness.attribute.contains("::")).find(|harness| {
+            paths.iter().any(|it| {
+                it.strip_suffix(harness.attribute.as_str())
+                    .map_or(false, |prefix| prefix.ends_with("::"))
+            })
+        })
+    });
+    harness.cloned()
+}
+
+fn module_runnables(db: &dyn TestIndexDatabase, module: Module) -> Arc<[RunnableDescriptor]> {
+    let _p = profile::span("module_runnables");
+    let db: &RootDatabase = db.upcast();
+    // The cfgs of the module and of its ancestors, outermost first.
+    let mut module_cfgs: Vec<CfgExpr> =
+        module.path_to_root(db).into_iter().filter_map(|it| it.attrs(db).cfg()).collect();
+    module_cfgs.reverse();
+    let cfg = |attrs: &Attrs| {
+        let mut preds = module_cfgs.clone();
+        preds.extend(attrs.cfg());
+        match preds.len() {
+            0 => None,
+            1 => preds.pop(),
+            _ => Some(CfgExpr::All(preds)),
+        }
+    };
+    let doctest = |definition: Definition, attrs: &Attrs, path: Option<String>| {
+        let path = path.filter(|_| has_runnable_doc_test(attrs))?;
+        Some(RunnableDescriptor {
+            kind: RunnableKind::DocTest,
+            definition,
+            path,
+            cfg: cfg(attrs),
+            harness: None,
+            ignored: false,
+        })
+    };
+
+    let mut res = Vec::new();
+
+    // Modules declared in their parent are handled there, with the other declarations.
+    if let ModuleSource::SourceFile(_) = module.definition_source(db).value {
+        let path = Some(module_path(db, module));
+        res.extend(doctest(Definition::Module(module), &module.attrs(db), path));
+    }
+    for def in module.declarations(db) {
+        let attrs = match def {
+            ModuleDef::Module(it) => it.attrs(db),
+            ModuleDef::Function(it) => it.attrs(db),
+            ModuleDef::Adt(it) => it.attrs(db),
+            ModuleDef::Variant(it) => it.attrs(db),
+            ModuleDef::Const(it) => it.attrs(db),
+            ModuleDef::Static(it) => it.attrs(db),
+            ModuleDef::Trait(it) => it.attrs(db),
+            ModuleDef::TraitAlias(it) => it.attrs(db),
+            ModuleDef::TypeAlias(it) => it.attrs(db),
+            ModuleDef::Macro(it) => it.attrs(db),
+            ModuleDef::BuiltinType(_) => continue,
+        };
+        let path = match def {
+            ModuleDef::Module(it) => {
+                if let ModuleSource::SourceFile(_) = it.definition_source(db).value {
+                    continue;
+                }
+                Some(module_path(db, it))
+            }
+            _ => def.canonical_path(db),
+        };
+        if let ModuleDef::Function(function) = def {
+            if let Some(runnable) = function_runnable(db, function, &attrs, path.clone()) {
+                res.push(RunnableDescriptor { cfg: cfg(&attrs), ..runnable });
+                continue;
+            }
+        }
+        res.extend(doctest(Definition::from(def), &attrs, path));
+    }
+    for impl_ in module.impl_defs(db) {
+        let Some(adt) = impl_.self_ty(db).as_adt() else { continue };
+        let Some(self_path) = ModuleDef::Adt(adt).canonical_path(db) else { continue };
+        for item in impl_.items(db) {
+            let attrs = item.attrs(db);
+            let path = item.name(db).map(|it| format!("{self_path}::{}", it.display(db)));
+            res.extend(doctest(Definition::from(item), &attrs, path));
+        }
+    }
+    res.into()
+}
+
+/// The runnable of a binary, test or benchmark function, without its cfg.
+fn function_runnable(
+    db: &RootDatabase,
+    function: hir::Function,
+    attrs: &Attrs,
+    path: Option<String>,
+) -> Option<RunnableDescriptor> {
+    let module = function.module(db);
+    let (kind, harness) = if function.name(db).to_smol_str() == "main" && module.is_crate_root() {
+        (RunnableKind::Bin, None)
+    } else {
+        let harness = find_harness(db, attrs)?;
+        (harness.kind, Some(harness.attribute))
+    };
+    Some(RunnableDescriptor {
+        kind,
+        definition: Definition::Function(function),
+        path: path.unwrap_or_else(|| function.name(db).to_smol_str().to_string()),
+        cfg: None,
+        harness,
+        ignored: kind == RunnableKind::Test && attrs.by_key("ignore").exists(),
+    })
+}
+
+fn module_path(db: &RootDatabase, module: Module) -> String {
+    let names: Vec<String> = module
+        .path_to_root(db)
+        .into_iter()
+        .rev()
+        .filter_map(|it| it.name(db))
+        .map(|it| it.display(db).to_string())
+        .collect();
+    names.join("::")
+}
+
+/// The runnables of a crate, sorted by path.
+pub fn crate_runnables(db: &RootDatabase, krate: Crate) -> Vec<RunnableDescriptor> {
+    let _p = profile::span("crate_runnables");
+    let mut res: Vec<_> = krate
+        .modules(db)
+        .into_iter()
+        .flat_map(|module| db.module_runnables(module).to_vec())
+        .collect();
+    res.sort_by(|a, b| a.path.cmp(&b.path));
+    res
+}
+
+/// The runnables of the workspace crates, sorted by path.
+pub fn workspace_runnables(db: &RootDatabase) -> Vec<RunnableDescriptor> {
+    let _p = profile::span("workspace_runnables");
+    let crate_graph = db.crate_graph();
+    let modules: Vec<Module> = Crate::all(db)
+        .into_iter()
+        .filter(|krate| crate_graph[(*krate).into()].origin.is_local())
+        .flat_map(|krate| krate.modules(db))
+        .collect();
+    let mut res = par_flat_map(db, modules, |db, module| db.module_runnables(module).to_vec());
+    res.sort_by(|a, b| a.path.cmp(&b.path));
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::{fixture::WithFixture, salsa::Durability};
+    use expect_test::{expect, Expect};
+    use stdx::format_to;
+
+    use super::*;
+
+    fn check(db: &RootDatabase, expect: Expect) {
+        let mut actual = String::new();
+        for runnable in workspace_runnables(db) {
+            format_to!(actual, "{} {}", runnable.kind, runnable.path);
+            if let Some(harness) = &runnable.harness {
+                format_to!(actual, " #[{harness}]");
+            }
+            if runnable.ignored {
+                actual.push_str(" ignored");
+            }
+            let features = runnable.required_features();
+            if !features.is_empty() {
+                format_to!(actual, " features={}", features.join(","));
+            }
+            actual.push('\n');
+        }
+        expect.assert_eq(&actual);
+    }
+
+    const FIXTURE: &str = r#"
+//- /main.rs crate:main cfg:test,feature=slow
+fn main() {}
+
+/// ```
+/// assert!(true);
+/// ```
+pub struct S;
+
+impl S {
+    /// ```
+    /// S::new();
+    /// ```
+    pub fn new() -> S { S }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn plain() {}
+
+    #[test]
+    #[ignore]
+    fn ignored() {}
+
+    #[tokio::test]
+    async fn with_tokio() {}
+
+    #[rstest::rstest]
+    fn with_rstest() {}
+
+    #[my_harness::check]
+    fn custom() {}
+
+    #[cfg(feature = "slow")]
+    #[bench]
+    fn bench() {}
+}
+"#;
+
+    #[test]
+    fn indexes_runnables() {
+        let db = RootDatabase::with_files(FIXTURE);
+        check(
+            &db,
+            expect![[r#"
+                doctest S
+                doctest S::new
+                bin main
+                bench tests::bench #[bench] features=slow
+                test tests::ignored #[test] ignored
+                test tests::plain #[test]
+                test tests::with_rstest #[rstest]
+                test tests::with_tokio #[tokio::test]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn custom_harnesses() {
+        let mut db = RootDatabase::with_files(FIXTURE);
+        let mut harnesses = TestHarness::defaults().to_vec();
+        harnesses.push(TestHarness::test("my_harness::check"));
+        db.set_test_harnesses_with_durability(Arc::from(harnesses), Durability::HIGH);
+        let runnables = workspace_runnables(&db);
+        let custom = runnables.iter().find(|it| it.path == "tests::custom").unwrap();
+        assert_eq!(custom.kind, RunnableKind::Test);
+        assert_eq!(custom.harness.as_deref(), Some("my_harness::check"));
+    }
+}
@@ -1,49 +1,99 @@
 //! Generates descriptors structure for unstable feature from Unstable Book
-use std::{borrow::Cow, fs, path::Path};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    ops::Range,
+    path::{Path, PathBuf},
+};
 
 use itertools::Itertools;
 use stdx::format_to;
 use test_utils::project_root;
 use xshell::{cmd, Shell};
 
+/// The rustc release the lints and features are generated from. Its toolchain must be installed
+/// for `rustdoc -W help`.
+const RUST_RELEASE: &str = "1.72.1";
+/// The Clippy lints of [`RUST_RELEASE`], as Clippy only publishes them for `.0` releases.
+const CLIPPY_RELEASE: &str = "rust-1.72.0";
+
 /// This clones rustc repo, and so is not worth to keep up-to-date. We update
-/// manually by un-ignoring the test from time to time.
+/// manually by un-ignoring the test from time to time, after bumping [`RUST_RELEASE`].
 #[test]
 #[ignore]
 fn sourcegen_lint_completions() {
     let sh = &Shell::new().unwrap();
 
-    let rust_repo = project_root().join("./target/rust");
+    let rust_repo = project_root().join(format!("./target/rust-{RUST_RELEASE}"));
     if !rust_repo.exists() {
-        cmd!(sh, "git clone --depth=1 https://github.com/rust-lang/rust {rust_repo}")
-            .run()
-            .unwrap();
+        cmd!(
+            sh,
+            "git clone --depth=1 --branch {RUST_RELEASE} https://github.com/rust-lang/rust {rust_repo}"
+        )
+        .run()
+        .unwrap();
     }
 
     let mut contents = String::from(
         r"
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+    Forbid,
+}
 #[derive(Clone)]
 pub struct Lint {
     pub label: &'static str,
     pub description: &'static str,
+    /// The level of the lint when it's not configured, `Allow` for lint groups.
+    pub default_level: LintLevel,
 }
 pub struct LintGroup {
     pub lint: Lint,
     pub children: &'static [&'static str],
 }
+pub struct RenamedLint {
+    pub old: &'static str,
+    pub new: &'static str,
+}
+pub struct RemovedLint {
+    pub label: &'static str,
+    pub reason: &'static str,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeatureStatus {
+    Unstable,
+    Accepted,
+    Removed,
+}
+#[derive(Clone)]
+pub struct Feature {
+    pub label: &'static str,
+    pub description: &'static str,
+    pub status: FeatureStatus,
+    /// The version the feature was added in, or stabilized or removed in for accepted and
+    /// removed features. Library features only declare it on the items they gate.
+    pub since: Option<&'static str>,
+}
 ",
     );
 
     generate_lint_descriptor(sh, &mut contents);
     contents.push('\n');
 
-    generate_feature_descriptor(&mut contents, &rust_repo.join("src/doc/unstable-book/src"));
+    generate_renamed_and_removed_lints(&mut contents, &rust_repo);
     contents.push('\n');
 
-    let lints_json = project_root().join("./target/clippy_lints.json");
+    generate_feature_descriptor(&mut contents, &rust_repo);
+    contents.push('\n');
+
+    let lints_json = project_root().join(format!("./target/clippy_lints-{CLIPPY_RELEASE}.json"));
     cmd!(
         sh,
-        "curl https://rust-lang.github.io/rust-clippy/master/lints.json --output {lints_json}"
+        "curl https://rust-lang.github.io/rust-clippy/{CLIPPY_RELEASE}/lints.json --output {lints_json}"
     )
     .run()
     .unwrap();
@@ -58,7 +108,8 @@ pub struct LintGroup {
 fn generate_lint_descriptor(sh: &Shell, buf: &mut String) {
     // FIXME: rustdoc currently requires an input file for -Whelp cc https://github.com/rust-lang/rust/pull/88831
     let file = project_root().join(file!());
-    let stdout = cmd!(sh, "rustdoc -W help {file}").read().unwrap();
+    let toolchain = format!("+{RUST_RELEASE}");
+    let stdout = cmd!(sh, "rustdoc {toolchain} -W help {file}").read().unwrap();
     let start_lints = stdout.find("----  -------  -------").unwrap();
     let start_lint_groups = stdout.find("----  ---------").unwrap();
     let start_lints_rustdoc =
@@ -71,8 +122,8 @@ fn generate_lint_descriptor(sh: &Shell, buf: &mut String) {
 
     let lints = stdout[start_lints..].lines().skip(1).take_while(|l| !l.is_empty()).map(|line| {
         let (name, rest) = line.trim().split_once(char::is_whitespace).unwrap();
-        let (_default_level, description) = rest.trim().split_once(char::is_whitespace).unwrap();
-        (name.trim(), Cow::Borrowed(description.trim()), vec![])
+        let (default_level, description) = rest.trim().split_once(char::is_whitespace).unwrap();
+        (name.trim(), Cow::Borrowed(description.trim()), lint_level(default_level), vec![])
     });
     let lint_groups =
         stdout[start_lint_groups..].lines().skip(1).take_while(|l| !l.is_empty()).map(|line| {
@@ -80,6 +131,7 @@ fn generate_lint_descriptor(sh: &Shell, buf: &mut String) {
             (
                 name.trim(),
                 format!("lint group for: {}", lints.trim()).into(),
+                "Allow",
                 lints
                     .split_ascii_whitespace()
                     .map(|s| s.trim().trim_matches(',').replace('-', "_"))
@@ -91,12 +143,12 @@ fn generate_lint_descriptor(sh: &Shell, buf: &mut String) {
         .chain(lint_groups)
         .sorted_by(|(ident, ..), (ident2, ..)| ident.cmp(ident2))
         .collect::<Vec<_>>();
-    for (name, description, ..) in &lints {
-        push_lint_completion(buf, &name.replace('-', "_"), description);
+    for (name, description, default_level, _) in &lints {
+        push_lint_completion(buf, &name.replace('-', "_"), description, default_level);
     }
     buf.push_str("];\n");
     buf.push_str(r#"pub const DEFAULT_LINT_GROUPS: &[LintGroup] = &["#);
-    for (name, description, children) in &lints {
+    for (name, description, _, children) in &lints {
         if !children.is_empty() {
             // HACK: warnings is emitted with a general description, not with its members
             if name == &"warnings" {
@@ -118,9 +170,8 @@ fn generate_lint_descriptor(sh: &Shell, buf: &mut String) {
     let lints_rustdoc =
         stdout[start_lints_rustdoc..].lines().skip(2).take_while(|l| !l.is_empty()).map(|line| {
             let (name, rest) = line.trim().split_once(char::is_whitespace).unwrap();
-            let (_default_level, description) =
-                rest.trim().split_once(char::is_whitespace).unwrap();
-            (name.trim(), Cow::Borrowed(description.trim()), vec![])
+            let (default_level, description) = rest.trim().split_once(char::is_whitespace).unwrap();
+            (name.trim(), Cow::Borrowed(description.trim()), lint_level(default_level), vec![])
         });
     let lint_groups_rustdoc =
         stdout[start_lint_groups_rustdoc..].lines().skip(2).take_while(|l| !l.is_empty()).map(
@@ -129,6 +180,7 @@ fn generate_lint_descriptor(sh: &Shell, buf: &mut String) {
                 (
                     name.trim(),
                     format!("lint group for: {}", lints.trim()).into(),
+                    "Allow",
                     lints
                         .split_ascii_whitespace()
                         .map(|s| s.trim().trim_matches(',').replace('-', "_"))
@@ -142,13 +194,13 @@ fn generate_lint_descriptor(sh: &Shell, buf: &mut String) {
         .sorted_by(|(ident, ..), (ident2, ..)| ident.cmp(ident2))
         .collect::<Vec<_>>();
 
-    for (name, description, ..) in &lints_rustdoc {
-        push_lint_completion(buf, &name.replace('-', "_"), description)
+    for (name, description, default_level, _) in &lints_rustdoc {
+        push_lint_completion(buf, &name.replace('-', "_"), description, default_level)
     }
     buf.push_str("];\n");
 
     buf.push_str(r#"pub const RUSTDOC_LINT_GROUPS: &[LintGroup] = &["#);
-    for (name, description, children) in &lints_rustdoc {
+    for (name, description, _, children) in &lints_rustdoc {
         if !children.is_empty() {
             push_lint_group(buf, &name.replace('-', "_"), description, children);
         }
@@ -157,8 +209,207 @@ fn generate_lint_descriptor(sh: &Shell, buf: &mut String) {
     buf.push_str("];\n");
 }
 
-fn generate_feature_descriptor(buf: &mut String, src_dir: &Path) {
-    let mut features = ["language-features", "library-features"]
+fn lint_level(level: &str) -> &'static str {
+    match level {
+        "allow" => "Allow",
+        "warn" => "Warn",
+        "deny" => "Deny",
+        "forbid" => "Forbid",
+        _ => panic!("unknown lint level {level}"),
+    }
+}
+
+/// A source file of the rustc checkout, which the parsers below report errors in.
+struct Source {
+    path: PathBuf,
+    text: String,
+}
+
+impl Source {
+    fn read(path: PathBuf) -> Source {
+        let text = fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()));
+        Source { path, text }
+    }
+
+    fn error(&self, offset: usize, message: &str) -> ! {
+        let line = self.text[..offset].matches('\n').count() + 1;
+        panic!("{}:{line}: {message}", self.path.display())
+    }
+
+    /// The lines of the file along with their offsets.
+    fn lines(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.text.split_inclusive('\n').scan(0, |offset, line| {
+            let line_start = *offset;
+            *offset += line.len();
+            Some((line_start, line.trim_end()))
+        })
+    }
+
+    /// The end of the parenthesized expression that starts at `start`, skipping string literals.
+    fn closing_paren(&self, start: usize) -> usize {
+        let mut depth = 0;
+        let mut offset = start;
+        while let Some(idx) = self.text[offset..].find(['(', ')', '"']) {
+            let idx = offset + idx;
+            offset = idx + 1;
+            match self.text.as_bytes()[idx] {
+                b'(' => depth += 1,
+                b')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return idx + 1;
+                    }
+                }
+                _ => offset = self.string_literal(idx).1,
+            }
+        }
+        self.error(start, "unclosed parenthesis")
+    }
+
+    /// Parses the string literal that starts at `start`, which may span lines with `\`
+    /// continuations, returning its value and end.
+    fn string_literal(&self, start: usize) -> (String, usize) {
+        let mut lit = String::new();
+        let mut chars = self.text[start + 1..].char_indices();
+        while let Some((idx, c)) = chars.next() {
+            match c {
+                '"' => return (lit, start + 1 + idx + 1),
+                '\\' => match chars.next() {
+                    Some((_, '\n')) => {
+                        while chars.clone().next().map_or(false, |(_, c)| c.is_whitespace()) {
+                            chars.next();
+                        }
+                    }
+                    Some((_, c)) => lit.push(c),
+                    None => break,
+                },
+                c => lit.push(c),
+            }
+        }
+        self.error(start, "unterminated string literal")
+    }
+
+    /// Collects the string literals in `range`.
+    fn string_literals(&self, range: Range<usize>) -> Vec<String> {
+        let mut res = Vec::new();
+        let mut offset = range.start;
+        while let Some(idx) = self.text[offset..range.end].find('"') {
+            let (lit, end) = self.string_literal(offset + idx);
+            if end > range.end {
+                self.error(offset + idx, "string literal extends past the item");
+            }
+            res.push(lit);
+            offset = end;
+        }
+        res
+    }
+
+    /// Splits the tuple in `range` into its fields, ignoring commas in nested parentheses.
+    fn tuple_fields(&self, range: Range<usize>) -> Vec<&str> {
+        let text = &self.text[range.start + 1..range.end - 1];
+        let mut fields = Vec::new();
+        let (mut depth, mut field_start, mut in_string) = (0, 0, false);
+        let mut chars = text.char_indices();
+        while let Some((idx, c)) = chars.next() {
+            match c {
+                '"' => in_string = !in_string,
+                '\\' if in_string => {
+                    chars.next();
+                }
+                '(' if !in_string => depth += 1,
+                ')' if !in_string => depth -= 1,
+                ',' if !in_string && depth == 0 => {
+                    fields.push(text[field_start..idx].trim());
+                    field_start = idx + 1;
+                }
+                _ => (),
+            }
+        }
+        fields.push(text[field_start..].trim());
+        fields.retain(|it| !it.is_empty());
+        fields
+    }
+}
+
+fn generate_renamed_and_removed_lints(buf: &mut String, rust_repo: &Path) {
+    let rustc_lints = Source::read(rust_repo.join("compiler/rustc_lint/src/lib.rs"));
+    let mut renamed = Vec::new();
+    let mut removed = Vec::new();
+    for (idx, _) in rustc_lints.text.match_indices("store.register_") {
+        let call = &rustc_lints.text[idx..];
+        let target = if call.starts_with("store.register_renamed(") {
+            &mut renamed
+        } else if call.starts_with("store.register_removed(") {
+            &mut removed
+        } else {
+            continue;
+        };
+        let args = idx + call.find('(').unwrap();
+        let end = rustc_lints.closing_paren(args);
+        match &rustc_lints.string_literals(args..end)[..] {
+            [name, other] => target.push((name.clone(), other.clone())),
+            _ => rustc_lints.error(idx, "expected a lint name and a string argument"),
+        }
+    }
+
+    let clippy_lints = rust_repo.join("src/tools/clippy/clippy_lints/src");
+    let clippy_renamed = Source::read(clippy_lints.join("renamed_lints.rs"));
+    for (offset, line) in clippy_renamed.lines() {
+        if !line.trim_start().starts_with("(\"") {
+            continue;
+        }
+        match &clippy_renamed.string_literals(offset..offset + line.len())[..] {
+            [old, new] => renamed.push((old.clone(), new.clone())),
+            _ => clippy_renamed.error(offset, "expected the old and the new name of a lint"),
+        }
+    }
+    let clippy_deprecated = Source::read(clippy_lints.join("deprecated_lints.rs"));
+    let mut lines = clippy_deprecated.lines();
+    while let Some((offset, line)) = lines.next() {
+        let Some(name) = line.trim().strip_prefix("pub ").and_then(|it| it.strip_suffix(','))
+        else {
+            continue;
+        };
+        if name.bytes().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'_') {
+            let reason = match lines.next() {
+                Some((offset, line)) => {
+                    clippy_deprecated.string_literals(offset..offset + line.len()).pop()
+                }
+                None => None,
+            };
+            let Some(reason) = reason else {
+                clippy_deprecated.error(offset, "expected the deprecation reason after the lint");
+            };
+            removed.push((format!("clippy::{}", name.to_ascii_lowercase()), reason));
+        }
+    }
+    renamed.sort();
+    removed.sort();
+
+    buf.push_str(r#"pub const RENAMED_LINTS: &[RenamedLint] = &["#);
+    buf.push('\n');
+    for (old, new) in renamed {
+        format_to!(buf, r#"    RenamedLint {{ old: "{}", new: "{}" }},"#, old, new);
+    }
+    buf.push_str("];\n");
+
+    buf.push_str(r#"pub const REMOVED_LINTS: &[RemovedLint] = &["#);
+    buf.push('\n');
+    for (label, reason) in removed {
+        format_to!(
+            buf,
+            r###"    RemovedLint {{ label: "{}", reason: r##"{}"## }},"###,
+            label,
+            reason
+        );
+    }
+    buf.push_str("];\n");
+}
+
+fn generate_feature_descriptor(buf: &mut String, rust_repo: &Path) {
+    let src_dir = rust_repo.join("src/doc/unstable-book/src");
+    let docs: BTreeMap<String, String> = ["language-features", "library-features"]
         .into_iter()
         .flat_map(|it| sourcegen::list_files(&src_dir.join(it)))
         .filter(|path| {
@@ -170,12 +421,85 @@ fn generate_feature_descriptor(buf: &mut String, src_dir: &Path) {
             let doc = fs::read_to_string(path).unwrap();
             (feature_ident, doc)
         })
-        .collect::<Vec<_>>();
-    features.sort_by(|(feature_ident, _), (feature_ident2, _)| feature_ident.cmp(feature_ident2));
+        .collect();
+
+    // The feature gates of the compiler, declared in `declare_features!` like
+    // `/// Allows ... (active, name, "1.0.0", Some(12345), None),`, possibly over several lines.
+    let mut gates: BTreeMap<String, (String, &str, String)> = BTreeMap::new();
+    for file in ["active.rs", "accepted.rs", "removed.rs"] {
+        let source = Source::read(rust_repo.join("compiler/rustc_feature/src").join(file));
+        let invocation = "\ndeclare_features! (";
+        let mut invocations = source.text.match_indices(invocation).peekable();
+        if invocations.peek().is_none() {
+            source.error(0, "expected a `declare_features!` invocation");
+        }
+        for (idx, _) in invocations {
+            let body_end = source.closing_paren(idx + invocation.len() - 1) - 1;
+            let mut offset = idx + invocation.len();
+            let mut doc = Vec::new();
+            loop {
+                let rest = &source.text[offset..body_end];
+                let item = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+                offset += rest.len() - item.len();
+                if item.is_empty() {
+                    break;
+                }
+                if !item.starts_with('(') {
+                    let line = &item[..item.find('\n').unwrap_or(item.len())];
+                    if let Some(line) = line.strip_prefix("///") {
+                        doc.push(line.trim());
+                    } else if !line.starts_with("#[") {
+                        doc.clear();
+                    }
+                    offset += line.len();
+                    continue;
+                }
 
-    buf.push_str(r#"pub const FEATURES: &[Lint] = &["#);
-    for (feature_ident, doc) in features.into_iter() {
-        push_lint_completion(buf, &feature_ident, &doc)
+                let end = source.closing_paren(offset);
+                let fields = source.tuple_fields(offset..end);
+                let status = match fields[0] {
+                    "active" | "incomplete" => "Unstable",
+                    "accepted" => "Accepted",
+                    "removed" | "stable_removed" => "Removed",
+                    status => source.error(offset, &format!("unknown feature status `{status}`")),
+                };
+                let (Some(name), Some(since)) = (
+                    fields.get(1),
+                    fields.get(2).and_then(|it| it.strip_prefix('"')?.strip_suffix('"')),
+                ) else {
+                    source.error(offset, "expected the name and the version of a feature");
+                };
+                gates.insert(name.to_string(), (doc.join(" "), status, since.to_owned()));
+                doc.clear();
+                offset = end;
+            }
+        }
+    }
+
+    let features = docs.keys().chain(gates.keys()).collect::<BTreeSet<_>>();
+    buf.push_str(r#"pub const FEATURES: &[Feature] = &["#);
+    for feature_ident in features {
+        let (description, status, since) = match gates.get(feature_ident) {
+            Some((doc, status, since)) => {
+                (docs.get(feature_ident).unwrap_or(doc), *status, Some(since))
+            }
+            // Library features only have their unstable book entry.
+            None => (&docs[feature_ident], "Unstable", None),
+        };
+        let since = since.map_or_else(|| "None".to_owned(), |it| format!("Some(\"{it}\")"));
+        format_to!(
+            buf,
+            r###"    Feature {{
+        label: "{}",
+        description: r##"{}"##,
+        status: FeatureStatus::{},
+        since: {},
+    }},"###,
+            feature_ident,
+            description,
+            status,
+            since,
+        );
     }
     buf.push('\n');
     buf.push_str("];\n");
@@ -185,6 +509,7 @@ fn generate_feature_descriptor(buf: &mut String, src_dir: &Path) {
 struct ClippyLint {
     help: String,
     id: String,
+    level: &'static str,
 }
 
 fn unescape(s: &str) -> String {
@@ -201,6 +526,7 @@ fn generate_descriptor_clippy(buf: &mut String, path: &Path) {
             let clippy_lint = ClippyLint {
                 id: line.strip_suffix(r#"","#).expect("should be suffixed by comma").into(),
                 help: String::new(),
+                level: "Allow",
             };
             clippy_lints.push(clippy_lint)
         } else if let Some(line) = line.strip_prefix(r#""group": ""#) {
@@ -210,6 +536,12 @@ fn generate_descriptor_clippy(buf: &mut String, path: &Path) {
                     .or_default()
                     .push(clippy_lints.last().unwrap().id.clone());
             }
+        } else if let Some(line) = line.strip_prefix(r#""level": ""#) {
+            let level = line.strip_suffix("\",").expect("should be suffixed by comma");
+            // Deprecated lints have no level.
+            if level != "none" {
+                clippy_lints.last_mut().unwrap().level = lint_level(level);
+            }
         } else if let Some(line) = line.strip_prefix(r#""docs": ""#) {
             let prefix_to_strip = r#" ### What it does"#;
             let line = match line.strip_prefix(prefix_to_strip) {
@@ -235,7 +567,7 @@ fn generate_descriptor_clippy(buf: &mut String, path: &Path) {
     for clippy_lint in clippy_lints.into_iter() {
         let lint_ident = format!("clippy::{}", clippy_lint.id);
         let doc = clippy_lint.help;
-        push_lint_completion(buf, &lint_ident, &doc);
+        push_lint_completion(buf, &lint_ident, &doc, clippy_lint.level);
     }
     buf.push_str("];\n");
 
@@ -252,15 +584,17 @@ fn generate_descriptor_clippy(buf: &mut String, path: &Path) {
     buf.push_str("];\n");
 }
 
-fn push_lint_completion(buf: &mut String, label: &str, description: &str) {
+fn push_lint_completion(buf: &mut String, label: &str, description: &str, default_level: &str) {
     format_to!(
         buf,
         r###"    Lint {{
         label: "{}",
         description: r##"{}"##,
+        default_level: LintLevel::{},
     }},"###,
         label,
         description,
+        default_level,
     );
 }
 
@@ -271,7 +605,7 @@ fn push_lint_group(buf: &mut String, label: &str, description: &str, children: &
         "###,
     );
 
-    push_lint_completion(buf, label, description);
+    push_lint_completion(buf, label, description, "Allow");
 
     let children = format!("&[{}]", children.iter().map(|it| format!("\"{it}\"")).join(", "));
     format_to!(
@@ -0,0 +1,151 @@
+//! A cheap, per-file text index used to prune the set of files that need to be
+//! looked at when searching for usages.
+//!
+//! For every file we lex the source text once and record every identifier-like
+//! token in a bloom filter. A search for `foo` can then skip every file whose
+//! filter definitely does not contain `foo`, without running a text search over
+//! the file, let alone parsing it.
+//!
+//! The index is a regular salsa query keyed by [`FileId`], so it is kept up to
+//! date by `apply_change`: changing the text of a file invalidates the index of
+//! that file only.
+
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use base_db::{salsa, FileId};
+use parser::SyntaxKind;
+use rustc_hash::{FxHashSet, FxHasher};
+use triomphe::Arc;
+
+#[salsa::query_group(TextIndexDatabaseStorage)]
+pub trait TextIndexDatabase: base_db::SourceDatabase {
+    /// The identifier filter of the given file.
+    fn file_text_index(&self, file_id: FileId) -> Arc<FileTextIndex>;
+}
+
+fn file_text_index(db: &dyn TextIndexDatabase, file_id: FileId) -> Arc<FileTextIndex> {
+    let _p = profile::span("file_text_index");
+    let text = db.file_text(file_id);
+    Arc::new(FileTextIndex::new(&text))
+}
+
+/// A bloom filter over the identifier-like tokens of a single file.
+///
+/// False positives are possible, false negatives are not: if
+/// [`FileTextIndex::may_contain`] returns `false`, no token of the file has
+/// the given text.
+#[derive(PartialEq, Eq)]
+pub struct FileTextIndex {
+    bits: Box<[u64]>,
+    n_tokens: usize,
+}
+
+impl fmt::Debug for FileTextIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileTextIndex")
+            .field("n_tokens", &self.n_tokens)
+            .field("n_bits", &(self.bits.len() * 64))
+            .finish()
+    }
+}
+
+/// Bits per distinct token, this gives a false positive rate of roughly 1%.
+const BITS_PER_TOKEN: usize = 10;
+const N_HASHES: u64 = 7;
+
+impl FileTextIndex {
+    pub fn new(text: &str) -> FileTextIndex {
+        let lexed = parser::LexedStr::new(text);
+        let mut tokens = FxHashSet::default();
+        for idx in 0..lexed.len() {
+            let text = lexed.text(idx);
+            match lexed.kind(idx) {
+                SyntaxKind::IDENT => {
+                    tokens.insert(text.trim_start_matches("r#"));
+                }
+                SyntaxKind::LIFETIME_IDENT => {
+                    tokens.insert(text);
+                }
+                // Tuple fields are named by integers, and `a.0.1` lexes the field
+                // accesses as a single float literal.
+                SyntaxKind::INT_NUMBER | SyntaxKind::FLOAT_NUMBER => {
+                    tokens.extend(text.split('.'));
+                }
+                kind if kind.is_keyword() => {
+                    tokens.insert(text);
+                }
+                _ => (),
+            }
+        }
+
+        let n_words = (tokens.len() * BITS_PER_TOKEN + 63) / 64;
+        let mut index = FileTextIndex {
+            bits: vec![0; n_words.max(1)].into_boxed_slice(),
+            n_tokens: tokens.len(),
+        };
+        for token in tokens {
+            index.insert(token);
+        }
+        index
+    }
+
+    /// Returns `false` if the file definitely does not contain a token with the given text.
+    pub fn may_contain(&self, token: &str) -> bool {
+        self.bit_indices(token).all(|bit| self.bits[bit / 64] & (1 << (bit % 64)) != 0)
+    }
+
+    pub fn memory_size(&self) -> usize {
+        self.bits.len() * std::mem::size_of::<u64>()
+    }
+
+    fn insert(&mut self, token: &str) {
+        for bit in self.bit_indices(token).collect::<Vec<_>>() {
+            self.bits[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+
+    fn bit_indices(&self, token: &str) -> impl Iterator<Item = usize> {
+        let n_bits = self.bits.len() as u64 * 64;
+        let mut hasher = FxHasher::default();
+        token.hash(&mut hasher);
+        let hash = hasher.finish();
+        // Kirsch-Mitzenmacher double hashing: derive all hashes from two.
+        let (h1, h2) = (hash, hash.rotate_left(32).wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1);
+        (0..N_HASHES).map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % n_bits) as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FileTextIndex;
+
+    #[test]
+    fn contains_identifier_like_tokens() {
+        let index = FileTextIndex::new(
+            r#"
+fn foo<'a>(r#bar: &'a Baz) -> Self {
+    let _ = (x.0, y.1.2, "quux", /* frob */);
+    self::super::crate::r#match
+}
+"#,
+        );
+        for token in ["foo", "'a", "bar", "Baz", "Self", "self", "super", "crate", "fn", "let"] {
+            assert!(index.may_contain(token), "{token}");
+        }
+        for token in ["0", "1", "2", "match"] {
+            assert!(index.may_contain(token), "{token}");
+        }
+        for token in ["quux", "frob", "Bar", "baz", "fo"] {
+            assert!(!index.may_contain(token), "{token}");
+        }
+    }
+
+    #[test]
+    fn empty_file() {
+        let index = FileTextIndex::new("");
+        assert!(!index.may_contain("foo"));
+    }
+}
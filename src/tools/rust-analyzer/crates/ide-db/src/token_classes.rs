@@ -0,0 +1,353 @@
+//! Classifies all the names of a file at once, for semantic highlighting and exporters.
+//!
+//! Every identifier-like token is classified into the definitions it refers to, with the kind of
+//! symbol and modifiers describing the definition and the use. Tokens in macro calls are
+//! classified in the expansions they end up in. The properties of each definition are computed
+//! once per file rather than once per token.
+
+use std::{fmt, ops};
+
+use hir::{AsAssocItem, Semantics};
+use syntax::{ast, AstNode, SyntaxKind, SyntaxToken, TextRange, T};
+
+use crate::{
+    base_db::FileId,
+    defs::{Definition, IdentClass, NameClass},
+    search::ReferenceCategory,
+    FxHashMap, RootDatabase, SymbolKind,
+};
+
+/// A use of a definition by a token of a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClassifiedToken {
+    /// The range of the token in the file.
+    pub range: TextRange,
+    pub definition: Definition,
+    /// The kind of the definition, if it is a symbol. Builtin types aren't.
+    pub kind: Option<SymbolKind>,
+    pub modifiers: TokenModifiers,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TokenModifier {
+    /// Associated items of traits and impls.
+    Associated,
+    Async,
+    /// Locals and parameters that can be called.
+    Callable,
+    /// Methods taking `self` by value.
+    Consuming,
+    /// The root module of a crate.
+    CrateRoot,
+    /// The token is the name of the definition, where it is declared.
+    Definition,
+    /// Definitions of another crate.
+    Library,
+    Mutable,
+    /// Definitions of the crate of the file visible outside of it.
+    Public,
+    /// References, and methods taking `self` by reference.
+    Reference,
+    /// Associated functions without a `self` parameter.
+    Static,
+    /// The items of traits and trait impls.
+    Trait,
+    Unsafe,
+    /// The token is assigned to.
+    Write,
+}
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TokenModifiers(u32);
+
+impl TokenModifier {
+    const ALL: &'static [TokenModifier; TokenModifier::Write as usize + 1] = &[
+        TokenModifier::Associated,
+        TokenModifier::Async,
+        TokenModifier::Callable,
+        TokenModifier::Consuming,
+        TokenModifier::CrateRoot,
+        TokenModifier::Definition,
+        TokenModifier::Library,
+        TokenModifier::Mutable,
+        TokenModifier::Public,
+        TokenModifier::Reference,
+        TokenModifier::Static,
+        TokenModifier::Trait,
+        TokenModifier::Unsafe,
+        TokenModifier::Write,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TokenModifier::Associated => "associated",
+            TokenModifier::Async => "async",
+            TokenModifier::Callable => "callable",
+            TokenModifier::Consuming => "consuming",
+            TokenModifier::CrateRoot => "crate_root",
+            TokenModifier::Definition => "definition",
+            TokenModifier::Library => "library",
+            TokenModifier::Mutable => "mutable",
+            TokenModifier::Public => "public",
+            TokenModifier::Reference => "reference",
+            TokenModifier::Static => "static",
+            TokenModifier::Trait => "trait",
+            TokenModifier::Unsafe => "unsafe",
+            TokenModifier::Write => "write",
+        }
+    }
+
+    fn mask(self) -> u32 {
+        1 << (self as u32)
+    }
+}
+
+impl fmt::Display for TokenModifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl ops::BitOrAssign<TokenModifier> for TokenModifiers {
+    fn bitor_assign(&mut self, rhs: TokenModifier) {
+        self.0 |= rhs.mask();
+    }
+}
+
+impl ops::BitOr<TokenModifier> for TokenModifiers {
+    type Output = TokenModifiers;
+
+    fn bitor(mut self, rhs: TokenModifier) -> TokenModifiers {
+        self |= rhs;
+        self
+    }
+}
+
+impl TokenModifiers {
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(self, m: TokenModifier) -> bool {
+        self.0 & m.mask() == m.mask()
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = TokenModifier> {
+        TokenModifier::ALL.iter().copied().filter(move |it| self.0 & it.mask() == it.mask())
+    }
+}
+
+/// Classifies the names, name references and lifetimes of `file_id`, in the order of the file. A
+/// token referring to both a local and a field, like a field shorthand, is classified twice.
+pub fn classify_file(sema: &Semantics<'_, RootDatabase>, file_id: FileId) -> Vec<ClassifiedToken> {
+    let _p = profile::span("classify_file");
+    let file = sema.parse(file_id);
+    let krate = sema.to_module_def(file_id).map(|it| it.krate());
+    let mut properties: FxHashMap<Definition, (Option<SymbolKind>, TokenModifiers)> =
+        FxHashMap::default();
+    let mut res = Vec::new();
+
+    for token in file.syntax().descendants_with_tokens().filter_map(|it| it.into_token()) {
+        if !matches!(
+            token.kind(),
+            SyntaxKind::IDENT
+                | SyntaxKind::INT_NUMBER
+                | SyntaxKind::LIFETIME_IDENT
+                | T![self]
+                | T![super]
+                | T![crate]
+                | T![Self]
+        ) {
+            continue;
+        }
+        let range = token.text_range();
+        // Descending is expensive, so only tokens of macro calls are mapped into the expansions.
+        let token = if token.parent().map_or(false, |it| ast::TokenTree::can_cast(it.kind())) {
+            sema.descend_into_macros_single(token)
+        } else {
+            token
+        };
+        let Some(class) = IdentClass::classify_token(sema, &token) else { continue };
+        let declared = match &class {
+            IdentClass::NameClass(NameClass::Definition(it)) => Some(*it),
+            IdentClass::NameClass(NameClass::PatFieldShorthand { local_def, .. }) => {
+                Some(Definition::Local(*local_def))
+            }
+            _ => None,
+        };
+        for definition in class.definitions_no_ops() {
+            let (kind, mut modifiers) = *properties
+                .entry(definition)
+                .or_insert_with(|| definition_properties(sema.db, krate, definition));
+            if declared == Some(definition) {
+                modifiers |= TokenModifier::Definition;
+            }
+            if is_write(&token, definition) {
+                modifiers |= TokenModifier::Write;
+            }
+            res.push(ClassifiedToken { range, definition, kind, modifiers });
+        }
+    }
+    res
+}
+
+fn is_write(token: &SyntaxToken, definition: Definition) -> bool {
+    let Some(name_ref) = token.parent().and_then(ast::NameRef::cast) else { return false };
+    ReferenceCategory::new(&definition, &name_ref) == Some(ReferenceCategory::Write)
+}
+
+/// The kind of `def` and the modifiers that don't depend on the token.
+fn definition_properties(
+    db: &RootDatabase,
+    krate: Option<hir::Crate>,
+    def: Definition,
+) -> (Option<SymbolKind>, TokenModifiers) {
+    let mut modifiers = TokenModifiers::default();
+    if let Some(item) = def.as_assoc_item(db) {
+        modifiers |= TokenModifier::Associated;
+        if item.containing_trait_or_trait_impl(db).is_some() {
+            modifiers |= TokenModifier::Trait;
+        }
+    }
+    let kind = match def {
+        Definition::Macro(it) => SymbolKind::from(it.kind(db)),
+        Definition::Field(_) => SymbolKind::Field,
+        Definition::Module(it) => {
+            if it.is_crate_root() {
+                modifiers |= TokenModifier::CrateRoot;
+            }
+            SymbolKind::Module
+        }
+        Definition::Function(it) => {
+            if it.as_assoc_item(db).is_some() {
+                match it.self_param(db).map(|it| it.access(db)) {
+                    Some(hir::Access::Exclusive) => {
+                        modifiers |= TokenModifier::Mutable;
+                        modifiers |= TokenModifier::Reference;
+                    }
+                    Some(hir::Access::Shared) => modifiers |= TokenModifier::Reference,
+                    Some(hir::Access::Owned) => modifiers |= TokenModifier::Consuming,
+                    None => modifiers |= TokenModifier::Static,
+                }
+            }
+            if it.is_unsafe_to_call(db) {
+                modifiers |= TokenModifier::Unsafe;
+            }
+            if it.is_async(db) {
+                modifiers |= TokenModifier::Async;
+            }
+            SymbolKind::Function
+        }
+        Definition::Adt(hir::Adt::Struct(_)) => SymbolKind::Struct,
+        Definition::Adt(hir::Adt::Enum(_)) => SymbolKind::Enum,
+        Definition::Adt(hir::Adt::Union(_)) => SymbolKind::Union,
+        Definition::Variant(_) => SymbolKind::Variant,
+        Definition::Const(_) => SymbolKind::Const,
+        Definition::Static(it) => {
+            if it.is_mut(db) {
+                modifiers |= TokenModifier::Mutable;
+                modifiers |= TokenModifier::Unsafe;
+            }
+            SymbolKind::Static
+        }
+        Definition::Trait(_) => SymbolKind::Trait,
+        Definition::TraitAlias(_) => SymbolKind::TraitAlias,
+        Definition::TypeAlias(_) => SymbolKind::TypeAlias,
+        Definition::BuiltinType(_) => return (None, modifiers),
+        Definition::SelfType(_) => SymbolKind::Impl,
+        Definition::GenericParam(hir::GenericParam::TypeParam(_)) => SymbolKind::TypeParam,
+        Definition::GenericParam(hir::GenericParam::ConstParam(_)) => SymbolKind::ConstParam,
+        Definition::GenericParam(hir::GenericParam::LifetimeParam(_)) => SymbolKind::LifetimeParam,
+        Definition::Local(it) => {
+            let ty = it.ty(db);
+            if it.is_mut(db) || ty.is_mutable_reference() {
+                modifiers |= TokenModifier::Mutable;
+            }
+            if it.is_ref(db) || ty.is_reference() {
+                modifiers |= TokenModifier::Reference;
+            }
+            if ty.as_callable(db).is_some() || ty.impls_fnonce(db) {
+                modifiers |= TokenModifier::Callable;
+            }
+            if it.is_self(db) {
+                SymbolKind::SelfParam
+            } else if it.is_param(db) {
+                SymbolKind::ValueParam
+            } else {
+                SymbolKind::Local
+            }
+        }
+        Definition::Label(_) => SymbolKind::Label,
+        Definition::BuiltinAttr(_) => SymbolKind::BuiltinAttr,
+        Definition::ToolModule(_) => SymbolKind::ToolModule,
+        Definition::DeriveHelper(_) => SymbolKind::DeriveHelper,
+    };
+    if def.krate(db) != krate {
+        modifiers |= TokenModifier::Library;
+    } else if def.visibility(db) == Some(hir::Visibility::Public) {
+        modifiers |= TokenModifier::Public;
+    }
+    (Some(kind), modifiers)
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::{fixture::WithFixture, SourceDatabaseExt};
+    use expect_test::{expect, Expect};
+    use stdx::format_to;
+
+    use super::*;
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let (db, file_id) = RootDatabase::with_single_file(ra_fixture);
+        let sema = Semantics::new(&db);
+        let text = db.file_text(file_id);
+        let mut actual = String::new();
+        for token in classify_file(&sema, file_id) {
+            format_to!(actual, "{} {:?}", &text[token.range], token.kind);
+            for modifier in token.modifiers.iter() {
+                format_to!(actual, " {modifier}");
+            }
+            actual.push('\n');
+        }
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn classifies_names_and_references() {
+        check(
+            r#"
+macro_rules! id { ($e:expr) => { $e }; }
+pub struct S { field: u32 }
+impl S {
+    fn method(&mut self) {}
+}
+fn main() {
+    let mut s = S { field: 0 };
+    s.field = id!(s.field);
+    s.method();
+}
+"#,
+            expect![[r#"
+                id Some(Macro) definition
+                S Some(Struct) definition public
+                field Some(Field) definition
+                u32 None
+                S Some(Struct) public
+                method Some(Function) associated definition mutable reference
+                self Some(SelfParam) definition mutable reference
+                main Some(Function) definition
+                s Some(Local) definition mutable
+                S Some(Struct) public
+                field Some(Field)
+                s Some(Local) mutable
+                field Some(Field) write
+                id Some(Macro)
+                s Some(Local) mutable
+                field Some(Field)
+                s Some(Local) mutable
+                method Some(Function) associated mutable reference
+            "#]],
+        );
+    }
+}
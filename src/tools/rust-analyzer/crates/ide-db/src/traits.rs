@@ -71,6 +71,45 @@ pub fn get_missing_assoc_items(
     })
 }
 
+/// The impls of a trait, split by whether they override a provided method of it.
+#[derive(Debug, Default)]
+pub struct DefaultOverrides {
+    /// The methods of the impls that override the default body.
+    pub overriding: Vec<hir::Function>,
+    /// The impls that inherit the default body.
+    pub inheriting: Vec<hir::Impl>,
+}
+
+/// Given a trait method with a default body, finds the impls of the trait that override it and
+/// the ones that inherit the default.
+///
+/// Returns `None` if `func` is not a provided trait method.
+pub fn default_method_overrides(
+    db: &RootDatabase,
+    func: hir::Function,
+) -> Option<DefaultOverrides> {
+    let trait_ = match func.as_assoc_item(db)?.container(db) {
+        hir::AssocItemContainer::Trait(it) => it,
+        hir::AssocItemContainer::Impl(_) => return None,
+    };
+    if !func.has_body(db) {
+        return None;
+    }
+    let name = func.name(db);
+    let mut res = DefaultOverrides::default();
+    for imp in hir::Impl::all_for_trait(db, trait_) {
+        let overriding = imp.items(db).into_iter().find_map(|it| match it {
+            hir::AssocItem::Function(it) if it.name(db) == name => Some(it),
+            _ => None,
+        });
+        match overriding {
+            Some(it) => res.overriding.push(it),
+            None => res.inheriting.push(imp),
+        }
+    }
+    Some(res)
+}
+
 /// Converts associated trait impl items to their trait definition counterpart
 pub(crate) fn convert_to_def_in_trait(db: &dyn HirDatabase, def: Definition) -> Definition {
     (|| {
@@ -115,7 +154,7 @@ fn assoc_item_of_trait(
 mod tests {
     use base_db::{fixture::ChangeFixture, FilePosition};
     use expect_test::{expect, Expect};
-    use hir::Semantics;
+    use hir::{AsAssocItem, HirDisplay, Semantics};
     use syntax::ast::{self, AstNode};
 
     use crate::RootDatabase;
@@ -145,6 +184,35 @@ fn check_trait(ra_fixture: &str, expect: Expect) {
         expect.assert_eq(&actual);
     }
 
+    fn check_default_overrides(ra_fixture: &str, expect: Expect) {
+        let (db, position) = position(ra_fixture);
+        let sema = Semantics::new(&db);
+        let file = sema.parse(position.file_id);
+        let func: ast::Fn =
+            sema.find_node_at_offset_with_descend(file.syntax(), position.offset).unwrap();
+        let func = sema.to_def(&func).unwrap();
+        let actual = match crate::traits::default_method_overrides(&db, func) {
+            Some(overrides) => {
+                let overriding = overrides.overriding.into_iter().map(|it| {
+                    let self_ty = match it.as_assoc_item(&db).unwrap().container(&db) {
+                        hir::AssocItemContainer::Impl(imp) => imp.self_ty(&db),
+                        hir::AssocItemContainer::Trait(_) => unreachable!(),
+                    };
+                    format!("overriding: {}", self_ty.display(&db))
+                });
+                let inheriting = overrides
+                    .inheriting
+                    .into_iter()
+                    .map(|it| format!("inheriting: {}", it.self_ty(&db).display(&db)));
+                let mut lines = overriding.chain(inheriting).collect::<Vec<_>>();
+                lines.sort();
+                lines.join("\n")
+            }
+            None => String::new(),
+        };
+        expect.assert_eq(&actual);
+    }
+
     fn check_missing_assoc(ra_fixture: &str, expect: Expect) {
         let (db, position) = position(ra_fixture);
         let sema = Semantics::new(&db);
@@ -272,4 +340,43 @@ impl Tr for () {
             expect![[r#""#]],
         );
     }
+
+    #[test]
+    fn default_method_overrides() {
+        check_default_overrides(
+            r#"
+trait Foo {
+    fn required();
+    fn provided$0() {}
+}
+struct A;
+struct B;
+impl Foo for A {
+    fn required() {}
+    fn provided() {}
+}
+impl Foo for B {
+    fn required() {}
+}
+impl Foo for u8 {
+    fn required() {}
+}
+"#,
+            expect![[r#"
+                inheriting: B
+                inheriting: u8
+                overriding: A"#]],
+        );
+        check_default_overrides(
+            r#"
+trait Foo {
+    fn required$0();
+}
+impl Foo for u8 {
+    fn required() {}
+}
+"#,
+            expect![[r#""#]],
+        );
+    }
 }
@@ -4,10 +4,16 @@
 
 use std::iter;
 
-use hir::Semantics;
-use syntax::ast::{self, make, Pat};
+use hir::{db::HirDatabase, HasAttrs, Semantics, SemanticsScope, StructKind};
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+use stdx::to_lower_snake_case;
+use syntax::{
+    ast::{self, make, Pat},
+    SyntaxKind,
+};
 
-use crate::RootDatabase;
+use crate::{defs::Definition, helpers::mod_path_to_ast, RootDatabase};
 
 /// Enum types that implement `std::ops::Try` trait.
 #[derive(Clone, Copy)]
@@ -84,3 +90,130 @@ fn type_name(self) -> &'static str {
         }
     }
 }
+
+/// The patterns suggested for matching the values of an enum, one per variant.
+#[derive(Debug, Clone)]
+pub struct EnumPatterns {
+    pub enum_: hir::Enum,
+    pub variants: Vec<VariantPattern>,
+    /// Whether a wildcard arm is needed after the ones of the variants, because the enum is
+    /// `#[non_exhaustive]` or has hidden variants, and is defined in another crate.
+    pub needs_wildcard: bool,
+}
+
+/// A pattern matching a variant, binding its fields.
+#[derive(Debug, Clone)]
+pub struct VariantPattern {
+    pub variant: hir::Variant,
+    /// The path of the variant in the scope, like `Some` or `Shape::Circle`.
+    pub path: ast::Path,
+    pub kind: StructKind,
+    /// The names the fields are bound to. The fields of record variants are bound by their names.
+    pub bindings: Vec<String>,
+}
+
+impl EnumPatterns {
+    /// Suggests the patterns for `ty`, if it's an enum, with the paths of the variants valid in
+    /// `scope`.
+    pub fn from_ty(
+        scope: &SemanticsScope<'_>,
+        ty: &hir::Type,
+        prefer_no_std: bool,
+    ) -> Option<EnumPatterns> {
+        let db = scope.db;
+        let enum_ = match ty.as_adt()? {
+            hir::Adt::Enum(it) => it,
+            _ => return None,
+        };
+        let is_foreign = enum_.module(db).krate() != scope.krate();
+        let mut needs_wildcard = is_foreign && enum_.attrs(db).by_key("non_exhaustive").exists();
+        let mut variants = Vec::new();
+        for variant in enum_.variants(db) {
+            if is_foreign && variant.attrs(db).has_doc_hidden() {
+                needs_wildcard = true;
+                continue;
+            }
+            let path = Definition::Variant(variant).path_in_scope(scope, prefer_no_std)?;
+            let kind = variant.kind(db);
+            let bindings = match kind {
+                StructKind::Record => {
+                    variant.fields(db).iter().map(|it| it.name(db).to_smol_str().into()).collect()
+                }
+                StructKind::Tuple => tuple_bindings(db, variant),
+                StructKind::Unit => Vec::new(),
+            };
+            variants.push(VariantPattern { variant, path: mod_path_to_ast(&path), kind, bindings });
+        }
+        Some(EnumPatterns { enum_, variants, needs_wildcard })
+    }
+
+    /// The patterns of all arms of an exhaustive `match`, including the wildcard if needed.
+    pub fn arm_patterns(&self) -> Vec<ast::Pat> {
+        let mut res: Vec<_> = self.variants.iter().map(|it| it.pattern()).collect();
+        if self.needs_wildcard {
+            res.push(make::wildcard_pat().into());
+        }
+        res
+    }
+}
+
+impl VariantPattern {
+    /// The pattern binding the fields of the variant.
+    pub fn pattern(&self) -> ast::Pat {
+        let pats =
+            self.bindings.iter().map(|it| make::ext::simple_ident_pat(make::name(it)).into());
+        self.with_field_pats(pats)
+    }
+
+    /// The pattern ignoring the fields of the variant.
+    pub fn wildcard_pattern(&self) -> ast::Pat {
+        match self.kind {
+            StructKind::Record => {
+                make::record_pat(self.path.clone(), iter::once(make::rest_pat().into())).into()
+            }
+            _ => self.with_field_pats(self.bindings.iter().map(|_| make::wildcard_pat().into())),
+        }
+    }
+
+    fn with_field_pats(&self, pats: impl Iterator<Item = ast::Pat>) -> ast::Pat {
+        match self.kind {
+            StructKind::Tuple => make::tuple_struct_pat(self.path.clone(), pats).into(),
+            StructKind::Record => make::record_pat(self.path.clone(), pats).into(),
+            StructKind::Unit => make::path_pat(self.path.clone()),
+        }
+    }
+}
+
+/// Names for the fields of a tuple variant: the name of the type of a field if it's an ADT,
+/// otherwise one fitting the variant, like `value` for `Some` and `err` for `Err`.
+fn tuple_bindings(db: &dyn HirDatabase, variant: hir::Variant) -> Vec<String> {
+    let variant_name = variant.name(db).to_smol_str();
+    let fallback = match variant_name.as_str() {
+        "Some" | "Ok" | "Ready" | "Break" | "Continue" => "value".to_owned(),
+        "Err" => "err".to_owned(),
+        name => to_lower_snake_case(name),
+    };
+    let fields = variant.fields(db);
+    let mut res: Vec<String> = fields
+        .iter()
+        .map(|field| match field.ty(db).as_adt() {
+            Some(adt) => to_lower_snake_case(&adt.name(db).to_smol_str()),
+            None => fallback.clone(),
+        })
+        .map(|name| match SyntaxKind::from_keyword(&name) {
+            Some(_) => format!("{name}_"),
+            None => name,
+        })
+        .collect();
+    // Fields with the same name get numbered.
+    let counts = res.iter().cloned().counts();
+    let mut numbers: FxHashMap<String, usize> = FxHashMap::default();
+    for name in &mut res {
+        if counts[name] > 1 {
+            let n = numbers.entry(name.clone()).or_default();
+            *n += 1;
+            *name = format!("{name}{n}");
+        }
+    }
+    res
+}
@@ -0,0 +1,247 @@
+//! Search-by-type: finds the expressions, locals and fields of a given type.
+//!
+//! Unlike [`crate::search`], there is no cheap text search that could narrow down
+//! the set of candidates here, so every file in the search scope gets parsed and
+//! the types of all its expressions are inferred. Callers should keep the search
+//! scope as small as possible.
+
+use base_db::FileId;
+use hir::{Adt, Semantics, Type};
+use nohash_hasher::IntMap;
+use syntax::{
+    ast::{self, HasName},
+    match_ast, AstNode, SyntaxNode, TextRange,
+};
+
+use crate::{defs::Definition, search::SearchScope, RootDatabase};
+
+/// What a [`TypeSearch`] is looking for.
+#[derive(Debug, Clone)]
+pub enum TypeSearchTarget {
+    /// Exactly this type.
+    Type(Type),
+    /// Any instantiation of this ADT, so `Vec` matches both `Vec<u32>` and `Vec<String>`.
+    Adt(Adt),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TypeMatchKind {
+    Expr,
+    Local,
+    Field,
+}
+
+#[derive(Debug, Clone)]
+pub struct TypeMatch {
+    /// The range of the expression, or the name of the local or field.
+    pub range: TextRange,
+    pub kind: TypeMatchKind,
+    /// The type of the node, this differs from the target when matching through references,
+    /// autoderef or when searching for an ADT.
+    pub ty: Type,
+}
+
+#[derive(Clone)]
+pub struct TypeSearch<'a> {
+    sema: &'a Semantics<'a, RootDatabase>,
+    target: TypeSearchTarget,
+    scope: Option<SearchScope>,
+    /// whether `&T` and `&mut T` match `T`
+    through_references: bool,
+    /// whether types that autoderef to the target match
+    autoderef: bool,
+}
+
+impl<'a> TypeSearch<'a> {
+    pub fn new(sema: &'a Semantics<'a, RootDatabase>, target: TypeSearchTarget) -> TypeSearch<'a> {
+        TypeSearch { sema, target, scope: None, through_references: false, autoderef: false }
+    }
+
+    /// Search for values of the type defined by `def`, if any.
+    pub fn for_definition(
+        sema: &'a Semantics<'a, RootDatabase>,
+        def: Definition,
+    ) -> Option<TypeSearch<'a>> {
+        let target = match def {
+            Definition::Adt(adt) => TypeSearchTarget::Adt(adt),
+            Definition::TypeAlias(it) => TypeSearchTarget::Type(it.ty(sema.db)),
+            Definition::BuiltinType(it) => TypeSearchTarget::Type(it.ty(sema.db)),
+            Definition::SelfType(it) => TypeSearchTarget::Type(it.self_ty(sema.db)),
+            _ => return None,
+        };
+        Some(TypeSearch::new(sema, target))
+    }
+
+    /// Limit the search to a given [`SearchScope`].
+    ///
+    /// By default, ADTs are searched for wherever they are visible, while other types are
+    /// searched for in the entire crate graph.
+    pub fn in_scope(mut self, scope: SearchScope) -> TypeSearch<'a> {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// Also match references to the target type, `&T` and `&mut T`.
+    pub fn through_references(mut self) -> TypeSearch<'a> {
+        self.through_references = true;
+        self
+    }
+
+    /// Also match types that autoderef to the target type, like `Box<T>` or `Rc<T>`.
+    pub fn with_autoderef(mut self) -> TypeSearch<'a> {
+        self.autoderef = true;
+        self
+    }
+
+    pub fn all(self) -> IntMap<FileId, Vec<TypeMatch>> {
+        let _p = profile::span("TypeSearch::all");
+        let mut res: IntMap<FileId, Vec<TypeMatch>> = IntMap::default();
+        let scope = match (&self.scope, &self.target) {
+            (Some(scope), _) => scope.clone(),
+            (None, TypeSearchTarget::Adt(adt)) => Definition::Adt(*adt).search_scope(self.sema.db),
+            (None, TypeSearchTarget::Type(_)) => SearchScope::crate_graph(self.sema.db),
+        };
+
+        for (file_id, range) in scope {
+            let tree = self.sema.parse(file_id);
+            let nodes = tree
+                .syntax()
+                .descendants()
+                .filter(|node| range.map_or(true, |range| range.contains_range(node.text_range())));
+            for node in nodes {
+                if let Some(hit) = self.match_node(&node) {
+                    res.entry(file_id).or_default().push(hit);
+                }
+            }
+        }
+        res
+    }
+
+    // FIXME: Nodes inside of macro calls are not looked at.
+    fn match_node(&self, node: &SyntaxNode) -> Option<TypeMatch> {
+        let sema = self.sema;
+        let (range, kind, ty) = match_ast! {
+            match node {
+                ast::Expr(it) => {
+                    // The inner expression is matched instead.
+                    if let ast::Expr::ParenExpr(_) = it {
+                        return None;
+                    }
+                    (it.syntax().text_range(), TypeMatchKind::Expr, sema.type_of_expr(&it)?.original)
+                },
+                ast::IdentPat(it) => {
+                    let ty = sema.type_of_binding_in_pat(&it)?;
+                    (it.name()?.syntax().text_range(), TypeMatchKind::Local, ty)
+                },
+                ast::SelfParam(it) => {
+                    let ty = sema.to_def(&it)?.ty(sema.db);
+                    (it.name()?.syntax().text_range(), TypeMatchKind::Local, ty)
+                },
+                ast::RecordField(it) => {
+                    let ty = sema.to_def(&it)?.ty(sema.db);
+                    (it.name()?.syntax().text_range(), TypeMatchKind::Field, ty)
+                },
+                ast::TupleField(it) => {
+                    let ty = sema.to_def(&it)?.ty(sema.db);
+                    (it.syntax().text_range(), TypeMatchKind::Field, ty)
+                },
+                _ => return None,
+            }
+        };
+        self.matches(&ty).then_some(TypeMatch { range, kind, ty })
+    }
+
+    fn matches(&self, ty: &Type) -> bool {
+        let is_target = |ty: &Type| match &self.target {
+            TypeSearchTarget::Type(target) => ty.is_same_type_as(target),
+            TypeSearchTarget::Adt(adt) => ty.as_adt() == Some(*adt),
+        };
+        if is_target(ty) {
+            return true;
+        }
+        if self.through_references && is_target(&ty.strip_references()) {
+            return true;
+        }
+        // autoderef also goes through references
+        self.autoderef && ty.autoderef(self.sema.db).any(|ty| is_target(&ty))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::{fixture::WithFixture, SourceDatabaseExt};
+    use expect_test::{expect, Expect};
+    use hir::Semantics;
+    use itertools::Itertools;
+
+    use super::*;
+
+    fn check(ra_fixture: &str, configure: fn(TypeSearch<'_>) -> TypeSearch<'_>, expect: Expect) {
+        let (db, position) = RootDatabase::with_position(ra_fixture);
+        let sema = Semantics::new(&db);
+        let file = sema.parse(position.file_id);
+        let adt: ast::Adt =
+            sema.find_node_at_offset_with_descend(file.syntax(), position.offset).unwrap();
+        let def = Definition::Adt(sema.to_def(&adt).unwrap());
+        let search = configure(TypeSearch::for_definition(&sema, def).unwrap());
+        let text = db.file_text(position.file_id);
+        let actual = search
+            .all()
+            .into_values()
+            .flatten()
+            .sorted_by_key(|it| it.range.start())
+            .map(|it| format!("{:?} {:?} {}\n", it.kind, it.range, &text[it.range]))
+            .collect::<String>();
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn finds_exprs_locals_and_fields() {
+        check(
+            r#"
+struct Foo$0;
+struct Bar { foo: Foo, r: &'static Foo }
+struct Wrapper(Foo);
+impl Foo {
+    fn by_value(self) {}
+    fn by_ref(&self) {}
+}
+fn f(a: Foo, b: &Foo) {
+    let c = (Foo);
+    let d = b;
+}
+"#,
+            |it| it,
+            expect![[r#"
+                Field 25..28 foo
+                Field 68..71 Foo
+                Local 101..105 self
+                Local 141..142 a
+                Local 168..169 c
+                Expr 173..176 Foo
+            "#]],
+        );
+    }
+
+    #[test]
+    fn through_references() {
+        check(
+            r#"
+struct Foo$0;
+impl Foo {
+    fn by_ref(&self) {}
+}
+fn f(b: &Foo) {
+    let d = b;
+}
+"#,
+            |it| it.through_references(),
+            expect![[r#"
+                Local 38..42 self
+                Local 54..55 b
+                Local 73..74 d
+                Expr 77..78 b
+            "#]],
+        );
+    }
+}
@@ -0,0 +1,203 @@
+//! Type hierarchies: the supertraits and subtraits of traits, the implementors of traits and the
+//! traits implemented by ADTs.
+//!
+//! Subtraits and impls can be declared in any crate depending on the crate of the trait or ADT,
+//! so they are looked up in an index of the impls and supertraits of each crate, which is only
+//! recomputed when the crate changes.
+
+use std::collections::VecDeque;
+
+use base_db::{salsa, CrateId, Upcast};
+use hir::{db::HirDatabase, Adt, Crate, Impl, ModuleDef, Trait};
+use triomphe::Arc;
+
+use crate::{FxHashMap, FxHashSet, RootDatabase};
+
+#[salsa::query_group(TypeHierarchyDatabaseStorage)]
+pub trait TypeHierarchyDatabase: HirDatabase + Upcast<dyn HirDatabase> {
+    /// The impls and trait declarations of the given crate, indexed for type hierarchies.
+    fn crate_impl_index(&self, krate: CrateId) -> Arc<ImplIndex>;
+}
+
+/// The impls of a crate by trait and by ADT, and the traits of a crate by supertrait.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ImplIndex {
+    by_trait: FxHashMap<Trait, Vec<Impl>>,
+    by_adt: FxHashMap<Adt, Vec<Impl>>,
+    subtraits: FxHashMap<Trait, Vec<Trait>>,
+}
+
+impl ImplIndex {
+    /// The impls of `trait_` in the crate.
+    pub fn impls_of_trait(&self, trait_: Trait) -> &[Impl] {
+        self.by_trait.get(&trait_).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// The impls, inherent or of a trait, whose self type is `adt` in the crate.
+    pub fn impls_of_adt(&self, adt: Adt) -> &[Impl] {
+        self.by_adt.get(&adt).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// The traits of the crate declaring `trait_` as one of their direct supertraits.
+    pub fn direct_subtraits(&self, trait_: Trait) -> &[Trait] {
+        self.subtraits.get(&trait_).map(Vec::as_slice).unwrap_or_default()
+    }
+}
+
+fn crate_impl_index(db: &dyn TypeHierarchyDatabase, krate: CrateId) -> Arc<ImplIndex> {
+    let _p = profile::span("crate_impl_index");
+    let db: &dyn HirDatabase = db.upcast();
+    let krate = Crate::from(krate);
+    let mut index = ImplIndex::default();
+    for impl_ in Impl::all_in_crate(db, krate) {
+        if let Some(trait_) = impl_.trait_(db) {
+            index.by_trait.entry(trait_).or_default().push(impl_);
+        }
+        if let Some(adt) = impl_.self_ty(db).as_adt() {
+            index.by_adt.entry(adt).or_default().push(impl_);
+        }
+    }
+    for module in krate.modules(db) {
+        for def in module.declarations(db) {
+            if let ModuleDef::Trait(trait_) = def {
+                for supertrait in trait_.direct_supertraits(db) {
+                    index.subtraits.entry(supertrait).or_default().push(trait_);
+                }
+            }
+        }
+    }
+    Arc::new(index)
+}
+
+/// The traits reachable from `root` by following either its supertraits or its subtraits, and the
+/// edges between them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraitGraph {
+    pub root: Trait,
+    /// The traits of the graph other than the root, closest first.
+    pub traits: Vec<Trait>,
+    /// The `(subtrait, supertrait)` pairs, each trait declaring the other as a direct supertrait.
+    pub edges: Vec<(Trait, Trait)>,
+}
+
+/// The supertraits of `trait_`, transitively.
+pub fn supertraits(db: &RootDatabase, trait_: Trait) -> TraitGraph {
+    trait_graph(trait_, |it| it.direct_supertraits(db), |sub, sup| (sub, sup))
+}
+
+/// The traits requiring `trait_`, transitively, in the crates depending on the crate of the trait.
+pub fn subtraits(db: &RootDatabase, trait_: Trait) -> TraitGraph {
+    let indices = crate_indices(db, trait_.module(db).krate());
+    let direct_subtraits = |it: Trait| {
+        indices.iter().flat_map(|index| index.direct_subtraits(it)).copied().collect::<Vec<_>>()
+    };
+    trait_graph(trait_, direct_subtraits, |sup, sub| (sub, sup))
+}
+
+/// The impls of `trait_`, whose self types are the types implementing it, ordered by crate.
+pub fn implementors(db: &RootDatabase, trait_: Trait) -> Vec<Impl> {
+    let indices = crate_indices(db, trait_.module(db).krate());
+    indices.iter().flat_map(|index| index.impls_of_trait(trait_)).copied().collect()
+}
+
+/// The traits implemented by `adt`, with the impls implementing them, ordered by crate.
+pub fn implemented_traits(db: &RootDatabase, adt: Adt) -> Vec<(Trait, Impl)> {
+    let indices = crate_indices(db, adt.module(db).krate());
+    indices
+        .iter()
+        .flat_map(|index| index.impls_of_adt(adt))
+        .filter_map(|&impl_| Some((impl_.trait_(db)?, impl_)))
+        .collect()
+}
+
+/// The indices of `krate` and of the crates depending on it, which are the only ones able to
+/// refer to its items.
+fn crate_indices(db: &RootDatabase, krate: Crate) -> Vec<Arc<ImplIndex>> {
+    let mut crates: Vec<CrateId> =
+        krate.transitive_reverse_dependencies(db).map(CrateId::from).collect();
+    crates.sort();
+    crates.into_iter().map(|krate| db.crate_impl_index(krate)).collect()
+}
+
+fn trait_graph(
+    root: Trait,
+    mut neighbors: impl FnMut(Trait) -> Vec<Trait>,
+    edge: impl Fn(Trait, Trait) -> (Trait, Trait),
+) -> TraitGraph {
+    let mut graph = TraitGraph { root, traits: Vec::new(), edges: Vec::new() };
+    // Trait hierarchies may have cycles, which are errors but shouldn't hang the search.
+    let mut visited = FxHashSet::from_iter([root]);
+    let mut queue = VecDeque::from([root]);
+    while let Some(trait_) = queue.pop_front() {
+        for neighbor in neighbors(trait_) {
+            graph.edges.push(edge(trait_, neighbor));
+            if visited.insert(neighbor) {
+                graph.traits.push(neighbor);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    graph
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::fixture::WithFixture;
+
+    use super::*;
+
+    fn module_def(db: &RootDatabase, name: &str) -> ModuleDef {
+        let krate = Crate::from(db.test_crate());
+        krate
+            .modules(db)
+            .into_iter()
+            .flat_map(|module| module.declarations(db))
+            .find(|it| it.name(db).map_or(false, |it| it.to_smol_str() == name))
+            .unwrap()
+    }
+
+    fn trait_(db: &RootDatabase, name: &str) -> Trait {
+        match module_def(db, name) {
+            ModuleDef::Trait(it) => it,
+            _ => panic!("`{name}` is not a trait"),
+        }
+    }
+
+    #[test]
+    fn trait_hierarchy() {
+        let db = RootDatabase::with_files(
+            r#"
+trait Base {}
+trait Left: Base {}
+trait Right: Base {}
+trait Both: Left + Right {}
+struct S;
+impl Base for S {}
+impl Left for S {}
+impl S {}
+"#,
+        );
+        let base = trait_(&db, "Base");
+        let left = trait_(&db, "Left");
+        let right = trait_(&db, "Right");
+        let both = trait_(&db, "Both");
+
+        let graph = supertraits(&db, both);
+        assert_eq!(graph.traits, [left, right, base]);
+        assert_eq!(graph.edges, [(both, left), (both, right), (left, base), (right, base)]);
+
+        let graph = subtraits(&db, base);
+        assert_eq!(graph.traits, [left, right, both]);
+        assert_eq!(graph.edges, [(left, base), (right, base), (both, left), (both, right)]);
+
+        let s = match module_def(&db, "S") {
+            ModuleDef::Adt(it) => it,
+            _ => unreachable!(),
+        };
+        assert_eq!(implementors(&db, base).len(), 1);
+        // The impls of a crate aren't ordered.
+        let traits: FxHashSet<_> =
+            implemented_traits(&db, s).into_iter().map(|(it, _)| it).collect();
+        assert_eq!(traits, FxHashSet::from_iter([base, left]));
+    }
+}
@@ -0,0 +1,433 @@
+//! An index of the unsafe code of the workspace, for safety audits.
+//!
+//! A site of unsafe code is an `unsafe` block, function, impl or trait. The sites of blocks and
+//! functions come with the operations they allow, like dereferencing raw pointers or calling
+//! foreign functions, including the ones in macro expansions. Operations in a block belong to the
+//! innermost block, and operations directly in the body of an unsafe function to the function.
+//!
+//! The sites of each definition are collected by a query, so only the definitions of edited
+//! files are visited again.
+
+use std::collections::VecDeque;
+
+use base_db::{salsa, CrateId, FileRange, SourceDatabase, Upcast};
+use hir::{
+    db::HirDatabase, AssocItem, Crate, Function, HasContainer, ItemContainer, ModuleDef,
+    PathResolution, Semantics, VariantDef,
+};
+use syntax::{ast, match_ast, AstNode, SyntaxKind, SyntaxNode, WalkEvent};
+use triomphe::Arc;
+
+use crate::{
+    call_index::CallIndexDatabase, defs::Definition, parallel::par_map, FxHashSet, RootDatabase,
+};
+
+#[salsa::query_group(UnsafeIndexDatabaseStorage)]
+pub trait UnsafeIndexDatabase: HirDatabase + Upcast<RootDatabase> {
+    /// The sites of unsafe code of the given definition: the definition itself if it is an
+    /// unsafe function, impl or trait, then the unsafe blocks in its body, in the order they
+    /// appear in. Nested items are definitions of their own.
+    fn unsafe_sites(&self, def: Definition) -> Arc<[UnsafeSite]>;
+
+    /// The sites of unsafe code of the definitions of the given crate.
+    fn crate_unsafe_sites(&self, krate: CrateId) -> Arc<[UnsafeSite]>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnsafeSiteKind {
+    Block,
+    Function,
+    Impl,
+    Trait,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnsafeOperationKind {
+    /// Dereferencing a raw pointer.
+    RawPointerDeref,
+    /// Accessing a field of a union.
+    UnionField,
+    /// Calling a function declared in an `extern` block.
+    ExternCall,
+    /// Calling any other unsafe function.
+    UnsafeCall,
+    /// Accessing a mutable static.
+    MutableStatic,
+    /// Accessing a static declared in an `extern` block.
+    ExternStatic,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnsafeOperation {
+    pub kind: UnsafeOperationKind,
+    /// The range of the expression in the original file.
+    pub range: FileRange,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnsafeSite {
+    pub kind: UnsafeSiteKind,
+    /// The definition the block is in, or the unsafe definition.
+    pub definition: Definition,
+    /// The range of the block or of the definition in the original file.
+    pub range: FileRange,
+    /// The operations allowed by the site. Always empty for impls and traits.
+    pub operations: Vec<UnsafeOperation>,
+}
+
+/// The number of sites of unsafe code in a crate, by kind.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UnsafeCount {
+    pub blocks: usize,
+    pub functions: usize,
+    pub impls: usize,
+    pub traits: usize,
+    /// The number of unsafe operations in all the sites.
+    pub operations: usize,
+}
+
+fn unsafe_sites(db: &dyn UnsafeIndexDatabase, def: Definition) -> Arc<[UnsafeSite]> {
+    let _p = profile::span("unsafe_sites");
+    let db: &RootDatabase = db.upcast();
+    let sema = Semantics::new(db);
+    let mut res = Vec::new();
+    let item_site = |kind, node: &SyntaxNode| UnsafeSite {
+        kind,
+        definition: def,
+        range: sema.original_range(node),
+        operations: Vec::new(),
+    };
+    let body = match def {
+        Definition::Function(it) => {
+            let Some(source) = sema.source(it) else { return Arc::from(res) };
+            // Functions of `extern` blocks are unsafe to call, but have no unsafe code.
+            if source.value.unsafe_token().is_some() {
+                res.push(item_site(UnsafeSiteKind::Function, source.value.syntax()));
+            }
+            source.value.body().map(|it| it.syntax().clone())
+        }
+        Definition::Const(it) => {
+            sema.source(it).and_then(|it| it.value.body()).map(|it| it.syntax().clone())
+        }
+        Definition::Static(it) => {
+            sema.source(it).and_then(|it| it.value.body()).map(|it| it.syntax().clone())
+        }
+        Definition::SelfType(it) => {
+            if let Some(source) = sema.source(it).filter(|it| it.value.unsafe_token().is_some()) {
+                res.push(item_site(UnsafeSiteKind::Impl, source.value.syntax()));
+            }
+            None
+        }
+        Definition::Trait(it) => {
+            if let Some(source) = sema.source(it).filter(|it| it.value.unsafe_token().is_some()) {
+                res.push(item_site(UnsafeSiteKind::Trait, source.value.syntax()));
+            }
+            None
+        }
+        _ => None,
+    };
+    if let Some(body) = body {
+        let site = if res.is_empty() { None } else { Some(0) };
+        collect_body_sites(&sema, def, &body, site, &mut res);
+    }
+    res.into()
+}
+
+/// Collects the unsafe blocks of `root`, including `root` itself, and the operations of the ones
+/// in `site`, the index of the innermost site `root` is in.
+fn collect_body_sites(
+    sema: &Semantics<'_, RootDatabase>,
+    def: Definition,
+    root: &SyntaxNode,
+    site: Option<usize>,
+    sites: &mut Vec<UnsafeSite>,
+) {
+    let mut preorder = root.preorder();
+    while let Some(event) = preorder.next() {
+        let WalkEvent::Enter(node) = event else { continue };
+        // Nested items are not part of the body, except for macro calls in statement position
+        // which are also items.
+        if ast::Item::can_cast(node.kind()) && node.kind() != SyntaxKind::MACRO_CALL {
+            preorder.skip_subtree();
+            continue;
+        }
+        if let Some(block) = ast::BlockExpr::cast(node.clone()) {
+            if block.unsafe_token().is_some() {
+                preorder.skip_subtree();
+                sites.push(UnsafeSite {
+                    kind: UnsafeSiteKind::Block,
+                    definition: def,
+                    range: sema.original_range(&node),
+                    operations: Vec::new(),
+                });
+                let block_site = sites.len() - 1;
+                for child in node.children() {
+                    collect_body_sites(sema, def, &child, Some(block_site), sites);
+                }
+                continue;
+            }
+        }
+        if let Some(macro_call) = ast::MacroCall::cast(node.clone()) {
+            preorder.skip_subtree();
+            if let Some(expansion) = sema.expand(&macro_call) {
+                collect_body_sites(sema, def, &expansion, site, sites);
+            }
+            continue;
+        }
+        let Some(site) = site else { continue };
+        if let Some(kind) = unsafe_operation(sema, &node) {
+            let range = sema.original_range(&node);
+            sites[site].operations.push(UnsafeOperation { kind, range });
+        }
+    }
+}
+
+fn unsafe_operation(
+    sema: &Semantics<'_, RootDatabase>,
+    node: &SyntaxNode,
+) -> Option<UnsafeOperationKind> {
+    let db = sema.db;
+    match_ast! {
+        match node {
+            ast::PrefixExpr(it) => {
+                if it.op_kind()? != ast::UnaryOp::Deref {
+                    return None;
+                }
+                let ty = sema.type_of_expr(&it.expr()?)?.original;
+                ty.is_raw_ptr().then_some(UnsafeOperationKind::RawPointerDeref)
+            },
+            ast::FieldExpr(it) => {
+                let field = sema.resolve_field(&it)?;
+                matches!(field.parent_def(db), VariantDef::Union(_))
+                    .then_some(UnsafeOperationKind::UnionField)
+            },
+            ast::CallExpr(it) => {
+                let callable = sema.type_of_expr(&it.expr()?)?.original.as_callable(db)?;
+                let hir::CallableKind::Function(func) = callable.kind() else { return None };
+                unsafe_call(db, func)
+            },
+            ast::MethodCallExpr(it) => unsafe_call(db, sema.resolve_method_call(&it)?),
+            ast::PathExpr(it) => {
+                let Some(PathResolution::Def(ModuleDef::Static(static_))) =
+                    sema.resolve_path(&it.path()?)
+                else {
+                    return None;
+                };
+                if static_.container(db) == ItemContainer::ExternBlock() {
+                    Some(UnsafeOperationKind::ExternStatic)
+                } else {
+                    static_.is_mut(db).then_some(UnsafeOperationKind::MutableStatic)
+                }
+            },
+            _ => None,
+        }
+    }
+}
+
+fn unsafe_call(db: &RootDatabase, func: Function) -> Option<UnsafeOperationKind> {
+    if !func.is_unsafe_to_call(db) {
+        return None;
+    }
+    if func.container(db) == ItemContainer::ExternBlock() {
+        Some(UnsafeOperationKind::ExternCall)
+    } else {
+        Some(UnsafeOperationKind::UnsafeCall)
+    }
+}
+
+fn crate_unsafe_sites(db: &dyn UnsafeIndexDatabase, krate: CrateId) -> Arc<[UnsafeSite]> {
+    let _p = profile::span("crate_unsafe_sites");
+    let root_db: &RootDatabase = db.upcast();
+    let mut res = Vec::new();
+    for def in crate_definitions(root_db, Crate::from(krate)) {
+        res.extend(db.unsafe_sites(def).iter().cloned());
+    }
+    res.into()
+}
+
+/// The definitions of `krate` that can be or contain unsafe code.
+fn crate_definitions(db: &RootDatabase, krate: Crate) -> Vec<Definition> {
+    let assoc_item = |item: AssocItem| match item {
+        AssocItem::Function(it) => Some(Definition::Function(it)),
+        AssocItem::Const(it) => Some(Definition::Const(it)),
+        AssocItem::TypeAlias(_) => None,
+    };
+    let mut res = Vec::new();
+    for module in krate.modules(db) {
+        for def in module.declarations(db) {
+            match def {
+                ModuleDef::Function(it) => res.push(Definition::Function(it)),
+                ModuleDef::Const(it) => res.push(Definition::Const(it)),
+                ModuleDef::Static(it) => res.push(Definition::Static(it)),
+                ModuleDef::Trait(it) => {
+                    res.push(Definition::Trait(it));
+                    res.extend(it.items(db).into_iter().filter_map(assoc_item));
+                }
+                _ => (),
+            }
+        }
+        for impl_ in module.impl_defs(db) {
+            res.push(Definition::SelfType(impl_));
+            res.extend(impl_.items(db).into_iter().filter_map(assoc_item));
+        }
+    }
+    res
+}
+
+/// The sites of unsafe code of `func` and of the workspace functions it calls, directly or not.
+/// Every function is visited once, in the order of a breadth-first search of the calls.
+pub fn reachable_unsafe(db: &RootDatabase, func: Function) -> Vec<UnsafeSite> {
+    let _p = profile::span("reachable_unsafe");
+    let mut res = Vec::new();
+    let mut visited = FxHashSet::default();
+    let mut queue = VecDeque::from([func]);
+    visited.insert(func);
+    while let Some(func) = queue.pop_front() {
+        res.extend(db.unsafe_sites(Definition::Function(func)).iter().cloned());
+        for call in db.outgoing_calls(func).iter() {
+            let callee = call.callee;
+            if callee.module(db).krate().origin(db).is_local() && visited.insert(callee) {
+                queue.push_back(callee);
+            }
+        }
+    }
+    res
+}
+
+impl UnsafeCount {
+    fn of(sites: &[UnsafeSite]) -> UnsafeCount {
+        let mut res = UnsafeCount::default();
+        for site in sites {
+            match site.kind {
+                UnsafeSiteKind::Block => res.blocks += 1,
+                UnsafeSiteKind::Function => res.functions += 1,
+                UnsafeSiteKind::Impl => res.impls += 1,
+                UnsafeSiteKind::Trait => res.traits += 1,
+            }
+            res.operations += site.operations.len();
+        }
+        res
+    }
+}
+
+/// The number of sites of unsafe code of each workspace crate.
+pub fn unsafe_count_per_crate(db: &RootDatabase) -> Vec<(Crate, UnsafeCount)> {
+    let _p = profile::span("unsafe_count_per_crate");
+    let crate_graph = db.crate_graph();
+    let crates: Vec<Crate> = Crate::all(db)
+        .into_iter()
+        .filter(|krate| crate_graph[(*krate).into()].origin.is_local())
+        .collect();
+    par_map(db, crates, |db, krate| (krate, UnsafeCount::of(&db.crate_unsafe_sites(krate.into()))))
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::{fixture::WithFixture, SourceDatabaseExt};
+    use expect_test::{expect, Expect};
+    use stdx::format_to;
+
+    use super::*;
+
+    fn describe(db: &RootDatabase, sites: &[UnsafeSite]) -> String {
+        let mut res = String::new();
+        for site in sites {
+            let name = site.definition.name(db).map(|it| it.display(db).to_string());
+            format_to!(res, "{:?} in {}\n", site.kind, name.as_deref().unwrap_or("impl"));
+            for operation in &site.operations {
+                let text = db.file_text(operation.range.file_id);
+                format_to!(res, "  {:?} `{}`\n", operation.kind, &text[operation.range.range]);
+            }
+        }
+        res
+    }
+
+    fn check_reachable(ra_fixture: &str, expect: Expect) {
+        let (db, file_id) = RootDatabase::with_single_file(ra_fixture);
+        let sema = Semantics::new(&db);
+        let krate = sema.to_module_def(file_id).unwrap().krate();
+        let main = crate_definitions(&db, krate)
+            .into_iter()
+            .find_map(|def| match def {
+                Definition::Function(it) if it.name(&db).to_smol_str() == "main" => Some(it),
+                _ => None,
+            })
+            .unwrap();
+        expect.assert_eq(&describe(&db, &reachable_unsafe(&db, main)));
+    }
+
+    #[test]
+    fn finds_reachable_unsafe_operations() {
+        check_reachable(
+            r#"
+macro_rules! deref { ($e:expr) => { *$e }; }
+extern "C" { fn abort(); static errno: i32; }
+union U { a: u32, b: f32 }
+static mut COUNTER: u32 = 0;
+unsafe fn raw(p: *const u32) -> u32 {
+    let v = *p;
+    unsafe { deref!(p) + v }
+}
+fn helper(u: U) -> u32 {
+    unsafe {
+        COUNTER += 1;
+        u.a + errno as u32
+    }
+}
+fn unrelated() { unsafe { abort() } }
+fn main() {
+    let x = 0u32;
+    helper(U { a: 1 });
+    unsafe {
+        raw(&x);
+        abort();
+    }
+}
+"#,
+            expect![[r#"
+                Block in main
+                  UnsafeCall `raw(&x)`
+                  ExternCall `abort()`
+                Block in helper
+                  MutableStatic `COUNTER`
+                  UnionField `u.a`
+                  ExternStatic `errno`
+                Function in raw
+                  RawPointerDeref `*p`
+                Block in raw
+                  RawPointerDeref `deref!(p)`
+            "#]],
+        );
+    }
+
+    #[test]
+    fn counts_unsafe_per_crate() {
+        let db = RootDatabase::with_files(
+            r#"
+//- /lib.rs crate:lib
+pub unsafe trait Tr {}
+unsafe impl Tr for () {}
+pub unsafe fn f(p: *const u8) -> u8 { unsafe { *p } }
+//- /main.rs crate:main deps:lib
+fn main() { unsafe { lib::f(&0) }; }
+"#,
+        );
+        let counts: Vec<_> = unsafe_count_per_crate(&db)
+            .into_iter()
+            .map(|(krate, count)| (krate.display_name(&db).unwrap().to_string(), count))
+            .collect();
+        assert_eq!(
+            counts,
+            [
+                (
+                    "lib".to_owned(),
+                    UnsafeCount { blocks: 1, functions: 1, impls: 1, traits: 1, operations: 1 }
+                ),
+                (
+                    "main".to_owned(),
+                    UnsafeCount { blocks: 1, functions: 0, impls: 0, traits: 0, operations: 1 }
+                ),
+            ]
+        );
+    }
+}
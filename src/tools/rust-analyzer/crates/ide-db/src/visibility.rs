@@ -0,0 +1,527 @@
+//! Computes the visibility changes that make a definition usable from a module.
+//!
+//! A definition is usable from a module if the module can name it through some path. When it
+//! can't, the item itself and the modules on the path from the closest module containing both
+//! down to the item may need to be made more visible. The visibilities given are the narrowest
+//! ones that work: `pub(super)`, `pub(crate)` or `pub(in path)` within a crate and `pub` from
+//! other crates.
+
+use base_db::FileId;
+use hir::{
+    AsAssocItem, AssocItemContainer, HasSource, HasVisibility, InFile, ItemInNs, Module, ModuleDef,
+    ModuleSource, Name,
+};
+use itertools::Itertools;
+use syntax::{ast, AstNode, SyntaxKind, SyntaxNode, TextRange, TextSize};
+use text_edit::TextEdit;
+
+use crate::{defs::Definition, source_change::SourceChange, RootDatabase};
+
+/// Whether, and how, a definition can be made usable from a module.
+#[derive(Debug, Clone)]
+pub enum Exposure {
+    /// The definition is usable already.
+    Visible,
+    /// The definition becomes usable once these visibilities are changed.
+    Edits(Vec<VisibilityEdit>),
+    /// No change of visibility makes the definition usable, e.g. because it's defined in a block,
+    /// a macro expansion or a crate the module's crate doesn't depend on.
+    Impossible,
+}
+
+/// A change of the visibility of an item, a module or a field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VisibilityEdit {
+    pub file_id: FileId,
+    /// The range of the current visibility, or an empty range where the visibility is inserted.
+    pub range: TextRange,
+    /// The range of the item, module or field the visibility belongs to.
+    pub target: TextRange,
+    pub name: Option<Name>,
+    /// The new visibility, like `pub(crate)`.
+    pub visibility: String,
+}
+
+impl VisibilityEdit {
+    /// The text replacing [`VisibilityEdit::range`].
+    pub fn text(&self) -> String {
+        if self.range.is_empty() {
+            format!("{} ", self.visibility)
+        } else {
+            self.visibility.clone()
+        }
+    }
+}
+
+impl Exposure {
+    /// The change making the definition usable, `None` if it's already usable or can't be made
+    /// usable.
+    pub fn source_change(&self) -> Option<SourceChange> {
+        let Exposure::Edits(edits) = self else { return None };
+        let edits = edits.iter().into_group_map_by(|it| it.file_id);
+        Some(
+            edits
+                .into_iter()
+                .map(|(file_id, edits)| {
+                    let mut builder = TextEdit::builder();
+                    for edit in edits.into_iter().sorted_by_key(|it| it.range.start()) {
+                        builder.replace(edit.range, edit.text());
+                    }
+                    (file_id, builder.finish())
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Computes the visibility changes that make `def` usable from `module`.
+///
+/// Associated items of traits are usable if their trait is, and the ones of inherent impls only
+/// need to be visible themselves, as the type may be reachable through other means than a path.
+pub fn expose_to(db: &RootDatabase, def: Definition, module: Module) -> Exposure {
+    let _p = profile::span("expose_to");
+    match def {
+        Definition::Field(field) => {
+            let holder = Holder::Field(field);
+            match field.parent_def(db) {
+                // Fields of enum variants are as visible as the enum.
+                hir::VariantDef::Variant(variant) => {
+                    expose_to(db, Definition::Adt(variant.parent_enum(db).into()), module)
+                }
+                parent => expose_holders(db, vec![(holder, parent.module(db))], module),
+            }
+        }
+        Definition::Variant(variant) => {
+            expose_to(db, Definition::Adt(variant.parent_enum(db).into()), module)
+        }
+        Definition::Module(it) => expose_module_def(db, it.into(), module),
+        Definition::Trait(it) => expose_module_def(db, it.into(), module),
+        Definition::TraitAlias(it) => expose_module_def(db, it.into(), module),
+        Definition::Adt(it) => expose_module_def(db, it.into(), module),
+        Definition::Function(it) => expose_assoc_or_module_def(db, it.into(), module),
+        Definition::Const(it) => expose_assoc_or_module_def(db, it.into(), module),
+        Definition::Static(it) => expose_assoc_or_module_def(db, it.into(), module),
+        Definition::TypeAlias(it) => expose_assoc_or_module_def(db, it.into(), module),
+        Definition::BuiltinType(_) => Exposure::Visible,
+        // Macros are exported by attributes rather than visibilities, the other definitions are
+        // local to a body or can't be made visible.
+        _ => match def.visibility(db) {
+            Some(vis) if !vis.is_visible_from(db, module.into()) => Exposure::Impossible,
+            _ => Exposure::Visible,
+        },
+    }
+}
+
+/// Something with a visibility that may need to change.
+#[derive(Debug, Clone, Copy)]
+enum Holder {
+    Def(ModuleDef),
+    Field(hir::Field),
+}
+
+fn expose_assoc_or_module_def(db: &RootDatabase, def: ModuleDef, module: Module) -> Exposure {
+    match def.as_assoc_item(db).map(|it| it.container(db)) {
+        Some(AssocItemContainer::Trait(trait_)) => expose_module_def(db, trait_.into(), module),
+        Some(AssocItemContainer::Impl(impl_)) => match impl_.trait_(db) {
+            // Items of trait impls are as visible as the trait.
+            Some(trait_) => expose_module_def(db, trait_.into(), module),
+            None => expose_holders(db, vec![(Holder::Def(def), impl_.module(db))], module),
+        },
+        None => expose_module_def(db, def, module),
+    }
+}
+
+fn expose_module_def(db: &RootDatabase, def: ModuleDef, module: Module) -> Exposure {
+    if module.find_use_path(db, ItemInNs::from(def), false).is_some() {
+        return Exposure::Visible;
+    }
+    let def_module = match def {
+        // A module's visibility is relative to the one declaring it.
+        ModuleDef::Module(it) => match it.parent(db) {
+            Some(parent) => parent,
+            None => return Exposure::Visible,
+        },
+        _ => match def.module(db) {
+            Some(it) => it,
+            None => return Exposure::Impossible,
+        },
+    };
+    // The modules of the path to the item, each with the module declaring it.
+    let mut holders = vec![(Holder::Def(def), def_module)];
+    holders.extend(
+        def_module
+            .path_to_root(db)
+            .into_iter()
+            .tuple_windows()
+            .map(|(child, parent)| (Holder::Def(ModuleDef::Module(child)), parent)),
+    );
+    expose_holders(db, holders, module)
+}
+
+/// Computes the edits making all of `holders`, which are given with the modules declaring them,
+/// visible from `module`.
+fn expose_holders(db: &RootDatabase, holders: Vec<(Holder, Module)>, module: Module) -> Exposure {
+    let Some(&(_, def_module)) = holders.first() else { return Exposure::Visible };
+    let def_crate_root = def_module.krate().root_module(db);
+    // Items in blocks can only be named from within the block.
+    if let Some(&block) = def_module.path_to_root(db).last().filter(|&&it| it != def_crate_root) {
+        let visible = is_in_block(db, module, block)
+            && holders.iter().all(|&(holder, _)| is_visible(db, holder, module));
+        return match visible {
+            true => Exposure::Visible,
+            false => Exposure::Impossible,
+        };
+    }
+
+    let scope = if module.krate() == def_module.krate() {
+        let ancestors = module.nearest_non_block_module(db).path_to_root(db);
+        match def_module.path_to_root(db).into_iter().find(|it| ancestors.contains(it)) {
+            Some(it) => Some(it),
+            None => return Exposure::Impossible,
+        }
+    } else {
+        let is_dependency =
+            module.krate().dependencies(db).iter().any(|dep| dep.krate == def_module.krate());
+        if !is_dependency {
+            return Exposure::Impossible;
+        }
+        None
+    };
+
+    let mut edits = Vec::new();
+    for (holder, declaring_module) in holders {
+        if is_visible(db, holder, module) {
+            continue;
+        }
+        let visibility = match scope {
+            Some(scope) => visibility_for(db, declaring_module, scope),
+            None => "pub".to_owned(),
+        };
+        match visibility_edit(db, holder, visibility) {
+            Some(edit) => edits.push(edit),
+            None => return Exposure::Impossible,
+        }
+    }
+    match edits.is_empty() {
+        true => Exposure::Visible,
+        false => Exposure::Edits(edits),
+    }
+}
+
+/// Whether `module` is the module of `block`, a block expression, or is declared in it. The
+/// visibilities of items in blocks are the ones of the containing module, so they don't tell.
+fn is_in_block(db: &RootDatabase, module: Module, block: Module) -> bool {
+    let range = |module: Module| {
+        let InFile { file_id, value } = module.definition_source(db);
+        let range = match value {
+            ModuleSource::SourceFile(it) => it.syntax().text_range(),
+            ModuleSource::Module(it) => it.syntax().text_range(),
+            ModuleSource::BlockExpr(it) => it.syntax().text_range(),
+        };
+        (file_id, range)
+    };
+    let (block_file, block_range) = range(block);
+    let (file_id, range) = range(module);
+    file_id == block_file && block_range.contains_range(range)
+}
+
+fn is_visible(db: &RootDatabase, holder: Holder, module: Module) -> bool {
+    let vis = match holder {
+        Holder::Def(def) => def.visibility(db),
+        Holder::Field(field) => field.visibility(db),
+    };
+    vis.is_visible_from(db, module.into())
+}
+
+/// The narrowest visibility of an item declared in `declaring_module` that makes it visible
+/// everywhere in `scope`, an ancestor of the declaring module.
+fn visibility_for(db: &RootDatabase, declaring_module: Module, scope: Module) -> String {
+    if scope.is_crate_root() {
+        "pub(crate)".to_owned()
+    } else if declaring_module.parent(db) == Some(scope) {
+        "pub(super)".to_owned()
+    } else {
+        let path = scope
+            .path_to_root(db)
+            .into_iter()
+            .rev()
+            .filter_map(|it| it.name(db))
+            .map(|name| name.display(db).to_string())
+            .join("::");
+        format!("pub(in crate::{path})")
+    }
+}
+
+fn visibility_edit(
+    db: &RootDatabase,
+    holder: Holder,
+    visibility: String,
+) -> Option<VisibilityEdit> {
+    fn source<N: AstNode + ast::HasVisibility>(
+        src: Option<InFile<N>>,
+    ) -> Option<(FileId, SyntaxNode, Option<ast::Visibility>)> {
+        let src = src?;
+        // Items from macro expansions can't be edited.
+        let file_id = src.file_id.file_id()?;
+        Some((file_id, src.value.syntax().clone(), src.value.visibility()))
+    }
+
+    let (name, src) = match holder {
+        Holder::Field(field) => {
+            let src = field.source(db)?;
+            let src = match src.value {
+                hir::FieldSource::Named(it) => source(Some(InFile::new(src.file_id, it))),
+                hir::FieldSource::Pos(it) => source(Some(InFile::new(src.file_id, it))),
+            };
+            (Some(field.name(db)), src)
+        }
+        Holder::Def(def) => {
+            let src = match def {
+                ModuleDef::Module(it) => source(it.declaration_source(db)),
+                ModuleDef::Function(it) => source(it.source(db)),
+                ModuleDef::Adt(hir::Adt::Struct(it)) => source(it.source(db)),
+                ModuleDef::Adt(hir::Adt::Union(it)) => source(it.source(db)),
+                ModuleDef::Adt(hir::Adt::Enum(it)) => source(it.source(db)),
+                ModuleDef::Const(it) => source(it.source(db)),
+                ModuleDef::Static(it) => source(it.source(db)),
+                ModuleDef::Trait(it) => source(it.source(db)),
+                ModuleDef::TraitAlias(it) => source(it.source(db)),
+                ModuleDef::TypeAlias(it) => source(it.source(db)),
+                ModuleDef::Variant(_) | ModuleDef::Macro(_) | ModuleDef::BuiltinType(_) => None,
+            };
+            (def.name(db), src)
+        }
+    };
+    let (file_id, syntax, current) = src?;
+    let range = match current {
+        Some(current) => current.syntax().text_range(),
+        None => TextRange::empty(vis_offset(&syntax)),
+    };
+    Some(VisibilityEdit { file_id, range, target: syntax.text_range(), name, visibility })
+}
+
+fn vis_offset(node: &SyntaxNode) -> TextSize {
+    node.children_with_tokens()
+        .find(|it| {
+            !matches!(it.kind(), SyntaxKind::WHITESPACE | SyntaxKind::COMMENT | SyntaxKind::ATTR)
+        })
+        .map_or_else(|| node.text_range().start(), |it| it.text_range().start())
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::{fixture::WithFixture, SourceDatabaseExt};
+    use expect_test::{expect, Expect};
+    use hir::Semantics;
+    use stdx::format_to;
+
+    use super::*;
+    use crate::defs::NameClass;
+
+    /// Checks the changes making the definition at `$0` usable from the module `target`.
+    fn check(ra_fixture: &str, expect: Expect) {
+        let (db, position) = RootDatabase::with_position(ra_fixture);
+        let sema = Semantics::new(&db);
+        let file = sema.parse(position.file_id);
+        let name: ast::Name =
+            sema.find_node_at_offset_with_descend(file.syntax(), position.offset).unwrap();
+        let def = NameClass::classify(&sema, &name).unwrap().defined().unwrap();
+        let target = hir::Crate::all(&db)
+            .into_iter()
+            .flat_map(|krate| krate.modules(&db))
+            .find(|it| it.name(&db).map_or(false, |name| name.to_smol_str() == "target"))
+            .unwrap();
+
+        let actual = match expose_to(&db, def, target) {
+            Exposure::Visible => "visible\n".to_owned(),
+            Exposure::Impossible => "impossible\n".to_owned(),
+            exposure => {
+                let applied = exposure.source_change().unwrap().apply(&db);
+                let mut res = String::new();
+                for (file_id, text) in applied.file_texts.into_iter().sorted_by_key(|it| it.0) {
+                    let source_root = db.source_root(db.file_source_root(file_id));
+                    let path = source_root.path_for_file(&file_id).unwrap();
+                    format_to!(res, "{path}\n{text}");
+                }
+                res
+            }
+        };
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn already_visible() {
+        check(
+            r#"
+mod a {
+    pub fn f$0() {}
+}
+mod target {}
+"#,
+            expect![[r#"
+                visible
+            "#]],
+        );
+    }
+
+    #[test]
+    fn item_and_modules() {
+        check(
+            r#"
+mod a {
+    mod b {
+        fn f$0() {}
+    }
+    mod target {}
+}
+"#,
+            expect![[r#"
+                /main.rs
+                mod a {
+                    mod b {
+                        pub(super) fn f() {}
+                    }
+                    mod target {}
+                }
+            "#]],
+        );
+        check(
+            r#"
+mod a {
+    mod b {
+        /// Doc.
+        pub(self) mod c {
+            fn f$0() {}
+        }
+    }
+}
+mod target {}
+"#,
+            expect![[r#"
+                /main.rs
+                mod a {
+                    pub(crate) mod b {
+                        /// Doc.
+                        pub(crate) mod c {
+                            pub(crate) fn f() {}
+                        }
+                    }
+                }
+                mod target {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn in_path_visibility() {
+        check(
+            r#"
+mod a {
+    mod b {
+        mod c {
+            fn f$0() {}
+        }
+    }
+    mod target {}
+}
+"#,
+            expect![[r#"
+                /main.rs
+                mod a {
+                    mod b {
+                        pub(super) mod c {
+                            pub(in crate::a) fn f() {}
+                        }
+                    }
+                    mod target {}
+                }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn fields_and_assoc_items() {
+        check(
+            r#"
+mod a {
+    pub struct S { f$0: u32 }
+}
+mod target {}
+"#,
+            expect![[r#"
+                /main.rs
+                mod a {
+                    pub struct S { pub(crate) f: u32 }
+                }
+                mod target {}
+            "#]],
+        );
+        check(
+            r#"
+mod a {
+    struct S;
+    impl S {
+        fn f$0() {}
+    }
+}
+mod target {}
+"#,
+            expect![[r#"
+                /main.rs
+                mod a {
+                    struct S;
+                    impl S {
+                        pub(crate) fn f() {}
+                    }
+                }
+                mod target {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn other_crate() {
+        check(
+            r#"
+//- /main.rs crate:main deps:lib
+mod target {}
+//- /lib.rs crate:lib
+mod a {
+    pub(crate) fn f$0() {}
+}
+"#,
+            expect![[r#"
+                /lib.rs
+                pub mod a {
+                    pub fn f() {}
+                }
+            "#]],
+        );
+        check(
+            r#"
+//- /main.rs crate:main
+mod target {}
+//- /lib.rs crate:lib deps:main
+pub fn f$0() {}
+"#,
+            expect![[r#"
+                impossible
+            "#]],
+        );
+    }
+
+    #[test]
+    fn block_items() {
+        check(
+            r#"
+fn g() {
+    struct S$0;
+}
+mod target {}
+"#,
+            expect![[r#"
+                impossible
+            "#]],
+        );
+    }
+}
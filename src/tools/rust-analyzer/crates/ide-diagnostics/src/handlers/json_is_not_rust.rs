@@ -135,10 +135,10 @@ pub(crate) fn json_in_items(
                                 if let Some(it) = current_module.find_use_path_prefixed(
                                     sema.db,
                                     it,
-                                    config.insert_use.prefix_kind,
-                                    config.prefer_no_std,
+                                    config.imports.prefix_kind,
+                                    config.imports.prefer_no_std,
                                 ) {
-                                    insert_use(&scope, mod_path_to_ast(&it), &config.insert_use);
+                                    insert_use(&scope, mod_path_to_ast(&it), &config.imports);
                                 }
                             }
                         }
@@ -147,10 +147,10 @@ pub(crate) fn json_in_items(
                                 if let Some(it) = current_module.find_use_path_prefixed(
                                     sema.db,
                                     it,
-                                    config.insert_use.prefix_kind,
-                                    config.prefer_no_std,
+                                    config.imports.prefix_kind,
+                                    config.imports.prefer_no_std,
                                 ) {
-                                    insert_use(&scope, mod_path_to_ast(&it), &config.insert_use);
+                                    insert_use(&scope, mod_path_to_ast(&it), &config.imports);
                                 }
                             }
                         }
@@ -115,6 +115,16 @@ fn test_unresolved_module_diagnostic() {
                                                 },
                                             ],
                                             is_snippet: false,
+                                            snippet_edits: {},
+                                            other_file_edits: [],
+                                            undo_edits: {},
+                                            annotations: {},
+                                            kind: Some(
+                                                QuickFix,
+                                            ),
+                                            origin: Some(
+                                                "create_module",
+                                            ),
                                         },
                                     ),
                                     trigger_signature_help: false,
@@ -142,6 +152,16 @@ fn test_unresolved_module_diagnostic() {
                                                 },
                                             ],
                                             is_snippet: false,
+                                            snippet_edits: {},
+                                            other_file_edits: [],
+                                            undo_edits: {},
+                                            annotations: {},
+                                            kind: Some(
+                                                QuickFix,
+                                            ),
+                                            origin: Some(
+                                                "create_module",
+                                            ),
                                         },
                                     ),
                                     trigger_signature_help: false,
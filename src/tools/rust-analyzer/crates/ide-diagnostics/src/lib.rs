@@ -71,9 +71,9 @@ mod handlers {
 use ide_db::{
     assists::{Assist, AssistId, AssistKind, AssistResolveStrategy},
     base_db::{FileId, FileRange, SourceDatabase},
-    imports::insert_use::InsertUseConfig,
+    imports::insert_use::ImportPrefs,
     label::Label,
-    source_change::SourceChange,
+    source_change::{ChangeKind, SourceChange},
     FxHashSet, RootDatabase,
 };
 use syntax::{algo::find_node_at_range, ast::AstNode, SyntaxNodePtr, TextRange};
@@ -161,14 +161,13 @@ pub struct DiagnosticsConfig {
     pub disabled: FxHashSet<String>,
     pub expr_fill_default: ExprFillDefaultMode,
     // FIXME: We may want to include a whole `AssistConfig` here
-    pub insert_use: InsertUseConfig,
-    pub prefer_no_std: bool,
+    pub imports: ImportPrefs,
 }
 
 impl DiagnosticsConfig {
     pub fn test_sample() -> Self {
         use hir::PrefixKind;
-        use ide_db::imports::insert_use::ImportGranularity;
+        use ide_db::imports::insert_use::{ImportGranularity, ImportGroupStyle, ImportLocation};
 
         Self {
             proc_macros_enabled: Default::default(),
@@ -176,14 +175,16 @@ pub fn test_sample() -> Self {
             disable_experimental: Default::default(),
             disabled: Default::default(),
             expr_fill_default: Default::default(),
-            insert_use: InsertUseConfig {
+            imports: ImportPrefs {
                 granularity: ImportGranularity::Preserve,
                 enforce_granularity: false,
                 prefix_kind: PrefixKind::Plain,
                 group: false,
+                group_style: ImportGroupStyle::PathKind,
                 skip_glob_imports: false,
+                location: ImportLocation::Module,
+                prefer_no_std: false,
             },
-            prefer_no_std: false,
         }
     }
 }
@@ -319,7 +320,7 @@ pub fn diagnostics(
 
 fn fix(id: &'static str, label: &str, source_change: SourceChange, target: TextRange) -> Assist {
     let mut res = unresolved_fix(id, label, target);
-    res.source_change = Some(source_change);
+    res.source_change = Some(source_change.with_kind(ChangeKind::QuickFix, id));
     res
 }
 
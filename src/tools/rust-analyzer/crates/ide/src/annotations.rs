@@ -2,7 +2,7 @@
 use ide_db::{
     base_db::{FileId, FilePosition, FileRange},
     defs::Definition,
-    helpers::visit_file_defs,
+    helpers::{visit_file_defs_of_kinds, DefKind},
     RootDatabase,
 };
 use syntax::{ast::HasName, AstNode, TextRange};
@@ -80,7 +80,8 @@ pub(crate) fn annotations(
         (annotation_range, target_pos)
     };
 
-    visit_file_defs(&Semantics::new(db), file_id, &mut |def| {
+    let kinds = [DefKind::Const, DefKind::Trait, DefKind::Adt];
+    visit_file_defs_of_kinds(&Semantics::new(db), file_id, &kinds, &mut |def| {
         let range = match def {
             Definition::Const(konst) if config.annotate_references => {
                 konst.source(db).and_then(|node| name_range(db, node, file_id))
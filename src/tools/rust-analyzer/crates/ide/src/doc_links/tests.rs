@@ -5,15 +5,13 @@
 use ide_db::{
     base_db::{FilePosition, FileRange},
     defs::Definition,
+    rust_doc::{extract_definitions_from_docs, resolve_doc_path_for_def},
     RootDatabase,
 };
 use itertools::Itertools;
 use syntax::{ast, match_ast, AstNode, SyntaxNode};
 
-use crate::{
-    doc_links::{extract_definitions_from_docs, resolve_doc_path_for_def, rewrite_links},
-    fixture, TryToNav,
-};
+use crate::{doc_links::rewrite_links, fixture, TryToNav};
 
 fn check_external_docs(
     ra_fixture: &str,
@@ -1721,6 +1721,25 @@ mod foo {
         );
     }
 
+    #[test]
+    fn goto_def_derive_helper() {
+        check(
+            r#"
+//- minicore:derive
+//- /main.rs crate:main deps:lib
+#[derive(lib::Copy)]
+struct Foo {
+    #[helper$0]
+    field: (),
+}
+//- /lib.rs crate:lib
+#[rustc_builtin_macro(Copy, attributes(helper))]
+                                     //^^^^^^
+pub macro Copy {}
+"#,
+        );
+    }
+
     #[test]
     fn goto_def_in_macro_multi() {
         check(
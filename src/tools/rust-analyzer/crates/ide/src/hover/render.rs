@@ -1,5 +1,5 @@
 //! Logic for rendering the different hover messages
-use std::fmt::Display;
+use std::{borrow::Cow, fmt::Display};
 
 use either::Either;
 use hir::{
@@ -10,7 +10,7 @@
     base_db::SourceDatabase,
     defs::Definition,
     famous_defs::FamousDefs,
-    generated::lints::{CLIPPY_LINTS, DEFAULT_LINTS, FEATURES},
+    helpers::{lookup_feature, lookup_lint, LintInfo},
     syntax_helpers::insert_whitespace_into_node,
     RootDatabase,
 };
@@ -312,38 +312,43 @@ pub(super) fn try_for_lint(attr: &ast::Attr, token: &SyntaxToken) -> Option<Hove
     if !tt.syntax().text_range().contains(token.text_range().start()) {
         return None;
     }
-    let (is_clippy, lints) = match &*path {
-        "feature" => (false, FEATURES),
+    let is_clippy = || {
+        algo::non_trivia_sibling(token.clone().into(), Direction::Prev)
+            .filter(|t| t.kind() == T![:])
+            .and_then(|t| algo::non_trivia_sibling(t, Direction::Prev))
+            .filter(|t| t.kind() == T![:])
+            .and_then(|t| algo::non_trivia_sibling(t, Direction::Prev))
+            .map_or(false, |t| {
+                t.kind() == T![ident] && t.into_token().map_or(false, |t| t.text() == "clippy")
+            })
+    };
+    let (label, description) = match &*path {
+        "feature" => {
+            let feature = lookup_feature(token.text())?;
+            (feature.label, Cow::Borrowed(feature.description))
+        }
         "allow" | "deny" | "forbid" | "warn" => {
-            let is_clippy = algo::non_trivia_sibling(token.clone().into(), Direction::Prev)
-                .filter(|t| t.kind() == T![:])
-                .and_then(|t| algo::non_trivia_sibling(t, Direction::Prev))
-                .filter(|t| t.kind() == T![:])
-                .and_then(|t| algo::non_trivia_sibling(t, Direction::Prev))
-                .map_or(false, |t| {
-                    t.kind() == T![ident] && t.into_token().map_or(false, |t| t.text() == "clippy")
-                });
-            if is_clippy {
-                (true, CLIPPY_LINTS)
+            let name = if is_clippy() {
+                format!("clippy::{}", token.text())
             } else {
-                (false, DEFAULT_LINTS)
+                token.text().to_owned()
+            };
+            match lookup_lint(&name)? {
+                LintInfo::Lint(lint) => (lint.label, Cow::Borrowed(lint.description)),
+                LintInfo::Group(group) => (group.lint.label, Cow::Borrowed(group.lint.description)),
+                LintInfo::Renamed(renamed) => {
+                    (renamed.old, Cow::Owned(format!("renamed to `{}`", renamed.new)))
+                }
+                LintInfo::Removed(removed) => {
+                    (removed.label, Cow::Owned(format!("removed: {}", removed.reason)))
+                }
             }
         }
         _ => return None,
     };
 
-    let tmp;
-    let needle = if is_clippy {
-        tmp = format!("clippy::{}", token.text());
-        &tmp
-    } else {
-        &*token.text()
-    };
-
-    let lint =
-        lints.binary_search_by_key(&needle, |lint| lint.label).ok().map(|idx| &lints[idx])?;
     Some(HoverResult {
-        markup: Markup::from(format!("```\n{}\n```\n___\n\n{}", lint.label, lint.description)),
+        markup: Markup::from(format!("```\n{label}\n```\n___\n\n{description}")),
         ..Default::default()
     })
 }
@@ -5130,37 +5130,15 @@ pub fn foo() {}
 fn hover_feature() {
     check(
         r#"#![feature(box_syntax$0)]"#,
-        expect![[r##"
-                *box_syntax*
-                ```
-                box_syntax
-                ```
-                ___
-
-                # `box_syntax`
-
-                The tracking issue for this feature is: [#49733]
-
-                [#49733]: https://github.com/rust-lang/rust/issues/49733
-
-                See also [`box_patterns`](box-patterns.md)
-
-                ------------------------
-
-                Currently the only stable way to create a `Box` is via the `Box::new` method.
-                Also it is not possible in stable Rust to destructure a `Box` in a match
-                pattern. The unstable `box` keyword can be used to create a `Box`. An example
-                usage would be:
-
-                ```rust
-                #![feature(box_syntax)]
-
-                fn main() {
-                    let b = box 5;
-                }
-                ```
+        expect![[r#"
+            *box_syntax*
+            ```
+            box_syntax
+            ```
+            ___
 
-            "##]],
+            Allows using the `box $expr` syntax.
+        "#]],
     )
 }
 
@@ -5196,6 +5174,22 @@ fn hover_clippy_lint() {
     )
 }
 
+#[test]
+fn hover_renamed_lint() {
+    check(
+        r#"#![allow(bare_trait_object$0)]"#,
+        expect![[r#"
+                *bare_trait_object*
+                ```
+                bare_trait_object
+                ```
+                ___
+
+                renamed to `bare_trait_objects`
+            "#]],
+    )
+}
+
 #[test]
 fn hover_attr_path_qualifier() {
     check(
@@ -651,6 +651,7 @@ fn main() {
                                     Indel {
                                         insert: ": Struct",
                                         delete: 130..130,
+                                        annotation: None,
                                     },
                                 ],
                             },
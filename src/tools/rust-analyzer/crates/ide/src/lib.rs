@@ -68,7 +68,7 @@ macro_rules! eprintln {
 use ide_db::{
     base_db::{
         salsa::{self, ParallelDatabase},
-        CrateOrigin, Env, FileLoader, FileSet, SourceDatabase, VfsPath,
+        AnchoredPathBuf, CrateOrigin, Env, FileLoader, FileSet, SourceDatabase, VfsPath,
     },
     symbol_index, FxHashMap, FxIndexSet, LineIndexDatabase,
 };
@@ -100,7 +100,10 @@ macro_rules! eprintln {
     navigation_target::NavigationTarget,
     prime_caches::ParallelPrimeCachesProgress,
     references::ReferenceSearchResult,
-    rename::RenameError,
+    rename::{
+        NameCase, RenameEditKind, RenameError, RenamePreview, RenamePreviewEdit, RenameWarning,
+        DEFAULT_ACCESSOR_PATTERNS,
+    },
     runnables::{Runnable, RunnableKind, TestId},
     signature_help::SignatureHelp,
     static_index::{StaticIndex, StaticIndexedFile, TokenId, TokenStaticData},
@@ -122,17 +125,23 @@ macro_rules! eprintln {
         Cancelled, Change, CrateGraph, CrateId, Edition, FileId, FilePosition, FileRange,
         SourceRoot, SourceRootId,
     },
+    eviction::EvictionReport,
+    invalidation::ChangeInvalidation,
     label::Label,
     line_index::{LineCol, LineIndex},
+    lru::LruCapacities,
+    memory_usage::MemoryUsageReport,
+    overlay::Overlay,
+    priority::Priority,
     search::{ReferenceCategory, SearchScope},
-    source_change::{FileSystemEdit, SourceChange},
-    symbol_index::Query,
+    source_change::{ChangeKind, EditAnnotation, FileSystemEdit, SourceChange},
+    ssr::SsrError,
+    symbol_index::{Query, QueryScope},
     RootDatabase, SymbolKind,
 };
 pub use ide_diagnostics::{Diagnostic, DiagnosticsConfig, ExprFillDefaultMode, Severity};
-pub use ide_ssr::SsrError;
 pub use syntax::{TextRange, TextSize};
-pub use text_edit::{Indel, TextEdit};
+pub use text_edit::{ChangeAnnotationId, Indel, TextEdit};
 
 pub type Cancellable<T> = Result<T, Cancelled>;
 
@@ -168,12 +177,47 @@ pub fn update_lru_capacities(&mut self, lru_capacities: &FxHashMap<Box<str>, usi
         self.db.update_lru_capacities(lru_capacities);
     }
 
+    pub fn set_lru_capacities(&mut self, capacities: &LruCapacities) {
+        self.db.set_lru_capacities(capacities);
+    }
+
+    pub fn set_active_files(&mut self, files: impl IntoIterator<Item = FileId>) {
+        self.db.set_active_files(files);
+    }
+
+    /// Evicts the caches of the files that aren't active if more than `threshold` bytes are
+    /// allocated, see [`RootDatabase::evict_inactive_files`].
+    pub fn evict_inactive_files(
+        &mut self,
+        capacities: &LruCapacities,
+        threshold: profile::Bytes,
+    ) -> Option<EvictionReport> {
+        self.db.evict_inactive_files(capacities, threshold)
+    }
+
     /// Returns a snapshot of the current state, which you can query for
     /// semantic information.
     pub fn analysis(&self) -> Analysis {
         Analysis { db: self.db.snapshot() }
     }
 
+    /// Returns a snapshot of a pinned copy of the current state, for long-running work that
+    /// shouldn't be cancelled by changes nor delay them.
+    pub fn pinned_analysis(&self) -> Analysis {
+        Analysis { db: self.db.pin().snapshot() }
+    }
+
+    /// Returns a snapshot for work of the given priority. Background work is preempted while a
+    /// foreground snapshot is alive, see [`Priority`].
+    pub fn analysis_with_priority(&self, priority: Priority) -> Analysis {
+        Analysis { db: self.db.snapshot_with_priority(priority) }
+    }
+
+    /// Whether a foreground snapshot is alive, during which background work is cancelled.
+    pub fn has_foreground_work(&self) -> bool {
+        self.db.has_foreground_work()
+    }
+
     /// Applies changes to the current state of the world. If there are
     /// outstanding snapshots, they will be canceled.
     pub fn apply_change(&mut self, change: Change) {
@@ -184,6 +228,16 @@ pub fn apply_change(&mut self, change: Change) {
     pub fn per_query_memory_usage(&mut self) -> Vec<(String, profile::Bytes, usize)> {
         self.db.per_query_memory_usage()
     }
+    /// NB: this clears the database
+    pub fn memory_usage_report(&mut self) -> MemoryUsageReport {
+        self.db.memory_usage_report()
+    }
+    pub fn set_invalidation_tracking(&self, enabled: bool) {
+        self.db.set_invalidation_tracking(enabled);
+    }
+    pub fn invalidation_stats(&self) -> Vec<ChangeInvalidation> {
+        self.db.invalidation_stats()
+    }
     pub fn request_cancellation(&mut self) {
         self.db.request_cancellation();
     }
@@ -534,6 +588,11 @@ pub fn crate_edition(&self, crate_id: CrateId) -> Cancellable<Edition> {
         self.with_db(|db| db.crate_graph()[crate_id].edition)
     }
 
+    /// Returns the display name of the given crate.
+    pub fn crate_display_name(&self, crate_id: CrateId) -> Cancellable<Option<String>> {
+        self.with_db(|db| db.crate_graph()[crate_id].display_name.as_ref().map(ToString::to_string))
+    }
+
     /// Returns true if this crate has `no_std` or `no_core` specified.
     pub fn is_crate_no_std(&self, crate_id: CrateId) -> Cancellable<bool> {
         self.with_db(|db| hir::db::DefDatabase::crate_def_map(db, crate_id).is_no_std())
@@ -672,6 +731,67 @@ pub fn rename(
         self.with_db(|db| rename::rename(db, position, new_name))
     }
 
+    /// Returns the edit required to convert the name of the definition at the position to
+    /// `case`, along with the names related to it.
+    pub fn rename_case(
+        &self,
+        position: FilePosition,
+        case: NameCase,
+    ) -> Cancellable<Result<SourceChange, RenameError>> {
+        self.with_db(|db| rename::rename_case(db, position, case))
+    }
+
+    /// Returns the edit required to rename the reference at the position to the new name. If it
+    /// refers to a field, the methods named after it according to `accessor_patterns` are renamed
+    /// as well.
+    pub fn rename_with_accessors(
+        &self,
+        position: FilePosition,
+        new_name: &str,
+        accessor_patterns: &[&str],
+    ) -> Cancellable<Result<SourceChange, RenameError>> {
+        self.with_db(|db| rename::rename_with_accessors(db, position, new_name, accessor_patterns))
+    }
+
+    /// Returns the edit renaming the mentions of the definition at the position in comments and
+    /// string literals. These are not references, so the edit is kept apart from [`Self::rename`]
+    /// for the user to opt into.
+    pub fn rename_textual_occurrences(
+        &self,
+        position: FilePosition,
+        new_name: &str,
+    ) -> Cancellable<Result<SourceChange, RenameError>> {
+        self.with_db(|db| rename::rename_textual_occurrences(db, position, new_name))
+    }
+
+    /// Returns the edits required to rename the reference at the position to the new name,
+    /// grouped by file and by kind for previewing them.
+    pub fn rename_preview(
+        &self,
+        position: FilePosition,
+        new_name: &str,
+    ) -> Cancellable<Result<RenamePreview, RenameError>> {
+        self.with_db(|db| rename::rename_preview(db, position, new_name))
+    }
+
+    /// Returns the effects of renaming the definition at the position outside of the workspace,
+    /// like breaking the public API of a crate.
+    pub fn rename_warnings(
+        &self,
+        position: FilePosition,
+    ) -> Cancellable<Result<Vec<RenameWarning>, RenameError>> {
+        self.with_db(|db| rename::rename_warnings(db, position))
+    }
+
+    /// Returns the edit that keeps the exported symbol of the `#[no_mangle]` definition at the
+    /// position unchanged when renaming it, to be applied along with [`Self::rename`].
+    pub fn export_name_edit(
+        &self,
+        position: FilePosition,
+    ) -> Cancellable<Result<Option<SourceChange>, RenameError>> {
+        self.with_db(|db| rename::export_name_edit(db, position))
+    }
+
     pub fn prepare_rename(
         &self,
         position: FilePosition,
@@ -679,6 +799,18 @@ pub fn prepare_rename(
         self.with_db(|db| rename::prepare_rename(db, position))
     }
 
+    /// Returns the edit required to rename a crate, including its references in the crates
+    /// depending on it. The `Cargo.toml` manifests that aren't loaded are read with
+    /// `read_manifest`.
+    pub fn rename_crate(
+        &self,
+        crate_id: CrateId,
+        new_name: &str,
+        read_manifest: impl Fn(&AnchoredPathBuf) -> Option<String> + std::panic::UnwindSafe,
+    ) -> Cancellable<Result<SourceChange, RenameError>> {
+        self.with_db(move |db| rename::rename_crate(db, crate_id, new_name, &read_manifest))
+    }
+
     pub fn will_rename_file(
         &self,
         file_id: FileId,
@@ -695,9 +827,9 @@ pub fn structural_search_replace(
         selections: Vec<FileRange>,
     ) -> Cancellable<Result<SourceChange, SsrError>> {
         self.with_db(|db| {
-            let rule: ide_ssr::SsrRule = query.parse()?;
+            let rule: ide_db::ssr::SsrRule = query.parse()?;
             let mut match_finder =
-                ide_ssr::MatchFinder::in_context(db, resolve_context, selections)?;
+                ide_db::ssr::MatchFinder::in_context(db, resolve_context, selections)?;
             match_finder.add_rule(rule)?;
             let edits = if parse_only { Default::default() } else { match_finder.edits() };
             Ok(SourceChange::from(edits))
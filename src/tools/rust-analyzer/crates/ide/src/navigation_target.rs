@@ -14,8 +14,9 @@
 use ide_db::{defs::Definition, RootDatabase};
 use stdx::never;
 use syntax::{
+    algo,
     ast::{self, HasName},
-    AstNode, SmolStr, SyntaxNode, TextRange,
+    AstNode, Direction, SmolStr, SyntaxKind, SyntaxNode, TextRange,
 };
 
 /// `NavigationTarget` represents an element in the editor's UI which you can
@@ -165,13 +166,12 @@ fn from_syntax(
 impl TryToNav for FileSymbol {
     fn try_to_nav(&self, db: &RootDatabase) -> Option<NavigationTarget> {
         let full_range = self.loc.original_range(db);
-        let focus_range = self.loc.original_name_range(db).and_then(|it| {
-            if it.file_id == full_range.file_id {
-                Some(it.range)
-            } else {
-                None
-            }
-        });
+        let focus_range = self
+            .loc
+            .original_name_range(db)
+            // Names generated by a macro don't map back to the source, focus the macro call instead.
+            .or_else(|| self.loc.macro_call_range(db))
+            .and_then(|it| if it.file_id == full_range.file_id { Some(it.range) } else { None });
 
         Some(NavigationTarget {
             file_id: full_range.file_id,
@@ -220,8 +220,7 @@ fn try_to_nav(&self, db: &RootDatabase) -> Option<NavigationTarget> {
             Definition::BuiltinType(_) => None,
             Definition::ToolModule(_) => None,
             Definition::BuiltinAttr(_) => None,
-            // FIXME: The focus range should be set to the helper declaration
-            Definition::DeriveHelper(it) => it.derive().try_to_nav(db),
+            Definition::DeriveHelper(it) => it.try_to_nav(db),
         }
     }
 }
@@ -416,6 +415,43 @@ fn try_to_nav(&self, db: &RootDatabase) -> Option<NavigationTarget> {
     }
 }
 
+impl TryToNav for hir::DeriveHelper {
+    fn try_to_nav(&self, db: &RootDatabase) -> Option<NavigationTarget> {
+        let derive = self.derive();
+        let mut res = derive.try_to_nav(db)?;
+        // Focus the declaration of the helper in the `attributes(...)` list of the derive's
+        // attribute, like `#[proc_macro_derive(Derive, attributes(helper))]`.
+        let src = derive.source(db)?;
+        if src.file_id.file_id() != Some(res.file_id) {
+            return Some(res);
+        }
+        let attrs_owner: &dyn ast::HasAttrs = match &src.value {
+            Either::Left(it) => it,
+            Either::Right(it) => it,
+        };
+        let name = self.name(db).to_smol_str();
+        let declaration = attrs_owner.attrs().filter_map(|attr| attr.token_tree()).find_map(|tt| {
+            tt.syntax().descendants_with_tokens().filter_map(|it| it.into_token()).find(|token| {
+                token.kind() == SyntaxKind::IDENT
+                    && token.text() == name
+                    && token
+                        .parent()
+                        .and_then(|list| {
+                            algo::non_trivia_sibling(list.into(), Direction::Prev)?.into_token()
+                        })
+                        .map_or(false, |it| it.text() == "attributes")
+            })
+        });
+        if let Some(declaration) = declaration {
+            res.focus_range = Some(declaration.text_range());
+            res.name = name;
+            res.kind = Some(SymbolKind::DeriveHelper);
+            res.docs = None;
+        }
+        Some(res)
+    }
+}
+
 impl TryToNav for hir::Adt {
     fn try_to_nav(&self, db: &RootDatabase) -> Option<NavigationTarget> {
         match self {
@@ -659,6 +695,33 @@ fn foo() { enum FooInner { } }
         .assert_debug_eq(&navs);
     }
 
+    #[test]
+    fn test_nav_for_macro_generated_symbol() {
+        let (analysis, _) = fixture::file(
+            r#"
+macro_rules! gen { () => { struct Generated; } }
+gen!();
+"#,
+        );
+
+        let navs = analysis.symbol_search(Query::new("Generated".to_string())).unwrap();
+        expect![[r#"
+            [
+                NavigationTarget {
+                    file_id: FileId(
+                        0,
+                    ),
+                    full_range: 49..56,
+                    focus_range: 49..56,
+                    name: "Generated",
+                    kind: Struct,
+                    description: "struct Generated",
+                },
+            ]
+        "#]]
+        .assert_debug_eq(&navs);
+    }
+
     #[test]
     fn test_world_symbols_are_case_sensitive() {
         let (analysis, _) = fixture::file(
@@ -6,9 +6,10 @@
 
 use hir::{AsAssocItem, InFile, Semantics};
 use ide_db::{
-    base_db::FileId,
+    base_db::{AnchoredPathBuf, CrateId, FileId},
     defs::{Definition, NameClass, NameRefClass},
-    rename::{bail, format_err, source_edit_from_references, IdentifierKind},
+    rename::{bail, format_err, new_name_for_edition, source_edit_from_references, IdentifierKind},
+    source_change::ChangeKind,
     RootDatabase,
 };
 use itertools::Itertools;
@@ -19,7 +20,10 @@
 
 use crate::{FilePosition, RangeInfo, SourceChange};
 
-pub use ide_db::rename::RenameError;
+pub use ide_db::rename::{
+    NameCase, RenameEditKind, RenameError, RenamePreview, RenamePreviewEdit, RenameWarning,
+    DEFAULT_ACCESSOR_PATTERNS,
+};
 
 type RenameResult<T> = Result<T, RenameError>;
 
@@ -110,9 +114,127 @@ pub(crate) fn rename(
 
     ops?.into_iter()
         .reduce(|acc, elem| acc.merge(elem))
+        .map(|it| it.with_kind(ChangeKind::Rename, "rename"))
+        .ok_or_else(|| format_err!("No references found at position"))
+}
+
+/// Converts the name of the definition at `position` to another case, see
+/// [`ide_db::rename::rename_case`].
+pub(crate) fn rename_case(
+    db: &RootDatabase,
+    position: FilePosition,
+    case: NameCase,
+) -> RenameResult<SourceChange> {
+    let sema = Semantics::new(db);
+    let source_file = sema.parse(position.file_id);
+    let syntax = source_file.syntax();
+
+    let defs = find_definitions(&sema, syntax, position)?;
+    let ops: RenameResult<Vec<SourceChange>> =
+        defs.map(|(_, def)| ide_db::rename::rename_case(&sema, def, case)).collect();
+    ops?.into_iter()
+        .reduce(|acc, elem| acc.merge(elem))
+        .map(|it| it.with_kind(ChangeKind::Rename, "rename_case"))
         .ok_or_else(|| format_err!("No references found at position"))
 }
 
+/// Checks what renaming the definition at `position` affects outside of the workspace, see
+/// [`Definition::rename_warnings`].
+pub(crate) fn rename_warnings(
+    db: &RootDatabase,
+    position: FilePosition,
+) -> RenameResult<Vec<RenameWarning>> {
+    let sema = Semantics::new(db);
+    let source_file = sema.parse(position.file_id);
+    let syntax = source_file.syntax();
+
+    let mut res = Vec::new();
+    for (_, def) in find_definitions(&sema, syntax, position)? {
+        for warning in def.rename_warnings(&sema) {
+            if !res.contains(&warning) {
+                res.push(warning);
+            }
+        }
+    }
+    Ok(res)
+}
+
+/// The edit keeping the symbol of the `#[no_mangle]` definition at `position` when it is renamed,
+/// see [`Definition::export_name_edit`].
+pub(crate) fn export_name_edit(
+    db: &RootDatabase,
+    position: FilePosition,
+) -> RenameResult<Option<SourceChange>> {
+    let sema = Semantics::new(db);
+    let source_file = sema.parse(position.file_id);
+    let syntax = source_file.syntax();
+
+    let edits = find_definitions(&sema, syntax, position)?
+        .filter_map(|(_, def)| def.export_name_edit(&sema));
+    Ok(edits.reduce(|acc, elem| acc.merge(elem)))
+}
+
+/// Renames the definition at `position` like [`rename`], renaming the accessors named after it
+/// along with it if it is a field, see [`ide_db::rename::rename_field_with_accessors`].
+pub(crate) fn rename_with_accessors(
+    db: &RootDatabase,
+    position: FilePosition,
+    new_name: &str,
+    accessor_patterns: &[&str],
+) -> RenameResult<SourceChange> {
+    let sema = Semantics::new(db);
+    let source_file = sema.parse(position.file_id);
+    let syntax = source_file.syntax();
+
+    let defs = find_definitions(&sema, syntax, position)?;
+    let ops: RenameResult<Vec<SourceChange>> = defs
+        .map(|(_, def)| match def {
+            Definition::Field(field) => ide_db::rename::rename_field_with_accessors(
+                &sema,
+                field,
+                new_name,
+                accessor_patterns,
+            ),
+            _ => def.rename(&sema, new_name),
+        })
+        .collect();
+    ops?.into_iter()
+        .reduce(|acc, elem| acc.merge(elem))
+        .map(|it| it.with_kind(ChangeKind::Rename, "rename"))
+        .ok_or_else(|| format_err!("No references found at position"))
+}
+
+/// Renames the mentions of the definition at `position` in comments and string literals, see
+/// [`Definition::rename_textual_occurrences`].
+pub(crate) fn rename_textual_occurrences(
+    db: &RootDatabase,
+    position: FilePosition,
+    new_name: &str,
+) -> RenameResult<SourceChange> {
+    let sema = Semantics::new(db);
+    let source_file = sema.parse(position.file_id);
+    let syntax = source_file.syntax();
+
+    let defs = find_definitions(&sema, syntax, position)?;
+    let ops: RenameResult<Vec<SourceChange>> =
+        defs.map(|(_, def)| def.rename_textual_occurrences(&sema, new_name)).collect();
+    ops?.into_iter()
+        .reduce(|acc, elem| acc.merge(elem))
+        .map(|it| it.with_kind(ChangeKind::Rename, "rename_textual_occurrences"))
+        .ok_or_else(|| format_err!("No references found at position"))
+}
+
+/// Renames the definition at `position` like [`rename`], but returns the edits classified for
+/// the client to preview them.
+pub(crate) fn rename_preview(
+    db: &RootDatabase,
+    position: FilePosition,
+    new_name: &str,
+) -> RenameResult<RenamePreview> {
+    let source_change = rename(db, position, new_name)?;
+    Ok(RenamePreview::new(&Semantics::new(db), &source_change))
+}
+
 /// Called by the client when it is about to rename a file.
 pub(crate) fn will_rename_file(
     db: &RootDatabase,
@@ -128,7 +250,18 @@ pub(crate) fn will_rename_file(
         def.rename(&sema, new_name_stem).ok()?
     };
     change.file_system_edits.clear();
-    Some(change)
+    Some(change.with_kind(ChangeKind::Rename, "will_rename_file"))
+}
+
+/// Renames a whole crate, see [`ide_db::rename::rename_crate`].
+pub(crate) fn rename_crate(
+    db: &RootDatabase,
+    crate_id: CrateId,
+    new_name: &str,
+    read_manifest: &dyn Fn(&AnchoredPathBuf) -> Option<String>,
+) -> RenameResult<SourceChange> {
+    let sema = Semantics::new(db);
+    ide_db::rename::rename_crate(&sema, crate_id.into(), new_name, read_manifest)
 }
 
 fn find_definitions(
@@ -296,6 +429,7 @@ fn rename_self_to_param(
     }
 
     let identifier_kind = IdentifierKind::classify(new_name)?;
+    let new_name = &new_name_for_edition(new_name, local.module(sema.db).krate().edition(sema.db))?;
 
     let InFile { file_id, value: self_param } =
         self_param.source(sema.db).ok_or_else(|| format_err!("cannot find function source"))?;
@@ -341,7 +475,8 @@ fn target_type_name(impl_def: &ast::Impl) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use expect_test::{expect, Expect};
-    use stdx::trim_indent;
+    use ide_db::base_db::AnchoredPathBuf;
+    use stdx::{format_to, trim_indent};
     use test_utils::assert_eq_text;
     use text_edit::TextEdit;
 
@@ -396,6 +531,70 @@ fn check_expect(new_name: &str, ra_fixture: &str, expect: Expect) {
         expect.assert_debug_eq(&source_change)
     }
 
+    /// Renames the crate at the cursor, with the manifests given as the path they are read
+    /// from, like `FileId(0)/../Cargo.toml`, and their text.
+    fn check_rename_crate(
+        new_name: &str,
+        ra_fixture: &str,
+        manifests: &[(&str, &str)],
+        expect: Expect,
+    ) {
+        let (analysis, position) = fixture::position(ra_fixture);
+        let crate_id = analysis.crates_for(position.file_id).unwrap()[0];
+        let manifest_text = |path: &AnchoredPathBuf| {
+            let path = format!("{:?}/{}", path.anchor, path.path);
+            manifests.iter().find(|(it, _)| *it == path).map(|(_, text)| text.to_string())
+        };
+        let source_change = match analysis.rename_crate(crate_id, new_name, manifest_text).unwrap()
+        {
+            Ok(it) => it,
+            Err(err) => return expect.assert_eq(&format!("error: {err}")),
+        };
+        let mut edits: Vec<_> = source_change.source_file_edits.into_iter().collect();
+        edits.sort_by_key(|&(file_id, _)| file_id);
+        let mut actual = String::new();
+        for (file_id, edit) in edits {
+            let mut text = analysis.file_text(file_id).unwrap().to_string();
+            edit.apply(&mut text);
+            format_to!(actual, "{file_id:?}\n{text}\n");
+        }
+        for (path, edit) in source_change.other_file_edits {
+            let mut text = manifest_text(&path).unwrap();
+            edit.apply(&mut text);
+            format_to!(actual, "{:?}/{}\n{text}\n", path.anchor, path.path);
+        }
+        expect.assert_eq(&actual)
+    }
+
+    fn check_textual_occurrences(new_name: &str, ra_fixture: &str, expect: Expect) {
+        let (analysis, position) = fixture::position(ra_fixture);
+        let source_change =
+            analysis.rename_textual_occurrences(position, new_name).unwrap().unwrap();
+        let mut text = analysis.file_text(position.file_id).unwrap().to_string();
+        if let Some(edit) = source_change.get_source_edit(position.file_id) {
+            edit.apply(&mut text);
+        }
+        expect.assert_eq(&text)
+    }
+
+    fn check_preview(new_name: &str, ra_fixture: &str, expect: Expect) {
+        let (analysis, position) = fixture::position(ra_fixture);
+        let preview = analysis.rename_preview(position, new_name).unwrap().unwrap();
+        let mut actual = String::new();
+        for (file_id, edits) in preview.files {
+            for edit in edits {
+                format_to!(
+                    actual,
+                    "{file_id:?} {:?} {:?} -> {:?}\n",
+                    edit.kind,
+                    edit.old_text,
+                    edit.new_text
+                );
+            }
+        }
+        expect.assert_eq(&actual)
+    }
+
     fn check_expect_will_rename_file(new_name: &str, ra_fixture: &str, expect: Expect) {
         let (analysis, position) = fixture::position(ra_fixture);
         let source_change = analysis
@@ -516,11 +715,52 @@ fn test_rename_to_invalid_identifier2() {
     }
 
     #[test]
-    fn test_rename_to_invalid_identifier3() {
+    fn test_rename_to_keyword() {
+        check("let", r#"fn main() { let i$0 = 1; }"#, r#"fn main() { let r#let = 1; }"#);
         check(
-            "let",
-            r#"fn main() { let i$0 = 1; }"#,
-            "error: Invalid name `let`: not an identifier",
+            "type",
+            r#"
+struct Foo { i$0: u32 }
+fn foo(foo: Foo) -> u32 { foo.i }
+"#,
+            r#"
+struct Foo { r#type: u32 }
+fn foo(foo: Foo) -> u32 { foo.r#type }
+"#,
+        );
+        check("async", r#"mod foo$0 {}"#, r#"mod r#async {}"#);
+    }
+
+    #[test]
+    fn test_rename_to_keyword_2015() {
+        check(
+            "async",
+            r#"
+//- /main.rs crate:main edition:2015
+fn foo$0() {}
+fn main() { foo(); }
+"#,
+            r#"
+fn async() {}
+fn main() { async(); }
+"#,
+        );
+        check(
+            "type",
+            r#"
+//- /main.rs crate:main edition:2015
+fn foo$0() {}
+"#,
+            "error: Invalid name `type`: `type` is a keyword in Rust 2015",
+        );
+    }
+
+    #[test]
+    fn test_rename_lifetime_to_keyword() {
+        check(
+            "'type",
+            r#"fn main<'a>(_: &'a$0 ()) {}"#,
+            "error: Invalid name `'type`: lifetimes cannot use keyword names",
         );
     }
 
@@ -900,6 +1140,7 @@ fn test_rename_mod() {
                                 Indel {
                                     insert: "foo2",
                                     delete: 4..7,
+                                    annotation: None,
                                 },
                             ],
                         },
@@ -918,6 +1159,16 @@ fn test_rename_mod() {
                         },
                     ],
                     is_snippet: false,
+                    snippet_edits: {},
+                    other_file_edits: [],
+                    undo_edits: {},
+                    annotations: {},
+                    kind: Some(
+                        Rename,
+                    ),
+                    origin: Some(
+                        "rename",
+                    ),
                 }
             "#]],
         );
@@ -949,6 +1200,7 @@ fn main() {}
                                 Indel {
                                     insert: "quux",
                                     delete: 8..11,
+                                    annotation: None,
                                 },
                             ],
                         },
@@ -959,6 +1211,7 @@ fn main() {}
                                 Indel {
                                     insert: "quux",
                                     delete: 11..14,
+                                    annotation: None,
                                 },
                             ],
                         },
@@ -977,6 +1230,16 @@ fn main() {}
                         },
                     ],
                     is_snippet: false,
+                    snippet_edits: {},
+                    other_file_edits: [],
+                    undo_edits: {},
+                    annotations: {},
+                    kind: Some(
+                        Rename,
+                    ),
+                    origin: Some(
+                        "rename",
+                    ),
                 }
             "#]],
         );
@@ -1002,6 +1265,7 @@ fn test_rename_mod_in_dir() {
                                 Indel {
                                     insert: "foo2",
                                     delete: 4..7,
+                                    annotation: None,
                                 },
                             ],
                         },
@@ -1026,6 +1290,16 @@ fn test_rename_mod_in_dir() {
                         },
                     ],
                     is_snippet: false,
+                    snippet_edits: {},
+                    other_file_edits: [],
+                    undo_edits: {},
+                    annotations: {},
+                    kind: Some(
+                        Rename,
+                    ),
+                    origin: Some(
+                        "rename",
+                    ),
                 }
             "#]],
         );
@@ -1052,6 +1326,7 @@ fn test_rename_unusually_nested_mod() {
                                 Indel {
                                     insert: "bar",
                                     delete: 16..19,
+                                    annotation: None,
                                 },
                             ],
                         },
@@ -1070,6 +1345,16 @@ fn test_rename_unusually_nested_mod() {
                         },
                     ],
                     is_snippet: false,
+                    snippet_edits: {},
+                    other_file_edits: [],
+                    undo_edits: {},
+                    annotations: {},
+                    kind: Some(
+                        Rename,
+                    ),
+                    origin: Some(
+                        "rename",
+                    ),
                 }
             "#]],
         );
@@ -1125,6 +1410,7 @@ fn f() {
                                 Indel {
                                     insert: "foo2",
                                     delete: 27..30,
+                                    annotation: None,
                                 },
                             ],
                         },
@@ -1135,6 +1421,7 @@ fn f() {
                                 Indel {
                                     insert: "foo2",
                                     delete: 8..11,
+                                    annotation: None,
                                 },
                             ],
                         },
@@ -1153,6 +1440,16 @@ fn f() {
                         },
                     ],
                     is_snippet: false,
+                    snippet_edits: {},
+                    other_file_edits: [],
+                    undo_edits: {},
+                    annotations: {},
+                    kind: Some(
+                        Rename,
+                    ),
+                    origin: Some(
+                        "rename",
+                    ),
                 }
             "#]],
         );
@@ -1192,6 +1489,7 @@ fn test_rename_mod_recursive() {
                                 Indel {
                                     insert: "foo2",
                                     delete: 4..7,
+                                    annotation: None,
                                 },
                             ],
                         },
@@ -1227,10 +1525,262 @@ fn test_rename_mod_recursive() {
                         },
                     ],
                     is_snippet: false,
+                    snippet_edits: {},
+                    other_file_edits: [],
+                    undo_edits: {},
+                    annotations: {},
+                    kind: Some(
+                        Rename,
+                    ),
+                    origin: Some(
+                        "rename",
+                    ),
                 }
             "#]],
         )
     }
+    #[test]
+    fn test_rename_mod_with_path_attr() {
+        check_expect(
+            "bar",
+            r#"
+//- /lib.rs
+#[path = "impls/foo.rs"]
+mod foo$0;
+
+//- /impls/foo.rs
+pub struct S;
+"#,
+            expect![[r#"
+                SourceChange {
+                    source_file_edits: {
+                        FileId(
+                            0,
+                        ): TextEdit {
+                            indels: [
+                                Indel {
+                                    insert: "impls/bar.rs",
+                                    delete: 10..22,
+                                    annotation: None,
+                                },
+                                Indel {
+                                    insert: "bar",
+                                    delete: 29..32,
+                                    annotation: None,
+                                },
+                            ],
+                        },
+                    },
+                    file_system_edits: [
+                        MoveFile {
+                            src: FileId(
+                                1,
+                            ),
+                            dst: AnchoredPathBuf {
+                                anchor: FileId(
+                                    1,
+                                ),
+                                path: "bar.rs",
+                            },
+                        },
+                    ],
+                    is_snippet: false,
+                    snippet_edits: {},
+                    other_file_edits: [],
+                    undo_edits: {},
+                    annotations: {},
+                    kind: Some(
+                        Rename,
+                    ),
+                    origin: Some(
+                        "rename",
+                    ),
+                }
+            "#]],
+        );
+        check_expect(
+            "bar",
+            r#"
+//- /lib.rs
+#[path = "impls.rs"]
+mod foo$0;
+
+//- /impls.rs
+pub struct S;
+"#,
+            expect![[r#"
+                SourceChange {
+                    source_file_edits: {
+                        FileId(
+                            0,
+                        ): TextEdit {
+                            indels: [
+                                Indel {
+                                    insert: "bar",
+                                    delete: 25..28,
+                                    annotation: None,
+                                },
+                            ],
+                        },
+                    },
+                    file_system_edits: [],
+                    is_snippet: false,
+                    snippet_edits: {},
+                    other_file_edits: [],
+                    undo_edits: {},
+                    annotations: {},
+                    kind: Some(
+                        Rename,
+                    ),
+                    origin: Some(
+                        "rename",
+                    ),
+                }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_rename_mod_with_included_files() {
+        check_expect(
+            "bar",
+            r#"
+//- /lib.rs
+mod foo$0;
+static DATA: &str = include_str!("foo/data.txt");
+
+//- /foo.rs
+// empty
+"#,
+            expect![[r#"
+                SourceChange {
+                    source_file_edits: {
+                        FileId(
+                            0,
+                        ): TextEdit {
+                            indels: [
+                                Indel {
+                                    insert: "bar",
+                                    delete: 4..7,
+                                    annotation: None,
+                                },
+                                Indel {
+                                    insert: "bar/data.txt",
+                                    delete: 43..55,
+                                    annotation: None,
+                                },
+                            ],
+                        },
+                    },
+                    file_system_edits: [
+                        MoveFile {
+                            src: FileId(
+                                1,
+                            ),
+                            dst: AnchoredPathBuf {
+                                anchor: FileId(
+                                    1,
+                                ),
+                                path: "bar.rs",
+                            },
+                        },
+                        MoveDir {
+                            src: AnchoredPathBuf {
+                                anchor: FileId(
+                                    1,
+                                ),
+                                path: "foo",
+                            },
+                            src_id: FileId(
+                                1,
+                            ),
+                            dst: AnchoredPathBuf {
+                                anchor: FileId(
+                                    1,
+                                ),
+                                path: "bar",
+                            },
+                        },
+                    ],
+                    is_snippet: false,
+                    snippet_edits: {},
+                    other_file_edits: [],
+                    undo_edits: {},
+                    annotations: {},
+                    kind: Some(
+                        Rename,
+                    ),
+                    origin: Some(
+                        "rename",
+                    ),
+                }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_rename_inline_mod_with_file_children() {
+        check_expect(
+            "baz",
+            r#"
+//- /lib.rs
+mod foo$0 {
+    mod bar;
+}
+
+//- /foo/bar.rs
+// empty
+"#,
+            expect![[r#"
+                SourceChange {
+                    source_file_edits: {
+                        FileId(
+                            0,
+                        ): TextEdit {
+                            indels: [
+                                Indel {
+                                    insert: "baz",
+                                    delete: 4..7,
+                                    annotation: None,
+                                },
+                            ],
+                        },
+                    },
+                    file_system_edits: [
+                        MoveDir {
+                            src: AnchoredPathBuf {
+                                anchor: FileId(
+                                    1,
+                                ),
+                                path: "../foo",
+                            },
+                            src_id: FileId(
+                                1,
+                            ),
+                            dst: AnchoredPathBuf {
+                                anchor: FileId(
+                                    1,
+                                ),
+                                path: "../baz",
+                            },
+                        },
+                    ],
+                    is_snippet: false,
+                    snippet_edits: {},
+                    other_file_edits: [],
+                    undo_edits: {},
+                    annotations: {},
+                    kind: Some(
+                        Rename,
+                    ),
+                    origin: Some(
+                        "rename",
+                    ),
+                }
+            "#]],
+        );
+    }
+
     #[test]
     fn test_rename_mod_ref_by_super() {
         check(
@@ -1299,11 +1849,283 @@ fn foo() {}
                     source_file_edits: {},
                     file_system_edits: [],
                     is_snippet: false,
+                    snippet_edits: {},
+                    other_file_edits: [],
+                    undo_edits: {},
+                    annotations: {},
+                    kind: Some(
+                        Rename,
+                    ),
+                    origin: Some(
+                        "will_rename_file",
+                    ),
                 }
                 "#]],
         )
     }
 
+    #[test]
+    fn test_rename_in_doctests() {
+        check(
+            "bar",
+            r#"
+//- /lib.rs crate:lib
+pub mod m {
+    /// Calls [`foo`].
+    ///
+    /// ```
+    /// # use lib::m::foo;
+    /// foo();
+    /// lib::m::foo();
+    /// other::foo();
+    /// ```
+    pub fn foo$0() {}
+}
+"#,
+            r#"
+pub mod m {
+    /// Calls [`foo`].
+    ///
+    /// ```
+    /// # use lib::m::bar;
+    /// bar();
+    /// lib::m::bar();
+    /// other::foo();
+    /// ```
+    pub fn bar() {}
+}
+"#,
+        );
+        check(
+            "type",
+            r#"
+//- /lib.rs crate:lib
+/// ```
+/// lib::foo();
+/// ```
+pub fn foo$0() {}
+"#,
+            r#"
+/// ```
+/// lib::r#type();
+/// ```
+pub fn r#type() {}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_rename_textual_occurrences() {
+        check_textual_occurrences(
+            "Bar",
+            r#"
+/// Creates a [`Foo`], unlike `Foos` or `FooBar`.
+struct Foo$0;
+fn f() -> &'static str {
+    // Foo::new is not a Foo.
+    "Foo"
+}
+"#,
+            expect![[r#"
+                /// Creates a [`Bar`], unlike `Foos` or `FooBar`.
+                struct Foo;
+                fn f() -> &'static str {
+                    // Bar::new is not a Bar.
+                    "Bar"
+                }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_rename_preview() {
+        check_preview(
+            "bar",
+            r#"
+#[derive(serde::Serialize)]
+struct Foo { foo$0: u32 }
+fn f(foo: u32) -> Foo {
+    let _ = Foo { foo: 0 };
+    Foo { foo }
+}
+"#,
+            expect![[r##"
+                FileId(0) Definition "foo" -> "bar"
+                FileId(0) Reference "foo" -> "bar"
+                FileId(0) ShorthandExpansion "" -> "bar: "
+                FileId(0) AttributeUpdate "" -> "#[serde(rename = \"foo\")] "
+            "##]],
+        );
+        check_preview(
+            "bar",
+            r#"
+//- /main.rs
+mod foo$0;
+fn f() { foo::g() }
+//- /foo.rs
+pub fn g() {}
+"#,
+            expect![[r#"
+                FileId(0) Definition "foo" -> "bar"
+                FileId(0) Reference "foo" -> "bar"
+                FileId(1) FileMove "foo.rs" -> "bar.rs"
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_rename_crate() {
+        check_rename_crate(
+            "baz",
+            r#"
+//- /foo/src/lib.rs crate:foo env:CARGO_MANIFEST_DIR=/foo
+pub fn bar$0() {}
+#[macro_export]
+macro_rules! m { () => { $crate::bar() } }
+//- /main/src/main.rs crate:main deps:foo env:CARGO_MANIFEST_DIR=/main
+extern crate foo;
+use foo::bar;
+use ::foo as aliased;
+macro_rules! call_bar { ($foo:ident) => { ::foo::bar(); $foo::bar() } }
+fn main() {
+    foo::bar();
+    aliased::bar();
+    foo::m!();
+}
+//- /other/src/lib.rs crate:renamed deps:foo
+use foo::bar;
+"#,
+            &[
+                (
+                    "FileId(0)/../Cargo.toml",
+                    r#"
+[package]
+name = "foo"
+version = "0.1.0"
+"#,
+                ),
+                (
+                    "FileId(1)/../Cargo.toml",
+                    r#"
+[package]
+name = "main"
+
+[dependencies]
+foo = { path = "../foo" }
+other-name = { path = "../foo", package = "foo" }
+"#,
+                ),
+            ],
+            expect![[r#"
+                FileId(1)
+                extern crate baz;
+                use baz::bar;
+                use ::baz as aliased;
+                macro_rules! call_bar { ($foo:ident) => { ::baz::bar(); $foo::bar() } }
+                fn main() {
+                    baz::bar();
+                    aliased::bar();
+                    baz::m!();
+                }
+
+                FileId(2)
+                use baz::bar;
+
+                FileId(0)/../Cargo.toml
+
+                [package]
+                name = "baz"
+                version = "0.1.0"
+
+                FileId(1)/../Cargo.toml
+
+                [package]
+                name = "main"
+
+                [dependencies]
+                baz = { path = "../foo" }
+                other-name = { path = "../foo", package = "baz" }
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_rename_crate_shared_manifest() {
+        check_rename_crate(
+            "baz",
+            r#"
+//- /foo/src/lib.rs crate:foo env:CARGO_MANIFEST_DIR=/foo
+pub fn bar$0() {}
+//- /foo/src/main.rs crate:main deps:foo env:CARGO_MANIFEST_DIR=/foo
+use foo::bar;
+"#,
+            &[(
+                "FileId(0)/../Cargo.toml",
+                r#"
+[package]
+name = "foo"
+
+[lib]
+name = "foo"
+
+[dev-dependencies.foo]
+path = "."
+"#,
+            )],
+            expect![[r#"
+                FileId(1)
+                use baz::bar;
+
+                FileId(0)/../Cargo.toml
+
+                [package]
+                name = "baz"
+
+                [lib]
+                name = "baz"
+
+                [dev-dependencies.baz]
+                path = "."
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_rename_crate_invalid_name() {
+        let fixture = r#"
+//- /foo/src/lib.rs crate:foo
+pub fn bar$0() {}
+//- /main/src/main.rs crate:main deps:foo
+use foo::bar;
+"#;
+        check_rename_crate(
+            "type",
+            fixture,
+            &[],
+            expect!["error: Invalid name `type`: `type` is not a valid Cargo package name"],
+        );
+        check_rename_crate(
+            "r#async",
+            fixture,
+            &[],
+            expect!["error: Invalid name `r#async`: `r#async` is not a valid Cargo package name"],
+        );
+        check_rename_crate(
+            "test",
+            fixture,
+            &[],
+            expect!["error: Invalid name `test`: `test` is not a valid Cargo package name"],
+        );
+        check_rename_crate(
+            "1foo",
+            fixture,
+            &[],
+            expect!["error: Invalid name `1foo`: `1foo` is not a valid Cargo package name"],
+        );
+    }
+
     #[test]
     fn test_rename_mod_to_raw_ident() {
         check_expect(
@@ -1330,10 +2152,12 @@ pub fn baz() {}
                                 Indel {
                                     insert: "r#fn",
                                     delete: 4..7,
+                                    annotation: None,
                                 },
                                 Indel {
                                     insert: "r#fn",
                                     delete: 22..25,
+                                    annotation: None,
                                 },
                             ],
                         },
@@ -1369,6 +2193,16 @@ pub fn baz() {}
                         },
                     ],
                     is_snippet: false,
+                    snippet_edits: {},
+                    other_file_edits: [],
+                    undo_edits: {},
+                    annotations: {},
+                    kind: Some(
+                        Rename,
+                    ),
+                    origin: Some(
+                        "rename",
+                    ),
                 }
             "#]],
         );
@@ -1400,10 +2234,12 @@ pub fn baz() {}
                                 Indel {
                                     insert: "foo",
                                     delete: 4..8,
+                                    annotation: None,
                                 },
                                 Indel {
                                     insert: "foo",
                                     delete: 23..27,
+                                    annotation: None,
                                 },
                             ],
                         },
@@ -1439,6 +2275,16 @@ pub fn baz() {}
                         },
                     ],
                     is_snippet: false,
+                    snippet_edits: {},
+                    other_file_edits: [],
+                    undo_edits: {},
+                    annotations: {},
+                    kind: Some(
+                        Rename,
+                    ),
+                    origin: Some(
+                        "rename",
+                    ),
                 }
             "#]],
         );
@@ -2095,6 +2941,88 @@ fn foo() {
         )
     }
 
+    #[test]
+    fn test_rename_serialized_field() {
+        check(
+            "baz",
+            r#"
+#[derive(Serialize, Deserialize)]
+struct Foo {
+    bar$0: u32,
+}
+fn foo(foo: Foo) -> u32 { foo.bar }
+"#,
+            r#"
+#[derive(Serialize, Deserialize)]
+struct Foo {
+    #[serde(rename = "bar")]
+    baz: u32,
+}
+fn foo(foo: Foo) -> u32 { foo.baz }
+"#,
+        );
+        check(
+            "baz",
+            r#"
+#[derive(serde::Serialize)]
+struct Foo {
+    /// Docs.
+    #[serde(rename = "BAR")]
+    pub bar$0: u32,
+}
+"#,
+            r#"
+#[derive(serde::Serialize)]
+struct Foo {
+    /// Docs.
+    #[serde(rename = "BAR")]
+    pub baz: u32,
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_rename_serialized_field_rename_all() {
+        check(
+            "new_name",
+            r#"
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Foo {
+    pub old_name$0: u32,
+}
+"#,
+            r#"
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Foo {
+    #[serde(rename = "oldName")]
+    pub new_name: u32,
+}
+"#,
+        );
+        check(
+            "new_name",
+            r#"
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Foo {
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    Bar { old_name$0: u32 },
+}
+"#,
+            r#"
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Foo {
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    Bar { #[serde(rename = "OLD_NAME")] new_name: u32 },
+}
+"#,
+        );
+    }
+
     #[test]
     fn test_rename_tuple_field() {
         check(
@@ -2278,6 +3206,62 @@ fn foobar() {}
         );
     }
 
+    #[test]
+    fn test_rename_trait_method_propagates_to_impls() {
+        check(
+            "baz",
+            r#"
+trait Foo {
+    fn bar$0(&self) -> u32;
+}
+struct S;
+impl Foo for S {
+    fn bar(&self) -> u32 { 0 }
+}
+macro_rules! impl_foo {
+    ($t:ty, $name:ident) => { impl Foo for $t { fn $name(&self) -> u32 { 1 } } }
+}
+impl_foo!(u32, bar);
+fn f<T: Foo>(t: &T) -> u32 {
+    t.bar() + <T as Foo>::bar(t) + T::bar(t) + Foo::bar(t) + <S as Foo>::bar(&S) + S.bar()
+}
+"#,
+            r#"
+trait Foo {
+    fn baz(&self) -> u32;
+}
+struct S;
+impl Foo for S {
+    fn baz(&self) -> u32 { 0 }
+}
+macro_rules! impl_foo {
+    ($t:ty, $name:ident) => { impl Foo for $t { fn $name(&self) -> u32 { 1 } } }
+}
+impl_foo!(u32, baz);
+fn f<T: Foo>(t: &T) -> u32 {
+    t.baz() + <T as Foo>::baz(t) + T::baz(t) + Foo::baz(t) + <S as Foo>::baz(&S) + S.baz()
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_rename_trait_method_implemented_by_macro() {
+        check(
+            "baz",
+            r#"
+trait Foo {
+    fn bar$0(&self);
+}
+macro_rules! impl_foo {
+    ($t:ty) => { impl Foo for $t { fn bar(&self) {} } }
+}
+impl_foo!(u32);
+"#,
+            "error: Cannot rename `bar` as one of its implementations is created by a macro",
+        );
+    }
+
     #[test]
     fn test_rename_trait_const() {
         let res = r"
@@ -2343,6 +3327,46 @@ fn main() { f$0()  }
         )
     }
 
+    #[test]
+    fn rename_in_macro_call_token_tree() {
+        check(
+            "bar",
+            r#"
+macro_rules! m { ($($tt:tt)*) => { $($tt)* } }
+fn foo() {}
+fn main() {
+    m! {
+        if true { foo$0() } else { m!(foo()) }
+    }
+}
+"#,
+            r#"
+macro_rules! m { ($($tt:tt)*) => { $($tt)* } }
+fn bar() {}
+fn main() {
+    m! {
+        if true { bar() } else { m!(bar()) }
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn defs_constructed_by_macros_arent_renamed() {
+        check(
+            "bar",
+            r#"
+//- proc_macros: shorten
+fn main() {
+    let proc_macros::shorten!(foo) = 0;
+    f$0;
+}
+"#,
+            "error: Cannot rename local that is constructed by a macro",
+        )
+    }
+
     #[test]
     fn attributed_item() {
         check(
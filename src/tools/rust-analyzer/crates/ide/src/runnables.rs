@@ -8,7 +8,9 @@
     base_db::{FilePosition, FileRange},
     defs::Definition,
     helpers::visit_file_defs,
+    rust_doc::has_runnable_doc_test,
     search::SearchScope,
+    test_index::{self, find_harness},
     FxHashMap, FxHashSet, RootDatabase, SymbolKind,
 };
 use itertools::Itertools;
@@ -323,11 +325,13 @@ pub(crate) fn runnable_fn(
             canonical_path.map(TestId::Path).unwrap_or(TestId::Name(name))
         };
 
-        if test_related_attribute(&func.value).is_some() {
+        let harness = find_harness(sema.db, &def.attrs(sema.db)).map(|it| it.kind);
+        if harness == Some(test_index::RunnableKind::Bench) {
+            RunnableKind::Bench { test_id: test_id() }
+        } else if harness.is_some() || test_related_attribute(&func.value).is_some() {
+            // Attributes looking like the ones of tests are assumed to be of unknown harnesses.
             let attr = TestAttr::from_fn(&func.value);
             RunnableKind::Test { test_id: test_id(), attr }
-        } else if func.value.has_atom_attr("bench") {
-            RunnableKind::Bench { test_id: test_id() }
         } else {
             return None;
         }
@@ -496,34 +500,6 @@ fn from_fn(fn_def: &ast::Fn) -> TestAttr {
     }
 }
 
-const RUSTDOC_FENCES: [&str; 2] = ["```", "~~~"];
-const RUSTDOC_CODE_BLOCK_ATTRIBUTES_RUNNABLE: &[&str] =
-    &["", "rust", "should_panic", "edition2015", "edition2018", "edition2021"];
-
-fn has_runnable_doc_test(attrs: &hir::Attrs) -> bool {
-    attrs.docs().map_or(false, |doc| {
-        let mut in_code_block = false;
-
-        for line in String::from(doc).lines() {
-            if let Some(header) =
-                RUSTDOC_FENCES.into_iter().find_map(|fence| line.strip_prefix(fence))
-            {
-                in_code_block = !in_code_block;
-
-                if in_code_block
-                    && header
-                        .split(',')
-                        .all(|sub| RUSTDOC_CODE_BLOCK_ATTRIBUTES_RUNNABLE.contains(&sub.trim()))
-                {
-                    return true;
-                }
-            }
-        }
-
-        false
-    })
-}
-
 // We could create runnables for modules with number_of_test_submodules > 0,
 // but that bloats the runnables for no real benefit, since all tests can be run by the submodule already
 fn has_test_function_or_multiple_test_submodules(
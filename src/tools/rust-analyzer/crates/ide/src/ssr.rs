@@ -1,6 +1,5 @@
-//! This module provides an SSR assist. It is not desirable to include this
-//! assist in ide_assists because that would require the ide_assists crate
-//! depend on the ide_ssr crate.
+//! This module provides an SSR assist, applying the structural search replace
+//! rule written in a comment to the file or to the whole workspace.
 
 use ide_assists::{Assist, AssistId, AssistKind, AssistResolveStrategy, GroupLabel};
 use ide_db::{base_db::FileRange, label::Label, source_change::SourceChange, RootDatabase};
@@ -12,7 +11,7 @@ pub(crate) fn ssr_assists(
 ) -> Vec<Assist> {
     let mut ssr_assists = Vec::with_capacity(2);
 
-    let (match_finder, comment_range) = match ide_ssr::ssr_from_comment(db, frange) {
+    let (match_finder, comment_range) = match ide_db::ssr::ssr_from_comment(db, frange) {
         Some(ssr_data) => ssr_data,
         None => return ssr_assists,
     };
@@ -26,7 +25,7 @@ pub(crate) fn ssr_assists(
             SourceChange::from_text_edit(frange.file_id, text_edit_for_file)
         };
 
-        let source_change_for_workspace = SourceChange::from(match_finder.edits());
+        let source_change_for_workspace = match_finder.source_change();
 
         (Some(source_change_for_file), Some(source_change_for_workspace))
     } else {
@@ -131,12 +130,19 @@ fn bar() { 2 }
                                     Indel {
                                         insert: "3",
                                         delete: 33..34,
+                                        annotation: None,
                                     },
                                 ],
                             },
                         },
                         file_system_edits: [],
                         is_snippet: false,
+                        snippet_edits: {},
+                        other_file_edits: [],
+                        undo_edits: {},
+                        annotations: {},
+                        kind: None,
+                        origin: None,
                     },
                 ),
                 trigger_signature_help: false,
@@ -168,6 +174,7 @@ fn bar() { 2 }
                                     Indel {
                                         insert: "3",
                                         delete: 33..34,
+                                        annotation: None,
                                     },
                                 ],
                             },
@@ -178,12 +185,19 @@ fn bar() { 2 }
                                     Indel {
                                         insert: "3",
                                         delete: 11..12,
+                                        annotation: None,
                                     },
                                 ],
                             },
                         },
                         file_system_edits: [],
                         is_snippet: false,
+                        snippet_edits: {},
+                        other_file_edits: [],
+                        undo_edits: {},
+                        annotations: {},
+                        kind: None,
+                        origin: None,
                     },
                 ),
                 trigger_signature_help: false,
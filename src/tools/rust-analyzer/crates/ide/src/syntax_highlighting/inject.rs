@@ -5,7 +5,12 @@
 use either::Either;
 use hir::{InFile, Semantics};
 use ide_db::{
-    active_parameter::ActiveParameter, base_db::FileId, defs::Definition, rust_doc::is_rust_fence,
+    active_parameter::ActiveParameter,
+    base_db::FileId,
+    defs::Definition,
+    rust_doc::{
+        doc_attributes, extract_definitions_from_docs, is_rust_fence, resolve_doc_path_for_def,
+    },
     SymbolKind,
 };
 use syntax::{
@@ -14,7 +19,6 @@
 };
 
 use crate::{
-    doc_links::{doc_attributes, extract_definitions_from_docs, resolve_doc_path_for_def},
     syntax_highlighting::{highlights::Highlights, injector::Injector, HighlightConfig},
     Analysis, HlMod, HlRange, HlTag, RootDatabase,
 };
@@ -87,7 +87,7 @@ fn memusage_linux() -> MemoryUsage {
     }
 }
 
-#[derive(Default, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[derive(Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 pub struct Bytes(isize);
 
 impl Bytes {
@@ -97,6 +97,10 @@ pub fn new(bytes: isize) -> Bytes {
 }
 
 impl Bytes {
+    pub fn bytes(self) -> isize {
+        self.0
+    }
+
     pub fn megabytes(self) -> isize {
         self.0 / 1024 / 1024
     }
@@ -125,6 +129,13 @@ fn add_assign(&mut self, x: usize) {
     }
 }
 
+impl std::ops::Add for Bytes {
+    type Output = Bytes;
+    fn add(self, rhs: Bytes) -> Bytes {
+        Bytes(self.0 + rhs.0)
+    }
+}
+
 impl std::ops::Sub for Bytes {
     type Output = Bytes;
     fn sub(self, rhs: Bytes) -> Bytes {
@@ -10,6 +10,7 @@
 mod ssr;
 mod lsif;
 mod scip;
+mod symbol_index;
 
 mod progress_report;
 
@@ -2,7 +2,7 @@
 #![allow(unreachable_pub)]
 use std::{path::PathBuf, str::FromStr};
 
-use ide_ssr::{SsrPattern, SsrRule};
+use ide_db::ssr::{SsrPattern, SsrRule};
 
 use crate::cli::Verbosity;
 
@@ -124,6 +124,17 @@
             /// The output path where the SCIP file will be written to. Defaults to `index.scip`.
             optional --output path: PathBuf
         }
+
+        /// Dump the symbols of the workspace for external code search tools.
+        cmd symbol-index {
+            required path: PathBuf
+
+            /// The format of the dump, `json` (the default) or `scip`.
+            optional --format format: SymbolIndexFormat
+            /// The output path where the dump will be written to. Defaults to stdout for JSON and
+            /// `symbols.scip` for SCIP.
+            optional --output path: PathBuf
+        }
     }
 }
 
@@ -152,6 +163,7 @@ pub enum RustAnalyzerCmd {
     Search(Search),
     Lsif(Lsif),
     Scip(Scip),
+    SymbolIndex(SymbolIndex),
 }
 
 #[derive(Debug)]
@@ -226,6 +238,14 @@ pub struct Scip {
     pub output: Option<PathBuf>,
 }
 
+#[derive(Debug)]
+pub struct SymbolIndex {
+    pub path: PathBuf,
+
+    pub format: Option<SymbolIndexFormat>,
+    pub output: Option<PathBuf>,
+}
+
 impl RustAnalyzer {
     #[allow(dead_code)]
     pub fn from_env_or_exit() -> Self {
@@ -249,6 +269,12 @@ pub enum OutputFormat {
     Csv,
 }
 
+#[derive(Debug, PartialEq, Eq)]
+pub enum SymbolIndexFormat {
+    Json,
+    Scip,
+}
+
 impl RustAnalyzer {
     pub fn verbosity(&self) -> Verbosity {
         if self.quiet {
@@ -272,3 +298,15 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
         }
     }
 }
+
+impl FromStr for SymbolIndexFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "scip" => Ok(Self::Scip),
+            _ => Err(format!("unknown symbol index format `{s}`")),
+        }
+    }
+}
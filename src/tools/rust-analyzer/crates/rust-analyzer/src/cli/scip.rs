@@ -175,7 +175,7 @@ pub fn run(self) -> Result<()> {
     }
 }
 
-fn get_relative_filepath(
+pub(super) fn get_relative_filepath(
     vfs: &vfs::Vfs,
     rootpath: &vfs::AbsPathBuf,
     file_id: ide::FileId,
@@ -187,7 +187,7 @@ fn get_relative_filepath(
 // only encode as a vector of [start_line, start_col, end_col].
 //
 // This transforms a line index into the optimized SCIP Range.
-fn text_range_to_scip_range(line_index: &LineIndex, range: TextRange) -> Vec<i32> {
+pub(super) fn text_range_to_scip_range(line_index: &LineIndex, range: TextRange) -> Vec<i32> {
     let LineCol { line: start_line, col: start_col } = line_index.index.line_col(range.start());
     let LineCol { line: end_line, col: end_col } = line_index.index.line_col(range.end());
 
@@ -1,6 +1,6 @@
 //! Applies structured search replace rules from the command line.
 
-use ide_ssr::MatchFinder;
+use ide_db::ssr::MatchFinder;
 use project_model::{CargoConfig, RustLibSource};
 
 use crate::cli::{
@@ -11,7 +11,6 @@
 
 impl flags::Ssr {
     pub fn run(self) -> Result<()> {
-        use ide_db::base_db::SourceDatabaseExt;
         let mut cargo_config = CargoConfig::default();
         cargo_config.sysroot = Some(RustLibSource::Discover);
         let load_cargo_config = LoadCargoConfig {
@@ -30,11 +29,9 @@ pub fn run(self) -> Result<()> {
         for rule in self.rule {
             match_finder.add_rule(rule)?;
         }
-        let edits = match_finder.edits();
-        for (file_id, edit) in edits {
+        let applied = match_finder.source_change().apply(db);
+        for (file_id, contents) in applied.file_texts {
             if let Some(path) = vfs.file_path(file_id).as_path() {
-                let mut contents = db.file_text(file_id).to_string();
-                edit.apply(&mut contents);
                 std::fs::write(path, contents)?;
             }
         }
@@ -0,0 +1,264 @@
+//! Dumps the symbol index of a workspace as JSON or SCIP.
+
+use std::{collections::BTreeMap, env, fs, io::Write, path::PathBuf, time::Instant};
+
+use hir::{HasVisibility, ModuleDef};
+use ide::{FileId, LineCol, RootDatabase, TextRange};
+use ide_db::{
+    symbol_index::{self, Query},
+    LineIndexDatabase,
+};
+use project_model::{CargoConfig, ProjectManifest, ProjectWorkspace, RustLibSource};
+use scip::types as scip_types;
+use serde::Serialize;
+
+use crate::{
+    cli::{
+        flags::{self, SymbolIndexFormat},
+        load_cargo::{load_workspace, LoadCargoConfig, ProcMacroServerChoice},
+        scip::{get_relative_filepath, text_range_to_scip_range},
+        Result,
+    },
+    line_index::{LineEndings, LineIndex, PositionEncoding},
+};
+
+/// A symbol of the index, as written to the JSON dump.
+#[derive(Serialize)]
+struct SymbolEntry {
+    name: String,
+    kind: &'static str,
+    container: Option<String>,
+    /// The path of the file relative to the workspace root.
+    file: String,
+    range: Range,
+    visibility: &'static str,
+    /// The first paragraph of the symbol's documentation.
+    docs: Option<String>,
+    #[serde(skip)]
+    scip_symbol: String,
+    #[serde(skip)]
+    file_id: FileId,
+    /// The range of the name, or of the whole declaration if the name doesn't map to the file.
+    #[serde(skip)]
+    text_range: TextRange,
+}
+
+/// Zero-based lines and UTF-8 columns.
+#[derive(Serialize)]
+struct Range {
+    start: Position,
+    end: Position,
+}
+
+#[derive(Serialize)]
+struct Position {
+    line: u32,
+    col: u32,
+}
+
+impl flags::SymbolIndex {
+    pub fn run(self) -> Result<()> {
+        eprintln!("Generating symbol index start...");
+        let now = Instant::now();
+        let mut cargo_config = CargoConfig::default();
+        cargo_config.sysroot = Some(RustLibSource::Discover);
+
+        let no_progress = &|s| (eprintln!("rust-analyzer: Loading {s}"));
+        let load_cargo_config = LoadCargoConfig {
+            load_out_dirs_from_check: true,
+            with_proc_macro_server: ProcMacroServerChoice::Sysroot,
+            prefill_caches: false,
+        };
+        let path = vfs::AbsPathBuf::assert(env::current_dir()?.join(&self.path));
+        let rootpath = path.normalize();
+        let manifest = ProjectManifest::discover_single(&path)?;
+
+        let workspace = ProjectWorkspace::load(manifest, &cargo_config, no_progress)?;
+
+        let (host, vfs, _) =
+            load_workspace(workspace, &cargo_config.extra_env, &load_cargo_config)?;
+        let db = host.raw_database();
+
+        // An empty query matches every symbol of the workspace.
+        let mut entries = Vec::new();
+        for symbol in symbol_index::world_symbols(db, Query::new(String::new())) {
+            let full_range = symbol.loc.original_range(db);
+            let Some(file) = get_relative_filepath(&vfs, &rootpath, full_range.file_id) else {
+                continue;
+            };
+            let text_range = symbol
+                .loc
+                .original_name_range(db)
+                .filter(|it| it.file_id == full_range.file_id)
+                .map_or(full_range.range, |it| it.range);
+            let line_index = db.line_index(full_range.file_id);
+            let position = |line_col: LineCol| Position { line: line_col.line, col: line_col.col };
+            entries.push(SymbolEntry {
+                name: symbol.name.to_string(),
+                kind: symbol_kind(symbol.def),
+                container: symbol.container_name.as_ref().map(ToString::to_string),
+                file,
+                range: Range {
+                    start: position(line_index.line_col(full_range.range.start())),
+                    end: position(line_index.line_col(full_range.range.end())),
+                },
+                visibility: match symbol.def.visibility(db) {
+                    hir::Visibility::Public => "public",
+                    hir::Visibility::Module(_) => "restricted",
+                },
+                docs: docs_summary(db, symbol.def),
+                scip_symbol: scip_symbol(db, symbol.def, &symbol.name),
+                file_id: full_range.file_id,
+                text_range,
+            });
+        }
+        entries
+            .sort_by(|a, b| (&a.file, a.text_range.start()).cmp(&(&b.file, b.text_range.start())));
+
+        match self.format.unwrap_or(SymbolIndexFormat::Json) {
+            SymbolIndexFormat::Json => {
+                let json = serde_json::to_string_pretty(&entries)?;
+                match self.output {
+                    Some(output) => fs::write(output, json)?,
+                    None => writeln!(std::io::stdout(), "{json}")?,
+                }
+            }
+            SymbolIndexFormat::Scip => {
+                let index = scip_index(db, &rootpath, entries)?;
+                let out_path = self.output.unwrap_or_else(|| PathBuf::from(r"symbols.scip"));
+                scip::write_message_to_file(out_path, index)
+                    .map_err(|err| anyhow::anyhow!("Failed to write scip to file: {}", err))?;
+            }
+        }
+
+        eprintln!("Generating symbol index finished {:?}", now.elapsed());
+        Ok(())
+    }
+}
+
+/// Builds a SCIP index with a definition occurrence and the symbol information of every symbol.
+fn scip_index(
+    db: &RootDatabase,
+    rootpath: &vfs::AbsPathBuf,
+    entries: Vec<SymbolEntry>,
+) -> Result<scip_types::Index> {
+    let mut documents: BTreeMap<String, scip_types::Document> = BTreeMap::new();
+    for entry in entries {
+        let line_index = LineIndex {
+            index: db.line_index(entry.file_id),
+            encoding: PositionEncoding::Utf8,
+            endings: LineEndings::Unix,
+        };
+        let document =
+            documents.entry(entry.file.clone()).or_insert_with(|| scip_types::Document {
+                relative_path: entry.file.clone(),
+                language: "rust".to_string(),
+                ..Default::default()
+            });
+        document.occurrences.push(scip_types::Occurrence {
+            range: text_range_to_scip_range(&line_index, entry.text_range),
+            symbol: entry.scip_symbol.clone(),
+            symbol_roles: scip_types::SymbolRole::Definition as i32,
+            ..Default::default()
+        });
+        document.symbols.push(scip_types::SymbolInformation {
+            symbol: entry.scip_symbol,
+            documentation: entry.docs.into_iter().collect(),
+            ..Default::default()
+        });
+    }
+
+    let metadata = scip_types::Metadata {
+        version: scip_types::ProtocolVersion::UnspecifiedProtocolVersion.into(),
+        tool_info: Some(scip_types::ToolInfo {
+            name: "rust-analyzer".to_owned(),
+            version: "0.1".to_owned(),
+            ..Default::default()
+        })
+        .into(),
+        project_root: format!(
+            "file://{}",
+            rootpath
+                .as_os_str()
+                .to_str()
+                .ok_or(anyhow::anyhow!("Unable to normalize project_root path"))?
+        ),
+        text_document_encoding: scip_types::TextEncoding::UTF8.into(),
+        special_fields: Default::default(),
+    };
+    Ok(scip_types::Index {
+        metadata: Some(metadata).into(),
+        documents: documents.into_values().collect(),
+        ..Default::default()
+    })
+}
+
+/// The SCIP symbol of a definition, made up of its crate, module path and name.
+fn scip_symbol(db: &RootDatabase, def: ModuleDef, name: &str) -> String {
+    use scip_types::descriptor::Suffix;
+
+    let descriptor = |name: String, suffix: Suffix| scip_types::Descriptor {
+        name,
+        suffix: suffix.into(),
+        ..Default::default()
+    };
+    let krate = def.module(db).map(|it| it.krate());
+    let mut descriptors: Vec<_> = def
+        .canonical_module_path(db)
+        .into_iter()
+        .flatten()
+        .filter_map(|it| it.name(db))
+        .map(|it| descriptor(it.display(db).to_string(), Suffix::Namespace))
+        .collect();
+    let suffix = match def {
+        ModuleDef::Module(_) => Suffix::Namespace,
+        ModuleDef::Function(_) => Suffix::Method,
+        ModuleDef::Adt(_)
+        | ModuleDef::Trait(_)
+        | ModuleDef::TraitAlias(_)
+        | ModuleDef::TypeAlias(_)
+        | ModuleDef::BuiltinType(_) => Suffix::Type,
+        ModuleDef::Variant(_) | ModuleDef::Const(_) | ModuleDef::Static(_) => Suffix::Term,
+        ModuleDef::Macro(_) => Suffix::Macro,
+    };
+    descriptors.push(descriptor(name.to_owned(), suffix));
+
+    scip::symbol::format_symbol(scip_types::Symbol {
+        scheme: "rust-analyzer".into(),
+        package: Some(scip_types::Package {
+            manager: "cargo".to_string(),
+            name: krate
+                .and_then(|it| it.display_name(db))
+                .map_or_else(String::new, |it| it.to_string()),
+            version: krate.and_then(|it| it.version(db)).unwrap_or_else(|| ".".to_string()),
+            ..Default::default()
+        })
+        .into(),
+        descriptors,
+        ..Default::default()
+    })
+}
+
+fn symbol_kind(def: ModuleDef) -> &'static str {
+    match def {
+        ModuleDef::Module(_) => "module",
+        ModuleDef::Function(_) => "function",
+        ModuleDef::Adt(hir::Adt::Struct(_)) => "struct",
+        ModuleDef::Adt(hir::Adt::Enum(_)) => "enum",
+        ModuleDef::Adt(hir::Adt::Union(_)) => "union",
+        ModuleDef::Variant(_) => "variant",
+        ModuleDef::Const(_) => "const",
+        ModuleDef::Static(_) => "static",
+        ModuleDef::Trait(_) => "trait",
+        ModuleDef::TraitAlias(_) => "trait_alias",
+        ModuleDef::TypeAlias(_) => "type_alias",
+        ModuleDef::BuiltinType(_) => "builtin_type",
+        ModuleDef::Macro(_) => "macro",
+    }
+}
+
+fn docs_summary(db: &RootDatabase, def: ModuleDef) -> Option<String> {
+    let docs = def.attrs(db)?.docs()?;
+    let summary = docs.as_str().split("\n\n").next()?.trim();
+    (!summary.is_empty()).then(|| summary.to_owned())
+}
@@ -17,7 +17,10 @@
     JoinLinesConfig, MemoryLayoutHoverConfig, MemoryLayoutHoverRenderKind, Snippet, SnippetScope,
 };
 use ide_db::{
-    imports::insert_use::{ImportGranularity, InsertUseConfig, PrefixKind},
+    imports::insert_use::{
+        ImportGranularity, ImportGroupStyle, ImportLocation, ImportPrefs, ImportPrefsOverride,
+        PrefixKind,
+    },
     SnippetCap,
 };
 use itertools::Itertools;
@@ -328,12 +331,20 @@ struct ConfigData {
         /// How to render the size information in a memory layout hover.
         hover_memoryLayout_size: Option<MemoryLayoutHoverRenderKindDef> = "\"both\"",
 
+        /// Overrides of the import settings for individual crates, keyed by crate name, e.g.
+        /// `{ "vendored_crate": { "granularity": "item", "prefix": "crate" } }`. The overridable
+        /// settings are `granularity`, `prefix`, `group`, `preferNoStd` and `mergeGlob`.
+        imports_crateOverrides: FxHashMap<String, ImportPrefsOverrideDef> = "{}",
         /// Whether to enforce the import granularity setting for all files. If set to false rust-analyzer will try to keep import styles consistent per file.
         imports_granularity_enforce: bool              = "false",
         /// How imports should be grouped into use statements.
         imports_granularity_group: ImportGranularityDef  = "\"crate\"",
         /// Group inserted imports by the https://rust-analyzer.github.io/manual.html#auto-import[following order]. Groups are separated by newlines.
         imports_group_enable: bool                           = "true",
+        /// Which groups to sort inserted imports into when grouping them.
+        imports_group_style: ImportGroupStyleDef             = "\"path_kind\"",
+        /// Where to insert imports.
+        imports_location: ImportLocationDef                  = "\"module\"",
         /// Whether to allow import insertion to merge new imports into single path glob imports like `use std::fmt::*;`.
         imports_merge_glob: bool           = "true",
         /// Prefer to unconditionally use imports of the core and alloc crate, over the std crate.
@@ -1087,8 +1098,7 @@ pub fn diagnostics(&self) -> DiagnosticsConfig {
                 ExprFillDefaultDef::Todo => ExprFillDefaultMode::Todo,
                 ExprFillDefaultDef::Default => ExprFillDefaultMode::Default,
             },
-            insert_use: self.insert_use_config(),
-            prefer_no_std: self.data.imports_prefer_no_std,
+            imports: self.import_prefs(None),
         }
     }
 
@@ -1392,22 +1402,33 @@ pub fn inlay_hints(&self) -> InlayHintsConfig {
         }
     }
 
-    fn insert_use_config(&self) -> InsertUseConfig {
-        InsertUseConfig {
-            granularity: match self.data.imports_granularity_group {
-                ImportGranularityDef::Preserve => ImportGranularity::Preserve,
-                ImportGranularityDef::Item => ImportGranularity::Item,
-                ImportGranularityDef::Crate => ImportGranularity::Crate,
-                ImportGranularityDef::Module => ImportGranularity::Module,
-            },
+    /// The import preferences, with the overrides for the crate named `krate` applied.
+    pub fn import_prefs(&self, krate: Option<&str>) -> ImportPrefs {
+        let prefs = ImportPrefs {
+            granularity: import_granularity(&self.data.imports_granularity_group),
             enforce_granularity: self.data.imports_granularity_enforce,
-            prefix_kind: match self.data.imports_prefix {
-                ImportPrefixDef::Plain => PrefixKind::Plain,
-                ImportPrefixDef::ByCrate => PrefixKind::ByCrate,
-                ImportPrefixDef::BySelf => PrefixKind::BySelf,
-            },
+            prefix_kind: import_prefix_kind(&self.data.imports_prefix),
             group: self.data.imports_group_enable,
+            group_style: match self.data.imports_group_style {
+                ImportGroupStyleDef::PathKind => ImportGroupStyle::PathKind,
+                ImportGroupStyleDef::StdExternalCrate => ImportGroupStyle::StdExternalCrate,
+            },
             skip_glob_imports: !self.data.imports_merge_glob,
+            location: match self.data.imports_location {
+                ImportLocationDef::Module => ImportLocation::Module,
+                ImportLocationDef::Block => ImportLocation::Block,
+            },
+            prefer_no_std: self.data.imports_prefer_no_std,
+        };
+        match krate.and_then(|it| self.data.imports_crateOverrides.get(it)) {
+            Some(def) => prefs.with_override(&ImportPrefsOverride {
+                granularity: def.granularity.as_ref().map(import_granularity),
+                prefix_kind: def.prefix.as_ref().map(import_prefix_kind),
+                group: def.group,
+                prefer_no_std: def.prefer_no_std,
+                skip_glob_imports: def.merge_glob.map(|it| !it),
+            }),
+            None => prefs,
         }
     }
 
@@ -1423,8 +1444,7 @@ pub fn completion(&self) -> CompletionConfig {
                 CallableCompletionDef::AddParentheses => Some(CallableSnippets::AddParentheses),
                 CallableCompletionDef::None => None,
             },
-            insert_use: self.insert_use_config(),
-            prefer_no_std: self.data.imports_prefer_no_std,
+            imports: self.import_prefs(None),
             snippet_cap: SnippetCap::new(try_or_def!(
                 self.caps
                     .text_document
@@ -1452,8 +1472,7 @@ pub fn assist(&self) -> AssistConfig {
         AssistConfig {
             snippet_cap: SnippetCap::new(self.experimental("snippetTextEdit")),
             allowed: None,
-            insert_use: self.insert_use_config(),
-            prefer_no_std: self.data.imports_prefer_no_std,
+            imports: self.import_prefs(None),
             assist_emit_must_use: self.data.assist_emitMustUse,
         }
     }
@@ -1823,6 +1842,34 @@ enum ExprFillDefaultDef {
     Default,
 }
 
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default, rename_all = "camelCase")]
+struct ImportPrefsOverrideDef {
+    granularity: Option<ImportGranularityDef>,
+    prefix: Option<ImportPrefixDef>,
+    group: Option<bool>,
+    prefer_no_std: Option<bool>,
+    merge_glob: Option<bool>,
+}
+
+fn import_granularity(def: &ImportGranularityDef) -> ImportGranularity {
+    match def {
+        ImportGranularityDef::Preserve => ImportGranularity::Preserve,
+        ImportGranularityDef::Item => ImportGranularity::Item,
+        ImportGranularityDef::Crate => ImportGranularity::Crate,
+        ImportGranularityDef::Module => ImportGranularity::Module,
+        ImportGranularityDef::One => ImportGranularity::One,
+    }
+}
+
+fn import_prefix_kind(def: &ImportPrefixDef) -> PrefixKind {
+    match def {
+        ImportPrefixDef::Plain => PrefixKind::Plain,
+        ImportPrefixDef::ByCrate => PrefixKind::ByCrate,
+        ImportPrefixDef::BySelf => PrefixKind::BySelf,
+    }
+}
+
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 enum ImportGranularityDef {
@@ -1830,6 +1877,21 @@ enum ImportGranularityDef {
     Item,
     Crate,
     Module,
+    One,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+enum ImportGroupStyleDef {
+    PathKind,
+    StdExternalCrate,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+enum ImportLocationDef {
+    Module,
+    Block,
 }
 
 #[derive(Deserialize, Debug, Copy, Clone)]
@@ -2130,6 +2192,9 @@ macro_rules! set {
         "FxHashMap<String, SnippetDef>" => set! {
             "type": "object",
         },
+        "FxHashMap<String, ImportPrefsOverrideDef>" => set! {
+            "type": "object",
+        },
         "FxHashMap<String, String>" => set! {
             "type": "object",
         },
@@ -2163,12 +2228,29 @@ macro_rules! set {
         },
         "ImportGranularityDef" => set! {
             "type": "string",
-            "enum": ["preserve", "crate", "module", "item"],
+            "enum": ["preserve", "crate", "module", "item", "one"],
             "enumDescriptions": [
                 "Do not change the granularity of any imports and preserve the original structure written by the developer.",
                 "Merge imports from the same crate into a single use statement. Conversely, imports from different crates are split into separate statements.",
                 "Merge imports from the same module into a single use statement. Conversely, imports from different modules are split into separate statements.",
-                "Flatten imports so that each has its own use statement."
+                "Flatten imports so that each has its own use statement.",
+                "Merge all imports into a single use statement as long as they have the same visibility and attributes."
+            ],
+        },
+        "ImportGroupStyleDef" => set! {
+            "type": "string",
+            "enum": ["path_kind", "std_external_crate"],
+            "enumDescriptions": [
+                "Group imports of `std` and `core`, of external crates, and the ones starting with `crate`, `self` and `super` separately.",
+                "Group imports like rustfmt's `group_imports = \"StdExternalCrate\"`: imports of `std`, `core` and `alloc`, of external crates, and of the current crate."
+            ],
+        },
+        "ImportLocationDef" => set! {
+            "type": "string",
+            "enum": ["module", "block"],
+            "enumDescriptions": [
+                "Insert imports at the top of the enclosing module.",
+                "Insert imports as the first statements of the innermost block enclosing the usage, or at the top of the enclosing module outside of blocks."
             ],
         },
         "ImportPrefixDef" => set! {
@@ -2565,4 +2647,25 @@ fn proc_macro_srv_rel() {
             Some(AbsPathBuf::try_from(project_root().join("./server")).unwrap())
         );
     }
+
+    #[test]
+    fn import_prefs_crate_overrides() {
+        let mut config =
+            Config::new(AbsPathBuf::try_from(project_root()).unwrap(), Default::default(), vec![]);
+        config
+            .update(serde_json::json!({
+                "imports": {
+                    "prefix": "crate",
+                    "crateOverrides": { "vendored": { "granularity": "item", "preferNoStd": true } }
+                }
+            }))
+            .unwrap();
+        let prefs = config.import_prefs(None);
+        assert_eq!(prefs.granularity, ImportGranularity::Crate);
+        assert!(!prefs.prefer_no_std);
+        let vendored = config.import_prefs(Some("vendored"));
+        assert_eq!(vendored.granularity, ImportGranularity::Item);
+        assert_eq!(vendored.prefix_kind, PrefixKind::ByCrate);
+        assert!(vendored.prefer_no_std);
+    }
 }
@@ -1,7 +1,7 @@
 //! See [RequestDispatcher].
 use std::{fmt, panic, thread};
 
-use ide::Cancelled;
+use ide::{Cancelled, Priority};
 use lsp_server::ExtractError;
 use serde::{de::DeserializeOwned, Serialize};
 use stdx::thread::ThreadIntent;
@@ -193,7 +193,17 @@ fn on_with_thread_intent<const MAIN_POOL: bool, R>(
             None => return self,
         };
 
-        let world = self.global_state.snapshot();
+        let priority = match intent {
+            ThreadIntent::LatencySensitive => Priority::Foreground,
+            ThreadIntent::Worker => Priority::Normal,
+        };
+        if priority == Priority::Foreground && self.global_state.prime_caches_queue.op_in_progress()
+        {
+            // Cache priming is cancelled like on a change, and the main loop restarts it once
+            // there is no foreground work left.
+            self.global_state.analysis_host.request_cancellation();
+        }
+        let world = self.global_state.snapshot_with_priority(priority);
         if MAIN_POOL {
             &mut self.global_state.task_pool.handle
         } else {
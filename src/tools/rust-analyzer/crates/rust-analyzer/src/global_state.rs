@@ -7,8 +7,11 @@
 
 use crossbeam_channel::{unbounded, Receiver, Sender};
 use flycheck::FlycheckHandle;
-use ide::{Analysis, AnalysisHost, Cancellable, Change, FileId};
-use ide_db::base_db::{CrateId, FileLoader, ProcMacroPaths, SourceDatabase};
+use ide::{Analysis, AnalysisHost, Cancellable, Change, FileId, Priority};
+use ide_db::{
+    base_db::{CrateId, FileLoader, ProcMacroPaths, SourceDatabase},
+    imports::insert_use::ImportPrefs,
+};
 use lsp_types::{SemanticTokens, Url};
 use nohash_hasher::IntMap;
 use parking_lot::{Mutex, RwLock};
@@ -336,10 +339,15 @@ pub(crate) fn process_changes(&mut self) -> bool {
     }
 
     pub(crate) fn snapshot(&self) -> GlobalStateSnapshot {
+        self.snapshot_with_priority(Priority::Normal)
+    }
+
+    /// Takes a snapshot for work of the given priority, see [`Priority`].
+    pub(crate) fn snapshot_with_priority(&self, priority: Priority) -> GlobalStateSnapshot {
         GlobalStateSnapshot {
             config: Arc::clone(&self.config),
             workspaces: Arc::clone(&self.workspaces),
-            analysis: self.analysis_host.analysis(),
+            analysis: self.analysis_host.analysis_with_priority(priority),
             vfs: Arc::clone(&self.vfs),
             check_fixes: Arc::clone(&self.diagnostics.check_fixes),
             mem_docs: self.mem_docs.clone(),
@@ -470,6 +478,15 @@ pub(crate) fn cargo_target_for_crate_root(
         })
     }
 
+    /// The import preferences for the crate `file_id` belongs to.
+    pub(crate) fn import_prefs(&self, file_id: FileId) -> Cancellable<ImportPrefs> {
+        let name = match self.analysis.crates_for(file_id)?.first() {
+            Some(&krate) => self.analysis.crate_display_name(krate)?,
+            None => None,
+        };
+        Ok(self.config.import_prefs(name.as_deref()))
+    }
+
     pub(crate) fn vfs_memory_usage(&self) -> usize {
         self.vfs.read().0.memory_usage()
     }
@@ -10,8 +10,8 @@
 use anyhow::Context;
 use ide::{
     AnnotationConfig, AssistKind, AssistResolveStrategy, Cancellable, FilePosition, FileRange,
-    HoverAction, HoverGotoTypeData, Query, RangeInfo, ReferenceCategory, Runnable, RunnableKind,
-    SingleResolve, SourceChange, TextEdit,
+    HoverAction, HoverGotoTypeData, Query, QueryScope, RangeInfo, ReferenceCategory, Runnable,
+    RunnableKind, SingleResolve, SourceChange, TextEdit,
 };
 use ide_db::SymbolKind;
 use lsp_server::ErrorCode;
@@ -454,26 +454,23 @@ pub(crate) fn handle_workspace_symbol(
     let _p = profile::span("handle_workspace_symbol");
 
     let config = snap.config.workspace_symbol();
-    let (all_symbols, libs) = decide_search_scope_and_kind(&params, &config);
+    let (all_symbols, scope) = decide_search_scope_and_kind(&params, &config);
     let limit = config.search_limit;
 
-    let query = {
-        let query: String = params.query.chars().filter(|&c| c != '#' && c != '*').collect();
-        let mut q = Query::new(query);
-        if !all_symbols {
+    let query_text: String = params.query.chars().filter(|&c| c != '#' && c != '*').collect();
+    let query = |only_types| {
+        let mut q = Query::parse(&query_text);
+        if only_types {
             q.only_types();
         }
-        if libs {
-            q.libs();
-        }
+        q.scope(scope);
         q.limit(limit);
         q
     };
-    let mut res = exec_query(&snap, query)?;
+    let mut res = exec_query(&snap, query(!all_symbols))?;
+    // Fall back to all symbols, like associated items, if there are no types of that name.
     if res.is_empty() && !all_symbols {
-        let mut query = Query::new(params.query);
-        query.limit(limit);
-        res = exec_query(&snap, query)?;
+        res = exec_query(&snap, query(false))?;
     }
 
     return Ok(Some(res));
@@ -481,10 +478,10 @@ pub(crate) fn handle_workspace_symbol(
     fn decide_search_scope_and_kind(
         params: &WorkspaceSymbolParams,
         config: &WorkspaceSymbolConfig,
-    ) -> (bool, bool) {
+    ) -> (bool, QueryScope) {
         // Support old-style parsing of markers in the query.
         let mut all_symbols = params.query.contains('#');
-        let mut libs = params.query.contains('*');
+        let libs = params.query.contains('*');
 
         // If no explicit marker was set, check request params. If that's also empty
         // use global config.
@@ -499,18 +496,24 @@ fn decide_search_scope_and_kind(
             }
         }
 
-        if !libs {
+        let scope = if libs {
+            QueryScope::Libraries
+        } else {
             let search_scope = match params.search_scope {
                 Some(ref search_scope) => search_scope,
                 None => &config.search_scope,
             };
-            libs = match search_scope {
-                lsp_ext::WorkspaceSymbolSearchScope::Workspace => false,
-                lsp_ext::WorkspaceSymbolSearchScope::WorkspaceAndDependencies => true,
+            match search_scope {
+                lsp_ext::WorkspaceSymbolSearchScope::Workspace => QueryScope::Workspace,
+                lsp_ext::WorkspaceSymbolSearchScope::WorkspaceAndDependencies => {
+                    QueryScope::Libraries
+                }
+                lsp_ext::WorkspaceSymbolSearchScope::Dependencies => QueryScope::Dependencies,
+                lsp_ext::WorkspaceSymbolSearchScope::Sysroot => QueryScope::Sysroot,
             }
-        }
+        };
 
-        (all_symbols, libs)
+        (all_symbols, scope)
     }
 
     fn exec_query(snap: &GlobalStateSnapshot, query: Query) -> Result<Vec<SymbolInformation>> {
@@ -851,9 +854,10 @@ pub(crate) fn handle_completion(
     let completion_trigger_character =
         params.context.and_then(|ctx| ctx.trigger_character).and_then(|s| s.chars().next());
 
-    let completion_config = &snap.config.completion();
+    let mut completion_config = snap.config.completion();
+    completion_config.imports = snap.import_prefs(position.file_id)?;
     let items = match snap.analysis.completions(
-        completion_config,
+        &completion_config,
         position,
         completion_trigger_character,
     )? {
@@ -893,10 +897,12 @@ pub(crate) fn handle_completion_resolve(
     let line_index = snap.file_line_index(file_id)?;
     let offset = from_proto::offset(&line_index, resolve_data.position.position)?;
 
+    let mut completion_config = snap.config.completion();
+    completion_config.imports = snap.import_prefs(file_id)?;
     let additional_edits = snap
         .analysis
         .resolve_completion_edits(
-            &snap.config.completion(),
+            &completion_config,
             FilePosition { file_id, offset },
             resolve_data
                 .imports
@@ -1110,6 +1116,7 @@ pub(crate) fn handle_code_action(
     let frange = from_proto::file_range(&snap, params.text_document.clone(), params.range)?;
 
     let mut assists_config = snap.config.assist();
+    assists_config.imports = snap.import_prefs(frange.file_id)?;
     assists_config.allowed = params
         .context
         .only
@@ -1124,9 +1131,11 @@ pub(crate) fn handle_code_action(
     } else {
         AssistResolveStrategy::All
     };
+    let mut diagnostics_config = snap.config.diagnostics();
+    diagnostics_config.imports = assists_config.imports;
     let assists = snap.analysis.assists_with_fixes(
         &assists_config,
-        &snap.config.diagnostics(),
+        &diagnostics_config,
         resolve,
         frange,
     )?;
@@ -1171,6 +1180,7 @@ pub(crate) fn handle_code_action_resolve(
     let frange = FileRange { file_id, range };
 
     let mut assists_config = snap.config.assist();
+    assists_config.imports = snap.import_prefs(file_id)?;
     assists_config.allowed = params
         .code_action_params
         .context
@@ -1191,9 +1201,11 @@ pub(crate) fn handle_code_action_resolve(
     let expected_assist_id = assist_resolve.assist_id.clone();
     let expected_kind = assist_resolve.assist_kind;
 
+    let mut diagnostics_config = snap.config.diagnostics();
+    diagnostics_config.imports = assists_config.imports;
     let assists = snap.analysis.assists_with_fixes(
         &assists_config,
-        &snap.config.diagnostics(),
+        &diagnostics_config,
         AssistResolveStrategy::Single(assist_resolve),
         frange,
     )?;
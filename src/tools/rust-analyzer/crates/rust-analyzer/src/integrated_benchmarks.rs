@@ -12,7 +12,7 @@
 
 use ide::{CallableSnippets, Change, CompletionConfig, FilePosition, TextSize};
 use ide_db::{
-    imports::insert_use::{ImportGranularity, InsertUseConfig},
+    imports::insert_use::{ImportGranularity, ImportGroupStyle, ImportLocation, ImportPrefs},
     SnippetCap,
 };
 use project_model::CargoConfig;
@@ -136,15 +136,17 @@ fn integrated_completion_benchmark() {
             enable_private_editable: true,
             callable: Some(CallableSnippets::FillArguments),
             snippet_cap: SnippetCap::new(true),
-            insert_use: InsertUseConfig {
+            imports: ImportPrefs {
                 granularity: ImportGranularity::Crate,
                 prefix_kind: hir::PrefixKind::ByCrate,
                 enforce_granularity: true,
                 group: true,
+                group_style: ImportGroupStyle::PathKind,
                 skip_glob_imports: true,
+                location: ImportLocation::Module,
+                prefer_no_std: false,
             },
             snippets: Vec::new(),
-            prefer_no_std: false,
             limit: None,
         };
         let position =
@@ -175,15 +177,17 @@ fn integrated_completion_benchmark() {
             enable_private_editable: true,
             callable: Some(CallableSnippets::FillArguments),
             snippet_cap: SnippetCap::new(true),
-            insert_use: InsertUseConfig {
+            imports: ImportPrefs {
                 granularity: ImportGranularity::Crate,
                 prefix_kind: hir::PrefixKind::ByCrate,
                 enforce_granularity: true,
                 group: true,
+                group_style: ImportGroupStyle::PathKind,
                 skip_glob_imports: true,
+                location: ImportLocation::Module,
+                prefer_no_std: false,
             },
             snippets: Vec::new(),
-            prefer_no_std: false,
             limit: None,
         };
         let position =
@@ -626,6 +626,10 @@ pub struct WorkspaceSymbolParams {
 pub enum WorkspaceSymbolSearchScope {
     Workspace,
     WorkspaceAndDependencies,
+    /// Crates outside of the workspace, excluding the sysroot.
+    Dependencies,
+    /// The sysroot crates, like `std` and `core`.
+    Sysroot,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
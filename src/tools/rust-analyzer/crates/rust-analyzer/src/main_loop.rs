@@ -386,8 +386,11 @@ fn handle_event(&mut self, event: Event) -> Result<()> {
             }
         }
 
-        if let Some((cause, ())) = self.prime_caches_queue.should_start_op() {
-            self.prime_caches(cause);
+        // Foreground requests cancel priming, so it's only restarted once they are done.
+        if !self.analysis_host.has_foreground_work() {
+            if let Some((cause, ())) = self.prime_caches_queue.should_start_op() {
+                self.prime_caches(cause);
+            }
         }
 
         self.update_status_or_notify();
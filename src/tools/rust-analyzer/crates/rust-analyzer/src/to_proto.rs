@@ -14,8 +14,9 @@
     TextEdit, TextRange, TextSize,
 };
 use itertools::Itertools;
+use rustc_hash::FxHashMap;
 use serde_json::to_value;
-use vfs::AbsPath;
+use vfs::{AbsPath, AnchoredPathBuf};
 
 use crate::{
     cargo_target_spec::CargoTargetSpec,
@@ -900,6 +901,35 @@ pub(crate) fn snippet_text_document_edit(
     Ok(lsp_ext::SnippetTextDocumentEdit { text_document, edits })
 }
 
+/// Converts the edit of a file that isn't part of the crate graph. Its text is taken from the
+/// VFS if the file is open, or read from disk otherwise.
+fn other_file_text_document_edit(
+    snap: &GlobalStateSnapshot,
+    path: &AnchoredPathBuf,
+    edit: TextEdit,
+) -> Cancellable<Option<lsp_ext::SnippetTextDocumentEdit>> {
+    let uri = snap.anchored_path(path);
+    if let Ok(file_id) = snap.url_to_file_id(&uri) {
+        return snippet_text_document_edit(snap, false, file_id, edit).map(Some);
+    }
+    let text = match uri.to_file_path().ok().map(std::fs::read_to_string) {
+        Some(Ok(text)) => text,
+        _ => {
+            tracing::error!("failed to read {} to edit it", uri);
+            return Ok(None);
+        }
+    };
+    let (text, endings) = LineEndings::normalize(text);
+    let line_index = LineIndex {
+        index: triomphe::Arc::new(ide::LineIndex::new(&text)),
+        endings,
+        encoding: snap.config.position_encoding(),
+    };
+    let edits = edit.into_iter().map(|it| snippet_text_edit(&line_index, false, it)).collect();
+    let text_document = lsp_types::OptionalVersionedTextDocumentIdentifier { version: None, uri };
+    Ok(Some(lsp_ext::SnippetTextDocumentEdit { text_document, edits }))
+}
+
 pub(crate) fn snippet_text_document_ops(
     snap: &GlobalStateSnapshot,
     file_system_edit: FileSystemEdit,
@@ -956,6 +986,22 @@ pub(crate) fn snippet_text_document_ops(
                 rename_file,
             )))
         }
+        FileSystemEdit::DeleteFile { file } => {
+            let uri = snap.file_id_to_url(file);
+            let mut delete_file = lsp_types::DeleteFile { uri, options: None };
+            if snap.analysis.is_library_file(file).ok() == Some(true)
+                && snap.config.change_annotation_support()
+            {
+                delete_file.options = Some(lsp_types::DeleteFileOptions {
+                    recursive: None,
+                    ignore_if_not_exists: None,
+                    annotation_id: Some(outside_workspace_annotation_id()),
+                })
+            }
+            ops.push(lsp_ext::SnippetDocumentChangeOperation::Op(lsp_types::ResourceOp::Delete(
+                delete_file,
+            )))
+        }
     }
     Ok(ops)
 }
@@ -970,10 +1016,52 @@ pub(crate) fn snippet_workspace_edit(
         let ops = snippet_text_document_ops(snap, op)?;
         document_changes.extend_from_slice(&ops);
     }
+    let mut snippet_edits = source_change.snippet_edits;
+    let mut annotations = FxHashMap::default();
     for (file_id, edit) in source_change.source_file_edits {
-        let edit = snippet_text_document_edit(snap, source_change.is_snippet, file_id, edit)?;
+        let (is_snippet, edit) = match snippet_edits.remove(&file_id) {
+            Some(snippet_edit) => {
+                let text = snap.analysis.file_text(file_id)?;
+                (true, snippet_edit.into_snippet_text_edit(&text, edit))
+            }
+            None => (source_change.is_snippet, edit),
+        };
+        let annotation_ids: Vec<_> = edit
+            .iter()
+            .map(|indel| {
+                let id = indel.annotation.as_ref()?;
+                let description = source_change.annotations.get(id)?;
+                annotations.entry(id.0.clone()).or_insert_with(|| lsp_types::ChangeAnnotation {
+                    label: description.clone(),
+                    needs_confirmation: None,
+                    description: None,
+                });
+                Some(id.0.clone())
+            })
+            .collect();
+        let mut edit = snippet_text_document_edit(snap, is_snippet, file_id, edit)?;
+        if snap.config.change_annotation_support() {
+            for (text_edit, annotation_id) in edit.edits.iter_mut().zip(annotation_ids) {
+                // Edits outside of the workspace keep the annotation asking for confirmation.
+                if text_edit.annotation_id.is_none() {
+                    text_edit.annotation_id = annotation_id;
+                }
+            }
+        }
         document_changes.push(lsp_ext::SnippetDocumentChangeOperation::Edit(edit));
     }
+    // Snippets in files that are not edited otherwise.
+    for (file_id, snippet_edit) in snippet_edits {
+        let text = snap.analysis.file_text(file_id)?;
+        let edit = snippet_edit.into_snippet_text_edit(&text, TextEdit::default());
+        let edit = snippet_text_document_edit(snap, true, file_id, edit)?;
+        document_changes.push(lsp_ext::SnippetDocumentChangeOperation::Edit(edit));
+    }
+    for (path, edit) in source_change.other_file_edits {
+        if let Some(edit) = other_file_text_document_edit(snap, &path, edit)? {
+            document_changes.push(lsp_ext::SnippetDocumentChangeOperation::Edit(edit));
+        }
+    }
     let mut workspace_edit = lsp_ext::SnippetWorkspaceEdit {
         changes: None,
         document_changes: Some(document_changes),
@@ -991,6 +1079,7 @@ pub(crate) fn snippet_workspace_edit(
                     )),
                 },
             ))
+            .chain(annotations)
             .collect(),
         )
     }
@@ -1410,9 +1499,12 @@ pub(crate) fn rename_error(err: RenameError) -> crate::LspError {
 
 #[cfg(test)]
 mod tests {
-    use ide::{Analysis, FilePosition};
+    use ide::{Analysis, Change, FilePosition, SourceRoot};
     use test_utils::extract_offset;
     use triomphe::Arc;
+    use vfs::{file_set::FileSet, AbsPathBuf, VfsPath};
+
+    use crate::global_state::GlobalState;
 
     use super::*;
 
@@ -1480,6 +1572,45 @@ fn bar(_: usize) {}
         assert!(!docs.contains("use crate::bar"));
     }
 
+    #[test]
+    fn annotates_deletions_outside_of_workspace() {
+        let root = AbsPathBuf::assert(std::env::temp_dir());
+        let caps = lsp_types::ClientCapabilities {
+            workspace: Some(lsp_types::WorkspaceClientCapabilities {
+                workspace_edit: Some(lsp_types::WorkspaceEditClientCapabilities {
+                    change_annotation_support: Some(Default::default()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let config = Config::new(root.clone(), caps, vec![root.clone()]);
+        let mut state = GlobalState::new(crossbeam_channel::unbounded().0, config);
+
+        let path = VfsPath::from(root.join("lib.rs"));
+        let file = {
+            let vfs = &mut state.vfs.write().0;
+            vfs.set_file_contents(path.clone(), Some(Vec::new()));
+            vfs.file_id(&path).unwrap()
+        };
+        let mut file_set = FileSet::default();
+        file_set.insert(file, path);
+        let mut change = Change::new();
+        change.set_roots(vec![SourceRoot::new_library(file_set)]);
+        state.analysis_host.apply_change(change);
+
+        let ops = snippet_text_document_ops(&state.snapshot(), FileSystemEdit::DeleteFile { file })
+            .unwrap();
+        let [lsp_ext::SnippetDocumentChangeOperation::Op(lsp_types::ResourceOp::Delete(delete))] =
+            &ops[..]
+        else {
+            panic!("expected a single deletion, got {ops:?}");
+        };
+        let annotation_id = delete.options.as_ref().and_then(|it| it.annotation_id.clone());
+        assert_eq!(annotation_id, Some(outside_workspace_annotation_id()));
+    }
+
     // `Url` is not able to parse windows paths on unix machines.
     #[test]
     #[cfg(target_os = "windows")]
@@ -280,6 +280,8 @@ fn check_test_attrs(path: &Path, text: &str) {
         "slow-tests/tidy.rs",
         // Special case to run `#[ignore]` tests.
         "ide/src/runnables.rs",
+        // Indexes `#[ignore]` tests, like the above.
+        "ide-db/src/test_index.rs",
         // A legit test which needs to be ignored, as it takes too long to run
         // :(
         "hir-def/src/nameres/collector.rs",
@@ -45,6 +45,20 @@ pub fn to_lower_snake_case(s: &str) -> String {
 pub fn to_upper_snake_case(s: &str) -> String {
     to_snake_case(s, char::to_uppercase)
 }
+pub fn to_upper_camel_case(s: &str) -> String {
+    let snake = to_lower_snake_case(s);
+    let words = snake.trim_start_matches('_');
+    // Preserve leading underscores
+    let mut res = snake[..snake.len() - words.len()].to_owned();
+    for word in words.split('_') {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            res.extend(first.to_uppercase());
+            res.push_str(chars.as_str());
+        }
+    }
+    res
+}
 
 // Code partially taken from rust/compiler/rustc_lint/src/nonstandard_style.rs
 // commit: 9626f2b
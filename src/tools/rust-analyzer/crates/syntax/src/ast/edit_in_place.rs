@@ -1,6 +1,6 @@
 //! Structural editing for ast.
 
-use std::iter::{empty, successors};
+use std::iter::{empty, once, successors};
 
 use parser::{SyntaxKind, T};
 use rowan::SyntaxElement;
@@ -423,6 +423,25 @@ fn split_path_prefix(prefix: &ast::Path) -> Option<()> {
             Some(())
         }
     }
+
+    /// Wraps the use tree in a use tree list without a path, unless it already is one.
+    ///
+    /// # Examples
+    ///
+    /// `foo::bar` -> `{foo::bar}`
+    ///
+    /// `{foo::bar}` -> `{foo::bar}`
+    pub fn wrap_in_tree_list(&self) {
+        if self.path().is_none() && self.use_tree_list().is_some() {
+            return;
+        }
+        let subtree = self.clone_subtree().clone_for_update();
+        ted::remove_all_iter(self.syntax().children_with_tokens());
+        ted::append_child(
+            self.syntax(),
+            make::use_tree_list(once(subtree)).clone_for_update().syntax(),
+        );
+    }
 }
 
 impl ast::UseTreeList {
@@ -384,6 +384,10 @@ pub fn use_tree(
     ast_from_text(&buf)
 }
 
+pub fn rename(name: ast::Name) -> ast::Rename {
+    ast_from_text(&format!("use foo as {name};"))
+}
+
 pub fn use_tree_list(use_trees: impl IntoIterator<Item = ast::UseTree>) -> ast::UseTreeList {
     let use_trees = use_trees.into_iter().map(|it| it.syntax().clone()).join(", ");
     ast_from_text(&format!("use {{{use_trees}}};"))
@@ -625,6 +629,10 @@ fn from_text(text: &str) -> ast::WildcardPat {
     }
 }
 
+pub fn rest_pat() -> ast::RestPat {
+    ast_from_text("fn f() { let (..) = (); }")
+}
+
 pub fn literal_pat(lit: &str) -> ast::LiteralPat {
     return from_text(lit);
 
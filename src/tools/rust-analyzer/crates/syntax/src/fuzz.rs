@@ -42,7 +42,7 @@ pub fn from_data(data: &[u8]) -> Option<Self> {
             TextRange::at(delete_start.try_into().unwrap(), delete_len.try_into().unwrap());
         let edited_text =
             format!("{}{}{}", &text[..delete_start], &insert, &text[delete_start + delete_len..]);
-        let edit = Indel { insert, delete };
+        let edit = Indel::replace(delete, insert);
         Some(CheckReparse { text, edit, edited_text })
     }
 
@@ -18,8 +18,14 @@ pub struct Indel {
     pub insert: String,
     /// Refers to offsets in the original text
     pub delete: TextRange,
+    /// Why the indel is made, for clients that group the edits of a change by their reason.
+    pub annotation: Option<ChangeAnnotationId>,
 }
 
+/// Identifies the reason of an [`Indel`], which is the same for all indels made for it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChangeAnnotationId(pub String);
+
 #[derive(Default, Debug, Clone)]
 pub struct TextEdit {
     /// Invariant: disjoint and sorted by `delete`.
@@ -39,7 +45,7 @@ pub fn delete(range: TextRange) -> Indel {
         Indel::replace(range, String::new())
     }
     pub fn replace(range: TextRange, replace_with: String) -> Indel {
-        Indel { delete: range, insert: replace_with }
+        Indel { delete: range, insert: replace_with, annotation: None }
     }
 
     pub fn apply(&self, text: &mut String) {
@@ -84,6 +90,13 @@ pub fn iter(&self) -> std::slice::Iter<'_, Indel> {
         self.into_iter()
     }
 
+    /// Annotates the indels that aren't annotated yet with `annotation`.
+    pub fn annotate(&mut self, annotation: &ChangeAnnotationId) {
+        for indel in self.indels.iter_mut().filter(|it| it.annotation.is_none()) {
+            indel.annotation = Some(annotation.clone());
+        }
+    }
+
     pub fn apply(&self, text: &mut String) {
         match self.len() {
             0 => return,
@@ -173,6 +186,10 @@ pub fn delete(&mut self, range: TextRange) {
     pub fn insert(&mut self, offset: TextSize, text: String) {
         self.indel(Indel::insert(offset, text));
     }
+    /// Adds an indel as is, keeping its annotation.
+    pub fn push(&mut self, indel: Indel) {
+        self.indel(indel);
+    }
     pub fn finish(self) -> TextEdit {
         let mut indels = self.indels;
         assert_disjoint_or_equal(&mut indels);
@@ -210,7 +227,7 @@ fn coalesce_indels(indels: Vec<Indel>) -> Vec<Indel> {
     indels
         .into_iter()
         .coalesce(|mut a, b| {
-            if a.delete.end() == b.delete.start() {
+            if a.delete.end() == b.delete.start() && a.annotation == b.annotation {
                 a.insert.push_str(&b.insert);
                 a.delete = TextRange::new(a.delete.start(), b.delete.end());
                 Ok(a)